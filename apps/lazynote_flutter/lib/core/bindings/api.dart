@@ -6,9 +6,9 @@
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:lazynote_flutter/core/bindings/frb_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `atom_list_failure`, `atom_type_label`, `atom_update_status_impl`, `calendar_list_by_range_impl`, `calendar_update_event_impl`, `code`, `code`, `code`, `entry_create_note_impl`, `entry_create_task_impl`, `entry_schedule_impl`, `entry_search_impl`, `failure`, `is_db_busy`, `map_db_error`, `map_note_service_error`, `map_repo_error`, `map_task_service_error`, `map_tree_repo_error`, `map_tree_service_error`, `map_workspace_db_error`, `message`, `message`, `message`, `normalize_entry_limit`, `normalize_section_limit`, `note_create_impl`, `note_failure`, `note_get_impl`, `note_set_tags_impl`, `note_update_impl`, `notes_list_impl`, `parse_entry_search_kind`, `parse_folder_delete_mode`, `parse_note_id`, `parse_optional_parent_node_id`, `parse_workspace_atom_id`, `parse_workspace_node_id`, `resolve_entry_db_path`, `set_configured_entry_db_path`, `success`, `tags_list_impl`, `tasks_list_inbox_impl`, `tasks_list_today_impl`, `tasks_list_upcoming_impl`, `to_atom_list_item`, `to_entry_search_item`, `to_note_item`, `to_workspace_node_item`, `with_atom_service`, `with_note_service`, `with_task_service`, `with_tree_service`, `workspace_create_folder_impl`, `workspace_create_note_ref_impl`, `workspace_delete_folder_impl`, `workspace_failure`, `workspace_list_children_impl`, `workspace_list_failure`, `workspace_move_node_impl`, `workspace_node_failure`, `workspace_node_kind_label`, `workspace_rename_node_impl`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AtomFfiError`, `NotesFfiError`, `WorkspaceFfiError`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `atom_add_tag_impl`, `atom_list_failure`, `atom_record_access_impl`, `atom_remove_tag_impl`, `atom_set_flag_impl`, `atom_set_tags_impl`, `atom_tags_mutation`, `atom_type_label`, `atom_update_status_impl`, `calendar_list_by_range_impl`, `calendar_update_event_impl`, `code`, `code`, `code`, `entry_create_note_impl`, `entry_create_task_impl`, `entry_schedule_impl`, `entry_search_all_impl`, `entry_search_impl`, `entry_search_semantic_impl`, `failure`, `failure`, `is_db_busy`, `journal_redo_impl`, `journal_replay`, `journal_status_impl`, `journal_undo_impl`, `lookup_search_session`, `map_db_error`, `map_note_service_error`, `map_repo_error`, `map_task_service_error`, `map_tree_repo_error`, `map_tree_service_error`, `map_workspace_db_error`, `message`, `message`, `message`, `normalize_entry_limit`, `normalize_section_limit`, `note_create_from_template_impl`, `note_create_impl`, `note_failure`, `note_get_impl`, `note_set_tags_impl`, `note_template_delete_impl`, `note_template_failure`, `note_template_save_impl`, `note_templates_list_impl`, `note_update_impl`, `notes_list_impl`, `parse_entry_search_kind`, `parse_folder_delete_mode`, `parse_note_id`, `parse_optional_parent_node_id`, `parse_template_id`, `parse_workspace_atom_id`, `parse_workspace_node_id`, `resolve_entry_db_path`, `run_entry_atom_search`, `run_entry_search`, `search_refresh_embeddings_impl`, `search_session_cancel_impl`, `search_session_close_impl`, `search_session_open_impl`, `search_session_query_impl`, `section_flag_filter`, `set_configured_entry_db_path`, `success`, `success`, `tags_list_impl`, `tasks_list_inbox_impl`, `tasks_list_today_impl`, `tasks_list_upcoming_impl`, `to_atom_list_item`, `to_entry_search_all_item`, `to_entry_search_item`, `to_note_item`, `to_note_template_item`, `to_workspace_node_item`, `trash_action`, `trash_list_impl`, `trash_purge_impl`, `trash_purge_older_than_impl`, `trash_restore_impl`, `with_atom_service`, `with_filtered_task_service`, `with_journal_service`, `with_note_service`, `with_tag_service`, `with_task_service`, `with_trash_service`, `with_tree_service`, `workspace_create_folder_impl`, `workspace_create_note_from_link_impl`, `workspace_create_note_ref_impl`, `workspace_create_smart_folder_impl`, `workspace_delete_folder_impl`, `workspace_failure`, `workspace_list_children_impl`, `workspace_list_deleted_folders_impl`, `workspace_list_failure`, `workspace_move_node_impl`, `workspace_node_failure`, `workspace_node_kind_label`, `workspace_rename_node_impl`, `workspace_restore_folder_impl`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AtomFfiError`, `NotesFfiError`, `SearchSessionSlot`, `WorkspaceFfiError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// Minimal health-check API for FRB smoke integration.
 ///
@@ -55,6 +55,8 @@ String configureEntryDbPath({required String dbPath}) =>
 /// - Async call, DB-backed execution.
 /// - Never panics.
 /// - Returns deterministic envelope with applied limit.
+/// - `kind`: optional `all|note|task|event` (case-insensitive).
+/// - Returns `invalid_kind` when `kind` is outside allowed values.
 Future<EntrySearchResponse> entrySearch({
   required String text,
   String? kind,
@@ -65,6 +67,98 @@ Future<EntrySearchResponse> entrySearch({
   limit: limit,
 );
 
+/// Searches atoms by keywords and meaning for single-entry flow.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - Same `kind`/`limit` semantics and envelope as [`entry_search`].
+/// - Fuses keyword rank with local embedding similarity, so atoms that share
+///   no literal term with `text` can match.
+/// - Read-only: embeddings come from [`search_refresh_embeddings`].
+Future<EntrySearchResponse> entrySearchSemantic({
+  required String text,
+  String? kind,
+  int? limit,
+}) => RustLib.instance.api.crateApiEntrySearchSemantic(
+  text: text,
+  kind: kind,
+  limit: limit,
+);
+
+/// Embeds atoms whose stored vector is missing or out of date.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - [`entry_search_semantic`] only reads stored embeddings; call this on
+///   app start and after bulk edits so new wording is found by meaning.
+Future<EmbeddingsRefreshResponse> searchRefreshEmbeddings() =>
+    RustLib.instance.api.crateApiSearchRefreshEmbeddings();
+
+/// Searches atoms, tags and workspace node names for single-entry flow.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - Same `kind`/`limit` semantics as [`entry_search`]; a non-`all` kind
+///   disables tag and workspace name matching.
+/// - Items are ordered workspace nodes, tags, then atoms.
+Future<EntrySearchAllResponse> entrySearchAll({
+  required String text,
+  String? kind,
+  int? limit,
+}) => RustLib.instance.api.crateApiEntrySearchAll(
+  text: text,
+  kind: kind,
+  limit: limit,
+);
+
+/// Opens a search-as-you-type session bound to the entry database.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - `kind` and `limit` follow [`entry_search`] semantics and stay fixed for
+///   the session lifetime.
+/// - Callers must release the session with [`search_session_close`].
+Future<SearchSessionResponse> searchSessionOpen({String? kind, int? limit}) =>
+    RustLib.instance.api.crateApiSearchSessionOpen(kind: kind, limit: limit);
+
+/// Runs one keystroke query inside an open search session.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - Cancels any in-flight query of the same session first; the superseded
+///   call returns `error_code = "cancelled"`.
+/// - Extending the previous text narrows cached results without a rescan.
+Future<EntrySearchResponse> searchSessionQuery({
+  required String sessionId,
+  required String text,
+}) => RustLib.instance.api.crateApiSearchSessionQuery(
+  sessionId: sessionId,
+  text: text,
+);
+
+/// Cancels the in-flight query of a search session, if any.
+///
+/// # FFI contract
+/// - Sync call, non-blocking; safe to call from the UI thread.
+/// - Never panics.
+/// - The session stays open.
+SearchSessionResponse searchSessionCancel({required String sessionId}) =>
+    RustLib.instance.api.crateApiSearchSessionCancel(sessionId: sessionId);
+
+/// Closes a search session and releases its connection.
+///
+/// # FFI contract
+/// - Sync call, non-blocking.
+/// - Never panics.
+/// - Cancels any in-flight query of the session.
+SearchSessionResponse searchSessionClose({required String sessionId}) =>
+    RustLib.instance.api.crateApiSearchSessionClose(sessionId: sessionId);
+
 /// Creates a note from single-entry command flow.
 ///
 /// # FFI contract
@@ -128,18 +222,30 @@ Future<NoteResponse> noteUpdate({
 Future<NoteResponse> noteGet({required String atomId}) =>
     RustLib.instance.api.crateApiNoteGet(atomId: atomId);
 
-/// Lists notes with optional single-tag filter and pagination.
+/// Lists notes with optional single-tag filter, flag filters and pagination.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Returns only `AtomType::Note` rows.
+/// - Returns only `AtomType::Note` rows, pinned notes first.
+/// - Archived notes are skipped unless `include_archived` is true.
+/// - `pinned`/`starred`: `Some(value)` keeps only notes whose flag equals
+///   `value`.
 /// - Limit normalization: default 10, max 50.
-Future<NotesListResponse> notesList({String? tag, int? limit, int? offset}) =>
-    RustLib.instance.api.crateApiNotesList(
-      tag: tag,
-      limit: limit,
-      offset: offset,
-    );
+Future<NotesListResponse> notesList({
+  String? tag,
+  int? limit,
+  int? offset,
+  required bool includeArchived,
+  bool? pinned,
+  bool? starred,
+}) => RustLib.instance.api.crateApiNotesList(
+  tag: tag,
+  limit: limit,
+  offset: offset,
+  includeArchived: includeArchived,
+  pinned: pinned,
+  starred: starred,
+);
 
 /// Atomically replaces full tag set for one note.
 ///
@@ -183,6 +289,30 @@ Future<WorkspaceNodeResponse> workspaceCreateFolder({
   name: name,
 );
 
+/// Creates one workspace smart folder backed by a saved search.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `kind`: `all|note|task|event` or null for all types.
+/// - `tag`: optional tag filter; leading `#` is ignored.
+/// - `open_only`: keep only atoms with `todo|in_progress` status.
+/// - Children are evaluated live by [`workspace_list_children`].
+Future<WorkspaceNodeResponse> workspaceCreateSmartFolder({
+  String? parentNodeId,
+  required String name,
+  required String queryText,
+  String? kind,
+  String? tag,
+  required bool openOnly,
+}) => RustLib.instance.api.crateApiWorkspaceCreateSmartFolder(
+  parentNodeId: parentNodeId,
+  name: name,
+  queryText: queryText,
+  kind: kind,
+  tag: tag,
+  openOnly: openOnly,
+);
+
 /// Creates one workspace note_ref under optional parent.
 ///
 /// # FFI contract
@@ -198,6 +328,20 @@ Future<WorkspaceNodeResponse> workspaceCreateNoteRef({
   displayName: displayName,
 );
 
+/// Creates the missing note behind an unresolved `[[target_title]]` link.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `source_atom_id` must be UUID string of a note containing the link.
+/// - Returns the new note_ref node placed next to the source note's node.
+Future<WorkspaceNodeResponse> workspaceCreateNoteFromLink({
+  required String sourceAtomId,
+  required String targetTitle,
+}) => RustLib.instance.api.crateApiWorkspaceCreateNoteFromLink(
+  sourceAtomId: sourceAtomId,
+  targetTitle: targetTitle,
+);
+
 /// Renames one workspace node.
 ///
 /// # FFI contract
@@ -240,13 +384,45 @@ Future<WorkspaceActionResponse> workspaceDeleteFolder({
   mode: mode,
 );
 
+/// Lists workspace folder deletions that can still be undone.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Newest deletion first.
+Future<DeletedFoldersResponse> workspaceListDeletedFolders() =>
+    RustLib.instance.api.crateApiWorkspaceListDeletedFolders();
+
+/// Undoes the latest deletion of one workspace folder.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Restores the folder at its original parent and position (root when
+///   the parent is gone), its subtree and the notes the delete trashed.
+/// - Returns `node_not_found` when the folder has no undoable deletion.
+Future<WorkspaceNodeResponse> workspaceRestoreFolder({
+  required String nodeId,
+}) => RustLib.instance.api.crateApiWorkspaceRestoreFolder(nodeId: nodeId);
+
 /// Lists inbox atoms (both `start_at` and `end_at` NULL).
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
 /// - Excludes done/cancelled atoms.
-Future<AtomListResponse> tasksListInbox({int? limit, int? offset}) =>
-    RustLib.instance.api.crateApiTasksListInbox(limit: limit, offset: offset);
+/// - Archived atoms are skipped unless `include_archived` is true;
+///   `pinned`/`starred` keep only atoms whose flag equals the given value.
+Future<AtomListResponse> tasksListInbox({
+  int? limit,
+  int? offset,
+  required bool includeArchived,
+  bool? pinned,
+  bool? starred,
+}) => RustLib.instance.api.crateApiTasksListInbox(
+  limit: limit,
+  offset: offset,
+  includeArchived: includeArchived,
+  pinned: pinned,
+  starred: starred,
+);
 
 /// Lists atoms active today based on time-matrix rules.
 ///
@@ -254,16 +430,24 @@ Future<AtomListResponse> tasksListInbox({int? limit, int? offset}) =>
 /// - `bod_ms`/`eod_ms`: device-local day boundaries in epoch ms.
 /// - Async call, DB-backed execution.
 /// - Excludes done/cancelled atoms.
+/// - Archived atoms are skipped unless `include_archived` is true;
+///   `pinned`/`starred` keep only atoms whose flag equals the given value.
 Future<AtomListResponse> tasksListToday({
   required PlatformInt64 bodMs,
   required PlatformInt64 eodMs,
   int? limit,
   int? offset,
+  required bool includeArchived,
+  bool? pinned,
+  bool? starred,
 }) => RustLib.instance.api.crateApiTasksListToday(
   bodMs: bodMs,
   eodMs: eodMs,
   limit: limit,
   offset: offset,
+  includeArchived: includeArchived,
+  pinned: pinned,
+  starred: starred,
 );
 
 /// Lists atoms anchored entirely in the future.
@@ -272,14 +456,22 @@ Future<AtomListResponse> tasksListToday({
 /// - `eod_ms`: end of today in epoch ms.
 /// - Async call, DB-backed execution.
 /// - Excludes done/cancelled atoms.
+/// - Archived atoms are skipped unless `include_archived` is true;
+///   `pinned`/`starred` keep only atoms whose flag equals the given value.
 Future<AtomListResponse> tasksListUpcoming({
   required PlatformInt64 eodMs,
   int? limit,
   int? offset,
+  required bool includeArchived,
+  bool? pinned,
+  bool? starred,
 }) => RustLib.instance.api.crateApiTasksListUpcoming(
   eodMs: eodMs,
   limit: limit,
   offset: offset,
+  includeArchived: includeArchived,
+  pinned: pinned,
+  starred: starred,
 );
 
 /// Updates `task_status` for any atom type (universal completion).
@@ -296,22 +488,92 @@ Future<EntryActionResponse> atomUpdateStatus({
   status: status,
 );
 
+/// Sets the archived, pinned or starred flag on any atom type.
+///
+/// # FFI contract
+/// - `flag`: one of `archived|pinned|starred`.
+/// - Async call, DB-backed execution.
+/// - Idempotent: setting the same value twice succeeds.
+/// - Archiving hides the atom from lists, sections and search; it is not a
+///   delete.
+Future<EntryActionResponse> atomSetFlag({
+  required String atomId,
+  required String flag,
+  required bool value,
+}) => RustLib.instance.api.crateApiAtomSetFlag(
+  atomId: atomId,
+  flag: flag,
+  value: value,
+);
+
+/// Records that the user opened an atom; feeds the search usage boost.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Fails with `atom_not_found` for missing or deleted atoms.
+/// - Stores only an aggregated count and last access time per atom.
+Future<EntryActionResponse> atomRecordAccess({required String atomId}) =>
+    RustLib.instance.api.crateApiAtomRecordAccess(atomId: atomId);
+
+/// Replaces all tags of one atom of any type (note, task, event).
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Tags are normalized (trimmed, lowercased, deduplicated).
+/// - Replaces manual tags only; inline `#hashtags` from content are kept.
+/// - Fails with `invalid_tag` when any tag is blank and `atom_not_found`
+///   for missing or deleted atoms.
+Future<AtomTagsResponse> atomSetTags({
+  required String atomId,
+  required List<String> tags,
+}) => RustLib.instance.api.crateApiAtomSetTags(atomId: atomId, tags: tags);
+
+/// Adds one tag to an atom of any type.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Idempotent: adding an existing tag succeeds unchanged.
+/// - Returns the atom's full tag list after the mutation.
+Future<AtomTagsResponse> atomAddTag({
+  required String atomId,
+  required String tag,
+}) => RustLib.instance.api.crateApiAtomAddTag(atomId: atomId, tag: tag);
+
+/// Removes one tag from an atom of any type.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Idempotent: removing an absent tag succeeds unchanged.
+/// - Descendant tags (`tag/...`) and inline `#hashtags` stay on the atom.
+Future<AtomTagsResponse> atomRemoveTag({
+  required String atomId,
+  required String tag,
+}) => RustLib.instance.api.crateApiAtomRemoveTag(atomId: atomId, tag: tag);
+
 /// Lists atoms with both `start_at` and `end_at` that overlap the given time range.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
 /// - Includes all statuses (done/cancelled shown on calendar).
 /// - Range overlap: `start_at < range_end AND end_at > range_start`.
+/// - Archived atoms are skipped unless `include_archived` is true;
+///   `pinned`/`starred` keep only atoms whose flag equals the given value.
 Future<AtomListResponse> calendarListByRange({
   required PlatformInt64 startMs,
   required PlatformInt64 endMs,
   int? limit,
   int? offset,
+  required bool includeArchived,
+  bool? pinned,
+  bool? starred,
 }) => RustLib.instance.api.crateApiCalendarListByRange(
   startMs: startMs,
   endMs: endMs,
   limit: limit,
   offset: offset,
+  includeArchived: includeArchived,
+  pinned: pinned,
+  starred: starred,
 );
 
 /// Updates only `start_at` and `end_at` for a calendar event.
@@ -330,6 +592,133 @@ Future<EntryActionResponse> calendarUpdateEvent({
   endMs: endMs,
 );
 
+/// Lists soft-deleted atoms, most recently deleted first.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `limit` defaults to 50 and clamps to 50.
+Future<TrashListResponse> trashList({int? limit, int? offset}) =>
+    RustLib.instance.api.crateApiTrashList(limit: limit, offset: offset);
+
+/// Restores one soft-deleted atom from the trash.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `atom_not_found` when the atom is not in the trash.
+/// - A restored note reappears in the workspace tree; its note_ref moves to
+///   the root when the original folder was deleted.
+Future<EntryActionResponse> trashRestore({required String atomId}) =>
+    RustLib.instance.api.crateApiTrashRestore(atomId: atomId);
+
+/// Permanently deletes one soft-deleted atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `atom_not_found` when the atom is not in the trash; active
+///   atoms are never purged.
+Future<EntryActionResponse> trashPurge({required String atomId}) =>
+    RustLib.instance.api.crateApiTrashPurge(atomId: atomId);
+
+/// Permanently deletes atoms trashed more than `days` days ago.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `days = 0` empties the trash.
+/// - Intended to run on app start with the user's retention setting.
+Future<TrashPurgeResponse> trashPurgeOlderThan({required int days}) =>
+    RustLib.instance.api.crateApiTrashPurgeOlderThan(days: days);
+
+/// Undoes the newest recorded mutation.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Covers note content/tag edits, workspace moves, status changes and
+///   event time changes.
+/// - `ok=true` with `command=None` when there is nothing to undo.
+/// - Returns `atom_not_found` when the mutated atom or node is gone; that
+///   step is dropped so the next call undoes the one before it.
+Future<JournalResponse> journalUndo() =>
+    RustLib.instance.api.crateApiJournalUndo();
+
+/// Redoes the last undone mutation.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `ok=true` with `command=None` when there is nothing to redo; any new
+///   mutation after an undo clears the redo side.
+Future<JournalResponse> journalRedo() =>
+    RustLib.instance.api.crateApiJournalRedo();
+
+/// Returns how many steps can be undone and redone.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never replays anything; `command` and `target_id` are always `None`.
+Future<JournalResponse> journalStatus() =>
+    RustLib.instance.api.crateApiJournalStatus();
+
+/// Creates or updates one note template.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Creates a template when `template_id` is `None`, otherwise replaces
+///   every field of that template.
+/// - Returns `invalid_template` for a blank or already used name and
+///   `invalid_tag` for blank tags.
+/// - `folder_node_id` is not validated: notes land at the workspace root
+///   when it does not point at an active folder.
+Future<NoteTemplateResponse> noteTemplateSave({
+  String? templateId,
+  required String name,
+  required String content,
+  required List<String> tags,
+  String? folderNodeId,
+}) => RustLib.instance.api.crateApiNoteTemplateSave(
+  templateId: templateId,
+  name: name,
+  content: content,
+  tags: tags,
+  folderNodeId: folderNodeId,
+);
+
+/// Lists note templates sorted by name.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns typed envelope with stable error codes.
+Future<NoteTemplatesListResponse> noteTemplatesList() =>
+    RustLib.instance.api.crateApiNoteTemplatesList();
+
+/// Deletes one note template.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Notes already created from the template are kept.
+/// - Returns `template_not_found` when the template does not exist.
+Future<NoteTemplateResponse> noteTemplateDelete({required String templateId}) =>
+    RustLib.instance.api.crateApiNoteTemplateDelete(templateId: templateId);
+
+/// Creates one note from a template.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Expands `{{date}}`/`{{time}}` in `utc_offset_minutes` (UTC when
+///   `None`), `{{title}}` from `title` and custom prompts from `answers`;
+///   unanswered prompts expand to empty text.
+/// - Applies the template tags and files the note in the template folder.
+/// - Returns `template_not_found` when the template does not exist.
+Future<TemplateNoteResponse> noteCreateFromTemplate({
+  required String templateId,
+  String? title,
+  required List<TemplatePromptAnswer> answers,
+  int? utcOffsetMinutes,
+}) => RustLib.instance.api.crateApiNoteCreateFromTemplate(
+  templateId: templateId,
+  title: title,
+  answers: answers,
+  utcOffsetMinutes: utcOffsetMinutes,
+);
+
 /// Atom list item returned by section queries (Inbox/Today/Upcoming).
 class AtomListItem {
   /// Stable atom ID in string form.
@@ -362,6 +751,15 @@ class AtomListItem {
   /// Update timestamp in epoch milliseconds.
   final PlatformInt64 updatedAt;
 
+  /// Hidden from default lists; not deleted.
+  final bool isArchived;
+
+  /// Sorted first in lists.
+  final bool isPinned;
+
+  /// User favorite.
+  final bool isStarred;
+
   const AtomListItem({
     required this.atomId,
     required this.kind,
@@ -373,6 +771,9 @@ class AtomListItem {
     this.endAt,
     this.taskStatus,
     required this.updatedAt,
+    required this.isArchived,
+    required this.isPinned,
+    required this.isStarred,
   });
 
   @override
@@ -386,7 +787,10 @@ class AtomListItem {
       startAt.hashCode ^
       endAt.hashCode ^
       taskStatus.hashCode ^
-      updatedAt.hashCode;
+      updatedAt.hashCode ^
+      isArchived.hashCode ^
+      isPinned.hashCode ^
+      isStarred.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -402,7 +806,10 @@ class AtomListItem {
           startAt == other.startAt &&
           endAt == other.endAt &&
           taskStatus == other.taskStatus &&
-          updatedAt == other.updatedAt;
+          updatedAt == other.updatedAt &&
+          isArchived == other.isArchived &&
+          isPinned == other.isPinned &&
+          isStarred == other.isStarred;
 }
 
 /// Section list response envelope.
@@ -450,109 +857,440 @@ class AtomListResponse {
           appliedLimit == other.appliedLimit;
 }
 
-/// Generic action response envelope for single-entry command flow.
-class EntryActionResponse {
+/// Atom tag mutation response envelope (any atom type).
+class AtomTagsResponse {
   /// Whether operation succeeded.
   final bool ok;
 
-  /// Optional created atom ID.
-  final String? atomId;
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
 
-  /// Human-readable response message for diagnostics/UI.
+  /// Human-readable message for diagnostics/UI.
   final String message;
 
-  const EntryActionResponse({
+  /// Target atom ID on success.
+  final String? atomId;
+
+  /// Normalized tags on the atom after the mutation.
+  final List<String> tags;
+
+  /// Subset of `tags` derived from the content (inline `#hashtags` and
+  /// front-matter `tags`); these go away by editing the content, not via
+  /// `atom_remove_tag`.
+  final List<String> inlineTags;
+
+  const AtomTagsResponse({
     required this.ok,
-    this.atomId,
+    this.errorCode,
     required this.message,
+    this.atomId,
+    required this.tags,
+    required this.inlineTags,
   });
 
   @override
-  int get hashCode => ok.hashCode ^ atomId.hashCode ^ message.hashCode;
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      atomId.hashCode ^
+      tags.hashCode ^
+      inlineTags.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is EntryActionResponse &&
+      other is AtomTagsResponse &&
           runtimeType == other.runtimeType &&
           ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
           atomId == other.atomId &&
-          message == other.message;
+          tags == other.tags &&
+          inlineTags == other.inlineTags;
 }
 
-/// Search item returned by single-entry search API.
-class EntrySearchItem {
-  /// Stable atom ID in string form.
-  final String atomId;
+/// Undoable folder deletion DTO exposed over FFI.
+class DeletedFolderItem {
+  /// Deleted folder node id.
+  final String nodeId;
 
-  /// Atom projection kind (`note|task|event`).
-  final String kind;
+  /// Folder display name.
+  final String displayName;
 
-  /// Short snippet summary for result display.
-  final String snippet;
+  /// Delete mode label (`dissolve|delete_all`).
+  final String mode;
 
-  const EntrySearchItem({
-    required this.atomId,
-    required this.kind,
-    required this.snippet,
+  /// Deletion time in epoch milliseconds.
+  final PlatformInt64 deletedAt;
+
+  /// Number of nodes the delete moved or removed, folder included.
+  final int nodeCount;
+
+  /// Number of notes the delete moved to the trash.
+  final int noteCount;
+
+  const DeletedFolderItem({
+    required this.nodeId,
+    required this.displayName,
+    required this.mode,
+    required this.deletedAt,
+    required this.nodeCount,
+    required this.noteCount,
   });
 
   @override
-  int get hashCode => atomId.hashCode ^ kind.hashCode ^ snippet.hashCode;
+  int get hashCode =>
+      nodeId.hashCode ^
+      displayName.hashCode ^
+      mode.hashCode ^
+      deletedAt.hashCode ^
+      nodeCount.hashCode ^
+      noteCount.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is EntrySearchItem &&
+      other is DeletedFolderItem &&
           runtimeType == other.runtimeType &&
-          atomId == other.atomId &&
-          kind == other.kind &&
-          snippet == other.snippet;
+          nodeId == other.nodeId &&
+          displayName == other.displayName &&
+          mode == other.mode &&
+          deletedAt == other.deletedAt &&
+          nodeCount == other.nodeCount &&
+          noteCount == other.noteCount;
 }
 
-/// Search response envelope for single-entry search flow.
-class EntrySearchResponse {
-  /// Whether search execution succeeded.
+/// Deleted-folder list response envelope.
+class DeletedFoldersResponse {
+  /// Whether operation succeeded.
   final bool ok;
 
-  /// Optional stable error code for machine branching.
+  /// Stable machine-readable error code for failure paths.
   final String? errorCode;
 
-  /// Search results (empty when no hits or scaffold mode).
-  final List<EntrySearchItem> items;
-
-  /// Human-readable response message for diagnostics.
+  /// Human-readable message for diagnostics/UI.
   final String message;
 
-  /// Effective applied search limit.
-  final int appliedLimit;
+  /// Undoable folder deletions, newest first.
+  final List<DeletedFolderItem> items;
 
-  const EntrySearchResponse({
+  const DeletedFoldersResponse({
     required this.ok,
     this.errorCode,
-    required this.items,
     required this.message,
-    required this.appliedLimit,
+    required this.items,
   });
 
   @override
   int get hashCode =>
-      ok.hashCode ^
-      errorCode.hashCode ^
-      items.hashCode ^
-      message.hashCode ^
-      appliedLimit.hashCode;
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ items.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is EntrySearchResponse &&
+      other is DeletedFoldersResponse &&
           runtimeType == other.runtimeType &&
           ok == other.ok &&
           errorCode == other.errorCode &&
-          items == other.items &&
           message == other.message &&
-          appliedLimit == other.appliedLimit;
+          items == other.items;
+}
+
+/// Embeddings refresh response envelope.
+class EmbeddingsRefreshResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Number of atoms whose embedding was (re)computed.
+  final int refreshed;
+
+  const EmbeddingsRefreshResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.refreshed,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ refreshed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmbeddingsRefreshResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          refreshed == other.refreshed;
+}
+
+/// Generic action response envelope for single-entry command flow.
+class EntryActionResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Optional created atom ID.
+  final String? atomId;
+
+  /// Human-readable response message for diagnostics/UI.
+  final String message;
+
+  const EntryActionResponse({
+    required this.ok,
+    this.atomId,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => ok.hashCode ^ atomId.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntryActionResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          atomId == other.atomId &&
+          message == other.message;
+}
+
+/// Typed search item returned by [`entry_search_all`].
+class EntrySearchAllItem {
+  /// Hit kind (`note|task|event|folder|note_ref|tag`).
+  final String kind;
+
+  /// Stable atom ID for atom hits and note_ref hits.
+  final String? atomId;
+
+  /// Workspace node ID for folder/note_ref hits.
+  final String? nodeId;
+
+  /// Parent workspace node ID for non-root folder/note_ref hits.
+  final String? parentNodeId;
+
+  /// Snippet for atom hits, display name for nodes, tag name for tags.
+  final String label;
+
+  /// Active atom count for tag hits.
+  final int? atomCount;
+
+  const EntrySearchAllItem({
+    required this.kind,
+    this.atomId,
+    this.nodeId,
+    this.parentNodeId,
+    required this.label,
+    this.atomCount,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      atomId.hashCode ^
+      nodeId.hashCode ^
+      parentNodeId.hashCode ^
+      label.hashCode ^
+      atomCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchAllItem &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          atomId == other.atomId &&
+          nodeId == other.nodeId &&
+          parentNodeId == other.parentNodeId &&
+          label == other.label &&
+          atomCount == other.atomCount;
+}
+
+/// Search response envelope for typed single-entry search flow.
+class EntrySearchAllResponse {
+  /// Whether search execution succeeded.
+  final bool ok;
+
+  /// Optional stable error code for machine branching.
+  final String? errorCode;
+
+  /// Typed results: workspace nodes, tags, then atoms.
+  final List<EntrySearchAllItem> items;
+
+  /// Human-readable response message for diagnostics.
+  final String message;
+
+  /// Effective applied search limit.
+  final int appliedLimit;
+
+  const EntrySearchAllResponse({
+    required this.ok,
+    this.errorCode,
+    required this.items,
+    required this.message,
+    required this.appliedLimit,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      items.hashCode ^
+      message.hashCode ^
+      appliedLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchAllResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          items == other.items &&
+          message == other.message &&
+          appliedLimit == other.appliedLimit;
+}
+
+/// Search item returned by single-entry search API.
+class EntrySearchItem {
+  /// Stable atom ID in string form.
+  final String atomId;
+
+  /// Atom projection kind (`note|task|event`).
+  final String kind;
+
+  /// Short snippet summary for result display.
+  final String snippet;
+
+  const EntrySearchItem({
+    required this.atomId,
+    required this.kind,
+    required this.snippet,
+  });
+
+  @override
+  int get hashCode => atomId.hashCode ^ kind.hashCode ^ snippet.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchItem &&
+          runtimeType == other.runtimeType &&
+          atomId == other.atomId &&
+          kind == other.kind &&
+          snippet == other.snippet;
+}
+
+/// Search response envelope for single-entry search flow.
+class EntrySearchResponse {
+  /// Whether search execution succeeded.
+  final bool ok;
+
+  /// Optional stable error code for machine branching.
+  final String? errorCode;
+
+  /// Search results (empty when no hits or scaffold mode).
+  final List<EntrySearchItem> items;
+
+  /// Human-readable response message for diagnostics.
+  final String message;
+
+  /// Effective applied search limit.
+  final int appliedLimit;
+
+  const EntrySearchResponse({
+    required this.ok,
+    this.errorCode,
+    required this.items,
+    required this.message,
+    required this.appliedLimit,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      items.hashCode ^
+      message.hashCode ^
+      appliedLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EntrySearchResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          items == other.items &&
+          message == other.message &&
+          appliedLimit == other.appliedLimit;
+}
+
+/// Undo/redo response envelope.
+class JournalResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Replayed command
+  /// (`note_update|note_set_tags|atom_set_tags|node_move|atom_status|event_times`);
+  /// `None` when there was nothing to replay.
+  final String? command;
+
+  /// Atom or workspace node id the replay wrote to.
+  final String? targetId;
+
+  /// Steps that can be undone after this call.
+  final int undoCount;
+
+  /// Steps that can be redone after this call.
+  final int redoCount;
+
+  const JournalResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.command,
+    this.targetId,
+    required this.undoCount,
+    required this.redoCount,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      command.hashCode ^
+      targetId.hashCode ^
+      undoCount.hashCode ^
+      redoCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JournalResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          command == other.command &&
+          targetId == other.targetId &&
+          undoCount == other.undoCount &&
+          redoCount == other.redoCount;
 }
 
 /// Note DTO returned by notes/tags APIs.
@@ -575,6 +1313,15 @@ class NoteItem {
   /// Normalized tags attached to the note.
   final List<String> tags;
 
+  /// Hidden from default lists; not deleted.
+  final bool isArchived;
+
+  /// Sorted first in lists.
+  final bool isPinned;
+
+  /// User favorite.
+  final bool isStarred;
+
   const NoteItem({
     required this.atomId,
     required this.content,
@@ -582,6 +1329,9 @@ class NoteItem {
     this.previewImage,
     required this.updatedAt,
     required this.tags,
+    required this.isArchived,
+    required this.isPinned,
+    required this.isStarred,
   });
 
   @override
@@ -591,7 +1341,10 @@ class NoteItem {
       previewText.hashCode ^
       previewImage.hashCode ^
       updatedAt.hashCode ^
-      tags.hashCode;
+      tags.hashCode ^
+      isArchived.hashCode ^
+      isPinned.hashCode ^
+      isStarred.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -603,7 +1356,10 @@ class NoteItem {
           previewText == other.previewText &&
           previewImage == other.previewImage &&
           updatedAt == other.updatedAt &&
-          tags == other.tags;
+          tags == other.tags &&
+          isArchived == other.isArchived &&
+          isPinned == other.isPinned &&
+          isStarred == other.isStarred;
 }
 
 /// Note create/update/get response envelope.
@@ -642,6 +1398,135 @@ class NoteResponse {
           note == other.note;
 }
 
+/// Note template DTO.
+class NoteTemplateItem {
+  /// Stable template id.
+  final String templateId;
+
+  /// Display name, unique case-insensitively.
+  final String name;
+
+  /// Markdown source with `{{placeholder}}` markers.
+  final String content;
+
+  /// Normalized tags applied to created notes.
+  final List<String> tags;
+
+  /// Workspace folder receiving created notes.
+  final String? folderNodeId;
+
+  /// Custom prompt names to ask for, in first-use order.
+  final List<String> prompts;
+
+  /// Update timestamp in epoch milliseconds.
+  final PlatformInt64 updatedAt;
+
+  const NoteTemplateItem({
+    required this.templateId,
+    required this.name,
+    required this.content,
+    required this.tags,
+    this.folderNodeId,
+    required this.prompts,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      templateId.hashCode ^
+      name.hashCode ^
+      content.hashCode ^
+      tags.hashCode ^
+      folderNodeId.hashCode ^
+      prompts.hashCode ^
+      updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteTemplateItem &&
+          runtimeType == other.runtimeType &&
+          templateId == other.templateId &&
+          name == other.name &&
+          content == other.content &&
+          tags == other.tags &&
+          folderNodeId == other.folderNodeId &&
+          prompts == other.prompts &&
+          updatedAt == other.updatedAt;
+}
+
+/// Note template save/delete response envelope.
+class NoteTemplateResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Saved template; `None` on failure and after delete.
+  final NoteTemplateItem? template;
+
+  const NoteTemplateResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.template,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ template.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteTemplateResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          template == other.template;
+}
+
+/// Note template list response envelope.
+class NoteTemplatesListResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Templates sorted by name.
+  final List<NoteTemplateItem> items;
+
+  const NoteTemplatesListResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteTemplatesListResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items;
+}
+
 /// Note list response envelope.
 class NotesListResponse {
   /// Whether operation succeeded.
@@ -687,6 +1572,42 @@ class NotesListResponse {
           appliedLimit == other.appliedLimit;
 }
 
+/// Response envelope for search session lifecycle calls.
+class SearchSessionResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Optional stable error code for machine branching.
+  final String? errorCode;
+
+  /// Session ID for subsequent `search_session_*` calls.
+  final String? sessionId;
+
+  /// Human-readable response message for diagnostics.
+  final String message;
+
+  const SearchSessionResponse({
+    required this.ok,
+    this.errorCode,
+    this.sessionId,
+    required this.message,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ sessionId.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchSessionResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          sessionId == other.sessionId &&
+          message == other.message;
+}
+
 /// Tags list response envelope.
 class TagsListResponse {
   /// Whether operation succeeded.
@@ -723,6 +1644,205 @@ class TagsListResponse {
           tags == other.tags;
 }
 
+/// Create-from-template response envelope.
+class TemplateNoteResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Created note.
+  final NoteItem? note;
+
+  /// `note_ref` node filing the note, when the template has a folder.
+  final String? nodeId;
+
+  /// UTF-16 offset of `{{cursor}}` in note content, for the editor.
+  final int? cursorOffset;
+
+  const TemplateNoteResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    this.note,
+    this.nodeId,
+    this.cursorOffset,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      note.hashCode ^
+      nodeId.hashCode ^
+      cursorOffset.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TemplateNoteResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          note == other.note &&
+          nodeId == other.nodeId &&
+          cursorOffset == other.cursorOffset;
+}
+
+/// Answer to one custom template prompt.
+class TemplatePromptAnswer {
+  /// Prompt name as listed in [`NoteTemplateItem::prompts`].
+  final String name;
+
+  /// Text substituted for the prompt.
+  final String value;
+
+  const TemplatePromptAnswer({required this.name, required this.value});
+
+  @override
+  int get hashCode => name.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TemplatePromptAnswer &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value;
+}
+
+/// One soft-deleted atom shown in the trash view.
+class TrashItem {
+  /// Stable atom ID in string form.
+  final String atomId;
+
+  /// Atom projection kind (`note|task|event`).
+  final String kind;
+
+  /// Raw markdown content.
+  final String content;
+
+  /// Derived plain-text preview.
+  final String? previewText;
+
+  /// Deletion time in epoch milliseconds.
+  final PlatformInt64 deletedAt;
+
+  const TrashItem({
+    required this.atomId,
+    required this.kind,
+    required this.content,
+    this.previewText,
+    required this.deletedAt,
+  });
+
+  @override
+  int get hashCode =>
+      atomId.hashCode ^
+      kind.hashCode ^
+      content.hashCode ^
+      previewText.hashCode ^
+      deletedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrashItem &&
+          runtimeType == other.runtimeType &&
+          atomId == other.atomId &&
+          kind == other.kind &&
+          content == other.content &&
+          previewText == other.previewText &&
+          deletedAt == other.deletedAt;
+}
+
+/// Trash list response envelope.
+class TrashListResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Trashed atoms, most recently deleted first.
+  final List<TrashItem> items;
+
+  /// Effective limit after normalization.
+  final int appliedLimit;
+
+  const TrashListResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.items,
+    required this.appliedLimit,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^
+      errorCode.hashCode ^
+      message.hashCode ^
+      items.hashCode ^
+      appliedLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrashListResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          items == other.items &&
+          appliedLimit == other.appliedLimit;
+}
+
+/// Trash purge response envelope.
+class TrashPurgeResponse {
+  /// Whether operation succeeded.
+  final bool ok;
+
+  /// Stable machine-readable error code for failure paths.
+  final String? errorCode;
+
+  /// Human-readable message for diagnostics/UI.
+  final String message;
+
+  /// Number of atoms permanently deleted.
+  final int purged;
+
+  const TrashPurgeResponse({
+    required this.ok,
+    this.errorCode,
+    required this.message,
+    required this.purged,
+  });
+
+  @override
+  int get hashCode =>
+      ok.hashCode ^ errorCode.hashCode ^ message.hashCode ^ purged.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrashPurgeResponse &&
+          runtimeType == other.runtimeType &&
+          ok == other.ok &&
+          errorCode == other.errorCode &&
+          message == other.message &&
+          purged == other.purged;
+}
+
 /// Workspace action response envelope.
 class WorkspaceActionResponse {
   /// Whether operation succeeded.
@@ -794,13 +1914,13 @@ class WorkspaceNodeItem {
   /// Stable workspace node id.
   final String nodeId;
 
-  /// Node kind label (`folder|note_ref`).
+  /// Node kind label (`folder|note_ref|smart_folder|atom_ref`).
   final String kind;
 
   /// Parent node id for non-root nodes.
   final String? parentNodeId;
 
-  /// Target note atom id for note_ref nodes.
+  /// Target atom id for note_ref and virtual atom_ref nodes.
   final String? atomId;
 
   /// User-facing display name.
@@ -65,7 +65,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 1852740980;
+  int get rustContentHash => 705686268;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -76,6 +76,31 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<AtomTagsResponse> crateApiAtomAddTag({
+    required String atomId,
+    required String tag,
+  });
+
+  Future<EntryActionResponse> crateApiAtomRecordAccess({
+    required String atomId,
+  });
+
+  Future<AtomTagsResponse> crateApiAtomRemoveTag({
+    required String atomId,
+    required String tag,
+  });
+
+  Future<EntryActionResponse> crateApiAtomSetFlag({
+    required String atomId,
+    required String flag,
+    required bool value,
+  });
+
+  Future<AtomTagsResponse> crateApiAtomSetTags({
+    required String atomId,
+    required List<String> tags,
+  });
+
   Future<EntryActionResponse> crateApiAtomUpdateStatus({
     required String atomId,
     String? status,
@@ -86,6 +111,9 @@ abstract class RustLibApi extends BaseApi {
     required PlatformInt64 endMs,
     int? limit,
     int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
   });
 
   Future<EntryActionResponse> crateApiCalendarUpdateEvent({
@@ -118,10 +146,35 @@ abstract class RustLibApi extends BaseApi {
     int? limit,
   });
 
+  Future<EntrySearchAllResponse> crateApiEntrySearchAll({
+    required String text,
+    String? kind,
+    int? limit,
+  });
+
+  Future<EntrySearchResponse> crateApiEntrySearchSemantic({
+    required String text,
+    String? kind,
+    int? limit,
+  });
+
   String crateApiInitLogging({required String level, required String logDir});
 
+  Future<JournalResponse> crateApiJournalRedo();
+
+  Future<JournalResponse> crateApiJournalStatus();
+
+  Future<JournalResponse> crateApiJournalUndo();
+
   Future<NoteResponse> crateApiNoteCreate({required String content});
 
+  Future<TemplateNoteResponse> crateApiNoteCreateFromTemplate({
+    required String templateId,
+    String? title,
+    required List<TemplatePromptAnswer> answers,
+    int? utcOffsetMinutes,
+  });
+
   Future<NoteResponse> crateApiNoteGet({required String atomId});
 
   Future<NoteResponse> crateApiNoteSetTags({
@@ -129,6 +182,20 @@ abstract class RustLibApi extends BaseApi {
     required List<String> tags,
   });
 
+  Future<NoteTemplateResponse> crateApiNoteTemplateDelete({
+    required String templateId,
+  });
+
+  Future<NoteTemplateResponse> crateApiNoteTemplateSave({
+    String? templateId,
+    required String name,
+    required String content,
+    required List<String> tags,
+    String? folderNodeId,
+  });
+
+  Future<NoteTemplatesListResponse> crateApiNoteTemplatesList();
+
   Future<NoteResponse> crateApiNoteUpdate({
     required String atomId,
     required String content,
@@ -138,38 +205,93 @@ abstract class RustLibApi extends BaseApi {
     String? tag,
     int? limit,
     int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
   });
 
   String crateApiPing();
 
+  Future<EmbeddingsRefreshResponse> crateApiSearchRefreshEmbeddings();
+
+  SearchSessionResponse crateApiSearchSessionCancel({
+    required String sessionId,
+  });
+
+  SearchSessionResponse crateApiSearchSessionClose({required String sessionId});
+
+  Future<SearchSessionResponse> crateApiSearchSessionOpen({
+    String? kind,
+    int? limit,
+  });
+
+  Future<EntrySearchResponse> crateApiSearchSessionQuery({
+    required String sessionId,
+    required String text,
+  });
+
   Future<TagsListResponse> crateApiTagsList();
 
-  Future<AtomListResponse> crateApiTasksListInbox({int? limit, int? offset});
+  Future<AtomListResponse> crateApiTasksListInbox({
+    int? limit,
+    int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
+  });
 
   Future<AtomListResponse> crateApiTasksListToday({
     required PlatformInt64 bodMs,
     required PlatformInt64 eodMs,
     int? limit,
     int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
   });
 
   Future<AtomListResponse> crateApiTasksListUpcoming({
     required PlatformInt64 eodMs,
     int? limit,
     int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
   });
 
+  Future<TrashListResponse> crateApiTrashList({int? limit, int? offset});
+
+  Future<EntryActionResponse> crateApiTrashPurge({required String atomId});
+
+  Future<TrashPurgeResponse> crateApiTrashPurgeOlderThan({required int days});
+
+  Future<EntryActionResponse> crateApiTrashRestore({required String atomId});
+
   Future<WorkspaceNodeResponse> crateApiWorkspaceCreateFolder({
     String? parentNodeId,
     required String name,
   });
 
+  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateNoteFromLink({
+    required String sourceAtomId,
+    required String targetTitle,
+  });
+
   Future<WorkspaceNodeResponse> crateApiWorkspaceCreateNoteRef({
     String? parentNodeId,
     required String atomId,
     String? displayName,
   });
 
+  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateSmartFolder({
+    String? parentNodeId,
+    required String name,
+    required String queryText,
+    String? kind,
+    String? tag,
+    required bool openOnly,
+  });
+
   Future<WorkspaceActionResponse> crateApiWorkspaceDeleteFolder({
     required String nodeId,
     required String mode,
@@ -179,6 +301,8 @@ abstract class RustLibApi extends BaseApi {
     String? parentNodeId,
   });
 
+  Future<DeletedFoldersResponse> crateApiWorkspaceListDeletedFolders();
+
   Future<WorkspaceActionResponse> crateApiWorkspaceMoveNode({
     required String nodeId,
     String? newParentId,
@@ -189,6 +313,10 @@ abstract class RustLibApi extends BaseApi {
     required String nodeId,
     required String newName,
   });
+
+  Future<WorkspaceNodeResponse> crateApiWorkspaceRestoreFolder({
+    required String nodeId,
+  });
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -199,6 +327,176 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  Future<AtomTagsResponse> crateApiAtomAddTag({
+    required String atomId,
+    required String tag,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_String(tag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_tags_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAtomAddTagConstMeta,
+        argValues: [atomId, tag],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAtomAddTagConstMeta => const TaskConstMeta(
+    debugName: 'atom_add_tag',
+    argNames: ['atomId', 'tag'],
+  );
+
+  @override
+  Future<EntryActionResponse> crateApiAtomRecordAccess({
+    required String atomId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_entry_action_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAtomRecordAccessConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAtomRecordAccessConstMeta => const TaskConstMeta(
+    debugName: 'atom_record_access',
+    argNames: ['atomId'],
+  );
+
+  @override
+  Future<AtomTagsResponse> crateApiAtomRemoveTag({
+    required String atomId,
+    required String tag,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_String(tag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_tags_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAtomRemoveTagConstMeta,
+        argValues: [atomId, tag],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAtomRemoveTagConstMeta => const TaskConstMeta(
+    debugName: 'atom_remove_tag',
+    argNames: ['atomId', 'tag'],
+  );
+
+  @override
+  Future<EntryActionResponse> crateApiAtomSetFlag({
+    required String atomId,
+    required String flag,
+    required bool value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_String(flag, serializer);
+          sse_encode_bool(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_entry_action_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAtomSetFlagConstMeta,
+        argValues: [atomId, flag, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAtomSetFlagConstMeta => const TaskConstMeta(
+    debugName: 'atom_set_flag',
+    argNames: ['atomId', 'flag', 'value'],
+  );
+
+  @override
+  Future<AtomTagsResponse> crateApiAtomSetTags({
+    required String atomId,
+    required List<String> tags,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          sse_encode_list_String(tags, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_tags_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiAtomSetTagsConstMeta,
+        argValues: [atomId, tags],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAtomSetTagsConstMeta => const TaskConstMeta(
+    debugName: 'atom_set_tags',
+    argNames: ['atomId', 'tags'],
+  );
+
   @override
   Future<EntryActionResponse> crateApiAtomUpdateStatus({
     required String atomId,
@@ -213,7 +511,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 1,
+            funcId: 6,
             port: port_,
           );
         },
@@ -239,6 +537,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required PlatformInt64 endMs,
     int? limit,
     int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -248,10 +549,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_i_64(endMs, serializer);
           sse_encode_opt_box_autoadd_u_32(limit, serializer);
           sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_bool(includeArchived, serializer);
+          sse_encode_opt_box_autoadd_bool(pinned, serializer);
+          sse_encode_opt_box_autoadd_bool(starred, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 2,
+            funcId: 7,
             port: port_,
           );
         },
@@ -260,7 +564,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: null,
         ),
         constMeta: kCrateApiCalendarListByRangeConstMeta,
-        argValues: [startMs, endMs, limit, offset],
+        argValues: [
+          startMs,
+          endMs,
+          limit,
+          offset,
+          includeArchived,
+          pinned,
+          starred,
+        ],
         apiImpl: this,
       ),
     );
@@ -269,7 +581,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiCalendarListByRangeConstMeta =>
       const TaskConstMeta(
         debugName: 'calendar_list_by_range',
-        argNames: ['startMs', 'endMs', 'limit', 'offset'],
+        argNames: [
+          'startMs',
+          'endMs',
+          'limit',
+          'offset',
+          'includeArchived',
+          'pinned',
+          'starred',
+        ],
       );
 
   @override
@@ -288,7 +608,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 8,
             port: port_,
           );
         },
@@ -316,7 +636,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(dbPath, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -341,7 +661,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -369,7 +689,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 11,
             port: port_,
           );
         },
@@ -401,7 +721,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 12,
             port: port_,
           );
         },
@@ -437,7 +757,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 13,
             port: port_,
           );
         },
@@ -473,7 +793,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 14,
             port: port_,
           );
         },
@@ -494,485 +814,1217 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  String crateApiInitLogging({required String level, required String logDir}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<EntrySearchAllResponse> crateApiEntrySearchAll({
+    required String text,
+    String? kind,
+    int? limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(level, serializer);
-          sse_encode_String(logDir, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+          sse_encode_String(text, serializer);
+          sse_encode_opt_String(kind, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_entry_search_all_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitLoggingConstMeta,
-        argValues: [level, logDir],
+        constMeta: kCrateApiEntrySearchAllConstMeta,
+        argValues: [text, kind, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitLoggingConstMeta => const TaskConstMeta(
-    debugName: 'init_logging',
-    argNames: ['level', 'logDir'],
+  TaskConstMeta get kCrateApiEntrySearchAllConstMeta => const TaskConstMeta(
+    debugName: 'entry_search_all',
+    argNames: ['text', 'kind', 'limit'],
   );
 
   @override
-  Future<NoteResponse> crateApiNoteCreate({required String content}) {
+  Future<EntrySearchResponse> crateApiEntrySearchSemantic({
+    required String text,
+    String? kind,
+    int? limit,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
+          sse_encode_String(text, serializer);
+          sse_encode_opt_String(kind, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 16,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_note_response,
+          decodeSuccessData: sse_decode_entry_search_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNoteCreateConstMeta,
-        argValues: [content],
+        constMeta: kCrateApiEntrySearchSemanticConstMeta,
+        argValues: [text, kind, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNoteCreateConstMeta =>
-      const TaskConstMeta(debugName: 'note_create', argNames: ['content']);
+  TaskConstMeta get kCrateApiEntrySearchSemanticConstMeta =>
+      const TaskConstMeta(
+        debugName: 'entry_search_semantic',
+        argNames: ['text', 'kind', 'limit'],
+      );
 
   @override
-  Future<NoteResponse> crateApiNoteGet({required String atomId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiInitLogging({required String level, required String logDir}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(atomId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
+          sse_encode_String(level, serializer);
+          sse_encode_String(logDir, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_note_response,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNoteGetConstMeta,
-        argValues: [atomId],
+        constMeta: kCrateApiInitLoggingConstMeta,
+        argValues: [level, logDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNoteGetConstMeta =>
-      const TaskConstMeta(debugName: 'note_get', argNames: ['atomId']);
+  TaskConstMeta get kCrateApiInitLoggingConstMeta => const TaskConstMeta(
+    debugName: 'init_logging',
+    argNames: ['level', 'logDir'],
+  );
 
   @override
-  Future<NoteResponse> crateApiNoteSetTags({
-    required String atomId,
-    required List<String> tags,
-  }) {
+  Future<JournalResponse> crateApiJournalRedo() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(atomId, serializer);
-          sse_encode_list_String(tags, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 18,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_note_response,
+          decodeSuccessData: sse_decode_journal_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNoteSetTagsConstMeta,
-        argValues: [atomId, tags],
+        constMeta: kCrateApiJournalRedoConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNoteSetTagsConstMeta => const TaskConstMeta(
-    debugName: 'note_set_tags',
-    argNames: ['atomId', 'tags'],
-  );
+  TaskConstMeta get kCrateApiJournalRedoConstMeta =>
+      const TaskConstMeta(debugName: 'journal_redo', argNames: []);
 
   @override
-  Future<NoteResponse> crateApiNoteUpdate({
-    required String atomId,
-    required String content,
-  }) {
+  Future<JournalResponse> crateApiJournalStatus() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(atomId, serializer);
-          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 19,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_note_response,
+          decodeSuccessData: sse_decode_journal_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNoteUpdateConstMeta,
-        argValues: [atomId, content],
+        constMeta: kCrateApiJournalStatusConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNoteUpdateConstMeta => const TaskConstMeta(
-    debugName: 'note_update',
-    argNames: ['atomId', 'content'],
-  );
+  TaskConstMeta get kCrateApiJournalStatusConstMeta =>
+      const TaskConstMeta(debugName: 'journal_status', argNames: []);
 
   @override
-  Future<NotesListResponse> crateApiNotesList({
-    String? tag,
-    int? limit,
-    int? offset,
-  }) {
+  Future<JournalResponse> crateApiJournalUndo() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(tag, serializer);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 20,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_notes_list_response,
+          decodeSuccessData: sse_decode_journal_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiNotesListConstMeta,
-        argValues: [tag, limit, offset],
+        constMeta: kCrateApiJournalUndoConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiNotesListConstMeta => const TaskConstMeta(
-    debugName: 'notes_list',
-    argNames: ['tag', 'limit', 'offset'],
-  );
+  TaskConstMeta get kCrateApiJournalUndoConstMeta =>
+      const TaskConstMeta(debugName: 'journal_undo', argNames: []);
 
   @override
-  String crateApiPing() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<NoteResponse> crateApiNoteCreate({required String content}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_note_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiPingConstMeta,
-        argValues: [],
+        constMeta: kCrateApiNoteCreateConstMeta,
+        argValues: [content],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPingConstMeta =>
-      const TaskConstMeta(debugName: 'ping', argNames: []);
+  TaskConstMeta get kCrateApiNoteCreateConstMeta =>
+      const TaskConstMeta(debugName: 'note_create', argNames: ['content']);
 
   @override
-  Future<TagsListResponse> crateApiTagsList() {
+  Future<TemplateNoteResponse> crateApiNoteCreateFromTemplate({
+    required String templateId,
+    String? title,
+    required List<TemplatePromptAnswer> answers,
+    int? utcOffsetMinutes,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(templateId, serializer);
+          sse_encode_opt_String(title, serializer);
+          sse_encode_list_template_prompt_answer(answers, serializer);
+          sse_encode_opt_box_autoadd_i_32(utcOffsetMinutes, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 22,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_tags_list_response,
+          decodeSuccessData: sse_decode_template_note_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTagsListConstMeta,
-        argValues: [],
+        constMeta: kCrateApiNoteCreateFromTemplateConstMeta,
+        argValues: [templateId, title, answers, utcOffsetMinutes],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTagsListConstMeta =>
-      const TaskConstMeta(debugName: 'tags_list', argNames: []);
+  TaskConstMeta get kCrateApiNoteCreateFromTemplateConstMeta =>
+      const TaskConstMeta(
+        debugName: 'note_create_from_template',
+        argNames: ['templateId', 'title', 'answers', 'utcOffsetMinutes'],
+      );
 
   @override
-  Future<AtomListResponse> crateApiTasksListInbox({int? limit, int? offset}) {
+  Future<NoteResponse> crateApiNoteGet({required String atomId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_String(atomId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 23,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_atom_list_response,
+          decodeSuccessData: sse_decode_note_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTasksListInboxConstMeta,
-        argValues: [limit, offset],
+        constMeta: kCrateApiNoteGetConstMeta,
+        argValues: [atomId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTasksListInboxConstMeta => const TaskConstMeta(
-    debugName: 'tasks_list_inbox',
-    argNames: ['limit', 'offset'],
-  );
+  TaskConstMeta get kCrateApiNoteGetConstMeta =>
+      const TaskConstMeta(debugName: 'note_get', argNames: ['atomId']);
 
   @override
-  Future<AtomListResponse> crateApiTasksListToday({
-    required PlatformInt64 bodMs,
-    required PlatformInt64 eodMs,
-    int? limit,
-    int? offset,
+  Future<NoteResponse> crateApiNoteSetTags({
+    required String atomId,
+    required List<String> tags,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(bodMs, serializer);
-          sse_encode_i_64(eodMs, serializer);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_String(atomId, serializer);
+          sse_encode_list_String(tags, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 24,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_atom_list_response,
+          decodeSuccessData: sse_decode_note_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTasksListTodayConstMeta,
-        argValues: [bodMs, eodMs, limit, offset],
+        constMeta: kCrateApiNoteSetTagsConstMeta,
+        argValues: [atomId, tags],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTasksListTodayConstMeta => const TaskConstMeta(
-    debugName: 'tasks_list_today',
-    argNames: ['bodMs', 'eodMs', 'limit', 'offset'],
+  TaskConstMeta get kCrateApiNoteSetTagsConstMeta => const TaskConstMeta(
+    debugName: 'note_set_tags',
+    argNames: ['atomId', 'tags'],
   );
 
   @override
-  Future<AtomListResponse> crateApiTasksListUpcoming({
-    required PlatformInt64 eodMs,
-    int? limit,
-    int? offset,
+  Future<NoteTemplateResponse> crateApiNoteTemplateDelete({
+    required String templateId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(eodMs, serializer);
-          sse_encode_opt_box_autoadd_u_32(limit, serializer);
-          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_String(templateId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 25,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_atom_list_response,
+          decodeSuccessData: sse_decode_note_template_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiTasksListUpcomingConstMeta,
-        argValues: [eodMs, limit, offset],
+        constMeta: kCrateApiNoteTemplateDeleteConstMeta,
+        argValues: [templateId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTasksListUpcomingConstMeta => const TaskConstMeta(
-    debugName: 'tasks_list_upcoming',
-    argNames: ['eodMs', 'limit', 'offset'],
+  TaskConstMeta get kCrateApiNoteTemplateDeleteConstMeta => const TaskConstMeta(
+    debugName: 'note_template_delete',
+    argNames: ['templateId'],
   );
 
   @override
-  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateFolder({
-    String? parentNodeId,
+  Future<NoteTemplateResponse> crateApiNoteTemplateSave({
+    String? templateId,
     required String name,
+    required String content,
+    required List<String> tags,
+    String? folderNodeId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(parentNodeId, serializer);
+          sse_encode_opt_String(templateId, serializer);
           sse_encode_String(name, serializer);
+          sse_encode_String(content, serializer);
+          sse_encode_list_String(tags, serializer);
+          sse_encode_opt_String(folderNodeId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 26,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeSuccessData: sse_decode_note_template_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceCreateFolderConstMeta,
-        argValues: [parentNodeId, name],
+        constMeta: kCrateApiNoteTemplateSaveConstMeta,
+        argValues: [templateId, name, content, tags, folderNodeId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceCreateFolderConstMeta =>
-      const TaskConstMeta(
-        debugName: 'workspace_create_folder',
-        argNames: ['parentNodeId', 'name'],
-      );
+  TaskConstMeta get kCrateApiNoteTemplateSaveConstMeta => const TaskConstMeta(
+    debugName: 'note_template_save',
+    argNames: ['templateId', 'name', 'content', 'tags', 'folderNodeId'],
+  );
 
   @override
-  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateNoteRef({
-    String? parentNodeId,
-    required String atomId,
-    String? displayName,
-  }) {
+  Future<NoteTemplatesListResponse> crateApiNoteTemplatesList() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(parentNodeId, serializer);
-          sse_encode_String(atomId, serializer);
-          sse_encode_opt_String(displayName, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 27,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeSuccessData: sse_decode_note_templates_list_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceCreateNoteRefConstMeta,
-        argValues: [parentNodeId, atomId, displayName],
+        constMeta: kCrateApiNoteTemplatesListConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceCreateNoteRefConstMeta =>
-      const TaskConstMeta(
-        debugName: 'workspace_create_note_ref',
-        argNames: ['parentNodeId', 'atomId', 'displayName'],
-      );
+  TaskConstMeta get kCrateApiNoteTemplatesListConstMeta =>
+      const TaskConstMeta(debugName: 'note_templates_list', argNames: []);
 
   @override
-  Future<WorkspaceActionResponse> crateApiWorkspaceDeleteFolder({
-    required String nodeId,
-    required String mode,
+  Future<NoteResponse> crateApiNoteUpdate({
+    required String atomId,
+    required String content,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(nodeId, serializer);
-          sse_encode_String(mode, serializer);
+          sse_encode_String(atomId, serializer);
+          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 28,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_action_response,
+          decodeSuccessData: sse_decode_note_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceDeleteFolderConstMeta,
-        argValues: [nodeId, mode],
+        constMeta: kCrateApiNoteUpdateConstMeta,
+        argValues: [atomId, content],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceDeleteFolderConstMeta =>
-      const TaskConstMeta(
-        debugName: 'workspace_delete_folder',
-        argNames: ['nodeId', 'mode'],
-      );
+  TaskConstMeta get kCrateApiNoteUpdateConstMeta => const TaskConstMeta(
+    debugName: 'note_update',
+    argNames: ['atomId', 'content'],
+  );
 
   @override
-  Future<WorkspaceListChildrenResponse> crateApiWorkspaceListChildren({
-    String? parentNodeId,
+  Future<NotesListResponse> crateApiNotesList({
+    String? tag,
+    int? limit,
+    int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(parentNodeId, serializer);
+          sse_encode_opt_String(tag, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_bool(includeArchived, serializer);
+          sse_encode_opt_box_autoadd_bool(pinned, serializer);
+          sse_encode_opt_box_autoadd_bool(starred, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 29,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_workspace_list_children_response,
+          decodeSuccessData: sse_decode_notes_list_response,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWorkspaceListChildrenConstMeta,
-        argValues: [parentNodeId],
+        constMeta: kCrateApiNotesListConstMeta,
+        argValues: [tag, limit, offset, includeArchived, pinned, starred],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWorkspaceListChildrenConstMeta =>
-      const TaskConstMeta(
+  TaskConstMeta get kCrateApiNotesListConstMeta => const TaskConstMeta(
+    debugName: 'notes_list',
+    argNames: [
+      'tag',
+      'limit',
+      'offset',
+      'includeArchived',
+      'pinned',
+      'starred',
+    ],
+  );
+
+  @override
+  String crateApiPing() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiPingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPingConstMeta =>
+      const TaskConstMeta(debugName: 'ping', argNames: []);
+
+  @override
+  Future<EmbeddingsRefreshResponse> crateApiSearchRefreshEmbeddings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_embeddings_refresh_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSearchRefreshEmbeddingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchRefreshEmbeddingsConstMeta =>
+      const TaskConstMeta(debugName: 'search_refresh_embeddings', argNames: []);
+
+  @override
+  SearchSessionResponse crateApiSearchSessionCancel({
+    required String sessionId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_search_session_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSearchSessionCancelConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchSessionCancelConstMeta =>
+      const TaskConstMeta(
+        debugName: 'search_session_cancel',
+        argNames: ['sessionId'],
+      );
+
+  @override
+  SearchSessionResponse crateApiSearchSessionClose({
+    required String sessionId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_search_session_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSearchSessionCloseConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchSessionCloseConstMeta => const TaskConstMeta(
+    debugName: 'search_session_close',
+    argNames: ['sessionId'],
+  );
+
+  @override
+  Future<SearchSessionResponse> crateApiSearchSessionOpen({
+    String? kind,
+    int? limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(kind, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_search_session_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSearchSessionOpenConstMeta,
+        argValues: [kind, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchSessionOpenConstMeta => const TaskConstMeta(
+    debugName: 'search_session_open',
+    argNames: ['kind', 'limit'],
+  );
+
+  @override
+  Future<EntrySearchResponse> crateApiSearchSessionQuery({
+    required String sessionId,
+    required String text,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          sse_encode_String(text, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_entry_search_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSearchSessionQueryConstMeta,
+        argValues: [sessionId, text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchSessionQueryConstMeta => const TaskConstMeta(
+    debugName: 'search_session_query',
+    argNames: ['sessionId', 'text'],
+  );
+
+  @override
+  Future<TagsListResponse> crateApiTagsList() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tags_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTagsListConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTagsListConstMeta =>
+      const TaskConstMeta(debugName: 'tags_list', argNames: []);
+
+  @override
+  Future<AtomListResponse> crateApiTasksListInbox({
+    int? limit,
+    int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_bool(includeArchived, serializer);
+          sse_encode_opt_box_autoadd_bool(pinned, serializer);
+          sse_encode_opt_box_autoadd_bool(starred, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListInboxConstMeta,
+        argValues: [limit, offset, includeArchived, pinned, starred],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListInboxConstMeta => const TaskConstMeta(
+    debugName: 'tasks_list_inbox',
+    argNames: ['limit', 'offset', 'includeArchived', 'pinned', 'starred'],
+  );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListToday({
+    required PlatformInt64 bodMs,
+    required PlatformInt64 eodMs,
+    int? limit,
+    int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(bodMs, serializer);
+          sse_encode_i_64(eodMs, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_bool(includeArchived, serializer);
+          sse_encode_opt_box_autoadd_bool(pinned, serializer);
+          sse_encode_opt_box_autoadd_bool(starred, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListTodayConstMeta,
+        argValues: [
+          bodMs,
+          eodMs,
+          limit,
+          offset,
+          includeArchived,
+          pinned,
+          starred,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListTodayConstMeta => const TaskConstMeta(
+    debugName: 'tasks_list_today',
+    argNames: [
+      'bodMs',
+      'eodMs',
+      'limit',
+      'offset',
+      'includeArchived',
+      'pinned',
+      'starred',
+    ],
+  );
+
+  @override
+  Future<AtomListResponse> crateApiTasksListUpcoming({
+    required PlatformInt64 eodMs,
+    int? limit,
+    int? offset,
+    required bool includeArchived,
+    bool? pinned,
+    bool? starred,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(eodMs, serializer);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          sse_encode_bool(includeArchived, serializer);
+          sse_encode_opt_box_autoadd_bool(pinned, serializer);
+          sse_encode_opt_box_autoadd_bool(starred, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_atom_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTasksListUpcomingConstMeta,
+        argValues: [eodMs, limit, offset, includeArchived, pinned, starred],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTasksListUpcomingConstMeta => const TaskConstMeta(
+    debugName: 'tasks_list_upcoming',
+    argNames: [
+      'eodMs',
+      'limit',
+      'offset',
+      'includeArchived',
+      'pinned',
+      'starred',
+    ],
+  );
+
+  @override
+  Future<TrashListResponse> crateApiTrashList({int? limit, int? offset}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(limit, serializer);
+          sse_encode_opt_box_autoadd_u_32(offset, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_trash_list_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrashListConstMeta,
+        argValues: [limit, offset],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrashListConstMeta => const TaskConstMeta(
+    debugName: 'trash_list',
+    argNames: ['limit', 'offset'],
+  );
+
+  @override
+  Future<EntryActionResponse> crateApiTrashPurge({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_entry_action_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrashPurgeConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrashPurgeConstMeta =>
+      const TaskConstMeta(debugName: 'trash_purge', argNames: ['atomId']);
+
+  @override
+  Future<TrashPurgeResponse> crateApiTrashPurgeOlderThan({required int days}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(days, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_trash_purge_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrashPurgeOlderThanConstMeta,
+        argValues: [days],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrashPurgeOlderThanConstMeta =>
+      const TaskConstMeta(
+        debugName: 'trash_purge_older_than',
+        argNames: ['days'],
+      );
+
+  @override
+  Future<EntryActionResponse> crateApiTrashRestore({required String atomId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(atomId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_entry_action_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrashRestoreConstMeta,
+        argValues: [atomId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrashRestoreConstMeta =>
+      const TaskConstMeta(debugName: 'trash_restore', argNames: ['atomId']);
+
+  @override
+  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateFolder({
+    String? parentNodeId,
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentNodeId, serializer);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceCreateFolderConstMeta,
+        argValues: [parentNodeId, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceCreateFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_create_folder',
+        argNames: ['parentNodeId', 'name'],
+      );
+
+  @override
+  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateNoteFromLink({
+    required String sourceAtomId,
+    required String targetTitle,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sourceAtomId, serializer);
+          sse_encode_String(targetTitle, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceCreateNoteFromLinkConstMeta,
+        argValues: [sourceAtomId, targetTitle],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceCreateNoteFromLinkConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_create_note_from_link',
+        argNames: ['sourceAtomId', 'targetTitle'],
+      );
+
+  @override
+  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateNoteRef({
+    String? parentNodeId,
+    required String atomId,
+    String? displayName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentNodeId, serializer);
+          sse_encode_String(atomId, serializer);
+          sse_encode_opt_String(displayName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceCreateNoteRefConstMeta,
+        argValues: [parentNodeId, atomId, displayName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceCreateNoteRefConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_create_note_ref',
+        argNames: ['parentNodeId', 'atomId', 'displayName'],
+      );
+
+  @override
+  Future<WorkspaceNodeResponse> crateApiWorkspaceCreateSmartFolder({
+    String? parentNodeId,
+    required String name,
+    required String queryText,
+    String? kind,
+    String? tag,
+    required bool openOnly,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentNodeId, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_String(queryText, serializer);
+          sse_encode_opt_String(kind, serializer);
+          sse_encode_opt_String(tag, serializer);
+          sse_encode_bool(openOnly, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceCreateSmartFolderConstMeta,
+        argValues: [parentNodeId, name, queryText, kind, tag, openOnly],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceCreateSmartFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_create_smart_folder',
+        argNames: [
+          'parentNodeId',
+          'name',
+          'queryText',
+          'kind',
+          'tag',
+          'openOnly',
+        ],
+      );
+
+  @override
+  Future<WorkspaceActionResponse> crateApiWorkspaceDeleteFolder({
+    required String nodeId,
+    required String mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(nodeId, serializer);
+          sse_encode_String(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_action_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceDeleteFolderConstMeta,
+        argValues: [nodeId, mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceDeleteFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_delete_folder',
+        argNames: ['nodeId', 'mode'],
+      );
+
+  @override
+  Future<WorkspaceListChildrenResponse> crateApiWorkspaceListChildren({
+    String? parentNodeId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(parentNodeId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_list_children_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceListChildrenConstMeta,
+        argValues: [parentNodeId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceListChildrenConstMeta =>
+      const TaskConstMeta(
         debugName: 'workspace_list_children',
         argNames: ['parentNodeId'],
       );
 
+  @override
+  Future<DeletedFoldersResponse> crateApiWorkspaceListDeletedFolders() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_deleted_folders_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceListDeletedFoldersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceListDeletedFoldersConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_list_deleted_folders',
+        argNames: [],
+      );
+
   @override
   Future<WorkspaceActionResponse> crateApiWorkspaceMoveNode({
     required String nodeId,
@@ -989,7 +2041,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 51,
             port: port_,
           );
         },
@@ -1023,7 +2075,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 52,
             port: port_,
           );
         },
@@ -1044,6 +2096,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['nodeId', 'newName'],
       );
 
+  @override
+  Future<WorkspaceNodeResponse> crateApiWorkspaceRestoreFolder({
+    required String nodeId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(nodeId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_workspace_node_response,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkspaceRestoreFolderConstMeta,
+        argValues: [nodeId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkspaceRestoreFolderConstMeta =>
+      const TaskConstMeta(
+        debugName: 'workspace_restore_folder',
+        argNames: ['nodeId'],
+      );
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1054,8 +2139,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   AtomListItem dco_decode_atom_list_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
     return AtomListItem(
       atomId: dco_decode_String(arr[0]),
       kind: dco_decode_String(arr[1]),
@@ -1067,6 +2152,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       endAt: dco_decode_opt_box_autoadd_i_64(arr[7]),
       taskStatus: dco_decode_opt_String(arr[8]),
       updatedAt: dco_decode_i_64(arr[9]),
+      isArchived: dco_decode_bool(arr[10]),
+      isPinned: dco_decode_bool(arr[11]),
+      isStarred: dco_decode_bool(arr[12]),
     );
   }
 
@@ -1085,12 +2173,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  AtomTagsResponse dco_decode_atom_tags_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AtomTagsResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      atomId: dco_decode_opt_String(arr[3]),
+      tags: dco_decode_list_String(arr[4]),
+      inlineTags: dco_decode_list_String(arr[5]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as bool;
   }
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1103,6 +2219,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_note_item(raw);
   }
 
+  @protected
+  NoteTemplateItem dco_decode_box_autoadd_note_template_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_note_template_item(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1110,9 +2232,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  WorkspaceNodeItem dco_decode_box_autoadd_workspace_node_item(dynamic raw) {
+  WorkspaceNodeItem dco_decode_box_autoadd_workspace_node_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_workspace_node_item(raw);
+  }
+
+  @protected
+  DeletedFolderItem dco_decode_deleted_folder_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DeletedFolderItem(
+      nodeId: dco_decode_String(arr[0]),
+      displayName: dco_decode_String(arr[1]),
+      mode: dco_decode_String(arr[2]),
+      deletedAt: dco_decode_i_64(arr[3]),
+      nodeCount: dco_decode_u_32(arr[4]),
+      noteCount: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  DeletedFoldersResponse dco_decode_deleted_folders_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DeletedFoldersResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_deleted_folder_item(arr[3]),
+    );
+  }
+
+  @protected
+  EmbeddingsRefreshResponse dco_decode_embeddings_refresh_response(
+    dynamic raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_workspace_node_item(raw);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return EmbeddingsRefreshResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      refreshed: dco_decode_u_32(arr[3]),
+    );
   }
 
   @protected
@@ -1128,6 +2296,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  EntrySearchAllItem dco_decode_entry_search_all_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return EntrySearchAllItem(
+      kind: dco_decode_String(arr[0]),
+      atomId: dco_decode_opt_String(arr[1]),
+      nodeId: dco_decode_opt_String(arr[2]),
+      parentNodeId: dco_decode_opt_String(arr[3]),
+      label: dco_decode_String(arr[4]),
+      atomCount: dco_decode_opt_box_autoadd_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  EntrySearchAllResponse dco_decode_entry_search_all_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return EntrySearchAllResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      items: dco_decode_list_entry_search_all_item(arr[2]),
+      message: dco_decode_String(arr[3]),
+      appliedLimit: dco_decode_u_32(arr[4]),
+    );
+  }
+
   @protected
   EntrySearchItem dco_decode_entry_search_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1156,12 +2355,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  JournalResponse dco_decode_journal_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return JournalResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      command: dco_decode_opt_String(arr[3]),
+      targetId: dco_decode_opt_String(arr[4]),
+      undoCount: dco_decode_u_32(arr[5]),
+      redoCount: dco_decode_u_32(arr[6]),
+    );
+  }
+
   @protected
   List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1174,6 +2396,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_atom_list_item).toList();
   }
 
+  @protected
+  List<DeletedFolderItem> dco_decode_list_deleted_folder_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_deleted_folder_item).toList();
+  }
+
+  @protected
+  List<EntrySearchAllItem> dco_decode_list_entry_search_all_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_entry_search_all_item).toList(
+    );
+  }
+
   @protected
   List<EntrySearchItem> dco_decode_list_entry_search_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1186,12 +2421,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_note_item).toList();
   }
 
+  @protected
+  List<NoteTemplateItem> dco_decode_list_note_template_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_note_template_item).toList();
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as Uint8List;
   }
 
+  @protected
+  List<TemplatePromptAnswer> dco_decode_list_template_prompt_answer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_template_prompt_answer).toList(
+    );
+  }
+
+  @protected
+  List<TrashItem> dco_decode_list_trash_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_trash_item).toList();
+  }
+
   @protected
   List<WorkspaceNodeItem> dco_decode_list_workspace_node_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1202,8 +2458,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   NoteItem dco_decode_note_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
     return NoteItem(
       atomId: dco_decode_String(arr[0]),
       content: dco_decode_String(arr[1]),
@@ -1211,6 +2467,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       previewImage: dco_decode_opt_String(arr[3]),
       updatedAt: dco_decode_i_64(arr[4]),
       tags: dco_decode_list_String(arr[5]),
+      isArchived: dco_decode_bool(arr[6]),
+      isPinned: dco_decode_bool(arr[7]),
+      isStarred: dco_decode_bool(arr[8]),
     );
   }
 
@@ -1228,6 +2487,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  NoteTemplateItem dco_decode_note_template_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return NoteTemplateItem(
+      templateId: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      tags: dco_decode_list_String(arr[3]),
+      folderNodeId: dco_decode_opt_String(arr[4]),
+      prompts: dco_decode_list_String(arr[5]),
+      updatedAt: dco_decode_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  NoteTemplateResponse dco_decode_note_template_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NoteTemplateResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      template: dco_decode_opt_box_autoadd_note_template_item(arr[3]),
+    );
+  }
+
+  @protected
+  NoteTemplatesListResponse dco_decode_note_templates_list_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return NoteTemplatesListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_note_template_item(arr[3]),
+    );
+  }
+
   @protected
   NotesListResponse dco_decode_notes_list_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1249,6 +2555,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_String(raw);
   }
 
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1261,6 +2579,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_note_item(raw);
   }
 
+  @protected
+  NoteTemplateItem? dco_decode_opt_box_autoadd_note_template_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_note_template_item(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1275,6 +2599,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_workspace_node_item(raw);
   }
 
+  @protected
+  SearchSessionResponse dco_decode_search_session_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SearchSessionResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      sessionId: dco_decode_opt_String(arr[2]),
+      message: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   TagsListResponse dco_decode_tags_list_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1289,6 +2627,78 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TemplateNoteResponse dco_decode_template_note_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TemplateNoteResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      note: dco_decode_opt_box_autoadd_note_item(arr[3]),
+      nodeId: dco_decode_opt_String(arr[4]),
+      cursorOffset: dco_decode_opt_box_autoadd_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  TemplatePromptAnswer dco_decode_template_prompt_answer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TemplatePromptAnswer(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  TrashItem dco_decode_trash_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TrashItem(
+      atomId: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      previewText: dco_decode_opt_String(arr[3]),
+      deletedAt: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  TrashListResponse dco_decode_trash_list_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TrashListResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      items: dco_decode_list_trash_item(arr[3]),
+      appliedLimit: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  TrashPurgeResponse dco_decode_trash_purge_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TrashPurgeResponse(
+      ok: dco_decode_bool(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      purged: dco_decode_u_32(arr[3]),
+    );
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1386,6 +2796,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_endAt = sse_decode_opt_box_autoadd_i_64(deserializer);
     var var_taskStatus = sse_decode_opt_String(deserializer);
     var var_updatedAt = sse_decode_i_64(deserializer);
+    var var_isArchived = sse_decode_bool(deserializer);
+    var var_isPinned = sse_decode_bool(deserializer);
+    var var_isStarred = sse_decode_bool(deserializer);
     return AtomListItem(
       atomId: var_atomId,
       kind: var_kind,
@@ -1397,6 +2810,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       endAt: var_endAt,
       taskStatus: var_taskStatus,
       updatedAt: var_updatedAt,
+      isArchived: var_isArchived,
+      isPinned: var_isPinned,
+      isStarred: var_isStarred,
     );
   }
 
@@ -1417,12 +2833,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  AtomTagsResponse sse_decode_atom_tags_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_inlineTags = sse_decode_list_String(deserializer);
+    return AtomTagsResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      atomId: var_atomId,
+      tags: var_tags,
+      inlineTags: var_inlineTags,
+    );
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1430,37 +2877,140 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  NoteItem sse_decode_box_autoadd_note_item(SseDeserializer deserializer) {
+  NoteItem sse_decode_box_autoadd_note_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_note_item(deserializer));
+  }
+
+  @protected
+  NoteTemplateItem sse_decode_box_autoadd_note_template_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_note_template_item(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  WorkspaceNodeItem sse_decode_box_autoadd_workspace_node_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_workspace_node_item(deserializer));
+  }
+
+  @protected
+  DeletedFolderItem sse_decode_deleted_folder_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_nodeId = sse_decode_String(deserializer);
+    var var_displayName = sse_decode_String(deserializer);
+    var var_mode = sse_decode_String(deserializer);
+    var var_deletedAt = sse_decode_i_64(deserializer);
+    var var_nodeCount = sse_decode_u_32(deserializer);
+    var var_noteCount = sse_decode_u_32(deserializer);
+    return DeletedFolderItem(
+      nodeId: var_nodeId,
+      displayName: var_displayName,
+      mode: var_mode,
+      deletedAt: var_deletedAt,
+      nodeCount: var_nodeCount,
+      noteCount: var_noteCount,
+    );
+  }
+
+  @protected
+  DeletedFoldersResponse sse_decode_deleted_folders_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_deleted_folder_item(deserializer);
+    return DeletedFoldersResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+    );
+  }
+
+  @protected
+  EmbeddingsRefreshResponse sse_decode_embeddings_refresh_response(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_note_item(deserializer));
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_refreshed = sse_decode_u_32(deserializer);
+    return EmbeddingsRefreshResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      refreshed: var_refreshed,
+    );
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  EntryActionResponse sse_decode_entry_action_response(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    var var_ok = sse_decode_bool(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return EntryActionResponse(
+      ok: var_ok,
+      atomId: var_atomId,
+      message: var_message,
+    );
   }
 
   @protected
-  WorkspaceNodeItem sse_decode_box_autoadd_workspace_node_item(
+  EntrySearchAllItem sse_decode_entry_search_all_item(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_workspace_node_item(deserializer));
+    var var_kind = sse_decode_String(deserializer);
+    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_nodeId = sse_decode_opt_String(deserializer);
+    var var_parentNodeId = sse_decode_opt_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_atomCount = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return EntrySearchAllItem(
+      kind: var_kind,
+      atomId: var_atomId,
+      nodeId: var_nodeId,
+      parentNodeId: var_parentNodeId,
+      label: var_label,
+      atomCount: var_atomCount,
+    );
   }
 
   @protected
-  EntryActionResponse sse_decode_entry_action_response(
+  EntrySearchAllResponse sse_decode_entry_search_all_response(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_ok = sse_decode_bool(deserializer);
-    var var_atomId = sse_decode_opt_String(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_items = sse_decode_list_entry_search_all_item(deserializer);
     var var_message = sse_decode_String(deserializer);
-    return EntryActionResponse(
+    var var_appliedLimit = sse_decode_u_32(deserializer);
+    return EntrySearchAllResponse(
       ok: var_ok,
-      atomId: var_atomId,
+      errorCode: var_errorCode,
+      items: var_items,
       message: var_message,
+      appliedLimit: var_appliedLimit,
     );
   }
 
@@ -1496,12 +3046,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  JournalResponse sse_decode_journal_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_command = sse_decode_opt_String(deserializer);
+    var var_targetId = sse_decode_opt_String(deserializer);
+    var var_undoCount = sse_decode_u_32(deserializer);
+    var var_redoCount = sse_decode_u_32(deserializer);
+    return JournalResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      command: var_command,
+      targetId: var_targetId,
+      undoCount: var_undoCount,
+      redoCount: var_redoCount,
+    );
+  }
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1528,6 +3105,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<DeletedFolderItem> sse_decode_list_deleted_folder_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DeletedFolderItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_deleted_folder_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EntrySearchAllItem> sse_decode_list_entry_search_all_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EntrySearchAllItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_entry_search_all_item(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<EntrySearchItem> sse_decode_list_entry_search_item(
     SseDeserializer deserializer,
@@ -1554,6 +3159,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<NoteTemplateItem> sse_decode_list_note_template_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NoteTemplateItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_note_template_item(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1561,6 +3180,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8List(len_);
   }
 
+  @protected
+  List<TemplatePromptAnswer> sse_decode_list_template_prompt_answer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TemplatePromptAnswer>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_template_prompt_answer(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrashItem> sse_decode_list_trash_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrashItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_trash_item(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<WorkspaceNodeItem> sse_decode_list_workspace_node_item(
     SseDeserializer deserializer,
@@ -1584,6 +3229,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_previewImage = sse_decode_opt_String(deserializer);
     var var_updatedAt = sse_decode_i_64(deserializer);
     var var_tags = sse_decode_list_String(deserializer);
+    var var_isArchived = sse_decode_bool(deserializer);
+    var var_isPinned = sse_decode_bool(deserializer);
+    var var_isStarred = sse_decode_bool(deserializer);
     return NoteItem(
       atomId: var_atomId,
       content: var_content,
@@ -1591,6 +3239,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       previewImage: var_previewImage,
       updatedAt: var_updatedAt,
       tags: var_tags,
+      isArchived: var_isArchived,
+      isPinned: var_isPinned,
+      isStarred: var_isStarred,
     );
   }
 
@@ -1609,6 +3260,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  NoteTemplateItem sse_decode_note_template_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_templateId = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_folderNodeId = sse_decode_opt_String(deserializer);
+    var var_prompts = sse_decode_list_String(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    return NoteTemplateItem(
+      templateId: var_templateId,
+      name: var_name,
+      content: var_content,
+      tags: var_tags,
+      folderNodeId: var_folderNodeId,
+      prompts: var_prompts,
+      updatedAt: var_updatedAt,
+    );
+  }
+
+  @protected
+  NoteTemplateResponse sse_decode_note_template_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_template =
+        sse_decode_opt_box_autoadd_note_template_item(deserializer);
+    return NoteTemplateResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      template: var_template,
+    );
+  }
+
+  @protected
+  NoteTemplatesListResponse sse_decode_note_templates_list_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_note_template_item(deserializer);
+    return NoteTemplatesListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+    );
+  }
+
   @protected
   NotesListResponse sse_decode_notes_list_response(
     SseDeserializer deserializer,
@@ -1639,6 +3346,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1661,6 +3390,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  NoteTemplateItem? sse_decode_opt_box_autoadd_note_template_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_note_template_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1686,17 +3428,118 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  TagsListResponse sse_decode_tags_list_response(SseDeserializer deserializer) {
+  SearchSessionResponse sse_decode_search_session_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_sessionId = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return SearchSessionResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      sessionId: var_sessionId,
+      message: var_message,
+    );
+  }
+
+  @protected
+  TagsListResponse sse_decode_tags_list_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    return TagsListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      tags: var_tags,
+    );
+  }
+
+  @protected
+  TemplateNoteResponse sse_decode_template_note_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_note = sse_decode_opt_box_autoadd_note_item(deserializer);
+    var var_nodeId = sse_decode_opt_String(deserializer);
+    var var_cursorOffset = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return TemplateNoteResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      note: var_note,
+      nodeId: var_nodeId,
+      cursorOffset: var_cursorOffset,
+    );
+  }
+
+  @protected
+  TemplatePromptAnswer sse_decode_template_prompt_answer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return TemplatePromptAnswer(name: var_name, value: var_value);
+  }
+
+  @protected
+  TrashItem sse_decode_trash_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_atomId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_previewText = sse_decode_opt_String(deserializer);
+    var var_deletedAt = sse_decode_i_64(deserializer);
+    return TrashItem(
+      atomId: var_atomId,
+      kind: var_kind,
+      content: var_content,
+      previewText: var_previewText,
+      deletedAt: var_deletedAt,
+    );
+  }
+
+  @protected
+  TrashListResponse sse_decode_trash_list_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ok = sse_decode_bool(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_trash_item(deserializer);
+    var var_appliedLimit = sse_decode_u_32(deserializer);
+    return TrashListResponse(
+      ok: var_ok,
+      errorCode: var_errorCode,
+      message: var_message,
+      items: var_items,
+      appliedLimit: var_appliedLimit,
+    );
+  }
+
+  @protected
+  TrashPurgeResponse sse_decode_trash_purge_response(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_ok = sse_decode_bool(deserializer);
     var var_errorCode = sse_decode_opt_String(deserializer);
     var var_message = sse_decode_String(deserializer);
-    var var_tags = sse_decode_list_String(deserializer);
-    return TagsListResponse(
+    var var_purged = sse_decode_u_32(deserializer);
+    return TrashPurgeResponse(
       ok: var_ok,
       errorCode: var_errorCode,
       message: var_message,
-      tags: var_tags,
+      purged: var_purged,
     );
   }
 
@@ -1787,12 +3630,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1812,6 +3649,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_i_64(self.endAt, serializer);
     sse_encode_opt_String(self.taskStatus, serializer);
     sse_encode_i_64(self.updatedAt, serializer);
+    sse_encode_bool(self.isArchived, serializer);
+    sse_encode_bool(self.isPinned, serializer);
+    sse_encode_bool(self.isStarred, serializer);
   }
 
   @protected
@@ -1827,12 +3667,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_32(self.appliedLimit, serializer);
   }
 
+  @protected
+  void sse_encode_atom_tags_response(
+    AtomTagsResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_list_String(self.inlineTags, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putUint8(self ? 1 : 0);
   }
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_i_64(
     PlatformInt64 self,
@@ -1851,6 +3717,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_note_item(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_note_template_item(
+    NoteTemplateItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_note_template_item(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1866,6 +3741,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_workspace_node_item(self, serializer);
   }
 
+  @protected
+  void sse_encode_deleted_folder_item(
+    DeletedFolderItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.nodeId, serializer);
+    sse_encode_String(self.displayName, serializer);
+    sse_encode_String(self.mode, serializer);
+    sse_encode_i_64(self.deletedAt, serializer);
+    sse_encode_u_32(self.nodeCount, serializer);
+    sse_encode_u_32(self.noteCount, serializer);
+  }
+
+  @protected
+  void sse_encode_deleted_folders_response(
+    DeletedFoldersResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_deleted_folder_item(self.items, serializer);
+  }
+
+  @protected
+  void sse_encode_embeddings_refresh_response(
+    EmbeddingsRefreshResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.refreshed, serializer);
+  }
+
   @protected
   void sse_encode_entry_action_response(
     EntryActionResponse self,
@@ -1877,6 +3790,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_entry_search_all_item(
+    EntrySearchAllItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_opt_String(self.atomId, serializer);
+    sse_encode_opt_String(self.nodeId, serializer);
+    sse_encode_opt_String(self.parentNodeId, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.atomCount, serializer);
+  }
+
+  @protected
+  void sse_encode_entry_search_all_response(
+    EntrySearchAllResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_list_entry_search_all_item(self.items, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.appliedLimit, serializer);
+  }
+
   @protected
   void sse_encode_entry_search_item(
     EntrySearchItem self,
@@ -1901,12 +3841,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_32(self.appliedLimit, serializer);
   }
 
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_journal_response(
+    JournalResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.command, serializer);
+    sse_encode_opt_String(self.targetId, serializer);
+    sse_encode_u_32(self.undoCount, serializer);
+    sse_encode_u_32(self.redoCount, serializer);
+  }
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1928,6 +3889,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_deleted_folder_item(
+    List<DeletedFolderItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_deleted_folder_item(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_entry_search_all_item(
+    List<EntrySearchAllItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_entry_search_all_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_entry_search_item(
     List<EntrySearchItem> self,
@@ -1952,6 +3937,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_note_template_item(
+    List<NoteTemplateItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_note_template_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -1962,6 +3959,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_template_prompt_answer(
+    List<TemplatePromptAnswer> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_template_prompt_answer(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_trash_item(
+    List<TrashItem> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_trash_item(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_workspace_node_item(
     List<WorkspaceNodeItem> self,
@@ -1983,6 +4004,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.previewImage, serializer);
     sse_encode_i_64(self.updatedAt, serializer);
     sse_encode_list_String(self.tags, serializer);
+    sse_encode_bool(self.isArchived, serializer);
+    sse_encode_bool(self.isPinned, serializer);
+    sse_encode_bool(self.isStarred, serializer);
   }
 
   @protected
@@ -1994,6 +4018,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_note_item(self.note, serializer);
   }
 
+  @protected
+  void sse_encode_note_template_item(
+    NoteTemplateItem self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.templateId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_String(self.folderNodeId, serializer);
+    sse_encode_list_String(self.prompts, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_note_template_response(
+    NoteTemplateResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_note_template_item(self.template, serializer);
+  }
+
+  @protected
+  void sse_encode_note_templates_list_response(
+    NoteTemplatesListResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_note_template_item(self.items, serializer);
+  }
+
   @protected
   void sse_encode_notes_list_response(
     NotesListResponse self,
@@ -2017,6 +4080,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_i_64(
     PlatformInt64? self,
@@ -2043,6 +4126,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_note_template_item(
+    NoteTemplateItem? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_note_template_item(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2066,6 +4162,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_search_session_response(
+    SearchSessionResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_opt_String(self.sessionId, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
   @protected
   void sse_encode_tags_list_response(
     TagsListResponse self,
@@ -2078,6 +4186,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_String(self.tags, serializer);
   }
 
+  @protected
+  void sse_encode_template_note_response(
+    TemplateNoteResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_note_item(self.note, serializer);
+    sse_encode_opt_String(self.nodeId, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.cursorOffset, serializer);
+  }
+
+  @protected
+  void sse_encode_template_prompt_answer(
+    TemplatePromptAnswer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_trash_item(TrashItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.atomId, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.previewText, serializer);
+    sse_encode_i_64(self.deletedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_trash_list_response(
+    TrashListResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_trash_item(self.items, serializer);
+    sse_encode_u_32(self.appliedLimit, serializer);
+  }
+
+  @protected
+  void sse_encode_trash_purge_response(
+    TrashPurgeResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.ok, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_u_32(self.purged, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2143,10 +4310,4 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.message, serializer);
     sse_encode_opt_box_autoadd_workspace_node_item(self.node, serializer);
   }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
 }
@@ -28,48 +28,98 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AtomListResponse dco_decode_atom_list_response(dynamic raw);
 
+  @protected
+  AtomTagsResponse dco_decode_atom_tags_response(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
   @protected
   NoteItem dco_decode_box_autoadd_note_item(dynamic raw);
 
+  @protected
+  NoteTemplateItem dco_decode_box_autoadd_note_template_item(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
   WorkspaceNodeItem dco_decode_box_autoadd_workspace_node_item(dynamic raw);
 
+  @protected
+  DeletedFolderItem dco_decode_deleted_folder_item(dynamic raw);
+
+  @protected
+  DeletedFoldersResponse dco_decode_deleted_folders_response(dynamic raw);
+
+  @protected
+  EmbeddingsRefreshResponse dco_decode_embeddings_refresh_response(dynamic raw);
+
   @protected
   EntryActionResponse dco_decode_entry_action_response(dynamic raw);
 
+  @protected
+  EntrySearchAllItem dco_decode_entry_search_all_item(dynamic raw);
+
+  @protected
+  EntrySearchAllResponse dco_decode_entry_search_all_response(dynamic raw);
+
   @protected
   EntrySearchItem dco_decode_entry_search_item(dynamic raw);
 
   @protected
   EntrySearchResponse dco_decode_entry_search_response(dynamic raw);
 
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  JournalResponse dco_decode_journal_response(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
   @protected
   List<AtomListItem> dco_decode_list_atom_list_item(dynamic raw);
 
+  @protected
+  List<DeletedFolderItem> dco_decode_list_deleted_folder_item(dynamic raw);
+
+  @protected
+  List<EntrySearchAllItem> dco_decode_list_entry_search_all_item(dynamic raw);
+
   @protected
   List<EntrySearchItem> dco_decode_list_entry_search_item(dynamic raw);
 
   @protected
   List<NoteItem> dco_decode_list_note_item(dynamic raw);
 
+  @protected
+  List<NoteTemplateItem> dco_decode_list_note_template_item(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<TemplatePromptAnswer> dco_decode_list_template_prompt_answer(
+    dynamic raw,
+  );
+
+  @protected
+  List<TrashItem> dco_decode_list_trash_item(dynamic raw);
+
   @protected
   List<WorkspaceNodeItem> dco_decode_list_workspace_node_item(dynamic raw);
 
@@ -79,18 +129,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   NoteResponse dco_decode_note_response(dynamic raw);
 
+  @protected
+  NoteTemplateItem dco_decode_note_template_item(dynamic raw);
+
+  @protected
+  NoteTemplateResponse dco_decode_note_template_response(dynamic raw);
+
+  @protected
+  NoteTemplatesListResponse dco_decode_note_templates_list_response(
+    dynamic raw,
+  );
+
   @protected
   NotesListResponse dco_decode_notes_list_response(dynamic raw);
 
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
   @protected
   NoteItem? dco_decode_opt_box_autoadd_note_item(dynamic raw);
 
+  @protected
+  NoteTemplateItem? dco_decode_opt_box_autoadd_note_template_item(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
@@ -99,9 +169,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  SearchSessionResponse dco_decode_search_session_response(dynamic raw);
+
   @protected
   TagsListResponse dco_decode_tags_list_response(dynamic raw);
 
+  @protected
+  TemplateNoteResponse dco_decode_template_note_response(dynamic raw);
+
+  @protected
+  TemplatePromptAnswer dco_decode_template_prompt_answer(dynamic raw);
+
+  @protected
+  TrashItem dco_decode_trash_item(dynamic raw);
+
+  @protected
+  TrashListResponse dco_decode_trash_list_response(dynamic raw);
+
+  @protected
+  TrashPurgeResponse dco_decode_trash_purge_response(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -134,15 +222,29 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AtomListResponse sse_decode_atom_list_response(SseDeserializer deserializer);
 
+  @protected
+  AtomTagsResponse sse_decode_atom_tags_response(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
   @protected
   NoteItem sse_decode_box_autoadd_note_item(SseDeserializer deserializer);
 
+  @protected
+  NoteTemplateItem sse_decode_box_autoadd_note_template_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -151,11 +253,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  DeletedFolderItem sse_decode_deleted_folder_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DeletedFoldersResponse sse_decode_deleted_folders_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EmbeddingsRefreshResponse sse_decode_embeddings_refresh_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   EntryActionResponse sse_decode_entry_action_response(
     SseDeserializer deserializer,
   );
 
+  @protected
+  EntrySearchAllItem sse_decode_entry_search_all_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EntrySearchAllResponse sse_decode_entry_search_all_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   EntrySearchItem sse_decode_entry_search_item(SseDeserializer deserializer);
 
@@ -164,9 +291,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  JournalResponse sse_decode_journal_response(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
@@ -175,6 +308,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<DeletedFolderItem> sse_decode_list_deleted_folder_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EntrySearchAllItem> sse_decode_list_entry_search_all_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<EntrySearchItem> sse_decode_list_entry_search_item(
     SseDeserializer deserializer,
@@ -183,9 +326,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<NoteItem> sse_decode_list_note_item(SseDeserializer deserializer);
 
+  @protected
+  List<NoteTemplateItem> sse_decode_list_note_template_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<TemplatePromptAnswer> sse_decode_list_template_prompt_answer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TrashItem> sse_decode_list_trash_item(SseDeserializer deserializer);
+
   @protected
   List<WorkspaceNodeItem> sse_decode_list_workspace_node_item(
     SseDeserializer deserializer,
@@ -197,6 +353,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   NoteResponse sse_decode_note_response(SseDeserializer deserializer);
 
+  @protected
+  NoteTemplateItem sse_decode_note_template_item(SseDeserializer deserializer);
+
+  @protected
+  NoteTemplateResponse sse_decode_note_template_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoteTemplatesListResponse sse_decode_note_templates_list_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   NotesListResponse sse_decode_notes_list_response(
     SseDeserializer deserializer,
@@ -205,12 +374,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
   @protected
   NoteItem? sse_decode_opt_box_autoadd_note_item(SseDeserializer deserializer);
 
+  @protected
+  NoteTemplateItem? sse_decode_opt_box_autoadd_note_template_item(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -219,9 +399,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  SearchSessionResponse sse_decode_search_session_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   TagsListResponse sse_decode_tags_list_response(SseDeserializer deserializer);
 
+  @protected
+  TemplateNoteResponse sse_decode_template_note_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TemplatePromptAnswer sse_decode_template_prompt_answer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TrashItem sse_decode_trash_item(SseDeserializer deserializer);
+
+  @protected
+  TrashListResponse sse_decode_trash_list_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TrashPurgeResponse sse_decode_trash_purge_response(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -251,9 +459,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
@@ -266,9 +471,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_atom_tags_response(
+    AtomTagsResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_i_64(
     PlatformInt64 self,
@@ -281,6 +498,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_note_template_item(
+    NoteTemplateItem self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
@@ -290,12 +513,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_deleted_folder_item(
+    DeletedFolderItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_deleted_folders_response(
+    DeletedFoldersResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_embeddings_refresh_response(
+    EmbeddingsRefreshResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_entry_action_response(
     EntryActionResponse self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_entry_search_all_item(
+    EntrySearchAllItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_entry_search_all_response(
+    EntrySearchAllResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_entry_search_item(
     EntrySearchItem self,
@@ -308,9 +561,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_journal_response(
+    JournalResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
@@ -320,6 +582,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_deleted_folder_item(
+    List<DeletedFolderItem> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_entry_search_all_item(
+    List<EntrySearchAllItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_entry_search_item(
     List<EntrySearchItem> self,
@@ -329,12 +603,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_note_item(List<NoteItem> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_note_template_item(
+    List<NoteTemplateItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_template_prompt_answer(
+    List<TemplatePromptAnswer> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_trash_item(
+    List<TrashItem> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_workspace_node_item(
     List<WorkspaceNodeItem> self,
@@ -347,6 +639,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_note_response(NoteResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_note_template_item(
+    NoteTemplateItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_note_template_response(
+    NoteTemplateResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_note_templates_list_response(
+    NoteTemplatesListResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_notes_list_response(
     NotesListResponse self,
@@ -356,6 +666,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_i_64(
     PlatformInt64? self,
@@ -368,6 +684,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_note_template_item(
+    NoteTemplateItem? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
@@ -377,12 +699,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_search_session_response(
+    SearchSessionResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_tags_list_response(
     TagsListResponse self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_template_note_response(
+    TemplateNoteResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_template_prompt_answer(
+    TemplatePromptAnswer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_trash_item(TrashItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_trash_list_response(
+    TrashListResponse self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_trash_purge_response(
+    TrashPurgeResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -415,9 +770,6 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     WorkspaceNodeResponse self,
     SseSerializer serializer,
   );
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
 }
 
 // Section: wire_class
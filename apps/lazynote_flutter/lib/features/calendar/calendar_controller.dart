@@ -210,6 +210,7 @@ Future<rust_api.AtomListResponse> _defaultRangeInvoker({
     endMs: endMs,
     limit: limit,
     offset: offset,
+    includeArchived: false,
   );
 }
 
@@ -2280,6 +2280,9 @@ class NotesController extends ChangeNotifier {
       previewImage: current.previewImage,
       updatedAt: current.updatedAt,
       tags: current.tags,
+      isArchived: current.isArchived,
+      isPinned: current.isPinned,
+      isStarred: current.isStarred,
     );
   }
 
@@ -3051,7 +3054,12 @@ Future<rust_api.NotesListResponse> _defaultNotesListInvoker({
   int? limit,
   int? offset,
 }) {
-  return rust_api.notesList(tag: tag, limit: limit, offset: offset);
+  return rust_api.notesList(
+    tag: tag,
+    limit: limit,
+    offset: offset,
+    includeArchived: false,
+  );
 }
 
 Future<rust_api.NoteResponse> _defaultNoteGetInvoker({required String atomId}) {
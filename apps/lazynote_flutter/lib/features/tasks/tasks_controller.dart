@@ -342,7 +342,11 @@ Future<rust_api.AtomListResponse> _defaultInboxInvoker({
   int? limit,
   int? offset,
 }) {
-  return rust_api.tasksListInbox(limit: limit, offset: offset);
+  return rust_api.tasksListInbox(
+    limit: limit,
+    offset: offset,
+    includeArchived: false,
+  );
 }
 
 Future<rust_api.AtomListResponse> _defaultTodayInvoker({
@@ -356,6 +360,7 @@ Future<rust_api.AtomListResponse> _defaultTodayInvoker({
     eodMs: eodMs,
     limit: limit,
     offset: offset,
+    includeArchived: false,
   );
 }
 
@@ -364,7 +369,12 @@ Future<rust_api.AtomListResponse> _defaultUpcomingInvoker({
   int? limit,
   int? offset,
 }) {
-  return rust_api.tasksListUpcoming(eodMs: eodMs, limit: limit, offset: offset);
+  return rust_api.tasksListUpcoming(
+    eodMs: eodMs,
+    limit: limit,
+    offset: offset,
+    includeArchived: false,
+  );
 }
 
 Future<rust_api.EntryActionResponse> _defaultStatusInvoker({
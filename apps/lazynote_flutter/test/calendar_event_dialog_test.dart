@@ -67,6 +67,9 @@ void main() {
       startAt: DateTime(2026, 2, 16, 10, 0).millisecondsSinceEpoch,
       endAt: DateTime(2026, 2, 16, 11, 0).millisecondsSinceEpoch,
       updatedAt: DateTime(2026, 2, 16, 10, 0).millisecondsSinceEpoch,
+      isArchived: false,
+      isPinned: false,
+      isStarred: false,
     );
 
     await openDialog(tester, existingItem: existing);
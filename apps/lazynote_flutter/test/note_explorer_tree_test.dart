@@ -17,6 +17,9 @@ rust_api.NoteItem _note({
     previewImage: null,
     updatedAt: updatedAt,
     tags: const [],
+    isArchived: false,
+    isPinned: false,
+    isStarred: false,
   );
 }
 
@@ -279,6 +282,9 @@ void main() {
           previewImage: null,
           updatedAt: existing.updatedAt + 1,
           tags: existing.tags,
+          isArchived: existing.isArchived,
+          isPinned: existing.isPinned,
+          isStarred: existing.isStarred,
         );
         store[atomId] = updated;
         return rust_api.NoteResponse(
@@ -10,6 +10,9 @@ void main() {
       previewImage: null,
       updatedAt: 123,
       tags: ['work'],
+      isArchived: false,
+      isPinned: false,
+      isStarred: false,
     );
 
     expect(item.previewText, isNull);
@@ -25,6 +28,9 @@ void main() {
       previewImage: 'cover.png',
       updatedAt: 456,
       tags: ['important', 'work'],
+      isArchived: false,
+      isPinned: false,
+      isStarred: false,
     );
     const response = NoteResponse(
       ok: true,
@@ -53,6 +59,9 @@ void main() {
           previewImage: null,
           updatedAt: 1,
           tags: ['work'],
+          isArchived: false,
+          isPinned: false,
+          isStarred: false,
         ),
         NoteItem(
           atomId: 'atom-b',
@@ -61,6 +70,9 @@ void main() {
           previewImage: 'b.png',
           updatedAt: 2,
           tags: ['home'],
+          isArchived: false,
+          isPinned: false,
+          isStarred: false,
         ),
       ],
     );
@@ -17,6 +17,9 @@ rust_api.NoteItem _note({
     previewImage: null,
     updatedAt: updatedAt,
     tags: const [],
+    isArchived: false,
+    isPinned: false,
+    isStarred: false,
   );
 }
 
@@ -83,6 +86,9 @@ NotesController _buildController({
             previewImage: current.previewImage,
             updatedAt: current.updatedAt + 1,
             tags: current.tags,
+            isArchived: current.isArchived,
+            isPinned: current.isPinned,
+            isStarred: current.isStarred,
           );
           store[atomId] = updated;
           return rust_api.NoteResponse(
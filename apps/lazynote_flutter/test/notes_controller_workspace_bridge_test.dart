@@ -15,6 +15,9 @@ rust_api.NoteItem _note({
     previewImage: null,
     updatedAt: updatedAt,
     tags: const [],
+    isArchived: false,
+    isPinned: false,
+    isStarred: false,
   );
 }
 
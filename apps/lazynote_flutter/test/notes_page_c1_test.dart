@@ -26,6 +26,9 @@ void main() {
       previewImage: null,
       updatedAt: updatedAt,
       tags: const [],
+      isArchived: false,
+      isPinned: false,
+      isStarred: false,
     );
   }
 
@@ -24,6 +24,9 @@ void main() {
       previewImage: null,
       updatedAt: updatedAt,
       tags: tags,
+      isArchived: false,
+      isPinned: false,
+      isStarred: false,
     );
   }
 
@@ -22,6 +22,9 @@ void main() {
       previewImage: null,
       updatedAt: updatedAt,
       tags: const [],
+      isArchived: false,
+      isPinned: false,
+      isStarred: false,
     );
   }
 
@@ -34,6 +34,9 @@ void main() {
       endAt: endAt,
       taskStatus: null,
       updatedAt: 1000,
+      isArchived: false,
+      isPinned: false,
+      isStarred: false,
     );
   }
 
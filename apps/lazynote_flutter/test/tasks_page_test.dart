@@ -32,6 +32,9 @@ void main() {
       endAt: endAt,
       taskStatus: taskStatus,
       updatedAt: 1000,
+      isArchived: false,
+      isPinned: false,
+      isStarred: false,
     );
   }
 
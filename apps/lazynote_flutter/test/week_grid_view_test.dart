@@ -28,6 +28,9 @@ void main() {
       startAt: startMs,
       endAt: endMs,
       updatedAt: startMs,
+      isArchived: false,
+      isPinned: false,
+      isStarred: false,
     );
   }
 
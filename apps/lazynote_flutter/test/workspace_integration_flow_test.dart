@@ -17,6 +17,9 @@ rust_api.NoteItem _note({
     previewImage: null,
     updatedAt: updatedAt,
     tags: const [],
+    isArchived: false,
+    isPinned: false,
+    isStarred: false,
   );
 }
 
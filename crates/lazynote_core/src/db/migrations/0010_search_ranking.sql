@@ -0,0 +1,170 @@
+-- Migration: 0010_search_ranking.sql
+-- Purpose: rebuild FTS index with a weighted `tags` column and add atom access
+--          log used by search usage boost.
+-- Invariants:
+-- - atoms_fts column order is (content, tags, uuid, type); bm25 weights and
+--   snippet column indexes depend on it.
+-- - atoms_fts.tags mirrors space-joined tag names of the atom; atom_tags and
+--   tags.name changes refresh affected rows via triggers.
+-- - atom_access_log keeps one aggregated row per atom (count + last access),
+--   never a per-open history.
+-- Backward compatibility:
+-- - atoms_fts is derived data and is fully rebuilt from atoms/atom_tags.
+
+DROP TRIGGER IF EXISTS atoms_ai_fts;
+DROP TRIGGER IF EXISTS atoms_ad_fts;
+DROP TRIGGER IF EXISTS atoms_au_fts;
+DROP TABLE IF EXISTS atoms_fts;
+
+CREATE VIRTUAL TABLE atoms_fts USING fts5(
+    content,
+    tags,
+    uuid UNINDEXED,
+    type UNINDEXED,
+    tokenize = 'unicode61'
+);
+
+INSERT INTO atoms_fts (rowid, content, tags, uuid, type)
+SELECT
+    a.rowid,
+    a.content,
+    (
+        SELECT COALESCE(group_concat(t.name, ' '), '')
+        FROM atom_tags at
+        INNER JOIN tags t ON t.id = at.tag_id
+        WHERE at.atom_uuid = a.uuid
+    ),
+    a.uuid,
+    a.type
+FROM atoms a
+WHERE a.is_deleted = 0;
+
+CREATE TRIGGER atoms_ai_fts
+AFTER INSERT ON atoms
+WHEN NEW.is_deleted = 0
+BEGIN
+    INSERT INTO atoms_fts (rowid, content, tags, uuid, type)
+    VALUES (
+        NEW.rowid,
+        NEW.content,
+        (
+            SELECT COALESCE(group_concat(t.name, ' '), '')
+            FROM atom_tags at
+            INNER JOIN tags t ON t.id = at.tag_id
+            WHERE at.atom_uuid = NEW.uuid
+        ),
+        NEW.uuid,
+        NEW.type
+    );
+END;
+
+CREATE TRIGGER atoms_ad_fts
+AFTER DELETE ON atoms
+WHEN OLD.is_deleted = 0
+BEGIN
+    DELETE FROM atoms_fts
+    WHERE rowid = OLD.rowid;
+END;
+
+CREATE TRIGGER atoms_au_fts
+AFTER UPDATE ON atoms
+BEGIN
+    DELETE FROM atoms_fts
+    WHERE rowid = OLD.rowid;
+
+    INSERT INTO atoms_fts (rowid, content, tags, uuid, type)
+    SELECT
+        NEW.rowid,
+        NEW.content,
+        (
+            SELECT COALESCE(group_concat(t.name, ' '), '')
+            FROM atom_tags at
+            INNER JOIN tags t ON t.id = at.tag_id
+            WHERE at.atom_uuid = NEW.uuid
+        ),
+        NEW.uuid,
+        NEW.type
+    WHERE NEW.is_deleted = 0;
+END;
+
+CREATE TRIGGER atom_tags_ai_fts
+AFTER INSERT ON atom_tags
+BEGIN
+    DELETE FROM atoms_fts
+    WHERE rowid = (SELECT rowid FROM atoms WHERE uuid = NEW.atom_uuid);
+
+    INSERT INTO atoms_fts (rowid, content, tags, uuid, type)
+    SELECT
+        a.rowid,
+        a.content,
+        (
+            SELECT COALESCE(group_concat(t.name, ' '), '')
+            FROM atom_tags at
+            INNER JOIN tags t ON t.id = at.tag_id
+            WHERE at.atom_uuid = a.uuid
+        ),
+        a.uuid,
+        a.type
+    FROM atoms a
+    WHERE a.uuid = NEW.atom_uuid
+      AND a.is_deleted = 0;
+END;
+
+CREATE TRIGGER atom_tags_ad_fts
+AFTER DELETE ON atom_tags
+BEGIN
+    DELETE FROM atoms_fts
+    WHERE rowid = (SELECT rowid FROM atoms WHERE uuid = OLD.atom_uuid);
+
+    INSERT INTO atoms_fts (rowid, content, tags, uuid, type)
+    SELECT
+        a.rowid,
+        a.content,
+        (
+            SELECT COALESCE(group_concat(t.name, ' '), '')
+            FROM atom_tags at
+            INNER JOIN tags t ON t.id = at.tag_id
+            WHERE at.atom_uuid = a.uuid
+        ),
+        a.uuid,
+        a.type
+    FROM atoms a
+    WHERE a.uuid = OLD.atom_uuid
+      AND a.is_deleted = 0;
+END;
+
+CREATE TRIGGER tags_au_fts
+AFTER UPDATE OF name ON tags
+BEGIN
+    DELETE FROM atoms_fts
+    WHERE rowid IN (
+        SELECT a.rowid
+        FROM atoms a
+        INNER JOIN atom_tags at ON at.atom_uuid = a.uuid
+        WHERE at.tag_id = NEW.id
+    );
+
+    INSERT INTO atoms_fts (rowid, content, tags, uuid, type)
+    SELECT
+        a.rowid,
+        a.content,
+        (
+            SELECT COALESCE(group_concat(t.name, ' '), '')
+            FROM atom_tags inner_at
+            INNER JOIN tags t ON t.id = inner_at.tag_id
+            WHERE inner_at.atom_uuid = a.uuid
+        ),
+        a.uuid,
+        a.type
+    FROM atoms a
+    INNER JOIN atom_tags at ON at.atom_uuid = a.uuid
+    WHERE at.tag_id = NEW.id
+      AND a.is_deleted = 0;
+END;
+
+CREATE TABLE atom_access_log (
+    atom_uuid TEXT PRIMARY KEY NOT NULL,
+    access_count INTEGER NOT NULL DEFAULT 0 CHECK (access_count >= 0),
+    last_accessed_at INTEGER NOT NULL,
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
@@ -0,0 +1,114 @@
+-- Migration: 0011_saved_searches.sql
+-- Purpose: persist named search queries and expose them in the workspace tree
+--          as `smart_folder` nodes whose children are evaluated at read time.
+-- Invariants:
+-- - `kind='smart_folder'` must carry `saved_search_uuid` and no `atom_uuid`.
+-- - `folder`/`note_ref` rows never carry `saved_search_uuid`.
+-- - saved_searches.kind is NULL (all types) or one of note/task/event.
+-- - saved_searches.tag is stored lowercase without leading `#`.
+-- Backward compatibility:
+-- - workspace_nodes is rebuilt to widen the `kind` CHECK; existing rows,
+--   indexes and note_ref triggers are preserved.
+
+CREATE TABLE saved_searches (
+    uuid TEXT PRIMARY KEY NOT NULL,
+    query_text TEXT NOT NULL DEFAULT '',
+    kind TEXT NULL CHECK (kind IS NULL OR kind IN ('note', 'task', 'event')),
+    tag TEXT NULL,
+    open_only INTEGER NOT NULL DEFAULT 0 CHECK (open_only IN (0, 1)),
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000)
+);
+
+CREATE TABLE workspace_nodes_new (
+    node_uuid TEXT PRIMARY KEY NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('folder', 'note_ref', 'smart_folder')),
+    parent_uuid TEXT NULL,
+    atom_uuid TEXT NULL,
+    saved_search_uuid TEXT NULL,
+    display_name TEXT NOT NULL,
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    is_deleted INTEGER NOT NULL DEFAULT 0 CHECK (is_deleted IN (0, 1)),
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    CHECK (parent_uuid IS NULL OR parent_uuid <> node_uuid),
+    CHECK (
+        (kind = 'folder' AND atom_uuid IS NULL AND saved_search_uuid IS NULL)
+        OR (kind = 'note_ref' AND atom_uuid IS NOT NULL AND saved_search_uuid IS NULL)
+        OR (kind = 'smart_folder' AND atom_uuid IS NULL AND saved_search_uuid IS NOT NULL)
+    ),
+    -- Why: self-reference targets the new table so dropping the old table does
+    -- not trip FK checks; RENAME below rewrites it to `workspace_nodes`.
+    FOREIGN KEY (parent_uuid) REFERENCES workspace_nodes_new(node_uuid),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid),
+    FOREIGN KEY (saved_search_uuid) REFERENCES saved_searches(uuid)
+);
+
+INSERT INTO workspace_nodes_new (
+    node_uuid,
+    kind,
+    parent_uuid,
+    atom_uuid,
+    saved_search_uuid,
+    display_name,
+    sort_order,
+    is_deleted,
+    created_at,
+    updated_at
+)
+SELECT
+    node_uuid,
+    kind,
+    parent_uuid,
+    atom_uuid,
+    NULL,
+    display_name,
+    sort_order,
+    is_deleted,
+    created_at,
+    updated_at
+FROM workspace_nodes;
+
+DROP TABLE workspace_nodes;
+ALTER TABLE workspace_nodes_new RENAME TO workspace_nodes;
+
+CREATE INDEX IF NOT EXISTS idx_workspace_nodes_parent_order
+    ON workspace_nodes(parent_uuid, is_deleted, sort_order, node_uuid);
+CREATE INDEX IF NOT EXISTS idx_workspace_nodes_atom_uuid
+    ON workspace_nodes(atom_uuid);
+CREATE INDEX IF NOT EXISTS idx_workspace_nodes_saved_search_uuid
+    ON workspace_nodes(saved_search_uuid);
+
+CREATE TRIGGER workspace_nodes_note_ref_requires_note_insert
+BEFORE INSERT ON workspace_nodes
+WHEN NEW.kind = 'note_ref'
+BEGIN
+    SELECT
+        CASE
+            WHEN (
+                SELECT COUNT(1)
+                FROM atoms
+                WHERE uuid = NEW.atom_uuid
+                  AND type = 'note'
+                  AND is_deleted = 0
+            ) = 0
+            THEN RAISE(ABORT, 'workspace note_ref atom_uuid must reference an active note atom')
+        END;
+END;
+
+CREATE TRIGGER workspace_nodes_note_ref_requires_note_update
+BEFORE UPDATE OF kind, atom_uuid ON workspace_nodes
+WHEN NEW.kind = 'note_ref'
+BEGIN
+    SELECT
+        CASE
+            WHEN (
+                SELECT COUNT(1)
+                FROM atoms
+                WHERE uuid = NEW.atom_uuid
+                  AND type = 'note'
+                  AND is_deleted = 0
+            ) = 0
+            THEN RAISE(ABORT, 'workspace note_ref atom_uuid must reference an active note atom')
+        END;
+END;
@@ -0,0 +1,22 @@
+-- Migration: 0012_atom_embeddings.sql
+-- Purpose: add optional per-atom embedding storage used by hybrid
+--          (keyword + vector) search.
+-- Invariants:
+-- - one row per atom; `model` identifies the embedder that produced it.
+-- - `vector` is little-endian f32 array with exactly `dimensions` entries.
+-- - `content_hash` is FNV-1a 64 of the embedded content; rows whose hash or
+--   model differ from the current atom/embedder are stale and refreshed lazily.
+-- Backward compatibility:
+-- - additive schema update; table starts empty and is filled on demand.
+
+CREATE TABLE atom_embeddings (
+    atom_uuid TEXT PRIMARY KEY NOT NULL,
+    model TEXT NOT NULL,
+    dimensions INTEGER NOT NULL CHECK (dimensions > 0),
+    vector BLOB NOT NULL,
+    content_hash INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_atom_embeddings_model ON atom_embeddings(model);
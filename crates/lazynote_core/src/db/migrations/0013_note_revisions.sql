@@ -0,0 +1,23 @@
+-- Migration: 0013_note_revisions.sql
+-- Purpose: keep note content history so earlier versions can be diffed and
+--          restored after full-content updates.
+-- Invariants:
+-- - rows are append-only per note except for time-window coalescing, which
+--   rewrites `content`/`updated_at` of the newest row only.
+-- - `id` grows monotonically; newest revision has the highest id.
+-- - `created_at` marks the start of the coalescing window.
+-- Backward compatibility:
+-- - additive schema update; existing notes get a baseline revision lazily
+--   on their next update.
+
+CREATE TABLE note_revisions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    atom_uuid TEXT NOT NULL,
+    content TEXT NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_note_revisions_atom
+ON note_revisions(atom_uuid, id DESC);
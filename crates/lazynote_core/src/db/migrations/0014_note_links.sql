@@ -0,0 +1,32 @@
+-- Migration: 0014_note_links.sql
+-- Purpose: persist wiki-style `[[...]]` references between notes for
+--          outgoing-link and backlink queries.
+-- Invariants:
+-- - rows are derived from source note content and fully replaced on every
+--   note create/update.
+-- - `target_title` is the raw link target text; `target_atom_uuid` is the
+--   resolved note, or NULL when no note matches yet.
+-- - `position` is the byte offset of `[[` in the source content.
+-- Backward compatibility:
+-- - additive schema update; existing notes are indexed on their next write.
+
+CREATE TABLE note_links (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    source_atom_uuid TEXT NOT NULL,
+    target_atom_uuid TEXT NULL,
+    target_title TEXT NOT NULL,
+    label TEXT NULL,
+    position INTEGER NOT NULL CHECK (position >= 0),
+    context TEXT NOT NULL,
+    FOREIGN KEY (source_atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    FOREIGN KEY (target_atom_uuid) REFERENCES atoms(uuid) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_note_links_source
+ON note_links(source_atom_uuid, position);
+
+CREATE INDEX IF NOT EXISTS idx_note_links_target
+ON note_links(target_atom_uuid);
+
+CREATE INDEX IF NOT EXISTS idx_note_links_title
+ON note_links(target_title COLLATE NOCASE);
@@ -0,0 +1,22 @@
+-- Migration: 0015_atom_properties.sql
+-- Purpose: persist per-atom key/value metadata (first producer: note
+--          front-matter keys without a dedicated column).
+-- Invariants:
+-- - one value per (`atom_uuid`, `key`); keys are stored lowercased.
+-- - `source` records the producer; `front_matter` rows are fully replaced
+--   on every note create/update.
+-- Backward compatibility:
+-- - additive schema update; existing notes are indexed on their next write.
+
+CREATE TABLE atom_properties (
+    atom_uuid TEXT NOT NULL,
+    key TEXT NOT NULL CHECK (length(key) > 0),
+    value TEXT NOT NULL,
+    source TEXT NOT NULL DEFAULT 'front_matter',
+    updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    PRIMARY KEY (atom_uuid, key),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_atom_properties_key_value
+ON atom_properties(key, value);
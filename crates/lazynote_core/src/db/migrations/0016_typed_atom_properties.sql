@@ -0,0 +1,26 @@
+-- Migration: 0016_typed_atom_properties.sql
+-- Purpose: type atom property values (text, number, date, bool, atom ref)
+--          so list queries can compare them without string parsing.
+-- Invariants:
+-- - `value` keeps the text rendering of every value.
+-- - `value_number` holds number values, dates as epoch ms and bools as 0/1.
+-- - `value_ref` holds the referenced atom uuid for `atom_ref` values.
+-- Backward compatibility:
+-- - additive schema update; existing rows become `text` values and are
+--   retyped on their next write.
+
+ALTER TABLE atom_properties
+ADD COLUMN value_type TEXT NOT NULL DEFAULT 'text'
+CHECK (value_type IN ('text', 'number', 'date', 'bool', 'atom_ref'));
+
+ALTER TABLE atom_properties
+ADD COLUMN value_number REAL NULL;
+
+ALTER TABLE atom_properties
+ADD COLUMN value_ref TEXT NULL REFERENCES atoms(uuid) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_atom_properties_key_number
+ON atom_properties(key, value_number);
+
+CREATE INDEX IF NOT EXISTS idx_atom_properties_value_ref
+ON atom_properties(value_ref);
@@ -0,0 +1,57 @@
+-- Migration: 0017_attachments.sql
+-- Purpose: link atoms to content-addressed attachment blobs stored in a
+--          SHA-256 named directory next to the database file.
+-- Invariants:
+-- - one `attachment_blobs` row per distinct content hash (lowercase hex).
+-- - `attachment_blobs.ref_count` equals the number of `attachments` rows
+--   pointing at the blob; triggers keep it in sync, including cascades from
+--   hard-deleted atoms.
+-- - blobs with `ref_count = 0` are orphans; garbage collection deletes the
+--   row and the file.
+-- Backward compatibility:
+-- - additive schema update; `atoms.preview_image` is unchanged.
+
+CREATE TABLE attachment_blobs (
+    sha256 TEXT PRIMARY KEY NOT NULL CHECK (length(sha256) = 64),
+    size_bytes INTEGER NOT NULL CHECK (size_bytes >= 0),
+    ref_count INTEGER NOT NULL DEFAULT 0 CHECK (ref_count >= 0),
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000)
+);
+
+CREATE TABLE attachments (
+    uuid TEXT PRIMARY KEY NOT NULL,
+    atom_uuid TEXT NOT NULL,
+    blob_sha256 TEXT NOT NULL,
+    original_name TEXT NOT NULL CHECK (length(original_name) > 0),
+    mime_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL CHECK (size_bytes >= 0),
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    FOREIGN KEY (blob_sha256) REFERENCES attachment_blobs(sha256)
+);
+
+CREATE INDEX IF NOT EXISTS idx_attachments_atom
+ON attachments(atom_uuid, created_at);
+
+CREATE INDEX IF NOT EXISTS idx_attachments_blob
+ON attachments(blob_sha256);
+
+CREATE INDEX IF NOT EXISTS idx_attachment_blobs_orphans
+ON attachment_blobs(ref_count)
+WHERE ref_count = 0;
+
+CREATE TRIGGER attachments_ref_count_ai
+AFTER INSERT ON attachments
+BEGIN
+    UPDATE attachment_blobs
+    SET ref_count = ref_count + 1
+    WHERE sha256 = NEW.blob_sha256;
+END;
+
+CREATE TRIGGER attachments_ref_count_ad
+AFTER DELETE ON attachments
+BEGIN
+    UPDATE attachment_blobs
+    SET ref_count = ref_count - 1
+    WHERE sha256 = OLD.blob_sha256;
+END;
@@ -0,0 +1,20 @@
+-- Migration: 0018_note_preview_stats.sql
+-- Purpose: add markdown-derived title and reading statistics to the note
+--          preview projection.
+-- Invariants:
+-- - preview_title stores the first heading text when present.
+-- - word_count counts rendered words; each CJK character counts as one.
+-- - reading_time_minutes is word_count / 200 rounded up.
+-- - all fields are derived from `content`, never source-of-truth.
+-- Backward compatibility:
+-- - additive columns; existing rows read as untitled with zero counts until
+--   their next content write.
+
+ALTER TABLE atoms
+ADD COLUMN preview_title TEXT NULL;
+
+ALTER TABLE atoms
+ADD COLUMN word_count INTEGER NOT NULL DEFAULT 0 CHECK (word_count >= 0);
+
+ALTER TABLE atoms
+ADD COLUMN reading_time_minutes INTEGER NOT NULL DEFAULT 0 CHECK (reading_time_minutes >= 0);
@@ -0,0 +1,22 @@
+-- Migration: 0019_note_tasks.sql
+-- Purpose: project GFM task-list items (`- [ ] item`) inside notes as task
+--          atoms linked back to their note and line.
+-- Invariants:
+-- - one row per projected task atom; `item_index` is the item's position
+--   among the note's task-list items, `line_number` its 1-based line.
+-- - rows are re-synced from note content on every note create/update.
+-- - task status and the note checkbox are kept in sync in both directions.
+-- Backward compatibility:
+-- - additive schema update; existing notes are projected on their next write.
+
+CREATE TABLE note_tasks (
+    task_atom_uuid TEXT PRIMARY KEY NOT NULL,
+    note_atom_uuid TEXT NOT NULL,
+    item_index INTEGER NOT NULL CHECK (item_index >= 0),
+    line_number INTEGER NOT NULL CHECK (line_number > 0),
+    FOREIGN KEY (task_atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    FOREIGN KEY (note_atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_note_tasks_note
+ON note_tasks(note_atom_uuid, item_index);
@@ -0,0 +1,25 @@
+-- Migration: 0020_note_blocks.sql
+-- Purpose: index addressable markdown blocks (paragraphs, headings, list
+--          items) of notes under stable block ids for `![[note#^id]]` embeds.
+-- Invariants:
+-- - rows are derived from note content and re-synced on every note write.
+-- - `block_id` is unique per note and survives edits: explicit `^id`
+--   anchors win, otherwise ids are carried over by diffing block text.
+-- - `position` is the block's index in document order.
+-- Backward compatibility:
+-- - additive schema update; existing notes are indexed on their next write.
+
+CREATE TABLE note_blocks (
+    note_atom_uuid TEXT NOT NULL,
+    block_id TEXT NOT NULL CHECK (length(block_id) > 0),
+    position INTEGER NOT NULL CHECK (position >= 0),
+    kind TEXT NOT NULL CHECK (kind IN ('paragraph', 'heading', 'list_item')),
+    content TEXT NOT NULL,
+    start_offset INTEGER NOT NULL CHECK (start_offset >= 0),
+    end_offset INTEGER NOT NULL CHECK (end_offset >= start_offset),
+    PRIMARY KEY (note_atom_uuid, block_id),
+    FOREIGN KEY (note_atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_note_blocks_position
+ON note_blocks(note_atom_uuid, position);
@@ -0,0 +1,13 @@
+-- Migration: 0021_tag_metadata.sql
+-- Purpose: attach display metadata (color, icon, description) to tags.
+-- Invariants:
+-- - `color` is NULL or a lowercase `#rrggbb` hex color.
+-- - `icon` and `description` are NULL or non-blank trimmed text.
+-- - tags carrying metadata are kept when their last atom link goes away.
+-- Backward compatibility:
+-- - additive schema update; existing tags start without metadata.
+
+ALTER TABLE tags ADD COLUMN color TEXT
+    CHECK (color IS NULL OR (length(color) = 7 AND substr(color, 1, 1) = '#'));
+ALTER TABLE tags ADD COLUMN icon TEXT;
+ALTER TABLE tags ADD COLUMN description TEXT;
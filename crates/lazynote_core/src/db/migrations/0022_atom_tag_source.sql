@@ -0,0 +1,13 @@
+-- Migration: 0022_atom_tag_source.sql
+-- Purpose: record whether an atom-tag link was assigned manually or derived
+-- from an inline `#hashtag` in the atom content.
+-- Invariants:
+-- - `source` is `manual` or `inline`; one link per (atom_uuid, tag_id).
+-- - a tag both assigned manually and written inline is stored as `manual`.
+-- - `inline` links are owned by content sync and follow content edits.
+-- Backward compatibility:
+-- - additive schema update; existing links become `manual`. Existing
+--   content is scanned for hashtags on its next edit.
+
+ALTER TABLE atom_tags ADD COLUMN source TEXT NOT NULL DEFAULT 'manual'
+    CHECK (source IN ('manual', 'inline'));
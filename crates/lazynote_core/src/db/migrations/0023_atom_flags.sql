@@ -0,0 +1,21 @@
+-- Migration: 0023_atom_flags.sql
+-- Purpose: add archive, pin and star (favorite) flags to atoms.
+-- Invariants:
+-- - each flag is 0 or 1 and independent of the others.
+-- - `is_archived` hides an atom from default lists and search but is not a
+--   tombstone: `is_deleted` stays the only delete signal, so workspace
+--   note_ref read-time filtering (0008) and the active-target check (0007)
+--   ignore archiving.
+-- Backward compatibility:
+-- - additive schema update; existing atoms start unarchived, unpinned and
+--   unstarred.
+
+ALTER TABLE atoms ADD COLUMN is_archived INTEGER NOT NULL DEFAULT 0
+    CHECK (is_archived IN (0, 1));
+ALTER TABLE atoms ADD COLUMN is_pinned INTEGER NOT NULL DEFAULT 0
+    CHECK (is_pinned IN (0, 1));
+ALTER TABLE atoms ADD COLUMN is_starred INTEGER NOT NULL DEFAULT 0
+    CHECK (is_starred IN (0, 1));
+
+CREATE INDEX IF NOT EXISTS idx_atoms_archived_pinned
+    ON atoms(is_archived, is_pinned);
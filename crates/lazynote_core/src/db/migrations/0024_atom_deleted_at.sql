@@ -0,0 +1,40 @@
+-- Migration: 0024_atom_deleted_at.sql
+-- Purpose: record when an atom was moved to the trash so the trash view can
+--          sort by deletion time and purge expired tombstones.
+-- Invariants:
+-- - `deleted_at` is set iff `is_deleted = 1`; triggers maintain it for
+--   every writer that flips the tombstone.
+-- - restoring an atom clears `deleted_at`.
+-- Backward compatibility:
+-- - additive schema update; existing tombstones use `updated_at` (the soft
+--   delete timestamp) as their deletion time.
+
+ALTER TABLE atoms ADD COLUMN deleted_at INTEGER NULL;
+
+UPDATE atoms
+SET deleted_at = updated_at
+WHERE is_deleted = 1;
+
+CREATE INDEX IF NOT EXISTS idx_atoms_deleted_at
+    ON atoms(is_deleted, deleted_at);
+
+CREATE TRIGGER atoms_ai_deleted_at
+AFTER INSERT ON atoms
+WHEN NEW.is_deleted = 1 AND NEW.deleted_at IS NULL
+BEGIN
+    UPDATE atoms
+    SET deleted_at = (strftime('%s', 'now') * 1000)
+    WHERE uuid = NEW.uuid;
+END;
+
+CREATE TRIGGER atoms_au_deleted_at
+AFTER UPDATE OF is_deleted ON atoms
+WHEN NEW.is_deleted <> OLD.is_deleted
+BEGIN
+    UPDATE atoms
+    SET deleted_at = CASE
+        WHEN NEW.is_deleted = 1 THEN (strftime('%s', 'now') * 1000)
+        ELSE NULL
+    END
+    WHERE uuid = NEW.uuid;
+END;
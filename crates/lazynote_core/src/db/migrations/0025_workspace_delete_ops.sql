@@ -0,0 +1,41 @@
+-- Migration: 0025_workspace_delete_ops.sql
+-- Purpose: record workspace folder deletions so they can be undone.
+-- Invariants:
+-- - one `workspace_delete_ops` row per folder delete (`dissolve` or
+--   `delete_all`); `restored_at` is set once the delete is undone.
+-- - `workspace_delete_op_nodes` stores each affected node with the parent
+--   and sort order it had before the delete.
+-- - `workspace_delete_op_atoms` stores note atoms soft-deleted by a
+--   `delete_all` delete.
+-- - op rows do not pin their targets: no foreign keys, so trash purges can
+--   still hard-delete atoms and note_refs; restore skips missing rows.
+-- Backward compatibility:
+-- - additive schema update; deletions made before this migration cannot be
+--   undone.
+
+CREATE TABLE workspace_delete_ops (
+    op_uuid TEXT PRIMARY KEY NOT NULL,
+    folder_uuid TEXT NOT NULL,
+    mode TEXT NOT NULL CHECK (mode IN ('dissolve', 'delete_all')),
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    restored_at INTEGER NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_workspace_delete_ops_folder
+    ON workspace_delete_ops(folder_uuid, created_at);
+
+CREATE TABLE workspace_delete_op_nodes (
+    op_uuid TEXT NOT NULL,
+    node_uuid TEXT NOT NULL,
+    parent_uuid TEXT NULL,
+    sort_order INTEGER NOT NULL,
+    PRIMARY KEY (op_uuid, node_uuid),
+    FOREIGN KEY (op_uuid) REFERENCES workspace_delete_ops(op_uuid) ON DELETE CASCADE
+);
+
+CREATE TABLE workspace_delete_op_atoms (
+    op_uuid TEXT NOT NULL,
+    atom_uuid TEXT NOT NULL,
+    PRIMARY KEY (op_uuid, atom_uuid),
+    FOREIGN KEY (op_uuid) REFERENCES workspace_delete_ops(op_uuid) ON DELETE CASCADE
+);
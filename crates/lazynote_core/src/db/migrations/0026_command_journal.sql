@@ -0,0 +1,27 @@
+-- Migration: 0026_command_journal.sql
+-- Purpose: persist an undo/redo journal of core mutations.
+-- Invariants:
+-- - one row per recorded mutation; `undo_json`/`redo_json` hold the state to
+--   write back on undo and redo (`JournalCommand` JSON).
+-- - `undone_at` is set while an entry sits on the redo side; recording a new
+--   mutation deletes every undone entry.
+-- - entries do not pin their targets: no foreign keys, replaying an entry
+--   whose target is gone fails and drops the entry.
+-- Backward compatibility:
+-- - additive schema update; mutations made before this migration cannot be
+--   undone.
+
+CREATE TABLE command_journal (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    command TEXT NOT NULL CHECK (
+        command IN ('note_update', 'note_set_tags', 'node_move', 'atom_status', 'event_times')
+    ),
+    target_uuid TEXT NOT NULL,
+    undo_json TEXT NOT NULL,
+    redo_json TEXT NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    undone_at INTEGER NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_command_journal_undone
+    ON command_journal(undone_at, id);
@@ -0,0 +1,20 @@
+-- Migration: 0027_note_templates.sql
+-- Purpose: persist named note templates used to create pre-filled notes.
+-- Invariants:
+-- - `name` is unique case-insensitively and never blank.
+-- - `content` keeps `{{placeholder}}` markers unexpanded.
+-- - `tags_json` is a JSON array of normalized tag names.
+-- - `folder_uuid` is a hint only: no foreign key, a deleted or missing folder
+--   makes created notes land at the workspace root.
+-- Backward compatibility:
+-- - additive schema update; no existing rows are touched.
+
+CREATE TABLE note_templates (
+    uuid TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL COLLATE NOCASE UNIQUE CHECK (length(trim(name)) > 0),
+    content TEXT NOT NULL DEFAULT '',
+    tags_json TEXT NOT NULL DEFAULT '[]',
+    folder_uuid TEXT NULL,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000)
+);
@@ -0,0 +1,87 @@
+-- Migration: 0029_front_matter_tag_source.sql
+-- Purpose: let `atom_tags.source` record tags declared in front matter, so
+--          removing a tag from the front matter removes it from the atom.
+-- Invariants:
+-- - `source` is `manual`, `front_matter` or `inline`; one link per
+--   (atom_uuid, tag_id), ranked `manual` > `front_matter` > `inline`.
+-- - `front_matter` links are owned by content sync, like `inline` links.
+-- - the FTS triggers on `atom_tags` are recreated unchanged.
+-- Backward compatibility:
+-- - the table is rebuilt to widen the CHECK constraint; rows are copied
+--   as is.
+-- - the registered backfill (`backfill_front_matter_tag_source`) turns
+--   `manual` links of tags declared in a note's front matter into
+--   `front_matter` links; those used to be stored as `manual`.
+
+DROP TRIGGER IF EXISTS atom_tags_ai_fts;
+DROP TRIGGER IF EXISTS atom_tags_ad_fts;
+
+CREATE TABLE atom_tags_new (
+    atom_uuid TEXT NOT NULL,
+    tag_id INTEGER NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    source TEXT NOT NULL DEFAULT 'manual'
+        CHECK (source IN ('manual', 'front_matter', 'inline')),
+    PRIMARY KEY (atom_uuid, tag_id),
+    FOREIGN KEY (atom_uuid) REFERENCES atoms(uuid) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE
+);
+
+INSERT INTO atom_tags_new (atom_uuid, tag_id, created_at, source)
+SELECT atom_uuid, tag_id, created_at, source
+FROM atom_tags;
+
+-- Why: triggers on `atoms` and `tags` reference `atom_tags` by name; legacy
+-- rename skips re-checking them while the table is briefly missing.
+PRAGMA legacy_alter_table = ON;
+DROP TABLE atom_tags;
+ALTER TABLE atom_tags_new RENAME TO atom_tags;
+PRAGMA legacy_alter_table = OFF;
+
+CREATE INDEX IF NOT EXISTS idx_atom_tags_tag_id ON atom_tags(tag_id);
+
+CREATE TRIGGER atom_tags_ai_fts
+AFTER INSERT ON atom_tags
+BEGIN
+    DELETE FROM atoms_fts
+    WHERE rowid = (SELECT rowid FROM atoms WHERE uuid = NEW.atom_uuid);
+
+    INSERT INTO atoms_fts (rowid, content, tags, uuid, type)
+    SELECT
+        a.rowid,
+        a.content,
+        (
+            SELECT COALESCE(group_concat(t.name, ' '), '')
+            FROM atom_tags at
+            INNER JOIN tags t ON t.id = at.tag_id
+            WHERE at.atom_uuid = a.uuid
+        ),
+        a.uuid,
+        a.type
+    FROM atoms a
+    WHERE a.uuid = NEW.atom_uuid
+      AND a.is_deleted = 0;
+END;
+
+CREATE TRIGGER atom_tags_ad_fts
+AFTER DELETE ON atom_tags
+BEGIN
+    DELETE FROM atoms_fts
+    WHERE rowid = (SELECT rowid FROM atoms WHERE uuid = OLD.atom_uuid);
+
+    INSERT INTO atoms_fts (rowid, content, tags, uuid, type)
+    SELECT
+        a.rowid,
+        a.content,
+        (
+            SELECT COALESCE(group_concat(t.name, ' '), '')
+            FROM atom_tags at
+            INNER JOIN tags t ON t.id = at.tag_id
+            WHERE at.atom_uuid = a.uuid
+        ),
+        a.uuid,
+        a.type
+    FROM atoms a
+    WHERE a.uuid = OLD.atom_uuid
+      AND a.is_deleted = 0;
+END;
@@ -0,0 +1,72 @@
+-- Migration: 0030_note_task_tombstone_cascade.sql
+-- Purpose: keep checklist task atoms (`note_tasks`) in step with their
+--          note's tombstone so tasks of a trashed note leave task views.
+-- Invariants:
+-- - trashing a note trashes its active projected tasks and marks them
+--   `deleted_with_note`; restoring the note restores only marked tasks.
+--   The triggers cover every writer that flips the note's `is_deleted`.
+-- - tasks deleted on their own, or already unlinked from their note, are
+--   not touched.
+-- Backward compatibility:
+-- - additive schema update; active projected tasks of notes already in
+--   the trash are tombstoned and marked once.
+
+ALTER TABLE note_tasks
+ADD COLUMN deleted_with_note INTEGER NOT NULL DEFAULT 0
+CHECK (deleted_with_note IN (0, 1));
+
+UPDATE note_tasks
+SET deleted_with_note = 1
+WHERE note_atom_uuid IN (SELECT uuid FROM atoms WHERE is_deleted = 1)
+  AND task_atom_uuid IN (SELECT uuid FROM atoms WHERE is_deleted = 0);
+
+UPDATE atoms
+SET is_deleted = 1,
+    updated_at = (strftime('%s', 'now') * 1000)
+WHERE uuid IN (
+    SELECT task_atom_uuid
+    FROM note_tasks
+    WHERE deleted_with_note = 1
+);
+
+CREATE TRIGGER IF NOT EXISTS atoms_au_note_tasks_deleted
+AFTER UPDATE OF is_deleted ON atoms
+WHEN NEW.type = 'note' AND NEW.is_deleted = 1 AND OLD.is_deleted = 0
+BEGIN
+    UPDATE note_tasks
+    SET deleted_with_note = 1
+    WHERE note_atom_uuid = NEW.uuid
+      AND task_atom_uuid IN (SELECT uuid FROM atoms WHERE is_deleted = 0);
+
+    UPDATE atoms
+    SET is_deleted = 1,
+        updated_at = (strftime('%s', 'now') * 1000)
+    WHERE is_deleted = 0
+      AND uuid IN (
+          SELECT task_atom_uuid
+          FROM note_tasks
+          WHERE note_atom_uuid = NEW.uuid
+            AND deleted_with_note = 1
+      );
+END;
+
+CREATE TRIGGER IF NOT EXISTS atoms_au_note_tasks_restored
+AFTER UPDATE OF is_deleted ON atoms
+WHEN NEW.type = 'note' AND NEW.is_deleted = 0 AND OLD.is_deleted = 1
+BEGIN
+    UPDATE atoms
+    SET is_deleted = 0,
+        updated_at = (strftime('%s', 'now') * 1000)
+    WHERE is_deleted = 1
+      AND uuid IN (
+          SELECT task_atom_uuid
+          FROM note_tasks
+          WHERE note_atom_uuid = NEW.uuid
+            AND deleted_with_note = 1
+      );
+
+    UPDATE note_tasks
+    SET deleted_with_note = 0
+    WHERE note_atom_uuid = NEW.uuid
+      AND deleted_with_note = 1;
+END;
@@ -0,0 +1,39 @@
+-- Migration: 0031_journal_atom_tags.sql
+-- Purpose: journal manual tag edits on atoms of any type (`atom_set_tags`)
+--          so TagService set/add/remove can be undone.
+-- Invariants:
+-- - `command` admits `atom_set_tags` next to the commands of migration 26.
+-- - entry ids, order and undo/redo state are kept as they were.
+-- Backward compatibility:
+-- - table rebuild; existing entries are copied unchanged.
+
+CREATE TABLE command_journal_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    command TEXT NOT NULL CHECK (
+        command IN (
+            'note_update',
+            'note_set_tags',
+            'atom_set_tags',
+            'node_move',
+            'atom_status',
+            'event_times'
+        )
+    ),
+    target_uuid TEXT NOT NULL,
+    undo_json TEXT NOT NULL,
+    redo_json TEXT NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000),
+    undone_at INTEGER NULL
+);
+
+INSERT INTO command_journal_new (
+    id, command, target_uuid, undo_json, redo_json, created_at, undone_at
+)
+SELECT id, command, target_uuid, undo_json, redo_json, created_at, undone_at
+FROM command_journal;
+
+DROP TABLE command_journal;
+ALTER TABLE command_journal_new RENAME TO command_journal;
+
+CREATE INDEX IF NOT EXISTS idx_command_journal_undone
+    ON command_journal(undone_at, id);
@@ -0,0 +1,13 @@
+-- Migration: 0033_atom_tags_fts_refresh.sql
+-- Purpose: stop re-indexing a whole `atoms_fts` row for every `atom_tags`
+--          insert or delete.
+-- Invariants:
+-- - atoms_fts.tags still mirrors space-joined tag names of the atom; tag
+--   writers in `repo::tag_repo` rebuild it once per touched atom after all
+--   of that atom's links changed.
+-- - `atoms` and `tags.name` FTS triggers are unchanged.
+-- Backward compatibility:
+-- - trigger-only change; existing index rows are already current.
+
+DROP TRIGGER IF EXISTS atom_tags_ai_fts;
+DROP TRIGGER IF EXISTS atom_tags_ad_fts;
@@ -0,0 +1,226 @@
+//! Derivation rules frozen for Rust migration steps.
+//!
+//! # Responsibility
+//! - Hold the markdown, front-matter and tag rules data migrations were
+//!   written against, independent of the live `markdown`/`repo` code.
+//!
+//! # Invariants
+//! - Never change a function here once a migration uses it: a migration
+//!   must write the same data whenever a database reaches its version.
+//!   A migration needing new rules gets new functions.
+//! - Nothing here calls live crate code; only external parsers are used.
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use std::collections::BTreeSet;
+use yaml_rust2::{Yaml, YamlLoader};
+
+/// Reading speed behind `reading_time_minutes`.
+const READING_WORDS_PER_MINUTE: u32 = 200;
+
+/// Title and reading stats of one atom's markdown.
+pub(super) struct PreviewStats {
+    pub title: Option<String>,
+    pub word_count: u32,
+    pub reading_time_minutes: u32,
+}
+
+/// Derives preview stats: text of the first heading, words of rendered
+/// text (each CJK character counts as one) and `words / 200` rounded up.
+/// A leading front-matter block is ignored.
+pub(super) fn preview_stats(content: &str) -> PreviewStats {
+    let body = split_front_matter(content).1;
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_WIKILINKS;
+    let mut title: Option<String> = None;
+    let mut heading: Option<String> = None;
+    let mut word_count = 0_u32;
+    let mut in_word = false;
+    let mut image_depth = 0_usize;
+    for event in Parser::new_ext(body, options) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => heading = Some(String::new()),
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(text) = heading.take() {
+                    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if title.is_none() && !text.is_empty() {
+                        title = Some(text);
+                    }
+                }
+                in_word = false;
+            }
+            Event::Start(Tag::Paragraph) => {}
+            Event::Start(Tag::Image { .. }) => image_depth += 1,
+            Event::End(TagEnd::Image) => image_depth = image_depth.saturating_sub(1),
+            Event::Text(text) | Event::Code(text) => {
+                if image_depth > 0 {
+                    continue;
+                }
+                for ch in text.chars() {
+                    if is_cjk(ch) {
+                        word_count += 1;
+                        in_word = false;
+                    } else if ch.is_alphanumeric() {
+                        if !in_word {
+                            word_count += 1;
+                            in_word = true;
+                        }
+                    } else if ch.is_whitespace() {
+                        in_word = false;
+                    }
+                }
+                if let Some(buffer) = heading.as_mut() {
+                    buffer.push_str(text.as_ref());
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                in_word = false;
+                if let Some(buffer) = heading.as_mut() {
+                    buffer.push(' ');
+                }
+            }
+            Event::Start(_) | Event::End(_) => in_word = false,
+            _ => {}
+        }
+    }
+    PreviewStats {
+        title,
+        word_count,
+        reading_time_minutes: word_count.div_ceil(READING_WORDS_PER_MINUTE),
+    }
+}
+
+/// Returns the normalized, deduplicated `tags` declared in a leading YAML
+/// (`---`) or TOML (`+++`) block; empty when there is none or it does not
+/// parse. A scalar value is split on commas.
+pub(super) fn front_matter_tags(content: &str) -> Vec<String> {
+    let Some((is_yaml, raw)) = split_front_matter(content).0 else {
+        return Vec::new();
+    };
+    let values = if is_yaml {
+        yaml_tags(raw)
+    } else {
+        toml_tags(raw)
+    };
+    let mut unique = BTreeSet::new();
+    for value in values.unwrap_or_default() {
+        if let Some(tag) = normalize_tag(value.as_str()) {
+            unique.insert(tag);
+        }
+    }
+    unique.into_iter().collect()
+}
+
+/// Normalizes one tag: `/`-separated segments trimmed and lowercased,
+/// empty segments dropped; `None` when nothing is left.
+pub(super) fn normalize_tag(tag: &str) -> Option<String> {
+    let segments: Vec<String> = tag
+        .split('/')
+        .map(|segment| segment.trim().to_lowercase())
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    (!segments.is_empty()).then(|| segments.join("/"))
+}
+
+/// Splits off a leading front-matter block as `(is_yaml, raw)` plus the
+/// body after its closing line.
+fn split_front_matter(content: &str) -> (Option<(bool, &str)>, &str) {
+    let (is_yaml, open_len) = match first_line(content) {
+        Some(("---", len)) => (true, len),
+        Some(("+++", len)) => (false, len),
+        _ => return (None, content),
+    };
+    let mut cursor = open_len;
+    while let Some((line, len)) = first_line(&content[cursor..]) {
+        let closes = if is_yaml {
+            line == "---" || line == "..."
+        } else {
+            line == "+++"
+        };
+        if closes {
+            return (
+                Some((is_yaml, &content[open_len..cursor])),
+                &content[cursor + len..],
+            );
+        }
+        cursor += len;
+    }
+    (None, content)
+}
+
+fn first_line(text: &str) -> Option<(&str, usize)> {
+    if text.is_empty() {
+        return None;
+    }
+    let len = text.find('\n').map_or(text.len(), |index| index + 1);
+    Some((text[..len].trim_end(), len))
+}
+
+fn is_cjk(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+    )
+}
+
+fn yaml_tags(raw: &str) -> Option<Vec<String>> {
+    let documents = YamlLoader::load_from_str(raw).ok()?;
+    let Some(Yaml::Hash(mapping)) = documents.into_iter().next() else {
+        return None;
+    };
+    let value = mapping
+        .into_iter()
+        .filter(|(key, _)| yaml_text(key).is_some_and(|key| is_tags_key(key.as_str())))
+        .map(|(_, value)| value)
+        .next_back()?;
+    match value {
+        Yaml::Array(items) => Some(items.iter().filter_map(yaml_text).collect()),
+        other => yaml_text(&other).map(|text| split_tag_list(text.as_str())),
+    }
+}
+
+fn yaml_text(value: &Yaml) -> Option<String> {
+    match value {
+        Yaml::String(text) | Yaml::Real(text) => Some(text.clone()),
+        Yaml::Integer(number) => Some(number.to_string()),
+        Yaml::Boolean(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+fn toml_tags(raw: &str) -> Option<Vec<String>> {
+    let table: toml::Table = raw.parse().ok()?;
+    let value = table
+        .into_iter()
+        .filter(|(key, _)| is_tags_key(key.as_str()))
+        .map(|(_, value)| value)
+        .next_back()?;
+    match value {
+        toml::Value::Array(items) => Some(items.iter().filter_map(toml_text).collect()),
+        other => toml_text(&other).map(|text| split_tag_list(text.as_str())),
+    }
+}
+
+fn toml_text(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(text) => Some(text.clone()),
+        toml::Value::Integer(number) => Some(number.to_string()),
+        toml::Value::Float(number) => Some(number.to_string()),
+        toml::Value::Boolean(flag) => Some(flag.to_string()),
+        toml::Value::Datetime(datetime) => Some(datetime.to_string()),
+        toml::Value::Array(_) | toml::Value::Table(_) => None,
+    }
+}
+
+fn is_tags_key(key: &str) -> bool {
+    key.trim().to_lowercase() == "tags"
+}
+
+fn split_tag_list(text: &str) -> Vec<String> {
+    text.split(',').map(str::to_string).collect()
+}
@@ -3,61 +3,198 @@
 //! # Responsibility
 //! - Register schema migrations in strictly increasing order.
 //! - Apply pending migrations atomically.
+//! - Run Rust data backfills for columns SQL cannot derive.
 //!
 //! # Invariants
 //! - `version` values must remain monotonic.
 //! - Applied migration version is mirrored to `PRAGMA user_version`.
+//! - Backfills only use the frozen rules in `frozen`, never live parsing
+//!   or normalization code.
 //!
 //! # See also
 //! - docs/releases/v0.1/prs/PR-0005-sqlite-schema-migrations.md
 
+mod frozen;
+
 use crate::db::{DbError, DbResult};
 use log::{error, info, warn};
-use rusqlite::Connection;
+use rusqlite::{params, Connection, OptionalExtension};
 use std::time::Instant;
 
 #[derive(Debug, Clone, Copy)]
 struct Migration {
     version: u32,
-    sql: &'static str,
+    /// Schema step; `None` for Rust-only data migrations.
+    sql: Option<&'static str>,
+    /// Data step run after `sql`, inside the same transaction.
+    backfill: Option<fn(&Connection) -> rusqlite::Result<()>>,
 }
 
 const MIGRATIONS: &[Migration] = &[
     Migration {
         version: 1,
-        sql: include_str!("0001_init.sql"),
+        sql: Some(include_str!("0001_init.sql")),
+        backfill: None,
     },
     Migration {
         version: 2,
-        sql: include_str!("0002_tags.sql"),
+        sql: Some(include_str!("0002_tags.sql")),
+        backfill: None,
     },
     Migration {
         version: 3,
-        sql: include_str!("0003_external_mappings.sql"),
+        sql: Some(include_str!("0003_external_mappings.sql")),
+        backfill: None,
     },
     Migration {
         version: 4,
-        sql: include_str!("0004_fts.sql"),
+        sql: Some(include_str!("0004_fts.sql")),
+        backfill: None,
     },
     Migration {
         version: 5,
-        sql: include_str!("0005_note_preview.sql"),
+        sql: Some(include_str!("0005_note_preview.sql")),
+        backfill: None,
     },
     Migration {
         version: 6,
-        sql: include_str!("0006_time_matrix.sql"),
+        sql: Some(include_str!("0006_time_matrix.sql")),
+        backfill: None,
     },
     Migration {
         version: 7,
-        sql: include_str!("0007_workspace_tree.sql"),
+        sql: Some(include_str!("0007_workspace_tree.sql")),
+        backfill: None,
     },
     Migration {
         version: 8,
-        sql: include_str!("0008_workspace_tree_delete_policy.sql"),
+        sql: Some(include_str!("0008_workspace_tree_delete_policy.sql")),
+        backfill: None,
     },
     Migration {
         version: 9,
-        sql: include_str!("0009_workspace_note_ref_backfill.sql"),
+        sql: Some(include_str!("0009_workspace_note_ref_backfill.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 10,
+        sql: Some(include_str!("0010_search_ranking.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 11,
+        sql: Some(include_str!("0011_saved_searches.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 12,
+        sql: Some(include_str!("0012_atom_embeddings.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 13,
+        sql: Some(include_str!("0013_note_revisions.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 14,
+        sql: Some(include_str!("0014_note_links.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 15,
+        sql: Some(include_str!("0015_atom_properties.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 16,
+        sql: Some(include_str!("0016_typed_atom_properties.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 17,
+        sql: Some(include_str!("0017_attachments.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 18,
+        sql: Some(include_str!("0018_note_preview_stats.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 19,
+        sql: Some(include_str!("0019_note_tasks.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 20,
+        sql: Some(include_str!("0020_note_blocks.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 21,
+        sql: Some(include_str!("0021_tag_metadata.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 22,
+        sql: Some(include_str!("0022_atom_tag_source.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 23,
+        sql: Some(include_str!("0023_atom_flags.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 24,
+        sql: Some(include_str!("0024_atom_deleted_at.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 25,
+        sql: Some(include_str!("0025_workspace_delete_ops.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 26,
+        sql: Some(include_str!("0026_command_journal.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 27,
+        sql: Some(include_str!("0027_note_templates.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 28,
+        sql: None,
+        backfill: Some(backfill_preview_stats),
+    },
+    Migration {
+        version: 29,
+        sql: Some(include_str!("0029_front_matter_tag_source.sql")),
+        backfill: Some(backfill_front_matter_tag_source),
+    },
+    Migration {
+        version: 30,
+        sql: Some(include_str!("0030_note_task_tombstone_cascade.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 31,
+        sql: Some(include_str!("0031_journal_atom_tags.sql")),
+        backfill: None,
+    },
+    Migration {
+        version: 32,
+        sql: None,
+        backfill: Some(backfill_normalized_tag_names),
+    },
+    Migration {
+        version: 33,
+        sql: Some(include_str!("0033_atom_tags_fts_refresh.sql")),
+        backfill: None,
     },
 ];
 
@@ -129,15 +266,29 @@ pub fn apply_migrations(conn: &mut Connection) -> DbResult<()> {
             migration.version
         );
 
-        tx.execute_batch(migration.sql).map_err(|err| {
-            error!(
-                "event=db_migrate_step_done module=db status=error target_version={} duration_ms={} error_code=migration_sql_failed error={}",
-                migration.version,
-                step_started_at.elapsed().as_millis(),
-                err
-            );
-            DbError::Sqlite(err)
-        })?;
+        if let Some(sql) = migration.sql {
+            tx.execute_batch(sql).map_err(|err| {
+                error!(
+                    "event=db_migrate_step_done module=db status=error target_version={} duration_ms={} error_code=migration_sql_failed error={}",
+                    migration.version,
+                    step_started_at.elapsed().as_millis(),
+                    err
+                );
+                DbError::Sqlite(err)
+            })?;
+        }
+
+        if let Some(backfill) = migration.backfill {
+            backfill(&tx).map_err(|err| {
+                error!(
+                    "event=db_migrate_step_done module=db status=error target_version={} duration_ms={} error_code=migration_backfill_failed error={}",
+                    migration.version,
+                    step_started_at.elapsed().as_millis(),
+                    err
+                );
+                DbError::Sqlite(err)
+            })?;
+        }
 
         tx.execute_batch(&format!("PRAGMA user_version = {};", migration.version))
             .map_err(|err| {
@@ -186,6 +337,150 @@ fn current_user_version(conn: &Connection) -> DbResult<u32> {
     Ok(version)
 }
 
+/// Derives `preview_title`, `word_count` and `reading_time_minutes` for
+/// atoms written before migration 18 added them.
+///
+/// Rows already carrying stats are skipped; `updated_at` is left untouched.
+fn backfill_preview_stats(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT uuid, content
+         FROM atoms
+         WHERE preview_title IS NULL
+           AND word_count = 0
+           AND content <> '';",
+    )?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for (uuid, content) in rows {
+        let stats = frozen::preview_stats(content.as_str());
+        conn.execute(
+            "UPDATE atoms
+             SET preview_title = ?2,
+                 word_count = ?3,
+                 reading_time_minutes = ?4
+             WHERE uuid = ?1;",
+            params![
+                uuid,
+                stats.title,
+                stats.word_count,
+                stats.reading_time_minutes
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Marks `manual` links of tags declared in an atom's front matter as
+/// `front_matter`, the source content sync now owns.
+///
+/// Why: before migration 29 front-matter tags were stored as `manual`
+/// links, which content edits could never remove.
+fn backfill_front_matter_tag_source(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT uuid, content
+         FROM atoms
+         WHERE content LIKE '---%'
+            OR content LIKE '+++%';",
+    )?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for (uuid, content) in rows {
+        for tag in frozen::front_matter_tags(content.as_str()) {
+            conn.execute(
+                "UPDATE atom_tags
+                 SET source = 'front_matter'
+                 WHERE atom_uuid = ?1
+                   AND source = 'manual'
+                   AND tag_id IN (SELECT id FROM tags WHERE name = ?2);",
+                params![uuid, tag],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Rewrites tag and saved-search tag names to their normalized form.
+///
+/// Why: older writers stored raw names, which forced every tag filter to
+/// compare `lower(name)`; colliding rows are merged like a tag rename.
+fn backfill_normalized_tag_names(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare("SELECT id, name FROM tags ORDER BY id ASC;")?;
+    let tags = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for (tag_id, name) in tags {
+        let Some(normalized) = frozen::normalize_tag(name.as_str()) else {
+            // Links are removed explicitly so FTS tag triggers see every row.
+            conn.execute("DELETE FROM atom_tags WHERE tag_id = ?1;", [tag_id])?;
+            conn.execute("DELETE FROM tags WHERE id = ?1;", [tag_id])?;
+            continue;
+        };
+        if normalized == name {
+            continue;
+        }
+        let existing: Option<i64> = conn
+            .query_row(
+                "SELECT id FROM tags WHERE name = ?1 AND id <> ?2;",
+                params![normalized, tag_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(target_id) = existing else {
+            conn.execute(
+                "UPDATE tags SET name = ?2 WHERE id = ?1;",
+                params![tag_id, normalized],
+            )?;
+            continue;
+        };
+        conn.execute(
+            "UPDATE tags
+             SET color = COALESCE(color, (SELECT color FROM tags WHERE id = ?1)),
+                 icon = COALESCE(icon, (SELECT icon FROM tags WHERE id = ?1)),
+                 description = COALESCE(description, (SELECT description FROM tags WHERE id = ?1))
+             WHERE id = ?2;",
+            params![tag_id, target_id],
+        )?;
+        conn.execute(
+            "INSERT INTO atom_tags (atom_uuid, tag_id, created_at, source)
+             SELECT atom_uuid, ?2, created_at, source
+             FROM atom_tags
+             WHERE tag_id = ?1
+             ON CONFLICT (atom_uuid, tag_id) DO UPDATE
+             SET source = excluded.source
+             WHERE (CASE excluded.source WHEN 'manual' THEN 2 WHEN 'front_matter' THEN 1 ELSE 0 END)
+                 > (CASE atom_tags.source WHEN 'manual' THEN 2 WHEN 'front_matter' THEN 1 ELSE 0 END);",
+            params![tag_id, target_id],
+        )?;
+        conn.execute("DELETE FROM atom_tags WHERE tag_id = ?1;", [tag_id])?;
+        conn.execute("DELETE FROM tags WHERE id = ?1;", [tag_id])?;
+    }
+
+    let mut stmt = conn.prepare("SELECT uuid, tag FROM saved_searches WHERE tag IS NOT NULL;")?;
+    let searches = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for (uuid, tag) in searches {
+        let normalized = frozen::normalize_tag(tag.as_str());
+        if normalized.as_deref() != Some(tag.as_str()) {
+            conn.execute(
+                "UPDATE saved_searches SET tag = ?2 WHERE uuid = ?1;",
+                params![uuid, normalized],
+            )?;
+        }
+    }
+    Ok(())
+}
+
 fn validate_registry(migrations: &[Migration]) -> DbResult<()> {
     let mut previous = 0;
     for migration in migrations {
@@ -201,6 +496,12 @@ fn validate_registry(migrations: &[Migration]) -> DbResult<()> {
             ));
         }
 
+        if migration.sql.is_none() && migration.backfill.is_none() {
+            return Err(DbError::InvalidMigrationRegistry(
+                "migration must have a SQL or Rust step",
+            ));
+        }
+
         previous = migration.version;
     }
 
@@ -217,11 +518,13 @@ mod tests {
         let migrations = [
             Migration {
                 version: 1,
-                sql: "SELECT 1;",
+                sql: Some("SELECT 1;"),
+                backfill: None,
             },
             Migration {
                 version: 1,
-                sql: "SELECT 1;",
+                sql: Some("SELECT 1;"),
+                backfill: None,
             },
         ];
 
@@ -233,7 +536,20 @@ mod tests {
     fn registry_rejects_zero_version() {
         let migrations = [Migration {
             version: 0,
-            sql: "SELECT 1;",
+            sql: Some("SELECT 1;"),
+            backfill: None,
+        }];
+
+        let err = validate_registry(&migrations).unwrap_err();
+        assert!(matches!(err, DbError::InvalidMigrationRegistry(_)));
+    }
+
+    #[test]
+    fn registry_rejects_migration_without_steps() {
+        let migrations = [Migration {
+            version: 1,
+            sql: None,
+            backfill: None,
         }];
 
         let err = validate_registry(&migrations).unwrap_err();
@@ -7,6 +7,8 @@ pub mod db;
 pub mod extension;
 /// Structured logging initialization and status APIs.
 pub mod logging;
+/// Markdown source analysis (front matter, wiki links).
+pub mod markdown;
 /// Canonical Atom data model.
 pub mod model;
 /// Persistence contracts and SQLite repository implementations.
@@ -36,34 +38,129 @@ pub use extension::manifest::{
 };
 /// Re-export logging entry points for FFI/UI layers.
 pub use logging::{default_log_level, init_logging, logging_status};
+/// Re-export markdown block segmentation helpers.
+pub use markdown::blocks::{assign_block_ids, parse_blocks, BlockKind, MarkdownBlock};
+/// Re-export task-list parsing helpers.
+pub use markdown::checklist::{parse_checklist, set_checklist_item_checked, ChecklistItem};
+/// Re-export front-matter parsing helpers.
+pub use markdown::front_matter::{
+    format_front_matter_date, parse_front_matter, parse_front_matter_date,
+    rewrite_front_matter_tags, split_front_matter, strip_front_matter, FrontMatter,
+    FrontMatterFormat,
+};
+/// Re-export inline hashtag extraction and rewriting.
+pub use markdown::hashtags::{parse_hashtags, rewrite_hashtags};
+/// Re-export markdown preview derivation.
+pub use markdown::preview::{derive_markdown_preview, MarkdownPreview, READING_WORDS_PER_MINUTE};
+/// Re-export markdown renderers and link resolution.
+pub use markdown::render::{
+    note_url, render_html, render_plain_text, LinkTable, RenderResolver, ATTACHMENT_URL_PREFIX,
+    ATTACHMENT_URL_SCHEME, NOTE_URL_PREFIX, SAFE_URL_SCHEMES,
+};
+/// Re-export note template placeholder expansion.
+pub use markdown::template::{
+    render_template, template_prompts, RenderedTemplate, TemplateContext,
+    TEMPLATE_PLACEHOLDER_CURSOR, TEMPLATE_PLACEHOLDER_DATE, TEMPLATE_PLACEHOLDER_TIME,
+    TEMPLATE_PLACEHOLDER_TITLE,
+};
+/// Re-export wiki link parsing helpers.
+pub use markdown::wiki_links::{
+    parse_wiki_links, rewrite_wiki_link_target, rewrite_wiki_link_to_atom,
+    split_wiki_link_fragment, WikiLink,
+};
 /// Re-export canonical Atom model types.
 pub use model::atom::{Atom, AtomId, AtomType, AtomValidationError, TaskStatus};
 /// Re-export repository contracts and SQLite implementation.
 pub use repo::atom_repo::{
-    AtomListQuery, AtomRepository, RepoError, RepoResult, SectionAtomRow, SqliteAtomRepository,
+    AtomFlag, AtomListQuery, AtomRepository, RepoError, RepoResult, SectionAtomRow, SectionFilter,
+    SqliteAtomRepository,
 };
+/// Re-export attachment repository models and implementation.
+pub use repo::attachment_repo::{
+    Attachment, AttachmentError, AttachmentId, AttachmentRepository, AttachmentResult,
+    BlobGcReport, SqliteAttachmentRepository,
+};
+/// Re-export content-addressed blob store.
+pub use repo::blob_store::{BlobStore, StagedBlob, BLOB_DIR_NAME};
+/// Re-export note block index models.
+pub use repo::block_repo::NoteBlock;
+/// Re-export command journal contracts and implementation.
+pub use repo::journal_repo::{
+    JournalCommand, JournalEntry, JournalRepository, JournalStatus, SqliteJournalRepository,
+    JOURNAL_MAX_ENTRIES,
+};
+/// Re-export note link index models.
+pub use repo::link_repo::{NoteLink, UnresolvedLinks};
 /// Re-export notes/tags repository models and implementation.
 pub use repo::note_repo::{
     load_tags_for_atoms, normalize_note_limit, normalize_tag, normalize_tags, NoteListQuery,
-    NoteRecord, NoteRepository, SqliteNoteRepository,
+    NoteRecord, NoteRepository, NoteRevision, SqliteNoteRepository, NOTE_REVISION_WINDOW_MS,
+};
+/// Re-export note checklist task models.
+pub use repo::note_task_repo::NoteTask;
+/// Re-export atom property models and filters.
+pub use repo::property_repo::{
+    normalize_property_key, AtomProperty, PropertyFilter, PropertyPredicate, PropertyValue,
+    PROPERTY_SOURCE_FRONT_MATTER, PROPERTY_SOURCE_USER,
 };
+/// Re-export boolean tag filter expressions.
+pub use repo::tag_filter::{TagFilter, TagFilterParseError};
+/// Re-export hierarchical tag tree models.
+pub use repo::tag_repo::{
+    AtomTag, SqliteTagRepository, TagMetadata, TagRepository, TagSource, TagSummary, TagTreeNode,
+    TagUsage, TAG_PATH_SEPARATOR,
+};
+/// Re-export note template models and repository.
+pub use repo::template_repo::{
+    NoteTemplate, NoteTemplateDraft, NoteTemplateId, SqliteTemplateRepository, TemplateRepository,
+};
+/// Re-export trash repository contracts and implementation.
+pub use repo::trash_repo::{SqliteTrashRepository, TrashQuery, TrashRepository, TrashedAtom};
 /// Re-export workspace tree repository contracts and implementation.
 pub use repo::tree_repo::{
-    SqliteTreeRepository, TreeRepoError, TreeRepoResult, TreeRepository, WorkspaceNode,
-    WorkspaceNodeId, WorkspaceNodeKind,
+    DeletedFolder, SavedSearch, SavedSearchId, SavedSearchQuery, SqliteTreeRepository,
+    TreeRepoError, TreeRepoResult, TreeRepository, WorkspaceNode, WorkspaceNodeId,
+    WorkspaceNodeKind,
 };
 /// Re-export search query/result models and search entry point.
-pub use search::fts::{search_all, SearchError, SearchHit, SearchQuery, SearchResult};
+pub use search::fts::{
+    search_all, search_all_with_embedder, SearchError, SearchHit, SearchItem, SearchMode,
+    SearchQuery, SearchRanking, SearchResult, TagSearchHit, WorkspaceSearchHit,
+};
+/// Re-export incremental search session and cancellation handle.
+pub use search::session::{SearchCancelHandle, SearchSession, SessionHits, SESSION_CANDIDATES_MAX};
+/// Re-export search access-log entry points.
+pub use search::usage::{atom_access_stats, record_atom_access, record_atom_access_at};
+/// Re-export embedding backend contracts and vector index entry points.
+pub use search::vector::{
+    refresh_embeddings, search_vector, Embedder, HashingEmbedder, VectorHit,
+    HASHING_EMBEDDER_DIMENSIONS,
+};
 /// Re-export atom service facade.
 pub use service::atom_service::{AtomService, ScheduleEventRequest};
+/// Re-export attachment service facade.
+pub use service::attachment_service::{
+    guess_mime_type, AttachmentService, DEFAULT_ATTACHMENT_MIME_TYPE,
+};
+/// Re-export undo/redo service facade.
+pub use service::journal_service::JournalService;
 /// Re-export notes service facade and models.
 pub use service::note_service::{
-    derive_markdown_preview, MarkdownPreview, NoteService, NoteServiceError, NotesListResult,
+    diff_text, DiffGranularity, DiffOp, DiffSegment, NoteService, NoteServiceError,
+    NotesListResult, TemplateNote,
 };
+/// Re-export tag management service facade.
+pub use service::tag_service::{normalize_tag_color, TagService};
 /// Re-export task/section service facade and models.
 pub use service::task_service::{SectionAtom, TaskService, TaskServiceError};
+/// Re-export note template service facade.
+pub use service::template_service::TemplateService;
+/// Re-export trash service facade.
+pub use service::trash_service::TrashService;
 /// Re-export workspace tree service facade and errors.
-pub use service::tree_service::{FolderDeleteMode, TreeService, TreeServiceError};
+pub use service::tree_service::{
+    FolderDeleteMode, TreeService, TreeServiceError, SMART_FOLDER_CHILDREN_MAX,
+};
 /// Re-export provider SPI and sync contract models.
 pub use sync::provider_registry::{ProviderRegistry, ProviderRegistryError};
 pub use sync::provider_spi::ProviderSpi;
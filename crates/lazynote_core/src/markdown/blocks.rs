@@ -0,0 +1,230 @@
+//! Block-level segmentation of note markdown.
+//!
+//! # Responsibility
+//! - Split note markdown into addressable blocks (paragraphs, headings,
+//!   list items) with their source ranges.
+//! - Read explicit `^block-id` anchors written at the end of a block.
+//! - Carry block ids across edits by diffing old and new block text.
+//!
+//! # Invariants
+//! - Paragraphs inside list items belong to the list item, not a block of
+//!   their own; nested list items are separate blocks.
+//! - A list item's text stops where its nested list starts.
+//! - Block text excludes the trailing `^block-id` anchor.
+
+use crate::markdown::front_matter::strip_front_matter;
+use once_cell::sync::Lazy;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use similar::{capture_diff_slices, Algorithm, DiffOp};
+use std::collections::HashSet;
+
+static BLOCK_ANCHOR_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|\s)\^([A-Za-z0-9][A-Za-z0-9-]*)\s*$").expect("valid block anchor regex")
+});
+
+/// Kind of one addressable markdown block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockKind {
+    /// Top-level or quoted paragraph.
+    Paragraph,
+    /// ATX or setext heading.
+    Heading,
+    /// One list item, without its nested list.
+    ListItem,
+}
+
+impl BlockKind {
+    /// Stable storage name.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Paragraph => "paragraph",
+            Self::Heading => "heading",
+            Self::ListItem => "list_item",
+        }
+    }
+
+    /// Parses a storage name produced by [`Self::as_str`].
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "paragraph" => Some(Self::Paragraph),
+            "heading" => Some(Self::Heading),
+            "list_item" => Some(Self::ListItem),
+            _ => None,
+        }
+    }
+}
+
+/// One addressable block of markdown source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownBlock {
+    /// Block kind.
+    pub kind: BlockKind,
+    /// Trimmed markdown source of the block, anchor removed.
+    pub text: String,
+    /// Explicit `^block-id` anchor, when present.
+    pub anchor: Option<String>,
+    /// Byte offset where the block starts.
+    pub start: usize,
+    /// Byte offset just past the block.
+    pub end: usize,
+}
+
+/// Splits markdown source into addressable blocks in document order.
+pub fn parse_blocks(content: &str) -> Vec<MarkdownBlock> {
+    let body = strip_front_matter(content);
+    let body_offset = content.len() - body.len();
+    let mut blocks = Vec::new();
+    // Open list items: (block index, nested list seen).
+    let mut items: Vec<(usize, bool)> = Vec::new();
+    let mut open: Option<(BlockKind, usize)> = None;
+    let options = Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_WIKILINKS;
+    for (event, range) in Parser::new_ext(body, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { .. }) => open = Some((BlockKind::Heading, range.start)),
+            Event::Start(Tag::Paragraph) if items.is_empty() => {
+                open = Some((BlockKind::Paragraph, range.start));
+            }
+            Event::End(TagEnd::Heading(_)) | Event::End(TagEnd::Paragraph) => {
+                if let Some((kind, start)) = open.take() {
+                    push_block(&mut blocks, body, body_offset, kind, start, range.end);
+                }
+            }
+            Event::Start(Tag::Item) => {
+                items.push((blocks.len(), false));
+                push_block(
+                    &mut blocks,
+                    body,
+                    body_offset,
+                    BlockKind::ListItem,
+                    range.start,
+                    range.end,
+                );
+            }
+            Event::Start(Tag::List(_)) => {
+                if let Some((index, nested)) = items.last_mut() {
+                    if !*nested {
+                        *nested = true;
+                        let start = blocks[*index].start - body_offset;
+                        let block =
+                            make_block(body, body_offset, BlockKind::ListItem, start, range.start);
+                        blocks[*index] = block;
+                    }
+                }
+            }
+            Event::End(TagEnd::Item) => {
+                items.pop();
+            }
+            _ => {}
+        }
+    }
+    blocks.retain(|block| !block.text.is_empty() || block.anchor.is_some());
+    blocks
+}
+
+/// Assigns ids to `current` blocks, reusing ids of `previous` blocks.
+///
+/// `previous` holds `(id, kind, text)` of the last stored version. Explicit
+/// anchors always win; otherwise unchanged blocks keep their id and edited
+/// blocks keep the id of the block they replaced (same kind, matched by
+/// diff position). Remaining blocks get ids from `new_id`.
+pub fn assign_block_ids(
+    previous: &[(String, BlockKind, String)],
+    current: &[MarkdownBlock],
+    mut new_id: impl FnMut() -> String,
+) -> Vec<String> {
+    let old_texts: Vec<&str> = previous.iter().map(|(_, _, text)| text.as_str()).collect();
+    let new_texts: Vec<&str> = current.iter().map(|block| block.text.as_str()).collect();
+    let mut inherited: Vec<Option<usize>> = vec![None; current.len()];
+    for op in capture_diff_slices(Algorithm::Myers, &old_texts, &new_texts) {
+        match op {
+            DiffOp::Equal {
+                old_index,
+                new_index,
+                len,
+            } => {
+                for offset in 0..len {
+                    inherited[new_index + offset] = Some(old_index + offset);
+                }
+            }
+            DiffOp::Replace {
+                old_index,
+                old_len,
+                new_index,
+                new_len,
+            } => {
+                for offset in 0..old_len.min(new_len) {
+                    if previous[old_index + offset].1 == current[new_index + offset].kind {
+                        inherited[new_index + offset] = Some(old_index + offset);
+                    }
+                }
+            }
+            DiffOp::Insert { .. } | DiffOp::Delete { .. } => {}
+        }
+    }
+
+    let mut used: HashSet<String> = current
+        .iter()
+        .filter_map(|block| block.anchor.clone())
+        .collect();
+    let mut anchors_seen = HashSet::new();
+    let mut ids = Vec::with_capacity(current.len());
+    for (block, old) in current.iter().zip(inherited) {
+        if let Some(anchor) = block.anchor.as_ref() {
+            if anchors_seen.insert(anchor.clone()) {
+                ids.push(anchor.clone());
+                continue;
+            }
+        }
+        let reused = old
+            .map(|index| previous[index].0.clone())
+            .filter(|id| !used.contains(id));
+        let id = reused.unwrap_or_else(|| loop {
+            let candidate = new_id();
+            if !used.contains(&candidate) {
+                break candidate;
+            }
+        });
+        used.insert(id.clone());
+        ids.push(id);
+    }
+    ids
+}
+
+fn push_block(
+    blocks: &mut Vec<MarkdownBlock>,
+    body: &str,
+    body_offset: usize,
+    kind: BlockKind,
+    start: usize,
+    end: usize,
+) {
+    blocks.push(make_block(body, body_offset, kind, start, end));
+}
+
+fn make_block(
+    body: &str,
+    body_offset: usize,
+    kind: BlockKind,
+    start: usize,
+    end: usize,
+) -> MarkdownBlock {
+    let source = body[start..end].trim_end();
+    let (text, anchor) = match BLOCK_ANCHOR_RE.captures(source) {
+        Some(caps) => {
+            let whole = caps.get(0).expect("group 0 always present");
+            (
+                source[..whole.start()].trim_end(),
+                Some(caps[1].to_string()),
+            )
+        }
+        None => (source, None),
+    };
+    MarkdownBlock {
+        kind,
+        text: text.to_string(),
+        anchor,
+        start: body_offset + start,
+        end: body_offset + start + source.len(),
+    }
+}
@@ -0,0 +1,95 @@
+//! GFM task-list (`- [ ] item`) parsing and checkbox rewriting.
+//!
+//! # Responsibility
+//! - Find task-list items in note markdown with their line and state.
+//! - Flip one item's checkbox without touching the rest of the source.
+//!
+//! # Invariants
+//! - Items inside code blocks or the front-matter block are ignored.
+//! - Item text excludes nested list items and is whitespace normalized.
+//! - Rewriting changes exactly one byte (the checkbox mark).
+
+use crate::markdown::front_matter::strip_front_matter;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// One `- [ ]` / `- [x]` item found in markdown source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecklistItem {
+    /// Item text without the checkbox; may be empty.
+    pub text: String,
+    /// Whether the checkbox is ticked (`[x]` or `[X]`).
+    pub checked: bool,
+    /// 1-based line of the checkbox.
+    pub line: u32,
+    /// Byte offset of the checkbox `[`.
+    pub marker: usize,
+}
+
+/// Extracts task-list items from markdown source in document order.
+pub fn parse_checklist(content: &str) -> Vec<ChecklistItem> {
+    let body = strip_front_matter(content);
+    let body_offset = content.len() - body.len();
+    let mut items = Vec::new();
+    // One frame per open list item; `Some(index)` once it proved to be a task.
+    let mut frames: Vec<Option<usize>> = Vec::new();
+    let options = Options::ENABLE_TASKLISTS | Options::ENABLE_TABLES;
+    for (event, range) in Parser::new_ext(body, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Item) => frames.push(None),
+            Event::End(TagEnd::Item) => {
+                frames.pop();
+            }
+            Event::TaskListMarker(checked) => {
+                if let Some(frame) = frames.last_mut() {
+                    let marker = body_offset + range.start;
+                    *frame = Some(items.len());
+                    items.push(ChecklistItem {
+                        text: String::new(),
+                        checked,
+                        line: line_of(content, marker),
+                        marker,
+                    });
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(Some(index)) = frames.last() {
+                    items[*index].text.push_str(text.as_ref());
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                if let Some(Some(index)) = frames.last() {
+                    items[*index].text.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+    for item in &mut items {
+        item.text = item.text.split_whitespace().collect::<Vec<_>>().join(" ");
+    }
+    items
+}
+
+/// Returns `content` with the checkbox on `line` set to `checked`.
+///
+/// Returns `None` when `line` holds no task-list item. Content already in
+/// the requested state is returned unchanged.
+pub fn set_checklist_item_checked(content: &str, line: u32, checked: bool) -> Option<String> {
+    let item = parse_checklist(content)
+        .into_iter()
+        .find(|item| item.line == line)?;
+    let mut updated = content.to_string();
+    if item.checked != checked {
+        let mark = if checked { "x" } else { " " };
+        updated.replace_range(item.marker + 1..item.marker + 2, mark);
+    }
+    Some(updated)
+}
+
+fn line_of(content: &str, offset: usize) -> u32 {
+    let newlines = content.as_bytes()[..offset]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count();
+    u32::try_from(newlines + 1).unwrap_or(u32::MAX)
+}
@@ -0,0 +1,531 @@
+//! Front-matter block parsing for note markdown.
+//!
+//! # Responsibility
+//! - Detect a leading YAML (`---`) or TOML (`+++`) front-matter block.
+//! - Map well-known keys to note metadata: `tags`, `title`, and
+//!   `due`/`start`/`end` time fields.
+//! - Keep remaining scalar keys as plain-text properties.
+//! - Rewrite `tags` values in place when a tag is renamed.
+//!
+//! # Invariants
+//! - The block must start on the first line; anything else is body text.
+//! - Malformed blocks are treated as absent, never as a parse failure of
+//!   the note itself.
+//! - Keys are trimmed and lowercased; nested tables/maps are ignored.
+//! - YAML scalars keep their source text (`007` stays `007`); TOML values
+//!   are rendered from their parsed form.
+//! - Date values without an offset are read as UTC.
+//! - Tag rewrites only replace the value text inside the `tags` entry;
+//!   quoting, layout and every other key stay byte-for-byte. Values whose
+//!   source spelling differs from the parsed text (escapes) are left alone.
+
+use std::collections::BTreeMap;
+use yaml_rust2::parser::{Event, EventReceiver, Parser};
+use yaml_rust2::scanner::TScalarStyle;
+
+/// Syntax of one front-matter block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrontMatterFormat {
+    /// `---` delimited YAML.
+    Yaml,
+    /// `+++` delimited TOML.
+    Toml,
+}
+
+/// Metadata extracted from one front-matter block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontMatter {
+    /// Block syntax.
+    pub format: FrontMatterFormat,
+    /// Raw tag values in declaration order; callers normalize them.
+    pub tags: Vec<String>,
+    /// Non-blank `title:` value.
+    pub title: Option<String>,
+    /// `start:` as epoch milliseconds.
+    pub start_at: Option<i64>,
+    /// `end:` (or `due:` when `end:` is absent) as epoch milliseconds.
+    pub end_at: Option<i64>,
+    /// Remaining scalar/list keys rendered as text.
+    pub properties: BTreeMap<String, String>,
+}
+
+/// Splits `content` into an optional raw front-matter block and the body.
+///
+/// The body slice starts right after the closing delimiter line.
+pub fn split_front_matter(content: &str) -> (Option<(FrontMatterFormat, &str)>, &str) {
+    let (format, open_len) = match first_line(content) {
+        Some(("---", len)) => (FrontMatterFormat::Yaml, len),
+        Some(("+++", len)) => (FrontMatterFormat::Toml, len),
+        _ => return (None, content),
+    };
+
+    let mut cursor = open_len;
+    while cursor < content.len() {
+        let Some((line, len)) = first_line(&content[cursor..]) else {
+            break;
+        };
+        let closes = match format {
+            FrontMatterFormat::Yaml => line == "---" || line == "...",
+            FrontMatterFormat::Toml => line == "+++",
+        };
+        if closes {
+            let raw = &content[open_len..cursor];
+            return (Some((format, raw)), &content[cursor + len..]);
+        }
+        cursor += len;
+    }
+    (None, content)
+}
+
+/// Returns `content` without its leading front-matter block.
+pub fn strip_front_matter(content: &str) -> &str {
+    split_front_matter(content).1
+}
+
+/// Parses the leading front-matter block of `content`.
+///
+/// Returns `None` when there is no block or it is not valid YAML/TOML.
+pub fn parse_front_matter(content: &str) -> Option<FrontMatter> {
+    let (format, raw) = split_front_matter(content).0?;
+    let entries = match format {
+        FrontMatterFormat::Yaml => yaml_entries(raw)?,
+        FrontMatterFormat::Toml => toml_entries(raw)?,
+    };
+
+    let mut front_matter = FrontMatter {
+        format,
+        tags: Vec::new(),
+        title: None,
+        start_at: None,
+        end_at: None,
+        properties: BTreeMap::new(),
+    };
+    let mut due_at = None;
+    for (key, value) in entries {
+        match key.as_str() {
+            "tags" => {
+                front_matter.tags = match value {
+                    RawValue::List(items) => items,
+                    RawValue::Text(text) => text.split(',').map(str::to_string).collect(),
+                };
+            }
+            "title" => {
+                let title = value.into_text();
+                let title = title.trim();
+                front_matter.title = (!title.is_empty()).then(|| title.to_string());
+            }
+            "due" | "start" | "end" => {
+                let text = value.into_text();
+                match parse_front_matter_date(text.as_str()) {
+                    Some(epoch_ms) if key == "start" => front_matter.start_at = Some(epoch_ms),
+                    Some(epoch_ms) if key == "end" => front_matter.end_at = Some(epoch_ms),
+                    Some(epoch_ms) => due_at = Some(epoch_ms),
+                    // Why: keep unparseable dates visible instead of dropping
+                    // user-written metadata.
+                    None => {
+                        front_matter.properties.insert(key, text);
+                    }
+                }
+            }
+            _ => {
+                front_matter.properties.insert(key, value.into_text());
+            }
+        }
+    }
+    front_matter.end_at = front_matter.end_at.or(due_at);
+    if let (Some(start), Some(end)) = (front_matter.start_at, front_matter.end_at) {
+        if end < start {
+            front_matter.end_at = None;
+        }
+    }
+    Some(front_matter)
+}
+
+/// Replaces every front-matter tag value for which `rename` returns a new
+/// tag.
+///
+/// `rename` receives the raw value as declared (trimmed). Returns `None`
+/// when there is no parseable block or no value changed.
+pub fn rewrite_front_matter_tags(
+    content: &str,
+    rename: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    let (format, raw) = split_front_matter(content).0?;
+    let front_matter = parse_front_matter(content)?;
+    let raw_offset = raw.as_ptr() as usize - content.as_ptr() as usize;
+    let (region_start, region_end) = tags_region(raw, format)?;
+
+    let mut rewritten = String::with_capacity(content.len());
+    let mut cursor = raw_offset + region_start;
+    let mut search_from = region_start;
+    rewritten.push_str(&content[..cursor]);
+    let mut matched = false;
+    for value in &front_matter.tags {
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        let Some(start) = find_tag_value(&raw[..region_end], search_from, value) else {
+            continue;
+        };
+        search_from = start + value.len();
+        if let Some(renamed) = rename(value) {
+            rewritten.push_str(&content[cursor..raw_offset + start]);
+            rewritten.push_str(renamed.as_str());
+            cursor = raw_offset + start + value.len();
+            matched = true;
+        }
+    }
+    if !matched {
+        return None;
+    }
+    rewritten.push_str(&content[cursor..]);
+    Some(rewritten)
+}
+
+/// Parses a front-matter date into epoch milliseconds (UTC).
+///
+/// Accepts integer epoch milliseconds, `YYYY-MM-DD`, and
+/// `YYYY-MM-DD[T ]HH:MM[:SS[.fff]][Z|±HH:MM]`.
+pub fn parse_front_matter_date(value: &str) -> Option<i64> {
+    let value = value.trim();
+    if let Ok(epoch_ms) = value.parse::<i64>() {
+        return Some(epoch_ms);
+    }
+    if value.len() < 10 || !value.is_char_boundary(10) {
+        return None;
+    }
+
+    let (date, rest) = value.split_at(10);
+    let mut parts = date.split('-');
+    let year: i64 = parse_digits(parts.next()?, 4)?;
+    let month: u32 = parse_digits(parts.next()?, 2)?;
+    let day: u32 = parse_digits(parts.next()?, 2)?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    let mut epoch_ms = days_from_civil(year, month, day) * 86_400_000;
+    if rest.is_empty() {
+        return Some(epoch_ms);
+    }
+
+    let time = rest.strip_prefix('T').or_else(|| rest.strip_prefix(' '))?;
+    let (clock, offset_ms) = split_offset(time)?;
+    let mut fields = clock.split(':');
+    let hour: i64 = parse_digits(fields.next()?, 2)?;
+    let minute: i64 = parse_digits(fields.next()?, 2)?;
+    let (second, millis) = match fields.next() {
+        Some(seconds) => {
+            let (whole, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+            let millis = if fraction.is_empty() {
+                0
+            } else {
+                let digits: String = fraction.chars().chain("000".chars()).take(3).collect();
+                digits.parse::<i64>().ok()?
+            };
+            (parse_digits::<i64>(whole, 2)?, millis)
+        }
+        None => (0, 0),
+    };
+    if fields.next().is_some() || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    epoch_ms += ((hour * 60 + minute) * 60 + second) * 1000 + millis;
+    Some(epoch_ms - offset_ms)
+}
+
+/// Formats epoch milliseconds as `YYYY-MM-DD` (midnight UTC) or
+/// `YYYY-MM-DDTHH:MM:SS[.fff]Z`; the inverse of [`parse_front_matter_date`].
+pub fn format_front_matter_date(epoch_ms: i64) -> String {
+    let days = epoch_ms.div_euclid(86_400_000);
+    let day_ms = epoch_ms.rem_euclid(86_400_000);
+    let (year, month, day) = civil_from_days(days);
+    if day_ms == 0 {
+        return format!("{year:04}-{month:02}-{day:02}");
+    }
+    let (seconds, millis) = (day_ms / 1000, day_ms % 1000);
+    let (hour, minute, second) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if millis == 0 {
+        format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+    } else {
+        format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{millis:03}Z")
+    }
+}
+
+/// Intermediate value shared by the YAML and TOML readers.
+enum RawValue {
+    Text(String),
+    List(Vec<String>),
+}
+
+impl RawValue {
+    fn into_text(self) -> String {
+        match self {
+            Self::Text(text) => text,
+            Self::List(items) => items.join(", "),
+        }
+    }
+}
+
+fn yaml_entries(raw: &str) -> Option<Vec<(String, RawValue)>> {
+    if raw.trim().is_empty() {
+        return Some(Vec::new());
+    }
+    let mut builder = YamlTreeBuilder::default();
+    Parser::new_from_str(raw).load(&mut builder, false).ok()?;
+    let Some(YamlNode::Mapping(mapping)) = builder.root else {
+        return None;
+    };
+    let mut entries = Vec::new();
+    for (key, value) in mapping {
+        let Some(key) = yaml_scalar(key).and_then(normalize_key) else {
+            continue;
+        };
+        let value = match value {
+            YamlNode::Sequence(items) => {
+                RawValue::List(items.into_iter().filter_map(yaml_scalar).collect())
+            }
+            other => match yaml_scalar(other) {
+                Some(text) => RawValue::Text(text),
+                None => continue,
+            },
+        };
+        entries.push((key, value));
+    }
+    Some(entries)
+}
+
+fn yaml_scalar(node: YamlNode) -> Option<String> {
+    match node {
+        YamlNode::Scalar(text) => text,
+        YamlNode::Sequence(_) | YamlNode::Mapping(_) => None,
+    }
+}
+
+/// YAML document node whose scalars keep their source text.
+///
+/// Why: resolving scalars to numbers would rewrite values such as `007` or
+/// `1.50`; typing is left to the property layer.
+enum YamlNode {
+    /// Scalar text; `None` for null.
+    Scalar(Option<String>),
+    Sequence(Vec<YamlNode>),
+    Mapping(Vec<(YamlNode, YamlNode)>),
+}
+
+/// Collects parser events of the first YAML document into a [`YamlNode`].
+#[derive(Default)]
+struct YamlTreeBuilder {
+    /// Open collections: whether each is a mapping, and its items so far
+    /// (keys and values alternate in mappings).
+    open: Vec<(bool, Vec<YamlNode>)>,
+    root: Option<YamlNode>,
+}
+
+impl EventReceiver for YamlTreeBuilder {
+    fn on_event(&mut self, event: Event) {
+        let node = match event {
+            Event::Scalar(text, style, _, _) => {
+                let null = style == TScalarStyle::Plain
+                    && matches!(text.as_str(), "" | "~" | "null" | "Null" | "NULL");
+                YamlNode::Scalar((!null).then_some(text))
+            }
+            // Why: aliases are rare in front matter; treat them as null
+            // rather than expanding anchors.
+            Event::Alias(_) => YamlNode::Scalar(None),
+            Event::SequenceStart(..) => {
+                self.open.push((false, Vec::new()));
+                return;
+            }
+            Event::MappingStart(..) => {
+                self.open.push((true, Vec::new()));
+                return;
+            }
+            Event::SequenceEnd | Event::MappingEnd => {
+                let Some((is_mapping, items)) = self.open.pop() else {
+                    return;
+                };
+                if is_mapping {
+                    let mut items = items.into_iter();
+                    let mut pairs = Vec::new();
+                    while let (Some(key), Some(value)) = (items.next(), items.next()) {
+                        pairs.push((key, value));
+                    }
+                    YamlNode::Mapping(pairs)
+                } else {
+                    YamlNode::Sequence(items)
+                }
+            }
+            _ => return,
+        };
+        match self.open.last_mut() {
+            Some((_, items)) => items.push(node),
+            None => {
+                self.root.get_or_insert(node);
+            }
+        }
+    }
+}
+
+fn toml_entries(raw: &str) -> Option<Vec<(String, RawValue)>> {
+    let table: toml::Table = raw.parse().ok()?;
+    let mut entries = Vec::new();
+    for (key, value) in table {
+        let Some(key) = normalize_key(key) else {
+            continue;
+        };
+        let value = match value {
+            toml::Value::Array(items) => {
+                RawValue::List(items.iter().filter_map(toml_scalar).collect())
+            }
+            other => match toml_scalar(&other) {
+                Some(text) => RawValue::Text(text),
+                None => continue,
+            },
+        };
+        entries.push((key, value));
+    }
+    Some(entries)
+}
+
+fn toml_scalar(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(text) => Some(text.clone()),
+        toml::Value::Integer(number) => Some(number.to_string()),
+        toml::Value::Float(number) => Some(number.to_string()),
+        toml::Value::Boolean(flag) => Some(flag.to_string()),
+        toml::Value::Datetime(datetime) => Some(datetime.to_string()),
+        toml::Value::Array(_) | toml::Value::Table(_) => None,
+    }
+}
+
+fn normalize_key(key: String) -> Option<String> {
+    let normalized = key.trim().to_lowercase();
+    (!normalized.is_empty()).then_some(normalized)
+}
+
+/// Byte range in `raw` holding the value of the top-level `tags` entry.
+///
+/// The range starts after the key separator and runs until the next
+/// top-level key.
+fn tags_region(raw: &str, format: FrontMatterFormat) -> Option<(usize, usize)> {
+    let separator = match format {
+        FrontMatterFormat::Yaml => ':',
+        FrontMatterFormat::Toml => '=',
+    };
+    let mut start = None;
+    let mut offset = 0;
+    for line in raw.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let top_level_key = line
+            .split_once(separator)
+            .filter(|_| !line.starts_with([' ', '\t', '-', ']', '"', '\'', '#']))
+            .map(|(key, _)| key.trim().trim_matches(['"', '\'']));
+        match (start, top_level_key) {
+            (None, Some(key)) if key.eq_ignore_ascii_case("tags") => {
+                start = Some(line_start + line.find(separator)? + 1);
+            }
+            (Some(start), Some(_)) => return Some((start, line_start)),
+            _ => {}
+        }
+    }
+    start.map(|start| (start, raw.len()))
+}
+
+/// Finds `value` in `region` at or after `from` as a whole list item.
+fn find_tag_value(region: &str, from: usize, value: &str) -> Option<usize> {
+    let mut search_from = from;
+    while let Some(found) = region.get(search_from..)?.find(value) {
+        let start = search_from + found;
+        let end = start + value.len();
+        let before = region[..start].chars().next_back();
+        let after = region[end..].chars().next();
+        let opens = before.is_none_or(|ch| {
+            ch.is_whitespace() || matches!(ch, '[' | ',' | '"' | '\'' | ':' | '=')
+        });
+        let closes =
+            after.is_none_or(|ch| ch.is_whitespace() || matches!(ch, ']' | ',' | '"' | '\'' | '#'));
+        if opens && closes {
+            return Some(start);
+        }
+        search_from = end;
+    }
+    None
+}
+
+/// Returns the first line without its terminator and the consumed length.
+fn first_line(text: &str) -> Option<(&str, usize)> {
+    if text.is_empty() {
+        return None;
+    }
+    let len = text.find('\n').map_or(text.len(), |index| index + 1);
+    Some((text[..len].trim_end(), len))
+}
+
+fn parse_digits<T: std::str::FromStr>(text: &str, width: usize) -> Option<T> {
+    if text.len() != width || !text.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+/// Splits a trailing `Z`/`±HH:MM` offset and returns it in milliseconds.
+fn split_offset(time: &str) -> Option<(&str, i64)> {
+    if let Some(clock) = time.strip_suffix('Z').or_else(|| time.strip_suffix('z')) {
+        return Some((clock, 0));
+    }
+    if time.len() > 6 && time.is_char_boundary(time.len() - 6) {
+        let (clock, offset) = time.split_at(time.len() - 6);
+        let sign = match offset.as_bytes()[0] {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return Some((time, 0)),
+        };
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        let hours: i64 = parse_digits(hours, 2)?;
+        let minutes: i64 = parse_digits(minutes, 2)?;
+        return Some((clock, sign * (hours * 60 + minutes) * 60_000));
+    }
+    Some((time, 0))
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date for a day count since 1970-01-01.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
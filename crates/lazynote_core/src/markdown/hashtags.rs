@@ -0,0 +1,161 @@
+//! Inline `#hashtag` extraction from atom content.
+//!
+//! # Responsibility
+//! - Find `#tag` tokens in markdown prose; callers normalize them with the
+//!   usual tag rules.
+//! - Rewrite tags in place when a tag is renamed.
+//!
+//! # Invariants
+//! - Code spans, code blocks, headings, links, raw HTML and the front-matter
+//!   block are ignored.
+//! - A tag starts after whitespace or opening punctuation, so URL fragments
+//!   (`page#top`), `[[Note#heading]]` and `&#39;` entities are not tags.
+//! - Tag characters are Unicode letters/digits plus `_`, `-` and `/`; a tag
+//!   needs at least one letter, so `#1` and `#2024` are not tags.
+//! - Output keeps document order without the leading `#`; exact duplicates
+//!   are dropped.
+//! - Rewriting only touches the tag text after `#`; prose whose source
+//!   differs from its rendered text (escapes, entities) is left alone.
+
+use crate::markdown::front_matter::strip_front_matter;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// Extracts inline hashtags from markdown source in document order.
+pub fn parse_hashtags(content: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for_each_prose_run(content, |text, _| {
+        for (start, end) in hashtag_spans(text) {
+            let candidate = &text[start..end];
+            if !tags.iter().any(|tag| tag == candidate) {
+                tags.push(candidate.to_string());
+            }
+        }
+    });
+    tags
+}
+
+/// Replaces every inline hashtag for which `rename` returns a new tag.
+///
+/// `rename` receives the tag text without `#`. Returns `None` when no tag
+/// changed, so callers can skip the write.
+pub fn rewrite_hashtags(content: &str, rename: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let mut rewritten = String::with_capacity(content.len());
+    let mut cursor = 0;
+    let mut matched = false;
+    for_each_prose_run(content, |text, source_start| {
+        let Some(source_start) = source_start else {
+            return;
+        };
+        for (start, end) in hashtag_spans(text) {
+            if let Some(renamed) = rename(&text[start..end]) {
+                rewritten.push_str(&content[cursor..source_start + start]);
+                rewritten.push_str(renamed.as_str());
+                cursor = source_start + end;
+                matched = true;
+            }
+        }
+    });
+    if !matched {
+        return None;
+    }
+    rewritten.push_str(&content[cursor..]);
+    Some(rewritten)
+}
+
+/// Calls `visit` with every run of consecutive prose text outside skipped
+/// elements, plus the run's byte offset in `content` when the source slice
+/// matches the text exactly.
+fn for_each_prose_run(content: &str, mut visit: impl FnMut(&str, Option<usize>)) {
+    let body = strip_front_matter(content);
+    let body_offset = content.len() - body.len();
+    let mut text = String::new();
+    // Source range of the current run; `None` once it stops matching text.
+    let mut range: Option<(usize, usize)> = None;
+    let mut verbatim = true;
+    // Depth of enclosing headings, code blocks, links and images whose text
+    // is skipped.
+    let mut skipped = 0usize;
+    let options = Options::ENABLE_TASKLISTS | Options::ENABLE_TABLES;
+    let mut flush = |text: &mut String, range: &mut Option<(usize, usize)>, verbatim: &mut bool| {
+        if !text.is_empty() {
+            let start = range
+                .filter(|(start, end)| *verbatim && &body[*start..*end] == text.as_str())
+                .map(|(start, _)| body_offset + start);
+            visit(text.as_str(), start);
+        }
+        text.clear();
+        *range = None;
+        *verbatim = true;
+    };
+    for (event, span) in Parser::new_ext(body, options).into_offset_iter() {
+        match event {
+            Event::Text(value) if skipped == 0 => {
+                match range.as_mut() {
+                    Some((_, end)) if *end == span.start => *end = span.end,
+                    Some(_) => verbatim = false,
+                    None => range = Some((span.start, span.end)),
+                }
+                text.push_str(value.as_ref());
+                continue;
+            }
+            Event::Start(
+                Tag::Heading { .. } | Tag::CodeBlock(_) | Tag::Link { .. } | Tag::Image { .. },
+            ) => {
+                skipped += 1;
+            }
+            Event::End(TagEnd::Heading(_) | TagEnd::CodeBlock | TagEnd::Link | TagEnd::Image) => {
+                skipped = skipped.saturating_sub(1);
+            }
+            _ => {}
+        }
+        // Any non-text event ends the current run of prose.
+        flush(&mut text, &mut range, &mut verbatim);
+    }
+    flush(&mut text, &mut range, &mut verbatim);
+}
+
+/// Byte ranges of hashtag names (without `#`) in one run of prose.
+fn hashtag_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut word_start = 0;
+    for word in text.split_whitespace() {
+        // Why: `split_whitespace` yields subslices, so the offset is found by
+        // scanning forward from the previous word.
+        let offset = word_start + text[word_start..].find(word).unwrap_or_default();
+        word_start = offset + word.len();
+        if word.contains("://") || word.starts_with("www.") {
+            continue;
+        }
+        let mut previous: Option<char> = None;
+        let mut chars = word.char_indices().peekable();
+        while let Some((index, ch)) = chars.next() {
+            if ch == '#' && previous.is_none_or(opens_hashtag) {
+                let rest = &word[index + 1..];
+                let len = rest
+                    .char_indices()
+                    .find(|(_, ch)| !is_hashtag_char(*ch))
+                    .map_or(rest.len(), |(offset, _)| offset);
+                let candidate = &rest[..len];
+                if candidate.chars().any(char::is_alphabetic) {
+                    let start = offset + index + 1;
+                    spans.push((start, start + len));
+                }
+                while chars.peek().is_some_and(|(next, _)| *next <= index + len) {
+                    chars.next();
+                }
+                previous = candidate.chars().last().or(Some('#'));
+                continue;
+            }
+            previous = Some(ch);
+        }
+    }
+    spans
+}
+
+fn opens_hashtag(ch: char) -> bool {
+    matches!(ch, '(' | '"' | '\'' | ',' | ';')
+}
+
+fn is_hashtag_char(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, '_' | '-' | '/')
+}
@@ -0,0 +1,21 @@
+//! Markdown source analysis shared by note use-cases.
+//!
+//! # Responsibility
+//! - Extract structured references, task-list items, inline hashtags and
+//!   front-matter metadata from raw note markdown.
+//! - Derive note preview projections (title, summary, image, reading stats).
+//! - Expand note template placeholders.
+//! - Render note markdown to sanitized HTML and plain text.
+//! - Keep parsing pure (no I/O) so services and repositories can share it.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+pub mod blocks;
+pub mod checklist;
+pub mod front_matter;
+pub mod hashtags;
+pub mod preview;
+pub mod render;
+pub mod template;
+pub mod wiki_links;
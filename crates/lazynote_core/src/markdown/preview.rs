@@ -0,0 +1,300 @@
+//! Markdown preview projection shared by note writes.
+//!
+//! # Responsibility
+//! - Derive title, preview text/image and reading stats from note markdown.
+//!
+//! # Invariants
+//! - A leading front-matter block never contributes to the preview.
+//! - Derivation is pure, so repositories can refresh stored projections in
+//!   the same transaction as a content write.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::markdown::front_matter::strip_front_matter;
+use once_cell::sync::Lazy;
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+
+static HTML_IMAGE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)<img\b[^>]*?\bsrc\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+))"#)
+        .expect("valid html image regex")
+});
+
+/// Maximum characters kept in `preview_text`.
+const PREVIEW_TEXT_CHARS: usize = 100;
+/// Reading speed used for `reading_time_minutes`.
+pub const READING_WORDS_PER_MINUTE: u32 = 200;
+
+/// Markdown-derived preview projection for notes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownPreview {
+    /// Text of the first heading.
+    pub title: Option<String>,
+    /// Sanitized summary text.
+    pub preview_text: Option<String>,
+    /// First image source.
+    pub preview_image: Option<String>,
+    /// Words in rendered text.
+    pub word_count: u32,
+    /// Estimated reading time, rounded up to whole minutes.
+    pub reading_time_minutes: u32,
+}
+
+/// Derives note preview fields from markdown content.
+///
+/// Rules:
+/// - A leading front-matter block is ignored.
+/// - `title`: text of the first heading.
+/// - `preview_text`: first non-empty paragraph (image alt text excluded),
+///   whitespace normalized, first 100 chars retained; falls back to the
+///   first non-heading block text when the note has no paragraph.
+/// - `preview_image`: first image source, including reference-style
+///   images and raw HTML `<img src=...>`.
+/// - `word_count`: words of rendered text; each CJK character counts as one.
+/// - `reading_time_minutes`: `word_count / 200` rounded up.
+pub fn derive_markdown_preview(content: &str) -> MarkdownPreview {
+    let body = strip_front_matter(content);
+    let mut title: Option<String> = None;
+    let mut first_paragraph: Option<String> = None;
+    let mut fallback_text = String::new();
+    let mut preview_image: Option<String> = None;
+    let mut words = WordCounter::default();
+
+    let mut heading: Option<String> = None;
+    let mut paragraph: Option<String> = None;
+    let mut image_depth = 0_usize;
+    for event in Parser::new_ext(body, preview_parse_options()) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => heading = Some(String::new()),
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(text) = heading.take() {
+                    if title.is_none() {
+                        title = normalize_preview_text(text.as_str(), usize::MAX);
+                    }
+                }
+                words.break_word();
+            }
+            Event::Start(Tag::Paragraph) => paragraph = Some(String::new()),
+            Event::End(TagEnd::Paragraph) => {
+                if let Some(text) = paragraph.take() {
+                    if first_paragraph.is_none() {
+                        first_paragraph = normalize_preview_text(text.as_str(), PREVIEW_TEXT_CHARS);
+                    }
+                }
+                words.break_word();
+            }
+            Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                ..
+            }) => {
+                image_depth += 1;
+                if preview_image.is_none() && !matches!(link_type, LinkType::WikiLink { .. }) {
+                    preview_image = Some(dest_url.trim().to_string()).filter(|url| !url.is_empty());
+                }
+            }
+            Event::End(TagEnd::Image) => image_depth = image_depth.saturating_sub(1),
+            Event::Html(raw) | Event::InlineHtml(raw) if preview_image.is_none() => {
+                preview_image = html_image_source(raw.as_ref());
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if image_depth > 0 {
+                    continue;
+                }
+                words.push(text.as_ref());
+                if let Some(buffer) = heading.as_mut() {
+                    buffer.push_str(text.as_ref());
+                    continue;
+                }
+                if let Some(buffer) = paragraph.as_mut() {
+                    buffer.push_str(text.as_ref());
+                }
+                fallback_text.push_str(text.as_ref());
+                fallback_text.push(' ');
+            }
+            Event::SoftBreak | Event::HardBreak => {
+                words.break_word();
+                for buffer in [heading.as_mut(), paragraph.as_mut()].into_iter().flatten() {
+                    buffer.push(' ');
+                }
+            }
+            Event::Start(_) | Event::End(_) => words.break_word(),
+            _ => {}
+        }
+    }
+
+    let preview_text = first_paragraph
+        .or_else(|| normalize_preview_text(fallback_text.as_str(), PREVIEW_TEXT_CHARS));
+    let word_count = words.count;
+    MarkdownPreview {
+        title,
+        preview_text,
+        preview_image,
+        word_count,
+        reading_time_minutes: word_count.div_ceil(READING_WORDS_PER_MINUTE),
+    }
+}
+
+fn preview_parse_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_WIKILINKS
+}
+
+fn normalize_preview_text(text: &str, max_chars: usize) -> Option<String> {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if normalized.is_empty() {
+        return None;
+    }
+    Some(normalized.chars().take(max_chars).collect())
+}
+
+fn html_image_source(raw: &str) -> Option<String> {
+    let caps = HTML_IMAGE_RE.captures(raw)?;
+    caps.iter()
+        .skip(1)
+        .flatten()
+        .map(|value| value.as_str().trim().to_string())
+        .find(|value| !value.is_empty())
+}
+
+/// Streaming word counter over rendered text fragments.
+#[derive(Default)]
+struct WordCounter {
+    count: u32,
+    in_word: bool,
+}
+
+impl WordCounter {
+    fn push(&mut self, text: &str) {
+        for ch in text.chars() {
+            if is_cjk(ch) {
+                self.count += 1;
+                self.in_word = false;
+            } else if ch.is_alphanumeric() {
+                if !self.in_word {
+                    self.count += 1;
+                    self.in_word = true;
+                }
+            } else if ch.is_whitespace() {
+                self.in_word = false;
+            }
+        }
+    }
+
+    fn break_word(&mut self) {
+        self.in_word = false;
+    }
+}
+
+fn is_cjk(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::derive_markdown_preview;
+
+    #[test]
+    fn preview_extracts_first_image_path() {
+        let preview = derive_markdown_preview("x ![a](one.png) y ![b](two.png)");
+        assert_eq!(preview.preview_image.as_deref(), Some("one.png"));
+    }
+
+    #[test]
+    fn preview_strips_markdown_symbols_and_limits_length() {
+        let source = "# title\n\n- [link](https://example.com)\n**bold** `code`";
+        let preview = derive_markdown_preview(source);
+        let text = preview.preview_text.expect("preview_text should exist");
+        assert!(!text.contains('#'));
+        assert!(!text.contains('*'));
+        assert!(text.len() <= 100);
+    }
+
+    #[test]
+    fn preview_handles_complex_markdown_and_unicode_text() {
+        let source = r#"
+# title heading
+
+> quote line
+
+![cover]( https://cdn.example.com/first.png )
+Paragraph with [ref](https://example.com/path?q=1) and **bold** + `code`.
+
+![second](two.png)
+"#;
+        let preview = derive_markdown_preview(source);
+        assert_eq!(
+            preview.preview_image.as_deref(),
+            Some("https://cdn.example.com/first.png")
+        );
+        assert_eq!(preview.title.as_deref(), Some("title heading"));
+        assert_eq!(preview.preview_text.as_deref(), Some("quote line"));
+    }
+
+    #[test]
+    fn preview_resolves_reference_and_html_images() {
+        let reference = derive_markdown_preview("Intro\n\n![cover][img]\n\n[img]: refs/cover.png");
+        assert_eq!(reference.preview_image.as_deref(), Some("refs/cover.png"));
+
+        let html = derive_markdown_preview("Intro <img alt=\"x\" src='inline.png'> text");
+        assert_eq!(html.preview_image.as_deref(), Some("inline.png"));
+
+        let block = derive_markdown_preview("<p align=\"center\">\n<img src=\"block.png\">\n</p>");
+        assert_eq!(block.preview_image.as_deref(), Some("block.png"));
+
+        let wiki = derive_markdown_preview("![[Embedded Note]] and ![real](real.png)");
+        assert_eq!(wiki.preview_image.as_deref(), Some("real.png"));
+    }
+
+    #[test]
+    fn preview_keeps_code_and_tables_intact() {
+        let source = "```rust\nlet x = a * b;\n```\n\n| a_b | c |\n|---|---|\n| 1 | 2 |\n\nUse `snake_case` names.";
+        let preview = derive_markdown_preview(source);
+        assert!(preview.title.is_none());
+        assert_eq!(
+            preview.preview_text.as_deref(),
+            Some("Use snake_case names.")
+        );
+
+        let code_only = derive_markdown_preview("```\nlet x = a * b;\n```");
+        assert_eq!(code_only.preview_text.as_deref(), Some("let x = a * b;"));
+    }
+
+    #[test]
+    fn preview_counts_words_and_reading_time() {
+        let preview =
+            derive_markdown_preview("---\ntitle: Skip me\n---\n# Hello world\n\nit's 中文字 ok");
+        assert_eq!(preview.title.as_deref(), Some("Hello world"));
+        assert_eq!(preview.word_count, 7);
+        assert_eq!(preview.reading_time_minutes, 1);
+
+        let long = derive_markdown_preview("word ".repeat(401).as_str());
+        assert_eq!(long.word_count, 401);
+        assert_eq!(long.reading_time_minutes, 3);
+        assert_eq!(
+            long.preview_text.map(|text| text.chars().count()),
+            Some(100)
+        );
+
+        let empty = derive_markdown_preview("");
+        assert_eq!(empty.word_count, 0);
+        assert_eq!(empty.reading_time_minutes, 0);
+    }
+
+    #[test]
+    fn preview_returns_none_for_symbol_only_content() {
+        let preview = derive_markdown_preview("### *** ``` ~~ []() ![]()");
+        assert!(preview.preview_text.is_none());
+        assert!(preview.preview_image.is_none());
+    }
+}
@@ -0,0 +1,436 @@
+//! CommonMark + GFM rendering to sanitized HTML and plain text.
+//!
+//! # Responsibility
+//! - Render note markdown (tables, task lists, strikethrough, footnotes,
+//!   `[[wiki links]]`) identically for every client surface.
+//! - Resolve wiki-link targets, `![[note#^block]]` embeds and
+//!   `attachment:<id>` URLs through a [`RenderResolver`].
+//!
+//! # Invariants
+//! - Raw HTML in the source is escaped, never passed through.
+//! - Link and image URLs with schemes outside [`SAFE_URL_SCHEMES`] are
+//!   dropped.
+//! - A leading front-matter block is not rendered.
+//! - Embedded content never expands further embeds.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::markdown::front_matter::strip_front_matter;
+use crate::markdown::wiki_links::split_wiki_link_fragment;
+use pulldown_cmark::{CowStr, Event, LinkType, Options, Parser, Tag, TagEnd};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// URL schemes allowed in rendered `href`/`src` attributes.
+pub const SAFE_URL_SCHEMES: &[&str] = &["http", "https", "mailto", "tel", "lazynote"];
+/// URL scheme marking attachment references in note markdown.
+pub const ATTACHMENT_URL_SCHEME: &str = "attachment";
+/// URL prefix used for resolved note links.
+pub const NOTE_URL_PREFIX: &str = "lazynote://note/";
+/// URL prefix used for attachment references without an explicit mapping.
+pub const ATTACHMENT_URL_PREFIX: &str = "lazynote://attachment/";
+
+/// Resolves note-specific references while rendering.
+pub trait RenderResolver {
+    /// Returns the href for a `[[target]]` link, or `None` when it matches
+    /// no note.
+    fn resolve_wiki_link(&self, target: &str) -> Option<String>;
+
+    /// Returns the URL for an `attachment:<id>` reference.
+    ///
+    /// Defaults to `lazynote://attachment/<id>` for uuid ids.
+    fn resolve_attachment(&self, attachment_id: &str) -> Option<String> {
+        default_attachment_url(attachment_id)
+    }
+
+    /// Returns markdown to transclude for a `![[target]]` embed, e.g. the
+    /// source of `^block` in `![[Note#^block]]`.
+    ///
+    /// Defaults to `None`, rendering the embed as a plain wiki link.
+    fn resolve_embed(&self, _target: &str) -> Option<String> {
+        None
+    }
+}
+
+/// Lookup-table resolver keyed by link target and attachment id.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkTable {
+    note_urls: HashMap<String, String>,
+    attachment_urls: HashMap<String, String>,
+    embeds: HashMap<String, String>,
+}
+
+impl LinkTable {
+    /// Creates an empty table; every wiki link renders as unresolved.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps a wiki-link target (case-insensitive) to an href.
+    pub fn insert_note(&mut self, target: &str, url: impl Into<String>) {
+        self.note_urls
+            .insert(target.trim().to_lowercase(), url.into());
+    }
+
+    /// Maps an attachment id to a URL, e.g. a `file://` blob path.
+    pub fn insert_attachment(&mut self, attachment_id: &str, url: impl Into<String>) {
+        self.attachment_urls
+            .insert(attachment_id.trim().to_lowercase(), url.into());
+    }
+
+    /// Maps an embed target (case-insensitive, e.g. `Note#^block`) to the
+    /// markdown it transcludes.
+    pub fn insert_embed(&mut self, target: &str, markdown: impl Into<String>) {
+        self.embeds
+            .insert(target.trim().to_lowercase(), markdown.into());
+    }
+}
+
+impl RenderResolver for LinkTable {
+    /// Looks up the full target first, then its note part without
+    /// `#fragment`.
+    fn resolve_wiki_link(&self, target: &str) -> Option<String> {
+        self.note_urls
+            .get(&target.trim().to_lowercase())
+            .or_else(|| {
+                let note = split_wiki_link_fragment(target).0;
+                self.note_urls.get(&note.to_lowercase())
+            })
+            .cloned()
+    }
+
+    fn resolve_attachment(&self, attachment_id: &str) -> Option<String> {
+        self.attachment_urls
+            .get(&attachment_id.trim().to_lowercase())
+            .cloned()
+            .or_else(|| default_attachment_url(attachment_id))
+    }
+
+    fn resolve_embed(&self, target: &str) -> Option<String> {
+        self.embeds.get(&target.trim().to_lowercase()).cloned()
+    }
+}
+
+/// Resolver for transcluded content: links resolve as in the outer note,
+/// nested embeds render as plain wiki links so embeds cannot recurse.
+struct EmbedScope<'a>(&'a dyn RenderResolver);
+
+impl RenderResolver for EmbedScope<'_> {
+    fn resolve_wiki_link(&self, target: &str) -> Option<String> {
+        self.0.resolve_wiki_link(target)
+    }
+
+    fn resolve_attachment(&self, attachment_id: &str) -> Option<String> {
+        self.0.resolve_attachment(attachment_id)
+    }
+}
+
+/// Returns the href used for links to one note.
+pub fn note_url(atom_id: Uuid) -> String {
+    format!("{NOTE_URL_PREFIX}{atom_id}")
+}
+
+/// Renders markdown to sanitized HTML.
+///
+/// Resolved wiki links become `<a class="wiki-link" href=...>`; unresolved
+/// ones become `<span class="wiki-link unresolved" data-target=...>`.
+/// `![[target]]` embeds the resolver can transclude render as
+/// `<div class="note-embed" data-target=...>` around the embedded markdown.
+pub fn render_html(content: &str, resolver: &dyn RenderResolver) -> String {
+    let body = strip_front_matter(content);
+    let mut closers: Vec<Option<&'static str>> = Vec::new();
+    // Set while the alt-text events of a transcluded embed are skipped.
+    let mut in_embed = false;
+    let events = Parser::new_ext(body, render_options()).filter_map(|event| {
+        if in_embed {
+            in_embed = !matches!(event, Event::End(TagEnd::Image));
+            return None;
+        }
+        Some(match event {
+            Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+            Event::Start(Tag::Image {
+                link_type: LinkType::WikiLink { .. },
+                dest_url,
+                ..
+            }) if resolver.resolve_embed(dest_url.as_ref()).is_some() => {
+                let markdown = resolver
+                    .resolve_embed(dest_url.as_ref())
+                    .unwrap_or_default();
+                in_embed = true;
+                Event::Html(
+                    format!(
+                        "<div class=\"note-embed\" data-target=\"{}\">{}</div>",
+                        escape_attr(dest_url.as_ref()),
+                        render_html(markdown.as_str(), &EmbedScope(resolver)).trim_end()
+                    )
+                    .into(),
+                )
+            }
+            Event::Start(Tag::Link {
+                link_type: LinkType::WikiLink { .. },
+                dest_url,
+                ..
+            })
+            | Event::Start(Tag::Image {
+                link_type: LinkType::WikiLink { .. },
+                dest_url,
+                ..
+            }) => {
+                let (open, close) = wiki_link_html(dest_url.as_ref(), resolver);
+                closers.push(Some(close));
+                Event::Html(open.into())
+            }
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => {
+                closers.push(None);
+                Event::Start(Tag::Link {
+                    link_type,
+                    dest_url: sanitize_url(dest_url, resolver),
+                    title,
+                    id,
+                })
+            }
+            Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => {
+                closers.push(None);
+                Event::Start(Tag::Image {
+                    link_type,
+                    dest_url: sanitize_url(dest_url, resolver),
+                    title,
+                    id,
+                })
+            }
+            Event::End(end @ (TagEnd::Link | TagEnd::Image)) => match closers.pop().flatten() {
+                Some(close) => Event::Html(close.into()),
+                None => Event::End(end),
+            },
+            other => other,
+        })
+    });
+
+    let mut html = String::with_capacity(body.len() * 3 / 2);
+    pulldown_cmark::html::push_html(&mut html, events);
+    html
+}
+
+/// Renders markdown to plain text.
+///
+/// Blocks are separated by blank lines, list items get `- ` (tasks add
+/// `[x] ` / `[ ] `), table cells are tab-separated, and links keep only
+/// their label. Raw HTML is dropped.
+pub fn render_plain_text(content: &str) -> String {
+    let body = strip_front_matter(content);
+    let mut out = PlainTextWriter::default();
+    for event in Parser::new_ext(body, render_options()) {
+        match event {
+            Event::Start(tag) => out.start(tag),
+            Event::End(tag) => out.end(tag),
+            Event::Text(text) | Event::Code(text) => out.push(text.as_ref()),
+            Event::InlineMath(text) | Event::DisplayMath(text) => out.push(text.as_ref()),
+            Event::SoftBreak => out.push(" "),
+            Event::HardBreak => out.newline(),
+            Event::Rule => {
+                out.block_break();
+                out.push("---");
+                out.block_break();
+            }
+            Event::TaskListMarker(checked) => out.push(if checked { "[x] " } else { "[ ] " }),
+            Event::FootnoteReference(label) => {
+                out.push("[");
+                out.push(label.as_ref());
+                out.push("]");
+            }
+            Event::Html(_) | Event::InlineHtml(_) => {}
+        }
+    }
+    out.finish()
+}
+
+fn default_attachment_url(attachment_id: &str) -> Option<String> {
+    Uuid::parse_str(attachment_id.trim())
+        .ok()
+        .map(|id| format!("{ATTACHMENT_URL_PREFIX}{id}"))
+}
+
+fn render_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_GFM
+        | Options::ENABLE_WIKILINKS
+}
+
+fn wiki_link_html(target: &str, resolver: &dyn RenderResolver) -> (String, &'static str) {
+    match resolver
+        .resolve_wiki_link(target)
+        .and_then(|url| safe_url(url.as_str()).then_some(url))
+    {
+        Some(url) => (
+            format!(
+                "<a class=\"wiki-link\" href=\"{}\">",
+                escape_attr(url.as_str())
+            ),
+            "</a>",
+        ),
+        None => (
+            format!(
+                "<span class=\"wiki-link unresolved\" data-target=\"{}\">",
+                escape_attr(target)
+            ),
+            "</span>",
+        ),
+    }
+}
+
+fn sanitize_url<'a>(url: CowStr<'a>, resolver: &dyn RenderResolver) -> CowStr<'a> {
+    if let Some(attachment_id) = attachment_id(url.as_ref()) {
+        return resolver
+            .resolve_attachment(attachment_id)
+            .filter(|resolved| safe_url(resolved.as_str()) || resolved.starts_with("file:"))
+            .map(CowStr::from)
+            .unwrap_or_else(|| CowStr::Borrowed(""));
+    }
+    if safe_url(url.as_ref()) {
+        url
+    } else {
+        CowStr::Borrowed("")
+    }
+}
+
+fn attachment_id(url: &str) -> Option<&str> {
+    let (scheme, rest) = url.split_once(':')?;
+    if !scheme.eq_ignore_ascii_case(ATTACHMENT_URL_SCHEME) {
+        return None;
+    }
+    let id = rest.trim_start_matches('/').trim();
+    (!id.is_empty()).then_some(id)
+}
+
+/// Relative URLs and anchors are safe; absolute URLs need an allowed scheme.
+fn safe_url(url: &str) -> bool {
+    let trimmed = url.trim();
+    let scheme_end = trimmed.find([':', '/', '?', '#']);
+    match scheme_end {
+        Some(index) if trimmed[index..].starts_with(':') => {
+            let scheme = &trimmed[..index];
+            SAFE_URL_SCHEMES
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(scheme))
+        }
+        _ => !trimmed.chars().any(char::is_control),
+    }
+}
+
+fn escape_attr(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[derive(Default)]
+struct PlainTextWriter {
+    text: String,
+    list_depth: usize,
+    pending_bullet: Option<String>,
+    cell_index: usize,
+}
+
+impl PlainTextWriter {
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph | Tag::Heading { .. } | Tag::BlockQuote(_) | Tag::CodeBlock(_)
+                if self.list_depth == 0 =>
+            {
+                self.block_break();
+            }
+            Tag::List(_) => {
+                if self.list_depth == 0 {
+                    self.block_break();
+                }
+                self.list_depth += 1;
+            }
+            Tag::Item => {
+                self.newline();
+                self.pending_bullet = Some(format!(
+                    "{}- ",
+                    "  ".repeat(self.list_depth.saturating_sub(1))
+                ));
+            }
+            Tag::Table(_) => self.block_break(),
+            Tag::TableHead | Tag::TableRow => {
+                self.newline();
+                self.cell_index = 0;
+            }
+            Tag::TableCell => {
+                if self.cell_index > 0 {
+                    self.text.push('\t');
+                }
+                self.cell_index += 1;
+            }
+            Tag::FootnoteDefinition(label) => {
+                self.block_break();
+                self.push("[");
+                self.push(label.as_ref());
+                self.push("]: ");
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::List(_) => {
+                self.list_depth = self.list_depth.saturating_sub(1);
+            }
+            TagEnd::Item => {
+                self.pending_bullet = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn push(&mut self, text: &str) {
+        if let Some(bullet) = self.pending_bullet.take() {
+            self.text.push_str(bullet.as_str());
+        }
+        self.text.push_str(text);
+    }
+
+    fn newline(&mut self) {
+        let trimmed = self.text.trim_end_matches([' ', '\t']).len();
+        self.text.truncate(trimmed);
+        if !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push('\n');
+        }
+    }
+
+    fn block_break(&mut self) {
+        self.newline();
+        if !self.text.is_empty() && !self.text.ends_with("\n\n") {
+            self.text.push('\n');
+        }
+    }
+
+    fn finish(self) -> String {
+        self.text.trim_end().to_string()
+    }
+}
@@ -0,0 +1,126 @@
+//! Note template placeholder expansion.
+//!
+//! # Responsibility
+//! - Find `{{name}}` placeholders in template source.
+//! - Expand built-in placeholders (`date`, `time`, `title`, `cursor`) and
+//!   caller-supplied prompt answers into note content.
+//!
+//! # Invariants
+//! - Built-in names are matched case-insensitively; every other name is a
+//!   custom prompt matched exactly (after trimming).
+//! - `{{date}}`/`{{time}}` render as `YYYY-MM-DD`/`HH:MM` in the caller's
+//!   UTC offset.
+//! - `{{cursor}}` renders as nothing; the first one marks the cursor offset.
+//! - Prompts without an answer render as empty text.
+//! - Text outside placeholders, including unmatched braces and blank
+//!   `{{ }}`, is kept verbatim.
+
+use crate::markdown::front_matter::civil_from_days;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+
+static PLACEHOLDER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{\{\s*([^{}\s](?:[^{}\n]*?[^{}\s])?)\s*\}\}").expect("valid placeholder regex")
+});
+
+/// Placeholder expanded to the current local date.
+pub const TEMPLATE_PLACEHOLDER_DATE: &str = "date";
+/// Placeholder expanded to the current local time.
+pub const TEMPLATE_PLACEHOLDER_TIME: &str = "time";
+/// Placeholder expanded to the requested note title.
+pub const TEMPLATE_PLACEHOLDER_TITLE: &str = "title";
+/// Placeholder marking where the editor cursor starts.
+pub const TEMPLATE_PLACEHOLDER_CURSOR: &str = "cursor";
+
+/// Values available while expanding one template.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateContext {
+    /// Value of `{{title}}`; empty when `None`.
+    pub title: Option<String>,
+    /// Instant used for `{{date}}` and `{{time}}`.
+    pub now_epoch_ms: i64,
+    /// Caller's offset from UTC, in minutes.
+    pub utc_offset_minutes: i32,
+    /// Answers to custom prompts, keyed by prompt name.
+    pub prompts: BTreeMap<String, String>,
+}
+
+/// Result of expanding one template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedTemplate {
+    /// Expanded content.
+    pub content: String,
+    /// Byte offset of the first `{{cursor}}` in `content`.
+    pub cursor: Option<usize>,
+}
+
+/// Expands every placeholder of `source` using `context`.
+pub fn render_template(source: &str, context: &TemplateContext) -> RenderedTemplate {
+    let local_ms = context
+        .now_epoch_ms
+        .saturating_add(i64::from(context.utc_offset_minutes) * 60_000);
+    let mut content = String::with_capacity(source.len());
+    let mut cursor = None;
+    let mut last = 0;
+    for captures in PLACEHOLDER_RE.captures_iter(source) {
+        let whole = captures.get(0).expect("match has group 0");
+        let name = captures.get(1).expect("match has group 1").as_str();
+        content.push_str(&source[last..whole.start()]);
+        last = whole.end();
+
+        match builtin_name(name) {
+            Some(TEMPLATE_PLACEHOLDER_DATE) => content.push_str(&format_local_date(local_ms)),
+            Some(TEMPLATE_PLACEHOLDER_TIME) => content.push_str(&format_local_time(local_ms)),
+            Some(TEMPLATE_PLACEHOLDER_TITLE) => {
+                content.push_str(context.title.as_deref().unwrap_or_default());
+            }
+            Some(_) => {
+                cursor.get_or_insert(content.len());
+            }
+            None => {
+                if let Some(answer) = context.prompts.get(name) {
+                    content.push_str(answer);
+                }
+            }
+        }
+    }
+    content.push_str(&source[last..]);
+
+    RenderedTemplate { content, cursor }
+}
+
+/// Lists custom prompt names of `source` in first-use order, without
+/// duplicates or built-in placeholders.
+pub fn template_prompts(source: &str) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    PLACEHOLDER_RE
+        .captures_iter(source)
+        .filter_map(|captures| captures.get(1))
+        .map(|name| name.as_str())
+        .filter(|name| builtin_name(name).is_none())
+        .filter(|name| seen.insert(*name))
+        .map(str::to_string)
+        .collect()
+}
+
+fn builtin_name(name: &str) -> Option<&'static str> {
+    [
+        TEMPLATE_PLACEHOLDER_DATE,
+        TEMPLATE_PLACEHOLDER_TIME,
+        TEMPLATE_PLACEHOLDER_TITLE,
+        TEMPLATE_PLACEHOLDER_CURSOR,
+    ]
+    .into_iter()
+    .find(|builtin| builtin.eq_ignore_ascii_case(name))
+}
+
+fn format_local_date(local_ms: i64) -> String {
+    let (year, month, day) = civil_from_days(local_ms.div_euclid(86_400_000));
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn format_local_time(local_ms: i64) -> String {
+    let minutes = local_ms.rem_euclid(86_400_000) / 60_000;
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
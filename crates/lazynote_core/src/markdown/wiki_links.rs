@@ -0,0 +1,179 @@
+//! Wiki-style `[[target]]` link parsing and rewriting.
+//!
+//! # Responsibility
+//! - Find `[[Note title]]`, `[[uuid]]` and `[[target|label]]` references.
+//! - Produce short context snippets around each reference.
+//! - Rewrite link targets when the referenced note title changes or a
+//!   missing note is created from a link.
+//!
+//! # Invariants
+//! - Links inside fenced code blocks are ignored.
+//! - Targets are trimmed; empty targets are not links.
+//! - Rewriting keeps labels, `#fragment` suffixes and surrounding text
+//!   byte-for-byte.
+
+use crate::model::atom::AtomId;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static WIKI_LINK_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[\[([^\[\]\|\n]+)(?:\|([^\[\]\n]*))?\]\]").expect("valid wiki link regex")
+});
+
+/// Characters kept on each side of a link in its context snippet.
+const CONTEXT_RADIUS_CHARS: usize = 60;
+
+/// One `[[...]]` reference found in markdown source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WikiLink {
+    /// Trimmed target text (note title or atom uuid).
+    pub target: String,
+    /// Optional display label after `|`.
+    pub label: Option<String>,
+    /// Byte offset of the opening `[[`.
+    pub start: usize,
+    /// Byte offset just past the closing `]]`.
+    pub end: usize,
+    /// Single-line excerpt around the link.
+    pub context: String,
+}
+
+/// Extracts wiki links from markdown source in document order.
+pub fn parse_wiki_links(content: &str) -> Vec<WikiLink> {
+    let mut links = Vec::new();
+    let mut in_fence = false;
+    let mut line_start = 0;
+    for line in content.split_inclusive('\n') {
+        let line_offset = line_start;
+        line_start += line.len();
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        for caps in WIKI_LINK_RE.captures_iter(line) {
+            let whole = caps.get(0).expect("group 0 always present");
+            let target = caps[1].trim();
+            if target.is_empty() {
+                continue;
+            }
+            let label = caps
+                .get(2)
+                .map(|value| value.as_str().trim().to_string())
+                .filter(|value| !value.is_empty());
+            links.push(WikiLink {
+                target: target.to_string(),
+                label,
+                start: line_offset + whole.start(),
+                end: line_offset + whole.end(),
+                context: context_snippet(line, whole.start(), whole.end()),
+            });
+        }
+    }
+    links
+}
+
+/// Splits a link target into its note part and optional `#fragment`
+/// (`Note#^block` -> `("Note", Some("^block"))`).
+pub fn split_wiki_link_fragment(target: &str) -> (&str, Option<&str>) {
+    match target.split_once('#') {
+        Some((note, fragment)) => (note.trim(), Some(fragment.trim())),
+        None => (target.trim(), None),
+    }
+}
+
+/// Replaces every link whose target equals `old_target` (case-insensitive)
+/// with `new_target`.
+///
+/// Returns `None` when no link matched, so callers can skip the write.
+pub fn rewrite_wiki_link_target(
+    content: &str,
+    old_target: &str,
+    new_target: &str,
+) -> Option<String> {
+    rewrite_matching_links(content, old_target, |link| {
+        let target = with_fragment(new_target, link);
+        match link.label.as_deref() {
+            Some(label) => format!("[[{target}|{label}]]"),
+            None => format!("[[{target}]]"),
+        }
+    })
+}
+
+/// Points every link whose target equals `old_target` (case-insensitive) at
+/// `atom_id`, keeping the visible text as label (`[[uuid|Old title]]`).
+///
+/// Returns `None` when no link matched.
+pub fn rewrite_wiki_link_to_atom(
+    content: &str,
+    old_target: &str,
+    atom_id: AtomId,
+) -> Option<String> {
+    rewrite_matching_links(content, old_target, |link| {
+        let label = link.label.as_deref().unwrap_or(link.target.as_str());
+        let target = with_fragment(atom_id.to_string().as_str(), link);
+        format!("[[{target}|{label}]]")
+    })
+}
+
+fn rewrite_matching_links(
+    content: &str,
+    old_target: &str,
+    render: impl Fn(&WikiLink) -> String,
+) -> Option<String> {
+    let old_lower = old_target.trim().to_lowercase();
+    let mut rewritten = String::with_capacity(content.len());
+    let mut cursor = 0;
+    let mut matched = false;
+    for link in parse_wiki_links(content) {
+        if split_wiki_link_fragment(link.target.as_str())
+            .0
+            .to_lowercase()
+            != old_lower
+        {
+            continue;
+        }
+        rewritten.push_str(&content[cursor..link.start]);
+        rewritten.push_str(render(&link).as_str());
+        cursor = link.end;
+        matched = true;
+    }
+    if !matched {
+        return None;
+    }
+    rewritten.push_str(&content[cursor..]);
+    Some(rewritten)
+}
+
+fn with_fragment(target: &str, link: &WikiLink) -> String {
+    match split_wiki_link_fragment(link.target.as_str()).1 {
+        Some(fragment) => format!("{target}#{fragment}"),
+        None => target.to_string(),
+    }
+}
+
+fn context_snippet(line: &str, start: usize, end: usize) -> String {
+    let before = line[..start].trim_start();
+    let after = line[end..].trim_end();
+    let before_chars = before.chars().count();
+    let before = if before_chars > CONTEXT_RADIUS_CHARS {
+        let skipped = before
+            .char_indices()
+            .nth(before_chars - CONTEXT_RADIUS_CHARS)
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        format!("...{}", &before[skipped..])
+    } else {
+        before.to_string()
+    };
+    let after = if after.chars().count() > CONTEXT_RADIUS_CHARS {
+        let kept = after.chars().take(CONTEXT_RADIUS_CHARS).collect::<String>();
+        format!("{kept}...")
+    } else {
+        after.to_string()
+    };
+    format!("{before}{}{after}", &line[start..end])
+}
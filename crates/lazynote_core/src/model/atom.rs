@@ -6,7 +6,8 @@
 //!
 //! # Invariants
 //! - `uuid` is stable and never reused for another atom.
-//! - `is_deleted` is the source of truth for tombstone state.
+//! - `is_deleted` is the source of truth for tombstone state; `is_archived`
+//!   only hides an atom from default views.
 //! - `end_at` should not be earlier than `start_at` when both are set.
 //!
 //! # See also
@@ -93,6 +94,12 @@ pub struct Atom {
     pub hlc_timestamp: Option<String>,
     /// Soft delete tombstone to preserve sync/recovery history.
     pub is_deleted: bool,
+    /// Hidden from default lists and search; not a tombstone.
+    pub is_archived: bool,
+    /// Sorted before unpinned atoms in lists.
+    pub is_pinned: bool,
+    /// User favorite.
+    pub is_starred: bool,
 }
 
 /// Validation errors for atom construction/deserialization/update boundaries.
@@ -131,6 +138,12 @@ struct AtomDe {
     recurrence_rule: Option<String>,
     hlc_timestamp: Option<String>,
     is_deleted: bool,
+    #[serde(default)]
+    is_archived: bool,
+    #[serde(default)]
+    is_pinned: bool,
+    #[serde(default)]
+    is_starred: bool,
 }
 
 impl TryFrom<AtomDe> for Atom {
@@ -149,6 +162,9 @@ impl TryFrom<AtomDe> for Atom {
             recurrence_rule: value.recurrence_rule,
             hlc_timestamp: value.hlc_timestamp,
             is_deleted: value.is_deleted,
+            is_archived: value.is_archived,
+            is_pinned: value.is_pinned,
+            is_starred: value.is_starred,
         };
         atom.validate()?;
         Ok(atom)
@@ -160,7 +176,7 @@ impl Atom {
     ///
     /// # Invariants
     /// - Optional projection fields are initialized to `None`.
-    /// - `is_deleted` starts as `false`.
+    /// - `is_deleted` and the archive/pin/star flags start as `false`.
     pub fn new(kind: AtomType, content: impl Into<String>) -> Self {
         Self {
             uuid: Uuid::new_v4(),
@@ -174,6 +190,9 @@ impl Atom {
             recurrence_rule: None,
             hlc_timestamp: None,
             is_deleted: false,
+            is_archived: false,
+            is_pinned: false,
+            is_starred: false,
         }
     }
 
@@ -211,6 +230,9 @@ impl Atom {
             recurrence_rule: None,
             hlc_timestamp: None,
             is_deleted: false,
+            is_archived: false,
+            is_pinned: false,
+            is_starred: false,
         };
         atom.validate()?;
         Ok(atom)
@@ -227,6 +249,8 @@ impl Atom {
     }
 
     /// Returns whether this Atom should be considered visible/active.
+    ///
+    /// Archived atoms are still active.
     pub fn is_active(&self) -> bool {
         !self.is_deleted
     }
@@ -13,10 +13,20 @@
 
 use crate::db::migrations::latest_version;
 use crate::db::DbError;
+use crate::markdown::preview::derive_markdown_preview;
 use crate::model::atom::{Atom, AtomId, AtomType, AtomValidationError, TaskStatus};
+use crate::repo::journal_repo::{self, JournalCommand};
+use crate::repo::note_repo::write_note_content_in_tx;
+use crate::repo::property_repo::{
+    self, push_property_filters, AtomProperty, PropertyFilter, PropertyValue, PROPERTY_SOURCE_USER,
+};
+use crate::repo::tag_filter::{push_tag_filter, TagFilter};
+use crate::repo::{note_task_repo, tag_repo};
 use log::{error, info, warn};
 use rusqlite::types::Value;
-use rusqlite::{params, params_from_iter, Connection, Row};
+use rusqlite::{
+    params, params_from_iter, Connection, OptionalExtension, Row, Transaction, TransactionBehavior,
+};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::time::Instant;
@@ -33,7 +43,10 @@ const ATOM_SELECT_SQL: &str = "SELECT
     end_at,
     recurrence_rule,
     hlc_timestamp,
-    is_deleted
+    is_deleted,
+    is_archived,
+    is_pinned,
+    is_starred
 FROM atoms";
 
 /// Result type used by atom repository operations.
@@ -62,6 +75,14 @@ pub enum RepoError {
     },
     /// Persisted row exists but cannot be converted into a valid atom.
     InvalidData(String),
+    /// Property key, value or filter predicate is not acceptable.
+    InvalidProperty(String),
+    /// Tag filter expression has blank tags or empty groups.
+    InvalidTagFilter(String),
+    /// Tag path is blank or a tag operation is not acceptable.
+    InvalidTag(String),
+    /// Note template name is blank or already taken.
+    InvalidTemplate(String),
 }
 
 impl Display for RepoError {
@@ -85,6 +106,10 @@ impl Display for RepoError {
                 "repository requires column `{column}` in table `{table}`, but it was not found"
             ),
             Self::InvalidData(message) => write!(f, "invalid persisted atom data: {message}"),
+            Self::InvalidProperty(message) => write!(f, "invalid atom property: {message}"),
+            Self::InvalidTagFilter(message) => write!(f, "invalid tag filter: {message}"),
+            Self::InvalidTag(message) => write!(f, "invalid tag: {message}"),
+            Self::InvalidTemplate(message) => write!(f, "invalid note template: {message}"),
         }
     }
 }
@@ -99,6 +124,10 @@ impl Error for RepoError {
             Self::MissingRequiredTable(_) => None,
             Self::MissingRequiredColumn { .. } => None,
             Self::InvalidData(_) => None,
+            Self::InvalidProperty(_) => None,
+            Self::InvalidTagFilter(_) => None,
+            Self::InvalidTag(_) => None,
+            Self::InvalidTemplate(_) => None,
         }
     }
 }
@@ -144,6 +173,9 @@ const SECTION_SELECT_SQL: &str = "SELECT
     recurrence_rule,
     hlc_timestamp,
     is_deleted,
+    is_archived,
+    is_pinned,
+    is_starred,
     updated_at
 FROM atoms";
 
@@ -158,6 +190,48 @@ pub struct AtomListQuery {
     pub limit: Option<u32>,
     /// Number of rows to skip from the sorted result set.
     pub offset: u32,
+    /// Property predicates; all must match.
+    pub properties: Vec<PropertyFilter>,
+    /// Whether archived rows should be included.
+    pub include_archived: bool,
+    /// `Some(value)` keeps only atoms whose pin flag equals `value`.
+    pub pinned: Option<bool>,
+    /// `Some(value)` keeps only atoms whose star flag equals `value`.
+    pub starred: Option<bool>,
+}
+
+/// Optional restrictions shared by section and calendar fetches.
+#[derive(Debug, Clone, Default)]
+pub struct SectionFilter {
+    /// Boolean tag expression rows must match.
+    pub tags: Option<TagFilter>,
+    /// Whether archived atoms are included.
+    pub include_archived: bool,
+    /// `Some(value)` keeps only atoms whose pin flag equals `value`.
+    pub pinned: Option<bool>,
+    /// `Some(value)` keeps only atoms whose star flag equals `value`.
+    pub starred: Option<bool>,
+}
+
+/// User-controlled boolean state of an atom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomFlag {
+    /// Hidden from default lists and search (`is_archived`).
+    Archived,
+    /// Sorted first in lists (`is_pinned`).
+    Pinned,
+    /// Favorite (`is_starred`).
+    Starred,
+}
+
+impl AtomFlag {
+    fn column(self) -> &'static str {
+        match self {
+            Self::Archived => "is_archived",
+            Self::Pinned => "is_pinned",
+            Self::Starred => "is_starred",
+        }
+    }
 }
 
 /// Repository interface for atom CRUD operations.
@@ -219,14 +293,37 @@ pub trait AtomRepository {
         offset: u32,
     ) -> RepoResult<Vec<SectionAtomRow>>;
 
+    /// Sets one archive/pin/star flag of an active atom.
+    ///
+    /// Idempotent. Returns [`RepoError::NotFound`] for missing or deleted
+    /// atoms.
+    fn set_atom_flag(&self, id: AtomId, flag: AtomFlag, value: bool) -> RepoResult<()>;
+
     /// Updates only `start_at` and `end_at` for a calendar event.
     /// Validates `end_at >= start_at`; returns `RepoError::Validation(InvalidEventWindow)` on failure.
     fn update_event_times(&self, id: AtomId, start_at: i64, end_at: i64) -> RepoResult<()>;
+
+    /// Inserts or replaces one typed property of an active atom.
+    ///
+    /// Returns [`RepoError::NotFound`] for missing atoms and
+    /// [`RepoError::InvalidProperty`] for blank keys, non-finite numbers or
+    /// references to missing atoms.
+    fn set_property(&self, id: AtomId, key: &str, value: &PropertyValue) -> RepoResult<()>;
+
+    /// Loads one property by key.
+    fn get_property(&self, id: AtomId, key: &str) -> RepoResult<Option<AtomProperty>>;
+
+    /// Lists all properties of one atom ordered by key.
+    fn list_properties(&self, id: AtomId) -> RepoResult<Vec<AtomProperty>>;
+
+    /// Removes one property; returns whether it existed.
+    fn remove_property(&self, id: AtomId, key: &str) -> RepoResult<bool>;
 }
 
 /// SQLite-backed atom repository.
 pub struct SqliteAtomRepository<'conn> {
     conn: &'conn Connection,
+    section_filter: SectionFilter,
 }
 
 impl<'conn> SqliteAtomRepository<'conn> {
@@ -240,60 +337,60 @@ impl<'conn> SqliteAtomRepository<'conn> {
     ///   incomplete.
     pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
         ensure_connection_ready(conn)?;
-        Ok(Self { conn })
+        Ok(Self {
+            conn,
+            section_filter: SectionFilter::default(),
+        })
+    }
+
+    /// Restricts section and calendar fetches to rows matching `filter`.
+    pub fn with_section_filter(mut self, filter: SectionFilter) -> Self {
+        self.section_filter = filter;
+        self
+    }
+
+    /// Runs one section query.
+    ///
+    /// `binds` must start with `limit` and `offset` (`?1`, `?2`), followed by
+    /// the parameters referenced in `where_sql`; tag filter parameters are
+    /// numbered after them.
+    fn query_section(
+        &self,
+        where_sql: &str,
+        order_sql: &str,
+        mut binds: Vec<Value>,
+    ) -> RepoResult<Vec<SectionAtomRow>> {
+        let filter = &self.section_filter;
+        let mut sql = format!("{SECTION_SELECT_SQL}\n             {where_sql}");
+        push_atom_flag_filters(
+            &mut sql,
+            "atoms.",
+            filter.include_archived,
+            filter.pinned,
+            filter.starred,
+        );
+        if let Some(tags) = filter.tags.as_ref() {
+            push_tag_filter(&mut sql, &mut binds, "atoms.uuid", tags)?;
+        }
+        sql.push_str(&format!(
+            "\n             {order_sql}\n             LIMIT ?1 OFFSET ?2"
+        ));
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query(params_from_iter(binds.iter()))?;
+        let mut result = Vec::new();
+        while let Some(row) = rows.next()? {
+            result.push(parse_section_atom_row(row)?);
+        }
+        Ok(result)
     }
 }
 
 impl AtomRepository for SqliteAtomRepository<'_> {
     fn create_atom(&self, atom: &Atom) -> RepoResult<AtomId> {
         let started_at = Instant::now();
-        if let Err(err) = atom.validate() {
-            warn!(
-                "event=atom_create module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=validation_error",
-                atom.uuid,
-                atom_type_to_db(atom.kind),
-                started_at.elapsed().as_millis()
-            );
-            return Err(err.into());
-        }
-
-        if let Err(err) = self.conn.execute(
-            "INSERT INTO atoms (
-                uuid,
-                type,
-                content,
-                preview_text,
-                preview_image,
-                task_status,
-                start_at,
-                end_at,
-                recurrence_rule,
-                hlc_timestamp,
-                is_deleted
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11);",
-            params![
-                atom.uuid.to_string(),
-                atom_type_to_db(atom.kind),
-                atom.content.as_str(),
-                atom.preview_text.as_deref(),
-                atom.preview_image.as_deref(),
-                atom.task_status.map(task_status_to_db),
-                atom.start_at,
-                atom.end_at,
-                atom.recurrence_rule.as_deref(),
-                atom.hlc_timestamp.as_deref(),
-                bool_to_int(atom.is_deleted),
-            ],
-        ) {
-            error!(
-                "event=atom_create module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=db_write_failed error={}",
-                atom.uuid,
-                atom_type_to_db(atom.kind),
-                started_at.elapsed().as_millis(),
-                err
-            );
-            return Err(err.into());
-        }
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        create_atom_in_tx(&tx, atom)?;
+        tx.commit()?;
 
         info!(
             "event=atom_create module=repo status=ok atom_id={} atom_type={} duration_ms={}",
@@ -307,67 +404,9 @@ impl AtomRepository for SqliteAtomRepository<'_> {
 
     fn update_atom(&self, atom: &Atom) -> RepoResult<()> {
         let started_at = Instant::now();
-        if let Err(err) = atom.validate() {
-            warn!(
-                "event=atom_update module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=validation_error",
-                atom.uuid,
-                atom_type_to_db(atom.kind),
-                started_at.elapsed().as_millis()
-            );
-            return Err(err.into());
-        }
-
-        let changed = match self.conn.execute(
-            "UPDATE atoms
-             SET
-                type = ?1,
-                content = ?2,
-                preview_text = ?3,
-                preview_image = ?4,
-                task_status = ?5,
-                start_at = ?6,
-                end_at = ?7,
-                recurrence_rule = ?8,
-                hlc_timestamp = ?9,
-                is_deleted = ?10,
-                updated_at = (strftime('%s', 'now') * 1000)
-             WHERE uuid = ?11;",
-            params![
-                atom_type_to_db(atom.kind),
-                atom.content.as_str(),
-                atom.preview_text.as_deref(),
-                atom.preview_image.as_deref(),
-                atom.task_status.map(task_status_to_db),
-                atom.start_at,
-                atom.end_at,
-                atom.recurrence_rule.as_deref(),
-                atom.hlc_timestamp.as_deref(),
-                bool_to_int(atom.is_deleted),
-                atom.uuid.to_string(),
-            ],
-        ) {
-            Ok(changed) => changed,
-            Err(err) => {
-                error!(
-                    "event=atom_update module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=db_write_failed error={}",
-                    atom.uuid,
-                    atom_type_to_db(atom.kind),
-                    started_at.elapsed().as_millis(),
-                    err
-                );
-                return Err(err.into());
-            }
-        };
-
-        if changed == 0 {
-            warn!(
-                "event=atom_update module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=not_found",
-                atom.uuid,
-                atom_type_to_db(atom.kind),
-                started_at.elapsed().as_millis()
-            );
-            return Err(RepoError::NotFound(atom.uuid));
-        }
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        update_atom_in_tx(&tx, atom)?;
+        tx.commit()?;
 
         info!(
             "event=atom_update module=repo status=ok atom_id={} atom_type={} duration_ms={}",
@@ -407,7 +446,16 @@ impl AtomRepository for SqliteAtomRepository<'_> {
             bind_values.push(Value::Text(atom_type_to_db(kind).to_string()));
         }
 
-        sql.push_str(" ORDER BY updated_at DESC, uuid ASC");
+        push_atom_flag_filters(
+            &mut sql,
+            "atoms.",
+            query.include_archived,
+            query.pinned,
+            query.starred,
+        );
+        push_property_filters(&mut sql, &mut bind_values, "atoms.uuid", &query.properties)?;
+
+        sql.push_str(" ORDER BY is_pinned DESC, updated_at DESC, uuid ASC");
 
         if let Some(limit) = query.limit {
             sql.push_str(" LIMIT ?");
@@ -482,22 +530,15 @@ impl AtomRepository for SqliteAtomRepository<'_> {
     }
 
     fn fetch_inbox(&self, limit: u32, offset: u32) -> RepoResult<Vec<SectionAtomRow>> {
-        let sql = format!(
-            "{SECTION_SELECT_SQL}
-             WHERE start_at IS NULL
+        let where_sql = "WHERE start_at IS NULL
                AND end_at IS NULL
                AND (task_status IS NULL OR task_status NOT IN ('done', 'cancelled'))
-               AND is_deleted = 0
-             ORDER BY updated_at DESC, uuid ASC
-             LIMIT ?1 OFFSET ?2"
-        );
-        let mut stmt = self.conn.prepare(&sql)?;
-        let mut rows = stmt.query(params![limit, offset])?;
-        let mut result = Vec::new();
-        while let Some(row) = rows.next()? {
-            result.push(parse_section_atom_row(row)?);
-        }
-        Ok(result)
+               AND is_deleted = 0";
+        self.query_section(
+            where_sql,
+            "ORDER BY is_pinned DESC, updated_at DESC, uuid ASC",
+            vec![Value::from(limit), Value::from(offset)],
+        )
     }
 
     fn fetch_today(
@@ -507,26 +548,24 @@ impl AtomRepository for SqliteAtomRepository<'_> {
         limit: u32,
         offset: u32,
     ) -> RepoResult<Vec<SectionAtomRow>> {
-        let sql = format!(
-            "{SECTION_SELECT_SQL}
-             WHERE is_deleted = 0
+        let where_sql = "WHERE is_deleted = 0
                AND (task_status IS NULL OR task_status NOT IN ('done', 'cancelled'))
                AND (
-                 (end_at IS NOT NULL AND end_at <= ?1 AND start_at IS NULL)
-                 OR (start_at IS NOT NULL AND end_at IS NULL AND start_at <= ?1)
+                 (end_at IS NOT NULL AND end_at <= ?3 AND start_at IS NULL)
+                 OR (start_at IS NOT NULL AND end_at IS NULL AND start_at <= ?3)
                  OR (start_at IS NOT NULL AND end_at IS NOT NULL
-                     AND start_at <= ?1 AND end_at >= ?2)
-               )
-             ORDER BY COALESCE(start_at, end_at) ASC, updated_at DESC
-             LIMIT ?3 OFFSET ?4"
-        );
-        let mut stmt = self.conn.prepare(&sql)?;
-        let mut rows = stmt.query(params![eod_ms, bod_ms, limit, offset])?;
-        let mut result = Vec::new();
-        while let Some(row) = rows.next()? {
-            result.push(parse_section_atom_row(row)?);
-        }
-        Ok(result)
+                     AND start_at <= ?3 AND end_at >= ?4)
+               )";
+        self.query_section(
+            where_sql,
+            "ORDER BY is_pinned DESC, COALESCE(start_at, end_at) ASC, updated_at DESC",
+            vec![
+                Value::from(limit),
+                Value::from(offset),
+                Value::from(eod_ms),
+                Value::from(bod_ms),
+            ],
+        )
     }
 
     fn fetch_upcoming(
@@ -535,40 +574,35 @@ impl AtomRepository for SqliteAtomRepository<'_> {
         limit: u32,
         offset: u32,
     ) -> RepoResult<Vec<SectionAtomRow>> {
-        let sql = format!(
-            "{SECTION_SELECT_SQL}
-             WHERE is_deleted = 0
+        let where_sql = "WHERE is_deleted = 0
                AND (task_status IS NULL OR task_status NOT IN ('done', 'cancelled'))
                AND (
-                 (end_at IS NOT NULL AND end_at > ?1 AND start_at IS NULL)
-                 OR (start_at IS NOT NULL AND end_at IS NULL AND start_at > ?1)
-                 OR (start_at IS NOT NULL AND end_at IS NOT NULL AND start_at > ?1)
-               )
-             ORDER BY COALESCE(start_at, end_at) ASC, updated_at DESC
-             LIMIT ?2 OFFSET ?3"
-        );
-        let mut stmt = self.conn.prepare(&sql)?;
-        let mut rows = stmt.query(params![eod_ms, limit, offset])?;
-        let mut result = Vec::new();
-        while let Some(row) = rows.next()? {
-            result.push(parse_section_atom_row(row)?);
-        }
-        Ok(result)
+                 (end_at IS NOT NULL AND end_at > ?3 AND start_at IS NULL)
+                 OR (start_at IS NOT NULL AND end_at IS NULL AND start_at > ?3)
+                 OR (start_at IS NOT NULL AND end_at IS NOT NULL AND start_at > ?3)
+               )";
+        self.query_section(
+            where_sql,
+            "ORDER BY is_pinned DESC, COALESCE(start_at, end_at) ASC, updated_at DESC",
+            vec![Value::from(limit), Value::from(offset), Value::from(eod_ms)],
+        )
     }
 
     fn update_atom_status(&self, id: AtomId, status: Option<TaskStatus>) -> RepoResult<()> {
         let started_at = Instant::now();
         let status_db = status.map(task_status_to_db);
 
-        let changed = match self.conn.execute(
-            "UPDATE atoms
-             SET task_status = ?1,
-                 updated_at = (strftime('%s', 'now') * 1000)
-             WHERE uuid = ?2
-               AND is_deleted = 0;",
-            params![status_db, id.to_string()],
-        ) {
-            Ok(changed) => changed,
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let previous = match write_atom_status(&tx, id, status) {
+            Ok(previous) => previous,
+            Err(RepoError::NotFound(_)) => {
+                warn!(
+                    "event=atom_update_status module=repo status=error atom_id={} duration_ms={} error_code=not_found",
+                    id,
+                    started_at.elapsed().as_millis()
+                );
+                return Err(RepoError::NotFound(id));
+            }
             Err(err) => {
                 error!(
                     "event=atom_update_status module=repo status=error atom_id={} duration_ms={} error_code=db_write_failed error={}",
@@ -576,18 +610,22 @@ impl AtomRepository for SqliteAtomRepository<'_> {
                     started_at.elapsed().as_millis(),
                     err
                 );
-                return Err(err.into());
+                return Err(err);
             }
         };
-
-        if changed == 0 {
-            warn!(
-                "event=atom_update_status module=repo status=error atom_id={} duration_ms={} error_code=not_found",
-                id,
-                started_at.elapsed().as_millis()
-            );
-            return Err(RepoError::NotFound(id));
-        }
+        journal_repo::record_command(
+            &tx,
+            &JournalCommand::AtomStatus {
+                atom_id: id,
+                status: previous,
+            },
+            &JournalCommand::AtomStatus {
+                atom_id: id,
+                status,
+            },
+            0,
+        )?;
+        tx.commit()?;
 
         info!(
             "event=atom_update_status module=repo status=ok atom_id={} new_status={} duration_ms={}",
@@ -606,56 +644,45 @@ impl AtomRepository for SqliteAtomRepository<'_> {
         limit: u32,
         offset: u32,
     ) -> RepoResult<Vec<SectionAtomRow>> {
-        let sql = format!(
-            "{SECTION_SELECT_SQL}
-             WHERE start_at IS NOT NULL
+        let where_sql = "WHERE start_at IS NOT NULL
                AND end_at IS NOT NULL
-               AND start_at < ?1
-               AND end_at > ?2
-               AND is_deleted = 0
-             ORDER BY start_at ASC, end_at ASC
-             LIMIT ?3 OFFSET ?4"
-        );
-        let mut stmt = self.conn.prepare(&sql)?;
-        let mut rows = stmt.query(params![range_end_ms, range_start_ms, limit, offset])?;
-        let mut result = Vec::new();
-        while let Some(row) = rows.next()? {
-            result.push(parse_section_atom_row(row)?);
-        }
-        Ok(result)
+               AND start_at < ?3
+               AND end_at > ?4
+               AND is_deleted = 0";
+        self.query_section(
+            where_sql,
+            "ORDER BY start_at ASC, end_at ASC",
+            vec![
+                Value::from(limit),
+                Value::from(offset),
+                Value::from(range_end_ms),
+                Value::from(range_start_ms),
+            ],
+        )
     }
 
-    fn update_event_times(&self, id: AtomId, start_at: i64, end_at: i64) -> RepoResult<()> {
+    fn set_atom_flag(&self, id: AtomId, flag: AtomFlag, value: bool) -> RepoResult<()> {
         let started_at = Instant::now();
-
-        if end_at < start_at {
-            warn!(
-                "event=update_event_times module=repo status=error atom_id={} duration_ms={} error_code=validation_error",
-                id,
-                started_at.elapsed().as_millis()
-            );
-            return Err(RepoError::Validation(
-                AtomValidationError::InvalidEventWindow {
-                    start: start_at,
-                    end: end_at,
-                },
-            ));
-        }
-
+        let column = flag.column();
+        // Why: flags are not content; `updated_at` only moves when the flag
+        // actually flips so re-pinning does not reshuffle recency order.
         let changed = match self.conn.execute(
-            "UPDATE atoms
-             SET start_at = ?1,
-                 end_at = ?2,
-                 updated_at = (strftime('%s', 'now') * 1000)
-             WHERE uuid = ?3
-               AND is_deleted = 0;",
-            params![start_at, end_at, id.to_string()],
+            &format!(
+                "UPDATE atoms
+                 SET {column} = ?1,
+                     updated_at = CASE WHEN {column} = ?1 THEN updated_at
+                                       ELSE (strftime('%s', 'now') * 1000) END
+                 WHERE uuid = ?2
+                   AND is_deleted = 0;"
+            ),
+            params![bool_to_int(value), id.to_string()],
         ) {
             Ok(changed) => changed,
             Err(err) => {
                 error!(
-                    "event=update_event_times module=repo status=error atom_id={} duration_ms={} error_code=db_write_failed error={}",
+                    "event=atom_set_flag module=repo status=error atom_id={} flag={} duration_ms={} error_code=db_write_failed error={}",
                     id,
+                    column,
                     started_at.elapsed().as_millis(),
                     err
                 );
@@ -665,13 +692,72 @@ impl AtomRepository for SqliteAtomRepository<'_> {
 
         if changed == 0 {
             warn!(
-                "event=update_event_times module=repo status=error atom_id={} duration_ms={} error_code=not_found",
+                "event=atom_set_flag module=repo status=error atom_id={} flag={} duration_ms={} error_code=not_found",
                 id,
+                column,
                 started_at.elapsed().as_millis()
             );
             return Err(RepoError::NotFound(id));
         }
 
+        info!(
+            "event=atom_set_flag module=repo status=ok atom_id={} flag={} value={} duration_ms={}",
+            id,
+            column,
+            value,
+            started_at.elapsed().as_millis()
+        );
+        Ok(())
+    }
+
+    fn update_event_times(&self, id: AtomId, start_at: i64, end_at: i64) -> RepoResult<()> {
+        let started_at = Instant::now();
+
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let previous = match write_atom_times(&tx, id, Some(start_at), Some(end_at)) {
+            Ok(previous) => previous,
+            Err(RepoError::NotFound(_)) => {
+                warn!(
+                    "event=update_event_times module=repo status=error atom_id={} duration_ms={} error_code=not_found",
+                    id,
+                    started_at.elapsed().as_millis()
+                );
+                return Err(RepoError::NotFound(id));
+            }
+            Err(err @ RepoError::Validation(_)) => {
+                warn!(
+                    "event=update_event_times module=repo status=error atom_id={} duration_ms={} error_code=validation_error",
+                    id,
+                    started_at.elapsed().as_millis()
+                );
+                return Err(err);
+            }
+            Err(err) => {
+                error!(
+                    "event=update_event_times module=repo status=error atom_id={} duration_ms={} error_code=db_write_failed error={}",
+                    id,
+                    started_at.elapsed().as_millis(),
+                    err
+                );
+                return Err(err);
+            }
+        };
+        journal_repo::record_command(
+            &tx,
+            &JournalCommand::EventTimes {
+                atom_id: id,
+                start_at: previous.0,
+                end_at: previous.1,
+            },
+            &JournalCommand::EventTimes {
+                atom_id: id,
+                start_at: Some(start_at),
+                end_at: Some(end_at),
+            },
+            0,
+        )?;
+        tx.commit()?;
+
         info!(
             "event=update_event_times module=repo status=ok atom_id={} start_at={} end_at={} duration_ms={}",
             id,
@@ -682,6 +768,390 @@ impl AtomRepository for SqliteAtomRepository<'_> {
 
         Ok(())
     }
+
+    fn set_property(&self, id: AtomId, key: &str, value: &PropertyValue) -> RepoResult<()> {
+        let started_at = Instant::now();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        if let Err(err) = property_repo::set_property(&tx, id, key, value, PROPERTY_SOURCE_USER) {
+            warn!(
+                "event=atom_set_property module=repo status=error atom_id={} value_type={} duration_ms={} error={}",
+                id,
+                value.type_name(),
+                started_at.elapsed().as_millis(),
+                err
+            );
+            return Err(err);
+        }
+        tx.commit()?;
+
+        info!(
+            "event=atom_set_property module=repo status=ok atom_id={} value_type={} duration_ms={}",
+            id,
+            value.type_name(),
+            started_at.elapsed().as_millis()
+        );
+        Ok(())
+    }
+
+    fn get_property(&self, id: AtomId, key: &str) -> RepoResult<Option<AtomProperty>> {
+        property_repo::get_property(self.conn, id, key)
+    }
+
+    fn list_properties(&self, id: AtomId) -> RepoResult<Vec<AtomProperty>> {
+        property_repo::list_properties(self.conn, id)
+    }
+
+    fn remove_property(&self, id: AtomId, key: &str) -> RepoResult<bool> {
+        property_repo::remove_property(self.conn, id, key)
+    }
+}
+
+/// Validates and inserts one atom, then derives its content tags.
+///
+/// Callers own the surrounding transaction.
+pub(crate) fn create_atom_in_tx(conn: &Connection, atom: &Atom) -> RepoResult<()> {
+    let started_at = Instant::now();
+    if let Err(err) = atom.validate() {
+        warn!(
+            "event=atom_create module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=validation_error",
+            atom.uuid,
+            atom_type_to_db(atom.kind),
+            started_at.elapsed().as_millis()
+        );
+        return Err(err.into());
+    }
+
+    let stats = derive_markdown_preview(atom.content.as_str());
+    if let Err(err) = conn.execute(
+        "INSERT INTO atoms (
+            uuid,
+            type,
+            content,
+            preview_text,
+            preview_image,
+            task_status,
+            start_at,
+            end_at,
+            recurrence_rule,
+            hlc_timestamp,
+            is_deleted,
+            preview_title,
+            word_count,
+            reading_time_minutes,
+            is_archived,
+            is_pinned,
+            is_starred
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17);",
+        params![
+            atom.uuid.to_string(),
+            atom_type_to_db(atom.kind),
+            atom.content.as_str(),
+            atom.preview_text.as_deref(),
+            atom.preview_image.as_deref(),
+            atom.task_status.map(task_status_to_db),
+            atom.start_at,
+            atom.end_at,
+            atom.recurrence_rule.as_deref(),
+            atom.hlc_timestamp.as_deref(),
+            bool_to_int(atom.is_deleted),
+            stats.title,
+            stats.word_count,
+            stats.reading_time_minutes,
+            bool_to_int(atom.is_archived),
+            bool_to_int(atom.is_pinned),
+            bool_to_int(atom.is_starred),
+        ],
+    ) {
+        error!(
+            "event=atom_create module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=db_write_failed error={}",
+            atom.uuid,
+            atom_type_to_db(atom.kind),
+            started_at.elapsed().as_millis(),
+            err
+        );
+        return Err(err.into());
+    }
+    sync_content_tags(conn, atom, "atom_create", started_at)?;
+
+    Ok(())
+}
+
+/// Validates and fully updates one atom, then re-derives its content tags.
+///
+/// Note content goes through the note write path (see
+/// [`write_note_atom_content`]) so it keeps revision history, indexes and
+/// undo. Returns [`RepoError::NotFound`] for missing atoms. Callers own the
+/// surrounding transaction.
+pub(crate) fn update_atom_in_tx(conn: &Connection, atom: &Atom) -> RepoResult<()> {
+    let started_at = Instant::now();
+    if let Err(err) = atom.validate() {
+        warn!(
+            "event=atom_update module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=validation_error",
+            atom.uuid,
+            atom_type_to_db(atom.kind),
+            started_at.elapsed().as_millis()
+        );
+        return Err(err.into());
+    }
+
+    let written = if atom.kind == AtomType::Note {
+        update_note_atom_metadata(conn, atom)
+    } else {
+        update_atom_row(conn, atom)
+    };
+    let changed = match written {
+        Ok(changed) => changed,
+        Err(err) => {
+            error!(
+                "event=atom_update module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=db_write_failed error={}",
+                atom.uuid,
+                atom_type_to_db(atom.kind),
+                started_at.elapsed().as_millis(),
+                err
+            );
+            return Err(err.into());
+        }
+    };
+
+    if changed == 0 {
+        warn!(
+            "event=atom_update module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=not_found",
+            atom.uuid,
+            atom_type_to_db(atom.kind),
+            started_at.elapsed().as_millis()
+        );
+        return Err(RepoError::NotFound(atom.uuid));
+    }
+    if atom.kind == AtomType::Note {
+        write_note_atom_content(conn, atom)?;
+    } else {
+        sync_content_tags(conn, atom, "atom_update", started_at)?;
+    }
+
+    Ok(())
+}
+
+/// Writes every column of one atom row.
+fn update_atom_row(conn: &Connection, atom: &Atom) -> rusqlite::Result<usize> {
+    let stats = derive_markdown_preview(atom.content.as_str());
+    conn.execute(
+        "UPDATE atoms
+         SET
+            type = ?1,
+            content = ?2,
+            preview_text = ?3,
+            preview_image = ?4,
+            task_status = ?5,
+            start_at = ?6,
+            end_at = ?7,
+            recurrence_rule = ?8,
+            hlc_timestamp = ?9,
+            is_deleted = ?10,
+            preview_title = ?12,
+            word_count = ?13,
+            reading_time_minutes = ?14,
+            is_archived = ?15,
+            is_pinned = ?16,
+            is_starred = ?17,
+            updated_at = (strftime('%s', 'now') * 1000)
+         WHERE uuid = ?11;",
+        params![
+            atom_type_to_db(atom.kind),
+            atom.content.as_str(),
+            atom.preview_text.as_deref(),
+            atom.preview_image.as_deref(),
+            atom.task_status.map(task_status_to_db),
+            atom.start_at,
+            atom.end_at,
+            atom.recurrence_rule.as_deref(),
+            atom.hlc_timestamp.as_deref(),
+            bool_to_int(atom.is_deleted),
+            atom.uuid.to_string(),
+            stats.title,
+            stats.word_count,
+            stats.reading_time_minutes,
+            bool_to_int(atom.is_archived),
+            bool_to_int(atom.is_pinned),
+            bool_to_int(atom.is_starred),
+        ],
+    )
+}
+
+/// Writes every column of one note atom row except its content and the
+/// projections derived from it.
+fn update_note_atom_metadata(conn: &Connection, atom: &Atom) -> rusqlite::Result<usize> {
+    conn.execute(
+        "UPDATE atoms
+         SET
+            type = 'note',
+            preview_text = ?2,
+            preview_image = ?3,
+            task_status = ?4,
+            start_at = ?5,
+            end_at = ?6,
+            recurrence_rule = ?7,
+            hlc_timestamp = ?8,
+            is_deleted = ?9,
+            is_archived = ?10,
+            is_pinned = ?11,
+            is_starred = ?12,
+            updated_at = (strftime('%s', 'now') * 1000)
+         WHERE uuid = ?1;",
+        params![
+            atom.uuid.to_string(),
+            atom.preview_text.as_deref(),
+            atom.preview_image.as_deref(),
+            atom.task_status.map(task_status_to_db),
+            atom.start_at,
+            atom.end_at,
+            atom.recurrence_rule.as_deref(),
+            atom.hlc_timestamp.as_deref(),
+            bool_to_int(atom.is_deleted),
+            bool_to_int(atom.is_archived),
+            bool_to_int(atom.is_pinned),
+            bool_to_int(atom.is_starred),
+        ],
+    )
+}
+
+/// Writes changed note content like a note edit: one revision, one undo
+/// step and rebuilt links, blocks, tasks, front matter and tags.
+///
+/// Content of a deleted note cannot change.
+fn write_note_atom_content(conn: &Connection, atom: &Atom) -> RepoResult<()> {
+    let current: String = conn.query_row(
+        "SELECT content FROM atoms WHERE uuid = ?1;",
+        [atom.uuid.to_string()],
+        |row| row.get(0),
+    )?;
+    if current == atom.content {
+        return Ok(());
+    }
+    if atom.is_deleted {
+        return Err(RepoError::InvalidData(
+            "content of a deleted note cannot change".to_string(),
+        ));
+    }
+    let previous_content = write_note_content_in_tx(
+        conn,
+        atom.uuid,
+        atom.content.as_str(),
+        atom.preview_text.as_deref(),
+        atom.preview_image.as_deref(),
+        0,
+    )?;
+    journal_repo::record_command(
+        conn,
+        &JournalCommand::NoteContent {
+            atom_id: atom.uuid,
+            content: previous_content,
+        },
+        &JournalCommand::NoteContent {
+            atom_id: atom.uuid,
+            content: atom.content.clone(),
+        },
+        0,
+    )
+}
+
+/// Re-derives front-matter and inline `#hashtag` tags after a content write.
+fn sync_content_tags(
+    conn: &Connection,
+    atom: &Atom,
+    event: &str,
+    started_at: Instant,
+) -> RepoResult<()> {
+    tag_repo::sync_content_tags(conn, atom.uuid.to_string().as_str()).inspect_err(|err| {
+        error!(
+            "event={} module=repo status=error atom_id={} atom_type={} duration_ms={} error_code=tag_sync_failed error={}",
+            event,
+            atom.uuid,
+            atom_type_to_db(atom.kind),
+            started_at.elapsed().as_millis(),
+            err
+        );
+    })
+}
+
+/// Writes `task_status` of one active atom and mirrors it into the note
+/// checkbox of a checklist task.
+///
+/// Returns the previous status. Callers own the surrounding transaction.
+pub(crate) fn write_atom_status(
+    conn: &Connection,
+    id: AtomId,
+    status: Option<TaskStatus>,
+) -> RepoResult<Option<TaskStatus>> {
+    let previous: Option<Option<String>> = conn
+        .query_row(
+            "SELECT task_status FROM atoms WHERE uuid = ?1 AND is_deleted = 0;",
+            [id.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(previous) = previous else {
+        return Err(RepoError::NotFound(id));
+    };
+    let previous = match previous {
+        Some(value) => Some(parse_task_status(&value).ok_or_else(|| {
+            RepoError::InvalidData(format!(
+                "invalid task status `{value}` in atoms.task_status"
+            ))
+        })?),
+        None => None,
+    };
+
+    conn.execute(
+        "UPDATE atoms
+         SET task_status = ?1,
+             updated_at = (strftime('%s', 'now') * 1000)
+         WHERE uuid = ?2;",
+        params![status.map(task_status_to_db), id.to_string()],
+    )?;
+    // Checklist tasks mirror their status into the parent note's checkbox.
+    note_task_repo::apply_task_status_to_note(conn, id, status)?;
+    Ok(previous)
+}
+
+/// Writes `start_at`/`end_at` of one active atom.
+///
+/// Rejects reversed windows with [`AtomValidationError::InvalidEventWindow`].
+/// Returns the previous `(start_at, end_at)`. Callers own the surrounding
+/// transaction.
+pub(crate) fn write_atom_times(
+    conn: &Connection,
+    id: AtomId,
+    start_at: Option<i64>,
+    end_at: Option<i64>,
+) -> RepoResult<(Option<i64>, Option<i64>)> {
+    if let (Some(start), Some(end)) = (start_at, end_at) {
+        if end < start {
+            return Err(RepoError::Validation(
+                AtomValidationError::InvalidEventWindow { start, end },
+            ));
+        }
+    }
+
+    let previous: Option<(Option<i64>, Option<i64>)> = conn
+        .query_row(
+            "SELECT start_at, end_at FROM atoms WHERE uuid = ?1 AND is_deleted = 0;",
+            [id.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    let Some(previous) = previous else {
+        return Err(RepoError::NotFound(id));
+    };
+
+    conn.execute(
+        "UPDATE atoms
+         SET start_at = ?1,
+             end_at = ?2,
+             updated_at = (strftime('%s', 'now') * 1000)
+         WHERE uuid = ?3;",
+        params![start_at, end_at, id.to_string()],
+    )?;
+    Ok(previous)
 }
 
 fn parse_section_atom_row(row: &Row<'_>) -> RepoResult<SectionAtomRow> {
@@ -690,7 +1160,7 @@ fn parse_section_atom_row(row: &Row<'_>) -> RepoResult<SectionAtomRow> {
     Ok(SectionAtomRow { atom, updated_at })
 }
 
-fn parse_atom_row(row: &Row<'_>) -> RepoResult<Atom> {
+pub(crate) fn parse_atom_row(row: &Row<'_>) -> RepoResult<Atom> {
     let uuid_text: String = row.get("uuid")?;
     let uuid = Uuid::parse_str(&uuid_text).map_err(|_| {
         RepoError::InvalidData(format!("invalid uuid value `{uuid_text}` in atoms.uuid"))
@@ -710,15 +1180,7 @@ fn parse_atom_row(row: &Row<'_>) -> RepoResult<Atom> {
         None => None,
     };
 
-    let is_deleted = match row.get::<_, i64>("is_deleted")? {
-        0 => false,
-        1 => true,
-        other => {
-            return Err(RepoError::InvalidData(format!(
-                "invalid is_deleted value `{other}` in atoms.is_deleted"
-            )));
-        }
-    };
+    let is_deleted = parse_flag_column(row, "is_deleted")?;
 
     let atom = Atom {
         uuid,
@@ -732,11 +1194,47 @@ fn parse_atom_row(row: &Row<'_>) -> RepoResult<Atom> {
         recurrence_rule: row.get("recurrence_rule")?,
         hlc_timestamp: row.get("hlc_timestamp")?,
         is_deleted,
+        is_archived: parse_flag_column(row, "is_archived")?,
+        is_pinned: parse_flag_column(row, "is_pinned")?,
+        is_starred: parse_flag_column(row, "is_starred")?,
     };
     atom.validate()?;
     Ok(atom)
 }
 
+fn parse_flag_column(row: &Row<'_>, column: &str) -> RepoResult<bool> {
+    match row.get::<_, i64>(column)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        other => Err(RepoError::InvalidData(format!(
+            "invalid {column} value `{other}` in atoms.{column}"
+        ))),
+    }
+}
+
+/// Appends archive/pin/star predicates; `prefix` qualifies the columns
+/// (e.g. `atoms.`). Archived atoms are excluded unless `include_archived`.
+pub(crate) fn push_atom_flag_filters(
+    sql: &mut String,
+    prefix: &str,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
+) {
+    if !include_archived {
+        sql.push_str(&format!(" AND {prefix}is_archived = 0"));
+    }
+    if let Some(pinned) = pinned {
+        sql.push_str(&format!(" AND {prefix}is_pinned = {}", bool_to_int(pinned)));
+    }
+    if let Some(starred) = starred {
+        sql.push_str(&format!(
+            " AND {prefix}is_starred = {}",
+            bool_to_int(starred)
+        ));
+    }
+}
+
 pub(crate) fn atom_type_to_db(kind: AtomType) -> &'static str {
     match kind {
         AtomType::Note => "note",
@@ -812,11 +1310,17 @@ fn ensure_connection_ready(conn: &Connection) -> RepoResult<()> {
         "content",
         "preview_text",
         "preview_image",
+        "preview_title",
+        "word_count",
+        "reading_time_minutes",
         "task_status",
         "start_at",
         "end_at",
         "recurrence_rule",
         "is_deleted",
+        "is_archived",
+        "is_pinned",
+        "is_starred",
         "updated_at",
     ] {
         if !table_has_column(conn, "atoms", column)? {
@@ -0,0 +1,500 @@
+//! Attachment repository contracts and SQLite implementation.
+//!
+//! # Responsibility
+//! - Link atoms to content-addressed blobs in `attachments`.
+//! - Keep `attachment_blobs` rows and blob files in step with references.
+//! - Collect blobs whose reference count dropped to zero.
+//!
+//! # Invariants
+//! - Filesystem changes only follow a committed transaction: staged blobs
+//!   are renamed into place after their rows commit, and collected blobs are
+//!   unlinked after their rows are deleted. A rolled-back transaction
+//!   therefore never leaves a file moved or removed.
+//! - Garbage collection re-checks that a blob row is still absent before
+//!   unlinking, so a concurrent add of the same content keeps its file.
+//! - `attachment_blobs.ref_count` is maintained by SQL triggers only.
+//! - Removing an attachment never deletes its blob; call
+//!   [`AttachmentRepository::collect_garbage`] for that.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::db::migrations::latest_version;
+use crate::db::DbError;
+use crate::model::atom::AtomId;
+use crate::repo::blob_store::{BlobStore, StagedBlob};
+use log::{info, warn};
+use rusqlite::{params, Connection, Row, Transaction, TransactionBehavior};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::time::Instant;
+use uuid::Uuid;
+
+/// Stable attachment identifier.
+pub type AttachmentId = Uuid;
+
+/// Result type for attachment repository operations.
+pub type AttachmentResult<T> = Result<T, AttachmentError>;
+
+/// Attachment repository/service error.
+#[derive(Debug)]
+pub enum AttachmentError {
+    /// Underlying SQLite/bootstrap error.
+    Db(DbError),
+    /// Blob directory read/write failed.
+    Io(io::Error),
+    /// Target atom does not exist or is soft-deleted.
+    AtomNotFound(AtomId),
+    /// Target attachment does not exist.
+    AttachmentNotFound(AttachmentId),
+    /// Original file name is blank after normalization.
+    InvalidName,
+    /// Attachment row exists but its blob file is gone.
+    BlobMissing(String),
+    /// Connection schema is not at the expected migrated version.
+    UninitializedConnection {
+        expected_version: u32,
+        actual_version: u32,
+    },
+    /// Required table is missing.
+    MissingRequiredTable(&'static str),
+    /// Persisted data cannot be converted to valid read model.
+    InvalidData(String),
+}
+
+impl Display for AttachmentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Db(err) => write!(f, "{err}"),
+            Self::Io(err) => write!(f, "attachment blob io failed: {err}"),
+            Self::AtomNotFound(id) => write!(f, "atom not found: {id}"),
+            Self::AttachmentNotFound(id) => write!(f, "attachment not found: {id}"),
+            Self::InvalidName => write!(f, "attachment name must not be blank"),
+            Self::BlobMissing(sha256) => write!(f, "attachment blob is missing: {sha256}"),
+            Self::UninitializedConnection {
+                expected_version,
+                actual_version,
+            } => write!(
+                f,
+                "attachment repository requires schema version {expected_version}, got {actual_version}"
+            ),
+            Self::MissingRequiredTable(table) => {
+                write!(f, "attachment repository requires table `{table}`")
+            }
+            Self::InvalidData(message) => write!(f, "invalid attachment data: {message}"),
+        }
+    }
+}
+
+impl Error for AttachmentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Db(err) => Some(err),
+            Self::Io(err) => Some(err),
+            Self::AtomNotFound(_) => None,
+            Self::AttachmentNotFound(_) => None,
+            Self::InvalidName => None,
+            Self::BlobMissing(_) => None,
+            Self::UninitializedConnection { .. } => None,
+            Self::MissingRequiredTable(_) => None,
+            Self::InvalidData(_) => None,
+        }
+    }
+}
+
+impl From<DbError> for AttachmentError {
+    fn from(value: DbError) -> Self {
+        Self::Db(value)
+    }
+}
+
+impl From<rusqlite::Error> for AttachmentError {
+    fn from(value: rusqlite::Error) -> Self {
+        Self::Db(DbError::Sqlite(value))
+    }
+}
+
+impl From<io::Error> for AttachmentError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// One file attached to an atom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    /// Stable attachment ID.
+    pub uuid: AttachmentId,
+    /// Owning atom.
+    pub atom_uuid: AtomId,
+    /// Lowercase hex SHA-256 of the content (blob file name).
+    pub blob_sha256: String,
+    /// File name as supplied by the caller.
+    pub original_name: String,
+    /// MIME type, e.g. `application/pdf`.
+    pub mime_type: String,
+    /// Content length in bytes.
+    pub size_bytes: u64,
+    /// Epoch ms when the attachment was added.
+    pub created_at: i64,
+}
+
+/// Outcome of one blob garbage collection pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlobGcReport {
+    /// Orphaned blobs whose rows were deleted.
+    pub removed_blobs: u32,
+    /// Sum of `size_bytes` over removed blobs.
+    pub freed_bytes: u64,
+}
+
+/// Repository interface for attachment persistence.
+pub trait AttachmentRepository {
+    /// Stores `reader` content as a blob and attaches it to an active atom.
+    ///
+    /// Identical content attached anywhere else reuses the same blob.
+    fn add_attachment(
+        &self,
+        atom_id: AtomId,
+        original_name: &str,
+        mime_type: &str,
+        reader: &mut dyn Read,
+    ) -> AttachmentResult<Attachment>;
+    /// Loads one attachment by ID.
+    fn get_attachment(&self, id: AttachmentId) -> AttachmentResult<Option<Attachment>>;
+    /// Lists attachments of one atom in insertion order.
+    fn list_attachments(&self, atom_id: AtomId) -> AttachmentResult<Vec<Attachment>>;
+    /// Detaches one attachment; returns whether it existed.
+    fn remove_attachment(&self, id: AttachmentId) -> AttachmentResult<bool>;
+    /// Resolves the blob file of one attachment.
+    ///
+    /// Returns [`AttachmentError::BlobMissing`] when the file is gone.
+    fn blob_path(&self, id: AttachmentId) -> AttachmentResult<PathBuf>;
+    /// Deletes blobs with no remaining attachment rows, including their files.
+    fn collect_garbage(&self) -> AttachmentResult<BlobGcReport>;
+}
+
+/// SQLite-backed attachment repository over one blob directory.
+pub struct SqliteAttachmentRepository<'conn> {
+    conn: &'conn Connection,
+    store: BlobStore,
+}
+
+impl<'conn> SqliteAttachmentRepository<'conn> {
+    /// Constructs repository from a migrated connection and blob store.
+    pub fn try_new(conn: &'conn Connection, store: BlobStore) -> AttachmentResult<Self> {
+        ensure_attachment_connection_ready(conn)?;
+        Ok(Self { conn, store })
+    }
+
+    /// Blob store backing this repository.
+    pub fn store(&self) -> &BlobStore {
+        &self.store
+    }
+}
+
+impl AttachmentRepository for SqliteAttachmentRepository<'_> {
+    fn add_attachment(
+        &self,
+        atom_id: AtomId,
+        original_name: &str,
+        mime_type: &str,
+        reader: &mut dyn Read,
+    ) -> AttachmentResult<Attachment> {
+        let started_at = Instant::now();
+        if original_name.trim().is_empty() {
+            return Err(AttachmentError::InvalidName);
+        }
+
+        let staged = self.store.stage(reader)?;
+        let attachment = match insert_attachment(
+            self.conn,
+            atom_id,
+            original_name,
+            mime_type,
+            &staged,
+        ) {
+            Ok(attachment) => attachment,
+            Err(err) => {
+                self.store.discard(staged)?;
+                warn!(
+                    "event=attachment_add module=repo status=error atom_id={} duration_ms={} error={}",
+                    atom_id,
+                    started_at.elapsed().as_millis(),
+                    err
+                );
+                return Err(err);
+            }
+        };
+        // Why: the rows are committed first so a rollback never leaves a file
+        // behind; a failed rename detaches the row again.
+        if let Err(err) = self.store.commit(staged) {
+            self.conn.execute(
+                "DELETE FROM attachments
+                 WHERE uuid = ?1;",
+                [attachment.uuid.to_string()],
+            )?;
+            warn!(
+                "event=attachment_add module=repo status=error atom_id={} duration_ms={} error={}",
+                atom_id,
+                started_at.elapsed().as_millis(),
+                err
+            );
+            return Err(err.into());
+        }
+
+        info!(
+            "event=attachment_add module=repo status=ok atom_id={} size_bytes={} duration_ms={}",
+            atom_id,
+            attachment.size_bytes,
+            started_at.elapsed().as_millis()
+        );
+        Ok(attachment)
+    }
+
+    fn get_attachment(&self, id: AttachmentId) -> AttachmentResult<Option<Attachment>> {
+        load_attachment(self.conn, id)
+    }
+
+    fn list_attachments(&self, atom_id: AtomId) -> AttachmentResult<Vec<Attachment>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT uuid, atom_uuid, blob_sha256, original_name, mime_type, size_bytes, created_at
+             FROM attachments
+             WHERE atom_uuid = ?1
+             ORDER BY created_at ASC, rowid ASC;",
+        )?;
+        let mut rows = stmt.query([atom_id.to_string()])?;
+        let mut attachments = Vec::new();
+        while let Some(row) = rows.next()? {
+            attachments.push(parse_attachment_row(row)?);
+        }
+        Ok(attachments)
+    }
+
+    fn remove_attachment(&self, id: AttachmentId) -> AttachmentResult<bool> {
+        let removed = self.conn.execute(
+            "DELETE FROM attachments
+             WHERE uuid = ?1;",
+            [id.to_string()],
+        )?;
+        info!(
+            "event=attachment_remove module=repo status=ok attachment_id={} removed={}",
+            id,
+            removed > 0
+        );
+        Ok(removed > 0)
+    }
+
+    fn blob_path(&self, id: AttachmentId) -> AttachmentResult<PathBuf> {
+        let attachment =
+            load_attachment(self.conn, id)?.ok_or(AttachmentError::AttachmentNotFound(id))?;
+        let path = self.store.path_for(attachment.blob_sha256.as_str());
+        if !path.is_file() {
+            return Err(AttachmentError::BlobMissing(attachment.blob_sha256));
+        }
+        Ok(path)
+    }
+
+    fn collect_garbage(&self) -> AttachmentResult<BlobGcReport> {
+        let started_at = Instant::now();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let orphans = {
+            let mut stmt = tx.prepare(
+                "SELECT sha256, size_bytes
+                 FROM attachment_blobs
+                 WHERE ref_count = 0
+                 ORDER BY sha256 ASC;",
+            )?;
+            let rows = stmt.query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?;
+            rows.collect::<Result<Vec<_>, _>>()?
+        };
+
+        let mut report = BlobGcReport::default();
+        for (sha256, size_bytes) in &orphans {
+            tx.execute(
+                "DELETE FROM attachment_blobs
+                 WHERE sha256 = ?1
+                   AND ref_count = 0;",
+                [sha256.as_str()],
+            )?;
+            report.removed_blobs += 1;
+            report.freed_bytes += u64::try_from(*size_bytes).unwrap_or_default();
+        }
+        tx.commit()?;
+
+        // Why: unlink only after the rows are gone for good, and skip blobs
+        // that an add re-created in the meantime.
+        for (sha256, _) in &orphans {
+            let recreated: i64 = self.conn.query_row(
+                "SELECT EXISTS(
+                    SELECT 1
+                    FROM attachment_blobs
+                    WHERE sha256 = ?1
+                );",
+                [sha256.as_str()],
+                |row| row.get(0),
+            )?;
+            if recreated == 0 {
+                self.store.remove(sha256.as_str())?;
+            }
+        }
+
+        info!(
+            "event=attachment_gc module=repo status=ok removed_blobs={} freed_bytes={} duration_ms={}",
+            report.removed_blobs,
+            report.freed_bytes,
+            started_at.elapsed().as_millis()
+        );
+        Ok(report)
+    }
+}
+
+fn insert_attachment(
+    conn: &Connection,
+    atom_id: AtomId,
+    original_name: &str,
+    mime_type: &str,
+    staged: &StagedBlob,
+) -> AttachmentResult<Attachment> {
+    let tx = Transaction::new_unchecked(conn, TransactionBehavior::Immediate)?;
+    let attachment_id = insert_attachment_rows(
+        &tx,
+        atom_id,
+        original_name,
+        mime_type,
+        staged.sha256.as_str(),
+        staged.size_bytes,
+    )?;
+    let attachment = load_required_attachment(&tx, attachment_id)?;
+    tx.commit()?;
+    Ok(attachment)
+}
+
+fn insert_attachment_rows(
+    conn: &Connection,
+    atom_id: AtomId,
+    original_name: &str,
+    mime_type: &str,
+    sha256: &str,
+    size_bytes: u64,
+) -> AttachmentResult<AttachmentId> {
+    let atom_active: i64 = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1
+            FROM atoms
+            WHERE uuid = ?1
+              AND is_deleted = 0
+        );",
+        [atom_id.to_string()],
+        |row| row.get(0),
+    )?;
+    if atom_active != 1 {
+        return Err(AttachmentError::AtomNotFound(atom_id));
+    }
+
+    let size_bytes = i64::try_from(size_bytes)
+        .map_err(|_| AttachmentError::InvalidData(format!("blob too large: {size_bytes}")))?;
+    conn.execute(
+        "INSERT INTO attachment_blobs (sha256, size_bytes)
+         VALUES (?1, ?2)
+         ON CONFLICT(sha256) DO NOTHING;",
+        params![sha256, size_bytes],
+    )?;
+
+    let attachment_id = Uuid::new_v4();
+    conn.execute(
+        "INSERT INTO attachments (
+            uuid,
+            atom_uuid,
+            blob_sha256,
+            original_name,
+            mime_type,
+            size_bytes
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6);",
+        params![
+            attachment_id.to_string(),
+            atom_id.to_string(),
+            sha256,
+            original_name,
+            mime_type,
+            size_bytes
+        ],
+    )?;
+    Ok(attachment_id)
+}
+
+fn load_attachment(conn: &Connection, id: AttachmentId) -> AttachmentResult<Option<Attachment>> {
+    let mut stmt = conn.prepare(
+        "SELECT uuid, atom_uuid, blob_sha256, original_name, mime_type, size_bytes, created_at
+         FROM attachments
+         WHERE uuid = ?1;",
+    )?;
+    let mut rows = stmt.query([id.to_string()])?;
+    if let Some(row) = rows.next()? {
+        return Ok(Some(parse_attachment_row(row)?));
+    }
+    Ok(None)
+}
+
+fn load_required_attachment(conn: &Connection, id: AttachmentId) -> AttachmentResult<Attachment> {
+    load_attachment(conn, id)?.ok_or(AttachmentError::AttachmentNotFound(id))
+}
+
+fn parse_attachment_row(row: &Row<'_>) -> AttachmentResult<Attachment> {
+    let uuid_text: String = row.get("uuid")?;
+    let uuid = Uuid::parse_str(uuid_text.as_str()).map_err(|_| {
+        AttachmentError::InvalidData(format!("invalid uuid `{uuid_text}` in attachments.uuid"))
+    })?;
+    let atom_text: String = row.get("atom_uuid")?;
+    let atom_uuid = Uuid::parse_str(atom_text.as_str()).map_err(|_| {
+        AttachmentError::InvalidData(format!(
+            "invalid uuid `{atom_text}` in attachments.atom_uuid"
+        ))
+    })?;
+    let size_bytes: i64 = row.get("size_bytes")?;
+    Ok(Attachment {
+        uuid,
+        atom_uuid,
+        blob_sha256: row.get("blob_sha256")?,
+        original_name: row.get("original_name")?,
+        mime_type: row.get("mime_type")?,
+        size_bytes: u64::try_from(size_bytes).map_err(|_| {
+            AttachmentError::InvalidData(format!(
+                "negative size `{size_bytes}` in attachments.size_bytes"
+            ))
+        })?,
+        created_at: row.get("created_at")?,
+    })
+}
+
+fn ensure_attachment_connection_ready(conn: &Connection) -> AttachmentResult<()> {
+    let expected_version = latest_version();
+    let actual_version: u32 = conn.query_row("PRAGMA user_version;", [], |row| row.get(0))?;
+    if actual_version != expected_version {
+        return Err(AttachmentError::UninitializedConnection {
+            expected_version,
+            actual_version,
+        });
+    }
+
+    for table in ["attachments", "attachment_blobs"] {
+        let exists: i64 = conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1
+                FROM sqlite_master
+                WHERE type = 'table' AND name = ?1
+            );",
+            [table],
+            |row| row.get(0),
+        )?;
+        if exists != 1 {
+            return Err(AttachmentError::MissingRequiredTable(table));
+        }
+    }
+
+    Ok(())
+}
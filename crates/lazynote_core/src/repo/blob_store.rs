@@ -0,0 +1,145 @@
+//! Content-addressed blob directory for attachments.
+//!
+//! # Responsibility
+//! - Stage incoming bytes while computing their SHA-256 digest.
+//! - Move staged files to their final `<root>/<aa>/<sha256>` location.
+//! - Resolve and remove blob files by digest.
+//!
+//! # Invariants
+//! - A blob file name is the lowercase hex SHA-256 of its content, so
+//!   committing identical content twice keeps a single file.
+//! - Staged files live under `<root>/tmp` and never appear at a final path
+//!   until fully written.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Directory name used by [`BlobStore::next_to_db`].
+pub const BLOB_DIR_NAME: &str = "attachments";
+
+const STAGING_DIR_NAME: &str = "tmp";
+const COPY_BUFFER_BYTES: usize = 64 * 1024;
+
+/// Blob directory rooted at one filesystem path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobStore {
+    root: PathBuf,
+}
+
+/// Bytes written to the staging area but not yet committed.
+#[derive(Debug)]
+pub struct StagedBlob {
+    /// Lowercase hex SHA-256 of the staged content.
+    pub sha256: String,
+    /// Content length in bytes.
+    pub size_bytes: u64,
+    temp_path: PathBuf,
+}
+
+impl BlobStore {
+    /// Creates a store rooted at `root`; the directory is created lazily.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Creates a store in the `attachments` directory beside `db_path`.
+    pub fn next_to_db(db_path: impl AsRef<Path>) -> Self {
+        let parent = db_path
+            .as_ref()
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        Self::new(parent.join(BLOB_DIR_NAME))
+    }
+
+    /// Root directory of this store.
+    pub fn root(&self) -> &Path {
+        self.root.as_path()
+    }
+
+    /// Final path for a blob digest (`<root>/<first two hex chars>/<sha256>`).
+    pub fn path_for(&self, sha256: &str) -> PathBuf {
+        let shard = sha256.get(..2).unwrap_or(sha256);
+        self.root.join(shard).join(sha256)
+    }
+
+    /// Copies `reader` into the staging area while hashing it.
+    pub fn stage(&self, reader: &mut dyn Read) -> io::Result<StagedBlob> {
+        let staging_dir = self.root.join(STAGING_DIR_NAME);
+        fs::create_dir_all(&staging_dir)?;
+        let temp_path = staging_dir.join(Uuid::new_v4().to_string());
+        let staged = copy_hashing(reader, temp_path.as_path());
+        if staged.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        let (sha256, size_bytes) = staged?;
+        Ok(StagedBlob {
+            sha256,
+            size_bytes,
+            temp_path,
+        })
+    }
+
+    /// Moves a staged blob to its final path.
+    ///
+    /// An existing file with the same digest is replaced atomically; its
+    /// content is identical, and replacing restores a file that a concurrent
+    /// garbage collection pass may be about to unlink.
+    pub fn commit(&self, staged: StagedBlob) -> io::Result<PathBuf> {
+        let target = self.path_for(staged.sha256.as_str());
+        if let Some(shard_dir) = target.parent() {
+            fs::create_dir_all(shard_dir)?;
+        }
+        fs::rename(&staged.temp_path, &target)?;
+        Ok(target)
+    }
+
+    /// Deletes a staged blob that will not be committed.
+    pub fn discard(&self, staged: StagedBlob) -> io::Result<()> {
+        match fs::remove_file(&staged.temp_path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    /// Deletes one blob file; returns whether a file was removed.
+    pub fn remove(&self, sha256: &str) -> io::Result<bool> {
+        match fs::remove_file(self.path_for(sha256)) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+fn copy_hashing(reader: &mut dyn Read, temp_path: &Path) -> io::Result<(String, u64)> {
+    let mut file = File::create(temp_path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0_u8; COPY_BUFFER_BYTES];
+    let mut size_bytes = 0_u64;
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        hasher.update(&buffer[..read]);
+        file.write_all(&buffer[..read])?;
+        size_bytes += read as u64;
+    }
+    file.sync_all()?;
+
+    let mut sha256 = String::with_capacity(64);
+    for byte in hasher.finalize() {
+        let _ = write!(sha256, "{byte:02x}");
+    }
+    Ok((sha256, size_bytes))
+}
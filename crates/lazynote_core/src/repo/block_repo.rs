@@ -0,0 +1,142 @@
+//! Note block index persistence.
+//!
+//! # Responsibility
+//! - Store addressable blocks of note content in `note_blocks`.
+//! - Keep block ids stable across note edits.
+//! - Serve block lookups for `![[note#^block-id]]` embeds.
+//!
+//! # Invariants
+//! - Block rows of one note are always replaced as a whole.
+//! - Ids are carried over via [`assign_block_ids`]; new ids are six
+//!   lowercase hex characters.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::markdown::blocks::{assign_block_ids, BlockKind, MarkdownBlock};
+use crate::model::atom::AtomId;
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use rusqlite::{params, Connection, Row};
+use uuid::Uuid;
+
+/// Length of generated block ids.
+const BLOCK_ID_LEN: usize = 6;
+
+/// One indexed block of a note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteBlock {
+    /// Owning note.
+    pub note_atom_id: AtomId,
+    /// Stable id, referenced as `^block_id`.
+    pub block_id: String,
+    /// Index in document order.
+    pub position: u32,
+    /// Block kind.
+    pub kind: BlockKind,
+    /// Markdown source of the block, anchor removed.
+    pub content: String,
+    /// Byte offset where the block starts in note content.
+    pub start: usize,
+    /// Byte offset just past the block in note content.
+    pub end: usize,
+}
+
+/// Replaces the block rows of `note` with `blocks`, reusing stored ids.
+///
+/// Callers own the surrounding transaction.
+pub fn sync_note_blocks(
+    conn: &Connection,
+    note: AtomId,
+    blocks: &[MarkdownBlock],
+) -> RepoResult<()> {
+    let previous: Vec<(String, BlockKind, String)> = list_note_blocks(conn, note)?
+        .into_iter()
+        .map(|block| (block.block_id, block.kind, block.content))
+        .collect();
+    let ids = assign_block_ids(&previous, blocks, || {
+        Uuid::new_v4().simple().to_string()[..BLOCK_ID_LEN].to_string()
+    });
+
+    let note_uuid = note.to_string();
+    conn.execute(
+        "DELETE FROM note_blocks WHERE note_atom_uuid = ?1;",
+        [note_uuid.as_str()],
+    )?;
+    for (position, (block, id)) in blocks.iter().zip(ids).enumerate() {
+        conn.execute(
+            "INSERT INTO note_blocks (
+                note_atom_uuid,
+                block_id,
+                position,
+                kind,
+                content,
+                start_offset,
+                end_offset
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7);",
+            params![
+                note_uuid.as_str(),
+                id.as_str(),
+                position as i64,
+                block.kind.as_str(),
+                block.text.as_str(),
+                block.start as i64,
+                block.end as i64,
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Lists indexed blocks of `note` in document order.
+pub fn list_note_blocks(conn: &Connection, note: AtomId) -> RepoResult<Vec<NoteBlock>> {
+    let mut stmt = conn.prepare(
+        "SELECT note_atom_uuid, block_id, position, kind, content, start_offset, end_offset
+         FROM note_blocks
+         WHERE note_atom_uuid = ?1
+         ORDER BY position ASC;",
+    )?;
+    let mut rows = stmt.query([note.to_string()])?;
+    let mut blocks = Vec::new();
+    while let Some(row) = rows.next()? {
+        blocks.push(parse_note_block_row(row)?);
+    }
+    Ok(blocks)
+}
+
+/// Loads one block by id; a leading `^` in `block_id` is ignored.
+pub fn get_note_block(
+    conn: &Connection,
+    note: AtomId,
+    block_id: &str,
+) -> RepoResult<Option<NoteBlock>> {
+    let block_id = block_id.trim().trim_start_matches('^');
+    let mut stmt = conn.prepare(
+        "SELECT note_atom_uuid, block_id, position, kind, content, start_offset, end_offset
+         FROM note_blocks
+         WHERE note_atom_uuid = ?1
+           AND block_id = ?2;",
+    )?;
+    let mut rows = stmt.query(params![note.to_string(), block_id])?;
+    match rows.next()? {
+        Some(row) => Ok(Some(parse_note_block_row(row)?)),
+        None => Ok(None),
+    }
+}
+
+fn parse_note_block_row(row: &Row<'_>) -> RepoResult<NoteBlock> {
+    let note: String = row.get("note_atom_uuid")?;
+    let kind: String = row.get("kind")?;
+    Ok(NoteBlock {
+        note_atom_id: Uuid::parse_str(note.as_str()).map_err(|_| {
+            RepoError::InvalidData(format!("invalid uuid value `{note}` in note_blocks"))
+        })?,
+        block_id: row.get("block_id")?,
+        position: row.get("position")?,
+        kind: BlockKind::parse(kind.as_str()).ok_or_else(|| {
+            RepoError::InvalidData(format!("invalid block kind `{kind}` in note_blocks.kind"))
+        })?,
+        content: row.get("content")?,
+        start: row.get::<_, i64>("start_offset")? as usize,
+        end: row.get::<_, i64>("end_offset")? as usize,
+    })
+}
@@ -0,0 +1,431 @@
+//! Command journal persistence for undo/redo.
+//!
+//! # Responsibility
+//! - Record the before/after state of core mutations (note content, note
+//!   and atom tags, workspace moves, status and event time changes).
+//! - Replay the newest entry backwards (undo) or forwards (redo).
+//!
+//! # Invariants
+//! - Mutating repositories record entries in the same transaction as their
+//!   write; replays never record new entries.
+//! - Undone entries form the redo side; recording a new mutation drops them.
+//! - An entry is replayed and its cursor moved in one transaction. Entries
+//!   that can no longer be replayed (target gone, invalid move, ...) fail
+//!   and are dropped; only database errors keep the entry for a retry.
+//! - Note content edits inside one revision window coalesce into one entry.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::db::migrations::latest_version;
+use crate::markdown::preview::derive_markdown_preview;
+use crate::model::atom::{AtomId, TaskStatus};
+use crate::repo::atom_repo::{write_atom_status, write_atom_times, RepoError, RepoResult};
+use crate::repo::note_repo::{replace_note_tags_in_tx, write_note_content_in_tx};
+use crate::repo::tag_repo::replace_atom_tags_in_tx;
+use crate::repo::tree_repo::{
+    ensure_valid_move_target, move_node_in_tx, TreeRepoError, WorkspaceNodeId,
+};
+use crate::sync::provider_types::now_epoch_ms;
+use log::{info, warn};
+use rusqlite::{params, Connection, OptionalExtension, Row, Transaction, TransactionBehavior};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Upper bound of journal entries kept; older entries are dropped.
+pub const JOURNAL_MAX_ENTRIES: u32 = 500;
+
+const JOURNAL_SELECT_SQL: &str = "SELECT
+    id,
+    undo_json,
+    redo_json,
+    created_at,
+    undone_at
+FROM command_journal";
+
+/// State written back by one side of a journal entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum JournalCommand {
+    /// Full markdown content of a note.
+    NoteContent { atom_id: AtomId, content: String },
+    /// Manual tag set of a note (normalized).
+    NoteTags { atom_id: AtomId, tags: Vec<String> },
+    /// Manual tag set of an atom of any type (normalized).
+    AtomTags { atom_id: AtomId, tags: Vec<String> },
+    /// Parent and sibling index of a workspace node.
+    NodePosition {
+        node_uuid: WorkspaceNodeId,
+        parent_uuid: Option<WorkspaceNodeId>,
+        index: i64,
+    },
+    /// `task_status` of any atom.
+    AtomStatus {
+        atom_id: AtomId,
+        status: Option<TaskStatus>,
+    },
+    /// `start_at`/`end_at` of any atom.
+    EventTimes {
+        atom_id: AtomId,
+        start_at: Option<i64>,
+        end_at: Option<i64>,
+    },
+}
+
+impl JournalCommand {
+    /// Stable command name stored in `command_journal.command`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NoteContent { .. } => "note_update",
+            Self::NoteTags { .. } => "note_set_tags",
+            Self::AtomTags { .. } => "atom_set_tags",
+            Self::NodePosition { .. } => "node_move",
+            Self::AtomStatus { .. } => "atom_status",
+            Self::EventTimes { .. } => "event_times",
+        }
+    }
+
+    /// Atom or workspace node the command writes to.
+    pub fn target(&self) -> Uuid {
+        match self {
+            Self::NoteContent { atom_id, .. }
+            | Self::NoteTags { atom_id, .. }
+            | Self::AtomTags { atom_id, .. }
+            | Self::AtomStatus { atom_id, .. }
+            | Self::EventTimes { atom_id, .. } => *atom_id,
+            Self::NodePosition { node_uuid, .. } => *node_uuid,
+        }
+    }
+}
+
+/// One recorded mutation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// Monotonic journal id.
+    pub id: i64,
+    /// State written back on undo.
+    pub undo: JournalCommand,
+    /// State written back on redo.
+    pub redo: JournalCommand,
+    /// Recording time in epoch milliseconds.
+    pub created_at: i64,
+    /// Undo time while the entry sits on the redo side.
+    pub undone_at: Option<i64>,
+}
+
+impl JournalEntry {
+    /// Stable command name, e.g. `node_move`.
+    pub fn command(&self) -> &'static str {
+        self.redo.name()
+    }
+}
+
+/// Undo/redo availability of the journal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JournalStatus {
+    /// Entries that can be undone.
+    pub undo_count: u32,
+    /// Entries that can be redone.
+    pub redo_count: u32,
+}
+
+/// Repository interface for the command journal.
+pub trait JournalRepository {
+    /// Lists entries newest first, redo side included.
+    fn list_entries(&self, limit: u32) -> RepoResult<Vec<JournalEntry>>;
+
+    /// Returns how many entries can be undone and redone.
+    fn status(&self) -> RepoResult<JournalStatus>;
+
+    /// Writes back the undo side of the newest entry not yet undone.
+    ///
+    /// Returns `None` when there is nothing to undo.
+    fn undo(&self) -> RepoResult<Option<JournalEntry>>;
+
+    /// Writes back the redo side of the oldest undone entry.
+    ///
+    /// Returns `None` when there is nothing to redo.
+    fn redo(&self) -> RepoResult<Option<JournalEntry>>;
+
+    /// Drops every entry.
+    fn clear(&self) -> RepoResult<()>;
+}
+
+/// SQLite-backed command journal.
+pub struct SqliteJournalRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteJournalRepository<'conn> {
+    /// Constructs a repository from a migrated connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::UninitializedConnection`] if schema version is not
+    ///   fully migrated.
+    /// - Returns [`RepoError::MissingRequiredTable`] when `command_journal` is
+    ///   missing.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        ensure_journal_connection_ready(conn)?;
+        Ok(Self { conn })
+    }
+
+    fn replay(&self, undo: bool) -> RepoResult<Option<JournalEntry>> {
+        let event = if undo { "journal_undo" } else { "journal_redo" };
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        // Why: undo walks back from the newest live entry; redo replays the
+        // undone entries in their original order.
+        let sql = if undo {
+            format!("{JOURNAL_SELECT_SQL} WHERE undone_at IS NULL ORDER BY id DESC LIMIT 1;")
+        } else {
+            format!("{JOURNAL_SELECT_SQL} WHERE undone_at IS NOT NULL ORDER BY id ASC LIMIT 1;")
+        };
+        let entry = tx
+            .query_row(sql.as_str(), [], |row| Ok(parse_journal_row(row)))
+            .optional()?
+            .transpose()?;
+        let Some(mut entry) = entry else {
+            return Ok(None);
+        };
+
+        let command = if undo { &entry.undo } else { &entry.redo };
+        if let Err(err) = apply_command(&tx, command) {
+            drop(tx);
+            // Why: an entry that fails validation (target gone, node no
+            // longer a folder, move into its own subtree, ...) will fail the
+            // same way forever and would block every older entry.
+            if !matches!(err, RepoError::Db(_)) {
+                warn!(
+                    "event={} module=repo status=error journal_id={} command={} error={} dropped=true",
+                    event,
+                    entry.id,
+                    entry.command(),
+                    err
+                );
+                self.conn
+                    .execute("DELETE FROM command_journal WHERE id = ?1;", [entry.id])?;
+            }
+            return Err(err);
+        }
+
+        entry.undone_at = undo.then(now_epoch_ms);
+        tx.execute(
+            "UPDATE command_journal SET undone_at = ?2 WHERE id = ?1;",
+            params![entry.id, entry.undone_at],
+        )?;
+        tx.commit()?;
+        info!(
+            "event={} module=repo status=ok journal_id={} command={}",
+            event,
+            entry.id,
+            entry.command()
+        );
+        Ok(Some(entry))
+    }
+}
+
+impl JournalRepository for SqliteJournalRepository<'_> {
+    fn list_entries(&self, limit: u32) -> RepoResult<Vec<JournalEntry>> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!("{JOURNAL_SELECT_SQL} ORDER BY id DESC LIMIT ?1;"))?;
+        let mut rows = stmt.query([limit])?;
+        let mut entries = Vec::new();
+        while let Some(row) = rows.next()? {
+            entries.push(parse_journal_row(row)?);
+        }
+        Ok(entries)
+    }
+
+    fn status(&self) -> RepoResult<JournalStatus> {
+        let (undo_count, redo_count) = self.conn.query_row(
+            "SELECT
+                COALESCE(SUM(undone_at IS NULL), 0),
+                COALESCE(SUM(undone_at IS NOT NULL), 0)
+             FROM command_journal;",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        Ok(JournalStatus {
+            undo_count,
+            redo_count,
+        })
+    }
+
+    fn undo(&self) -> RepoResult<Option<JournalEntry>> {
+        self.replay(true)
+    }
+
+    fn redo(&self) -> RepoResult<Option<JournalEntry>> {
+        self.replay(false)
+    }
+
+    fn clear(&self) -> RepoResult<()> {
+        self.conn.execute("DELETE FROM command_journal;", [])?;
+        Ok(())
+    }
+}
+
+/// Records one mutation with the state to write back on undo and redo.
+///
+/// No-op when both sides are equal. A new entry drops the redo side. When
+/// `coalesce_window_ms > 0` and the newest entry targets the same command and
+/// target within that window, its redo side is replaced instead, so bursts of
+/// edits undo as one step. Callers own the surrounding transaction.
+pub(crate) fn record_command(
+    conn: &Connection,
+    undo: &JournalCommand,
+    redo: &JournalCommand,
+    coalesce_window_ms: i64,
+) -> RepoResult<()> {
+    if undo == redo {
+        return Ok(());
+    }
+    let now = now_epoch_ms();
+    conn.execute(
+        "DELETE FROM command_journal WHERE undone_at IS NOT NULL;",
+        [],
+    )?;
+    let redo_json = encode_command(redo)?;
+
+    if coalesce_window_ms > 0 {
+        let latest = conn
+            .query_row(
+                &format!("{JOURNAL_SELECT_SQL} ORDER BY id DESC LIMIT 1;"),
+                [],
+                |row| Ok(parse_journal_row(row)),
+            )
+            .optional()?
+            .transpose()?;
+        if let Some(latest) = latest.filter(|entry| {
+            entry.command() == redo.name()
+                && entry.redo.target() == redo.target()
+                && now - entry.created_at < coalesce_window_ms
+        }) {
+            if latest.undo == *redo {
+                conn.execute("DELETE FROM command_journal WHERE id = ?1;", [latest.id])?;
+            } else {
+                conn.execute(
+                    "UPDATE command_journal SET redo_json = ?2 WHERE id = ?1;",
+                    params![latest.id, redo_json],
+                )?;
+            }
+            return Ok(());
+        }
+    }
+
+    conn.execute(
+        "INSERT INTO command_journal (command, target_uuid, undo_json, redo_json, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5);",
+        params![
+            redo.name(),
+            redo.target().to_string(),
+            encode_command(undo)?,
+            redo_json,
+            now
+        ],
+    )?;
+    conn.execute(
+        "DELETE FROM command_journal
+         WHERE id <= (
+            SELECT id FROM command_journal ORDER BY id DESC LIMIT 1 OFFSET ?1
+         );",
+        [JOURNAL_MAX_ENTRIES],
+    )?;
+    Ok(())
+}
+
+fn apply_command(tx: &Transaction<'_>, command: &JournalCommand) -> RepoResult<()> {
+    match command {
+        JournalCommand::NoteContent { atom_id, content } => {
+            let preview = derive_markdown_preview(content);
+            write_note_content_in_tx(
+                tx,
+                *atom_id,
+                content,
+                preview.preview_text.as_deref(),
+                preview.preview_image.as_deref(),
+                0,
+            )
+            .map(|_| ())
+        }
+        JournalCommand::NoteTags { atom_id, tags } => {
+            replace_note_tags_in_tx(tx, *atom_id, tags).map(|_| ())
+        }
+        JournalCommand::AtomTags { atom_id, tags } => {
+            replace_atom_tags_in_tx(tx, *atom_id, tags).map(|_| ())
+        }
+        JournalCommand::NodePosition {
+            node_uuid,
+            parent_uuid,
+            index,
+        } => {
+            if let Some(parent_uuid) = parent_uuid {
+                ensure_valid_move_target(tx, *node_uuid, *parent_uuid).map_err(tree_error)?;
+            }
+            move_node_in_tx(tx, *node_uuid, *parent_uuid, Some(*index))
+                .map(|_| ())
+                .map_err(tree_error)
+        }
+        JournalCommand::AtomStatus { atom_id, status } => {
+            write_atom_status(tx, *atom_id, *status).map(|_| ())
+        }
+        JournalCommand::EventTimes {
+            atom_id,
+            start_at,
+            end_at,
+        } => write_atom_times(tx, *atom_id, *start_at, *end_at).map(|_| ()),
+    }
+}
+
+fn tree_error(err: TreeRepoError) -> RepoError {
+    match err {
+        TreeRepoError::Db(err) => RepoError::Db(err),
+        TreeRepoError::NodeNotFound(id) => RepoError::NotFound(id),
+        other => RepoError::InvalidData(other.to_string()),
+    }
+}
+
+fn encode_command(command: &JournalCommand) -> RepoResult<String> {
+    serde_json::to_string(command)
+        .map_err(|err| RepoError::InvalidData(format!("journal command encode failed: {err}")))
+}
+
+fn decode_command(value: &str) -> RepoResult<JournalCommand> {
+    serde_json::from_str(value)
+        .map_err(|err| RepoError::InvalidData(format!("invalid journal command `{value}`: {err}")))
+}
+
+fn parse_journal_row(row: &Row<'_>) -> RepoResult<JournalEntry> {
+    let undo: String = row.get("undo_json")?;
+    let redo: String = row.get("redo_json")?;
+    Ok(JournalEntry {
+        id: row.get("id")?,
+        undo: decode_command(&undo)?,
+        redo: decode_command(&redo)?,
+        created_at: row.get("created_at")?,
+        undone_at: row.get("undone_at")?,
+    })
+}
+
+fn ensure_journal_connection_ready(conn: &Connection) -> RepoResult<()> {
+    let expected_version = latest_version();
+    let actual_version: u32 = conn.query_row("PRAGMA user_version;", [], |row| row.get(0))?;
+    if actual_version != expected_version {
+        return Err(RepoError::UninitializedConnection {
+            expected_version,
+            actual_version,
+        });
+    }
+
+    let exists: bool = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'command_journal'
+        );",
+        [],
+        |row| row.get(0),
+    )?;
+    if !exists {
+        return Err(RepoError::MissingRequiredTable("command_journal"));
+    }
+    Ok(())
+}
@@ -0,0 +1,381 @@
+//! Note link index persistence.
+//!
+//! # Responsibility
+//! - Store parsed `[[...]]` references in `note_links`.
+//! - Resolve link targets by atom uuid or workspace note title.
+//! - Serve outgoing-link/backlink reads and rename-driven rewrites.
+//! - Track unresolved targets and re-resolve them when matching notes appear.
+//!
+//! # Invariants
+//! - Link rows of one source note are always replaced as a whole.
+//! - Title targets resolve case-insensitively against active `note_ref`
+//!   display names; the oldest matching node wins.
+//! - Links to deleted notes read back as unresolved.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::markdown::preview::derive_markdown_preview;
+use crate::markdown::wiki_links::{
+    rewrite_wiki_link_target, rewrite_wiki_link_to_atom, split_wiki_link_fragment, WikiLink,
+};
+use crate::model::atom::AtomId;
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use crate::repo::journal_repo::{self, JournalCommand};
+use crate::repo::note_repo;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use uuid::Uuid;
+
+/// One stored wiki link between notes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteLink {
+    /// Note containing the link.
+    pub source_atom_id: AtomId,
+    /// Resolved target note; `None` when unresolved or deleted.
+    pub target_atom_id: Option<AtomId>,
+    /// Raw target text inside `[[...]]`.
+    pub target_title: String,
+    /// Optional display label after `|`.
+    pub label: Option<String>,
+    /// Byte offset of the link in source content.
+    pub position: i64,
+    /// Single-line excerpt around the link.
+    pub context: String,
+}
+
+/// Distinct unresolved link targets of one source note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedLinks {
+    /// Note containing the links.
+    pub source_atom_id: AtomId,
+    /// Target texts in document order, deduplicated case-insensitively.
+    pub targets: Vec<String>,
+}
+
+/// Replaces all link rows of `source` with `links`.
+///
+/// Callers own the surrounding transaction.
+pub fn replace_note_links(conn: &Connection, source: AtomId, links: &[WikiLink]) -> RepoResult<()> {
+    let source_uuid = source.to_string();
+    conn.execute(
+        "DELETE FROM note_links WHERE source_atom_uuid = ?1;",
+        [source_uuid.as_str()],
+    )?;
+    for link in links {
+        let target = resolve_link_target(conn, link.target.as_str())?;
+        conn.execute(
+            "INSERT INTO note_links (
+                source_atom_uuid,
+                target_atom_uuid,
+                target_title,
+                label,
+                position,
+                context
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6);",
+            params![
+                source_uuid.as_str(),
+                target,
+                link.target.as_str(),
+                link.label.as_deref(),
+                link.start as i64,
+                link.context.as_str(),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Lists links found in `source`, in document order.
+pub fn list_outgoing_links(conn: &Connection, source: AtomId) -> RepoResult<Vec<NoteLink>> {
+    let mut stmt = conn.prepare(
+        "SELECT
+            l.source_atom_uuid AS source_atom_uuid,
+            CASE WHEN t.is_deleted = 0 THEN l.target_atom_uuid END AS target_atom_uuid,
+            l.target_title AS target_title,
+            l.label AS label,
+            l.position AS position,
+            l.context AS context
+         FROM note_links l
+         LEFT JOIN atoms t ON t.uuid = l.target_atom_uuid
+         WHERE l.source_atom_uuid = ?1
+         ORDER BY l.position ASC, l.id ASC;",
+    )?;
+    let mut rows = stmt.query([source.to_string()])?;
+    let mut links = Vec::new();
+    while let Some(row) = rows.next()? {
+        links.push(parse_note_link_row(row)?);
+    }
+    Ok(links)
+}
+
+/// Lists links pointing at `target` from active notes.
+///
+/// Ordered by source `updated_at DESC`, then source uuid and position.
+pub fn list_backlinks(conn: &Connection, target: AtomId) -> RepoResult<Vec<NoteLink>> {
+    let mut stmt = conn.prepare(
+        "SELECT
+            l.source_atom_uuid AS source_atom_uuid,
+            l.target_atom_uuid AS target_atom_uuid,
+            l.target_title AS target_title,
+            l.label AS label,
+            l.position AS position,
+            l.context AS context
+         FROM note_links l
+         INNER JOIN atoms s ON s.uuid = l.source_atom_uuid
+         WHERE l.target_atom_uuid = ?1
+           AND s.is_deleted = 0
+         ORDER BY s.updated_at DESC, l.source_atom_uuid ASC, l.position ASC;",
+    )?;
+    let mut rows = stmt.query([target.to_string()])?;
+    let mut links = Vec::new();
+    while let Some(row) = rows.next()? {
+        links.push(parse_note_link_row(row)?);
+    }
+    Ok(links)
+}
+
+/// Lists unresolved link targets grouped per active source note.
+///
+/// Links to deleted notes count as unresolved. Sources are ordered by
+/// `updated_at DESC, uuid ASC`; `source = Some(..)` restricts to one note.
+pub fn list_unresolved_links(
+    conn: &Connection,
+    source: Option<AtomId>,
+) -> RepoResult<Vec<UnresolvedLinks>> {
+    let mut stmt = conn.prepare(
+        "SELECT l.source_atom_uuid, l.target_title
+         FROM note_links l
+         INNER JOIN atoms s ON s.uuid = l.source_atom_uuid
+         LEFT JOIN atoms t ON t.uuid = l.target_atom_uuid
+         WHERE s.is_deleted = 0
+           AND (t.uuid IS NULL OR t.is_deleted = 1)
+           AND (?1 IS NULL OR l.source_atom_uuid = ?1)
+         ORDER BY s.updated_at DESC, l.source_atom_uuid ASC, l.position ASC, l.id ASC;",
+    )?;
+    let mut rows = stmt.query([source.map(|value| value.to_string())])?;
+    let mut grouped: Vec<UnresolvedLinks> = Vec::new();
+    while let Some(row) = rows.next()? {
+        let source_uuid: String = row.get(0)?;
+        let target: String = row.get(1)?;
+        let source_atom_id = parse_uuid(source_uuid.as_str())?;
+        if grouped.last().map(|group| group.source_atom_id) != Some(source_atom_id) {
+            grouped.push(UnresolvedLinks {
+                source_atom_id,
+                targets: Vec::new(),
+            });
+        }
+        let group = grouped.last_mut().expect("group pushed above");
+        let lowered = target.to_lowercase();
+        if !group
+            .targets
+            .iter()
+            .any(|existing| existing.to_lowercase() == lowered)
+        {
+            group.targets.push(target);
+        }
+    }
+    Ok(grouped)
+}
+
+/// Returns whether `source` has an unresolved link to `title`.
+pub fn has_unresolved_link(conn: &Connection, source: AtomId, title: &str) -> RepoResult<bool> {
+    let exists: i64 = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1
+            FROM note_links l
+            LEFT JOIN atoms t ON t.uuid = l.target_atom_uuid
+            WHERE l.source_atom_uuid = ?1
+              AND (
+                    l.target_title = ?2 COLLATE NOCASE
+                    OR substr(l.target_title, 1, length(?2) + 1) = ?2 || '#' COLLATE NOCASE
+              )
+              AND (t.uuid IS NULL OR t.is_deleted = 1)
+        );",
+        params![source.to_string(), title],
+        |row| row.get(0),
+    )?;
+    Ok(exists == 1)
+}
+
+/// Points unresolved links whose target text is `title` at the note that
+/// now carries that title.
+///
+/// Returns the number of links that became resolved. Callers own the
+/// surrounding transaction.
+pub fn resolve_pending_title_links(conn: &Connection, title: &str) -> RepoResult<usize> {
+    let Some(target) = resolve_link_target(conn, title)? else {
+        return Ok(0);
+    };
+    let changed = conn.execute(
+        "UPDATE note_links
+         SET target_atom_uuid = ?2
+         WHERE (
+                target_title = ?1 COLLATE NOCASE
+                OR substr(target_title, 1, length(?1) + 1) = ?1 || '#' COLLATE NOCASE
+           )
+           AND (
+                target_atom_uuid IS NULL
+                OR target_atom_uuid NOT IN (SELECT uuid FROM atoms WHERE is_deleted = 0)
+           );",
+        params![title, target],
+    )?;
+    Ok(changed)
+}
+
+/// Rewrites `[[old_title]]` links that resolve to `target` into
+/// `[[new_title]]` across all active source notes.
+///
+/// Returns the number of rewritten source notes. Callers own the
+/// surrounding transaction.
+pub fn rewrite_links_for_rename(
+    conn: &Connection,
+    target: AtomId,
+    old_title: &str,
+    new_title: &str,
+) -> RepoResult<usize> {
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT s.uuid, s.content
+         FROM note_links l
+         INNER JOIN atoms s ON s.uuid = l.source_atom_uuid
+         WHERE l.target_atom_uuid = ?1
+           AND (
+                l.target_title = ?2 COLLATE NOCASE
+                OR substr(l.target_title, 1, length(?2) + 1) = ?2 || '#' COLLATE NOCASE
+           )
+           AND s.is_deleted = 0;",
+    )?;
+    let sources = stmt
+        .query_map(params![target.to_string(), old_title], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut rewritten = 0;
+    for (source_uuid, content) in sources {
+        let Some(updated) = rewrite_wiki_link_target(content.as_str(), old_title, new_title) else {
+            continue;
+        };
+        write_source_content(conn, parse_uuid(source_uuid.as_str())?, updated.as_str())?;
+        rewritten += 1;
+    }
+    Ok(rewritten)
+}
+
+/// Rewrites `[[title]]` links in `source` into `[[target-uuid|title]]` and
+/// reindexes its links.
+///
+/// Returns `false` when `source` is missing or has no such link. Callers
+/// own the surrounding transaction.
+pub fn point_links_at_atom(
+    conn: &Connection,
+    source: AtomId,
+    title: &str,
+    target: AtomId,
+) -> RepoResult<bool> {
+    let content: Option<String> = conn
+        .query_row(
+            "SELECT content
+             FROM atoms
+             WHERE uuid = ?1
+               AND type = 'note'
+               AND is_deleted = 0;",
+            [source.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(updated) = content
+        .as_deref()
+        .and_then(|content| rewrite_wiki_link_to_atom(content, title, target))
+    else {
+        return Ok(false);
+    };
+    write_source_content(conn, source, updated.as_str())?;
+    Ok(true)
+}
+
+/// Stores rewritten source content through the note write path, so the
+/// rewrite gets its own revision, fresh previews, a full re-index and an
+/// undoable journal entry.
+fn write_source_content(conn: &Connection, source: AtomId, content: &str) -> RepoResult<()> {
+    let preview = derive_markdown_preview(content);
+    // Why: a zero window keeps the rewrite out of the source note's
+    // in-progress revision and journal entry, so it can be told apart in
+    // history and undone on its own.
+    let previous_content = note_repo::write_note_content_in_tx(
+        conn,
+        source,
+        content,
+        preview.preview_text.as_deref(),
+        preview.preview_image.as_deref(),
+        0,
+    )?;
+    journal_repo::record_command(
+        conn,
+        &JournalCommand::NoteContent {
+            atom_id: source,
+            content: previous_content,
+        },
+        &JournalCommand::NoteContent {
+            atom_id: source,
+            content: content.to_string(),
+        },
+        0,
+    )
+}
+
+/// Resolves one link target to an active note uuid.
+///
+/// A `#fragment` suffix (heading or `^block` reference) is ignored.
+fn resolve_link_target(conn: &Connection, target: &str) -> RepoResult<Option<String>> {
+    let target = split_wiki_link_fragment(target).0;
+    if let Ok(atom_id) = Uuid::parse_str(target) {
+        let found = conn
+            .query_row(
+                "SELECT uuid
+                 FROM atoms
+                 WHERE uuid = ?1
+                   AND type = 'note'
+                   AND is_deleted = 0;",
+                [atom_id.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        return Ok(found);
+    }
+
+    let found = conn
+        .query_row(
+            "SELECT a.uuid
+             FROM workspace_nodes n
+             INNER JOIN atoms a ON a.uuid = n.atom_uuid
+             WHERE n.kind = 'note_ref'
+               AND n.is_deleted = 0
+               AND a.type = 'note'
+               AND a.is_deleted = 0
+               AND n.display_name = ?1 COLLATE NOCASE
+             ORDER BY n.created_at ASC, n.node_uuid ASC
+             LIMIT 1;",
+            [target],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(found)
+}
+
+fn parse_note_link_row(row: &Row<'_>) -> RepoResult<NoteLink> {
+    let source: String = row.get("source_atom_uuid")?;
+    let target: Option<String> = row.get("target_atom_uuid")?;
+    Ok(NoteLink {
+        source_atom_id: parse_uuid(source.as_str())?,
+        target_atom_id: target.as_deref().map(parse_uuid).transpose()?,
+        target_title: row.get("target_title")?,
+        label: row.get("label")?,
+        position: row.get("position")?,
+        context: row.get("context")?,
+    })
+}
+
+fn parse_uuid(value: &str) -> RepoResult<AtomId> {
+    Uuid::parse_str(value)
+        .map_err(|_| RepoError::InvalidData(format!("invalid uuid value `{value}` in note_links")))
+}
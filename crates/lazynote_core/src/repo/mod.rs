@@ -13,5 +13,16 @@
 //! - docs/releases/v0.1/prs/PR-0006-core-crud.md
 
 pub mod atom_repo;
+pub mod attachment_repo;
+pub mod blob_store;
+pub mod block_repo;
+pub mod journal_repo;
+pub mod link_repo;
 pub mod note_repo;
+pub mod note_task_repo;
+pub mod property_repo;
+pub mod tag_filter;
+pub mod tag_repo;
+pub mod template_repo;
+pub mod trash_repo;
 pub mod tree_repo;
@@ -3,25 +3,58 @@
 //! # Responsibility
 //! - Provide note-only persistence APIs on top of canonical `atoms`.
 //! - Own tag-link replacement logic (`note_set_tags`) with atomic semantics.
+//! - Record note content history in `note_revisions` on every full update.
+//! - Persist the wiki link index for outgoing-link/backlink queries.
+//! - Apply parsed front matter (tags, title, time fields, properties).
+//! - Create notes from templates.
 //!
 //! # Invariants
 //! - All note queries are constrained to `type='note'` and `is_deleted=0`.
 //! - `note_set_tags` replaces the whole tag set in a single transaction.
 //! - Tag names are normalized to lowercase before persistence.
+//! - Content updates inside one revision window rewrite the newest revision
+//!   instead of appending; restores always append.
+//! - Front-matter tags replace the note's `front_matter` tag links; manual
+//!   and inline links are kept.
 //!
 //! # See also
 //! - docs/releases/v0.1/prs/PR-0010B-notes-tags-core-ffi.md
 
+use crate::markdown::blocks::{parse_blocks, MarkdownBlock};
+use crate::markdown::checklist::parse_checklist;
+use crate::markdown::front_matter::{parse_front_matter, FrontMatter};
+use crate::markdown::preview::derive_markdown_preview;
+use crate::markdown::wiki_links::{parse_wiki_links, WikiLink};
 use crate::model::atom::{Atom, AtomId, AtomType};
-use crate::repo::atom_repo::{AtomRepository, RepoError, RepoResult, SqliteAtomRepository};
+use crate::repo::atom_repo::{
+    self, push_atom_flag_filters, RepoError, RepoResult, SqliteAtomRepository,
+};
+use crate::repo::block_repo::{self, NoteBlock};
+use crate::repo::journal_repo::{self, JournalCommand};
+use crate::repo::link_repo::{self, NoteLink, UnresolvedLinks};
+use crate::repo::note_task_repo::{self, NoteTask};
+use crate::repo::property_repo::{self, AtomProperty, PropertyFilter};
+use crate::repo::tag_filter::{push_tag_filter, TagFilter};
+use crate::repo::tag_repo::{self, tag_subtree_sql, TAG_PATH_SEPARATOR};
+use crate::repo::template_repo;
+use crate::repo::tree_repo::WorkspaceNodeId;
+use crate::sync::provider_types::now_epoch_ms;
 use rusqlite::types::Value;
-use rusqlite::{params, params_from_iter, Connection, Transaction, TransactionBehavior};
+use rusqlite::{
+    params, params_from_iter, Connection, OptionalExtension, Transaction, TransactionBehavior,
+};
 use std::collections::{BTreeSet, HashMap};
 use uuid::Uuid;
 
 const NOTES_DEFAULT_LIMIT: u32 = 10;
 const NOTES_LIMIT_MAX: u32 = 50;
 
+/// Default revision coalescing window (5 minutes).
+///
+/// Why: autosave writes every few seconds; one revision per burst of edits
+/// keeps history useful without storing every keystroke.
+pub const NOTE_REVISION_WINDOW_MS: i64 = 5 * 60 * 1000;
+
 /// Read model for note list/detail use-cases.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NoteRecord {
@@ -33,14 +66,41 @@ pub struct NoteRecord {
     pub preview_text: Option<String>,
     /// Derived first markdown image path (nullable).
     pub preview_image: Option<String>,
+    /// Derived first heading text (nullable).
+    pub title: Option<String>,
+    /// Derived rendered word count.
+    pub word_count: u32,
+    /// Derived reading time in whole minutes.
+    pub reading_time_minutes: u32,
     /// Update timestamp in epoch milliseconds.
     pub updated_at: i64,
     /// Note tags, normalized to lowercase.
     pub tags: Vec<String>,
+    /// Hidden from default lists; not deleted.
+    pub is_archived: bool,
+    /// Sorted first in lists.
+    pub is_pinned: bool,
+    /// User favorite.
+    pub is_starred: bool,
+}
+
+/// One stored version of a note's content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteRevision {
+    /// Monotonic revision id; newest revision has the highest id.
+    pub revision_id: i64,
+    /// Owning note atom id.
+    pub atom_id: AtomId,
+    /// Full markdown content of this version.
+    pub content: String,
+    /// Start of this revision's coalescing window in epoch milliseconds.
+    pub created_at: i64,
+    /// Last write coalesced into this revision in epoch milliseconds.
+    pub updated_at: i64,
 }
 
 /// Query options for note list use-cases.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct NoteListQuery {
     /// Optional single-tag exact match filter.
     pub tag: Option<String>,
@@ -48,13 +108,30 @@ pub struct NoteListQuery {
     pub limit: Option<u32>,
     /// Number of rows to skip.
     pub offset: u32,
+    /// Property predicates; all must match.
+    pub properties: Vec<PropertyFilter>,
+    /// Boolean tag expression; combined with `tag` when both are set.
+    pub tag_filter: Option<TagFilter>,
+    /// Whether archived notes are included.
+    pub include_archived: bool,
+    /// `Some(value)` keeps only notes whose pin flag equals `value`.
+    pub pinned: Option<bool>,
+    /// `Some(value)` keeps only notes whose star flag equals `value`.
+    pub starred: Option<bool>,
 }
 
 /// Repository interface for notes/tags operations.
 pub trait NoteRepository {
     /// Creates one note atom and returns its stable id.
+    ///
+    /// Links, front matter, blocks and checklist tasks are indexed in the
+    /// same transaction.
     fn create_note(&self, atom: &Atom) -> RepoResult<AtomId>;
     /// Replaces full note content and preview fields.
+    ///
+    /// Records the new content as a revision, coalescing with the newest
+    /// revision when it started inside the revision window, and re-indexes
+    /// the content in the same transaction.
     fn update_note_full(
         &self,
         atom_id: AtomId,
@@ -64,17 +141,76 @@ pub trait NoteRepository {
     ) -> RepoResult<()>;
     /// Gets one note by id.
     fn get_note(&self, atom_id: AtomId) -> RepoResult<Option<NoteRecord>>;
-    /// Lists notes using single-tag filter + pagination.
+    /// Lists notes using tag filters + pagination.
     fn list_notes(&self, query: &NoteListQuery) -> RepoResult<Vec<NoteRecord>>;
     /// Replaces all tags for the given note atom in one transaction.
     fn set_note_tags(&mut self, atom_id: AtomId, tags: &[String]) -> RepoResult<()>;
     /// Returns all known tags sorted by name.
     fn list_tags(&self) -> RepoResult<Vec<String>>;
+    /// Replaces full note content and always appends a new revision.
+    fn restore_note_content(
+        &self,
+        atom_id: AtomId,
+        content: &str,
+        preview_text: Option<&str>,
+        preview_image: Option<&str>,
+    ) -> RepoResult<()>;
+    /// Lists revisions of one note, newest first.
+    fn list_note_revisions(&self, atom_id: AtomId) -> RepoResult<Vec<NoteRevision>>;
+    /// Gets one revision of a note by id.
+    fn get_note_revision(
+        &self,
+        atom_id: AtomId,
+        revision_id: i64,
+    ) -> RepoResult<Option<NoteRevision>>;
+    /// Replaces the stored wiki links of one note in one transaction.
+    fn replace_note_links(&self, atom_id: AtomId, links: &[WikiLink]) -> RepoResult<()>;
+    /// Lists links found in one note, in document order.
+    fn outgoing_links(&self, atom_id: AtomId) -> RepoResult<Vec<NoteLink>>;
+    /// Lists links from active notes that resolve to one note.
+    fn backlinks(&self, atom_id: AtomId) -> RepoResult<Vec<NoteLink>>;
+    /// Lists unresolved link targets per source note, optionally for one
+    /// source only.
+    fn unresolved_links(&self, source: Option<AtomId>) -> RepoResult<Vec<UnresolvedLinks>>;
+    /// Applies parsed front matter of one note in one transaction.
+    ///
+    /// Tags replace the note's front-matter tag links, `title` renames the
+    /// note's `note_ref` nodes, time fields overwrite `start_at`/`end_at`
+    /// when present, and front-matter tags and properties are replaced as a
+    /// whole (cleared when `front_matter` is `None`).
+    fn apply_front_matter(
+        &self,
+        atom_id: AtomId,
+        front_matter: Option<&FrontMatter>,
+    ) -> RepoResult<()>;
+    /// Lists stored properties of one note ordered by key.
+    fn list_note_properties(&self, atom_id: AtomId) -> RepoResult<Vec<AtomProperty>>;
+    /// Lists active task atoms projected from one note, in document order.
+    fn list_note_tasks(&self, atom_id: AtomId) -> RepoResult<Vec<NoteTask>>;
+    /// Re-indexes the markdown blocks of one note in one transaction,
+    /// keeping ids of unchanged or edited blocks.
+    fn sync_note_blocks(&self, atom_id: AtomId, blocks: &[MarkdownBlock]) -> RepoResult<()>;
+    /// Lists indexed blocks of one note in document order.
+    fn list_note_blocks(&self, atom_id: AtomId) -> RepoResult<Vec<NoteBlock>>;
+    /// Gets one indexed block of a note by id.
+    fn get_note_block(&self, atom_id: AtomId, block_id: &str) -> RepoResult<Option<NoteBlock>>;
+    /// Creates one note with manual `tags` in one transaction and, when
+    /// `folder_uuid` is set, files it there under `display_name`.
+    ///
+    /// Returns the new `note_ref` node, if any.
+    fn create_note_from_template(
+        &self,
+        atom: &Atom,
+        tags: &[String],
+        folder_uuid: Option<WorkspaceNodeId>,
+        display_name: &str,
+    ) -> RepoResult<Option<WorkspaceNodeId>>;
 }
 
 /// SQLite-backed notes/tags repository.
 pub struct SqliteNoteRepository<'conn> {
     conn: &'conn mut Connection,
+    revision_window_ms: i64,
 }
 
 impl<'conn> SqliteNoteRepository<'conn> {
@@ -82,7 +218,50 @@ impl<'conn> SqliteNoteRepository<'conn> {
     pub fn try_new(conn: &'conn mut Connection) -> RepoResult<Self> {
         let _ = SqliteAtomRepository::try_new(conn)?;
         ensure_note_connection_ready(conn)?;
-        Ok(Self { conn })
+        Ok(Self {
+            conn,
+            revision_window_ms: NOTE_REVISION_WINDOW_MS,
+        })
+    }
+
+    /// Overrides the revision coalescing window; `0` disables coalescing.
+    pub fn with_revision_window_ms(mut self, window_ms: i64) -> Self {
+        self.revision_window_ms = window_ms.max(0);
+        self
+    }
+
+    fn write_note_content(
+        &self,
+        atom_id: AtomId,
+        content: &str,
+        preview_text: Option<&str>,
+        preview_image: Option<&str>,
+        coalesce: bool,
+    ) -> RepoResult<()> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let window_ms = if coalesce { self.revision_window_ms } else { 0 };
+        let previous_content = write_note_content_in_tx(
+            &tx,
+            atom_id,
+            content,
+            preview_text,
+            preview_image,
+            window_ms,
+        )?;
+        journal_repo::record_command(
+            &tx,
+            &JournalCommand::NoteContent {
+                atom_id,
+                content: previous_content,
+            },
+            &JournalCommand::NoteContent {
+                atom_id,
+                content: content.to_string(),
+            },
+            window_ms,
+        )?;
+        tx.commit()?;
+        Ok(())
     }
 }
 
@@ -94,8 +273,11 @@ impl NoteRepository for SqliteNoteRepository<'_> {
             ));
         }
 
-        let repo = SqliteAtomRepository::try_new(self.conn)?;
-        repo.create_atom(atom)
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        atom_repo::create_atom_in_tx(&tx, atom)?;
+        index_note_content_in_tx(&tx, atom.uuid, atom.content.as_str(), None)?;
+        tx.commit()?;
+        Ok(atom.uuid)
     }
 
     fn update_note_full(
@@ -105,24 +287,7 @@ impl NoteRepository for SqliteNoteRepository<'_> {
         preview_text: Option<&str>,
         preview_image: Option<&str>,
     ) -> RepoResult<()> {
-        let changed = self.conn.execute(
-            "UPDATE atoms
-             SET
-                content = ?2,
-                preview_text = ?3,
-                preview_image = ?4,
-                updated_at = (strftime('%s', 'now') * 1000)
-             WHERE uuid = ?1
-               AND type = 'note'
-               AND is_deleted = 0;",
-            params![atom_id.to_string(), content, preview_text, preview_image,],
-        )?;
-
-        if changed == 0 {
-            return Err(RepoError::NotFound(atom_id));
-        }
-
-        Ok(())
+        self.write_note_content(atom_id, content, preview_text, preview_image, true)
     }
 
     fn get_note(&self, atom_id: AtomId) -> RepoResult<Option<NoteRecord>> {
@@ -133,7 +298,13 @@ impl NoteRepository for SqliteNoteRepository<'_> {
                 content,
                 preview_text,
                 preview_image,
-                updated_at
+                preview_title,
+                word_count,
+                reading_time_minutes,
+                updated_at,
+                is_archived,
+                is_pinned,
+                is_starred
              FROM atoms
              WHERE uuid = ?1
                AND type = 'note'
@@ -150,8 +321,14 @@ impl NoteRepository for SqliteNoteRepository<'_> {
                 content: row.get("content")?,
                 preview_text: row.get("preview_text")?,
                 preview_image: row.get("preview_image")?,
+                title: row.get("preview_title")?,
+                word_count: row.get("word_count")?,
+                reading_time_minutes: row.get("reading_time_minutes")?,
                 updated_at: row.get("updated_at")?,
                 tags,
+                is_archived: row.get("is_archived")?,
+                is_pinned: row.get("is_pinned")?,
+                is_starred: row.get("is_starred")?,
             }));
         }
 
@@ -165,27 +342,52 @@ impl NoteRepository for SqliteNoteRepository<'_> {
                 content,
                 preview_text,
                 preview_image,
-                updated_at
+                preview_title,
+                word_count,
+                reading_time_minutes,
+                updated_at,
+                is_archived,
+                is_pinned,
+                is_starred
              FROM atoms
              WHERE type = 'note'
                AND is_deleted = 0",
         );
         let mut bind_values: Vec<Value> = Vec::new();
+        push_atom_flag_filters(
+            &mut sql,
+            "",
+            query.include_archived,
+            query.pinned,
+            query.starred,
+        );
 
         if let Some(tag) = query.tag.as_ref() {
-            sql.push_str(
+            bind_values.push(Value::Text(
+                normalize_tag(tag).unwrap_or_else(|| tag.clone()),
+            ));
+            sql.push_str(&format!(
                 " AND EXISTS (
                     SELECT 1
                     FROM atom_tags at
                     INNER JOIN tags t ON t.id = at.tag_id
                     WHERE at.atom_uuid = atoms.uuid
-                      AND t.name = ? COLLATE NOCASE
+                      AND {}
                 )",
-            );
-            bind_values.push(Value::Text(tag.clone()));
+                tag_subtree_sql("t.name", &format!("?{}", bind_values.len()))
+            ));
+        }
+        property_repo::push_property_filters(
+            &mut sql,
+            &mut bind_values,
+            "atoms.uuid",
+            &query.properties,
+        )?;
+        if let Some(filter) = query.tag_filter.as_ref() {
+            push_tag_filter(&mut sql, &mut bind_values, "atoms.uuid", filter)?;
         }
 
-        sql.push_str(" ORDER BY updated_at DESC, uuid ASC");
+        sql.push_str(" ORDER BY is_pinned DESC, updated_at DESC, uuid ASC");
         let limit = normalize_note_limit(query.limit);
         sql.push_str(" LIMIT ?");
         bind_values.push(Value::Integer(i64::from(limit)));
@@ -206,8 +408,14 @@ impl NoteRepository for SqliteNoteRepository<'_> {
                 content: row.get("content")?,
                 preview_text: row.get("preview_text")?,
                 preview_image: row.get("preview_image")?,
+                title: row.get("preview_title")?,
+                word_count: row.get("word_count")?,
+                reading_time_minutes: row.get("reading_time_minutes")?,
                 updated_at: row.get("updated_at")?,
                 tags,
+                is_archived: row.get("is_archived")?,
+                is_pinned: row.get("is_pinned")?,
+                is_starred: row.get("is_starred")?,
             });
         }
 
@@ -215,50 +423,22 @@ impl NoteRepository for SqliteNoteRepository<'_> {
     }
 
     fn set_note_tags(&mut self, atom_id: AtomId, tags: &[String]) -> RepoResult<()> {
-        let atom_id_text = atom_id.to_string();
         let tx = self
             .conn
             .transaction_with_behavior(TransactionBehavior::Immediate)?;
-        if !note_exists_in_tx(&tx, atom_id_text.as_str())? {
-            return Err(RepoError::NotFound(atom_id));
-        }
-
-        tx.execute(
-            "DELETE FROM atom_tags WHERE atom_uuid = ?1;",
-            [atom_id_text.as_str()],
-        )?;
-
-        for tag in tags {
-            tx.execute(
-                "INSERT OR IGNORE INTO tags (name) VALUES (?1);",
-                [tag.as_str()],
-            )?;
-            tx.execute(
-                "INSERT INTO atom_tags (atom_uuid, tag_id)
-                 SELECT ?1, id
-                 FROM tags
-                 WHERE name = ?2 COLLATE NOCASE;",
-                params![atom_id_text.as_str(), tag.as_str()],
-            )?;
-        }
-
-        tx.execute(
-            // Why: v0.1 filter chips should represent tags that still have at
-            // least one note reference; prune detached dictionary rows here.
-            "DELETE FROM tags
-             WHERE id NOT IN (SELECT DISTINCT tag_id FROM atom_tags);",
-            [],
-        )?;
-
-        tx.execute(
-            "UPDATE atoms
-             SET updated_at = (strftime('%s', 'now') * 1000)
-             WHERE uuid = ?1
-               AND type = 'note'
-               AND is_deleted = 0;",
-            [atom_id_text.as_str()],
+        let previous = replace_note_tags_in_tx(&tx, atom_id, tags)?;
+        journal_repo::record_command(
+            &tx,
+            &JournalCommand::NoteTags {
+                atom_id,
+                tags: previous,
+            },
+            &JournalCommand::NoteTags {
+                atom_id,
+                tags: tags.to_vec(),
+            },
+            0,
         )?;
-
         tx.commit()?;
         Ok(())
     }
@@ -275,6 +455,427 @@ impl NoteRepository for SqliteNoteRepository<'_> {
         }
         Ok(tags)
     }
+
+    fn restore_note_content(
+        &self,
+        atom_id: AtomId,
+        content: &str,
+        preview_text: Option<&str>,
+        preview_image: Option<&str>,
+    ) -> RepoResult<()> {
+        self.write_note_content(atom_id, content, preview_text, preview_image, false)
+    }
+
+    fn list_note_revisions(&self, atom_id: AtomId) -> RepoResult<Vec<NoteRevision>> {
+        if self.get_note(atom_id)?.is_none() {
+            return Err(RepoError::NotFound(atom_id));
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, content, created_at, updated_at
+             FROM note_revisions
+             WHERE atom_uuid = ?1
+             ORDER BY id DESC;",
+        )?;
+        let mut rows = stmt.query([atom_id.to_string()])?;
+        let mut revisions = Vec::new();
+        while let Some(row) = rows.next()? {
+            revisions.push(NoteRevision {
+                revision_id: row.get("id")?,
+                atom_id,
+                content: row.get("content")?,
+                created_at: row.get("created_at")?,
+                updated_at: row.get("updated_at")?,
+            });
+        }
+        Ok(revisions)
+    }
+
+    fn get_note_revision(
+        &self,
+        atom_id: AtomId,
+        revision_id: i64,
+    ) -> RepoResult<Option<NoteRevision>> {
+        let revision = self
+            .conn
+            .query_row(
+                "SELECT r.content, r.created_at, r.updated_at
+                 FROM note_revisions r
+                 INNER JOIN atoms a ON a.uuid = r.atom_uuid
+                 WHERE r.id = ?1
+                   AND r.atom_uuid = ?2
+                   AND a.type = 'note'
+                   AND a.is_deleted = 0;",
+                params![revision_id, atom_id.to_string()],
+                |row| {
+                    Ok(NoteRevision {
+                        revision_id,
+                        atom_id,
+                        content: row.get(0)?,
+                        created_at: row.get(1)?,
+                        updated_at: row.get(2)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(revision)
+    }
+
+    fn replace_note_links(&self, atom_id: AtomId, links: &[WikiLink]) -> RepoResult<()> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        if !note_exists_in_tx(&tx, atom_id.to_string().as_str())? {
+            return Err(RepoError::NotFound(atom_id));
+        }
+        link_repo::replace_note_links(&tx, atom_id, links)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn outgoing_links(&self, atom_id: AtomId) -> RepoResult<Vec<NoteLink>> {
+        link_repo::list_outgoing_links(self.conn, atom_id)
+    }
+
+    fn backlinks(&self, atom_id: AtomId) -> RepoResult<Vec<NoteLink>> {
+        link_repo::list_backlinks(self.conn, atom_id)
+    }
+
+    fn unresolved_links(&self, source: Option<AtomId>) -> RepoResult<Vec<UnresolvedLinks>> {
+        link_repo::list_unresolved_links(self.conn, source)
+    }
+
+    fn apply_front_matter(
+        &self,
+        atom_id: AtomId,
+        front_matter: Option<&FrontMatter>,
+    ) -> RepoResult<()> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        apply_front_matter_in_tx(&tx, atom_id, front_matter, None)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn list_note_properties(&self, atom_id: AtomId) -> RepoResult<Vec<AtomProperty>> {
+        property_repo::list_properties(self.conn, atom_id)
+    }
+
+    fn list_note_tasks(&self, atom_id: AtomId) -> RepoResult<Vec<NoteTask>> {
+        note_task_repo::list_note_tasks(self.conn, atom_id)
+    }
+
+    fn sync_note_blocks(&self, atom_id: AtomId, blocks: &[MarkdownBlock]) -> RepoResult<()> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        if !note_exists_in_tx(&tx, atom_id.to_string().as_str())? {
+            return Err(RepoError::NotFound(atom_id));
+        }
+        block_repo::sync_note_blocks(&tx, atom_id, blocks)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn list_note_blocks(&self, atom_id: AtomId) -> RepoResult<Vec<NoteBlock>> {
+        block_repo::list_note_blocks(self.conn, atom_id)
+    }
+
+    fn get_note_block(&self, atom_id: AtomId, block_id: &str) -> RepoResult<Option<NoteBlock>> {
+        block_repo::get_note_block(self.conn, atom_id, block_id)
+    }
+
+    fn create_note_from_template(
+        &self,
+        atom: &Atom,
+        tags: &[String],
+        folder_uuid: Option<WorkspaceNodeId>,
+        display_name: &str,
+    ) -> RepoResult<Option<WorkspaceNodeId>> {
+        if atom.kind != AtomType::Note {
+            return Err(RepoError::InvalidData(
+                "note repository only accepts AtomType::Note".to_string(),
+            ));
+        }
+
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        atom_repo::create_atom_in_tx(&tx, atom)?;
+        tag_repo::add_atom_tags(&tx, atom.uuid.to_string().as_str(), tags)?;
+        let node_uuid = folder_uuid
+            .map(|folder_uuid| {
+                template_repo::file_note_in_folder(&tx, atom.uuid, folder_uuid, display_name)
+            })
+            .transpose()?;
+        index_note_content_in_tx(&tx, atom.uuid, atom.content.as_str(), None)?;
+        tx.commit()?;
+        Ok(node_uuid)
+    }
+}
+
+/// Writes full note content with preview projections, its revision,
+/// content-derived tags and every other content index (see
+/// [`index_note_content_in_tx`]).
+///
+/// Returns the previous content. Callers own the surrounding transaction.
+pub(crate) fn write_note_content_in_tx(
+    tx: &Connection,
+    atom_id: AtomId,
+    content: &str,
+    preview_text: Option<&str>,
+    preview_image: Option<&str>,
+    window_ms: i64,
+) -> RepoResult<String> {
+    let atom_uuid = atom_id.to_string();
+    let previous: Option<(String, i64)> = tx
+        .query_row(
+            "SELECT content, updated_at
+             FROM atoms
+             WHERE uuid = ?1
+               AND type = 'note'
+               AND is_deleted = 0;",
+            [atom_uuid.as_str()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    let Some((previous_content, previous_updated_at)) = previous else {
+        return Err(RepoError::NotFound(atom_id));
+    };
+
+    let stats = derive_markdown_preview(content);
+    tx.execute(
+        "UPDATE atoms
+         SET
+            content = ?2,
+            preview_text = ?3,
+            preview_image = ?4,
+            preview_title = ?5,
+            word_count = ?6,
+            reading_time_minutes = ?7,
+            updated_at = (strftime('%s', 'now') * 1000)
+         WHERE uuid = ?1;",
+        params![
+            atom_uuid.as_str(),
+            content,
+            preview_text,
+            preview_image,
+            stats.title,
+            stats.word_count,
+            stats.reading_time_minutes
+        ],
+    )?;
+
+    record_revision_in_tx(
+        tx,
+        atom_uuid.as_str(),
+        (previous_content.as_str(), previous_updated_at),
+        content,
+        window_ms,
+    )?;
+    tag_repo::sync_content_tags(tx, atom_uuid.as_str())?;
+    index_note_content_in_tx(tx, atom_id, content, Some(previous_content.as_str()))?;
+    Ok(previous_content)
+}
+
+/// Rebuilds everything derived from note content: wiki links, front matter,
+/// blocks and checklist tasks.
+///
+/// Runs inside every note content write so no index lags the content it
+/// was derived from. `previous_content` is the content being replaced, if
+/// any; see [`apply_front_matter_in_tx`]. Callers own the surrounding
+/// transaction.
+pub(crate) fn index_note_content_in_tx(
+    conn: &Connection,
+    atom_id: AtomId,
+    content: &str,
+    previous_content: Option<&str>,
+) -> RepoResult<()> {
+    let previous_title = previous_content
+        .and_then(parse_front_matter)
+        .and_then(|front_matter| front_matter.title);
+    link_repo::replace_note_links(conn, atom_id, &parse_wiki_links(content))?;
+    apply_front_matter_in_tx(
+        conn,
+        atom_id,
+        parse_front_matter(content).as_ref(),
+        previous_title.as_deref(),
+    )?;
+    block_repo::sync_note_blocks(conn, atom_id, &parse_blocks(content))?;
+    note_task_repo::sync_note_tasks(conn, atom_id, &parse_checklist(content))
+}
+
+/// Replaces the manual tag set of one active note.
+///
+/// `tags` must already be normalized. Returns the previous manual tags.
+/// Callers own the surrounding transaction.
+pub(crate) fn replace_note_tags_in_tx(
+    conn: &Connection,
+    atom_id: AtomId,
+    tags: &[String],
+) -> RepoResult<Vec<String>> {
+    let atom_uuid = atom_id.to_string();
+    if !note_exists_in_tx(conn, atom_uuid.as_str())? {
+        return Err(RepoError::NotFound(atom_id));
+    }
+
+    let previous = tag_repo::manual_atom_tags(conn, atom_uuid.as_str())?;
+    tag_repo::replace_atom_tags(conn, atom_uuid.as_str(), tags)?;
+    Ok(previous)
+}
+
+/// Applies parsed front matter of one active note.
+///
+/// `title:` renames the note's refs only when it differs from
+/// `previous_title`, the title of the content being replaced (`None` when
+/// there is none). Callers own the surrounding transaction.
+fn apply_front_matter_in_tx(
+    conn: &Connection,
+    atom_id: AtomId,
+    front_matter: Option<&FrontMatter>,
+    previous_title: Option<&str>,
+) -> RepoResult<()> {
+    let atom_uuid = atom_id.to_string();
+    if !note_exists_in_tx(conn, atom_uuid.as_str())? {
+        return Err(RepoError::NotFound(atom_id));
+    }
+
+    let empty = Default::default();
+    property_repo::replace_front_matter_properties(
+        conn,
+        atom_id,
+        front_matter.map_or(&empty, |value| &value.properties),
+    )?;
+    let declared = front_matter.map_or_else(Vec::new, |value| normalize_tags(&value.tags));
+    tag_repo::sync_front_matter_tags(conn, atom_uuid.as_str(), &declared)?;
+    let Some(front_matter) = front_matter else {
+        return Ok(());
+    };
+
+    if front_matter.start_at.is_some() || front_matter.end_at.is_some() {
+        let (current_start, current_end): (Option<i64>, Option<i64>) = conn.query_row(
+            "SELECT start_at, end_at FROM atoms WHERE uuid = ?1;",
+            [atom_uuid.as_str()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let mut start_at = front_matter.start_at.or(current_start);
+        let mut end_at = front_matter.end_at.or(current_end);
+        // Why: a declared field wins over a stale stored one that would
+        // leave the window reversed.
+        if let (Some(start), Some(end)) = (start_at, end_at) {
+            if end < start {
+                if front_matter.start_at.is_some() {
+                    end_at = front_matter.end_at;
+                } else {
+                    start_at = None;
+                }
+            }
+        }
+        conn.execute(
+            "UPDATE atoms
+             SET start_at = ?2,
+                 end_at = ?3
+             WHERE uuid = ?1;",
+            params![atom_uuid.as_str(), start_at, end_at],
+        )?;
+    }
+
+    // Why: renaming on every save would undo explorer renames; only a
+    // changed `title:` renames the note's refs.
+    if let Some(title) = front_matter.title.as_deref() {
+        if previous_title != Some(title) {
+            rename_note_refs_in_tx(conn, atom_id, title)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Renames every active `note_ref` of one note to `title`, carrying title
+/// links along like a workspace rename does.
+fn rename_note_refs_in_tx(conn: &Connection, atom_id: AtomId, title: &str) -> RepoResult<()> {
+    let mut stmt = conn.prepare(
+        "SELECT node_uuid, display_name
+         FROM workspace_nodes
+         WHERE atom_uuid = ?1
+           AND kind = 'note_ref'
+           AND is_deleted = 0
+           AND display_name <> ?2;",
+    )?;
+    let nodes = stmt
+        .query_map(params![atom_id.to_string(), title], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    if nodes.is_empty() {
+        return Ok(());
+    }
+
+    for (node_uuid, old_title) in nodes {
+        conn.execute(
+            "UPDATE workspace_nodes
+             SET display_name = ?2,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE node_uuid = ?1;",
+            params![node_uuid.as_str(), title],
+        )?;
+        link_repo::rewrite_links_for_rename(conn, atom_id, old_title.as_str(), title)?;
+    }
+    link_repo::resolve_pending_title_links(conn, title)?;
+    Ok(())
+}
+
+/// Appends or coalesces the revision for a content write.
+///
+/// Notes without history first get a baseline revision holding the content
+/// they had before this write, so the pre-history version stays restorable.
+fn record_revision_in_tx(
+    tx: &Connection,
+    atom_uuid: &str,
+    previous: (&str, i64),
+    content: &str,
+    window_ms: i64,
+) -> RepoResult<()> {
+    let now = now_epoch_ms();
+    let latest: Option<(i64, String, i64)> = tx
+        .query_row(
+            "SELECT id, content, created_at
+             FROM note_revisions
+             WHERE atom_uuid = ?1
+             ORDER BY id DESC
+             LIMIT 1;",
+            [atom_uuid],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+
+    let coalesce_into = match latest {
+        Some((_, latest_content, _)) if latest_content == content => return Ok(()),
+        Some((id, _, created_at)) if now - created_at < window_ms => Some(id),
+        Some(_) => None,
+        None => {
+            let (previous_content, previous_updated_at) = previous;
+            if previous_content == content {
+                return Ok(());
+            }
+            tx.execute(
+                "INSERT INTO note_revisions (atom_uuid, content, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?3);",
+                params![atom_uuid, previous_content, previous_updated_at],
+            )?;
+            None
+        }
+    };
+
+    match coalesce_into {
+        Some(id) => {
+            tx.execute(
+                "UPDATE note_revisions SET content = ?2, updated_at = ?3 WHERE id = ?1;",
+                params![id, content, now],
+            )?;
+        }
+        None => {
+            tx.execute(
+                "INSERT INTO note_revisions (atom_uuid, content, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?3);",
+                params![atom_uuid, content, now],
+            )?;
+        }
+    }
+    Ok(())
 }
 
 /// Normalizes list limit according to notes contract.
@@ -289,11 +890,15 @@ pub fn normalize_note_limit(limit: Option<u32>) -> u32 {
 
 /// Normalizes one tag value according to notes contract.
 pub fn normalize_tag(tag: &str) -> Option<String> {
-    let trimmed = tag.trim();
-    if trimmed.is_empty() {
+    let segments: Vec<String> = tag
+        .split(TAG_PATH_SEPARATOR)
+        .map(|segment| segment.trim().to_lowercase())
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    if segments.is_empty() {
         None
     } else {
-        Some(trimmed.to_lowercase())
+        Some(segments.join("/"))
     }
 }
 
@@ -367,8 +972,8 @@ pub fn load_tags_for_atoms(
     Ok(map)
 }
 
-fn note_exists_in_tx(tx: &Transaction<'_>, atom_uuid: &str) -> RepoResult<bool> {
-    let exists: i64 = tx.query_row(
+fn note_exists_in_tx(conn: &Connection, atom_uuid: &str) -> RepoResult<bool> {
+    let exists: i64 = conn.query_row(
         "SELECT EXISTS(
             SELECT 1
             FROM atoms
@@ -407,6 +1012,17 @@ fn ensure_note_connection_ready(conn: &Connection) -> RepoResult<()> {
         }
     }
 
+    for table in [
+        "note_revisions",
+        "note_links",
+        "atom_properties",
+        "note_templates",
+    ] {
+        if !table_exists(conn, table)? {
+            return Err(RepoError::MissingRequiredTable(table));
+        }
+    }
+
     Ok(())
 }
 
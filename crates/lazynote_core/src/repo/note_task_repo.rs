@@ -0,0 +1,359 @@
+//! Note checklist task projection persistence.
+//!
+//! # Responsibility
+//! - Project task-list items of a note into child task atoms (`note_tasks`).
+//! - Reflect task status changes back into the note's checkbox.
+//!
+//! # Invariants
+//! - Items keep their task atom across edits: first by identical text, then
+//!   by identical item position.
+//! - A ticked item maps to `done`; unticking a `done` task resets it to
+//!   `todo`, other statuses are kept.
+//! - Tasks whose item disappeared are soft-deleted and unlinked.
+//! - Items with empty text are not projected.
+//! - Tasks share their note's tombstone (migration 30).
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::markdown::checklist::{parse_checklist, set_checklist_item_checked, ChecklistItem};
+use crate::markdown::preview::derive_markdown_preview;
+use crate::model::atom::{AtomId, TaskStatus};
+use crate::repo::atom_repo::{parse_task_status, task_status_to_db, RepoError, RepoResult};
+use crate::repo::note_repo::{self, NOTE_REVISION_WINDOW_MS};
+use crate::repo::tag_repo;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use uuid::Uuid;
+
+/// One task atom projected from a note checklist item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTask {
+    /// Projected task atom.
+    pub task_atom_id: AtomId,
+    /// Note containing the item.
+    pub note_atom_id: AtomId,
+    /// Position among the note's task-list items.
+    pub item_index: u32,
+    /// 1-based line of the item's checkbox.
+    pub line: u32,
+    /// Item text (task content).
+    pub text: String,
+    /// Current task status.
+    pub status: Option<TaskStatus>,
+}
+
+struct LinkedTask {
+    task_uuid: String,
+    item_index: u32,
+    content: String,
+    status: Option<TaskStatus>,
+}
+
+/// Re-syncs the task atoms of `note` with its parsed checklist `items`.
+///
+/// New tasks inherit the note's `start_at`/`end_at`. Callers own the
+/// surrounding transaction.
+pub fn sync_note_tasks(conn: &Connection, note: AtomId, items: &[ChecklistItem]) -> RepoResult<()> {
+    let note_uuid = note.to_string();
+    let mut linked = load_linked_tasks(conn, note_uuid.as_str())?;
+    let mut assigned: Vec<Option<LinkedTask>> = Vec::with_capacity(items.len());
+    for item in items {
+        let position = linked
+            .iter()
+            .position(|task| !item.text.is_empty() && task.content == item.text);
+        assigned.push(position.map(|index| linked.remove(index)));
+    }
+    for (index, item) in items.iter().enumerate() {
+        if assigned[index].is_some() || item.text.is_empty() {
+            continue;
+        }
+        let position = linked
+            .iter()
+            .position(|task| task.item_index as usize == index);
+        assigned[index] = position.map(|found| linked.remove(found));
+    }
+
+    for stale in linked {
+        conn.execute(
+            "DELETE FROM note_tasks WHERE task_atom_uuid = ?1;",
+            [stale.task_uuid.as_str()],
+        )?;
+        conn.execute(
+            "UPDATE atoms
+             SET is_deleted = 1,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE uuid = ?1
+               AND is_deleted = 0;",
+            [stale.task_uuid.as_str()],
+        )?;
+    }
+
+    for (index, (item, task)) in items.iter().zip(assigned).enumerate() {
+        if item.text.is_empty() {
+            continue;
+        }
+        match task {
+            Some(task) => update_linked_task(conn, &task, item, index)?,
+            None => create_linked_task(conn, note_uuid.as_str(), item, index)?,
+        }
+    }
+    Ok(())
+}
+
+/// Lists active task atoms projected from `note`, in document order.
+pub fn list_note_tasks(conn: &Connection, note: AtomId) -> RepoResult<Vec<NoteTask>> {
+    let mut stmt = conn.prepare(
+        "SELECT
+            nt.task_atom_uuid AS task_atom_uuid,
+            nt.note_atom_uuid AS note_atom_uuid,
+            nt.item_index AS item_index,
+            nt.line_number AS line_number,
+            a.content AS content,
+            a.task_status AS task_status
+         FROM note_tasks nt
+         INNER JOIN atoms a ON a.uuid = nt.task_atom_uuid
+         WHERE nt.note_atom_uuid = ?1
+           AND a.is_deleted = 0
+         ORDER BY nt.item_index ASC;",
+    )?;
+    let mut rows = stmt.query([note.to_string()])?;
+    let mut tasks = Vec::new();
+    while let Some(row) = rows.next()? {
+        tasks.push(parse_note_task_row(row)?);
+    }
+    Ok(tasks)
+}
+
+/// Loads the note link of one projected task atom.
+pub fn get_note_task(conn: &Connection, task: AtomId) -> RepoResult<Option<NoteTask>> {
+    let mut stmt = conn.prepare(
+        "SELECT
+            nt.task_atom_uuid AS task_atom_uuid,
+            nt.note_atom_uuid AS note_atom_uuid,
+            nt.item_index AS item_index,
+            nt.line_number AS line_number,
+            a.content AS content,
+            a.task_status AS task_status
+         FROM note_tasks nt
+         INNER JOIN atoms a ON a.uuid = nt.task_atom_uuid
+         WHERE nt.task_atom_uuid = ?1;",
+    )?;
+    let mut rows = stmt.query([task.to_string()])?;
+    match rows.next()? {
+        Some(row) => Ok(Some(parse_note_task_row(row)?)),
+        None => Ok(None),
+    }
+}
+
+/// Ticks or unticks the note checkbox of a projected task to match `status`.
+///
+/// The note is rewritten through the regular content write path, so the
+/// change gets a revision and fresh previews and indexes. No-op for tasks
+/// not projected from a note, or when the note or item is gone. Callers own
+/// the surrounding transaction.
+pub fn apply_task_status_to_note(
+    conn: &Connection,
+    task: AtomId,
+    status: Option<TaskStatus>,
+) -> RepoResult<()> {
+    let Some(link) = get_note_task(conn, task)? else {
+        return Ok(());
+    };
+    let note_uuid = link.note_atom_id.to_string();
+    let content: Option<String> = conn
+        .query_row(
+            "SELECT content
+             FROM atoms
+             WHERE uuid = ?1
+               AND type = 'note'
+               AND is_deleted = 0;",
+            [note_uuid.as_str()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(content) = content else {
+        return Ok(());
+    };
+
+    // Prefer the stored line; fall back to the item position when lines
+    // shifted since the last sync.
+    let items = parse_checklist(content.as_str());
+    let line = items
+        .iter()
+        .find(|item| item.line == link.line && item.text == link.text)
+        .or_else(|| items.get(link.item_index as usize))
+        .map(|item| item.line);
+    let Some(line) = line else {
+        return Ok(());
+    };
+    let checked = status == Some(TaskStatus::Done);
+    let Some(updated) = set_checklist_item_checked(content.as_str(), line, checked) else {
+        return Ok(());
+    };
+    if updated != content {
+        // Why: the checkbox flip is a regular note edit; the shared write
+        // path records a revision and re-indexes the note, which also
+        // refreshes this task's line.
+        let preview = derive_markdown_preview(updated.as_str());
+        note_repo::write_note_content_in_tx(
+            conn,
+            link.note_atom_id,
+            updated.as_str(),
+            preview.preview_text.as_deref(),
+            preview.preview_image.as_deref(),
+            NOTE_REVISION_WINDOW_MS,
+        )?;
+    } else if line != link.line {
+        conn.execute(
+            "UPDATE note_tasks SET line_number = ?2 WHERE task_atom_uuid = ?1;",
+            params![task.to_string(), line],
+        )?;
+    }
+    Ok(())
+}
+
+fn load_linked_tasks(conn: &Connection, note_uuid: &str) -> RepoResult<Vec<LinkedTask>> {
+    let mut stmt = conn.prepare(
+        "SELECT
+            nt.task_atom_uuid,
+            nt.item_index,
+            a.content,
+            a.task_status
+         FROM note_tasks nt
+         INNER JOIN atoms a ON a.uuid = nt.task_atom_uuid
+         WHERE nt.note_atom_uuid = ?1
+         ORDER BY nt.item_index ASC;",
+    )?;
+    let mut rows = stmt.query([note_uuid])?;
+    let mut tasks = Vec::new();
+    while let Some(row) = rows.next()? {
+        tasks.push(LinkedTask {
+            task_uuid: row.get(0)?,
+            item_index: row.get(1)?,
+            content: row.get(2)?,
+            status: read_status(row.get(3)?)?,
+        });
+    }
+    Ok(tasks)
+}
+
+fn update_linked_task(
+    conn: &Connection,
+    task: &LinkedTask,
+    item: &ChecklistItem,
+    index: usize,
+) -> RepoResult<()> {
+    let status = synced_status(task.status, item.checked);
+    if task.content != item.text || task.status != status {
+        let preview = derive_markdown_preview(item.text.as_str());
+        conn.execute(
+            "UPDATE atoms
+             SET
+                content = ?2,
+                preview_text = ?3,
+                preview_title = ?4,
+                word_count = ?5,
+                reading_time_minutes = ?6,
+                task_status = ?7,
+                updated_at = (strftime('%s', 'now') * 1000)
+             WHERE uuid = ?1;",
+            params![
+                task.task_uuid.as_str(),
+                item.text.as_str(),
+                preview.preview_text,
+                preview.title,
+                preview.word_count,
+                preview.reading_time_minutes,
+                status.map(task_status_to_db),
+            ],
+        )?;
+        tag_repo::sync_inline_tags(conn, task.task_uuid.as_str())?;
+    }
+    conn.execute(
+        "UPDATE note_tasks
+         SET item_index = ?2,
+             line_number = ?3
+         WHERE task_atom_uuid = ?1;",
+        params![task.task_uuid.as_str(), index as i64, item.line],
+    )?;
+    Ok(())
+}
+
+fn create_linked_task(
+    conn: &Connection,
+    note_uuid: &str,
+    item: &ChecklistItem,
+    index: usize,
+) -> RepoResult<()> {
+    let task_uuid = Uuid::new_v4().to_string();
+    let status = synced_status(Some(TaskStatus::Todo), item.checked);
+    let preview = derive_markdown_preview(item.text.as_str());
+    conn.execute(
+        "INSERT INTO atoms (
+            uuid,
+            type,
+            content,
+            preview_text,
+            preview_title,
+            word_count,
+            reading_time_minutes,
+            task_status,
+            start_at,
+            end_at
+         )
+         SELECT ?1, 'task', ?2, ?3, ?4, ?5, ?6, ?7, start_at, end_at
+         FROM atoms
+         WHERE uuid = ?8;",
+        params![
+            task_uuid.as_str(),
+            item.text.as_str(),
+            preview.preview_text,
+            preview.title,
+            preview.word_count,
+            preview.reading_time_minutes,
+            status.map(task_status_to_db),
+            note_uuid,
+        ],
+    )?;
+    conn.execute(
+        "INSERT INTO note_tasks (task_atom_uuid, note_atom_uuid, item_index, line_number)
+         VALUES (?1, ?2, ?3, ?4);",
+        params![task_uuid.as_str(), note_uuid, index as i64, item.line],
+    )?;
+    tag_repo::sync_inline_tags(conn, task_uuid.as_str())
+}
+
+fn synced_status(current: Option<TaskStatus>, checked: bool) -> Option<TaskStatus> {
+    match (checked, current) {
+        (true, _) => Some(TaskStatus::Done),
+        (false, Some(TaskStatus::Done)) => Some(TaskStatus::Todo),
+        (false, other) => other,
+    }
+}
+
+fn read_status(value: Option<String>) -> RepoResult<Option<TaskStatus>> {
+    match value {
+        Some(value) => parse_task_status(value.as_str()).map(Some).ok_or_else(|| {
+            RepoError::InvalidData(format!(
+                "invalid task status `{value}` in atoms.task_status"
+            ))
+        }),
+        None => Ok(None),
+    }
+}
+
+fn parse_note_task_row(row: &Row<'_>) -> RepoResult<NoteTask> {
+    Ok(NoteTask {
+        task_atom_id: parse_uuid(row.get::<_, String>("task_atom_uuid")?.as_str())?,
+        note_atom_id: parse_uuid(row.get::<_, String>("note_atom_uuid")?.as_str())?,
+        item_index: row.get("item_index")?,
+        line: row.get("line_number")?,
+        text: row.get("content")?,
+        status: read_status(row.get("task_status")?)?,
+    })
+}
+
+fn parse_uuid(value: &str) -> RepoResult<Uuid> {
+    Uuid::parse_str(value)
+        .map_err(|_| RepoError::InvalidData(format!("invalid uuid value `{value}` in note_tasks")))
+}
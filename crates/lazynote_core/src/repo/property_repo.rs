@@ -0,0 +1,550 @@
+//! Atom property persistence.
+//!
+//! # Responsibility
+//! - Store typed per-atom key/value metadata in `atom_properties`.
+//! - Replace front-matter sourced rows as a whole on note writes.
+//! - Build `EXISTS` predicates for property filters in list queries.
+//!
+//! # Invariants
+//! - Keys are trimmed and lowercased before persistence and filtering.
+//! - `value` always holds the text as written: front-matter values are kept
+//!   verbatim (`007` stays `007`), API values store their rendering.
+//!   `value_number` backs number, date (epoch ms) and bool (0/1) values;
+//!   `value_ref` backs atom refs.
+//! - Text comparisons are case-insensitive.
+//! - `user` rows take precedence: front-matter writes never overwrite a
+//!   property set through the property APIs.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::markdown::front_matter::{format_front_matter_date, parse_front_matter_date};
+use crate::model::atom::AtomId;
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use rusqlite::types::Value;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use uuid::Uuid;
+
+/// `atom_properties.source` value for rows derived from note front matter.
+pub const PROPERTY_SOURCE_FRONT_MATTER: &str = "front_matter";
+/// `atom_properties.source` value for rows written through property APIs.
+pub const PROPERTY_SOURCE_USER: &str = "user";
+
+/// Typed property value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    /// Free text.
+    Text(String),
+    /// Finite number.
+    Number(f64),
+    /// Point in time as epoch milliseconds.
+    Date(i64),
+    /// Boolean flag.
+    Bool(bool),
+    /// Reference to another atom.
+    AtomRef(AtomId),
+}
+
+impl PropertyValue {
+    /// Stable `atom_properties.value_type` label.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Text(_) => "text",
+            Self::Number(_) => "number",
+            Self::Date(_) => "date",
+            Self::Bool(_) => "bool",
+            Self::AtomRef(_) => "atom_ref",
+        }
+    }
+
+    /// Infers a typed value from front-matter text.
+    ///
+    /// `true`/`false` become bools, numerals numbers, ISO dates dates;
+    /// everything else stays text. The inferred type only drives sorting and
+    /// filtering; the stored text stays as written.
+    pub fn infer(text: &str) -> Self {
+        let trimmed = text.trim();
+        if trimmed.eq_ignore_ascii_case("true") || trimmed.eq_ignore_ascii_case("false") {
+            return Self::Bool(trimmed.eq_ignore_ascii_case("true"));
+        }
+        if trimmed.bytes().any(|byte| byte.is_ascii_digit()) {
+            if let Some(number) = trimmed
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+            {
+                return Self::Number(number);
+            }
+            if let Some(epoch_ms) = parse_front_matter_date(trimmed) {
+                return Self::Date(epoch_ms);
+            }
+        }
+        Self::Text(text.to_string())
+    }
+
+    fn number_column(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            Self::Date(value) => Some(*value as f64),
+            Self::Bool(value) => Some(f64::from(u8::from(*value))),
+            Self::Text(_) | Self::AtomRef(_) => None,
+        }
+    }
+}
+
+impl Display for PropertyValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(value) => write!(f, "{value}"),
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Date(value) => write!(f, "{}", format_front_matter_date(*value)),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::AtomRef(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for PropertyValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for PropertyValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<f64> for PropertyValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+/// One stored property of an atom.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtomProperty {
+    /// Lowercased property key.
+    pub key: String,
+    /// Typed value.
+    pub value: PropertyValue,
+    /// Value text as written (front matter) or rendered (property APIs).
+    pub text: String,
+    /// Producer of the row (`user` or `front_matter`).
+    pub source: String,
+    /// Epoch ms of the last write.
+    pub updated_at: i64,
+}
+
+/// Comparison applied by one [`PropertyFilter`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyPredicate {
+    /// Key is present with any value.
+    Exists,
+    /// Value equals; text operands match any value by its text rendering.
+    Equals(PropertyValue),
+    /// Key is present and value does not equal.
+    NotEquals(PropertyValue),
+    /// Value is greater than (text, number or date operands).
+    GreaterThan(PropertyValue),
+    /// Value is greater than or equal.
+    GreaterOrEqual(PropertyValue),
+    /// Value is less than.
+    LessThan(PropertyValue),
+    /// Value is less than or equal.
+    LessOrEqual(PropertyValue),
+    /// Text rendering contains the substring (case-insensitive).
+    Contains(String),
+}
+
+/// Property predicate for list queries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyFilter {
+    /// Property key; matched after trim + lowercase.
+    pub key: String,
+    /// Comparison applied to the value.
+    pub predicate: PropertyPredicate,
+}
+
+impl PropertyFilter {
+    /// Requires `key` to be present with any value.
+    pub fn has(key: impl Into<String>) -> Self {
+        Self::new(key, PropertyPredicate::Exists)
+    }
+
+    /// Requires `key` to equal `value`.
+    pub fn equals(key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        Self::new(key, PropertyPredicate::Equals(value.into()))
+    }
+
+    /// Requires `key` to be present with a value other than `value`.
+    pub fn not_equals(key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        Self::new(key, PropertyPredicate::NotEquals(value.into()))
+    }
+
+    /// Requires `key` to be greater than `value`.
+    pub fn greater_than(key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        Self::new(key, PropertyPredicate::GreaterThan(value.into()))
+    }
+
+    /// Requires `key` to be greater than or equal to `value`.
+    pub fn greater_or_equal(key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        Self::new(key, PropertyPredicate::GreaterOrEqual(value.into()))
+    }
+
+    /// Requires `key` to be less than `value`.
+    pub fn less_than(key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        Self::new(key, PropertyPredicate::LessThan(value.into()))
+    }
+
+    /// Requires `key` to be less than or equal to `value`.
+    pub fn less_or_equal(key: impl Into<String>, value: impl Into<PropertyValue>) -> Self {
+        Self::new(key, PropertyPredicate::LessOrEqual(value.into()))
+    }
+
+    /// Requires the text rendering of `key` to contain `needle`.
+    pub fn contains(key: impl Into<String>, needle: impl Into<String>) -> Self {
+        Self::new(key, PropertyPredicate::Contains(needle.into()))
+    }
+
+    fn new(key: impl Into<String>, predicate: PropertyPredicate) -> Self {
+        Self {
+            key: key.into(),
+            predicate,
+        }
+    }
+}
+
+/// Normalizes one property key; returns `None` for blank keys.
+pub fn normalize_property_key(key: &str) -> Option<String> {
+    let normalized = key.trim().to_lowercase();
+    (!normalized.is_empty()).then_some(normalized)
+}
+
+/// Inserts or replaces one property of an active atom.
+///
+/// Callers own the surrounding transaction.
+pub fn set_property(
+    conn: &Connection,
+    atom: AtomId,
+    key: &str,
+    value: &PropertyValue,
+    source: &str,
+) -> RepoResult<()> {
+    let key = require_key(key)?;
+    if !atom_is_active(conn, atom)? {
+        return Err(RepoError::NotFound(atom));
+    }
+    match value {
+        PropertyValue::Number(number) if !number.is_finite() => {
+            return Err(RepoError::InvalidProperty(format!(
+                "property `{key}` must be a finite number"
+            )));
+        }
+        PropertyValue::AtomRef(target) if !atom_is_active(conn, *target)? => {
+            return Err(RepoError::InvalidProperty(format!(
+                "property `{key}` references missing atom {target}"
+            )));
+        }
+        _ => {}
+    }
+    upsert_property(
+        conn,
+        atom,
+        key.as_str(),
+        value,
+        value.to_string().as_str(),
+        source,
+    )
+}
+
+/// Loads one property of an atom.
+pub fn get_property(
+    conn: &Connection,
+    atom: AtomId,
+    key: &str,
+) -> RepoResult<Option<AtomProperty>> {
+    let key = require_key(key)?;
+    let mut stmt = conn.prepare(
+        "SELECT key, value, value_type, value_number, value_ref, source, updated_at
+         FROM atom_properties
+         WHERE atom_uuid = ?1
+           AND key = ?2;",
+    )?;
+    let mut rows = stmt.query(params![atom.to_string(), key])?;
+    if let Some(row) = rows.next()? {
+        return Ok(Some(parse_property_row(row)?));
+    }
+    Ok(None)
+}
+
+/// Lists properties of one atom ordered by key.
+pub fn list_properties(conn: &Connection, atom: AtomId) -> RepoResult<Vec<AtomProperty>> {
+    let mut stmt = conn.prepare(
+        "SELECT key, value, value_type, value_number, value_ref, source, updated_at
+         FROM atom_properties
+         WHERE atom_uuid = ?1
+         ORDER BY key ASC;",
+    )?;
+    let mut rows = stmt.query([atom.to_string()])?;
+    let mut properties = Vec::new();
+    while let Some(row) = rows.next()? {
+        properties.push(parse_property_row(row)?);
+    }
+    Ok(properties)
+}
+
+/// Removes one property; returns whether a row existed.
+pub fn remove_property(conn: &Connection, atom: AtomId, key: &str) -> RepoResult<bool> {
+    let key = require_key(key)?;
+    let removed = conn.execute(
+        "DELETE FROM atom_properties
+         WHERE atom_uuid = ?1
+           AND key = ?2;",
+        params![atom.to_string(), key],
+    )?;
+    Ok(removed > 0)
+}
+
+/// Replaces all front-matter sourced properties of `atom` with `properties`.
+///
+/// Values are typed with [`PropertyValue::infer`] and stored verbatim. Keys
+/// already set through the property APIs keep their `user` value. Callers
+/// own the surrounding transaction.
+pub fn replace_front_matter_properties(
+    conn: &Connection,
+    atom: AtomId,
+    properties: &BTreeMap<String, String>,
+) -> RepoResult<()> {
+    conn.execute(
+        "DELETE FROM atom_properties
+         WHERE atom_uuid = ?1
+           AND source = ?2;",
+        params![atom.to_string(), PROPERTY_SOURCE_FRONT_MATTER],
+    )?;
+    for (key, value) in properties {
+        let Some(key) = normalize_property_key(key) else {
+            continue;
+        };
+        upsert_property(
+            conn,
+            atom,
+            key.as_str(),
+            &PropertyValue::infer(value),
+            value.as_str(),
+            PROPERTY_SOURCE_FRONT_MATTER,
+        )?;
+    }
+    Ok(())
+}
+
+/// Appends one ` AND EXISTS (...)` clause per filter, matching rows whose
+/// `atom_column` has the property.
+///
+/// Returns [`RepoError::InvalidProperty`] for blank keys and for ordering
+/// comparisons on bool or atom-ref operands.
+pub(crate) fn push_property_filters(
+    sql: &mut String,
+    bind_values: &mut Vec<Value>,
+    atom_column: &str,
+    filters: &[PropertyFilter],
+) -> RepoResult<()> {
+    for filter in filters {
+        let key = require_key(filter.key.as_str())?;
+        sql.push_str(&format!(
+            " AND EXISTS (
+                SELECT 1
+                FROM atom_properties p
+                WHERE p.atom_uuid = {atom_column}
+                  AND p.key = ?"
+        ));
+        bind_values.push(Value::Text(key.clone()));
+        match &filter.predicate {
+            PropertyPredicate::Exists => {}
+            PropertyPredicate::Equals(value) => {
+                sql.push_str(" AND ");
+                push_equals(sql, bind_values, value);
+            }
+            PropertyPredicate::NotEquals(value) => {
+                sql.push_str(" AND NOT (");
+                push_equals(sql, bind_values, value);
+                sql.push(')');
+            }
+            PropertyPredicate::GreaterThan(value) => {
+                push_ordering(sql, bind_values, key.as_str(), ">", value)?
+            }
+            PropertyPredicate::GreaterOrEqual(value) => {
+                push_ordering(sql, bind_values, key.as_str(), ">=", value)?
+            }
+            PropertyPredicate::LessThan(value) => {
+                push_ordering(sql, bind_values, key.as_str(), "<", value)?
+            }
+            PropertyPredicate::LessOrEqual(value) => {
+                push_ordering(sql, bind_values, key.as_str(), "<=", value)?
+            }
+            PropertyPredicate::Contains(needle) => {
+                sql.push_str(" AND instr(lower(p.value), lower(?)) > 0");
+                bind_values.push(Value::Text(needle.clone()));
+            }
+        }
+        sql.push(')');
+    }
+    Ok(())
+}
+
+fn push_equals(sql: &mut String, bind_values: &mut Vec<Value>, value: &PropertyValue) {
+    match value {
+        PropertyValue::Text(text) => {
+            sql.push_str("p.value = ? COLLATE NOCASE");
+            bind_values.push(Value::Text(text.clone()));
+        }
+        PropertyValue::AtomRef(target) => {
+            sql.push_str("p.value_type = 'atom_ref' AND p.value_ref = ?");
+            bind_values.push(Value::Text(target.to_string()));
+        }
+        typed => {
+            sql.push_str("p.value_type = ? AND p.value_number = ?");
+            bind_values.push(Value::Text(typed.type_name().to_string()));
+            bind_values.push(Value::Real(typed.number_column().unwrap_or_default()));
+        }
+    }
+}
+
+fn push_ordering(
+    sql: &mut String,
+    bind_values: &mut Vec<Value>,
+    key: &str,
+    operator: &str,
+    value: &PropertyValue,
+) -> RepoResult<()> {
+    match value {
+        PropertyValue::Text(text) => {
+            sql.push_str(&format!(
+                " AND p.value_type = 'text' AND p.value {operator} ? COLLATE NOCASE"
+            ));
+            bind_values.push(Value::Text(text.clone()));
+        }
+        PropertyValue::Number(_) | PropertyValue::Date(_) => {
+            sql.push_str(&format!(
+                " AND p.value_type = ? AND p.value_number {operator} ?"
+            ));
+            bind_values.push(Value::Text(value.type_name().to_string()));
+            bind_values.push(Value::Real(value.number_column().unwrap_or_default()));
+        }
+        PropertyValue::Bool(_) | PropertyValue::AtomRef(_) => {
+            return Err(RepoError::InvalidProperty(format!(
+                "property `{key}` cannot be ordered by a {} value",
+                value.type_name()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Writes one property row; `text` is stored in `value` as is.
+///
+/// A `user` row is only replaced by another `user` write.
+fn upsert_property(
+    conn: &Connection,
+    atom: AtomId,
+    key: &str,
+    value: &PropertyValue,
+    text: &str,
+    source: &str,
+) -> RepoResult<()> {
+    let value_ref = match value {
+        PropertyValue::AtomRef(target) => Some(target.to_string()),
+        _ => None,
+    };
+    conn.execute(
+        "INSERT INTO atom_properties (
+            atom_uuid,
+            key,
+            value,
+            value_type,
+            value_number,
+            value_ref,
+            source
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        ON CONFLICT(atom_uuid, key) DO UPDATE SET
+            value = excluded.value,
+            value_type = excluded.value_type,
+            value_number = excluded.value_number,
+            value_ref = excluded.value_ref,
+            source = excluded.source,
+            updated_at = (strftime('%s', 'now') * 1000)
+        WHERE excluded.source = ?8
+           OR atom_properties.source <> ?8;",
+        params![
+            atom.to_string(),
+            key,
+            text,
+            value.type_name(),
+            value.number_column(),
+            value_ref,
+            source,
+            PROPERTY_SOURCE_USER
+        ],
+    )?;
+    Ok(())
+}
+
+fn require_key(key: &str) -> RepoResult<String> {
+    normalize_property_key(key)
+        .ok_or_else(|| RepoError::InvalidProperty("property key must not be blank".to_string()))
+}
+
+fn atom_is_active(conn: &Connection, atom: AtomId) -> RepoResult<bool> {
+    let found: Option<i64> = conn
+        .query_row(
+            "SELECT 1 FROM atoms WHERE uuid = ?1 AND is_deleted = 0;",
+            [atom.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(found.is_some())
+}
+
+fn parse_property_row(row: &Row<'_>) -> RepoResult<AtomProperty> {
+    let key: String = row.get("key")?;
+    let text: String = row.get("value")?;
+    let value_type: String = row.get("value_type")?;
+    let number: Option<f64> = row.get("value_number")?;
+    let value_ref: Option<String> = row.get("value_ref")?;
+    let invalid = || {
+        RepoError::InvalidData(format!(
+            "invalid {value_type} value for property `{key}` in atom_properties"
+        ))
+    };
+    let value = match value_type.as_str() {
+        "text" => PropertyValue::Text(text.clone()),
+        "number" => PropertyValue::Number(number.ok_or_else(invalid)?),
+        "date" => PropertyValue::Date(number.ok_or_else(invalid)? as i64),
+        "bool" => PropertyValue::Bool(number.ok_or_else(invalid)? != 0.0),
+        "atom_ref" => match value_ref {
+            Some(target) => {
+                PropertyValue::AtomRef(Uuid::parse_str(target.as_str()).map_err(|_| invalid())?)
+            }
+            // Why: the referenced row was hard-deleted (FK set NULL); keep the
+            // last rendering rather than failing the whole read.
+            None => PropertyValue::Text(text.clone()),
+        },
+        _ => return Err(invalid()),
+    };
+    Ok(AtomProperty {
+        key,
+        value,
+        text,
+        source: row.get("source")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
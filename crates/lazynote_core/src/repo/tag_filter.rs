@@ -0,0 +1,393 @@
+//! Boolean tag filter expressions.
+//!
+//! # Responsibility
+//! - Represent `AND` / `OR` / `NOT` combinations of tags.
+//! - Parse textual expressions such as `(work OR home) AND NOT archived`.
+//! - Compile expressions to `EXISTS` predicates over `atom_tags`; a tag also
+//!   matches its descendants (`work` matches `work/ops`).
+//!
+//! # Invariants
+//! - Tag names are trimmed and lowercased; blank names are rejected.
+//! - Precedence is `NOT` > `AND` > `OR`; adjacent operands without an
+//!   operator are combined with `AND`.
+//! - Keywords are case-insensitive; tags containing spaces, parentheses or
+//!   keywords must be double-quoted.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use crate::repo::note_repo::normalize_tag;
+use crate::repo::tag_repo::tag_subtree_sql;
+use rusqlite::types::Value;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Boolean expression over atom tags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagFilter {
+    /// Atom carries the tag.
+    Tag(String),
+    /// Every operand matches.
+    And(Vec<TagFilter>),
+    /// At least one operand matches.
+    Or(Vec<TagFilter>),
+    /// The operand does not match.
+    Not(Box<TagFilter>),
+}
+
+impl TagFilter {
+    /// Matches atoms carrying `name`.
+    pub fn tag(name: impl Into<String>) -> Self {
+        Self::Tag(name.into())
+    }
+
+    /// Matches atoms matching every filter in `filters`.
+    pub fn all(filters: impl IntoIterator<Item = TagFilter>) -> Self {
+        Self::And(filters.into_iter().collect())
+    }
+
+    /// Matches atoms matching at least one filter in `filters`.
+    pub fn any(filters: impl IntoIterator<Item = TagFilter>) -> Self {
+        Self::Or(filters.into_iter().collect())
+    }
+
+    /// Matches atoms not matching `filter`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(filter: TagFilter) -> Self {
+        Self::Not(Box::new(filter))
+    }
+
+    /// Parses a textual expression, e.g. `(work OR home) AND NOT archived`.
+    pub fn parse(expression: &str) -> Result<Self, TagFilterParseError> {
+        let tokens = tokenize(expression)?;
+        if tokens.is_empty() {
+            return Err(TagFilterParseError::Empty);
+        }
+        let mut parser = ExprParser { tokens, cursor: 0 };
+        let filter = parser.parse_or()?;
+        match parser.tokens.get(parser.cursor) {
+            None => Ok(filter),
+            Some((token, position)) => Err(TagFilterParseError::UnexpectedToken {
+                token: token.to_string(),
+                position: *position,
+            }),
+        }
+    }
+}
+
+impl FromStr for TagFilter {
+    type Err = TagFilterParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl Display for TagFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tag(name) => {
+                let plain = !name.is_empty()
+                    && !name
+                        .chars()
+                        .any(|ch| ch.is_whitespace() || matches!(ch, '(' | ')' | '"'))
+                    && keyword(name).is_none();
+                if plain {
+                    write!(f, "{name}")
+                } else {
+                    write!(f, "\"{}\"", name.replace('"', ""))
+                }
+            }
+            Self::And(items) => write_joined(f, items, " AND "),
+            Self::Or(items) => write_joined(f, items, " OR "),
+            Self::Not(inner) => match inner.as_ref() {
+                Self::And(_) | Self::Or(_) => write!(f, "NOT ({inner})"),
+                _ => write!(f, "NOT {inner}"),
+            },
+        }
+    }
+}
+
+fn write_joined(f: &mut Formatter<'_>, items: &[TagFilter], separator: &str) -> std::fmt::Result {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            write!(f, "{separator}")?;
+        }
+        match item {
+            TagFilter::And(_) | TagFilter::Or(_) => write!(f, "({item})")?,
+            _ => write!(f, "{item}")?,
+        }
+    }
+    Ok(())
+}
+
+/// Errors produced by [`TagFilter::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagFilterParseError {
+    /// Expression has no tags.
+    Empty,
+    /// Token not valid at this position (byte offset).
+    UnexpectedToken { token: String, position: usize },
+    /// Expression ended where an operand was expected.
+    UnexpectedEnd,
+    /// `(` or `"` without its closing counterpart (byte offset of opener).
+    Unclosed { position: usize },
+}
+
+impl Display for TagFilterParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "tag filter is empty"),
+            Self::UnexpectedToken { token, position } => {
+                write!(f, "unexpected `{token}` at position {position}")
+            }
+            Self::UnexpectedEnd => write!(f, "tag filter ended unexpectedly"),
+            Self::Unclosed { position } => write!(f, "unclosed group at position {position}"),
+        }
+    }
+}
+
+impl Error for TagFilterParseError {}
+
+/// Appends ` AND (<filter>)`, matching rows whose `atom_column` satisfies
+/// `filter`.
+///
+/// Placeholders are numbered (`?N`) after the values already in
+/// `bind_values`, so the clause can be spliced into queries using either
+/// numbered or positional parameters. Returns
+/// [`RepoError::InvalidTagFilter`] for blank tag names or empty groups.
+pub(crate) fn push_tag_filter(
+    sql: &mut String,
+    bind_values: &mut Vec<Value>,
+    atom_column: &str,
+    filter: &TagFilter,
+) -> RepoResult<()> {
+    sql.push_str(" AND ");
+    compile(sql, bind_values, atom_column, filter)
+}
+
+fn compile(
+    sql: &mut String,
+    bind_values: &mut Vec<Value>,
+    atom_column: &str,
+    filter: &TagFilter,
+) -> RepoResult<()> {
+    match filter {
+        TagFilter::Tag(name) => push_tag_exists(sql, bind_values, atom_column, &[name]),
+        TagFilter::Or(items) if !items.is_empty() && items.iter().all(is_tag) => {
+            let names: Vec<&String> = items
+                .iter()
+                .filter_map(|item| match item {
+                    TagFilter::Tag(name) => Some(name),
+                    _ => None,
+                })
+                .collect();
+            push_tag_exists(sql, bind_values, atom_column, &names)
+        }
+        TagFilter::And(items) | TagFilter::Or(items) => {
+            if items.is_empty() {
+                return Err(RepoError::InvalidTagFilter(
+                    "empty AND/OR group".to_string(),
+                ));
+            }
+            let separator = if matches!(filter, TagFilter::And(_)) {
+                " AND "
+            } else {
+                " OR "
+            };
+            sql.push('(');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    sql.push_str(separator);
+                }
+                compile(sql, bind_values, atom_column, item)?;
+            }
+            sql.push(')');
+            Ok(())
+        }
+        TagFilter::Not(inner) => {
+            sql.push_str("NOT ");
+            compile(sql, bind_values, atom_column, inner)
+        }
+    }
+}
+
+fn is_tag(filter: &TagFilter) -> bool {
+    matches!(filter, TagFilter::Tag(_))
+}
+
+fn push_tag_exists(
+    sql: &mut String,
+    bind_values: &mut Vec<Value>,
+    atom_column: &str,
+    names: &[&String],
+) -> RepoResult<()> {
+    let mut placeholders = Vec::with_capacity(names.len());
+    for name in names {
+        let normalized = normalize_tag(name.as_str())
+            .ok_or_else(|| RepoError::InvalidTagFilter(format!("blank tag `{name}`")))?;
+        bind_values.push(Value::Text(normalized));
+        placeholders.push(format!("?{}", bind_values.len()));
+    }
+    let predicate = placeholders
+        .iter()
+        .map(|placeholder| tag_subtree_sql("t.name", placeholder))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    sql.push_str(&format!(
+        "EXISTS (
+            SELECT 1
+            FROM atom_tags at
+            INNER JOIN tags t ON t.id = at.tag_id
+            WHERE at.atom_uuid = {atom_column}
+              AND ({predicate})
+        )"
+    ));
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Tag(String),
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => write!(f, "("),
+            Self::Close => write!(f, ")"),
+            Self::And => write!(f, "AND"),
+            Self::Or => write!(f, "OR"),
+            Self::Not => write!(f, "NOT"),
+            Self::Tag(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+fn keyword(word: &str) -> Option<Token> {
+    match word.to_ascii_uppercase().as_str() {
+        "AND" => Some(Token::And),
+        "OR" => Some(Token::Or),
+        "NOT" => Some(Token::Not),
+        _ => None,
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<(Token, usize)>, TagFilterParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.char_indices().peekable();
+    while let Some((position, ch)) = chars.next() {
+        match ch {
+            ch if ch.is_whitespace() => {}
+            '(' => tokens.push((Token::Open, position)),
+            ')' => tokens.push((Token::Close, position)),
+            '"' => {
+                let mut name = String::new();
+                let mut closed = false;
+                for (_, next) in chars.by_ref() {
+                    if next == '"' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(next);
+                }
+                if !closed {
+                    return Err(TagFilterParseError::Unclosed { position });
+                }
+                tokens.push((Token::Tag(name), position));
+            }
+            _ => {
+                let mut word = String::from(ch);
+                while let Some((_, next)) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '(' | ')' | '"') {
+                        break;
+                    }
+                    word.push(*next);
+                    chars.next();
+                }
+                let token = keyword(word.as_str()).unwrap_or(Token::Tag(word));
+                tokens.push((token, position));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct ExprParser {
+    tokens: Vec<(Token, usize)>,
+    cursor: usize,
+}
+
+impl ExprParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.cursor).map(|(token, _)| token)
+    }
+
+    fn parse_or(&mut self) -> Result<TagFilter, TagFilterParseError> {
+        let mut items = vec![self.parse_and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.cursor += 1;
+            items.push(self.parse_and()?);
+        }
+        Ok(collapse(items, TagFilter::Or))
+    }
+
+    fn parse_and(&mut self) -> Result<TagFilter, TagFilterParseError> {
+        let mut items = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => {
+                    self.cursor += 1;
+                    items.push(self.parse_unary()?);
+                }
+                Some(Token::Not | Token::Open | Token::Tag(_)) => items.push(self.parse_unary()?),
+                _ => break,
+            }
+        }
+        Ok(collapse(items, TagFilter::And))
+    }
+
+    fn parse_unary(&mut self) -> Result<TagFilter, TagFilterParseError> {
+        let Some((token, position)) = self.tokens.get(self.cursor).cloned() else {
+            return Err(TagFilterParseError::UnexpectedEnd);
+        };
+        self.cursor += 1;
+        match token {
+            Token::Not => Ok(TagFilter::not(self.parse_unary()?)),
+            Token::Open => {
+                let inner = self.parse_or()?;
+                if self.peek() != Some(&Token::Close) {
+                    return Err(TagFilterParseError::Unclosed { position });
+                }
+                self.cursor += 1;
+                Ok(inner)
+            }
+            Token::Tag(name) => match normalize_tag(name.as_str()) {
+                Some(normalized) => Ok(TagFilter::Tag(normalized)),
+                None => Err(TagFilterParseError::UnexpectedToken {
+                    token: format!("\"{name}\""),
+                    position,
+                }),
+            },
+            other => Err(TagFilterParseError::UnexpectedToken {
+                token: other.to_string(),
+                position,
+            }),
+        }
+    }
+}
+
+fn collapse(mut items: Vec<TagFilter>, group: fn(Vec<TagFilter>) -> TagFilter) -> TagFilter {
+    if items.len() == 1 {
+        items.remove(0)
+    } else {
+        group(items)
+    }
+}
@@ -0,0 +1,1005 @@
+//! Hierarchical tag dictionary persistence.
+//!
+//! # Responsibility
+//! - Build the `/`-separated tag tree with per-node usage counts.
+//! - Rename or move a tag together with its whole subtree; merge and delete
+//!   tags across all atoms.
+//! - Store tag display metadata (color, icon, description).
+//! - Set, add and remove tags on atoms of any type.
+//! - Keep front-matter `tags` and inline `#hashtag` links in sync with atom
+//!   content.
+//! - Provide the SQL predicate used by every tag filter to roll up
+//!   descendants (`work` matches `work/ops`).
+//!
+//! # Invariants
+//! - Tag paths are normalized by [`normalize_tag`]: lowercase segments
+//!   without surrounding whitespace or empty segments.
+//! - Counts only include atoms that are not soft-deleted.
+//! - A subtree move never leaves a tag referenced by both old and new path;
+//!   colliding targets are merged into the existing tag and keep their own
+//!   metadata, filling only missing fields from the merged tag.
+//! - Manual set/add/remove edits on an atom are journaled for undo.
+//! - Tags without atom links are pruned after tag edits, except tags
+//!   carrying metadata: those stay listed (with zero usage) until
+//!   [`TagRepository::delete_tag`] removes them, so a color survives
+//!   re-tagging.
+//! - Manual edits only replace or remove `manual` links; `front_matter` and
+//!   `inline` links follow content. A tag reached through several sources
+//!   is stored once, ranked `manual` > `front_matter` > `inline`.
+//! - Every link write ends by rebuilding `atoms_fts.tags` of each touched
+//!   atom once; `atom_tags` has no FTS triggers (migration 33).
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::db::migrations::latest_version;
+use crate::markdown::front_matter::{parse_front_matter, rewrite_front_matter_tags};
+use crate::markdown::hashtags::{parse_hashtags, rewrite_hashtags};
+use crate::markdown::preview::derive_markdown_preview;
+use crate::model::atom::{AtomId, AtomType};
+use crate::repo::atom_repo::{RepoError, RepoResult, SqliteAtomRepository};
+use crate::repo::journal_repo::{self, JournalCommand};
+use crate::repo::note_repo::{self, load_tags_for_atoms, normalize_tag, normalize_tags};
+use rusqlite::{
+    params, Connection, OptionalExtension, Row, ToSql, Transaction, TransactionBehavior,
+};
+use std::collections::{BTreeMap, HashSet};
+use uuid::Uuid;
+
+/// Separator between tag path segments.
+pub const TAG_PATH_SEPARATOR: char = '/';
+
+/// One node of the tag tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagTreeNode {
+    /// Last path segment, e.g. `project-x`.
+    pub name: String,
+    /// Full tag path, e.g. `work/project-x`.
+    pub path: String,
+    /// Atoms tagged with exactly this path.
+    pub count: u32,
+    /// Distinct atoms tagged with this path or any descendant.
+    pub total_count: u32,
+    /// Child nodes ordered by name.
+    pub children: Vec<TagTreeNode>,
+}
+
+/// Display metadata of one tag; `None` fields are unset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagMetadata {
+    /// Lowercase `#rrggbb` color.
+    pub color: Option<String>,
+    /// Icon name or emoji.
+    pub icon: Option<String>,
+    /// Free-form description.
+    pub description: Option<String>,
+}
+
+/// Active atoms carrying one tag, by atom type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TagUsage {
+    /// Tagged notes.
+    pub notes: u32,
+    /// Tagged tasks.
+    pub tasks: u32,
+    /// Tagged events.
+    pub events: u32,
+}
+
+impl TagUsage {
+    /// Count for one atom type.
+    pub fn count(&self, kind: AtomType) -> u32 {
+        match kind {
+            AtomType::Note => self.notes,
+            AtomType::Task => self.tasks,
+            AtomType::Event => self.events,
+        }
+    }
+
+    /// Count over all atom types.
+    pub fn total(&self) -> u32 {
+        self.notes
+            .saturating_add(self.tasks)
+            .saturating_add(self.events)
+    }
+}
+
+/// One tag with metadata and exact-match usage counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagSummary {
+    /// Normalized tag path.
+    pub name: String,
+    /// Display metadata.
+    pub metadata: TagMetadata,
+    /// Atoms tagged with exactly this path (descendants not included).
+    pub usage: TagUsage,
+}
+
+/// How a tag got onto an atom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagSource {
+    /// Assigned through a tag API.
+    Manual,
+    /// Declared in the `tags` field of the atom's front matter.
+    FrontMatter,
+    /// Derived from a `#hashtag` in the atom content.
+    Inline,
+}
+
+impl TagSource {
+    fn as_db(self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::FrontMatter => "front_matter",
+            Self::Inline => "inline",
+        }
+    }
+
+    fn from_db(value: &str) -> RepoResult<Self> {
+        match value {
+            "manual" => Ok(Self::Manual),
+            "front_matter" => Ok(Self::FrontMatter),
+            "inline" => Ok(Self::Inline),
+            other => Err(RepoError::InvalidData(format!(
+                "unknown atom_tags.source `{other}`"
+            ))),
+        }
+    }
+}
+
+/// One tag on an atom with its source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomTag {
+    /// Normalized tag path.
+    pub name: String,
+    /// Whether the tag was assigned manually, declared in front matter or
+    /// written inline.
+    pub source: TagSource,
+}
+
+/// Repository interface for tag dictionary management.
+pub trait TagRepository {
+    /// Lists all tags ordered by name with metadata and usage counts.
+    fn list_tag_summaries(&self) -> RepoResult<Vec<TagSummary>>;
+    /// Loads one tag by normalized name.
+    fn get_tag_summary(&self, name: &str) -> RepoResult<Option<TagSummary>>;
+    /// Returns the hierarchical tag tree with usage counts.
+    fn tag_tree(&self) -> RepoResult<Vec<TagTreeNode>>;
+    /// Renames or moves `from` with its subtree under `to`, merging into
+    /// existing tags; returns how many tags were renamed.
+    fn rename_tag(&self, from: &str, to: &str) -> RepoResult<u32>;
+    /// Merges every tag in `sources` (with subtrees) into `target` in one
+    /// transaction; returns how many tags were folded in.
+    fn merge_tags(&self, sources: &[String], target: &str) -> RepoResult<u32>;
+    /// Removes one tag from every atom; returns how many atoms lost it.
+    fn delete_tag(&self, name: &str) -> RepoResult<u32>;
+    /// Replaces the metadata of one tag, creating the tag when missing.
+    ///
+    /// A tag with metadata is exempt from unused-tag pruning; clearing all
+    /// metadata of an unused tag removes it.
+    fn set_tag_metadata(&self, name: &str, metadata: &TagMetadata) -> RepoResult<()>;
+    /// Lists the tags of one active atom of any type, sorted by name.
+    fn atom_tags(&self, atom_id: AtomId) -> RepoResult<Vec<String>>;
+    /// Lists the tags of one active atom with their source, sorted by name.
+    fn atom_tag_details(&self, atom_id: AtomId) -> RepoResult<Vec<AtomTag>>;
+    /// Replaces the manual tags of one active atom in one transaction;
+    /// inline tags are kept.
+    fn set_atom_tags(&self, atom_id: AtomId, tags: &[String]) -> RepoResult<()>;
+    /// Adds one manual tag to an active atom; no-op when already manual.
+    fn add_atom_tag(&self, atom_id: AtomId, tag: &str) -> RepoResult<()>;
+    /// Removes one manual tag (not its descendants) from an active atom;
+    /// inline tags stay until removed from the content.
+    fn remove_atom_tag(&self, atom_id: AtomId, tag: &str) -> RepoResult<()>;
+}
+
+/// SQLite-backed tag repository.
+pub struct SqliteTagRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteTagRepository<'conn> {
+    /// Constructs a repository from a migrated connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::UninitializedConnection`] if schema version is not
+    ///   fully migrated.
+    /// - Returns [`RepoError::MissingRequiredTable`] or
+    ///   [`RepoError::MissingRequiredColumn`] when tag tables are incomplete.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let _ = SqliteAtomRepository::try_new(conn)?;
+        ensure_tag_connection_ready(conn)?;
+        Ok(Self { conn })
+    }
+}
+
+impl TagRepository for SqliteTagRepository<'_> {
+    fn list_tag_summaries(&self) -> RepoResult<Vec<TagSummary>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "{TAG_SUMMARY_SELECT_SQL} GROUP BY t.id ORDER BY t.name ASC;"
+        ))?;
+        let mut rows = stmt.query([])?;
+        let mut tags = Vec::new();
+        while let Some(row) = rows.next()? {
+            tags.push(parse_tag_summary_row(row)?);
+        }
+        Ok(tags)
+    }
+
+    fn get_tag_summary(&self, name: &str) -> RepoResult<Option<TagSummary>> {
+        let Some(name) = normalize_tag(name) else {
+            return Ok(None);
+        };
+        let mut stmt = self.conn.prepare(&format!(
+            "{TAG_SUMMARY_SELECT_SQL} WHERE t.name = ?1 GROUP BY t.id;"
+        ))?;
+        let mut rows = stmt.query([name.as_str()])?;
+        match rows.next()? {
+            Some(row) => Ok(Some(parse_tag_summary_row(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn tag_tree(&self) -> RepoResult<Vec<TagTreeNode>> {
+        tag_tree(self.conn)
+    }
+
+    fn rename_tag(&self, from: &str, to: &str) -> RepoResult<u32> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let renamed = rename_tag_subtree(&tx, from, to)?;
+        tx.commit()?;
+        Ok(renamed)
+    }
+
+    fn merge_tags(&self, sources: &[String], target: &str) -> RepoResult<u32> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let mut merged = 0_u32;
+        for source in sources {
+            merged = merged.saturating_add(rename_tag_subtree(&tx, source, target)?);
+        }
+        tx.commit()?;
+        Ok(merged)
+    }
+
+    fn delete_tag(&self, name: &str) -> RepoResult<u32> {
+        let name = normalize_tag(name)
+            .ok_or_else(|| RepoError::InvalidTag(format!("blank tag `{name}`")))?;
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let tagged = tagged_atoms(
+            &tx,
+            "tag_id IN (SELECT id FROM tags WHERE name = ?1)",
+            name.as_str(),
+        )?;
+        let unlinked = tx.execute(
+            "DELETE FROM atom_tags
+             WHERE tag_id IN (SELECT id FROM tags WHERE name = ?1);",
+            [name.as_str()],
+        )?;
+        tx.execute("DELETE FROM tags WHERE name = ?1;", [name.as_str()])?;
+        for atom_uuid in &tagged {
+            refresh_fts_tags(&tx, atom_uuid)?;
+        }
+        tx.commit()?;
+        Ok(u32::try_from(unlinked).unwrap_or(u32::MAX))
+    }
+
+    fn set_tag_metadata(&self, name: &str, metadata: &TagMetadata) -> RepoResult<()> {
+        let name = normalize_tag(name)
+            .ok_or_else(|| RepoError::InvalidTag(format!("blank tag `{name}`")))?;
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        tx.execute(
+            "INSERT OR IGNORE INTO tags (name) VALUES (?1);",
+            [name.as_str()],
+        )?;
+        tx.execute(
+            "UPDATE tags
+             SET color = ?2,
+                 icon = ?3,
+                 description = ?4
+             WHERE name = ?1;",
+            params![
+                name.as_str(),
+                metadata.color.as_deref(),
+                metadata.icon.as_deref(),
+                metadata.description.as_deref(),
+            ],
+        )?;
+        prune_unused_tags(&tx)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn atom_tags(&self, atom_id: AtomId) -> RepoResult<Vec<String>> {
+        let atom_uuid = atom_id.to_string();
+        if !atom_exists(self.conn, atom_uuid.as_str())? {
+            return Err(RepoError::NotFound(atom_id));
+        }
+        let mut tags = load_tags_for_atoms(self.conn, std::slice::from_ref(&atom_uuid))?;
+        Ok(tags.remove(atom_uuid.as_str()).unwrap_or_default())
+    }
+
+    fn atom_tag_details(&self, atom_id: AtomId) -> RepoResult<Vec<AtomTag>> {
+        let atom_uuid = atom_id.to_string();
+        if !atom_exists(self.conn, atom_uuid.as_str())? {
+            return Err(RepoError::NotFound(atom_id));
+        }
+        let mut stmt = self.conn.prepare(
+            "SELECT t.name, at.source
+             FROM atom_tags at
+             INNER JOIN tags t ON t.id = at.tag_id
+             WHERE at.atom_uuid = ?1
+             ORDER BY t.name ASC;",
+        )?;
+        let mut rows = stmt.query([atom_uuid.as_str()])?;
+        let mut tags = Vec::new();
+        while let Some(row) = rows.next()? {
+            let source: String = row.get(1)?;
+            tags.push(AtomTag {
+                name: row.get(0)?,
+                source: TagSource::from_db(source.as_str())?,
+            });
+        }
+        Ok(tags)
+    }
+
+    fn set_atom_tags(&self, atom_id: AtomId, tags: &[String]) -> RepoResult<()> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let previous = replace_atom_tags_in_tx(&tx, atom_id, tags)?;
+        record_atom_tags(&tx, atom_id, previous, tags.to_vec())?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn add_atom_tag(&self, atom_id: AtomId, tag: &str) -> RepoResult<()> {
+        let tag = normalize_tag(tag)
+            .ok_or_else(|| RepoError::InvalidTag(format!("blank tag `{tag}`")))?;
+        let atom_uuid = atom_id.to_string();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        if !atom_exists(&tx, atom_uuid.as_str())? {
+            return Err(RepoError::NotFound(atom_id));
+        }
+        let previous = manual_atom_tags(&tx, atom_uuid.as_str())?;
+        if link_atom_tag(&tx, atom_uuid.as_str(), tag.as_str(), TagSource::Manual)? {
+            refresh_fts_tags(&tx, atom_uuid.as_str())?;
+            touch_atom(&tx, atom_uuid.as_str())?;
+        }
+        let current = manual_atom_tags(&tx, atom_uuid.as_str())?;
+        record_atom_tags(&tx, atom_id, previous, current)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn remove_atom_tag(&self, atom_id: AtomId, tag: &str) -> RepoResult<()> {
+        let tag = normalize_tag(tag)
+            .ok_or_else(|| RepoError::InvalidTag(format!("blank tag `{tag}`")))?;
+        let atom_uuid = atom_id.to_string();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        if !atom_exists(&tx, atom_uuid.as_str())? {
+            return Err(RepoError::NotFound(atom_id));
+        }
+        let previous = manual_atom_tags(&tx, atom_uuid.as_str())?;
+        let removed = tx.execute(
+            "DELETE FROM atom_tags
+             WHERE atom_uuid = ?1
+               AND source = 'manual'
+               AND tag_id IN (SELECT id FROM tags WHERE name = ?2);",
+            params![atom_uuid.as_str(), tag.as_str()],
+        )?;
+        if removed > 0 {
+            // Why: a manual tag also declared in content stays on the atom.
+            sync_content_tags(&tx, atom_uuid.as_str())?;
+            prune_unused_tags(&tx)?;
+            touch_atom(&tx, atom_uuid.as_str())?;
+        }
+        let current = manual_atom_tags(&tx, atom_uuid.as_str())?;
+        record_atom_tags(&tx, atom_id, previous, current)?;
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+/// Replaces the manual tag set of one active atom of any type.
+///
+/// `tags` must already be normalized. Returns the previous manual tags.
+/// Callers own the surrounding transaction.
+pub(crate) fn replace_atom_tags_in_tx(
+    conn: &Connection,
+    atom_id: AtomId,
+    tags: &[String],
+) -> RepoResult<Vec<String>> {
+    let atom_uuid = atom_id.to_string();
+    if !atom_exists(conn, atom_uuid.as_str())? {
+        return Err(RepoError::NotFound(atom_id));
+    }
+    let previous = manual_atom_tags(conn, atom_uuid.as_str())?;
+    replace_atom_tags(conn, atom_uuid.as_str(), tags)?;
+    Ok(previous)
+}
+
+/// Lists the manual tags of one atom, lowercase and sorted by name.
+pub(crate) fn manual_atom_tags(conn: &Connection, atom_uuid: &str) -> RepoResult<Vec<String>> {
+    let mut stmt = conn.prepare(
+        "SELECT t.name
+         FROM atom_tags at
+         JOIN tags t ON t.id = at.tag_id
+         WHERE at.atom_uuid = ?1
+           AND at.source = 'manual'
+         ORDER BY t.name COLLATE NOCASE ASC;",
+    )?;
+    let tags = stmt
+        .query_map([atom_uuid], |row| row.get::<_, String>(0))?
+        .map(|name| name.map(|value| value.to_lowercase()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(tags)
+}
+
+fn record_atom_tags(
+    conn: &Connection,
+    atom_id: AtomId,
+    previous: Vec<String>,
+    current: Vec<String>,
+) -> RepoResult<()> {
+    journal_repo::record_command(
+        conn,
+        &JournalCommand::AtomTags {
+            atom_id,
+            tags: previous,
+        },
+        &JournalCommand::AtomTags {
+            atom_id,
+            tags: current,
+        },
+        0,
+    )
+}
+
+/// Replaces the manual tag set of one atom and prunes unreferenced tags.
+///
+/// `tags` must already be normalized. Content-derived tags are kept; a tag
+/// in both sets becomes `manual`. Callers own the surrounding transaction
+/// and check that the atom is active.
+pub(crate) fn replace_atom_tags(
+    conn: &Connection,
+    atom_uuid: &str,
+    tags: &[String],
+) -> RepoResult<()> {
+    conn.execute(
+        "DELETE FROM atom_tags WHERE atom_uuid = ?1 AND source = 'manual';",
+        [atom_uuid],
+    )?;
+    for tag in tags {
+        link_atom_tag(conn, atom_uuid, tag.as_str(), TagSource::Manual)?;
+    }
+    sync_content_tags(conn, atom_uuid)?;
+
+    prune_unused_tags(conn)?;
+    touch_atom(conn, atom_uuid)
+}
+
+/// Adds manual tags to one atom, keeping every existing link.
+///
+/// `tags` must already be normalized.
+pub(crate) fn add_atom_tags(conn: &Connection, atom_uuid: &str, tags: &[String]) -> RepoResult<()> {
+    let mut changed = false;
+    for tag in tags {
+        changed |= link_atom_tag(conn, atom_uuid, tag.as_str(), TagSource::Manual)?;
+    }
+    if changed {
+        refresh_fts_tags(conn, atom_uuid)?;
+        touch_atom(conn, atom_uuid)?;
+    }
+    Ok(())
+}
+
+/// Re-derives the front-matter and inline links of one atom from its
+/// content.
+///
+/// Does not touch `updated_at`; callers write the content in the same
+/// transaction.
+pub(crate) fn sync_content_tags(conn: &Connection, atom_uuid: &str) -> RepoResult<()> {
+    let content: Option<String> = conn
+        .query_row(
+            "SELECT content FROM atoms WHERE uuid = ?1;",
+            [atom_uuid],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(content) = content else {
+        return Ok(());
+    };
+    let declared = parse_front_matter(content.as_str())
+        .map(|front_matter| normalize_tags(&front_matter.tags))
+        .unwrap_or_default();
+    sync_front_matter_tags(conn, atom_uuid, &declared)
+}
+
+/// Replaces the `front_matter` links of one atom with `tags`, then
+/// re-derives its inline links.
+///
+/// `tags` must already be normalized. Does not touch `updated_at`.
+pub(crate) fn sync_front_matter_tags(
+    conn: &Connection,
+    atom_uuid: &str,
+    tags: &[String],
+) -> RepoResult<()> {
+    let removed = sync_source_tags(conn, atom_uuid, TagSource::FrontMatter, tags)?
+        + sync_inline_links(conn, atom_uuid)?;
+    if removed > 0 {
+        prune_unused_tags(conn)?;
+    }
+    refresh_fts_tags(conn, atom_uuid)
+}
+
+/// Re-derives the inline `#hashtag` links of one atom from its content.
+///
+/// Does not touch `updated_at`; callers write the content in the same
+/// transaction.
+pub(crate) fn sync_inline_tags(conn: &Connection, atom_uuid: &str) -> RepoResult<()> {
+    if sync_inline_links(conn, atom_uuid)? > 0 {
+        prune_unused_tags(conn)?;
+    }
+    refresh_fts_tags(conn, atom_uuid)
+}
+
+/// Makes the inline links of one atom match its `#hashtag`s, without
+/// pruning or re-indexing. Returns how many links were removed.
+fn sync_inline_links(conn: &Connection, atom_uuid: &str) -> RepoResult<usize> {
+    let content: Option<String> = conn
+        .query_row(
+            "SELECT content FROM atoms WHERE uuid = ?1;",
+            [atom_uuid],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(content) = content else {
+        return Ok(0);
+    };
+    let wanted = normalize_tags(&parse_hashtags(content.as_str()));
+    sync_source_tags(conn, atom_uuid, TagSource::Inline, &wanted)
+}
+
+/// Makes `wanted` the set of links `source` owns on one atom.
+///
+/// Links of other sources are kept; a wanted tag already linked through a
+/// higher-ranked source stays there. Returns how many links were removed.
+fn sync_source_tags(
+    conn: &Connection,
+    atom_uuid: &str,
+    source: TagSource,
+    wanted: &[String],
+) -> RepoResult<usize> {
+    let mut stmt = conn.prepare(
+        "SELECT t.name
+         FROM atom_tags at
+         INNER JOIN tags t ON t.id = at.tag_id
+         WHERE at.atom_uuid = ?1
+           AND at.source = ?2;",
+    )?;
+    let current: Vec<String> = stmt
+        .query_map(params![atom_uuid, source.as_db()], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+
+    let mut removed = 0;
+    for tag in current.iter().filter(|tag| !wanted.contains(tag)) {
+        removed += conn.execute(
+            "DELETE FROM atom_tags
+             WHERE atom_uuid = ?1
+               AND source = ?2
+               AND tag_id IN (SELECT id FROM tags WHERE name = ?3);",
+            params![atom_uuid, source.as_db(), tag.as_str()],
+        )?;
+    }
+    for tag in wanted {
+        link_atom_tag(conn, atom_uuid, tag.as_str(), source)?;
+    }
+    Ok(removed)
+}
+
+/// Links one normalized tag to an atom, creating the tag when missing.
+///
+/// A link upgrades an existing lower-ranked one and never downgrades (see
+/// [`source_rank_sql`]). Returns whether a row was inserted or upgraded.
+fn link_atom_tag(
+    conn: &Connection,
+    atom_uuid: &str,
+    tag: &str,
+    source: TagSource,
+) -> RepoResult<bool> {
+    conn.execute("INSERT OR IGNORE INTO tags (name) VALUES (?1);", [tag])?;
+    let changed = conn.execute(
+        &format!(
+            "INSERT INTO atom_tags (atom_uuid, tag_id, source)
+             SELECT ?1, id, ?3
+             FROM tags
+             WHERE name = ?2
+             ON CONFLICT (atom_uuid, tag_id) DO UPDATE
+             SET source = excluded.source
+             WHERE {} > {};",
+            source_rank_sql("excluded.source"),
+            source_rank_sql("atom_tags.source")
+        ),
+        params![atom_uuid, tag, source.as_db()],
+    )?;
+    Ok(changed > 0)
+}
+
+pub(crate) fn prune_unused_tags(conn: &Connection) -> RepoResult<()> {
+    conn.execute(
+        // Why: v0.1 filter chips should represent tags that still have at
+        // least one atom reference; prune detached dictionary rows here.
+        // Tags with user metadata are kept so colors survive re-tagging.
+        "DELETE FROM tags
+         WHERE id NOT IN (SELECT DISTINCT tag_id FROM atom_tags)
+           AND color IS NULL
+           AND icon IS NULL
+           AND description IS NULL;",
+        [],
+    )?;
+    Ok(())
+}
+
+fn touch_atom(conn: &Connection, atom_uuid: &str) -> RepoResult<()> {
+    conn.execute(
+        "UPDATE atoms
+         SET updated_at = (strftime('%s', 'now') * 1000)
+         WHERE uuid = ?1
+           AND is_deleted = 0;",
+        [atom_uuid],
+    )?;
+    Ok(())
+}
+
+/// Rebuilds the `tags` column of one atom's FTS row from its links.
+///
+/// Why: `atom_tags` has no FTS triggers, so a batch of link edits costs one
+/// re-index per atom instead of one per link row. Soft-deleted atoms have
+/// no FTS row and are skipped.
+fn refresh_fts_tags(conn: &Connection, atom_uuid: &str) -> RepoResult<()> {
+    conn.execute(
+        "UPDATE atoms_fts
+         SET tags = (
+             SELECT COALESCE(group_concat(t.name, ' '), '')
+             FROM atom_tags at
+             INNER JOIN tags t ON t.id = at.tag_id
+             WHERE at.atom_uuid = ?1
+         )
+         WHERE rowid = (SELECT rowid FROM atoms WHERE uuid = ?1 AND is_deleted = 0);",
+        [atom_uuid],
+    )?;
+    Ok(())
+}
+
+/// Lists atoms holding a link that matches `predicate` (over `atom_tags`
+/// columns, binding `?1` to `value`).
+fn tagged_atoms(conn: &Connection, predicate: &str, value: impl ToSql) -> RepoResult<Vec<String>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT DISTINCT atom_uuid FROM atom_tags WHERE {predicate};"
+    ))?;
+    let atoms = stmt
+        .query_map([value], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    Ok(atoms)
+}
+
+fn atom_exists(conn: &Connection, atom_uuid: &str) -> RepoResult<bool> {
+    let exists: i64 = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1
+            FROM atoms
+            WHERE uuid = ?1
+              AND is_deleted = 0
+        );",
+        [atom_uuid],
+        |row| row.get(0),
+    )?;
+    Ok(exists == 1)
+}
+
+const TAG_SUMMARY_SELECT_SQL: &str = "SELECT
+    t.name AS name,
+    t.color AS color,
+    t.icon AS icon,
+    t.description AS description,
+    COALESCE(SUM(a.type = 'note'), 0) AS note_count,
+    COALESCE(SUM(a.type = 'task'), 0) AS task_count,
+    COALESCE(SUM(a.type = 'event'), 0) AS event_count
+FROM tags t
+LEFT JOIN atom_tags at ON at.tag_id = t.id
+LEFT JOIN atoms a ON a.uuid = at.atom_uuid AND a.is_deleted = 0";
+
+fn parse_tag_summary_row(row: &Row<'_>) -> RepoResult<TagSummary> {
+    let name: String = row.get("name")?;
+    Ok(TagSummary {
+        name: normalize_tag(name.as_str()).unwrap_or(name),
+        metadata: TagMetadata {
+            color: row.get("color")?,
+            icon: row.get("icon")?,
+            description: row.get("description")?,
+        },
+        usage: TagUsage {
+            notes: row.get("note_count")?,
+            tasks: row.get("task_count")?,
+            events: row.get("event_count")?,
+        },
+    })
+}
+
+/// Returns a predicate matching tag names in `column` equal to the
+/// normalized tag bound at `placeholder` or nested below it.
+///
+/// `placeholder` appears more than once, so it must be numbered (`?N`).
+/// `%`, `_` and `\` in the bound tag are escaped for `LIKE`.
+pub(crate) fn tag_subtree_sql(column: &str, placeholder: &str) -> String {
+    format!(
+        "({column} = {placeholder}
+          OR {column} LIKE replace(replace(replace({placeholder}, '\\', '\\\\'), '%', '\\%'), '_', '\\_') || '/%' ESCAPE '\\')"
+    )
+}
+
+/// Builds the tag tree with usage counts of active atoms.
+///
+/// Ancestors that are not tags themselves appear with `count == 0`.
+pub fn tag_tree(conn: &Connection) -> RepoResult<Vec<TagTreeNode>> {
+    let mut stmt = conn.prepare(
+        "SELECT t.name, a.uuid
+         FROM tags t
+         LEFT JOIN atom_tags at ON at.tag_id = t.id
+         LEFT JOIN atoms a ON a.uuid = at.atom_uuid AND a.is_deleted = 0;",
+    )?;
+    let mut rows = stmt.query([])?;
+    let mut usage: BTreeMap<String, (u32, HashSet<String>)> = BTreeMap::new();
+    while let Some(row) = rows.next()? {
+        let name: String = row.get(0)?;
+        let atom: Option<String> = row.get(1)?;
+        let Some(path) = normalize_tag(name.as_str()) else {
+            continue;
+        };
+        let segments: Vec<&str> = path.split(TAG_PATH_SEPARATOR).collect();
+        for depth in 1..=segments.len() {
+            let entry = usage.entry(segments[..depth].join("/")).or_default();
+            if let Some(atom) = atom.as_ref() {
+                if depth == segments.len() {
+                    entry.0 += 1;
+                }
+                entry.1.insert(atom.clone());
+            }
+        }
+    }
+
+    // BTreeMap order puts every parent right before its descendants.
+    let mut roots: Vec<TagTreeNode> = Vec::new();
+    for (path, (count, atoms)) in usage {
+        let node = TagTreeNode {
+            name: path
+                .rsplit(TAG_PATH_SEPARATOR)
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            path,
+            count,
+            total_count: u32::try_from(atoms.len()).unwrap_or(u32::MAX),
+            children: Vec::new(),
+        };
+        insert_node(&mut roots, node);
+    }
+    Ok(roots)
+}
+
+fn insert_node(siblings: &mut Vec<TagTreeNode>, node: TagTreeNode) {
+    let parent = siblings.iter_mut().find(|candidate| {
+        node.path
+            .strip_prefix(candidate.path.as_str())
+            .is_some_and(|rest| rest.starts_with(TAG_PATH_SEPARATOR))
+    });
+    match parent {
+        Some(parent) => insert_node(&mut parent.children, node),
+        None => siblings.push(node),
+    }
+}
+
+/// Renames tag `from` and every tag nested below it to live under `to`.
+///
+/// Saved searches filtering on a moved tag follow it. Targets that already
+/// exist absorb the moved tag's atoms. Returns how many tags were renamed.
+/// Callers own the surrounding transaction.
+///
+/// # Errors
+/// - [`RepoError::InvalidTag`] for blank paths or when `to` lies inside the
+///   subtree of `from`.
+pub fn rename_tag_subtree(conn: &Connection, from: &str, to: &str) -> RepoResult<u32> {
+    let from =
+        normalize_tag(from).ok_or_else(|| RepoError::InvalidTag(format!("blank tag `{from}`")))?;
+    let to = normalize_tag(to).ok_or_else(|| RepoError::InvalidTag(format!("blank tag `{to}`")))?;
+    if from == to {
+        return Ok(0);
+    }
+    if to
+        .strip_prefix(from.as_str())
+        .is_some_and(|rest| rest.starts_with(TAG_PATH_SEPARATOR))
+    {
+        return Err(RepoError::InvalidTag(format!(
+            "cannot move `{from}` into its own subtree `{to}`"
+        )));
+    }
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT id, name FROM tags WHERE {} ORDER BY length(name) ASC;",
+        tag_subtree_sql("name", "?1")
+    ))?;
+    let moved: Vec<(i64, String)> = stmt
+        .query_map([from.as_str()], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<_, _>>()?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT DISTINCT at.atom_uuid
+         FROM atom_tags at
+         INNER JOIN tags t ON t.id = at.tag_id
+         WHERE at.source IN ('front_matter', 'inline')
+           AND {};",
+        tag_subtree_sql("t.name", "?1")
+    ))?;
+    let content_tagged: Vec<String> = stmt
+        .query_map([from.as_str()], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+
+    for (tag_id, name) in &moved {
+        let name = normalize_tag(name.as_str()).unwrap_or_default();
+        let renamed = format!("{to}{}", name.get(from.len()..).unwrap_or_default());
+        let existing: Option<i64> = conn
+            .query_row(
+                "SELECT id FROM tags WHERE name = ?1 AND id <> ?2;",
+                params![renamed.as_str(), tag_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match existing {
+            Some(target_id) => merge_tag_into(conn, *tag_id, target_id)?,
+            None => {
+                conn.execute(
+                    "UPDATE tags SET name = ?2 WHERE id = ?1;",
+                    params![tag_id, renamed.as_str()],
+                )?;
+            }
+        }
+    }
+
+    conn.execute(
+        &format!(
+            "UPDATE saved_searches
+             SET tag = ?2 || substr(tag, length(?1) + 1),
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE {};",
+            tag_subtree_sql("tag", "?1")
+        ),
+        params![from.as_str(), to.as_str()],
+    )?;
+
+    for atom_uuid in &content_tagged {
+        rewrite_content_tags(conn, atom_uuid, from.as_str(), to.as_str())?;
+    }
+    Ok(u32::try_from(moved.len()).unwrap_or(u32::MAX))
+}
+
+/// Maps `tag` into the `to` subtree when it lies in the `from` subtree.
+fn moved_tag_path(tag: &str, from: &str, to: &str) -> Option<String> {
+    let tag = normalize_tag(tag)?;
+    let rest = tag.strip_prefix(from)?;
+    (rest.is_empty() || rest.starts_with(TAG_PATH_SEPARATOR)).then(|| format!("{to}{rest}"))
+}
+
+/// Rewrites front-matter and inline tags of one atom after a subtree move.
+///
+/// Notes go through the note write path, so the rewrite gets a revision;
+/// other atoms only have their content and content tags updated.
+fn rewrite_content_tags(
+    conn: &Connection,
+    atom_uuid: &str,
+    from: &str,
+    to: &str,
+) -> RepoResult<()> {
+    let atom: Option<(String, String, i64)> = conn
+        .query_row(
+            "SELECT type, content, is_deleted FROM atoms WHERE uuid = ?1;",
+            [atom_uuid],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+    let Some((kind, content, is_deleted)) = atom else {
+        return Ok(());
+    };
+    let rename = |tag: &str| moved_tag_path(tag, from, to);
+    let front_matter = rewrite_front_matter_tags(content.as_str(), rename);
+    let source = front_matter.as_deref().unwrap_or(content.as_str());
+    let Some(rewritten) = rewrite_hashtags(source, rename).or(front_matter) else {
+        return Ok(());
+    };
+
+    if kind == "note" && is_deleted == 0 {
+        let atom_id = Uuid::parse_str(atom_uuid).map_err(|_| {
+            RepoError::InvalidData(format!("invalid uuid `{atom_uuid}` in atom_tags.atom_uuid"))
+        })?;
+        let preview = derive_markdown_preview(rewritten.as_str());
+        note_repo::write_note_content_in_tx(
+            conn,
+            atom_id,
+            rewritten.as_str(),
+            preview.preview_text.as_deref(),
+            preview.preview_image.as_deref(),
+            0,
+        )?;
+        return Ok(());
+    }
+    conn.execute(
+        "UPDATE atoms SET content = ?2 WHERE uuid = ?1;",
+        params![atom_uuid, rewritten.as_str()],
+    )?;
+    sync_content_tags(conn, atom_uuid)
+}
+
+/// Re-points every atom of tag `source` to `target`, then drops `source`.
+fn merge_tag_into(conn: &Connection, source: i64, target: i64) -> RepoResult<()> {
+    let tagged = tagged_atoms(conn, "tag_id = ?1", source)?;
+    conn.execute(
+        "UPDATE tags
+         SET color = COALESCE(color, (SELECT color FROM tags WHERE id = ?1)),
+             icon = COALESCE(icon, (SELECT icon FROM tags WHERE id = ?1)),
+             description = COALESCE(description, (SELECT description FROM tags WHERE id = ?1))
+         WHERE id = ?2;",
+        params![source, target],
+    )?;
+    conn.execute(
+        &format!(
+            "INSERT INTO atom_tags (atom_uuid, tag_id, created_at, source)
+             SELECT atom_uuid, ?2, created_at, source
+             FROM atom_tags
+             WHERE tag_id = ?1
+             ON CONFLICT (atom_uuid, tag_id) DO UPDATE
+             SET source = excluded.source
+             WHERE {} > {};",
+            source_rank_sql("excluded.source"),
+            source_rank_sql("atom_tags.source")
+        ),
+        params![source, target],
+    )?;
+    conn.execute("DELETE FROM atom_tags WHERE tag_id = ?1;", [source])?;
+    conn.execute("DELETE FROM tags WHERE id = ?1;", [source])?;
+    for atom_uuid in &tagged {
+        refresh_fts_tags(conn, atom_uuid)?;
+    }
+    Ok(())
+}
+
+/// SQL rank of an `atom_tags.source` value: `manual` > `front_matter` >
+/// `inline`.
+fn source_rank_sql(column: &str) -> String {
+    format!("(CASE {column} WHEN 'manual' THEN 2 WHEN 'front_matter' THEN 1 ELSE 0 END)")
+}
+
+fn ensure_tag_connection_ready(conn: &Connection) -> RepoResult<()> {
+    let expected_version = latest_version();
+    let actual_version: u32 = conn.query_row("PRAGMA user_version;", [], |row| row.get(0))?;
+    if actual_version != expected_version {
+        return Err(RepoError::UninitializedConnection {
+            expected_version,
+            actual_version,
+        });
+    }
+
+    for (table, columns) in [
+        ("tags", &["id", "name", "color", "icon", "description"][..]),
+        ("atom_tags", &["atom_uuid", "tag_id", "source"][..]),
+        ("saved_searches", &["tag"][..]),
+    ] {
+        let mut stmt = conn.prepare(&format!("PRAGMA table_info({table});"))?;
+        let present: HashSet<String> = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<_, _>>()?;
+        if present.is_empty() {
+            return Err(RepoError::MissingRequiredTable(table));
+        }
+        for column in columns {
+            if !present.contains(*column) {
+                return Err(RepoError::MissingRequiredColumn { table, column });
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,271 @@
+//! Note template persistence.
+//!
+//! # Responsibility
+//! - Define the template repository contract and its SQLite implementation.
+//! - Store named note templates in `note_templates`.
+//! - File notes created from a template under the template's folder.
+//!
+//! # Invariants
+//! - Template content is stored unexpanded; expansion happens at use time.
+//! - `tags` are stored already normalized, as a JSON array.
+//! - A name clash with another template (case-insensitive) fails with
+//!   [`RepoError::InvalidTemplate`].
+//! - A template folder that is missing, deleted or not a folder files the
+//!   note at the workspace root instead of failing.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::db::DbError;
+use crate::model::atom::AtomId;
+use crate::repo::atom_repo::{RepoError, RepoResult, SqliteAtomRepository};
+use crate::repo::tree_repo::{self, TreeRepoError, WorkspaceNodeId};
+use rusqlite::{params, Connection, ErrorCode, OptionalExtension, Row};
+use uuid::Uuid;
+
+/// Stable note template identifier.
+pub type NoteTemplateId = Uuid;
+
+/// One stored note template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTemplate {
+    pub uuid: NoteTemplateId,
+    /// Display name, unique case-insensitively.
+    pub name: String,
+    /// Markdown source with `{{placeholder}}` markers.
+    pub content: String,
+    /// Normalized tags applied to created notes.
+    pub tags: Vec<String>,
+    /// Folder that receives created notes; `None` leaves them unfiled.
+    pub folder_uuid: Option<WorkspaceNodeId>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Writable fields of a note template.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NoteTemplateDraft {
+    pub name: String,
+    pub content: String,
+    pub tags: Vec<String>,
+    pub folder_uuid: Option<WorkspaceNodeId>,
+}
+
+/// Repository contract for note template persistence.
+pub trait TemplateRepository {
+    /// Lists note templates ordered by name.
+    fn list_templates(&self) -> RepoResult<Vec<NoteTemplate>>;
+    /// Gets one note template by id.
+    fn get_template(&self, uuid: NoteTemplateId) -> RepoResult<Option<NoteTemplate>>;
+    /// Stores a new note template and returns its id.
+    fn create_template(&self, draft: &NoteTemplateDraft) -> RepoResult<NoteTemplateId>;
+    /// Replaces one note template; returns `false` when it does not exist.
+    fn update_template(&self, uuid: NoteTemplateId, draft: &NoteTemplateDraft) -> RepoResult<bool>;
+    /// Deletes one note template; returns `false` when it does not exist.
+    fn delete_template(&self, uuid: NoteTemplateId) -> RepoResult<bool>;
+}
+
+/// SQLite-backed note template repository.
+pub struct SqliteTemplateRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteTemplateRepository<'conn> {
+    /// Constructs a repository from a migrated connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::UninitializedConnection`] if schema version is not
+    ///   fully migrated.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let _ = SqliteAtomRepository::try_new(conn)?;
+        Ok(Self { conn })
+    }
+}
+
+impl TemplateRepository for SqliteTemplateRepository<'_> {
+    fn list_templates(&self) -> RepoResult<Vec<NoteTemplate>> {
+        list_templates(self.conn)
+    }
+
+    fn get_template(&self, uuid: NoteTemplateId) -> RepoResult<Option<NoteTemplate>> {
+        get_template(self.conn, uuid)
+    }
+
+    fn create_template(&self, draft: &NoteTemplateDraft) -> RepoResult<NoteTemplateId> {
+        insert_template(self.conn, draft)
+    }
+
+    fn update_template(&self, uuid: NoteTemplateId, draft: &NoteTemplateDraft) -> RepoResult<bool> {
+        update_template(self.conn, uuid, draft)
+    }
+
+    fn delete_template(&self, uuid: NoteTemplateId) -> RepoResult<bool> {
+        delete_template(self.conn, uuid)
+    }
+}
+
+/// Inserts one template and returns its id.
+fn insert_template(conn: &Connection, draft: &NoteTemplateDraft) -> RepoResult<NoteTemplateId> {
+    let uuid = Uuid::new_v4();
+    conn.execute(
+        "INSERT INTO note_templates (uuid, name, content, tags_json, folder_uuid)
+         VALUES (?1, ?2, ?3, ?4, ?5);",
+        params![
+            uuid.to_string(),
+            draft.name.as_str(),
+            draft.content.as_str(),
+            encode_tags(&draft.tags)?,
+            draft.folder_uuid.map(|value| value.to_string()),
+        ],
+    )
+    .map_err(|err| name_taken_error(err, draft))?;
+    Ok(uuid)
+}
+
+/// Replaces every writable field of one template.
+///
+/// Returns `false` when the template does not exist.
+fn update_template(
+    conn: &Connection,
+    uuid: NoteTemplateId,
+    draft: &NoteTemplateDraft,
+) -> RepoResult<bool> {
+    let changed = conn
+        .execute(
+            "UPDATE note_templates
+         SET name = ?2,
+             content = ?3,
+             tags_json = ?4,
+             folder_uuid = ?5,
+             updated_at = (strftime('%s', 'now') * 1000)
+         WHERE uuid = ?1;",
+            params![
+                uuid.to_string(),
+                draft.name.as_str(),
+                draft.content.as_str(),
+                encode_tags(&draft.tags)?,
+                draft.folder_uuid.map(|value| value.to_string()),
+            ],
+        )
+        .map_err(|err| name_taken_error(err, draft))?;
+    Ok(changed > 0)
+}
+
+/// Deletes one template; returns `false` when it does not exist.
+fn delete_template(conn: &Connection, uuid: NoteTemplateId) -> RepoResult<bool> {
+    let changed = conn.execute(
+        "DELETE FROM note_templates WHERE uuid = ?1;",
+        [uuid.to_string()],
+    )?;
+    Ok(changed > 0)
+}
+
+/// Loads one template by id.
+fn get_template(conn: &Connection, uuid: NoteTemplateId) -> RepoResult<Option<NoteTemplate>> {
+    conn.query_row(
+        "SELECT uuid, name, content, tags_json, folder_uuid, created_at, updated_at
+         FROM note_templates
+         WHERE uuid = ?1;",
+        [uuid.to_string()],
+        read_row,
+    )
+    .optional()?
+    .map(parse_template)
+    .transpose()
+}
+
+/// Lists all templates ordered by name.
+fn list_templates(conn: &Connection) -> RepoResult<Vec<NoteTemplate>> {
+    let mut stmt = conn.prepare(
+        "SELECT uuid, name, content, tags_json, folder_uuid, created_at, updated_at
+         FROM note_templates
+         ORDER BY name COLLATE NOCASE ASC, uuid ASC;",
+    )?;
+    let rows = stmt.query_map([], read_row)?;
+    let mut templates = Vec::new();
+    for row in rows {
+        templates.push(parse_template(row?)?);
+    }
+    Ok(templates)
+}
+
+/// Files `atom_uuid` under `folder_uuid`, or at the root when the folder is
+/// not an active folder; returns the new `note_ref` node.
+///
+/// Callers own the surrounding transaction.
+pub(crate) fn file_note_in_folder(
+    conn: &Connection,
+    atom_uuid: AtomId,
+    folder_uuid: WorkspaceNodeId,
+    display_name: &str,
+) -> RepoResult<WorkspaceNodeId> {
+    let parent_uuid = match tree_repo::ensure_active_folder_exists(conn, folder_uuid) {
+        Ok(()) => Some(folder_uuid),
+        Err(TreeRepoError::NodeNotFound(_) | TreeRepoError::NodeNotFolder(_)) => None,
+        Err(err) => return Err(tree_error(err)),
+    };
+    tree_repo::insert_note_ref_in_tx(conn, parent_uuid, atom_uuid, display_name).map_err(tree_error)
+}
+
+type TemplateRow = (String, String, String, String, Option<String>, i64, i64);
+
+fn read_row(row: &Row<'_>) -> rusqlite::Result<TemplateRow> {
+    Ok((
+        row.get(0)?,
+        row.get(1)?,
+        row.get(2)?,
+        row.get(3)?,
+        row.get(4)?,
+        row.get(5)?,
+        row.get(6)?,
+    ))
+}
+
+fn parse_template(row: TemplateRow) -> RepoResult<NoteTemplate> {
+    let (uuid, name, content, tags_json, folder_uuid, created_at, updated_at) = row;
+    let uuid = Uuid::parse_str(uuid.as_str())
+        .map_err(|_| RepoError::InvalidData(format!("invalid template uuid `{uuid}`")))?;
+    let tags = serde_json::from_str(tags_json.as_str())
+        .map_err(|err| RepoError::InvalidData(format!("invalid tags of template {uuid}: {err}")))?;
+    let folder_uuid = folder_uuid
+        .map(|value| {
+            Uuid::parse_str(value.as_str()).map_err(|_| {
+                RepoError::InvalidData(format!("invalid folder uuid of template {uuid}: `{value}`"))
+            })
+        })
+        .transpose()?;
+    Ok(NoteTemplate {
+        uuid,
+        name,
+        content,
+        tags,
+        folder_uuid,
+        created_at,
+        updated_at,
+    })
+}
+
+fn encode_tags(tags: &[String]) -> RepoResult<String> {
+    serde_json::to_string(tags)
+        .map_err(|err| RepoError::InvalidData(format!("cannot encode template tags: {err}")))
+}
+
+/// Maps the `name` UNIQUE violation to [`RepoError::InvalidTemplate`].
+fn name_taken_error(err: rusqlite::Error, draft: &NoteTemplateDraft) -> RepoError {
+    match err {
+        rusqlite::Error::SqliteFailure(failure, _)
+            if failure.code == ErrorCode::ConstraintViolation
+                && failure.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE =>
+        {
+            RepoError::InvalidTemplate(format!("name already in use: `{}`", draft.name))
+        }
+        other => RepoError::Db(DbError::Sqlite(other)),
+    }
+}
+
+fn tree_error(err: TreeRepoError) -> RepoError {
+    match err {
+        TreeRepoError::Db(err) => RepoError::Db(err),
+        other => RepoError::InvalidData(other.to_string()),
+    }
+}
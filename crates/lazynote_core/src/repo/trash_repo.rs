@@ -0,0 +1,391 @@
+//! Trash persistence: list, restore and purge soft-deleted atoms.
+//!
+//! # Responsibility
+//! - List tombstoned atoms by deletion time.
+//! - Restore tombstoned atoms and reattach their workspace `note_ref`.
+//! - Permanently delete tombstoned atoms with every dependent row.
+//!
+//! # Invariants
+//! - Only rows with `is_deleted = 1` are visible to this repository; active
+//!   atoms are never restored or purged.
+//! - `atoms.deleted_at` is maintained by triggers (migration 24).
+//! - A purge removes `atom_tags`, FTS, `external_mappings` and `note_ref`
+//!   rows explicitly; other dependents go through `ON DELETE CASCADE`.
+//!   Attachment blobs are released but only freed by blob GC.
+//! - Checklist tasks follow their note's tombstone (migration 30): they are
+//!   listed, restored and purged through the note, not on their own.
+//! - Retention purges also drop `workspace_delete_ops` older than the
+//!   cutoff, so folder deletes stop being undoable after the same window.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::db::migrations::latest_version;
+use crate::model::atom::{Atom, AtomId, AtomType};
+use crate::repo::atom_repo::{
+    atom_type_to_db, parse_atom_row, RepoError, RepoResult, SqliteAtomRepository,
+};
+use crate::repo::tag_repo::prune_unused_tags;
+use log::{error, info};
+use rusqlite::types::Value;
+use rusqlite::{
+    params, params_from_iter, Connection, OptionalExtension, Row, Transaction, TransactionBehavior,
+};
+use std::time::Instant;
+
+const TRASH_SELECT_SQL: &str = "SELECT
+    uuid,
+    type,
+    content,
+    preview_text,
+    preview_image,
+    task_status,
+    start_at,
+    end_at,
+    recurrence_rule,
+    hlc_timestamp,
+    is_deleted,
+    is_archived,
+    is_pinned,
+    is_starred,
+    deleted_at
+FROM atoms
+WHERE is_deleted = 1
+  AND NOT EXISTS (
+      SELECT 1
+      FROM note_tasks nt
+      INNER JOIN atoms n ON n.uuid = nt.note_atom_uuid
+      WHERE nt.task_atom_uuid = atoms.uuid
+        AND n.is_deleted = 1
+  )";
+
+/// One soft-deleted atom in the trash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashedAtom {
+    /// Tombstoned atom snapshot.
+    pub atom: Atom,
+    /// Deletion time in epoch milliseconds.
+    pub deleted_at: i64,
+}
+
+/// Query options for [`TrashRepository::list_trash`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrashQuery {
+    /// Optional filter by atom kind.
+    pub kind: Option<AtomType>,
+    /// Maximum rows to return. When `None`, no explicit limit is applied.
+    pub limit: Option<u32>,
+    /// Number of rows to skip from the sorted result set.
+    pub offset: u32,
+}
+
+/// Repository interface for the trash.
+pub trait TrashRepository {
+    /// Lists tombstoned atoms, most recently deleted first
+    /// (`deleted_at DESC, uuid ASC`).
+    fn list_trash(&self, query: &TrashQuery) -> RepoResult<Vec<TrashedAtom>>;
+
+    /// Restores one tombstoned atom.
+    ///
+    /// A restored note without a visible `note_ref` gets its most recently
+    /// deleted `note_ref` back, moved to the root when its folder is gone.
+    /// Returns [`RepoError::NotFound`] when the atom is not in the trash.
+    fn restore_atom(&self, id: AtomId) -> RepoResult<()>;
+
+    /// Permanently deletes one tombstoned atom.
+    ///
+    /// Returns [`RepoError::NotFound`] when the atom is not in the trash.
+    fn purge_atom(&self, id: AtomId) -> RepoResult<()>;
+
+    /// Permanently deletes every atom trashed before `cutoff_ms` (epoch ms),
+    /// together with folder delete records created before it.
+    ///
+    /// Returns how many atoms were purged.
+    fn purge_deleted_before(&self, cutoff_ms: i64) -> RepoResult<u32>;
+}
+
+/// SQLite-backed trash repository.
+pub struct SqliteTrashRepository<'conn> {
+    conn: &'conn Connection,
+}
+
+impl<'conn> SqliteTrashRepository<'conn> {
+    /// Constructs a repository from a migrated connection.
+    ///
+    /// # Errors
+    /// - Returns [`RepoError::UninitializedConnection`] if schema version is not
+    ///   fully migrated.
+    /// - Returns [`RepoError::MissingRequiredColumn`] when `atoms.deleted_at`
+    ///   is missing.
+    pub fn try_new(conn: &'conn Connection) -> RepoResult<Self> {
+        let _ = SqliteAtomRepository::try_new(conn)?;
+        ensure_trash_connection_ready(conn)?;
+        Ok(Self { conn })
+    }
+}
+
+impl TrashRepository for SqliteTrashRepository<'_> {
+    fn list_trash(&self, query: &TrashQuery) -> RepoResult<Vec<TrashedAtom>> {
+        let mut sql = String::from(TRASH_SELECT_SQL);
+        let mut bind_values = Vec::new();
+        if let Some(kind) = query.kind {
+            sql.push_str(" AND type = ?");
+            bind_values.push(Value::Text(atom_type_to_db(kind).to_string()));
+        }
+        sql.push_str(" ORDER BY deleted_at DESC, uuid ASC");
+        if let Some(limit) = query.limit {
+            sql.push_str(" LIMIT ? OFFSET ?");
+            bind_values.push(Value::Integer(i64::from(limit)));
+            bind_values.push(Value::Integer(i64::from(query.offset)));
+        } else if query.offset > 0 {
+            sql.push_str(" LIMIT -1 OFFSET ?");
+            bind_values.push(Value::Integer(i64::from(query.offset)));
+        }
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query(params_from_iter(bind_values))?;
+        let mut items = Vec::new();
+        while let Some(row) = rows.next()? {
+            items.push(parse_trashed_row(row)?);
+        }
+        Ok(items)
+    }
+
+    fn restore_atom(&self, id: AtomId) -> RepoResult<()> {
+        let started_at = Instant::now();
+        let result = (|| {
+            let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+            let changed = tx.execute(
+                "UPDATE atoms
+                 SET is_deleted = 0,
+                     updated_at = (strftime('%s', 'now') * 1000)
+                 WHERE uuid = ?1
+                   AND is_deleted = 1;",
+                [id.to_string()],
+            )?;
+            if changed == 0 {
+                return Err(RepoError::NotFound(id));
+            }
+            let reattached = reattach_note_ref(&tx, id)?;
+            tx.commit()?;
+            Ok(reattached)
+        })();
+
+        match result {
+            Ok(reattached) => {
+                info!(
+                    "event=trash_restore module=repo status=ok atom_id={} note_ref_reattached={} duration_ms={}",
+                    id,
+                    reattached,
+                    started_at.elapsed().as_millis()
+                );
+                Ok(())
+            }
+            Err(err) => {
+                error!(
+                    "event=trash_restore module=repo status=error atom_id={} duration_ms={} error={}",
+                    id,
+                    started_at.elapsed().as_millis(),
+                    err
+                );
+                Err(err)
+            }
+        }
+    }
+
+    fn purge_atom(&self, id: AtomId) -> RepoResult<()> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        if !purge_one(&tx, id.to_string().as_str())? {
+            return Err(RepoError::NotFound(id));
+        }
+        prune_unused_tags(&tx)?;
+        tx.commit()?;
+        info!("event=trash_purge module=repo status=ok atom_id={id} purged=1");
+        Ok(())
+    }
+
+    fn purge_deleted_before(&self, cutoff_ms: i64) -> RepoResult<u32> {
+        let started_at = Instant::now();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let expired: Vec<String> = {
+            let mut stmt = tx.prepare(
+                "SELECT uuid
+                 FROM atoms
+                 WHERE is_deleted = 1
+                   AND deleted_at < ?1
+                 ORDER BY deleted_at ASC, uuid ASC;",
+            )?;
+            let rows = stmt.query_map([cutoff_ms], |row| row.get(0))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        let mut purged = 0_u32;
+        for uuid in &expired {
+            if purge_one(&tx, uuid)? {
+                purged += 1;
+            }
+        }
+        // Why: folder delete records share the retention window; once
+        // their atoms are purged they can no longer be undone anyway.
+        // Node and atom rows follow through `ON DELETE CASCADE`.
+        tx.execute(
+            "DELETE FROM workspace_delete_ops WHERE created_at < ?1;",
+            [cutoff_ms],
+        )?;
+        prune_unused_tags(&tx)?;
+        tx.commit()?;
+        info!(
+            "event=trash_purge module=repo status=ok cutoff_ms={} purged={} duration_ms={}",
+            cutoff_ms,
+            purged,
+            started_at.elapsed().as_millis()
+        );
+        Ok(purged)
+    }
+}
+
+/// Brings back the note_ref of a restored note when none is visible.
+///
+/// Returns whether a node was reattached.
+fn reattach_note_ref(conn: &Connection, id: AtomId) -> RepoResult<bool> {
+    let atom_uuid = id.to_string();
+    let visible: bool = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1
+            FROM workspace_nodes n
+            INNER JOIN atoms a ON a.uuid = n.atom_uuid
+            WHERE n.kind = 'note_ref'
+              AND n.atom_uuid = ?1
+              AND n.is_deleted = 0
+              AND a.type = 'note'
+        );",
+        [atom_uuid.as_str()],
+        |row| row.get(0),
+    )?;
+    if visible {
+        return Ok(false);
+    }
+
+    // Why: `delete_all` folder deletes tombstone the note together with its
+    // note_ref; restoring only the atom would leave it unreachable from the
+    // tree.
+    let candidate: Option<(String, Option<String>)> = conn
+        .query_row(
+            "SELECT n.node_uuid, p.node_uuid
+             FROM workspace_nodes n
+             INNER JOIN atoms a ON a.uuid = n.atom_uuid
+             LEFT JOIN workspace_nodes p
+               ON p.node_uuid = n.parent_uuid
+              AND p.kind = 'folder'
+              AND p.is_deleted = 0
+             WHERE n.kind = 'note_ref'
+               AND n.atom_uuid = ?1
+               AND a.type = 'note'
+             ORDER BY n.updated_at DESC, n.node_uuid ASC
+             LIMIT 1;",
+            [atom_uuid.as_str()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+    let Some((node_uuid, parent_uuid)) = candidate else {
+        return Ok(false);
+    };
+
+    conn.execute(
+        "UPDATE workspace_nodes
+         SET is_deleted = 0,
+             parent_uuid = ?2,
+             sort_order = (
+                 SELECT COALESCE(MAX(sort_order), -1) + 1
+                 FROM workspace_nodes
+                 WHERE parent_uuid IS ?2
+                   AND is_deleted = 0
+             ),
+             updated_at = (strftime('%s', 'now') * 1000)
+         WHERE node_uuid = ?1;",
+        params![node_uuid, parent_uuid],
+    )?;
+    Ok(true)
+}
+
+/// Hard-deletes one tombstoned atom, its checklist tasks and its dependent
+/// rows.
+///
+/// Returns `false` when the atom is missing or still active. Callers own
+/// the transaction and prune unused tags afterwards.
+fn purge_one(conn: &Connection, atom_uuid: &str) -> RepoResult<bool> {
+    let rowid: Option<i64> = conn
+        .query_row(
+            "SELECT rowid FROM atoms WHERE uuid = ?1 AND is_deleted = 1;",
+            [atom_uuid],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(rowid) = rowid else {
+        return Ok(false);
+    };
+
+    let tasks: Vec<String> = {
+        let mut stmt =
+            conn.prepare("SELECT task_atom_uuid FROM note_tasks WHERE note_atom_uuid = ?1;")?;
+        let rows = stmt.query_map([atom_uuid], |row| row.get(0))?;
+        rows.collect::<Result<_, _>>()?
+    };
+    for task_uuid in &tasks {
+        purge_one(conn, task_uuid)?;
+    }
+
+    // Why: tombstones are normally unindexed already; delete defensively so
+    // no stale FTS row can outlive the atom.
+    conn.execute("DELETE FROM atoms_fts WHERE rowid = ?1;", [rowid])?;
+    conn.execute("DELETE FROM atom_tags WHERE atom_uuid = ?1;", [atom_uuid])?;
+    conn.execute(
+        "DELETE FROM external_mappings WHERE atom_uuid = ?1;",
+        [atom_uuid],
+    )?;
+    // Why: workspace_nodes.atom_uuid has no ON DELETE action, so note_refs
+    // (active or tombstoned) would block the atom delete.
+    conn.execute(
+        "DELETE FROM workspace_nodes
+         WHERE kind = 'note_ref'
+           AND atom_uuid = ?1;",
+        [atom_uuid],
+    )?;
+    conn.execute("DELETE FROM atoms WHERE rowid = ?1;", [rowid])?;
+    Ok(true)
+}
+
+fn parse_trashed_row(row: &Row<'_>) -> RepoResult<TrashedAtom> {
+    let atom = parse_atom_row(row)?;
+    let deleted_at: Option<i64> = row.get("deleted_at")?;
+    let deleted_at = deleted_at.ok_or_else(|| {
+        RepoError::InvalidData(format!(
+            "missing deleted_at for trashed atom `{}`",
+            atom.uuid
+        ))
+    })?;
+    Ok(TrashedAtom { atom, deleted_at })
+}
+
+fn ensure_trash_connection_ready(conn: &Connection) -> RepoResult<()> {
+    let expected_version = latest_version();
+    let actual_version: u32 = conn.query_row("PRAGMA user_version;", [], |row| row.get(0))?;
+    if actual_version != expected_version {
+        return Err(RepoError::UninitializedConnection {
+            expected_version,
+            actual_version,
+        });
+    }
+
+    let has_deleted_at: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM pragma_table_info('atoms') WHERE name = 'deleted_at');",
+        [],
+        |row| row.get(0),
+    )?;
+    if !has_deleted_at {
+        return Err(RepoError::MissingRequiredColumn {
+            table: "atoms",
+            column: "deleted_at",
+        });
+    }
+    Ok(())
+}
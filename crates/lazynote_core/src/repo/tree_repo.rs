@@ -1,25 +1,51 @@
 //! Workspace tree repository contracts and SQLite implementation.
 //!
 //! # Responsibility
-//! - Provide persistence APIs for folder/note_ref workspace hierarchy.
+//! - Provide persistence APIs for folder/note_ref/smart_folder workspace
+//!   hierarchy.
+//! - Persist saved searches and evaluate them into virtual child nodes.
 //! - Keep SQL details and ordering behavior inside repository boundary.
 //!
 //! # Invariants
 //! - Only active (`is_deleted=0`) nodes are returned by default.
 //! - Child listing is deterministic: `sort_order ASC, node_uuid ASC`.
 //! - `note_ref` targets must point to active note atoms.
+//! - `smart_folder` nodes own exactly one saved search and never have stored
+//!   children; their children are evaluated on every read.
+//! - Virtual `AtomRef` ids are derived from `(smart_folder, atom)`, so they
+//!   never collide with stored nodes or the same atom in another folder.
+//! - Deleting a smart folder removes its saved search in the same
+//!   transaction.
+//! - Renaming a `note_ref` rewrites `[[old name]]` links that resolve to its
+//!   note, in the same transaction.
+//! - Creating or renaming a `note_ref` resolves pending `[[name]]` links.
 
 use crate::db::migrations::latest_version;
 use crate::db::DbError;
-use crate::model::atom::{AtomId, AtomType};
-use rusqlite::{params, Connection, OptionalExtension, Row, Transaction, TransactionBehavior};
+use crate::markdown::front_matter::strip_front_matter;
+use crate::markdown::preview::derive_markdown_preview;
+use crate::model::atom::{Atom, AtomId, AtomType};
+use crate::repo::atom_repo::{self, atom_type_to_db, RepoError};
+use crate::repo::journal_repo::{self, JournalCommand};
+use crate::repo::link_repo;
+use crate::repo::note_repo::normalize_tag;
+use crate::repo::tag_repo::tag_subtree_sql;
+use crate::search::fts::{escaped_match_expression, SearchError};
+use rusqlite::types::Value;
+use rusqlite::{
+    params, params_from_iter, Connection, OptionalExtension, Row, Transaction, TransactionBehavior,
+};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use uuid::Uuid;
 
 /// Stable workspace node identifier.
 pub type WorkspaceNodeId = Uuid;
 
+/// Stable saved search identifier.
+pub type SavedSearchId = Uuid;
+
 /// Result type used by workspace tree repository operations.
 pub type TreeRepoResult<T> = Result<T, TreeRepoError>;
 
@@ -32,6 +58,8 @@ pub enum TreeRepoError {
     NodeNotFound(WorkspaceNodeId),
     /// Target workspace node exists but is not folder kind.
     NodeNotFolder(WorkspaceNodeId),
+    /// Saved search row referenced by a smart folder does not exist.
+    SavedSearchNotFound(SavedSearchId),
     /// Connection schema is not at the expected migrated version.
     UninitializedConnection {
         expected_version: u32,
@@ -44,6 +72,11 @@ pub enum TreeRepoError {
         table: &'static str,
         column: &'static str,
     },
+    /// Source note has no unresolved link with the requested target.
+    UnresolvedLinkNotFound {
+        source_atom_id: AtomId,
+        target_title: String,
+    },
     /// Persisted data cannot be converted to valid read model.
     InvalidData(String),
 }
@@ -54,6 +87,7 @@ impl Display for TreeRepoError {
             Self::Db(err) => write!(f, "{err}"),
             Self::NodeNotFound(id) => write!(f, "workspace node not found: {id}"),
             Self::NodeNotFolder(id) => write!(f, "workspace node is not a folder: {id}"),
+            Self::SavedSearchNotFound(id) => write!(f, "saved search not found: {id}"),
             Self::UninitializedConnection {
                 expected_version,
                 actual_version,
@@ -68,6 +102,13 @@ impl Display for TreeRepoError {
                 f,
                 "workspace repository requires column `{column}` in table `{table}`"
             ),
+            Self::UnresolvedLinkNotFound {
+                source_atom_id,
+                target_title,
+            } => write!(
+                f,
+                "note {source_atom_id} has no unresolved link to `{target_title}`"
+            ),
             Self::InvalidData(message) => write!(f, "invalid workspace data: {message}"),
         }
     }
@@ -79,9 +120,11 @@ impl Error for TreeRepoError {
             Self::Db(err) => Some(err),
             Self::NodeNotFound(_) => None,
             Self::NodeNotFolder(_) => None,
+            Self::SavedSearchNotFound(_) => None,
             Self::UninitializedConnection { .. } => None,
             Self::MissingRequiredTable(_) => None,
             Self::MissingRequiredColumn { .. } => None,
+            Self::UnresolvedLinkNotFound { .. } => None,
             Self::InvalidData(_) => None,
         }
     }
@@ -99,6 +142,24 @@ impl From<rusqlite::Error> for TreeRepoError {
     }
 }
 
+impl From<RepoError> for TreeRepoError {
+    fn from(value: RepoError) -> Self {
+        match value {
+            RepoError::Db(err) => Self::Db(err),
+            other => Self::InvalidData(other.to_string()),
+        }
+    }
+}
+
+impl From<SearchError> for TreeRepoError {
+    fn from(value: SearchError) -> Self {
+        match value {
+            SearchError::Db(err) => Self::Db(err),
+            other => Self::InvalidData(other.to_string()),
+        }
+    }
+}
+
 /// Workspace tree node kind.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WorkspaceNodeKind {
@@ -106,6 +167,72 @@ pub enum WorkspaceNodeKind {
     Folder,
     /// Link node pointing to one note atom.
     NoteRef,
+    /// Node backed by a saved search; children are evaluated live.
+    SmartFolder,
+    /// Virtual child of a smart folder pointing to one matched atom.
+    ///
+    /// Never persisted: `node_uuid` equals `atom_uuid` and the node cannot be
+    /// renamed, moved or deleted.
+    AtomRef,
+}
+
+/// Folder delete mode for workspace tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FolderDeleteMode {
+    /// Delete folder node only and move direct children to root.
+    Dissolve,
+    /// Delete folder subtree and soft-delete note atoms with no remaining refs.
+    DeleteAll,
+}
+
+impl FolderDeleteMode {
+    /// Stable storage and wire name.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dissolve => "dissolve",
+            Self::DeleteAll => "delete_all",
+        }
+    }
+}
+
+impl FromStr for FolderDeleteMode {
+    type Err = String;
+
+    /// Parses a name produced by [`FolderDeleteMode::as_str`]; the error
+    /// carries the rejected input.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "dissolve" => Ok(Self::Dissolve),
+            "delete_all" => Ok(Self::DeleteAll),
+            other => Err(other.to_string()),
+        }
+    }
+}
+
+/// Filters persisted by a saved search.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SavedSearchQuery {
+    /// Full-text terms; blank matches every atom.
+    pub text: String,
+    /// Optional atom type filter.
+    pub kind: Option<AtomType>,
+    /// Optional tag filter (lowercase, without leading `#`).
+    pub tag: Option<String>,
+    /// Keeps only atoms whose status is `todo` or `in_progress`.
+    pub open_only: bool,
+}
+
+/// Saved search read model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedSearch {
+    /// Stable saved search id.
+    pub uuid: SavedSearchId,
+    /// Persisted filters.
+    pub query: SavedSearchQuery,
+    /// Epoch ms creation timestamp.
+    pub created_at: i64,
+    /// Epoch ms update timestamp.
+    pub updated_at: i64,
 }
 
 /// Workspace tree read model.
@@ -117,8 +244,10 @@ pub struct WorkspaceNode {
     pub kind: WorkspaceNodeKind,
     /// Parent node id. `None` means root-level node.
     pub parent_uuid: Option<WorkspaceNodeId>,
-    /// Target atom id for note references.
+    /// Target atom id for note references and virtual atom refs.
     pub atom_uuid: Option<AtomId>,
+    /// Backing saved search for smart folders.
+    pub saved_search_uuid: Option<SavedSearchId>,
     /// User-facing label.
     pub display_name: String,
     /// Stable child order key within one parent.
@@ -131,6 +260,23 @@ pub struct WorkspaceNode {
     pub updated_at: i64,
 }
 
+/// One folder deletion that can still be undone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeletedFolder {
+    /// Deleted folder node id.
+    pub folder_uuid: WorkspaceNodeId,
+    /// Folder display name at deletion time.
+    pub display_name: String,
+    /// How the folder was deleted.
+    pub mode: FolderDeleteMode,
+    /// Epoch ms deletion timestamp.
+    pub deleted_at: i64,
+    /// Nodes moved or soft-deleted by the delete, folder included.
+    pub node_uuids: Vec<WorkspaceNodeId>,
+    /// Note atoms soft-deleted by a `DeleteAll` delete.
+    pub atom_uuids: Vec<AtomId>,
+}
+
 /// Repository interface for workspace tree operations.
 pub trait TreeRepository {
     /// Creates one folder node.
@@ -171,8 +317,52 @@ pub trait TreeRepository {
     fn delete_folder_dissolve(&self, folder_uuid: WorkspaceNodeId) -> TreeRepoResult<()>;
     /// Deletes one folder subtree and conditionally soft-deletes note atoms.
     fn delete_folder_delete_all(&self, folder_uuid: WorkspaceNodeId) -> TreeRepoResult<()>;
+    /// Lists folder deletions that have not been undone, newest first.
+    fn list_deleted_folders(&self) -> TreeRepoResult<Vec<DeletedFolder>>;
+    /// Undoes the latest folder deletion of `folder_uuid`: revives the
+    /// folder and its subtree at their recorded parents and sort orders and
+    /// restores the notes the delete soft-deleted.
+    fn restore_deleted_folder(&self, folder_uuid: WorkspaceNodeId)
+        -> TreeRepoResult<WorkspaceNode>;
     /// Loads atom type for active atom, if present.
     fn atom_kind(&self, atom_uuid: AtomId) -> TreeRepoResult<Option<AtomType>>;
+    /// Creates one saved search and its smart_folder node atomically.
+    fn create_smart_folder(
+        &self,
+        parent_uuid: Option<WorkspaceNodeId>,
+        display_name: &str,
+        query: &SavedSearchQuery,
+    ) -> TreeRepoResult<WorkspaceNode>;
+    /// Loads one saved search by id.
+    fn get_saved_search(
+        &self,
+        saved_search_uuid: SavedSearchId,
+    ) -> TreeRepoResult<Option<SavedSearch>>;
+    /// Replaces filters of one saved search.
+    fn update_saved_search(
+        &self,
+        saved_search_uuid: SavedSearchId,
+        query: &SavedSearchQuery,
+    ) -> TreeRepoResult<()>;
+    /// Deletes one smart_folder node together with its saved search row,
+    /// in one transaction.
+    fn delete_smart_folder(&self, node_uuid: WorkspaceNodeId) -> TreeRepoResult<()>;
+    /// Evaluates saved search filters into virtual `AtomRef` children of
+    /// `smart_folder_uuid`, newest first. Archived atoms are excluded.
+    fn evaluate_saved_search(
+        &self,
+        smart_folder_uuid: WorkspaceNodeId,
+        query: &SavedSearchQuery,
+        limit: u32,
+    ) -> TreeRepoResult<Vec<WorkspaceNode>>;
+    /// Creates the note named by an unresolved `[[target_title]]` link in
+    /// `source_atom_id`, places its `note_ref` right after the source's
+    /// node and points the link at the new note, atomically.
+    fn create_note_from_link(
+        &self,
+        source_atom_id: AtomId,
+        target_title: &str,
+    ) -> TreeRepoResult<WorkspaceNode>;
 }
 
 /// SQLite-backed workspace tree repository.
@@ -222,27 +412,11 @@ impl TreeRepository for SqliteTreeRepository<'_> {
         atom_uuid: AtomId,
         display_name: &str,
     ) -> TreeRepoResult<WorkspaceNode> {
-        let node_uuid = Uuid::new_v4();
-        let sort_order = next_sort_order(self.conn, parent_uuid)?;
-        self.conn.execute(
-            "INSERT INTO workspace_nodes (
-                node_uuid,
-                kind,
-                parent_uuid,
-                atom_uuid,
-                display_name,
-                sort_order,
-                is_deleted
-            ) VALUES (?1, 'note_ref', ?2, ?3, ?4, ?5, 0);",
-            params![
-                node_uuid.to_string(),
-                parent_uuid.map(|value| value.to_string()),
-                atom_uuid.to_string(),
-                display_name,
-                sort_order,
-            ],
-        )?;
-        load_required_node(self.conn, node_uuid)
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let node_uuid = insert_note_ref_in_tx(&tx, parent_uuid, atom_uuid, display_name)?;
+        let node = load_required_node(&tx, node_uuid)?;
+        tx.commit()?;
+        Ok(node)
     }
 
     fn get_node(
@@ -256,6 +430,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                 kind,
                 parent_uuid,
                 atom_uuid,
+                saved_search_uuid,
                 display_name,
                 sort_order,
                 is_deleted,
@@ -269,6 +444,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                 n.kind AS kind,
                 n.parent_uuid AS parent_uuid,
                 n.atom_uuid AS atom_uuid,
+                n.saved_search_uuid AS saved_search_uuid,
                 n.display_name AS display_name,
                 n.sort_order AS sort_order,
                 n.is_deleted AS is_deleted,
@@ -279,7 +455,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
              WHERE n.node_uuid = ?1
                AND n.is_deleted = 0
                AND (
-                 n.kind = 'folder'
+                 n.kind IN ('folder', 'smart_folder')
                  OR (n.kind = 'note_ref' AND a.type = 'note' AND a.is_deleted = 0)
                );"
         };
@@ -303,6 +479,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                     kind,
                     parent_uuid,
                     atom_uuid,
+                    saved_search_uuid,
                     display_name,
                     sort_order,
                     is_deleted,
@@ -318,6 +495,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                     kind,
                     parent_uuid,
                     atom_uuid,
+                    saved_search_uuid,
                     display_name,
                     sort_order,
                     is_deleted,
@@ -333,6 +511,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                     n.kind AS kind,
                     n.parent_uuid AS parent_uuid,
                     n.atom_uuid AS atom_uuid,
+                    n.saved_search_uuid AS saved_search_uuid,
                     n.display_name AS display_name,
                     n.sort_order AS sort_order,
                     n.is_deleted AS is_deleted,
@@ -343,7 +522,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                  WHERE n.parent_uuid = ?1
                    AND n.is_deleted = 0
                    AND (
-                     n.kind = 'folder'
+                     n.kind IN ('folder', 'smart_folder')
                      OR (n.kind = 'note_ref' AND a.type = 'note' AND a.is_deleted = 0)
                    )
                  ORDER BY n.sort_order ASC, n.node_uuid ASC;"
@@ -354,6 +533,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                     n.kind AS kind,
                     n.parent_uuid AS parent_uuid,
                     n.atom_uuid AS atom_uuid,
+                    n.saved_search_uuid AS saved_search_uuid,
                     n.display_name AS display_name,
                     n.sort_order AS sort_order,
                     n.is_deleted AS is_deleted,
@@ -364,7 +544,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                  WHERE n.parent_uuid IS NULL
                    AND n.is_deleted = 0
                    AND (
-                     n.kind = 'folder'
+                     n.kind IN ('folder', 'smart_folder')
                      OR (n.kind = 'note_ref' AND a.type = 'note' AND a.is_deleted = 0)
                    )
                  ORDER BY n.sort_order ASC, n.node_uuid ASC;"
@@ -385,7 +565,9 @@ impl TreeRepository for SqliteTreeRepository<'_> {
     }
 
     fn rename_node(&self, node_uuid: WorkspaceNodeId, display_name: &str) -> TreeRepoResult<()> {
-        let changed = self.conn.execute(
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let node = load_required_node(&tx, node_uuid)?;
+        tx.execute(
             "UPDATE workspace_nodes
              SET display_name = ?2,
                  updated_at = (strftime('%s', 'now') * 1000)
@@ -393,9 +575,21 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                AND is_deleted = 0;",
             params![node_uuid.to_string(), display_name],
         )?;
-        if changed == 0 {
-            return Err(TreeRepoError::NodeNotFound(node_uuid));
+
+        // Why: title links follow the note_ref label, so a rename must carry
+        // `[[Old title]]` references along to keep them resolved.
+        if let (WorkspaceNodeKind::NoteRef, Some(atom_uuid)) = (node.kind, node.atom_uuid) {
+            if node.display_name != display_name {
+                link_repo::rewrite_links_for_rename(
+                    &tx,
+                    atom_uuid,
+                    node.display_name.as_str(),
+                    display_name,
+                )?;
+                link_repo::resolve_pending_title_links(&tx, display_name)?;
+            }
         }
+        tx.commit()?;
         Ok(())
     }
 
@@ -410,37 +604,26 @@ impl TreeRepository for SqliteTreeRepository<'_> {
         }
 
         let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
-        let mut sibling_ids = list_visible_child_ids(&tx, new_parent_uuid)?;
-        sibling_ids.retain(|id| *id != node_uuid);
-
-        let target_index = target_order
-            .unwrap_or(sibling_ids.len() as i64)
-            .clamp(0, sibling_ids.len() as i64) as usize;
-        sibling_ids.insert(target_index, node_uuid);
-
-        tx.execute(
-            "UPDATE workspace_nodes
-             SET parent_uuid = ?2,
-                 updated_at = (strftime('%s', 'now') * 1000)
-             WHERE node_uuid = ?1
-               AND is_deleted = 0;",
-            params![
-                node_uuid.to_string(),
-                new_parent_uuid.map(|value| value.to_string()),
-            ],
+        let (previous_parent, previous_index) =
+            move_node_in_tx(&tx, node_uuid, new_parent_uuid, target_order)?;
+        let index = list_visible_child_ids(&tx, new_parent_uuid)?
+            .iter()
+            .position(|id| *id == node_uuid)
+            .unwrap_or_default() as i64;
+        journal_repo::record_command(
+            &tx,
+            &JournalCommand::NodePosition {
+                node_uuid,
+                parent_uuid: previous_parent,
+                index: previous_index,
+            },
+            &JournalCommand::NodePosition {
+                node_uuid,
+                parent_uuid: new_parent_uuid,
+                index,
+            },
+            0,
         )?;
-
-        for (index, id) in sibling_ids.into_iter().enumerate() {
-            tx.execute(
-                "UPDATE workspace_nodes
-                 SET sort_order = ?2,
-                     updated_at = (strftime('%s', 'now') * 1000)
-                 WHERE node_uuid = ?1
-                   AND is_deleted = 0;",
-                params![id.to_string(), index as i64],
-            )?;
-        }
-
         tx.commit()?;
         Ok(())
     }
@@ -450,6 +633,11 @@ impl TreeRepository for SqliteTreeRepository<'_> {
         ensure_active_folder_exists(&tx, folder_uuid)?;
 
         let children = list_active_child_ids(&tx, Some(folder_uuid))?;
+        let op_uuid = insert_delete_op(&tx, folder_uuid, FolderDeleteMode::Dissolve)?;
+        record_delete_op_node(&tx, op_uuid.as_str(), folder_uuid)?;
+        for child_uuid in &children {
+            record_delete_op_node(&tx, op_uuid.as_str(), *child_uuid)?;
+        }
         let base_order = next_sort_order(&tx, None)?;
         for (index, child_uuid) in children.into_iter().enumerate() {
             tx.execute(
@@ -482,6 +670,8 @@ impl TreeRepository for SqliteTreeRepository<'_> {
         ensure_active_folder_exists(&tx, folder_uuid)?;
 
         let referenced_atoms = list_referenced_note_atoms_in_subtree(&tx, folder_uuid)?;
+        let op_uuid = insert_delete_op(&tx, folder_uuid, FolderDeleteMode::DeleteAll)?;
+        record_delete_op_subtree(&tx, op_uuid.as_str(), folder_uuid)?;
         soft_delete_workspace_subtree(&tx, folder_uuid)?;
 
         for atom_uuid in referenced_atoms {
@@ -500,7 +690,7 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                 continue;
             }
 
-            tx.execute(
+            let deleted = tx.execute(
                 "UPDATE atoms
                  SET is_deleted = 1,
                      updated_at = (strftime('%s', 'now') * 1000)
@@ -509,12 +699,172 @@ impl TreeRepository for SqliteTreeRepository<'_> {
                    AND is_deleted = 0;",
                 [atom_uuid.to_string()],
             )?;
+            if deleted > 0 {
+                tx.execute(
+                    "INSERT INTO workspace_delete_op_atoms (op_uuid, atom_uuid)
+                     VALUES (?1, ?2);",
+                    params![op_uuid, atom_uuid.to_string()],
+                )?;
+            }
         }
 
         tx.commit()?;
         Ok(())
     }
 
+    fn list_deleted_folders(&self) -> TreeRepoResult<Vec<DeletedFolder>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT op.op_uuid, op.folder_uuid, op.mode, op.created_at, n.display_name
+             FROM workspace_delete_ops op
+             INNER JOIN workspace_nodes n ON n.node_uuid = op.folder_uuid
+             WHERE op.restored_at IS NULL
+               AND n.is_deleted = 1
+             ORDER BY op.created_at DESC, op.rowid DESC;",
+        )?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut folders = Vec::with_capacity(rows.len());
+        for (op_uuid, folder_uuid, mode, deleted_at, display_name) in rows {
+            folders.push(DeletedFolder {
+                folder_uuid: parse_uuid(&folder_uuid, "workspace_delete_ops.folder_uuid")?,
+                display_name,
+                mode: parse_delete_op_mode(&mode)?,
+                deleted_at,
+                node_uuids: list_delete_op_ids(
+                    self.conn,
+                    "SELECT node_uuid FROM workspace_delete_op_nodes
+                     WHERE op_uuid = ?1 ORDER BY rowid ASC;",
+                    op_uuid.as_str(),
+                    "workspace_delete_op_nodes.node_uuid",
+                )?,
+                atom_uuids: list_delete_op_ids(
+                    self.conn,
+                    "SELECT atom_uuid FROM workspace_delete_op_atoms
+                     WHERE op_uuid = ?1 ORDER BY rowid ASC;",
+                    op_uuid.as_str(),
+                    "workspace_delete_op_atoms.atom_uuid",
+                )?,
+            });
+        }
+        Ok(folders)
+    }
+
+    fn restore_deleted_folder(
+        &self,
+        folder_uuid: WorkspaceNodeId,
+    ) -> TreeRepoResult<WorkspaceNode> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let op: Option<(String, String)> = tx
+            .query_row(
+                "SELECT op.op_uuid, op.mode
+                 FROM workspace_delete_ops op
+                 INNER JOIN workspace_nodes n ON n.node_uuid = op.folder_uuid
+                 WHERE op.folder_uuid = ?1
+                   AND op.restored_at IS NULL
+                   AND n.is_deleted = 1
+                 ORDER BY op.created_at DESC, op.rowid DESC
+                 LIMIT 1;",
+                [folder_uuid.to_string()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        let Some((op_uuid, mode)) = op else {
+            return Err(TreeRepoError::NodeNotFound(folder_uuid));
+        };
+        let mode = parse_delete_op_mode(&mode)?;
+
+        // Why: notes come back first so revived note_refs are visible again.
+        tx.execute(
+            "UPDATE atoms
+             SET is_deleted = 0,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE uuid IN (
+                 SELECT atom_uuid FROM workspace_delete_op_atoms WHERE op_uuid = ?1
+             )
+               AND type = 'note'
+               AND is_deleted = 1;",
+            [op_uuid.as_str()],
+        )?;
+
+        let (folder_parent, folder_order): (Option<String>, i64) = tx.query_row(
+            "SELECT parent_uuid, sort_order
+             FROM workspace_delete_op_nodes
+             WHERE op_uuid = ?1
+               AND node_uuid = ?2;",
+            params![op_uuid, folder_uuid.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let folder_parent = folder_parent
+            .map(|value| parse_uuid(&value, "workspace_delete_op_nodes.parent_uuid"))
+            .transpose()?;
+        let (folder_parent, folder_order) = match folder_parent {
+            Some(parent) if ensure_active_folder_exists(&tx, parent).is_ok() => {
+                make_room_for_sort_order(&tx, Some(parent), folder_order)?;
+                (Some(parent), folder_order)
+            }
+            Some(_) => (None, next_sort_order(&tx, None)?),
+            None => {
+                make_room_for_sort_order(&tx, None, folder_order)?;
+                (None, folder_order)
+            }
+        };
+        tx.execute(
+            "UPDATE workspace_nodes
+             SET is_deleted = 0,
+                 parent_uuid = ?2,
+                 sort_order = ?3,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE node_uuid = ?1;",
+            params![
+                folder_uuid.to_string(),
+                folder_parent.map(|value| value.to_string()),
+                folder_order
+            ],
+        )?;
+
+        // Why: dissolve left the children active, so only those still active
+        // move back; a child the user deleted since stays deleted.
+        let only_active = match mode {
+            FolderDeleteMode::Dissolve => "AND n.is_deleted = 0",
+            FolderDeleteMode::DeleteAll => "",
+        };
+        tx.execute(
+            &format!(
+                "UPDATE workspace_nodes AS n
+                 SET is_deleted = 0,
+                     parent_uuid = op.parent_uuid,
+                     sort_order = op.sort_order,
+                     updated_at = (strftime('%s', 'now') * 1000)
+                 FROM workspace_delete_op_nodes AS op
+                 WHERE op.op_uuid = ?1
+                   AND op.node_uuid = n.node_uuid
+                   AND n.node_uuid <> ?2
+                   {only_active};"
+            ),
+            params![op_uuid, folder_uuid.to_string()],
+        )?;
+
+        tx.execute(
+            "UPDATE workspace_delete_ops
+             SET restored_at = (strftime('%s', 'now') * 1000)
+             WHERE op_uuid = ?1;",
+            [op_uuid.as_str()],
+        )?;
+        let node = load_required_node(&tx, folder_uuid)?;
+        tx.commit()?;
+        Ok(node)
+    }
+
     fn atom_kind(&self, atom_uuid: AtomId) -> TreeRepoResult<Option<AtomType>> {
         let value: Option<String> = self
             .conn
@@ -538,6 +888,354 @@ impl TreeRepository for SqliteTreeRepository<'_> {
             ))),
         }
     }
+
+    fn create_smart_folder(
+        &self,
+        parent_uuid: Option<WorkspaceNodeId>,
+        display_name: &str,
+        query: &SavedSearchQuery,
+    ) -> TreeRepoResult<WorkspaceNode> {
+        let node_uuid = Uuid::new_v4();
+        let saved_search_uuid = Uuid::new_v4();
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        tx.execute(
+            "INSERT INTO saved_searches (uuid, query_text, kind, tag, open_only)
+             VALUES (?1, ?2, ?3, ?4, ?5);",
+            params![
+                saved_search_uuid.to_string(),
+                query.text,
+                query.kind.map(atom_type_to_db),
+                query.tag,
+                i64::from(query.open_only),
+            ],
+        )?;
+        let sort_order = next_sort_order(&tx, parent_uuid)?;
+        tx.execute(
+            "INSERT INTO workspace_nodes (
+                node_uuid,
+                kind,
+                parent_uuid,
+                atom_uuid,
+                saved_search_uuid,
+                display_name,
+                sort_order,
+                is_deleted
+            ) VALUES (?1, 'smart_folder', ?2, NULL, ?3, ?4, ?5, 0);",
+            params![
+                node_uuid.to_string(),
+                parent_uuid.map(|value| value.to_string()),
+                saved_search_uuid.to_string(),
+                display_name,
+                sort_order,
+            ],
+        )?;
+        let node = load_required_node(&tx, node_uuid)?;
+        tx.commit()?;
+        Ok(node)
+    }
+
+    fn get_saved_search(
+        &self,
+        saved_search_uuid: SavedSearchId,
+    ) -> TreeRepoResult<Option<SavedSearch>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT uuid, query_text, kind, tag, open_only, created_at, updated_at
+             FROM saved_searches
+             WHERE uuid = ?1;",
+        )?;
+        let mut rows = stmt.query([saved_search_uuid.to_string()])?;
+        if let Some(row) = rows.next()? {
+            return Ok(Some(parse_saved_search_row(row)?));
+        }
+        Ok(None)
+    }
+
+    fn update_saved_search(
+        &self,
+        saved_search_uuid: SavedSearchId,
+        query: &SavedSearchQuery,
+    ) -> TreeRepoResult<()> {
+        let changed = self.conn.execute(
+            "UPDATE saved_searches
+             SET query_text = ?2,
+                 kind = ?3,
+                 tag = ?4,
+                 open_only = ?5,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE uuid = ?1;",
+            params![
+                saved_search_uuid.to_string(),
+                query.text,
+                query.kind.map(atom_type_to_db),
+                query.tag,
+                i64::from(query.open_only),
+            ],
+        )?;
+        if changed == 0 {
+            return Err(TreeRepoError::SavedSearchNotFound(saved_search_uuid));
+        }
+        Ok(())
+    }
+
+    fn delete_smart_folder(&self, node_uuid: WorkspaceNodeId) -> TreeRepoResult<()> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        let saved_search_uuid: Option<String> = tx
+            .query_row(
+                "SELECT saved_search_uuid
+                 FROM workspace_nodes
+                 WHERE node_uuid = ?1
+                   AND kind = 'smart_folder'
+                   AND is_deleted = 0;",
+                [node_uuid.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(saved_search_uuid) = saved_search_uuid else {
+            return Err(TreeRepoError::NodeNotFound(node_uuid));
+        };
+        // Why: the node row references the saved search and a smart folder
+        // must carry one, so both rows go together instead of leaving an
+        // orphaned saved search behind a soft-deleted node.
+        tx.execute(
+            "DELETE FROM workspace_nodes WHERE node_uuid = ?1;",
+            [node_uuid.to_string()],
+        )?;
+        tx.execute(
+            "DELETE FROM saved_searches WHERE uuid = ?1;",
+            [saved_search_uuid],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn evaluate_saved_search(
+        &self,
+        smart_folder_uuid: WorkspaceNodeId,
+        query: &SavedSearchQuery,
+        limit: u32,
+    ) -> TreeRepoResult<Vec<WorkspaceNode>> {
+        let mut sql = String::from(
+            "SELECT
+                a.uuid AS uuid,
+                a.content AS content,
+                a.created_at AS created_at,
+                a.updated_at AS updated_at
+             FROM atoms a
+             WHERE a.is_deleted = 0",
+        );
+        atom_repo::push_atom_flag_filters(&mut sql, "a.", false, None, None);
+        let mut bind_values = Vec::new();
+        if let Some(match_expr) = escaped_match_expression(&query.text) {
+            sql.push_str(
+                " AND a.rowid IN (
+                    SELECT rowid
+                    FROM atoms_fts
+                    WHERE atoms_fts MATCH ?
+                  )",
+            );
+            bind_values.push(Value::Text(match_expr));
+        }
+        if let Some(kind) = query.kind {
+            sql.push_str(" AND a.type = ?");
+            bind_values.push(Value::Text(atom_type_to_db(kind).to_string()));
+        }
+        if let Some(tag) = query.tag.as_deref() {
+            bind_values.push(Value::Text(
+                normalize_tag(tag).unwrap_or_else(|| tag.to_string()),
+            ));
+            sql.push_str(&format!(
+                " AND EXISTS (
+                    SELECT 1
+                    FROM atom_tags at
+                    INNER JOIN tags t ON t.id = at.tag_id
+                    WHERE at.atom_uuid = a.uuid
+                      AND {}
+                  )",
+                tag_subtree_sql("t.name", &format!("?{}", bind_values.len()))
+            ));
+        }
+        if query.open_only {
+            sql.push_str(" AND a.task_status IN ('todo', 'in_progress')");
+        }
+        sql.push_str(" ORDER BY a.updated_at DESC, a.uuid ASC LIMIT ?");
+        bind_values.push(Value::Integer(i64::from(limit)));
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let mut rows = stmt.query(params_from_iter(bind_values))?;
+        let mut items = Vec::new();
+        while let Some(row) = rows.next()? {
+            let uuid_text: String = row.get("uuid")?;
+            let atom_uuid = parse_uuid(&uuid_text, "atoms.uuid")?;
+            let content: String = row.get("content")?;
+            items.push(WorkspaceNode {
+                node_uuid: virtual_node_uuid(smart_folder_uuid, atom_uuid),
+                kind: WorkspaceNodeKind::AtomRef,
+                parent_uuid: Some(smart_folder_uuid),
+                atom_uuid: Some(atom_uuid),
+                saved_search_uuid: None,
+                display_name: virtual_display_name(&content),
+                sort_order: items.len() as i64,
+                is_deleted: false,
+                created_at: row.get("created_at")?,
+                updated_at: row.get("updated_at")?,
+            });
+        }
+        Ok(items)
+    }
+
+    fn create_note_from_link(
+        &self,
+        source_atom_id: AtomId,
+        target_title: &str,
+    ) -> TreeRepoResult<WorkspaceNode> {
+        let tx = Transaction::new_unchecked(self.conn, TransactionBehavior::Immediate)?;
+        if !link_repo::has_unresolved_link(&tx, source_atom_id, target_title)? {
+            return Err(TreeRepoError::UnresolvedLinkNotFound {
+                source_atom_id,
+                target_title: target_title.to_string(),
+            });
+        }
+
+        let mut atom = Atom::new(AtomType::Note, format!("# {target_title}\n"));
+        let preview = derive_markdown_preview(atom.content.as_str());
+        atom.preview_text = preview.preview_text;
+        atom.preview_image = preview.preview_image;
+        atom_repo::create_atom_in_tx(&tx, &atom)?;
+        let atom_uuid = atom.uuid;
+
+        // Why: the new note belongs next to the note that asked for it, so
+        // it lands right after the source's first node (or at root end).
+        let source_node = first_note_ref_for_atom(&tx, source_atom_id)?;
+        let parent_uuid = source_node.as_ref().and_then(|node| node.parent_uuid);
+        let mut sibling_ids = list_visible_child_ids(&tx, parent_uuid)?;
+        let insert_index = source_node
+            .and_then(|node| sibling_ids.iter().position(|id| *id == node.node_uuid))
+            .map_or(sibling_ids.len(), |index| index + 1);
+        let node_uuid = insert_note_ref(
+            &tx,
+            parent_uuid,
+            atom_uuid,
+            target_title,
+            next_sort_order(&tx, parent_uuid)?,
+        )?;
+        sibling_ids.insert(insert_index, node_uuid);
+        for (index, id) in sibling_ids.into_iter().enumerate() {
+            tx.execute(
+                "UPDATE workspace_nodes
+                 SET sort_order = ?2,
+                     updated_at = (strftime('%s', 'now') * 1000)
+                 WHERE node_uuid = ?1
+                   AND is_deleted = 0;",
+                params![id.to_string(), index as i64],
+            )?;
+        }
+
+        link_repo::point_links_at_atom(&tx, source_atom_id, target_title, atom_uuid)?;
+        link_repo::resolve_pending_title_links(&tx, target_title)?;
+        let node = load_required_node(&tx, node_uuid)?;
+        tx.commit()?;
+        Ok(node)
+    }
+}
+
+/// Upper bound for characters in a virtual `AtomRef` label.
+const VIRTUAL_DISPLAY_NAME_MAX_CHARS: usize = 80;
+
+/// Derives a label for a virtual node from the first non-blank content line.
+fn virtual_display_name(content: &str) -> String {
+    let line = strip_front_matter(content)
+        .lines()
+        .map(|line| line.trim().trim_start_matches('#').trim())
+        .find(|line| !line.is_empty());
+    match line {
+        Some(line) => line.chars().take(VIRTUAL_DISPLAY_NAME_MAX_CHARS).collect(),
+        None => "Untitled".to_string(),
+    }
+}
+
+/// Derives a stable id for the virtual node showing `atom_uuid` inside
+/// `smart_folder_uuid`.
+///
+/// Why: the same atom can appear in several smart folders and as a stored
+/// `note_ref`, so reusing the atom id would collide across the tree.
+fn virtual_node_uuid(smart_folder_uuid: WorkspaceNodeId, atom_uuid: AtomId) -> WorkspaceNodeId {
+    Uuid::new_v5(&smart_folder_uuid, atom_uuid.as_bytes())
+}
+
+/// Appends one `note_ref` under `parent_uuid` and resolves pending links to
+/// `display_name`.
+///
+/// Callers own the surrounding transaction.
+pub(crate) fn insert_note_ref_in_tx(
+    conn: &Connection,
+    parent_uuid: Option<WorkspaceNodeId>,
+    atom_uuid: AtomId,
+    display_name: &str,
+) -> TreeRepoResult<WorkspaceNodeId> {
+    let sort_order = next_sort_order(conn, parent_uuid)?;
+    let node_uuid = insert_note_ref(conn, parent_uuid, atom_uuid, display_name, sort_order)?;
+    link_repo::resolve_pending_title_links(conn, display_name)?;
+    Ok(node_uuid)
+}
+
+fn insert_note_ref(
+    conn: &Connection,
+    parent_uuid: Option<WorkspaceNodeId>,
+    atom_uuid: AtomId,
+    display_name: &str,
+    sort_order: i64,
+) -> TreeRepoResult<WorkspaceNodeId> {
+    let node_uuid = Uuid::new_v4();
+    conn.execute(
+        "INSERT INTO workspace_nodes (
+            node_uuid,
+            kind,
+            parent_uuid,
+            atom_uuid,
+            display_name,
+            sort_order,
+            is_deleted
+        ) VALUES (?1, 'note_ref', ?2, ?3, ?4, ?5, 0);",
+        params![
+            node_uuid.to_string(),
+            parent_uuid.map(|value| value.to_string()),
+            atom_uuid.to_string(),
+            display_name,
+            sort_order,
+        ],
+    )?;
+    Ok(node_uuid)
+}
+
+/// Loads the oldest active `note_ref` node pointing at `atom_uuid`.
+fn first_note_ref_for_atom(
+    conn: &Connection,
+    atom_uuid: AtomId,
+) -> TreeRepoResult<Option<WorkspaceNode>> {
+    let mut stmt = conn.prepare(
+        "SELECT
+            node_uuid,
+            kind,
+            parent_uuid,
+            atom_uuid,
+            saved_search_uuid,
+            display_name,
+            sort_order,
+            is_deleted,
+            created_at,
+            updated_at
+         FROM workspace_nodes
+         WHERE atom_uuid = ?1
+           AND kind = 'note_ref'
+           AND is_deleted = 0
+         ORDER BY created_at ASC, node_uuid ASC
+         LIMIT 1;",
+    )?;
+    let mut rows = stmt.query([atom_uuid.to_string()])?;
+    if let Some(row) = rows.next()? {
+        return Ok(Some(parse_workspace_node_row(row)?));
+    }
+    Ok(None)
 }
 
 fn load_required_node(
@@ -550,6 +1248,7 @@ fn load_required_node(
             kind,
             parent_uuid,
             atom_uuid,
+            saved_search_uuid,
             display_name,
             sort_order,
             is_deleted,
@@ -614,7 +1313,7 @@ fn list_visible_child_ids(
              WHERE n.parent_uuid = ?1
                AND n.is_deleted = 0
                AND (
-                 n.kind = 'folder'
+                 n.kind IN ('folder', 'smart_folder')
                  OR (n.kind = 'note_ref' AND a.type = 'note' AND a.is_deleted = 0)
                )
              ORDER BY n.sort_order ASC, n.node_uuid ASC;",
@@ -632,7 +1331,7 @@ fn list_visible_child_ids(
              WHERE n.parent_uuid IS NULL
                AND n.is_deleted = 0
                AND (
-                 n.kind = 'folder'
+                 n.kind IN ('folder', 'smart_folder')
                  OR (n.kind = 'note_ref' AND a.type = 'note' AND a.is_deleted = 0)
                )
              ORDER BY n.sort_order ASC, n.node_uuid ASC;",
@@ -646,6 +1345,101 @@ fn list_visible_child_ids(
     Ok(ids)
 }
 
+/// Moves one active node under `new_parent_uuid` at sibling index
+/// `target_order` (appended when `None`) and renumbers the new siblings.
+///
+/// Returns the parent and sibling index the node had before. Callers own the
+/// surrounding transaction and validate the parent.
+pub(crate) fn move_node_in_tx(
+    conn: &Connection,
+    node_uuid: WorkspaceNodeId,
+    new_parent_uuid: Option<WorkspaceNodeId>,
+    target_order: Option<i64>,
+) -> TreeRepoResult<(Option<WorkspaceNodeId>, i64)> {
+    let previous_parent: Option<Option<String>> = conn
+        .query_row(
+            "SELECT parent_uuid
+             FROM workspace_nodes
+             WHERE node_uuid = ?1
+               AND is_deleted = 0;",
+            [node_uuid.to_string()],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(previous_parent) = previous_parent else {
+        return Err(TreeRepoError::NodeNotFound(node_uuid));
+    };
+    let previous_parent = previous_parent
+        .map(|value| parse_uuid(&value, "workspace_nodes.parent_uuid"))
+        .transpose()?;
+    let previous_index = list_visible_child_ids(conn, previous_parent)?
+        .iter()
+        .position(|id| *id == node_uuid)
+        .unwrap_or_default() as i64;
+
+    let mut sibling_ids = list_visible_child_ids(conn, new_parent_uuid)?;
+    sibling_ids.retain(|id| *id != node_uuid);
+
+    let target_index = target_order
+        .unwrap_or(sibling_ids.len() as i64)
+        .clamp(0, sibling_ids.len() as i64) as usize;
+    sibling_ids.insert(target_index, node_uuid);
+
+    conn.execute(
+        "UPDATE workspace_nodes
+         SET parent_uuid = ?2,
+             updated_at = (strftime('%s', 'now') * 1000)
+         WHERE node_uuid = ?1
+           AND is_deleted = 0;",
+        params![
+            node_uuid.to_string(),
+            new_parent_uuid.map(|value| value.to_string()),
+        ],
+    )?;
+
+    for (index, id) in sibling_ids.into_iter().enumerate() {
+        conn.execute(
+            "UPDATE workspace_nodes
+             SET sort_order = ?2,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE node_uuid = ?1
+               AND is_deleted = 0;",
+            params![id.to_string(), index as i64],
+        )?;
+    }
+    Ok((previous_parent, previous_index))
+}
+
+/// Checks that moving `node_uuid` under `parent_uuid` keeps the tree
+/// acyclic and the parent an active folder.
+pub(crate) fn ensure_valid_move_target(
+    conn: &Connection,
+    node_uuid: WorkspaceNodeId,
+    parent_uuid: WorkspaceNodeId,
+) -> TreeRepoResult<()> {
+    ensure_active_folder_exists(conn, parent_uuid)?;
+    let mut cursor = Some(parent_uuid);
+    while let Some(current) = cursor {
+        if current == node_uuid {
+            return Err(TreeRepoError::InvalidData(format!(
+                "moving {node_uuid} under {parent_uuid} would create a cycle"
+            )));
+        }
+        let parent: Option<String> = conn
+            .query_row(
+                "SELECT parent_uuid FROM workspace_nodes WHERE node_uuid = ?1;",
+                [current.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        cursor = parent
+            .map(|value| parse_uuid(&value, "workspace_nodes.parent_uuid"))
+            .transpose()?;
+    }
+    Ok(())
+}
+
 fn next_sort_order(conn: &Connection, parent_uuid: Option<WorkspaceNodeId>) -> TreeRepoResult<i64> {
     let next = if let Some(parent_uuid) = parent_uuid {
         conn.query_row(
@@ -669,7 +1463,7 @@ fn next_sort_order(conn: &Connection, parent_uuid: Option<WorkspaceNodeId>) -> T
     Ok(next)
 }
 
-fn ensure_active_folder_exists(
+pub(crate) fn ensure_active_folder_exists(
     conn: &Connection,
     folder_uuid: WorkspaceNodeId,
 ) -> TreeRepoResult<()> {
@@ -750,6 +1544,118 @@ fn soft_delete_workspace_subtree(
     Ok(())
 }
 
+fn insert_delete_op(
+    conn: &Connection,
+    folder_uuid: WorkspaceNodeId,
+    mode: FolderDeleteMode,
+) -> TreeRepoResult<String> {
+    let op_uuid = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO workspace_delete_ops (op_uuid, folder_uuid, mode)
+         VALUES (?1, ?2, ?3);",
+        params![op_uuid, folder_uuid.to_string(), mode.as_str()],
+    )?;
+    Ok(op_uuid)
+}
+
+fn record_delete_op_node(
+    conn: &Connection,
+    op_uuid: &str,
+    node_uuid: WorkspaceNodeId,
+) -> TreeRepoResult<()> {
+    conn.execute(
+        "INSERT INTO workspace_delete_op_nodes (op_uuid, node_uuid, parent_uuid, sort_order)
+         SELECT ?1, node_uuid, parent_uuid, sort_order
+         FROM workspace_nodes
+         WHERE node_uuid = ?2;",
+        params![op_uuid, node_uuid.to_string()],
+    )?;
+    Ok(())
+}
+
+fn record_delete_op_subtree(
+    conn: &Connection,
+    op_uuid: &str,
+    folder_uuid: WorkspaceNodeId,
+) -> TreeRepoResult<()> {
+    conn.execute(
+        "WITH RECURSIVE subtree(node_uuid) AS (
+            SELECT node_uuid
+            FROM workspace_nodes
+            WHERE node_uuid = ?2
+              AND is_deleted = 0
+            UNION ALL
+            SELECT child.node_uuid
+            FROM workspace_nodes child
+            INNER JOIN subtree parent ON child.parent_uuid = parent.node_uuid
+            WHERE child.is_deleted = 0
+        )
+        INSERT INTO workspace_delete_op_nodes (op_uuid, node_uuid, parent_uuid, sort_order)
+        SELECT ?1, nodes.node_uuid, nodes.parent_uuid, nodes.sort_order
+        FROM workspace_nodes nodes
+        INNER JOIN subtree ON subtree.node_uuid = nodes.node_uuid;",
+        params![op_uuid, folder_uuid.to_string()],
+    )?;
+    Ok(())
+}
+
+fn list_delete_op_ids(
+    conn: &Connection,
+    sql: &str,
+    op_uuid: &str,
+    column: &'static str,
+) -> TreeRepoResult<Vec<Uuid>> {
+    let mut stmt = conn.prepare(sql)?;
+    let values = stmt
+        .query_map([op_uuid], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    values
+        .iter()
+        .map(|value| parse_uuid(value, column))
+        .collect()
+}
+
+fn parse_delete_op_mode(value: &str) -> TreeRepoResult<FolderDeleteMode> {
+    value.parse().map_err(|other| {
+        TreeRepoError::InvalidData(format!(
+            "invalid delete mode `{other}` in workspace_delete_ops.mode"
+        ))
+    })
+}
+
+/// Shifts active siblings at or after `sort_order` down by one when the
+/// slot is taken, so a restored node gets its original position back.
+fn make_room_for_sort_order(
+    conn: &Connection,
+    parent_uuid: Option<WorkspaceNodeId>,
+    sort_order: i64,
+) -> TreeRepoResult<()> {
+    let parent = parent_uuid.map(|value| value.to_string());
+    let taken: bool = conn.query_row(
+        "SELECT EXISTS(
+            SELECT 1
+            FROM workspace_nodes
+            WHERE parent_uuid IS ?1
+              AND is_deleted = 0
+              AND sort_order = ?2
+        );",
+        params![parent, sort_order],
+        |row| row.get(0),
+    )?;
+    if taken {
+        conn.execute(
+            "UPDATE workspace_nodes
+             SET sort_order = sort_order + 1,
+                 updated_at = (strftime('%s', 'now') * 1000)
+             WHERE parent_uuid IS ?1
+               AND is_deleted = 0
+               AND sort_order >= ?2;",
+            params![parent, sort_order],
+        )?;
+    }
+    Ok(())
+}
+
 fn parse_workspace_node_row(row: &Row<'_>) -> TreeRepoResult<WorkspaceNode> {
     let node_uuid_text: String = row.get("node_uuid")?;
     let node_uuid = parse_uuid(&node_uuid_text, "workspace_nodes.node_uuid")?;
@@ -762,6 +1668,10 @@ fn parse_workspace_node_row(row: &Row<'_>) -> TreeRepoResult<WorkspaceNode> {
         .get::<_, Option<String>>("atom_uuid")?
         .map(|value| parse_uuid(&value, "workspace_nodes.atom_uuid"))
         .transpose()?;
+    let saved_search_uuid = row
+        .get::<_, Option<String>>("saved_search_uuid")?
+        .map(|value| parse_uuid(&value, "workspace_nodes.saved_search_uuid"))
+        .transpose()?;
 
     let kind_text: String = row.get("kind")?;
     let kind = parse_workspace_kind(&kind_text).ok_or_else(|| {
@@ -785,6 +1695,7 @@ fn parse_workspace_node_row(row: &Row<'_>) -> TreeRepoResult<WorkspaceNode> {
         kind,
         parent_uuid,
         atom_uuid,
+        saved_search_uuid,
         display_name: row.get("display_name")?,
         sort_order: row.get("sort_order")?,
         is_deleted,
@@ -793,14 +1704,51 @@ fn parse_workspace_node_row(row: &Row<'_>) -> TreeRepoResult<WorkspaceNode> {
     })
 }
 
-fn parse_workspace_kind(value: &str) -> Option<WorkspaceNodeKind> {
+pub(crate) fn parse_workspace_kind(value: &str) -> Option<WorkspaceNodeKind> {
     match value {
         "folder" => Some(WorkspaceNodeKind::Folder),
         "note_ref" => Some(WorkspaceNodeKind::NoteRef),
+        "smart_folder" => Some(WorkspaceNodeKind::SmartFolder),
         _ => None,
     }
 }
 
+fn parse_saved_search_row(row: &Row<'_>) -> TreeRepoResult<SavedSearch> {
+    let uuid_text: String = row.get("uuid")?;
+    let kind = match row.get::<_, Option<String>>("kind")?.as_deref() {
+        None => None,
+        Some("note") => Some(AtomType::Note),
+        Some("task") => Some(AtomType::Task),
+        Some("event") => Some(AtomType::Event),
+        Some(other) => {
+            return Err(TreeRepoError::InvalidData(format!(
+                "invalid atom type `{other}` in saved_searches.kind"
+            )));
+        }
+    };
+    let open_only = match row.get::<_, i64>("open_only")? {
+        0 => false,
+        1 => true,
+        other => {
+            return Err(TreeRepoError::InvalidData(format!(
+                "invalid open_only value `{other}` in saved_searches.open_only"
+            )));
+        }
+    };
+
+    Ok(SavedSearch {
+        uuid: parse_uuid(&uuid_text, "saved_searches.uuid")?,
+        query: SavedSearchQuery {
+            text: row.get("query_text")?,
+            kind,
+            tag: row.get("tag")?,
+            open_only,
+        },
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
+
 fn parse_uuid(value: &str, column: &'static str) -> TreeRepoResult<Uuid> {
     Uuid::parse_str(value)
         .map_err(|_| TreeRepoError::InvalidData(format!("invalid uuid `{value}` in {column}")))
@@ -816,8 +1764,16 @@ fn ensure_tree_connection_ready(conn: &Connection) -> TreeRepoResult<()> {
         });
     }
 
-    if !table_exists(conn, "workspace_nodes")? {
-        return Err(TreeRepoError::MissingRequiredTable("workspace_nodes"));
+    for table in [
+        "workspace_nodes",
+        "saved_searches",
+        "workspace_delete_ops",
+        "workspace_delete_op_nodes",
+        "workspace_delete_op_atoms",
+    ] {
+        if !table_exists(conn, table)? {
+            return Err(TreeRepoError::MissingRequiredTable(table));
+        }
     }
 
     for column in [
@@ -825,6 +1781,7 @@ fn ensure_tree_connection_ready(conn: &Connection) -> TreeRepoResult<()> {
         "kind",
         "parent_uuid",
         "atom_uuid",
+        "saved_search_uuid",
         "display_name",
         "sort_order",
         "is_deleted",
@@ -2,14 +2,25 @@
 //!
 //! # Responsibility
 //! - Provide keyword search over atom content.
+//! - Match tag names and workspace node display names alongside content.
 //! - Return typed hits with stable IDs.
+//! - Rank atom hits by weighted bm25 with recency, usage, pin and star
+//!   boosts.
+//! - Fuse keyword and vector rankings in hybrid mode.
 //!
 //! # Invariants
-//! - Only non-deleted atoms are returned.
-//! - Result ordering is deterministic by rank and `updated_at`.
+//! - Only non-deleted atoms, tags in use and visible workspace nodes are
+//!   returned.
+//! - Result ordering is deterministic: workspace hits, tag hits, then atom
+//!   hits by ranked score, `updated_at` and `uuid`.
 
 use crate::db::DbError;
 use crate::model::atom::{AtomId, AtomType};
+use crate::repo::atom_repo::{push_atom_flag_filters, SectionFilter};
+use crate::repo::tag_filter::push_tag_filter;
+use crate::repo::tree_repo::{parse_workspace_kind, WorkspaceNodeId, WorkspaceNodeKind};
+use crate::search::vector::{search_vector_filtered, Embedder, HashingEmbedder};
+use crate::sync::provider_types::now_epoch_ms;
 use log::{error, info};
 use rusqlite::types::Value;
 use rusqlite::{params_from_iter, Connection, Row};
@@ -18,6 +29,32 @@ use std::fmt::{Display, Formatter};
 use std::time::Instant;
 use uuid::Uuid;
 
+/// Upper bound for name-matched hits per kind (workspace nodes, tags).
+///
+/// Why: name hits are navigational shortcuts and must not crowd out content
+/// hits when a short query matches many folders.
+const NAME_HITS_MAX: u32 = 5;
+
+/// Minimum number of FTS candidates re-ranked in memory.
+///
+/// Why: boosts are applied after SQLite returns bm25-ordered rows, so the
+/// candidate pool must be wider than `limit` for boosted atoms to surface.
+const RANK_CANDIDATES_MIN: u32 = 200;
+
+/// Candidate pool multiplier applied to the requested `limit`.
+const RANK_CANDIDATES_FACTOR: u32 = 10;
+
+const MS_PER_DAY: f64 = 86_400_000.0;
+
+/// Reciprocal rank fusion constant (`1 / (k + rank)`), as in the RRF paper.
+const RRF_K: f64 = 60.0;
+
+/// Minimum candidates taken from each ranking before fusion.
+const HYBRID_CANDIDATES_MIN: u32 = 50;
+
+/// Words kept in snippets of atoms found by vector similarity only.
+const VECTOR_SNIPPET_WORDS: usize = 10;
+
 /// Result type for search APIs.
 pub type SearchResult<T> = Result<T, SearchError>;
 
@@ -31,6 +68,8 @@ pub enum SearchError {
     },
     Db(DbError),
     InvalidData(String),
+    /// Search was cancelled through a [`crate::SearchCancelHandle`].
+    Cancelled,
 }
 
 impl Display for SearchError {
@@ -41,6 +80,7 @@ impl Display for SearchError {
             }
             Self::Db(err) => write!(f, "{err}"),
             Self::InvalidData(message) => write!(f, "invalid search row: {message}"),
+            Self::Cancelled => write!(f, "search cancelled"),
         }
     }
 }
@@ -51,6 +91,7 @@ impl Error for SearchError {
             Self::InvalidQuery { .. } => None,
             Self::Db(err) => Some(err),
             Self::InvalidData(_) => None,
+            Self::Cancelled => None,
         }
     }
 }
@@ -80,21 +121,161 @@ pub struct SearchQuery {
     ///
     /// Default is `false` to protect type-as-you-search UX from syntax errors.
     pub raw_fts_syntax: bool,
+    /// Whether tag names are matched and returned as [`SearchItem::Tag`].
+    ///
+    /// Ignored when `kind` or `raw_fts_syntax` is set.
+    pub include_tags: bool,
+    /// Whether workspace display names are matched and returned as
+    /// [`SearchItem::Workspace`].
+    ///
+    /// Ignored when `kind` or `raw_fts_syntax` is set.
+    pub include_workspace_nodes: bool,
+    /// Ranking parameters applied to atom hits.
+    pub ranking: SearchRanking,
+    /// Keyword-only or hybrid keyword + vector retrieval.
+    pub mode: SearchMode,
+    /// Whether archived atoms are returned. Default is `false`.
+    pub include_archived: bool,
+}
+
+/// Retrieval strategy for atom hits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    /// FTS5 bm25 ranking only.
+    #[default]
+    Keyword,
+    /// Reciprocal rank fusion of bm25 and embedding cosine similarity.
+    ///
+    /// Finds atoms that share no literal term with the query. Uses the
+    /// embeddings stored by [`crate::search::vector::refresh_embeddings`].
+    Hybrid,
 }
 
 impl SearchQuery {
     /// Creates a query with default pagination and no type filter.
+    ///
+    /// Tag and workspace name matching are enabled by default.
     pub fn new(text: impl Into<String>) -> Self {
         Self {
             text: text.into(),
             kind: None,
             limit: 20,
             raw_fts_syntax: false,
+            include_tags: true,
+            include_workspace_nodes: true,
+            ranking: SearchRanking::default(),
+            mode: SearchMode::Keyword,
+            include_archived: false,
         }
     }
+
+    fn matches_names(&self) -> bool {
+        self.kind.is_none() && !self.raw_fts_syntax
+    }
 }
 
-/// Single search hit returned by [`search_all`].
+/// Ranking parameters for atom hits.
+///
+/// Final score is `relevance * (1 + recency_boost + usage_boost +
+/// pin_boost + star_boost)` where `relevance` is the negated weighted bm25
+/// score, `recency_boost` decays with the age of `updated_at`, and
+/// `usage_boost` grows with the access count recorded by
+/// [`crate::search::usage::record_atom_access`] and decays with the age of
+/// the last access. Pin and star boosts are flat for flagged atoms. Boosts
+/// only reorder atoms that already match the query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchRanking {
+    /// bm25 weight of the `content` column.
+    pub content_weight: f64,
+    /// bm25 weight of the `tags` column.
+    pub tags_weight: f64,
+    /// Boost for an atom updated just now; halves every half-life.
+    pub recency_weight: f64,
+    /// Half-life in days shared by recency and usage decay.
+    pub recency_half_life_days: f64,
+    /// Boost per `ln(1 + access_count)` for a just-accessed atom.
+    pub usage_weight: f64,
+    /// Flat boost for pinned atoms.
+    pub pinned_weight: f64,
+    /// Flat boost for starred atoms.
+    pub starred_weight: f64,
+    /// Reference clock in epoch ms. `None` uses the current time.
+    ///
+    /// Tests pin this value to keep ranking deterministic.
+    pub now_epoch_ms: Option<i64>,
+}
+
+impl Default for SearchRanking {
+    fn default() -> Self {
+        Self {
+            content_weight: 1.0,
+            tags_weight: 2.0,
+            recency_weight: 0.5,
+            recency_half_life_days: 30.0,
+            usage_weight: 0.3,
+            pinned_weight: 0.5,
+            starred_weight: 0.25,
+            now_epoch_ms: None,
+        }
+    }
+}
+
+impl SearchRanking {
+    /// Plain bm25 ordering with equal column weights and no boosts.
+    pub fn bm25_only() -> Self {
+        Self {
+            content_weight: 1.0,
+            tags_weight: 1.0,
+            recency_weight: 0.0,
+            recency_half_life_days: 30.0,
+            usage_weight: 0.0,
+            pinned_weight: 0.0,
+            starred_weight: 0.0,
+            now_epoch_ms: None,
+        }
+    }
+
+    fn has_boosts(&self) -> bool {
+        self.recency_weight > 0.0
+            || self.usage_weight > 0.0
+            || self.pinned_weight > 0.0
+            || self.starred_weight > 0.0
+    }
+
+    fn decay(&self, age_ms: i64) -> f64 {
+        if self.recency_half_life_days <= 0.0 {
+            return 0.0;
+        }
+        let age_days = age_ms.max(0) as f64 / MS_PER_DAY;
+        0.5_f64.powf(age_days / self.recency_half_life_days)
+    }
+
+    fn score(&self, candidate: &AtomCandidate, now_ms: i64) -> f64 {
+        let relevance = -candidate.bm25;
+        let recency = self.recency_weight * self.decay(now_ms - candidate.updated_at);
+        let usage = match candidate.last_accessed_at {
+            Some(last_accessed_at) if candidate.access_count > 0 => {
+                self.usage_weight
+                    * (1.0 + candidate.access_count as f64).ln()
+                    * self.decay(now_ms - last_accessed_at)
+            }
+            _ => 0.0,
+        };
+        let pinned = if candidate.is_pinned {
+            self.pinned_weight
+        } else {
+            0.0
+        };
+        let starred = if candidate.is_starred {
+            self.starred_weight
+        } else {
+            0.0
+        };
+        relevance * (1.0 + recency + usage + pinned + starred)
+    }
+}
+
+/// Atom content hit returned by [`search_all`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SearchHit {
     pub atom_id: AtomId,
@@ -102,9 +283,56 @@ pub struct SearchHit {
     pub snippet: String,
 }
 
-/// Searches atoms via FTS5 and returns ranked results.
+/// Workspace node matched by `display_name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceSearchHit {
+    /// Stable workspace node id, used by explorer to reveal the node.
+    pub node_uuid: WorkspaceNodeId,
+    /// Folder or note reference.
+    pub kind: WorkspaceNodeKind,
+    /// Parent node id. `None` means root-level node.
+    pub parent_uuid: Option<WorkspaceNodeId>,
+    /// Target note atom for `note_ref` nodes.
+    pub atom_uuid: Option<AtomId>,
+    /// Matched user-facing label.
+    pub display_name: String,
+}
+
+/// Tag matched by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagSearchHit {
+    /// Normalized lowercase tag name.
+    pub name: String,
+    /// Number of active atoms carrying this tag.
+    pub atom_count: u32,
+}
+
+/// Typed item returned by [`search_all`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchItem {
+    /// Atom whose content matched the query.
+    Atom(SearchHit),
+    /// Workspace folder or note reference whose name matched the query.
+    Workspace(WorkspaceSearchHit),
+    /// Tag whose name matched the query.
+    Tag(TagSearchHit),
+}
+
+impl SearchItem {
+    /// Returns the atom hit when this item is [`SearchItem::Atom`].
+    pub fn as_atom(&self) -> Option<&SearchHit> {
+        match self {
+            Self::Atom(hit) => Some(hit),
+            _ => None,
+        }
+    }
+}
+
+/// Searches atoms, tags and workspace nodes and returns typed results.
 ///
-/// Returns an empty list for blank queries.
+/// Returns an empty list for blank queries. Name hits (workspace nodes, then
+/// tags) come first and are capped per kind; atom hits fill the remaining
+/// `limit` in FTS rank order.
 ///
 /// # Privacy
 /// - Query text content is never written to logs.
@@ -113,7 +341,19 @@ pub struct SearchHit {
 /// # Errors
 /// - Returns [`SearchError::InvalidQuery`] for malformed raw FTS syntax.
 /// - Returns [`SearchError::Db`] for SQLite execution failures.
-pub fn search_all(conn: &Connection, query: &SearchQuery) -> SearchResult<Vec<SearchHit>> {
+pub fn search_all(conn: &Connection, query: &SearchQuery) -> SearchResult<Vec<SearchItem>> {
+    search_all_with_embedder(conn, query, &HashingEmbedder::default())
+}
+
+/// Same as [`search_all`], using `embedder` for [`SearchMode::Hybrid`].
+///
+/// # Errors
+/// - Same as [`search_all`].
+pub fn search_all_with_embedder(
+    conn: &Connection,
+    query: &SearchQuery,
+    embedder: &dyn Embedder,
+) -> SearchResult<Vec<SearchItem>> {
     let started_at = Instant::now();
     // Why: only log search metadata to match privacy policy.
     let query_len = query.text.chars().count();
@@ -144,71 +384,449 @@ pub fn search_all(conn: &Connection, query: &SearchQuery) -> SearchResult<Vec<Se
         return Ok(Vec::new());
     }
 
+    let log_error = |err: SearchError| {
+        error!(
+            "event=search module=search status=error duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={} error_code={}",
+            started_at.elapsed().as_millis(),
+            query_len,
+            query_terms,
+            has_kind_filter,
+            query.raw_fts_syntax,
+            search_error_code(&err)
+        );
+        err
+    };
+
+    let mut items = Vec::new();
+    let name_terms = name_match_terms(query);
+    if query.include_workspace_nodes && !name_terms.is_empty() {
+        let cap = query.limit.min(NAME_HITS_MAX);
+        items.extend(
+            search_workspace_nodes(conn, &name_terms, cap)
+                .map_err(log_error)?
+                .into_iter()
+                .map(SearchItem::Workspace),
+        );
+    }
+    let workspace_hits = items.len();
+
+    if query.include_tags && !name_terms.is_empty() {
+        let remaining = query.limit.saturating_sub(items.len() as u32);
+        let cap = remaining.min(NAME_HITS_MAX);
+        if cap > 0 {
+            items.extend(
+                search_tags(conn, &name_terms, cap)
+                    .map_err(log_error)?
+                    .into_iter()
+                    .map(SearchItem::Tag),
+            );
+        }
+    }
+    let tag_hits = items.len() - workspace_hits;
+
+    let remaining = query.limit.saturating_sub(items.len() as u32);
+    if remaining > 0 {
+        let hits = match query.mode {
+            SearchMode::Keyword => search_atoms(conn, &match_expr, query, remaining),
+            SearchMode::Hybrid => {
+                search_atoms_hybrid(conn, &match_expr, query, embedder, remaining)
+            }
+        };
+        items.extend(hits.map_err(log_error)?.into_iter().map(SearchItem::Atom));
+    }
+
+    info!(
+        "event=search module=search status=ok hits={} workspace_hits={} tag_hits={} duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={}",
+        items.len(),
+        workspace_hits,
+        tag_hits,
+        started_at.elapsed().as_millis(),
+        query_len,
+        query_terms,
+        has_kind_filter,
+        query.raw_fts_syntax
+    );
+
+    Ok(items)
+}
+
+/// One FTS match with the signals used by [`SearchRanking`].
+pub(crate) struct AtomCandidate {
+    pub(crate) rowid: i64,
+    pub(crate) hit: SearchHit,
+    /// Indexed `content` column, used by search sessions to narrow.
+    pub(crate) content: String,
+    /// Indexed `tags` column, used by search sessions to narrow.
+    pub(crate) tags: String,
+    bm25: f64,
+    updated_at: i64,
+    access_count: i64,
+    last_accessed_at: Option<i64>,
+    is_pinned: bool,
+    is_starred: bool,
+}
+
+/// Builds the bm25-ordered candidate scan shared by [`search_all`] and
+/// [`crate::SearchSession`].
+///
+/// Returns the SQL and its positional bind values; rows decode with
+/// [`parse_atom_candidate`].
+pub(crate) fn atom_candidate_query(
+    match_expr: &str,
+    kind: Option<AtomType>,
+    filter: &SectionFilter,
+    ranking: &SearchRanking,
+    pool: u32,
+) -> SearchResult<(String, Vec<Value>)> {
     let mut sql = String::from(
         "SELECT
+            atoms.rowid AS rowid,
             atoms.uuid AS uuid,
             atoms.type AS type,
-            snippet(atoms_fts, 0, '[', ']', ' ... ', 10) AS snippet
+            atoms_fts.content AS content,
+            atoms_fts.tags AS tags,
+            snippet(atoms_fts, 0, '[', ']', ' ... ', 10) AS snippet,
+            bm25(atoms_fts, ?, ?) AS rank,
+            atoms.updated_at AS updated_at,
+            COALESCE(log.access_count, 0) AS access_count,
+            log.last_accessed_at AS last_accessed_at,
+            atoms.is_pinned AS is_pinned,
+            atoms.is_starred AS is_starred
          FROM atoms_fts
          JOIN atoms ON atoms.rowid = atoms_fts.rowid
+         LEFT JOIN atom_access_log log ON log.atom_uuid = atoms.uuid
          WHERE atoms_fts MATCH ?
            AND atoms.is_deleted = 0",
     );
-    let mut bind_values: Vec<Value> = vec![Value::Text(match_expr.clone())];
+    let mut bind_values: Vec<Value> = vec![
+        Value::Real(ranking.content_weight),
+        Value::Real(ranking.tags_weight),
+        Value::Text(match_expr.to_string()),
+    ];
 
-    if let Some(kind) = query.kind {
+    if let Some(kind) = kind {
         sql.push_str(" AND atoms.type = ?");
         bind_values.push(Value::Text(atom_type_to_db(kind).to_string()));
     }
+    push_atom_flag_filters(
+        &mut sql,
+        "atoms.",
+        filter.include_archived,
+        filter.pinned,
+        filter.starred,
+    );
+    if let Some(tags) = &filter.tags {
+        push_tag_filter(&mut sql, &mut bind_values, "atoms.uuid", tags).map_err(|err| {
+            SearchError::InvalidQuery {
+                query: match_expr.to_string(),
+                message: err.to_string(),
+            }
+        })?;
+    }
+    sql.push_str(" ORDER BY rank ASC, atoms.updated_at DESC, atoms.uuid ASC LIMIT ?");
+    bind_values.push(Value::Integer(i64::from(pool)));
+    Ok((sql, bind_values))
+}
+
+/// Decodes one row of [`atom_candidate_query`].
+pub(crate) fn parse_atom_candidate(row: &Row<'_>) -> SearchResult<AtomCandidate> {
+    Ok(AtomCandidate {
+        rowid: row.get("rowid")?,
+        hit: parse_search_hit(row)?,
+        content: row.get("content")?,
+        tags: row.get("tags")?,
+        bm25: row.get("rank")?,
+        updated_at: row.get("updated_at")?,
+        access_count: row.get("access_count")?,
+        last_accessed_at: row.get("last_accessed_at")?,
+        is_pinned: row.get("is_pinned")?,
+        is_starred: row.get("is_starred")?,
+    })
+}
+
+/// Re-orders bm25-ordered candidates by the boosted [`SearchRanking`] score.
+///
+/// No-op when the ranking has no boosts, since SQL order is then final.
+pub(crate) fn rank_atom_candidates(
+    ranking: &SearchRanking,
+    candidates: Vec<AtomCandidate>,
+) -> Vec<AtomCandidate> {
+    if !ranking.has_boosts() {
+        return candidates;
+    }
+    let now_ms = ranking.now_epoch_ms.unwrap_or_else(now_epoch_ms);
+    let mut scored = candidates
+        .into_iter()
+        .map(|candidate| (ranking.score(&candidate, now_ms), candidate))
+        .collect::<Vec<_>>();
+    scored.sort_by(|(left_score, left), (right_score, right)| {
+        right_score
+            .total_cmp(left_score)
+            .then_with(|| right.updated_at.cmp(&left.updated_at))
+            .then_with(|| left.hit.atom_id.cmp(&right.hit.atom_id))
+    });
+    scored.into_iter().map(|(_, candidate)| candidate).collect()
+}
 
-    sql.push_str(" ORDER BY bm25(atoms_fts), atoms.updated_at DESC, atoms.uuid ASC LIMIT ?");
-    bind_values.push(Value::Integer(i64::from(query.limit)));
+fn search_atoms(
+    conn: &Connection,
+    match_expr: &str,
+    query: &SearchQuery,
+    limit: u32,
+) -> SearchResult<Vec<SearchHit>> {
+    let ranking = &query.ranking;
+    // Why: without boosts SQL order is final, so only `limit` rows are needed.
+    let pool = if ranking.has_boosts() {
+        limit
+            .saturating_mul(RANK_CANDIDATES_FACTOR)
+            .max(RANK_CANDIDATES_MIN)
+    } else {
+        limit
+    };
+    let filter = SectionFilter {
+        include_archived: query.include_archived,
+        ..SectionFilter::default()
+    };
+    let (sql, bind_values) = atom_candidate_query(match_expr, query.kind, &filter, ranking, pool)?;
 
     let mut stmt = conn.prepare(&sql)?;
-    let mut rows = stmt.query(params_from_iter(bind_values)).map_err(|err| {
-        let mapped = map_query_error(err, &match_expr);
-        error!(
-            "event=search module=search status=error duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={} error_code={}",
-            started_at.elapsed().as_millis(),
-            query_len,
-            query_terms,
-            has_kind_filter,
-            query.raw_fts_syntax,
-            search_error_code(&mapped)
-        );
-        mapped
-    })?;
-    let mut hits = Vec::new();
+    let mut rows = stmt
+        .query(params_from_iter(bind_values))
+        .map_err(|err| map_query_error(err, match_expr))?;
+    let mut candidates = Vec::new();
+    while let Some(row) = rows
+        .next()
+        .map_err(|err| map_query_error(err, match_expr))?
+    {
+        candidates.push(parse_atom_candidate(row)?);
+    }
 
-    while let Some(row) = rows.next().map_err(|err| {
-        let mapped = map_query_error(err, &match_expr);
-        error!(
-            "event=search module=search status=error duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={} error_code={}",
-            started_at.elapsed().as_millis(),
-            query_len,
-            query_terms,
-            has_kind_filter,
-            query.raw_fts_syntax,
-            search_error_code(&mapped)
-        );
-        mapped
-    })? {
-        hits.push(parse_search_hit(row)?);
+    Ok(rank_atom_candidates(ranking, candidates)
+        .into_iter()
+        .take(limit as usize)
+        .map(|candidate| candidate.hit)
+        .collect())
+}
+
+/// Fuses keyword and vector rankings with reciprocal rank fusion.
+///
+/// Each list contributes `1 / (RRF_K + rank)`; ties keep keyword hits first,
+/// then order by `uuid`.
+fn search_atoms_hybrid(
+    conn: &Connection,
+    match_expr: &str,
+    query: &SearchQuery,
+    embedder: &dyn Embedder,
+    limit: u32,
+) -> SearchResult<Vec<SearchHit>> {
+    let pool = limit.max(HYBRID_CANDIDATES_MIN);
+    let keyword_hits = search_atoms(conn, match_expr, query, pool)?;
+    let vector_hits = search_vector_filtered(
+        conn,
+        embedder,
+        &query.text,
+        query.kind,
+        query.include_archived,
+        pool,
+    )?;
+
+    let mut fused: Vec<(f64, Option<usize>, SearchHit)> = Vec::new();
+    for (rank, hit) in keyword_hits.into_iter().enumerate() {
+        fused.push((1.0 / (RRF_K + rank as f64 + 1.0), Some(rank), hit));
+    }
+    for (rank, vector_hit) in vector_hits.into_iter().enumerate() {
+        let contribution = 1.0 / (RRF_K + rank as f64 + 1.0);
+        match fused
+            .iter_mut()
+            .find(|(_, _, hit)| hit.atom_id == vector_hit.atom_id)
+        {
+            Some(entry) => entry.0 += contribution,
+            None => fused.push((
+                contribution,
+                None,
+                SearchHit {
+                    atom_id: vector_hit.atom_id,
+                    kind: vector_hit.kind,
+                    snippet: vector_snippet(conn, vector_hit.atom_id)?,
+                },
+            )),
+        }
     }
 
-    info!(
-        "event=search module=search status=ok hits={} duration_ms={} query_len={} query_terms={} has_kind_filter={} raw_fts={}",
-        hits.len(),
-        started_at.elapsed().as_millis(),
-        query_len,
-        query_terms,
-        has_kind_filter,
-        query.raw_fts_syntax
+    fused.sort_by(
+        |(left_score, left_rank, left), (right_score, right_rank, right)| {
+            right_score
+                .total_cmp(left_score)
+                .then_with(|| right_rank.is_some().cmp(&left_rank.is_some()))
+                .then_with(|| left.atom_id.cmp(&right.atom_id))
+        },
+    );
+    Ok(fused
+        .into_iter()
+        .take(limit as usize)
+        .map(|(_, _, hit)| hit)
+        .collect())
+}
+
+/// Leading words of an atom found only by similarity (no FTS snippet).
+fn vector_snippet(conn: &Connection, atom_id: AtomId) -> SearchResult<String> {
+    let content: String = conn.query_row(
+        "SELECT content FROM atoms WHERE uuid = ?1",
+        [atom_id.to_string()],
+        |row| row.get(0),
+    )?;
+    let mut words = content.split_whitespace();
+    let mut snippet = words
+        .by_ref()
+        .take(VECTOR_SNIPPET_WORDS)
+        .collect::<Vec<_>>()
+        .join(" ");
+    if words.next().is_some() {
+        snippet.push_str(" ...");
+    }
+    Ok(snippet)
+}
+
+fn search_workspace_nodes(
+    conn: &Connection,
+    terms: &[String],
+    limit: u32,
+) -> SearchResult<Vec<WorkspaceSearchHit>> {
+    let mut sql = String::from(
+        "SELECT
+            n.node_uuid AS node_uuid,
+            n.kind AS kind,
+            n.parent_uuid AS parent_uuid,
+            n.atom_uuid AS atom_uuid,
+            n.display_name AS display_name
+         FROM workspace_nodes n
+         LEFT JOIN atoms a ON a.uuid = n.atom_uuid
+         WHERE n.is_deleted = 0
+           AND (
+             n.kind IN ('folder', 'smart_folder')
+             OR (n.kind = 'note_ref' AND a.type = 'note' AND a.is_deleted = 0)
+           )",
+    );
+    let mut bind_values = Vec::new();
+    push_name_like_filters(&mut sql, &mut bind_values, "n.display_name", terms);
+    push_name_order(&mut sql, &mut bind_values, "n.display_name", terms);
+    sql.push_str(", n.node_uuid ASC LIMIT ?");
+    bind_values.push(Value::Integer(i64::from(limit)));
+
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(bind_values))?;
+    let mut hits = Vec::new();
+    while let Some(row) = rows.next()? {
+        let kind_text: String = row.get("kind")?;
+        let kind = parse_workspace_kind(&kind_text).ok_or_else(|| {
+            SearchError::InvalidData(format!("invalid workspace kind `{kind_text}`"))
+        })?;
+        hits.push(WorkspaceSearchHit {
+            node_uuid: parse_uuid_column(row, "node_uuid")?,
+            kind,
+            parent_uuid: parse_optional_uuid_column(row, "parent_uuid")?,
+            atom_uuid: parse_optional_uuid_column(row, "atom_uuid")?,
+            display_name: row.get("display_name")?,
+        });
+    }
+    Ok(hits)
+}
+
+fn search_tags(conn: &Connection, terms: &[String], limit: u32) -> SearchResult<Vec<TagSearchHit>> {
+    let mut sql = String::from(
+        "SELECT
+            t.name AS name,
+            COUNT(1) AS atom_count
+         FROM tags t
+         INNER JOIN atom_tags at ON at.tag_id = t.id
+         INNER JOIN atoms a ON a.uuid = at.atom_uuid
+         WHERE a.is_deleted = 0",
     );
+    let mut bind_values = Vec::new();
+    push_name_like_filters(&mut sql, &mut bind_values, "t.name", terms);
+    sql.push_str(" GROUP BY t.id");
+    push_name_order(&mut sql, &mut bind_values, "t.name", terms);
+    sql.push_str(" LIMIT ?");
+    bind_values.push(Value::Integer(i64::from(limit)));
 
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(bind_values))?;
+    let mut hits = Vec::new();
+    while let Some(row) = rows.next()? {
+        let name: String = row.get("name")?;
+        let atom_count: i64 = row.get("atom_count")?;
+        hits.push(TagSearchHit {
+            name: name.to_lowercase(),
+            atom_count: u32::try_from(atom_count).unwrap_or(u32::MAX),
+        });
+    }
     Ok(hits)
 }
 
+/// Appends one case-insensitive substring predicate per query term.
+fn push_name_like_filters(
+    sql: &mut String,
+    bind_values: &mut Vec<Value>,
+    column: &str,
+    terms: &[String],
+) {
+    for term in terms {
+        sql.push_str(&format!(" AND {column} LIKE ? ESCAPE '\\'"));
+        bind_values.push(Value::Text(format!("%{}%", escape_like(term))));
+    }
+}
+
+/// Orders name hits: exact match, then prefix match, then shorter names.
+fn push_name_order(sql: &mut String, bind_values: &mut Vec<Value>, column: &str, terms: &[String]) {
+    let full = terms.join(" ");
+    sql.push_str(&format!(
+        " ORDER BY ({column} = ? COLLATE NOCASE) DESC,
+                   ({column} LIKE ? ESCAPE '\\') DESC,
+                   length({column}) ASC,
+                   {column} COLLATE NOCASE ASC"
+    ));
+    bind_values.push(Value::Text(full.clone()));
+    bind_values.push(Value::Text(format!("{}%", escape_like(&full))));
+}
+
+fn name_match_terms(query: &SearchQuery) -> Vec<String> {
+    if !query.matches_names() {
+        return Vec::new();
+    }
+    query
+        .text
+        .split_whitespace()
+        .map(|term| term.to_string())
+        .collect()
+}
+
+fn escape_like(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for ch in raw.chars() {
+        if matches!(ch, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+fn parse_uuid_column(row: &Row<'_>, column: &str) -> SearchResult<Uuid> {
+    let value: String = row.get(column)?;
+    Uuid::parse_str(&value)
+        .map_err(|_| SearchError::InvalidData(format!("invalid uuid `{value}` in {column}")))
+}
+
+fn parse_optional_uuid_column(row: &Row<'_>, column: &str) -> SearchResult<Option<Uuid>> {
+    row.get::<_, Option<String>>(column)?
+        .map(|value| {
+            Uuid::parse_str(&value).map_err(|_| {
+                SearchError::InvalidData(format!("invalid uuid `{value}` in {column}"))
+            })
+        })
+        .transpose()
+}
+
 fn parse_search_hit(row: &Row<'_>) -> SearchResult<SearchHit> {
     let uuid_text: String = row.get("uuid")?;
     let atom_id = Uuid::parse_str(&uuid_text)
@@ -225,7 +843,7 @@ fn parse_search_hit(row: &Row<'_>) -> SearchResult<SearchHit> {
     })
 }
 
-fn parse_atom_type(value: &str) -> Option<AtomType> {
+pub(crate) fn parse_atom_type(value: &str) -> Option<AtomType> {
     match value {
         "note" => Some(AtomType::Note),
         "task" => Some(AtomType::Task),
@@ -252,17 +870,20 @@ fn build_match_expression(query: &SearchQuery) -> SearchResult<Option<String>> {
         return Ok(Some(text.to_string()));
     }
 
+    Ok(escaped_match_expression(text))
+}
+
+/// Builds an FTS5 expression that matches every whitespace-separated term
+/// literally. Returns `None` for blank text.
+pub(crate) fn escaped_match_expression(text: &str) -> Option<String> {
     let terms = text
         .split_whitespace()
-        .filter(|term| !term.is_empty())
         .map(escape_fts_term)
         .collect::<Vec<_>>();
-
     if terms.is_empty() {
-        return Ok(None);
+        return None;
     }
-
-    Ok(Some(terms.join(" AND ")))
+    Some(terms.join(" AND "))
 }
 
 fn escape_fts_term(raw: &str) -> String {
@@ -298,5 +919,6 @@ fn search_error_code(err: &SearchError) -> &'static str {
         SearchError::InvalidQuery { .. } => "invalid_query",
         SearchError::Db(_) => "db_error",
         SearchError::InvalidData(_) => "invalid_data",
+        SearchError::Cancelled => "cancelled",
     }
 }
@@ -3,8 +3,14 @@
 //! # Responsibility
 //! - Expose query APIs backed by SQLite FTS5 index.
 //! - Keep search result shaping inside core.
+//! - Record atom access used by ranking.
+//! - Maintain the optional embedding index for hybrid search.
+//! - Serve incremental search-as-you-type sessions.
 //!
 //! # See also
 //! - docs/releases/v0.1/prs/PR-0007-fts5-search.md
 
 pub mod fts;
+pub mod session;
+pub mod usage;
+pub mod vector;
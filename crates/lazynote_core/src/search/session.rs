@@ -0,0 +1,389 @@
+//! Incremental search-as-you-type session.
+//!
+//! # Responsibility
+//! - Keep one connection, a cached prepared statement and the last candidate
+//!   set across keystrokes.
+//! - Narrow cached candidates in memory when the query text only grows.
+//! - Let callers drop stale keystrokes through a cancel handle.
+//!
+//! # Invariants
+//! - The last query term is prefix-matched (`meet` matches `meeting`).
+//! - Narrowing only runs when the cached scan was complete (not capped) and
+//!   the new text extends the previous text; otherwise the index is rescanned.
+//! - Narrowed results keep the rank order of the broader scan; scans rank
+//!   with the same [`SearchRanking`] as [`crate::search_all`].
+//! - The cache is dropped whenever the database changed since the scan
+//!   (`PRAGMA data_version` plus this connection's own writes), and narrowed
+//!   candidates are rechecked against `is_deleted`.
+//! - A cancelled search returns [`SearchError::Cancelled`] and never replaces
+//!   the cache with partial results.
+
+use crate::model::atom::AtomType;
+use crate::repo::atom_repo::SectionFilter;
+use crate::search::fts::{
+    atom_candidate_query, parse_atom_candidate, rank_atom_candidates, AtomCandidate, SearchError,
+    SearchHit, SearchRanking, SearchResult,
+};
+use log::info;
+use rusqlite::{params, params_from_iter, Connection, InterruptHandle};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Maximum candidates kept per scan.
+///
+/// Why: narrowing is only exact when the cached set holds every match, so
+/// scans beyond this cap are marked incomplete and always rescanned.
+pub const SESSION_CANDIDATES_MAX: u32 = 500;
+
+const SESSION_VERIFY_SQL: &str = "SELECT EXISTS(
+        SELECT 1
+        FROM atoms_fts
+        WHERE atoms_fts MATCH ?1
+          AND rowid = ?2
+     )";
+
+const SESSION_ACTIVE_SQL: &str = "SELECT EXISTS(
+        SELECT 1
+        FROM atoms
+        WHERE rowid = ?1
+          AND is_deleted = 0
+     )";
+
+/// Thread-safe handle that cancels the in-flight and any earlier search of
+/// one [`SearchSession`].
+#[derive(Clone)]
+pub struct SearchCancelHandle {
+    generation: Arc<AtomicU64>,
+    interrupt: Arc<InterruptHandle>,
+}
+
+impl SearchCancelHandle {
+    /// Cancels the running search, if any.
+    ///
+    /// Searches started after this call are not affected.
+    pub fn cancel(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.interrupt.interrupt();
+    }
+}
+
+/// Result of one [`SearchSession::search`] call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionHits {
+    /// Top hits in rank order, capped by the session limit.
+    pub hits: Vec<SearchHit>,
+    /// `true` when hits were derived from cached candidates without a scan.
+    pub narrowed: bool,
+}
+
+struct SessionCache {
+    text: String,
+    candidates: Vec<AtomCandidate>,
+    complete: bool,
+    /// Database state the candidates were read at.
+    version: (i64, u64),
+}
+
+/// Search-as-you-type session bound to one connection.
+pub struct SearchSession {
+    conn: Connection,
+    kind: Option<AtomType>,
+    limit: u32,
+    filter: SectionFilter,
+    ranking: SearchRanking,
+    cache: Option<SessionCache>,
+    generation: Arc<AtomicU64>,
+    interrupt: Arc<InterruptHandle>,
+}
+
+impl SearchSession {
+    /// Creates a session over a migrated connection with limit 20, no type
+    /// filter, the default list filter (archived atoms hidden) and the
+    /// default ranking.
+    pub fn new(conn: Connection) -> Self {
+        let interrupt = Arc::new(conn.get_interrupt_handle());
+        Self {
+            conn,
+            kind: None,
+            limit: 20,
+            filter: SectionFilter::default(),
+            ranking: SearchRanking::default(),
+            cache: None,
+            generation: Arc::new(AtomicU64::new(0)),
+            interrupt,
+        }
+    }
+
+    /// Sets the type filter; clears cached candidates when it changes.
+    pub fn set_kind(&mut self, kind: Option<AtomType>) {
+        if self.kind != kind {
+            self.kind = kind;
+            self.cache = None;
+        }
+    }
+
+    /// Sets the tag and flag filter; clears cached candidates.
+    pub fn set_filter(&mut self, filter: SectionFilter) {
+        self.filter = filter;
+        self.cache = None;
+    }
+
+    /// Sets the ranking applied to scans; clears cached candidates.
+    pub fn set_ranking(&mut self, ranking: SearchRanking) {
+        self.ranking = ranking;
+        self.cache = None;
+    }
+
+    /// Sets the maximum number of returned hits.
+    pub fn set_limit(&mut self, limit: u32) {
+        self.limit = limit;
+    }
+
+    /// Returns a handle that can cancel searches from another thread.
+    pub fn cancel_handle(&self) -> SearchCancelHandle {
+        SearchCancelHandle {
+            generation: Arc::clone(&self.generation),
+            interrupt: Arc::clone(&self.interrupt),
+        }
+    }
+
+    /// Returns the underlying connection.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Drops cached candidates.
+    ///
+    /// Writes are detected automatically; this only forces a rescan.
+    pub fn invalidate(&mut self) {
+        self.cache = None;
+    }
+
+    /// Runs one keystroke query.
+    ///
+    /// # Privacy
+    /// - Query text content is never written to logs.
+    ///
+    /// # Errors
+    /// - Returns [`SearchError::Cancelled`] when the search was cancelled.
+    /// - Returns [`SearchError::Db`] for SQLite execution failures.
+    pub fn search(&mut self, text: &str) -> SearchResult<SessionHits> {
+        let started_at = Instant::now();
+        let generation = self.generation.load(Ordering::SeqCst);
+        let normalized = text.trim_start().to_lowercase();
+        let terms = parse_terms(&normalized);
+        if terms.is_empty() || self.limit == 0 {
+            return Ok(SessionHits {
+                hits: Vec::new(),
+                narrowed: false,
+            });
+        }
+        let match_expr = build_prefix_expression(&terms);
+
+        let version = self.data_version()?;
+        let can_narrow = self.cache.as_ref().is_some_and(|cache| {
+            cache.complete
+                && cache.version == version
+                && normalized.is_ascii()
+                && normalized.starts_with(&cache.text)
+        });
+        let next_cache = if can_narrow {
+            let cache = self.cache.as_ref().expect("cache checked above");
+            let mut keep = Vec::with_capacity(cache.candidates.len());
+            for candidate in &cache.candidates {
+                self.ensure_current(generation)?;
+                keep.push(self.candidate_matches(candidate, &terms, &match_expr)?);
+            }
+            let previous = self.cache.take().expect("cache checked above");
+            SessionCache {
+                text: normalized,
+                candidates: previous
+                    .candidates
+                    .into_iter()
+                    .zip(keep)
+                    .filter_map(|(candidate, kept)| kept.then_some(candidate))
+                    .collect(),
+                complete: true,
+                version,
+            }
+        } else {
+            let candidates = self.scan(&match_expr, generation)?;
+            let complete = candidates.len() < SESSION_CANDIDATES_MAX as usize;
+            SessionCache {
+                text: normalized,
+                candidates,
+                complete,
+                version,
+            }
+        };
+        self.ensure_current(generation)?;
+
+        let hits = next_cache
+            .candidates
+            .iter()
+            .take(self.limit as usize)
+            .map(|candidate| candidate.hit.clone())
+            .collect::<Vec<_>>();
+        self.cache = Some(next_cache);
+
+        info!(
+            "event=search_session module=search status=ok hits={} narrowed={} duration_ms={} query_len={} query_terms={}",
+            hits.len(),
+            can_narrow,
+            started_at.elapsed().as_millis(),
+            text.chars().count(),
+            terms.len()
+        );
+        Ok(SessionHits {
+            hits,
+            narrowed: can_narrow,
+        })
+    }
+
+    fn ensure_current(&self, generation: u64) -> SearchResult<()> {
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return Err(SearchError::Cancelled);
+        }
+        Ok(())
+    }
+
+    /// Returns a marker that changes whenever any connection committed a
+    /// write since the last call.
+    fn data_version(&self) -> SearchResult<(i64, u64)> {
+        // Why: `data_version` only moves for commits of other connections;
+        // `total_changes` covers writes made through this session's own
+        // connection.
+        let external: i64 = self
+            .conn
+            .query_row("PRAGMA data_version;", [], |row| row.get(0))?;
+        Ok((external, self.conn.total_changes()))
+    }
+
+    fn scan(&self, match_expr: &str, generation: u64) -> SearchResult<Vec<AtomCandidate>> {
+        let (sql, bind_values) = atom_candidate_query(
+            match_expr,
+            self.kind,
+            &self.filter,
+            &self.ranking,
+            SESSION_CANDIDATES_MAX,
+        )?;
+        let mut stmt = self.conn.prepare_cached(&sql)?;
+        let mut rows = stmt
+            .query(params_from_iter(bind_values))
+            .map_err(|err| self.map_scan_error(err, generation))?;
+        let mut candidates = Vec::new();
+        while let Some(row) = rows
+            .next()
+            .map_err(|err| self.map_scan_error(err, generation))?
+        {
+            candidates.push(parse_atom_candidate(row)?);
+        }
+        Ok(rank_atom_candidates(&self.ranking, candidates))
+    }
+
+    fn map_scan_error(&self, err: rusqlite::Error, generation: u64) -> SearchError {
+        let interrupted = matches!(
+            &err,
+            rusqlite::Error::SqliteFailure(sqlite_err, _)
+                if sqlite_err.code == rusqlite::ErrorCode::OperationInterrupted
+        );
+        if interrupted || self.generation.load(Ordering::SeqCst) != generation {
+            return SearchError::Cancelled;
+        }
+        err.into()
+    }
+
+    /// Checks one cached candidate against the extended query.
+    ///
+    /// Candidates deleted since the scan are dropped. ASCII rows are matched
+    /// in memory with unicode61-equivalent tokenization; other rows are
+    /// verified by FTS to respect diacritic folding.
+    fn candidate_matches(
+        &self,
+        candidate: &AtomCandidate,
+        terms: &[Term],
+        match_expr: &str,
+    ) -> SearchResult<bool> {
+        let mut active = self.conn.prepare_cached(SESSION_ACTIVE_SQL)?;
+        let active: i64 = active.query_row([candidate.rowid], |row| row.get(0))?;
+        if active == 0 {
+            return Ok(false);
+        }
+        if candidate.content.is_ascii() && candidate.tags.is_ascii() {
+            let columns = [tokenize(&candidate.content), tokenize(&candidate.tags)];
+            return Ok(terms
+                .iter()
+                .all(|term| columns.iter().any(|tokens| term.matches(tokens))));
+        }
+        let mut stmt = self.conn.prepare_cached(SESSION_VERIFY_SQL)?;
+        let exists: i64 = stmt.query_row(params![match_expr, candidate.rowid], |row| row.get(0))?;
+        Ok(exists == 1)
+    }
+}
+
+/// One whitespace-separated query term split into index tokens.
+struct Term {
+    tokens: Vec<String>,
+    prefix: bool,
+}
+
+impl Term {
+    /// Phrase match: tokens must appear consecutively; the last token of a
+    /// prefix term only needs to start the content token.
+    fn matches(&self, column: &[String]) -> bool {
+        if self.tokens.is_empty() || column.len() < self.tokens.len() {
+            return false;
+        }
+        column.windows(self.tokens.len()).any(|window| {
+            window
+                .iter()
+                .zip(&self.tokens)
+                .enumerate()
+                .all(|(index, (actual, expected))| {
+                    if self.prefix && index + 1 == self.tokens.len() {
+                        actual.starts_with(expected.as_str())
+                    } else {
+                        actual == expected
+                    }
+                })
+        })
+    }
+}
+
+fn parse_terms(normalized: &str) -> Vec<Term> {
+    let ends_with_space = normalized.ends_with(char::is_whitespace);
+    let raw_terms = normalized.split_whitespace().collect::<Vec<_>>();
+    let last_index = raw_terms.len().saturating_sub(1);
+    raw_terms
+        .iter()
+        .enumerate()
+        .map(|(index, raw)| Term {
+            tokens: tokenize(raw),
+            prefix: index == last_index && !ends_with_space,
+        })
+        .filter(|term| !term.tokens.is_empty())
+        .collect()
+}
+
+fn build_prefix_expression(terms: &[Term]) -> String {
+    terms
+        .iter()
+        .map(|term| {
+            let phrase = format!("\"{}\"", term.tokens.join(" "));
+            if term.prefix {
+                format!("{phrase}*")
+            } else {
+                phrase
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,74 @@
+//! Atom access log feeding the search usage boost.
+//!
+//! # Responsibility
+//! - Record that the user opened an atom.
+//! - Keep one aggregated row per atom (`access_count`, `last_accessed_at`).
+//!
+//! # Invariants
+//! - Only existing, non-deleted atoms are recorded.
+//! - Rows are removed together with their atom (`ON DELETE CASCADE`).
+//!
+//! # See also
+//! - `search::fts::SearchRanking` for how the log affects ordering.
+
+use crate::model::atom::AtomId;
+use crate::search::fts::SearchResult;
+use crate::sync::provider_types::now_epoch_ms;
+use rusqlite::{params, Connection};
+
+/// Records one access of `atom_id` at the current time.
+///
+/// Returns `false` when the atom does not exist or is soft-deleted.
+///
+/// # Errors
+/// - Returns [`crate::search::fts::SearchError::Db`] for SQLite failures.
+pub fn record_atom_access(conn: &Connection, atom_id: AtomId) -> SearchResult<bool> {
+    record_atom_access_at(conn, atom_id, now_epoch_ms())
+}
+
+/// Records one access of `atom_id` at `accessed_at` (epoch ms).
+///
+/// `last_accessed_at` never moves backwards when an older timestamp is
+/// replayed.
+///
+/// # Errors
+/// - Returns [`crate::search::fts::SearchError::Db`] for SQLite failures.
+pub fn record_atom_access_at(
+    conn: &Connection,
+    atom_id: AtomId,
+    accessed_at: i64,
+) -> SearchResult<bool> {
+    let changed = conn.execute(
+        "INSERT INTO atom_access_log (atom_uuid, access_count, last_accessed_at)
+         SELECT uuid, 1, ?2
+         FROM atoms
+         WHERE uuid = ?1
+           AND is_deleted = 0
+         ON CONFLICT(atom_uuid) DO UPDATE SET
+            access_count = access_count + 1,
+            last_accessed_at = MAX(last_accessed_at, excluded.last_accessed_at)",
+        params![atom_id.to_string(), accessed_at],
+    )?;
+    Ok(changed > 0)
+}
+
+/// Returns `(access_count, last_accessed_at)` for `atom_id`, if recorded.
+///
+/// # Errors
+/// - Returns [`crate::search::fts::SearchError::Db`] for SQLite failures.
+pub fn atom_access_stats(conn: &Connection, atom_id: AtomId) -> SearchResult<Option<(u32, i64)>> {
+    let mut stmt = conn.prepare(
+        "SELECT access_count, last_accessed_at
+         FROM atom_access_log
+         WHERE atom_uuid = ?1",
+    )?;
+    let mut rows = stmt.query(params![atom_id.to_string()])?;
+    let Some(row) = rows.next()? else {
+        return Ok(None);
+    };
+    let count: i64 = row.get(0)?;
+    Ok(Some((
+        u32::try_from(count).unwrap_or(u32::MAX),
+        row.get(1)?,
+    )))
+}
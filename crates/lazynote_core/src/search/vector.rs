@@ -0,0 +1,311 @@
+//! Local embedding backend and vector index for semantic search.
+//!
+//! # Responsibility
+//! - Define the pluggable [`Embedder`] contract.
+//! - Provide a deterministic, offline [`HashingEmbedder`] default.
+//! - Bring `atom_embeddings` up to date on an explicit refresh and rank atoms
+//!   by cosine similarity.
+//!
+//! # Invariants
+//! - Embeddings are L2-normalized, so cosine similarity is a dot product.
+//! - Vector queries are read-only: they rank the rows stored for the
+//!   embedder's model and never embed atoms. Atoms edited since the last
+//!   [`refresh_embeddings`] rank by their previous vector; atoms never
+//!   refreshed only match by keyword.
+//! - Only non-deleted atoms are returned.
+
+use crate::model::atom::{AtomId, AtomType};
+use crate::repo::atom_repo::atom_type_to_db;
+use crate::search::fts::{parse_atom_type, SearchError, SearchResult};
+use log::info;
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection};
+use std::time::Instant;
+use uuid::Uuid;
+
+/// Default number of dimensions produced by [`HashingEmbedder`].
+pub const HASHING_EMBEDDER_DIMENSIONS: usize = 256;
+
+/// Text-to-vector backend used by the vector index.
+///
+/// Implementations must be deterministic for a given `model_id`: the index
+/// only re-embeds atoms whose content or model id changed.
+pub trait Embedder {
+    /// Stable identifier persisted next to each vector.
+    fn model_id(&self) -> &str;
+    /// Length of every vector returned by [`Embedder::embed`].
+    fn dimensions(&self) -> usize;
+    /// Embeds one text. Returned vector must have `dimensions()` entries.
+    fn embed(&self, text: &str) -> Vec<f32>;
+}
+
+/// Feature-hashing bag-of-words embedder.
+///
+/// Hashes lowercase word tokens and their character trigrams into a fixed
+/// number of signed buckets. Trigrams let related word forms (`plan`,
+/// `planning`) share dimensions. Needs no model files, network or GPU.
+#[derive(Debug, Clone)]
+pub struct HashingEmbedder {
+    dimensions: usize,
+    model_id: String,
+}
+
+impl HashingEmbedder {
+    /// Creates an embedder with `dimensions` buckets (minimum 1).
+    pub fn new(dimensions: usize) -> Self {
+        let dimensions = dimensions.max(1);
+        Self {
+            dimensions,
+            model_id: format!("hashing-bow-v1-{dimensions}"),
+        }
+    }
+}
+
+impl Default for HashingEmbedder {
+    fn default() -> Self {
+        Self::new(HASHING_EMBEDDER_DIMENSIONS)
+    }
+}
+
+impl Embedder for HashingEmbedder {
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn embed(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0_f32; self.dimensions];
+        let lowered = text.to_lowercase();
+        for word in lowered
+            .split(|ch: char| !ch.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            add_feature(&mut vector, word.as_bytes(), 1.0);
+
+            let padded = format!("^{word}$").chars().collect::<Vec<_>>();
+            for window in padded.windows(3) {
+                let trigram = window.iter().collect::<String>();
+                add_feature(&mut vector, trigram.as_bytes(), 0.5);
+            }
+        }
+        normalize(&mut vector);
+        vector
+    }
+}
+
+fn add_feature(vector: &mut [f32], feature: &[u8], weight: f32) {
+    let hash = fnv1a64(feature);
+    let bucket = (hash % vector.len() as u64) as usize;
+    // Why: a sign bit independent of the bucket keeps collisions unbiased.
+    let sign = if (hash >> 63) == 0 { 1.0 } else { -1.0 };
+    vector[bucket] += sign * weight;
+}
+
+fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|value| value * value).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for value in vector.iter_mut() {
+            *value /= norm;
+        }
+    }
+}
+
+/// FNV-1a 64-bit hash; stable across platforms and Rust versions.
+pub(crate) fn fnv1a64(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Atom ranked by cosine similarity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorHit {
+    pub atom_id: AtomId,
+    pub kind: AtomType,
+    /// Cosine similarity in `[-1, 1]`.
+    pub similarity: f32,
+}
+
+/// Embeds every non-deleted atom whose stored vector is missing or stale.
+///
+/// Returns the number of refreshed rows. This is the only writer of
+/// `atom_embeddings`; run it as maintenance (app start, after imports)
+/// rather than per query.
+///
+/// # Errors
+/// - Returns [`SearchError::Db`] for SQLite failures.
+/// - Returns [`SearchError::InvalidData`] when the embedder returns a vector
+///   of the wrong length.
+pub fn refresh_embeddings(conn: &Connection, embedder: &dyn Embedder) -> SearchResult<usize> {
+    let started_at = Instant::now();
+    let mut stmt = conn.prepare(
+        "SELECT a.uuid, a.content, e.model, e.content_hash
+         FROM atoms a
+         LEFT JOIN atom_embeddings e ON e.atom_uuid = a.uuid
+         WHERE a.is_deleted = 0",
+    )?;
+    let mut rows = stmt.query([])?;
+    let mut stale = Vec::new();
+    while let Some(row) = rows.next()? {
+        let uuid: String = row.get(0)?;
+        let content: String = row.get(1)?;
+        let model: Option<String> = row.get(2)?;
+        let stored_hash: Option<i64> = row.get(3)?;
+        let content_hash = fnv1a64(content.as_bytes()) as i64;
+        if model.as_deref() != Some(embedder.model_id()) || stored_hash != Some(content_hash) {
+            stale.push((uuid, content, content_hash));
+        }
+    }
+    drop(rows);
+
+    for (uuid, content, content_hash) in &stale {
+        let vector = embedder.embed(content);
+        if vector.len() != embedder.dimensions() {
+            return Err(SearchError::InvalidData(format!(
+                "embedder `{}` returned {} dimensions, expected {}",
+                embedder.model_id(),
+                vector.len(),
+                embedder.dimensions()
+            )));
+        }
+        conn.execute(
+            "INSERT INTO atom_embeddings (atom_uuid, model, dimensions, vector, content_hash)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(atom_uuid) DO UPDATE SET
+                model = excluded.model,
+                dimensions = excluded.dimensions,
+                vector = excluded.vector,
+                content_hash = excluded.content_hash,
+                updated_at = (strftime('%s', 'now') * 1000)",
+            params![
+                uuid,
+                embedder.model_id(),
+                vector.len() as i64,
+                encode_vector(&vector),
+                content_hash
+            ],
+        )?;
+    }
+
+    if !stale.is_empty() {
+        info!(
+            "event=embeddings_refresh module=search status=ok refreshed={} duration_ms={}",
+            stale.len(),
+            started_at.elapsed().as_millis()
+        );
+    }
+    Ok(stale.len())
+}
+
+/// Ranks atoms by cosine similarity between `text` and stored embeddings.
+///
+/// Reads stored embeddings only; see [`refresh_embeddings`]. Hits with
+/// non-positive similarity are dropped. Ties are ordered by `uuid` for
+/// determinism. Archived atoms are skipped.
+///
+/// # Errors
+/// - Returns [`SearchError::Db`] for SQLite failures.
+/// - Returns [`SearchError::InvalidData`] for malformed stored rows.
+pub fn search_vector(
+    conn: &Connection,
+    embedder: &dyn Embedder,
+    text: &str,
+    kind: Option<AtomType>,
+    limit: u32,
+) -> SearchResult<Vec<VectorHit>> {
+    search_vector_filtered(conn, embedder, text, kind, false, limit)
+}
+
+/// [`search_vector`] with control over archived atoms.
+pub(crate) fn search_vector_filtered(
+    conn: &Connection,
+    embedder: &dyn Embedder,
+    text: &str,
+    kind: Option<AtomType>,
+    include_archived: bool,
+    limit: u32,
+) -> SearchResult<Vec<VectorHit>> {
+    if text.trim().is_empty() || limit == 0 {
+        return Ok(Vec::new());
+    }
+    let query_vector = embedder.embed(text);
+
+    let mut sql = String::from(
+        "SELECT a.uuid, a.type, e.vector
+         FROM atom_embeddings e
+         INNER JOIN atoms a ON a.uuid = e.atom_uuid
+         WHERE a.is_deleted = 0
+           AND e.model = ?",
+    );
+    let mut bind_values = vec![Value::Text(embedder.model_id().to_string())];
+    if let Some(kind) = kind {
+        sql.push_str(" AND a.type = ?");
+        bind_values.push(Value::Text(atom_type_to_db(kind).to_string()));
+    }
+    if !include_archived {
+        sql.push_str(" AND a.is_archived = 0");
+    }
+
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(bind_values))?;
+    let mut hits = Vec::new();
+    while let Some(row) = rows.next()? {
+        let uuid_text: String = row.get(0)?;
+        let type_text: String = row.get(1)?;
+        let blob: Vec<u8> = row.get(2)?;
+        let vector = decode_vector(&blob)?;
+        if vector.len() != query_vector.len() {
+            continue;
+        }
+        let similarity = vector
+            .iter()
+            .zip(&query_vector)
+            .map(|(left, right)| left * right)
+            .sum::<f32>();
+        if similarity <= 0.0 {
+            continue;
+        }
+        hits.push(VectorHit {
+            atom_id: Uuid::parse_str(&uuid_text)
+                .map_err(|_| SearchError::InvalidData(format!("invalid uuid `{uuid_text}`")))?,
+            kind: parse_atom_type(&type_text)
+                .ok_or_else(|| SearchError::InvalidData(format!("invalid type `{type_text}`")))?,
+            similarity,
+        });
+    }
+
+    hits.sort_by(|left, right| {
+        right
+            .similarity
+            .total_cmp(&left.similarity)
+            .then_with(|| left.atom_id.cmp(&right.atom_id))
+    });
+    hits.truncate(limit as usize);
+    Ok(hits)
+}
+
+fn encode_vector(vector: &[f32]) -> Vec<u8> {
+    vector
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+fn decode_vector(blob: &[u8]) -> SearchResult<Vec<f32>> {
+    if !blob.len().is_multiple_of(4) {
+        return Err(SearchError::InvalidData(format!(
+            "embedding blob length {} is not a multiple of 4",
+            blob.len()
+        )));
+    }
+    Ok(blob
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
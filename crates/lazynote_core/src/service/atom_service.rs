@@ -8,9 +8,10 @@
 //! - Service APIs never bypass repository validation/persistence contracts.
 //! - Service layer remains storage-agnostic.
 
+use crate::markdown::preview::derive_markdown_preview;
 use crate::model::atom::{Atom, AtomId, AtomType, TaskStatus};
 use crate::repo::atom_repo::{AtomListQuery, AtomRepository, RepoResult};
-use crate::service::note_service::derive_markdown_preview;
+use crate::repo::property_repo::{AtomProperty, PropertyValue};
 
 /// Use-case service wrapper for atom CRUD operations.
 pub struct AtomService<R: AtomRepository> {
@@ -100,4 +101,29 @@ impl<R: AtomRepository> AtomService<R> {
     pub fn soft_delete_atom(&self, id: AtomId) -> RepoResult<()> {
         self.repo.soft_delete_atom(id)
     }
+
+    /// Sets one typed property on an atom, replacing any previous value.
+    pub fn set_property(
+        &self,
+        id: AtomId,
+        key: &str,
+        value: impl Into<PropertyValue>,
+    ) -> RepoResult<()> {
+        self.repo.set_property(id, key, &value.into())
+    }
+
+    /// Loads one property by key.
+    pub fn get_property(&self, id: AtomId, key: &str) -> RepoResult<Option<AtomProperty>> {
+        self.repo.get_property(id, key)
+    }
+
+    /// Lists all properties of one atom ordered by key.
+    pub fn list_properties(&self, id: AtomId) -> RepoResult<Vec<AtomProperty>> {
+        self.repo.list_properties(id)
+    }
+
+    /// Removes one property; returns whether it existed.
+    pub fn remove_property(&self, id: AtomId, key: &str) -> RepoResult<bool> {
+        self.repo.remove_property(id, key)
+    }
 }
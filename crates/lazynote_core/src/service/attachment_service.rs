@@ -0,0 +1,151 @@
+//! Attachment use-case service.
+//!
+//! # Responsibility
+//! - Normalize attachment names and MIME types before persistence.
+//! - Provide add/list/remove entry points over bytes or files on disk.
+//! - Expose blob garbage collection.
+//!
+//! # Invariants
+//! - Stored names are the final path component of the supplied name.
+//! - MIME types are lowercase; unknown extensions fall back to
+//!   `application/octet-stream`.
+
+use crate::model::atom::AtomId;
+use crate::repo::attachment_repo::{
+    Attachment, AttachmentError, AttachmentId, AttachmentRepository, AttachmentResult, BlobGcReport,
+};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// MIME type used when none is supplied and the extension is unknown.
+pub const DEFAULT_ATTACHMENT_MIME_TYPE: &str = "application/octet-stream";
+
+/// Use-case service wrapper for attachment operations.
+pub struct AttachmentService<R: AttachmentRepository> {
+    repo: R,
+}
+
+impl<R: AttachmentRepository> AttachmentService<R> {
+    /// Creates a service using the provided repository implementation.
+    pub fn new(repo: R) -> Self {
+        Self { repo }
+    }
+
+    /// Attaches in-memory content to an atom.
+    ///
+    /// `mime_type = None` guesses from the name's extension.
+    pub fn add_attachment(
+        &self,
+        atom_id: AtomId,
+        original_name: &str,
+        mime_type: Option<&str>,
+        bytes: &[u8],
+    ) -> AttachmentResult<Attachment> {
+        let name = normalize_attachment_name(original_name)?;
+        let mime_type = resolve_mime_type(name.as_str(), mime_type);
+        let mut reader = bytes;
+        self.repo
+            .add_attachment(atom_id, name.as_str(), mime_type.as_str(), &mut reader)
+    }
+
+    /// Attaches a file on disk to an atom, streaming it into the blob store.
+    ///
+    /// The stored name is the file name of `path`.
+    pub fn add_attachment_file(
+        &self,
+        atom_id: AtomId,
+        path: &Path,
+        mime_type: Option<&str>,
+    ) -> AttachmentResult<Attachment> {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let name = normalize_attachment_name(file_name.as_str())?;
+        let mime_type = resolve_mime_type(name.as_str(), mime_type);
+        let mut file = File::open(path)?;
+        self.repo
+            .add_attachment(atom_id, name.as_str(), mime_type.as_str(), &mut file)
+    }
+
+    /// Loads one attachment by ID.
+    pub fn get_attachment(&self, id: AttachmentId) -> AttachmentResult<Option<Attachment>> {
+        self.repo.get_attachment(id)
+    }
+
+    /// Lists attachments of one atom in insertion order.
+    pub fn list_attachments(&self, atom_id: AtomId) -> AttachmentResult<Vec<Attachment>> {
+        self.repo.list_attachments(atom_id)
+    }
+
+    /// Resolves the on-disk blob file for one attachment.
+    pub fn attachment_path(&self, id: AttachmentId) -> AttachmentResult<PathBuf> {
+        self.repo.blob_path(id)
+    }
+
+    /// Reads the full content of one attachment.
+    pub fn read_attachment(&self, id: AttachmentId) -> AttachmentResult<Vec<u8>> {
+        let path = self.repo.blob_path(id)?;
+        Ok(std::fs::read(path)?)
+    }
+
+    /// Detaches one attachment; the blob stays until [`Self::collect_garbage`].
+    pub fn remove_attachment(&self, id: AttachmentId) -> AttachmentResult<bool> {
+        self.repo.remove_attachment(id)
+    }
+
+    /// Deletes blobs no longer referenced by any attachment.
+    pub fn collect_garbage(&self) -> AttachmentResult<BlobGcReport> {
+        self.repo.collect_garbage()
+    }
+}
+
+/// Guesses a MIME type from a file name extension.
+pub fn guess_mime_type(name: &str) -> &'static str {
+    let extension = name
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "json" => "application/json",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "txt" => "text/plain",
+        "md" | "markdown" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "m4a" => "audio/mp4",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        _ => DEFAULT_ATTACHMENT_MIME_TYPE,
+    }
+}
+
+fn normalize_attachment_name(raw: &str) -> AttachmentResult<String> {
+    let name = raw.rsplit(['/', '\\']).next().unwrap_or_default().trim();
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(AttachmentError::InvalidName);
+    }
+    Ok(name.to_string())
+}
+
+fn resolve_mime_type(name: &str, supplied: Option<&str>) -> String {
+    supplied
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_else(|| guess_mime_type(name).to_string())
+}
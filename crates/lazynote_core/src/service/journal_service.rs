@@ -0,0 +1,53 @@
+//! Undo/redo use-case service.
+//!
+//! # Responsibility
+//! - Undo and redo recorded core mutations (note content and tags,
+//!   workspace moves, status and event time changes).
+//! - Expose journal history and undo/redo availability.
+//!
+//! # Invariants
+//! - Each undo/redo replays exactly one entry in one transaction.
+//! - Recording happens in the mutating repositories, never here.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::repo::atom_repo::RepoResult;
+use crate::repo::journal_repo::{JournalEntry, JournalRepository, JournalStatus};
+
+/// Use-case service wrapper for the command journal.
+pub struct JournalService<R: JournalRepository> {
+    repo: R,
+}
+
+impl<R: JournalRepository> JournalService<R> {
+    /// Creates a service using the provided repository implementation.
+    pub fn new(repo: R) -> Self {
+        Self { repo }
+    }
+
+    /// Undoes the newest mutation; returns `None` when there is none.
+    pub fn undo(&self) -> RepoResult<Option<JournalEntry>> {
+        self.repo.undo()
+    }
+
+    /// Redoes the last undone mutation; returns `None` when there is none.
+    pub fn redo(&self) -> RepoResult<Option<JournalEntry>> {
+        self.repo.redo()
+    }
+
+    /// Returns how many steps can be undone and redone.
+    pub fn status(&self) -> RepoResult<JournalStatus> {
+        self.repo.status()
+    }
+
+    /// Lists up to `limit` journal entries, newest first.
+    pub fn history(&self, limit: u32) -> RepoResult<Vec<JournalEntry>> {
+        self.repo.list_entries(limit)
+    }
+
+    /// Forgets every recorded mutation.
+    pub fn clear(&self) -> RepoResult<()> {
+        self.repo.clear()
+    }
+}
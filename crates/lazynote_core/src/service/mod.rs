@@ -8,6 +8,11 @@
 //! - docs/releases/v0.1/prs/PR-0006-core-crud.md
 
 pub mod atom_service;
+pub mod attachment_service;
+pub mod journal_service;
 pub mod note_service;
+pub mod tag_service;
 pub mod task_service;
+pub mod template_service;
+pub mod trash_service;
 pub mod tree_service;
@@ -2,37 +2,46 @@
 //!
 //! # Responsibility
 //! - Provide note-specific create/update/get/list APIs.
-//! - Derive markdown preview projections (`preview_text`, `preview_image`).
 //! - Normalize and atomically replace note tags.
+//! - List, diff and restore note revisions.
+//! - Keep the wiki link index in sync with note content.
+//! - Apply front-matter metadata (tags, title, time fields, properties).
+//! - Project checklist items into linked task atoms.
+//! - Index addressable blocks and resolve `![[note#^block]]` embeds.
+//! - Render notes to HTML/plain text with link-index resolution.
+//! - Create notes from stored templates.
 //!
 //! # Invariants
 //! - `note_update` uses full content replacement semantics.
 //! - Note list is always sorted by `updated_at DESC, uuid ASC`.
 //! - Tag names are normalized to lowercase and deduplicated.
+//! - Restoring a revision appends a new revision, so restores are undoable.
 //!
 //! # See also
 //! - docs/architecture/note-schema.md
 
+use crate::markdown::preview::derive_markdown_preview;
+use crate::markdown::render::{note_url, render_html, render_plain_text, LinkTable};
+use crate::markdown::template::{render_template, TemplateContext};
+use crate::markdown::wiki_links::split_wiki_link_fragment;
 use crate::model::atom::{Atom, AtomId, AtomType};
 use crate::repo::atom_repo::{RepoError, RepoResult};
+use crate::repo::block_repo::NoteBlock;
+use crate::repo::link_repo::{NoteLink, UnresolvedLinks};
 use crate::repo::note_repo::{
     normalize_note_limit, normalize_tag, normalize_tags, NoteListQuery, NoteRecord, NoteRepository,
+    NoteRevision,
 };
+use crate::repo::note_task_repo::NoteTask;
+use crate::repo::property_repo::{AtomProperty, PropertyFilter};
+use crate::repo::template_repo::{NoteTemplate, NoteTemplateId};
+use crate::repo::tree_repo::WorkspaceNodeId;
 use log::{error, info};
-use once_cell::sync::Lazy;
-use regex::Regex;
+use similar::{ChangeTag, TextDiff};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::time::Instant;
 
-static MARKDOWN_IMAGE_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"!\[[^\]]*]\(([^)]+)\)").expect("valid image regex"));
-static MARKDOWN_LINK_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"\[([^\]]+)\]\(([^)]+)\)").expect("valid link regex"));
-static MARKDOWN_SYMBOL_RE: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r#"[\*_`#>~\-\[\]\(\)!]+"#).expect("valid markdown symbol regex"));
-static WHITESPACE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").expect("valid ws regex"));
-
 /// Service error for note use-cases.
 #[derive(Debug)]
 pub enum NoteServiceError {
@@ -40,6 +49,12 @@ pub enum NoteServiceError {
     InvalidTag(String),
     /// Target note does not exist.
     NoteNotFound(AtomId),
+    /// Target revision does not exist for the note.
+    RevisionNotFound { atom_id: AtomId, revision_id: i64 },
+    /// Target note template does not exist.
+    TemplateNotFound(NoteTemplateId),
+    /// Template name is blank or already taken.
+    InvalidTemplate(String),
     /// Persistence-layer failure.
     Repo(RepoError),
     /// Internal consistency mismatch between write and read-back.
@@ -51,6 +66,12 @@ impl Display for NoteServiceError {
         match self {
             Self::InvalidTag(value) => write!(f, "invalid tag: `{value}`"),
             Self::NoteNotFound(atom_id) => write!(f, "note not found: {atom_id}"),
+            Self::RevisionNotFound {
+                atom_id,
+                revision_id,
+            } => write!(f, "revision {revision_id} not found for note {atom_id}"),
+            Self::TemplateNotFound(uuid) => write!(f, "note template not found: {uuid}"),
+            Self::InvalidTemplate(details) => write!(f, "invalid note template: {details}"),
             Self::Repo(err) => write!(f, "{err}"),
             Self::InconsistentState(details) => write!(f, "inconsistent note state: {details}"),
         }
@@ -70,6 +91,7 @@ impl From<RepoError> for NoteServiceError {
     fn from(value: RepoError) -> Self {
         match value {
             RepoError::NotFound(atom_id) => Self::NoteNotFound(atom_id),
+            RepoError::InvalidTemplate(details) => Self::InvalidTemplate(details),
             other => Self::Repo(other),
         }
     }
@@ -78,19 +100,48 @@ impl From<RepoError> for NoteServiceError {
 /// List result envelope used by service callers.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NotesListResult {
-    /// List items sorted by `updated_at DESC, uuid ASC`.
+    /// List items sorted by `is_pinned DESC, updated_at DESC, uuid ASC`.
     pub items: Vec<NoteRecord>,
     /// Effective normalized limit used by the query.
     pub applied_limit: u32,
 }
 
-/// Markdown-derived preview projection for notes.
+/// Unit used to split text before diffing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffGranularity {
+    /// Compare whole lines (newline kept with each line).
+    #[default]
+    Line,
+    /// Compare words and the whitespace between them.
+    Word,
+}
+
+/// Kind of one diff segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    /// Text present in both versions.
+    Equal,
+    /// Text only present in the newer version.
+    Insert,
+    /// Text only present in the older version.
+    Delete,
+}
+
+/// Contiguous run of text sharing one [`DiffOp`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct MarkdownPreview {
-    /// Sanitized summary text.
-    pub preview_text: Option<String>,
-    /// First markdown image path.
-    pub preview_image: Option<String>,
+pub struct DiffSegment {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// Note created by [`NoteService::create_from_template`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateNote {
+    pub note: NoteRecord,
+    /// `note_ref` node filing the note, when the template has a folder.
+    pub node_uuid: Option<WorkspaceNodeId>,
+    /// Byte offset of the template's `{{cursor}}` in note content.
+    pub cursor: Option<usize>,
 }
 
 /// Note service facade over repository implementations.
@@ -217,6 +268,17 @@ impl<R: NoteRepository> NoteService<R> {
         tag: Option<String>,
         limit: Option<u32>,
         offset: u32,
+    ) -> Result<NotesListResult, NoteServiceError> {
+        self.list_notes_filtered(tag, Vec::new(), limit, offset)
+    }
+
+    /// Lists notes matching an optional tag and all property filters.
+    pub fn list_notes_filtered(
+        &self,
+        tag: Option<String>,
+        properties: Vec<PropertyFilter>,
+        limit: Option<u32>,
+        offset: u32,
     ) -> Result<NotesListResult, NoteServiceError> {
         let normalized_tag = match tag {
             Some(value) => match normalize_tag(value.as_str()) {
@@ -230,6 +292,8 @@ impl<R: NoteRepository> NoteService<R> {
             tag: normalized_tag,
             limit: Some(applied_limit),
             offset,
+            properties,
+            ..NoteListQuery::default()
         };
         let items = self.repo.list_notes(&query)?;
         Ok(NotesListResult {
@@ -238,6 +302,29 @@ impl<R: NoteRepository> NoteService<R> {
         })
     }
 
+    /// Lists notes with a full query (tag, tag filter expression, properties,
+    /// archive/pin/star flags); the tag is normalized and the limit clamped as in
+    /// [`Self::list_notes`].
+    pub fn query_notes(&self, query: NoteListQuery) -> Result<NotesListResult, NoteServiceError> {
+        let tag = match query.tag {
+            Some(value) => match normalize_tag(value.as_str()) {
+                Some(normalized) => Some(normalized),
+                None => return Err(NoteServiceError::InvalidTag(value)),
+            },
+            None => None,
+        };
+        let applied_limit = normalize_note_limit(query.limit);
+        let items = self.repo.list_notes(&NoteListQuery {
+            tag,
+            limit: Some(applied_limit),
+            ..query
+        })?;
+        Ok(NotesListResult {
+            items,
+            applied_limit,
+        })
+    }
+
     /// Atomically replaces the full tag set for one note.
     pub fn set_note_tags(
         &mut self,
@@ -245,7 +332,7 @@ impl<R: NoteRepository> NoteService<R> {
         tags: Vec<String>,
     ) -> Result<NoteRecord, NoteServiceError> {
         for tag in &tags {
-            if tag.trim().is_empty() {
+            if normalize_tag(tag).is_none() {
                 return Err(NoteServiceError::InvalidTag(tag.clone()));
             }
         }
@@ -263,86 +350,274 @@ impl<R: NoteRepository> NoteService<R> {
     pub fn list_tags(&self) -> RepoResult<Vec<String>> {
         self.repo.list_tags()
     }
-}
 
-/// Derives note preview fields from markdown content.
-///
-/// Rules:
-/// - `preview_image`: first markdown image path matched by regex.
-/// - `preview_text`: markdown symbols removed, whitespace normalized, first
-///   100 chars retained.
-pub fn derive_markdown_preview(content: &str) -> MarkdownPreview {
-    let preview_image = MARKDOWN_IMAGE_RE
-        .captures(content)
-        .and_then(|caps| caps.get(1).map(|m| m.as_str().trim().to_string()))
-        .filter(|value| !value.is_empty());
-
-    let without_images = MARKDOWN_IMAGE_RE.replace_all(content, " ");
-    let without_links = MARKDOWN_LINK_RE.replace_all(&without_images, "$1");
-    let without_symbols = MARKDOWN_SYMBOL_RE.replace_all(&without_links, " ");
-    let normalized = WHITESPACE_RE.replace_all(&without_symbols, " ");
-    let trimmed = normalized.trim();
-    let preview_text = if trimmed.is_empty() {
-        None
-    } else {
-        Some(trimmed.chars().take(100).collect())
-    };
+    /// Lists revisions of one note, newest first.
+    pub fn list_revisions(&self, atom_id: AtomId) -> Result<Vec<NoteRevision>, NoteServiceError> {
+        Ok(self.repo.list_note_revisions(atom_id)?)
+    }
 
-    MarkdownPreview {
-        preview_text,
-        preview_image,
+    /// Diffs two revisions of the same note (`from` = older side).
+    pub fn diff_revisions(
+        &self,
+        atom_id: AtomId,
+        from_revision_id: i64,
+        to_revision_id: i64,
+        granularity: DiffGranularity,
+    ) -> Result<Vec<DiffSegment>, NoteServiceError> {
+        let from = self.require_revision(atom_id, from_revision_id)?;
+        let to = self.require_revision(atom_id, to_revision_id)?;
+        Ok(diff_text(
+            from.content.as_str(),
+            to.content.as_str(),
+            granularity,
+        ))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::derive_markdown_preview;
+    /// Restores one revision as the current note content.
+    ///
+    /// The restored content is appended as a new revision, never coalesced,
+    /// so the replaced content stays in history.
+    pub fn restore_revision(
+        &self,
+        atom_id: AtomId,
+        revision_id: i64,
+    ) -> Result<NoteRecord, NoteServiceError> {
+        let started_at = Instant::now();
+        let revision = self.require_revision(atom_id, revision_id)?;
+        let preview = derive_markdown_preview(revision.content.as_str());
+        if let Err(err) = self.repo.restore_note_content(
+            atom_id,
+            revision.content.as_str(),
+            preview.preview_text.as_deref(),
+            preview.preview_image.as_deref(),
+        ) {
+            error!(
+                "event=note_revision_restore module=service status=error duration_ms={} error_code=repo_write_failed error={}",
+                started_at.elapsed().as_millis(),
+                err
+            );
+            return Err(err.into());
+        }
 
-    #[test]
-    fn preview_extracts_first_image_path() {
-        let preview = derive_markdown_preview("x ![a](one.png) y ![b](two.png)");
-        assert_eq!(preview.preview_image.as_deref(), Some("one.png"));
+        let note = self
+            .repo
+            .get_note(atom_id)?
+            .ok_or(NoteServiceError::InconsistentState(
+                "restored note not found in read-back",
+            ))?;
+        info!(
+            "event=note_revision_restore module=service status=ok duration_ms={}",
+            started_at.elapsed().as_millis()
+        );
+        Ok(note)
     }
 
-    #[test]
-    fn preview_strips_markdown_symbols_and_limits_length() {
-        let source = "# title\n\n- [link](https://example.com)\n**bold** `code`";
-        let preview = derive_markdown_preview(source);
-        let text = preview.preview_text.expect("preview_text should exist");
-        assert!(!text.contains('#'));
-        assert!(!text.contains('*'));
-        assert!(text.len() <= 100);
+    /// Lists wiki links found in one note, in document order.
+    pub fn outgoing_links(&self, atom_id: AtomId) -> Result<Vec<NoteLink>, NoteServiceError> {
+        self.require_note(atom_id)?;
+        Ok(self.repo.outgoing_links(atom_id)?)
     }
 
-    #[test]
-    fn preview_handles_complex_markdown_and_unicode_text() {
-        let source = r#"
-# title heading
+    /// Lists wiki links from other active notes that resolve to this note.
+    pub fn backlinks(&self, atom_id: AtomId) -> Result<Vec<NoteLink>, NoteServiceError> {
+        self.require_note(atom_id)?;
+        Ok(self.repo.backlinks(atom_id)?)
+    }
 
-> quote line
+    /// Lists distinct link targets in one note that match no active note.
+    pub fn unresolved_links(&self, atom_id: AtomId) -> Result<Vec<String>, NoteServiceError> {
+        self.require_note(atom_id)?;
+        Ok(self
+            .repo
+            .unresolved_links(Some(atom_id))?
+            .into_iter()
+            .flat_map(|group| group.targets)
+            .collect())
+    }
 
-![cover]( https://cdn.example.com/first.png )
-Paragraph with [ref](https://example.com/path?q=1) and **bold** + `code`.
+    /// Lists unresolved link targets of all active notes, grouped per
+    /// source note, most recently updated source first.
+    pub fn all_unresolved_links(&self) -> Result<Vec<UnresolvedLinks>, NoteServiceError> {
+        Ok(self.repo.unresolved_links(None)?)
+    }
 
-![second](two.png)
-"#;
-        let preview = derive_markdown_preview(source);
-        assert_eq!(
-            preview.preview_image.as_deref(),
-            Some("https://cdn.example.com/first.png")
+    /// Renders one note to sanitized HTML.
+    ///
+    /// Wiki links resolve through the note's link index to
+    /// `lazynote://note/<uuid>` hrefs; `![[note#^block]]` embeds render the
+    /// referenced block.
+    pub fn render_note_html(&self, atom_id: AtomId) -> Result<String, NoteServiceError> {
+        let note = self.require_note(atom_id)?;
+        let mut links = LinkTable::new();
+        for link in self.repo.outgoing_links(atom_id)? {
+            let Some(target) = link.target_atom_id else {
+                continue;
+            };
+            links.insert_note(link.target_title.as_str(), note_url(target));
+            let block_id = split_wiki_link_fragment(link.target_title.as_str())
+                .1
+                .and_then(|fragment| fragment.strip_prefix('^'));
+            if let Some(block) = block_id
+                .map(|block_id| self.repo.get_note_block(target, block_id))
+                .transpose()?
+                .flatten()
+            {
+                links.insert_embed(link.target_title.as_str(), block.content);
+            }
+        }
+        Ok(render_html(note.content.as_str(), &links))
+    }
+
+    /// Renders one note to plain text.
+    pub fn render_note_plain_text(&self, atom_id: AtomId) -> Result<String, NoteServiceError> {
+        let note = self.require_note(atom_id)?;
+        Ok(render_plain_text(note.content.as_str()))
+    }
+
+    /// Lists stored properties of one note ordered by key.
+    pub fn note_properties(&self, atom_id: AtomId) -> Result<Vec<AtomProperty>, NoteServiceError> {
+        self.require_note(atom_id)?;
+        Ok(self.repo.list_note_properties(atom_id)?)
+    }
+
+    /// Lists task atoms projected from the note's checklist items.
+    pub fn note_tasks(&self, atom_id: AtomId) -> Result<Vec<NoteTask>, NoteServiceError> {
+        self.require_note(atom_id)?;
+        Ok(self.repo.list_note_tasks(atom_id)?)
+    }
+
+    /// Lists the indexed markdown blocks of one note in document order.
+    pub fn note_blocks(&self, atom_id: AtomId) -> Result<Vec<NoteBlock>, NoteServiceError> {
+        self.require_note(atom_id)?;
+        Ok(self.repo.list_note_blocks(atom_id)?)
+    }
+
+    /// Gets one block of a note by id (`abc123` or `^abc123`).
+    pub fn note_block(
+        &self,
+        atom_id: AtomId,
+        block_id: &str,
+    ) -> Result<Option<NoteBlock>, NoteServiceError> {
+        self.require_note(atom_id)?;
+        Ok(self.repo.get_note_block(atom_id, block_id)?)
+    }
+
+    fn require_note(&self, atom_id: AtomId) -> Result<NoteRecord, NoteServiceError> {
+        self.repo
+            .get_note(atom_id)?
+            .ok_or(NoteServiceError::NoteNotFound(atom_id))
+    }
+
+    fn require_revision(
+        &self,
+        atom_id: AtomId,
+        revision_id: i64,
+    ) -> Result<NoteRevision, NoteServiceError> {
+        if self.repo.get_note(atom_id)?.is_none() {
+            return Err(NoteServiceError::NoteNotFound(atom_id));
+        }
+        self.repo.get_note_revision(atom_id, revision_id)?.ok_or(
+            NoteServiceError::RevisionNotFound {
+                atom_id,
+                revision_id,
+            },
+        )
+    }
+
+    /// Creates one note by expanding `template` with `context`.
+    ///
+    /// The template is resolved by the caller (see
+    /// [`TemplateService::get_template`](crate::service::template_service::TemplateService::get_template)).
+    /// The note gets the template's tags and, when the template has a
+    /// folder, a `note_ref` there named after the note title (falling back
+    /// to `context.title`).
+    pub fn create_from_template(
+        &self,
+        template: &NoteTemplate,
+        context: &TemplateContext,
+    ) -> Result<TemplateNote, NoteServiceError> {
+        let started_at = Instant::now();
+        let template_uuid = template.uuid;
+        let rendered = render_template(template.content.as_str(), context);
+        let preview = derive_markdown_preview(rendered.content.as_str());
+        let display_name = preview
+            .title
+            .clone()
+            .or_else(|| {
+                context
+                    .title
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|title| !title.is_empty())
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(|| "Untitled note".to_string());
+        let mut atom = Atom::new(AtomType::Note, rendered.content);
+        atom.preview_text = preview.preview_text;
+        atom.preview_image = preview.preview_image;
+
+        let node_uuid = match self.repo.create_note_from_template(
+            &atom,
+            &template.tags,
+            template.folder_uuid,
+            display_name.as_str(),
+        ) {
+            Ok(node_uuid) => node_uuid,
+            Err(err) => {
+                error!(
+                    "event=note_create_from_template module=service status=error duration_ms={} error_code=repo_write_failed template_id={} error={}",
+                    started_at.elapsed().as_millis(),
+                    template_uuid,
+                    err
+                );
+                return Err(err.into());
+            }
+        };
+
+        let note = self
+            .repo
+            .get_note(atom.uuid)?
+            .ok_or(NoteServiceError::InconsistentState(
+                "note missing after template create",
+            ))?;
+        info!(
+            "event=note_create_from_template module=service status=ok duration_ms={} template_id={}",
+            started_at.elapsed().as_millis(),
+            template_uuid
         );
-        let text = preview.preview_text.expect("preview_text should exist");
-        assert!(text.contains("title heading"));
-        assert!(text.contains("quote line"));
-        assert!(!text.contains("!["));
-        assert!(!text.contains("]("));
-        assert!(text.len() <= 100);
+        Ok(TemplateNote {
+            note,
+            node_uuid,
+            cursor: rendered.cursor,
+        })
     }
+}
+
+/// Computes a diff between two texts.
+///
+/// Adjacent changes of the same kind are merged into one segment, so
+/// concatenating `Equal` + `Delete` segments yields `old` and `Equal` +
+/// `Insert` segments yields `new`.
+pub fn diff_text(old: &str, new: &str, granularity: DiffGranularity) -> Vec<DiffSegment> {
+    let diff = match granularity {
+        DiffGranularity::Line => TextDiff::from_lines(old, new),
+        DiffGranularity::Word => TextDiff::from_words(old, new),
+    };
 
-    #[test]
-    fn preview_returns_none_for_symbol_only_content() {
-        let preview = derive_markdown_preview("### *** ``` ~~ []() ![]()");
-        assert!(preview.preview_text.is_none());
-        assert!(preview.preview_image.is_none());
+    let mut segments: Vec<DiffSegment> = Vec::new();
+    for change in diff.iter_all_changes() {
+        let op = match change.tag() {
+            ChangeTag::Equal => DiffOp::Equal,
+            ChangeTag::Insert => DiffOp::Insert,
+            ChangeTag::Delete => DiffOp::Delete,
+        };
+        match segments.last_mut() {
+            Some(last) if last.op == op => last.text.push_str(change.value()),
+            _ => segments.push(DiffSegment {
+                op,
+                text: change.value().to_string(),
+            }),
+        }
     }
+    segments
 }
@@ -0,0 +1,160 @@
+//! Tag management use-case service.
+//!
+//! # Responsibility
+//! - Rename, merge and delete tags across all atoms.
+//! - Validate and store tag display metadata.
+//! - List tags with usage counts per atom type.
+//! - Tag atoms of any type (notes, tasks, events).
+//! - Report which atom tags are manual and which come from inline hashtags.
+//!
+//! # Invariants
+//! - Colors are stored as lowercase `#rrggbb`; `#rgb` shorthand is expanded.
+//! - Blank icon/description values clear the field.
+//! - Renames carry the whole `/` subtree and merge into existing tags.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::model::atom::AtomId;
+use crate::repo::atom_repo::{RepoError, RepoResult};
+use crate::repo::note_repo::{normalize_tag, normalize_tags};
+use crate::repo::tag_repo::{AtomTag, TagMetadata, TagRepository, TagSummary, TagTreeNode};
+
+/// Use-case service wrapper for tag management.
+pub struct TagService<R: TagRepository> {
+    repo: R,
+}
+
+impl<R: TagRepository> TagService<R> {
+    /// Creates a service using the provided repository implementation.
+    pub fn new(repo: R) -> Self {
+        Self { repo }
+    }
+
+    /// Lists all tags with metadata and per-type usage counts.
+    pub fn list_tags(&self) -> RepoResult<Vec<TagSummary>> {
+        self.repo.list_tag_summaries()
+    }
+
+    /// Loads one tag by name.
+    pub fn get_tag(&self, name: &str) -> RepoResult<Option<TagSummary>> {
+        self.repo.get_tag_summary(name)
+    }
+
+    /// Returns the `/`-separated tag hierarchy with rolled-up counts.
+    pub fn tag_tree(&self) -> RepoResult<Vec<TagTreeNode>> {
+        self.repo.tag_tree()
+    }
+
+    /// Renames `from` (with its subtree) to `to`; an existing `to` absorbs
+    /// the renamed tag's atoms. Returns how many tags were renamed.
+    pub fn rename_tag(&self, from: &str, to: &str) -> RepoResult<u32> {
+        self.repo.rename_tag(from, to)
+    }
+
+    /// Merges every tag in `sources` into `target` atomically.
+    ///
+    /// Returns how many tags were folded in.
+    pub fn merge_tags(&self, sources: &[String], target: &str) -> RepoResult<u32> {
+        self.repo.merge_tags(sources, target)
+    }
+
+    /// Removes `name` from every atom; descendant tags are kept.
+    ///
+    /// Returns how many atoms lost the tag.
+    pub fn delete_tag(&self, name: &str) -> RepoResult<u32> {
+        self.repo.delete_tag(name)
+    }
+
+    /// Replaces the display metadata of `name`, creating the tag if needed.
+    ///
+    /// Tags with metadata stay listed when no atom uses them. Returns `None`
+    /// when clearing the metadata of an unused tag removed it.
+    ///
+    /// # Errors
+    /// - [`RepoError::InvalidTag`] for blank names or colors that are not
+    ///   `#rgb` / `#rrggbb` hex.
+    pub fn set_tag_metadata(
+        &self,
+        name: &str,
+        metadata: TagMetadata,
+    ) -> RepoResult<Option<TagSummary>> {
+        let metadata = TagMetadata {
+            color: metadata
+                .color
+                .as_deref()
+                .map(normalize_tag_color)
+                .transpose()?
+                .flatten(),
+            icon: non_blank(metadata.icon),
+            description: non_blank(metadata.description),
+        };
+        self.repo.set_tag_metadata(name, &metadata)?;
+        self.repo.get_tag_summary(name)
+    }
+
+    /// Lists the tags of one atom of any type.
+    pub fn atom_tags(&self, atom_id: AtomId) -> RepoResult<Vec<String>> {
+        self.repo.atom_tags(atom_id)
+    }
+
+    /// Lists the tags of one atom with their source (manual or inline).
+    pub fn atom_tag_details(&self, atom_id: AtomId) -> RepoResult<Vec<AtomTag>> {
+        self.repo.atom_tag_details(atom_id)
+    }
+
+    /// Atomically replaces the manual tag set of one atom of any type.
+    ///
+    /// Inline `#hashtag` tags stay. Returns the normalized tags now on the
+    /// atom.
+    ///
+    /// # Errors
+    /// - [`RepoError::InvalidTag`] when any tag is blank.
+    /// - [`RepoError::NotFound`] for missing or deleted atoms.
+    pub fn set_atom_tags(&self, atom_id: AtomId, tags: Vec<String>) -> RepoResult<Vec<String>> {
+        if let Some(blank) = tags.iter().find(|tag| normalize_tag(tag).is_none()) {
+            return Err(RepoError::InvalidTag(format!("blank tag `{blank}`")));
+        }
+        self.repo.set_atom_tags(atom_id, &normalize_tags(&tags))?;
+        self.repo.atom_tags(atom_id)
+    }
+
+    /// Adds one tag to an atom of any type; returns the resulting tags.
+    pub fn add_tag(&self, atom_id: AtomId, tag: &str) -> RepoResult<Vec<String>> {
+        self.repo.add_atom_tag(atom_id, tag)?;
+        self.repo.atom_tags(atom_id)
+    }
+
+    /// Removes one tag from an atom of any type; returns the resulting tags.
+    ///
+    /// Descendant tags (`tag/...`) stay on the atom, and so does a tag still
+    /// written inline in the content.
+    pub fn remove_tag(&self, atom_id: AtomId, tag: &str) -> RepoResult<Vec<String>> {
+        self.repo.remove_atom_tag(atom_id, tag)?;
+        self.repo.atom_tags(atom_id)
+    }
+}
+
+/// Normalizes a hex color to lowercase `#rrggbb`; blank input clears it.
+pub fn normalize_tag_color(value: &str) -> RepoResult<Option<String>> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    let hex = trimmed.strip_prefix('#').unwrap_or(trimmed);
+    if !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return Err(RepoError::InvalidTag(format!("invalid color `{value}`")));
+    }
+    let expanded = match hex.len() {
+        3 => hex.chars().flat_map(|ch| [ch, ch]).collect::<String>(),
+        6 => hex.to_string(),
+        _ => return Err(RepoError::InvalidTag(format!("invalid color `{value}`"))),
+    };
+    Ok(Some(format!("#{}", expanded.to_ascii_lowercase())))
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
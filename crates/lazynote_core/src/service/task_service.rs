@@ -3,13 +3,16 @@
 //! # Responsibility
 //! - Provide section-based list queries (Inbox/Today/Upcoming) with tag enrichment.
 //! - Provide universal status update for any atom type.
+//! - Set archive/pin/star flags on any atom type.
 //!
 //! # Invariants
 //! - Section classification is driven by `start_at`/`end_at` nullability, not `type`.
 //! - `update_status(None)` clears task_status (demote to statusless).
+//! - Section restrictions (tags, archive/pin/star flags) come from the
+//!   repository, e.g. [`SqliteAtomRepository::with_section_filter`](crate::repo::atom_repo::SqliteAtomRepository::with_section_filter).
 
 use crate::model::atom::{Atom, AtomId, TaskStatus};
-use crate::repo::atom_repo::{AtomRepository, RepoError, SectionAtomRow};
+use crate::repo::atom_repo::{AtomFlag, AtomRepository, RepoError, SectionAtomRow};
 use crate::repo::note_repo::load_tags_for_atoms;
 use rusqlite::Connection;
 use std::error::Error;
@@ -133,6 +136,17 @@ impl<'conn, R: AtomRepository> TaskService<'conn, R> {
         self.enrich_with_tags(rows)
     }
 
+    /// Sets one archive/pin/star flag on an atom of any type.
+    pub fn set_flag(
+        &self,
+        id: AtomId,
+        flag: AtomFlag,
+        value: bool,
+    ) -> Result<(), TaskServiceError> {
+        self.repo.set_atom_flag(id, flag, value)?;
+        Ok(())
+    }
+
     /// Updates only `start_at` and `end_at` for a calendar event.
     pub fn update_event_times(
         &self,
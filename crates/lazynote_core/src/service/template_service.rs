@@ -0,0 +1,110 @@
+//! Note template management use-case service.
+//!
+//! # Responsibility
+//! - List, create, update and delete note templates.
+//! - Validate and normalize template drafts before persistence.
+//!
+//! # Invariants
+//! - Template names are trimmed, non-blank and unique case-insensitively.
+//! - Template tags are normalized to lowercase and deduplicated.
+//! - Creating notes from templates stays on
+//!   [`NoteService::create_from_template`](crate::service::note_service::NoteService::create_from_template).
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::repo::note_repo::{normalize_tag, normalize_tags};
+use crate::repo::template_repo::{
+    NoteTemplate, NoteTemplateDraft, NoteTemplateId, TemplateRepository,
+};
+use crate::service::note_service::NoteServiceError;
+
+/// Use-case service wrapper for note templates.
+pub struct TemplateService<R: TemplateRepository> {
+    repo: R,
+}
+
+impl<R: TemplateRepository> TemplateService<R> {
+    /// Creates a service using the provided repository implementation.
+    pub fn new(repo: R) -> Self {
+        Self { repo }
+    }
+
+    /// Lists note templates ordered by name.
+    pub fn list_templates(&self) -> Result<Vec<NoteTemplate>, NoteServiceError> {
+        Ok(self.repo.list_templates()?)
+    }
+
+    /// Gets one note template by id.
+    pub fn get_template(
+        &self,
+        uuid: NoteTemplateId,
+    ) -> Result<Option<NoteTemplate>, NoteServiceError> {
+        Ok(self.repo.get_template(uuid)?)
+    }
+
+    /// Stores a new note template.
+    ///
+    /// The name is trimmed and tags are normalized.
+    pub fn create_template(
+        &self,
+        draft: NoteTemplateDraft,
+    ) -> Result<NoteTemplate, NoteServiceError> {
+        let draft = normalize_template_draft(draft)?;
+        let uuid = self.repo.create_template(&draft)?;
+        self.repo
+            .get_template(uuid)?
+            .ok_or(NoteServiceError::InconsistentState(
+                "template missing after create",
+            ))
+    }
+
+    /// Replaces name, content, tags and folder of one note template.
+    pub fn update_template(
+        &self,
+        uuid: NoteTemplateId,
+        draft: NoteTemplateDraft,
+    ) -> Result<NoteTemplate, NoteServiceError> {
+        let draft = normalize_template_draft(draft)?;
+        if !self.repo.update_template(uuid, &draft)? {
+            return Err(NoteServiceError::TemplateNotFound(uuid));
+        }
+        self.repo
+            .get_template(uuid)?
+            .ok_or(NoteServiceError::InconsistentState(
+                "template missing after update",
+            ))
+    }
+
+    /// Deletes one note template; notes created from it are kept.
+    pub fn delete_template(&self, uuid: NoteTemplateId) -> Result<(), NoteServiceError> {
+        if !self.repo.delete_template(uuid)? {
+            return Err(NoteServiceError::TemplateNotFound(uuid));
+        }
+        Ok(())
+    }
+}
+
+/// Trims the name and normalizes tags; name clashes are left to the
+/// repository's unique index.
+fn normalize_template_draft(
+    draft: NoteTemplateDraft,
+) -> Result<NoteTemplateDraft, NoteServiceError> {
+    let name = draft.name.trim().to_string();
+    if name.is_empty() {
+        return Err(NoteServiceError::InvalidTemplate(
+            "name must not be blank".to_string(),
+        ));
+    }
+    for tag in &draft.tags {
+        if normalize_tag(tag).is_none() {
+            return Err(NoteServiceError::InvalidTag(tag.clone()));
+        }
+    }
+
+    Ok(NoteTemplateDraft {
+        name,
+        tags: normalize_tags(&draft.tags),
+        ..draft
+    })
+}
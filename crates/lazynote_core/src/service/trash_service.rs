@@ -0,0 +1,62 @@
+//! Trash use-case service.
+//!
+//! # Responsibility
+//! - List, restore and permanently purge soft-deleted atoms.
+//! - Turn a retention window in days into a purge cutoff.
+//!
+//! # Invariants
+//! - Purges only touch atoms that are already in the trash.
+//!
+//! # See also
+//! - docs/architecture/note-schema.md
+
+use crate::model::atom::AtomId;
+use crate::repo::atom_repo::RepoResult;
+use crate::repo::trash_repo::{TrashQuery, TrashRepository, TrashedAtom};
+use crate::sync::provider_types::now_epoch_ms;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Use-case service wrapper for the trash.
+pub struct TrashService<R: TrashRepository> {
+    repo: R,
+}
+
+impl<R: TrashRepository> TrashService<R> {
+    /// Creates a service using the provided repository implementation.
+    pub fn new(repo: R) -> Self {
+        Self { repo }
+    }
+
+    /// Lists trashed atoms, most recently deleted first.
+    pub fn list_trash(&self, query: &TrashQuery) -> RepoResult<Vec<TrashedAtom>> {
+        self.repo.list_trash(query)
+    }
+
+    /// Restores one trashed atom (and its workspace note_ref for notes).
+    pub fn restore(&self, id: AtomId) -> RepoResult<()> {
+        self.repo.restore_atom(id)
+    }
+
+    /// Permanently deletes one trashed atom.
+    pub fn purge(&self, id: AtomId) -> RepoResult<()> {
+        self.repo.purge_atom(id)
+    }
+
+    /// Permanently deletes atoms trashed more than `days` days ago.
+    ///
+    /// `days = 0` empties the whole trash. Returns how many atoms were
+    /// purged.
+    pub fn purge_older_than(&self, days: u32) -> RepoResult<u32> {
+        self.purge_older_than_at(days, now_epoch_ms())
+    }
+
+    /// Same as [`Self::purge_older_than`] with an explicit clock.
+    pub fn purge_older_than_at(&self, days: u32, now_epoch_ms: i64) -> RepoResult<u32> {
+        let cutoff = now_epoch_ms.saturating_sub(i64::from(days) * DAY_MS);
+        // Why: `deleted_at < cutoff` would keep rows deleted in the current
+        // millisecond when emptying the trash.
+        let cutoff = if days == 0 { i64::MAX } else { cutoff };
+        self.repo.purge_deleted_before(cutoff)
+    }
+}
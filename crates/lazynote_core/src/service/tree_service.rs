@@ -2,29 +2,31 @@
 //!
 //! # Responsibility
 //! - Validate tree hierarchy invariants above repository layer.
-//! - Provide folder/note_ref create, rename, move, and list operations.
+//! - Provide folder/note_ref/smart_folder create, rename, move, and list
+//!   operations.
+//! - Evaluate smart folder children live from their saved search.
+//! - Create missing notes from unresolved wiki links.
+//! - Undo folder deletions recorded by `delete_folder`.
 //!
 //! # Invariants
-//! - Parent node must exist and be a folder when provided.
+//! - Parent node must exist and be a folder when provided; smart folders
+//!   accept no stored children.
 //! - Move operations must not create parent-child cycles.
 //! - `note_ref` must target an active `AtomType::Note`.
 
 use crate::model::atom::{AtomId, AtomType};
+use crate::repo::note_repo::normalize_tag;
+pub use crate::repo::tree_repo::FolderDeleteMode;
 use crate::repo::tree_repo::{
-    TreeRepoError, TreeRepository, WorkspaceNode, WorkspaceNodeId, WorkspaceNodeKind,
+    DeletedFolder, SavedSearch, SavedSearchId, SavedSearchQuery, TreeRepoError, TreeRepository,
+    WorkspaceNode, WorkspaceNodeId, WorkspaceNodeKind,
 };
 use std::collections::HashSet;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
-/// Folder delete mode for workspace tree.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum FolderDeleteMode {
-    /// Delete folder node only and move direct children to root.
-    Dissolve,
-    /// Delete folder subtree and soft-delete note atoms with no remaining refs.
-    DeleteAll,
-}
+/// Upper bound for live children listed under one smart folder.
+pub const SMART_FOLDER_CHILDREN_MAX: u32 = 200;
 
 /// Errors from workspace tree service operations.
 #[derive(Debug)]
@@ -39,10 +41,19 @@ pub enum TreeServiceError {
     ParentMustBeFolder(WorkspaceNodeId),
     /// Target node exists but is not folder kind.
     NodeMustBeFolder(WorkspaceNodeId),
+    /// Target node exists but is not smart_folder kind.
+    NodeMustBeSmartFolder(WorkspaceNodeId),
+    /// Saved search tag filter is blank after normalization.
+    InvalidSavedSearchTag(String),
     /// Target note atom does not exist or is soft-deleted.
     AtomNotFound(AtomId),
     /// Target atom exists but is not note type.
     AtomNotNote(AtomId),
+    /// Source note has no unresolved link with the requested target.
+    UnresolvedLinkNotFound {
+        source_atom_id: AtomId,
+        target_title: String,
+    },
     /// Move operation would create a cycle.
     CycleDetected {
         node_uuid: WorkspaceNodeId,
@@ -62,8 +73,19 @@ impl Display for TreeServiceError {
                 write!(f, "workspace parent must be folder: {id}")
             }
             Self::NodeMustBeFolder(id) => write!(f, "workspace node must be folder: {id}"),
+            Self::NodeMustBeSmartFolder(id) => {
+                write!(f, "workspace node must be smart folder: {id}")
+            }
+            Self::InvalidSavedSearchTag(value) => write!(f, "invalid saved search tag: `{value}`"),
             Self::AtomNotFound(id) => write!(f, "atom not found: {id}"),
             Self::AtomNotNote(id) => write!(f, "atom is not a note: {id}"),
+            Self::UnresolvedLinkNotFound {
+                source_atom_id,
+                target_title,
+            } => write!(
+                f,
+                "note {source_atom_id} has no unresolved link to `{target_title}`"
+            ),
             Self::CycleDetected {
                 node_uuid,
                 parent_uuid,
@@ -90,6 +112,13 @@ impl From<TreeRepoError> for TreeServiceError {
         match value {
             TreeRepoError::NodeNotFound(node_uuid) => Self::NodeNotFound(node_uuid),
             TreeRepoError::NodeNotFolder(node_uuid) => Self::NodeMustBeFolder(node_uuid),
+            TreeRepoError::UnresolvedLinkNotFound {
+                source_atom_id,
+                target_title,
+            } => Self::UnresolvedLinkNotFound {
+                source_atom_id,
+                target_title,
+            },
             other => Self::Repo(other),
         }
     }
@@ -143,13 +172,96 @@ impl<R: TreeRepository> TreeService<R> {
             .map_err(Into::into)
     }
 
+    /// Creates the missing note behind an unresolved `[[target_title]]` link
+    /// in `source_atom_id` and returns its new `note_ref` node.
+    ///
+    /// The node is placed right after the source note's node and the link
+    /// is rewritten to the new note's uuid in the same transaction.
+    pub fn create_note_from_link(
+        &self,
+        source_atom_id: AtomId,
+        target_title: impl Into<String>,
+    ) -> Result<WorkspaceNode, TreeServiceError> {
+        self.ensure_atom_is_note(source_atom_id)?;
+        let normalized = normalize_display_name(target_title.into())?;
+        self.repo
+            .create_note_from_link(source_atom_id, normalized.as_str())
+            .map_err(Into::into)
+    }
+
+    /// Creates one smart folder backed by a new saved search.
+    ///
+    /// Text is trimmed; tag is lowercased and stripped of a leading `#`.
+    pub fn create_smart_folder(
+        &self,
+        parent_uuid: Option<WorkspaceNodeId>,
+        display_name: impl Into<String>,
+        query: SavedSearchQuery,
+    ) -> Result<WorkspaceNode, TreeServiceError> {
+        let normalized = normalize_display_name(display_name.into())?;
+        let query = normalize_saved_search_query(query)?;
+        if let Some(parent_uuid) = parent_uuid {
+            self.ensure_parent_is_folder(parent_uuid)?;
+        }
+        self.repo
+            .create_smart_folder(parent_uuid, normalized.as_str(), &query)
+            .map_err(Into::into)
+    }
+
+    /// Loads the saved search behind one smart folder.
+    pub fn get_saved_search(
+        &self,
+        node_uuid: WorkspaceNodeId,
+    ) -> Result<SavedSearch, TreeServiceError> {
+        let saved_search_uuid = self.smart_folder_search_id(node_uuid)?;
+        self.repo
+            .get_saved_search(saved_search_uuid)?
+            .ok_or(TreeServiceError::Repo(TreeRepoError::SavedSearchNotFound(
+                saved_search_uuid,
+            )))
+    }
+
+    /// Replaces filters of the saved search behind one smart folder.
+    pub fn update_saved_search(
+        &self,
+        node_uuid: WorkspaceNodeId,
+        query: SavedSearchQuery,
+    ) -> Result<(), TreeServiceError> {
+        let query = normalize_saved_search_query(query)?;
+        let saved_search_uuid = self.smart_folder_search_id(node_uuid)?;
+        self.repo
+            .update_saved_search(saved_search_uuid, &query)
+            .map_err(Into::into)
+    }
+
     /// Lists child nodes under optional parent.
+    ///
+    /// For smart folders, children are virtual [`WorkspaceNodeKind::AtomRef`]
+    /// nodes evaluated from the saved search on every call.
     pub fn list_children(
         &self,
         parent_uuid: Option<WorkspaceNodeId>,
     ) -> Result<Vec<WorkspaceNode>, TreeServiceError> {
         if let Some(parent_uuid) = parent_uuid {
-            self.ensure_parent_is_folder(parent_uuid)?;
+            let parent = self
+                .repo
+                .get_node(parent_uuid, false)?
+                .ok_or(TreeServiceError::ParentNotFound(parent_uuid))?;
+            match parent.kind {
+                WorkspaceNodeKind::Folder => {}
+                WorkspaceNodeKind::SmartFolder => {
+                    let saved_search = self.get_saved_search(parent_uuid)?;
+                    return self
+                        .repo
+                        .evaluate_saved_search(
+                            parent_uuid,
+                            &saved_search.query,
+                            SMART_FOLDER_CHILDREN_MAX,
+                        )
+                        .map_err(Into::into);
+                }
+                _ => return Err(TreeServiceError::ParentMustBeFolder(parent_uuid)),
+            }
         }
         self.repo
             .list_children(parent_uuid, false)
@@ -215,6 +327,12 @@ impl<R: TreeRepository> TreeService<R> {
             .repo
             .get_node(folder_uuid, false)?
             .ok_or(TreeServiceError::NodeNotFound(folder_uuid))?;
+        if folder.kind == WorkspaceNodeKind::SmartFolder {
+            // Why: smart folders have no stored children, so both modes only
+            // remove the node itself.
+            self.repo.delete_smart_folder(folder_uuid)?;
+            return Ok(());
+        }
         if folder.kind != WorkspaceNodeKind::Folder {
             return Err(TreeServiceError::NodeMustBeFolder(folder_uuid));
         }
@@ -226,6 +344,30 @@ impl<R: TreeRepository> TreeService<R> {
         Ok(())
     }
 
+    /// Lists folder deletions that can still be undone, newest first.
+    pub fn list_deleted_folders(&self) -> Result<Vec<DeletedFolder>, TreeServiceError> {
+        self.repo.list_deleted_folders().map_err(Into::into)
+    }
+
+    /// Undoes the latest `delete_folder` of `folder_uuid`.
+    ///
+    /// The folder returns to its original parent and sort position (root
+    /// when that parent is gone). `Dissolve` moves the still-active former
+    /// children back in; `DeleteAll` revives the subtree and restores the
+    /// notes it soft-deleted.
+    ///
+    /// # Errors
+    /// - [`TreeServiceError::NodeNotFound`] when the folder has no
+    ///   undoable deletion.
+    pub fn restore_deleted_folder(
+        &self,
+        folder_uuid: WorkspaceNodeId,
+    ) -> Result<WorkspaceNode, TreeServiceError> {
+        self.repo
+            .restore_deleted_folder(folder_uuid)
+            .map_err(Into::into)
+    }
+
     fn ensure_parent_is_folder(
         &self,
         parent_uuid: WorkspaceNodeId,
@@ -240,6 +382,20 @@ impl<R: TreeRepository> TreeService<R> {
         Ok(())
     }
 
+    fn smart_folder_search_id(
+        &self,
+        node_uuid: WorkspaceNodeId,
+    ) -> Result<SavedSearchId, TreeServiceError> {
+        let node = self
+            .repo
+            .get_node(node_uuid, false)?
+            .ok_or(TreeServiceError::NodeNotFound(node_uuid))?;
+        match (node.kind, node.saved_search_uuid) {
+            (WorkspaceNodeKind::SmartFolder, Some(saved_search_uuid)) => Ok(saved_search_uuid),
+            _ => Err(TreeServiceError::NodeMustBeSmartFolder(node_uuid)),
+        }
+    }
+
     fn ensure_atom_is_note(&self, atom_uuid: AtomId) -> Result<(), TreeServiceError> {
         match self.repo.atom_kind(atom_uuid)? {
             None => Err(TreeServiceError::AtomNotFound(atom_uuid)),
@@ -273,6 +429,23 @@ impl<R: TreeRepository> TreeService<R> {
     }
 }
 
+fn normalize_saved_search_query(
+    query: SavedSearchQuery,
+) -> Result<SavedSearchQuery, TreeServiceError> {
+    let tag = match query.tag {
+        None => None,
+        Some(raw) => match normalize_tag(raw.trim().trim_start_matches('#')) {
+            Some(normalized) => Some(normalized),
+            None => return Err(TreeServiceError::InvalidSavedSearchTag(raw)),
+        },
+    };
+    Ok(SavedSearchQuery {
+        text: query.text.trim().to_string(),
+        tag,
+        ..query
+    })
+}
+
 fn normalize_display_name(value: String) -> Result<String, TreeServiceError> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
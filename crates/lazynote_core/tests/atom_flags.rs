@@ -0,0 +1,206 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    search_all, Atom, AtomFlag, AtomId, AtomListQuery, AtomRepository, AtomType, NoteListQuery,
+    NoteService, RepoError, SearchItem, SearchQuery, SearchRanking, SectionFilter,
+    SqliteAtomRepository, SqliteNoteRepository, SqliteTreeRepository, TaskService, TreeService,
+};
+use rusqlite::Connection;
+
+fn create_atom(conn: &Connection, kind: AtomType, content: &str) -> AtomId {
+    let atom = Atom::new(kind, content);
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .create_atom(&atom)
+        .unwrap();
+    atom.uuid
+}
+
+fn set_flag(conn: &Connection, id: AtomId, flag: AtomFlag, value: bool) {
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .set_atom_flag(id, flag, value)
+        .unwrap();
+}
+
+fn search_ids(conn: &Connection, query: &SearchQuery) -> Vec<AtomId> {
+    search_all(conn, query)
+        .unwrap()
+        .into_iter()
+        .filter_map(|item| match item {
+            SearchItem::Atom(hit) => Some(hit.atom_id),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn flags_round_trip_and_default_to_false() {
+    let conn = open_db_in_memory().unwrap();
+    let id = create_atom(&conn, AtomType::Note, "plain");
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let atom = repo.get_atom(id, false).unwrap().unwrap();
+    assert!(!atom.is_archived && !atom.is_pinned && !atom.is_starred);
+
+    repo.set_atom_flag(id, AtomFlag::Pinned, true).unwrap();
+    repo.set_atom_flag(id, AtomFlag::Starred, true).unwrap();
+    let atom = repo.get_atom(id, false).unwrap().unwrap();
+    assert!(atom.is_pinned && atom.is_starred && !atom.is_archived);
+
+    let json = serde_json::json!({
+        "uuid": id,
+        "type": "note",
+        "content": "legacy",
+        "tags": [],
+        "is_deleted": false
+    });
+    let legacy: Atom = serde_json::from_value(json).unwrap();
+    assert!(!legacy.is_archived && !legacy.is_pinned && !legacy.is_starred);
+}
+
+#[test]
+fn archived_atoms_are_hidden_unless_included() {
+    let mut conn = open_db_in_memory().unwrap();
+    let kept = create_atom(&conn, AtomType::Task, "kept task");
+    let archived = create_atom(&conn, AtomType::Task, "archived task");
+    set_flag(&conn, archived, AtomFlag::Archived, true);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let listed: Vec<AtomId> = repo
+        .list_atoms(&AtomListQuery::default())
+        .unwrap()
+        .into_iter()
+        .map(|atom| atom.uuid)
+        .collect();
+    assert_eq!(listed, vec![kept]);
+    let all = repo
+        .list_atoms(&AtomListQuery {
+            include_archived: true,
+            ..AtomListQuery::default()
+        })
+        .unwrap();
+    assert_eq!(all.len(), 2);
+
+    let inbox = TaskService::new(&repo, &conn).fetch_inbox(50, 0).unwrap();
+    assert_eq!(inbox.len(), 1);
+    assert_eq!(inbox[0].atom.uuid, kept);
+    let archived_repo = SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .with_section_filter(SectionFilter {
+            include_archived: true,
+            ..SectionFilter::default()
+        });
+    let inbox_all = TaskService::new(&archived_repo, &conn)
+        .fetch_inbox(50, 0)
+        .unwrap();
+    assert_eq!(inbox_all.len(), 2);
+
+    let note = {
+        let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        service.create_note("archived note").unwrap().atom_id
+    };
+    set_flag(&conn, note, AtomFlag::Archived, true);
+    let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+    assert!(service
+        .query_notes(NoteListQuery::default())
+        .unwrap()
+        .items
+        .is_empty());
+    let notes = service
+        .query_notes(NoteListQuery {
+            include_archived: true,
+            ..NoteListQuery::default()
+        })
+        .unwrap();
+    assert_eq!(notes.items.len(), 1);
+    assert!(notes.items[0].is_archived);
+}
+
+#[test]
+fn pinned_atoms_sort_first_and_starred_filter_applies() {
+    let conn = open_db_in_memory().unwrap();
+    let pinned = create_atom(&conn, AtomType::Task, "older pinned");
+    let starred = create_atom(&conn, AtomType::Task, "starred");
+    let newest = create_atom(&conn, AtomType::Task, "newest");
+    set_flag(&conn, pinned, AtomFlag::Pinned, true);
+    set_flag(&conn, starred, AtomFlag::Starred, true);
+    conn.execute(
+        "UPDATE atoms SET updated_at = CASE uuid
+            WHEN ?1 THEN 1000 WHEN ?2 THEN 2000 ELSE 3000 END",
+        rusqlite::params![pinned.to_string(), starred.to_string()],
+    )
+    .unwrap();
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let order: Vec<AtomId> = repo
+        .list_atoms(&AtomListQuery::default())
+        .unwrap()
+        .into_iter()
+        .map(|atom| atom.uuid)
+        .collect();
+    assert_eq!(order, vec![pinned, newest, starred]);
+
+    let inbox = TaskService::new(&repo, &conn).fetch_inbox(50, 0).unwrap();
+    assert_eq!(inbox[0].atom.uuid, pinned);
+
+    let only_starred = repo
+        .list_atoms(&AtomListQuery {
+            starred: Some(true),
+            ..AtomListQuery::default()
+        })
+        .unwrap();
+    assert_eq!(only_starred.len(), 1);
+    assert_eq!(only_starred[0].uuid, starred);
+}
+
+#[test]
+fn search_skips_archived_atoms_and_boosts_pinned_ones() {
+    let conn = open_db_in_memory().unwrap();
+    let plain = create_atom(&conn, AtomType::Note, "quarterly report draft");
+    let pinned = create_atom(&conn, AtomType::Note, "quarterly report");
+    let archived = create_atom(&conn, AtomType::Note, "quarterly report archive");
+    set_flag(&conn, archived, AtomFlag::Archived, true);
+
+    let ranking = SearchRanking {
+        recency_weight: 0.0,
+        usage_weight: 0.0,
+        ..SearchRanking::default()
+    };
+    let mut query = SearchQuery::new("quarterly");
+    query.ranking = ranking;
+    let before = search_ids(&conn, &query);
+    assert_eq!(before.len(), 2);
+    assert!(!before.contains(&archived));
+
+    set_flag(&conn, plain, AtomFlag::Pinned, true);
+    let after = search_ids(&conn, &query);
+    assert_eq!(after, vec![plain, pinned]);
+
+    query.include_archived = true;
+    assert!(search_ids(&conn, &query).contains(&archived));
+}
+
+#[test]
+fn archiving_is_not_a_delete() {
+    let conn = open_db_in_memory().unwrap();
+    let note = create_atom(&conn, AtomType::Note, "referenced note");
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let node = tree
+        .create_note_ref(None, note, Some("Ref".to_string()))
+        .unwrap();
+
+    set_flag(&conn, note, AtomFlag::Archived, true);
+    let children = tree.list_children(None).unwrap();
+    assert!(children
+        .iter()
+        .any(|child| child.node_uuid == node.node_uuid));
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let atom = repo.get_atom(note, false).unwrap().unwrap();
+    assert!(atom.is_archived && !atom.is_deleted);
+
+    repo.soft_delete_atom(note).unwrap();
+    assert!(matches!(
+        repo.set_atom_flag(note, AtomFlag::Pinned, true),
+        Err(RepoError::NotFound(id)) if id == note
+    ));
+}
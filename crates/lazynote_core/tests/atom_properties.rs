@@ -0,0 +1,269 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    parse_front_matter_date, AtomListQuery, AtomService, NoteListQuery, NoteRepository,
+    PropertyFilter, PropertyValue, RepoError, SqliteAtomRepository, SqliteNoteRepository,
+    PROPERTY_SOURCE_FRONT_MATTER, PROPERTY_SOURCE_USER,
+};
+
+#[test]
+fn set_get_and_remove_typed_properties() {
+    let conn = open_db_in_memory().unwrap();
+    let service = AtomService::new(SqliteAtomRepository::try_new(&conn).unwrap());
+    let task = service.create_task("ship release").unwrap();
+    let project = service.create_note("# Launch").unwrap();
+    let due = parse_front_matter_date("2026-11-01").unwrap();
+
+    service.set_property(task, "Priority", 2.0).unwrap();
+    service.set_property(task, "blocked", false).unwrap();
+    service.set_property(task, "owner", "Robin").unwrap();
+    service
+        .set_property(task, "due", PropertyValue::Date(due))
+        .unwrap();
+    service
+        .set_property(task, "project", PropertyValue::AtomRef(project))
+        .unwrap();
+
+    let priority = service.get_property(task, " PRIORITY ").unwrap().unwrap();
+    assert_eq!(priority.key, "priority");
+    assert_eq!(priority.value, PropertyValue::Number(2.0));
+    assert_eq!(priority.source, PROPERTY_SOURCE_USER);
+    assert_eq!(
+        service
+            .list_properties(task)
+            .unwrap()
+            .into_iter()
+            .map(|property| (property.key, property.value.to_string()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("blocked".to_string(), "false".to_string()),
+            ("due".to_string(), "2026-11-01".to_string()),
+            ("owner".to_string(), "Robin".to_string()),
+            ("priority".to_string(), "2".to_string()),
+            ("project".to_string(), project.to_string()),
+        ]
+    );
+
+    service.set_property(task, "priority", "high").unwrap();
+    assert_eq!(
+        service
+            .get_property(task, "priority")
+            .unwrap()
+            .unwrap()
+            .value,
+        PropertyValue::Text("high".to_string())
+    );
+
+    assert!(service.remove_property(task, "owner").unwrap());
+    assert!(!service.remove_property(task, "owner").unwrap());
+    assert!(service.get_property(task, "owner").unwrap().is_none());
+}
+
+#[test]
+fn set_property_rejects_invalid_input() {
+    let conn = open_db_in_memory().unwrap();
+    let service = AtomService::new(SqliteAtomRepository::try_new(&conn).unwrap());
+    let task = service.create_task("review").unwrap();
+
+    assert!(matches!(
+        service.set_property(task, "   ", "x"),
+        Err(RepoError::InvalidProperty(_))
+    ));
+    assert!(matches!(
+        service.set_property(task, "score", f64::NAN),
+        Err(RepoError::InvalidProperty(_))
+    ));
+    assert!(matches!(
+        service.set_property(task, "parent", PropertyValue::AtomRef(uuid::Uuid::new_v4())),
+        Err(RepoError::InvalidProperty(_))
+    ));
+
+    let missing = uuid::Uuid::new_v4();
+    assert!(matches!(
+        service.set_property(missing, "score", 1.0),
+        Err(RepoError::NotFound(id)) if id == missing
+    ));
+
+    service.soft_delete_atom(task).unwrap();
+    assert!(matches!(
+        service.set_property(task, "score", 1.0),
+        Err(RepoError::NotFound(_))
+    ));
+}
+
+#[test]
+fn list_atoms_filters_on_typed_predicates() {
+    let conn = open_db_in_memory().unwrap();
+    let service = AtomService::new(SqliteAtomRepository::try_new(&conn).unwrap());
+    let low = service.create_task("low").unwrap();
+    let high = service.create_task("high").unwrap();
+    let other = service.create_task("other").unwrap();
+    service.set_property(low, "priority", 1.0).unwrap();
+    service.set_property(high, "priority", 5.0).unwrap();
+    service.set_property(high, "done", true).unwrap();
+    service.set_property(low, "owner", "Robin Hood").unwrap();
+    service.set_property(other, "priority", "urgent").unwrap();
+
+    let list = |properties: Vec<PropertyFilter>| {
+        let mut ids = service
+            .list_atoms(&AtomListQuery {
+                properties,
+                ..AtomListQuery::default()
+            })
+            .unwrap()
+            .into_iter()
+            .map(|atom| atom.uuid)
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids
+    };
+    let sorted = |mut ids: Vec<uuid::Uuid>| {
+        ids.sort();
+        ids
+    };
+
+    assert_eq!(
+        list(vec![PropertyFilter::has("priority")]),
+        sorted(vec![low, high, other])
+    );
+    assert_eq!(
+        list(vec![PropertyFilter::greater_than("priority", 2.0)]),
+        vec![high]
+    );
+    assert_eq!(
+        list(vec![PropertyFilter::less_or_equal("priority", 1.0)]),
+        vec![low]
+    );
+    assert_eq!(
+        list(vec![PropertyFilter::equals("priority", "URGENT")]),
+        vec![other]
+    );
+    assert_eq!(list(vec![PropertyFilter::equals("done", true)]), vec![high]);
+    assert_eq!(
+        list(vec![PropertyFilter::not_equals("priority", 5.0)]),
+        sorted(vec![low, other])
+    );
+    assert_eq!(
+        list(vec![PropertyFilter::contains("owner", "hood")]),
+        vec![low]
+    );
+    assert_eq!(
+        list(vec![
+            PropertyFilter::has("priority"),
+            PropertyFilter::has("done"),
+        ]),
+        vec![high]
+    );
+    assert!(matches!(
+        service.list_atoms(&AtomListQuery {
+            properties: vec![PropertyFilter::greater_than("done", true)],
+            ..AtomListQuery::default()
+        }),
+        Err(RepoError::InvalidProperty(_))
+    ));
+}
+
+#[test]
+fn front_matter_values_are_typed_and_filterable_on_notes() {
+    let mut conn = open_db_in_memory().unwrap();
+    let (first, second) = {
+        let service = AtomService::new(SqliteAtomRepository::try_new(&conn).unwrap());
+        let first = service.create_note("first").unwrap();
+        let second = service.create_note("second").unwrap();
+        service.set_property(second, "rating", 2.0).unwrap();
+        (first, second)
+    };
+    let notes = SqliteNoteRepository::try_new(&mut conn).unwrap();
+
+    let front_matter = lazynote_core::parse_front_matter(
+        "---\nrating: 4\npublished: true\nread_on: 2026-02-03\nauthor: Le Guin\n---\nbody",
+    )
+    .unwrap();
+    notes
+        .apply_front_matter(first, Some(&front_matter))
+        .unwrap();
+    assert_eq!(
+        notes.list_note_properties(second).unwrap()[0].source,
+        PROPERTY_SOURCE_USER
+    );
+
+    let properties = notes.list_note_properties(first).unwrap();
+    assert!(properties
+        .iter()
+        .all(|property| property.source == PROPERTY_SOURCE_FRONT_MATTER));
+    assert_eq!(
+        properties
+            .into_iter()
+            .map(|property| property.value)
+            .collect::<Vec<_>>(),
+        vec![
+            PropertyValue::Text("Le Guin".to_string()),
+            PropertyValue::Bool(true),
+            PropertyValue::Number(4.0),
+            PropertyValue::Date(parse_front_matter_date("2026-02-03").unwrap()),
+        ]
+    );
+
+    let hits = notes
+        .list_notes(&NoteListQuery {
+            properties: vec![
+                PropertyFilter::greater_or_equal("rating", 3.0),
+                PropertyFilter::less_than(
+                    "read_on",
+                    PropertyValue::Date(parse_front_matter_date("2026-03-01").unwrap()),
+                ),
+            ],
+            ..NoteListQuery::default()
+        })
+        .unwrap();
+    assert_eq!(
+        hits.into_iter()
+            .map(|note| note.atom_id)
+            .collect::<Vec<_>>(),
+        vec![first]
+    );
+}
+
+#[test]
+fn front_matter_keeps_text_verbatim_and_yields_to_user_values() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = {
+        let service = AtomService::new(SqliteAtomRepository::try_new(&conn).unwrap());
+        let note = service.create_note("agent").unwrap();
+        service.set_property(note, "status", "done").unwrap();
+        note
+    };
+    let notes = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let front_matter =
+        lazynote_core::parse_front_matter("---\ncode: 007\nprice: 1.50\nstatus: open\n---\nbody")
+            .unwrap();
+    notes.apply_front_matter(note, Some(&front_matter)).unwrap();
+
+    let properties = notes.list_note_properties(note).unwrap();
+    assert_eq!(
+        properties
+            .iter()
+            .map(|property| (
+                property.key.as_str(),
+                property.text.as_str(),
+                property.source.as_str()
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            ("code", "007", PROPERTY_SOURCE_FRONT_MATTER),
+            ("price", "1.50", PROPERTY_SOURCE_FRONT_MATTER),
+            ("status", "done", PROPERTY_SOURCE_USER),
+        ]
+    );
+    assert_eq!(properties[0].value, PropertyValue::Number(7.0));
+
+    let hits = notes
+        .list_notes(&NoteListQuery {
+            properties: vec![
+                PropertyFilter::equals("code", "007"),
+                PropertyFilter::greater_than("price", 1.0),
+            ],
+            ..NoteListQuery::default()
+        })
+        .unwrap();
+    assert_eq!(hits.len(), 1);
+}
@@ -0,0 +1,111 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomId, AtomRepository, AtomType, RepoError, SectionFilter, SqliteAtomRepository,
+    SqliteTagRepository, TagFilter, TagService, TaskService,
+};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+fn create_atom(conn: &Connection, kind: AtomType, content: &str) -> AtomId {
+    let atom = Atom::new(kind, content);
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .create_atom(&atom)
+        .unwrap();
+    atom.uuid
+}
+
+fn tags(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[test]
+fn tasks_and_events_can_be_tagged_like_notes() {
+    let conn = open_db_in_memory().unwrap();
+    let task = create_atom(&conn, AtomType::Task, "write report");
+    let event = create_atom(&conn, AtomType::Event, "standup");
+    let service = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+
+    assert_eq!(
+        service
+            .set_atom_tags(task, tags(&["Work/Reports", "urgent", "work/reports"]))
+            .unwrap(),
+        tags(&["urgent", "work/reports"])
+    );
+    assert_eq!(
+        service.add_tag(event, " Meetings ").unwrap(),
+        tags(&["meetings"])
+    );
+    assert_eq!(
+        service.add_tag(event, "meetings").unwrap(),
+        tags(&["meetings"])
+    );
+
+    let usage = service.get_tag("urgent").unwrap().unwrap().usage;
+    assert_eq!((usage.tasks, usage.events), (1, 0));
+
+    let repo = SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .with_section_filter(SectionFilter {
+            tags: Some(TagFilter::parse("work").unwrap()),
+            ..SectionFilter::default()
+        });
+    let inbox = TaskService::new(&repo, &conn).fetch_inbox(50, 0).unwrap();
+    assert_eq!(inbox.len(), 1);
+    assert_eq!(inbox[0].atom.uuid, task);
+    assert_eq!(inbox[0].tags, tags(&["urgent", "work/reports"]));
+}
+
+#[test]
+fn remove_tag_keeps_descendants_and_prunes_unused_tags() {
+    let conn = open_db_in_memory().unwrap();
+    let task = create_atom(&conn, AtomType::Task, "task");
+    let service = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+    service
+        .set_atom_tags(task, tags(&["work", "work/ops"]))
+        .unwrap();
+
+    assert_eq!(
+        service.remove_tag(task, "Work").unwrap(),
+        tags(&["work/ops"])
+    );
+    assert_eq!(
+        service.remove_tag(task, "absent").unwrap(),
+        tags(&["work/ops"])
+    );
+    assert!(service.get_tag("work").unwrap().is_none());
+
+    assert!(service.set_atom_tags(task, Vec::new()).unwrap().is_empty());
+    assert!(service.list_tags().unwrap().is_empty());
+}
+
+#[test]
+fn tagging_rejects_blank_tags_and_missing_atoms() {
+    let conn = open_db_in_memory().unwrap();
+    let task = create_atom(&conn, AtomType::Task, "task");
+    let deleted = create_atom(&conn, AtomType::Event, "gone");
+    SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .soft_delete_atom(deleted)
+        .unwrap();
+    let service = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+
+    assert!(matches!(
+        service.set_atom_tags(task, tags(&["ok", " / "])),
+        Err(RepoError::InvalidTag(_))
+    ));
+    assert!(matches!(
+        service.add_tag(task, "  "),
+        Err(RepoError::InvalidTag(_))
+    ));
+    assert!(service.atom_tags(task).unwrap().is_empty());
+
+    assert!(matches!(
+        service.add_tag(deleted, "x"),
+        Err(RepoError::NotFound(id)) if id == deleted
+    ));
+    assert!(matches!(
+        service.set_atom_tags(Uuid::new_v4(), tags(&["x"])),
+        Err(RepoError::NotFound(_))
+    ));
+}
@@ -0,0 +1,210 @@
+use lazynote_core::db::open_db;
+use lazynote_core::{
+    AtomService, AttachmentError, AttachmentService, BlobStore, SqliteAtomRepository,
+    SqliteAttachmentRepository, BLOB_DIR_NAME,
+};
+use rusqlite::Connection;
+
+const HELLO_SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+fn create_note(conn: &Connection, content: &str) -> uuid::Uuid {
+    AtomService::new(SqliteAtomRepository::try_new(conn).unwrap())
+        .create_note(content)
+        .unwrap()
+}
+
+fn blob_ref_count(conn: &Connection, sha256: &str) -> Option<i64> {
+    conn.query_row(
+        "SELECT ref_count FROM attachment_blobs WHERE sha256 = ?1;",
+        [sha256],
+        |row| row.get(0),
+    )
+    .ok()
+}
+
+#[test]
+fn add_list_and_read_attachments() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("lazynote.db");
+    let conn = open_db(&db_path).unwrap();
+    let atom_id = create_note(&conn, "# Trip");
+    let store = BlobStore::next_to_db(&db_path);
+    assert_eq!(store.root(), dir.path().join(BLOB_DIR_NAME));
+    let service =
+        AttachmentService::new(SqliteAttachmentRepository::try_new(&conn, store).unwrap());
+
+    let ticket = service
+        .add_attachment(atom_id, "downloads/Ticket.PDF", None, b"hello")
+        .unwrap();
+    assert_eq!(ticket.atom_uuid, atom_id);
+    assert_eq!(ticket.original_name, "Ticket.PDF");
+    assert_eq!(ticket.mime_type, "application/pdf");
+    assert_eq!(ticket.size_bytes, 5);
+    assert_eq!(ticket.blob_sha256, HELLO_SHA256);
+
+    let source = dir.path().join("notes.bin");
+    std::fs::write(&source, b"raw bytes").unwrap();
+    let raw = service
+        .add_attachment_file(atom_id, &source, Some(" Application/X-Custom "))
+        .unwrap();
+    assert_eq!(raw.original_name, "notes.bin");
+    assert_eq!(raw.mime_type, "application/x-custom");
+
+    assert_eq!(
+        service
+            .list_attachments(atom_id)
+            .unwrap()
+            .into_iter()
+            .map(|attachment| attachment.uuid)
+            .collect::<Vec<_>>(),
+        vec![ticket.uuid, raw.uuid]
+    );
+    let path = service.attachment_path(ticket.uuid).unwrap();
+    assert_eq!(
+        path,
+        dir.path()
+            .join(BLOB_DIR_NAME)
+            .join(&HELLO_SHA256[..2])
+            .join(HELLO_SHA256)
+    );
+    assert_eq!(service.read_attachment(ticket.uuid).unwrap(), b"hello");
+    assert_eq!(
+        service
+            .get_attachment(raw.uuid)
+            .unwrap()
+            .unwrap()
+            .size_bytes,
+        9
+    );
+}
+
+#[test]
+fn identical_content_shares_one_blob_and_gc_removes_orphans() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("lazynote.db");
+    let conn = open_db(&db_path).unwrap();
+    let first = create_note(&conn, "first");
+    let second = create_note(&conn, "second");
+    let service = AttachmentService::new(
+        SqliteAttachmentRepository::try_new(&conn, BlobStore::next_to_db(&db_path)).unwrap(),
+    );
+
+    let a = service
+        .add_attachment(first, "a.txt", None, b"hello")
+        .unwrap();
+    let b = service
+        .add_attachment(second, "b.txt", None, b"hello")
+        .unwrap();
+    assert_eq!(a.blob_sha256, b.blob_sha256);
+    assert_eq!(blob_ref_count(&conn, HELLO_SHA256), Some(2));
+    let blob_path = service.attachment_path(a.uuid).unwrap();
+
+    assert!(service.remove_attachment(a.uuid).unwrap());
+    assert!(!service.remove_attachment(a.uuid).unwrap());
+    assert_eq!(blob_ref_count(&conn, HELLO_SHA256), Some(1));
+    assert_eq!(service.collect_garbage().unwrap().removed_blobs, 0);
+    assert!(blob_path.is_file());
+
+    assert!(service.remove_attachment(b.uuid).unwrap());
+    let report = service.collect_garbage().unwrap();
+    assert_eq!(report.removed_blobs, 1);
+    assert_eq!(report.freed_bytes, 5);
+    assert!(!blob_path.exists());
+    assert_eq!(blob_ref_count(&conn, HELLO_SHA256), None);
+
+    let again = service
+        .add_attachment(first, "again.txt", None, b"hello")
+        .unwrap();
+    assert_eq!(service.read_attachment(again.uuid).unwrap(), b"hello");
+}
+
+#[test]
+fn hard_deleted_atoms_release_their_blobs() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("lazynote.db");
+    let conn = open_db(&db_path).unwrap();
+    let atom_id = create_note(&conn, "doomed");
+    let service = AttachmentService::new(
+        SqliteAttachmentRepository::try_new(&conn, BlobStore::next_to_db(&db_path)).unwrap(),
+    );
+    service
+        .add_attachment(atom_id, "a.txt", None, b"hello")
+        .unwrap();
+
+    conn.execute("DELETE FROM atoms WHERE uuid = ?1;", [atom_id.to_string()])
+        .unwrap();
+    assert_eq!(blob_ref_count(&conn, HELLO_SHA256), Some(0));
+    assert_eq!(service.collect_garbage().unwrap().removed_blobs, 1);
+}
+
+#[test]
+fn add_attachment_rejects_invalid_targets_without_leaving_blobs() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("lazynote.db");
+    let conn = open_db(&db_path).unwrap();
+    let atom_id = create_note(&conn, "note");
+    let store = BlobStore::next_to_db(&db_path);
+    let service =
+        AttachmentService::new(SqliteAttachmentRepository::try_new(&conn, store.clone()).unwrap());
+
+    assert!(matches!(
+        service.add_attachment(atom_id, "  ", None, b"x"),
+        Err(AttachmentError::InvalidName)
+    ));
+    assert!(matches!(
+        service.add_attachment(atom_id, "folder/", None, b"x"),
+        Err(AttachmentError::InvalidName)
+    ));
+
+    let missing = uuid::Uuid::new_v4();
+    assert!(matches!(
+        service.add_attachment(missing, "a.txt", None, b"hello"),
+        Err(AttachmentError::AtomNotFound(id)) if id == missing
+    ));
+    assert!(!store.path_for(HELLO_SHA256).exists());
+    assert_eq!(
+        std::fs::read_dir(store.root().join("tmp")).unwrap().count(),
+        0
+    );
+
+    assert!(matches!(
+        service.attachment_path(uuid::Uuid::new_v4()),
+        Err(AttachmentError::AttachmentNotFound(_))
+    ));
+}
+
+#[test]
+fn failed_transactions_leave_blob_files_untouched() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("lazynote.db");
+    let conn = open_db(&db_path).unwrap();
+    conn.busy_timeout(std::time::Duration::ZERO).unwrap();
+    let atom_id = create_note(&conn, "note");
+    let store = BlobStore::next_to_db(&db_path);
+    let service =
+        AttachmentService::new(SqliteAttachmentRepository::try_new(&conn, store.clone()).unwrap());
+    let orphan = service
+        .add_attachment(atom_id, "a.txt", None, b"hello")
+        .unwrap();
+    assert!(service.remove_attachment(orphan.uuid).unwrap());
+
+    let writer = open_db(&db_path).unwrap();
+    writer.execute_batch("BEGIN IMMEDIATE;").unwrap();
+    assert!(matches!(
+        service.collect_garbage(),
+        Err(AttachmentError::Db(_))
+    ));
+    assert!(store.path_for(HELLO_SHA256).is_file());
+    assert!(matches!(
+        service.add_attachment(atom_id, "b.txt", None, b"world"),
+        Err(AttachmentError::Db(_))
+    ));
+    assert_eq!(
+        std::fs::read_dir(store.root().join("tmp")).unwrap().count(),
+        0
+    );
+    writer.execute_batch("ROLLBACK;").unwrap();
+
+    assert_eq!(service.collect_garbage().unwrap().removed_blobs, 1);
+    assert!(!store.path_for(HELLO_SHA256).exists());
+}
@@ -0,0 +1,32 @@
+//! Note fixtures shared by integration tests.
+//!
+//! Each test binary compiles this module separately and uses only part of it.
+#![allow(dead_code)]
+
+use lazynote_core::{AtomId, NoteService, SqliteNoteRepository};
+use rusqlite::Connection;
+
+pub fn notes(conn: &mut Connection) -> NoteService<SqliteNoteRepository<'_>> {
+    NoteService::new(SqliteNoteRepository::try_new(conn).unwrap())
+}
+
+pub fn create_note(conn: &mut Connection, content: &str) -> AtomId {
+    notes(conn).create_note(content).unwrap().atom_id
+}
+
+pub fn create_tagged_note(conn: &mut Connection, content: &str, tags: &[&str]) -> AtomId {
+    let mut service = notes(conn);
+    let atom_id = service.create_note(content).unwrap().atom_id;
+    service
+        .set_note_tags(atom_id, tags.iter().map(|tag| tag.to_string()).collect())
+        .unwrap();
+    atom_id
+}
+
+pub fn update_note(conn: &mut Connection, atom_id: AtomId, content: &str) {
+    notes(conn).update_note(atom_id, content).unwrap();
+}
+
+pub fn note_content(conn: &mut Connection, atom_id: AtomId) -> String {
+    notes(conn).get_note(atom_id).unwrap().unwrap().content
+}
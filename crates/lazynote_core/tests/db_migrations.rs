@@ -14,9 +14,38 @@ fn open_db_in_memory_applies_all_migrations() {
     assert_table_exists(&conn, "external_mappings");
     assert_column_exists(&conn, "atoms", "preview_text");
     assert_column_exists(&conn, "atoms", "preview_image");
+    assert_column_exists(&conn, "atoms", "preview_title");
+    assert_column_exists(&conn, "atoms", "word_count");
+    assert_column_exists(&conn, "atoms", "reading_time_minutes");
     assert_column_exists(&conn, "atoms", "start_at");
     assert_column_exists(&conn, "atoms", "end_at");
     assert_column_exists(&conn, "atoms", "recurrence_rule");
+    assert_table_exists(&conn, "atom_access_log");
+    assert_column_exists(&conn, "atoms_fts", "tags");
+    assert_table_exists(&conn, "saved_searches");
+    assert_column_exists(&conn, "workspace_nodes", "saved_search_uuid");
+    assert_table_exists(&conn, "note_revisions");
+    assert_table_exists(&conn, "note_links");
+    assert_table_exists(&conn, "atom_properties");
+    assert_column_exists(&conn, "atom_properties", "value_type");
+    assert_column_exists(&conn, "atom_properties", "value_number");
+    assert_column_exists(&conn, "atom_properties", "value_ref");
+    assert_table_exists(&conn, "attachment_blobs");
+    assert_table_exists(&conn, "attachments");
+    assert_table_exists(&conn, "note_tasks");
+    assert_column_exists(&conn, "note_tasks", "line_number");
+    assert_table_exists(&conn, "note_blocks");
+    assert_column_exists(&conn, "note_blocks", "block_id");
+    assert_column_exists(&conn, "tags", "color");
+    assert_column_exists(&conn, "tags", "icon");
+    assert_column_exists(&conn, "tags", "description");
+    assert_column_exists(&conn, "atom_tags", "source");
+    assert_column_exists(&conn, "atoms", "is_archived");
+    assert_column_exists(&conn, "atoms", "is_pinned");
+    assert_column_exists(&conn, "atoms", "is_starred");
+    assert_column_exists(&conn, "atoms", "deleted_at");
+    assert_table_exists(&conn, "command_journal");
+    assert_table_exists(&conn, "note_templates");
 }
 
 #[test]
@@ -337,6 +366,167 @@ fn migration_9_backfill_sql_is_idempotent_on_replay() {
     assert_eq!(count_after_replay, 1);
 }
 
+#[test]
+fn migration_28_backfills_preview_stats_of_old_rows() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    apply_migrations(&mut conn).unwrap();
+    let old_note = Uuid::new_v4().to_string();
+    let empty_note = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO atoms (uuid, type, content, updated_at)
+         VALUES (?1, 'note', '# Old title\n\nthree more words', 42);",
+        [old_note.as_str()],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO atoms (uuid, type, content) VALUES (?1, 'note', '');",
+        [empty_note.as_str()],
+    )
+    .unwrap();
+    rewind_to_v29(&conn);
+    conn.execute_batch("PRAGMA user_version = 27;").unwrap();
+
+    apply_migrations(&mut conn).unwrap();
+
+    let (title, words, minutes, updated_at): (Option<String>, i64, i64, i64) = conn
+        .query_row(
+            "SELECT preview_title, word_count, reading_time_minutes, updated_at
+             FROM atoms WHERE uuid = ?1;",
+            [old_note.as_str()],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .unwrap();
+    assert_eq!(title.as_deref(), Some("Old title"));
+    assert_eq!((words, minutes, updated_at), (5, 1, 42));
+    let empty_words: i64 = conn
+        .query_row(
+            "SELECT word_count FROM atoms WHERE uuid = ?1;",
+            [empty_note.as_str()],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(empty_words, 0);
+}
+
+#[test]
+fn migration_29_marks_declared_front_matter_tags() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    apply_migrations(&mut conn).unwrap();
+    let note = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO atoms (uuid, type, content)
+         VALUES (?1, 'note', '---\ntags: [Books]\n---\nDune');",
+        [note.as_str()],
+    )
+    .unwrap();
+    conn.execute_batch("INSERT INTO tags (name) VALUES ('books'), ('favorite');")
+        .unwrap();
+    conn.execute(
+        "INSERT INTO atom_tags (atom_uuid, tag_id, source)
+         SELECT ?1, id, 'manual' FROM tags;",
+        [note.as_str()],
+    )
+    .unwrap();
+    // Before migration 33, `atom_tags` triggers kept this column current.
+    conn.execute(
+        "UPDATE atoms_fts SET tags = 'books favorite' WHERE uuid = ?1;",
+        [note.as_str()],
+    )
+    .unwrap();
+    rewind_to_v29(&conn);
+    conn.execute_batch("PRAGMA user_version = 28;").unwrap();
+
+    apply_migrations(&mut conn).unwrap();
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT t.name, at.source
+             FROM atom_tags at
+             INNER JOIN tags t ON t.id = at.tag_id
+             WHERE at.atom_uuid = ?1
+             ORDER BY t.name;",
+        )
+        .unwrap();
+    let links: Vec<(String, String)> = stmt
+        .query_map([note.as_str()], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        links,
+        vec![
+            ("books".to_string(), "front_matter".to_string()),
+            ("favorite".to_string(), "manual".to_string()),
+        ]
+    );
+    let indexed_tags: String = conn
+        .query_row(
+            "SELECT tags FROM atoms_fts WHERE uuid = ?1;",
+            [note.as_str()],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert!(indexed_tags.contains("books"));
+}
+
+#[test]
+fn migration_30_trashes_tasks_of_trashed_notes() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    apply_migrations(&mut conn).unwrap();
+    rewind_to_v29(&conn);
+    let note = Uuid::new_v4().to_string();
+    let task = Uuid::new_v4().to_string();
+    let deleted_task = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO atoms (uuid, type, content, is_deleted)
+         VALUES (?1, 'note', '- [ ] Draft\n- [ ] Dropped', 1);",
+        [note.as_str()],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO atoms (uuid, type, content, is_deleted)
+         VALUES (?1, 'task', 'Draft', 0), (?2, 'task', 'Dropped', 1);",
+        [task.as_str(), deleted_task.as_str()],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO note_tasks (task_atom_uuid, note_atom_uuid, item_index, line_number)
+         VALUES (?1, ?3, 0, 1), (?2, ?3, 1, 2);",
+        [task.as_str(), deleted_task.as_str(), note.as_str()],
+    )
+    .unwrap();
+
+    apply_migrations(&mut conn).unwrap();
+
+    let is_deleted = |conn: &Connection, uuid: &str| -> bool {
+        conn.query_row(
+            "SELECT is_deleted FROM atoms WHERE uuid = ?1;",
+            [uuid],
+            |row| row.get(0),
+        )
+        .unwrap()
+    };
+    assert!(is_deleted(&conn, task.as_str()));
+    conn.execute(
+        "UPDATE atoms SET is_deleted = 0 WHERE uuid = ?1;",
+        [note.as_str()],
+    )
+    .unwrap();
+    assert!(!is_deleted(&conn, task.as_str()));
+    assert!(is_deleted(&conn, deleted_task.as_str()));
+}
+
+/// Undoes the schema changes of migration 30 so it can be replayed.
+fn rewind_to_v29(conn: &Connection) {
+    conn.execute_batch(
+        "DROP TRIGGER atoms_au_note_tasks_deleted;
+         DROP TRIGGER atoms_au_note_tasks_restored;
+         ALTER TABLE note_tasks DROP COLUMN deleted_with_note;
+         PRAGMA user_version = 29;",
+    )
+    .unwrap();
+}
+
 fn migrate_to_v8(conn: &Connection) {
     let migrations = [
         (1u32, include_str!("../src/db/migrations/0001_init.sql")),
@@ -404,3 +594,70 @@ fn assert_column_exists(conn: &Connection, table_name: &str, column_name: &str)
     }
     panic!("column {column_name} does not exist in table {table_name}");
 }
+
+#[test]
+fn migration_32_normalizes_and_merges_tag_names() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    apply_migrations(&mut conn).unwrap();
+    let atom = Uuid::new_v4().to_string();
+    conn.execute(
+        "INSERT INTO atoms (uuid, type, content) VALUES (?1, 'note', 'tagged');",
+        [atom.as_str()],
+    )
+    .unwrap();
+    conn.execute_batch(
+        "INSERT INTO tags (id, name, color) VALUES (1, 'work/ops', NULL);
+         INSERT INTO tags (id, name, color) VALUES (2, ' Work // Ops ', '#ff0000');
+         INSERT INTO tags (id, name) VALUES (3, 'ÜBER');
+         INSERT INTO tags (id, name) VALUES (4, ' / ');
+         INSERT INTO saved_searches (uuid, tag) VALUES ('search', ' ÜBER/Sub ');",
+    )
+    .unwrap();
+    for (tag_id, source) in [(1, "inline"), (2, "manual"), (3, "manual"), (4, "manual")] {
+        conn.execute(
+            "INSERT INTO atom_tags (atom_uuid, tag_id, source) VALUES (?1, ?2, ?3);",
+            rusqlite::params![atom.as_str(), tag_id, source],
+        )
+        .unwrap();
+    }
+    conn.execute_batch("PRAGMA user_version = 31;").unwrap();
+
+    apply_migrations(&mut conn).unwrap();
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT t.name, t.color, at.source
+             FROM tags t
+             INNER JOIN atom_tags at ON at.tag_id = t.id
+             ORDER BY t.name ASC;",
+        )
+        .unwrap();
+    let rows: Vec<(String, Option<String>, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            (
+                "work/ops".to_string(),
+                Some("#ff0000".to_string()),
+                "manual".to_string()
+            ),
+            ("über".to_string(), None, "manual".to_string()),
+        ]
+    );
+    let tag_count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM tags;", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(tag_count, 2);
+    let search_tag: String = conn
+        .query_row(
+            "SELECT tag FROM saved_searches WHERE uuid = 'search';",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(search_tag, "über/sub");
+}
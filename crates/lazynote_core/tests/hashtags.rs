@@ -0,0 +1,139 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    parse_hashtags, rewrite_hashtags, AtomRepository, AtomService, AtomTag, NoteService,
+    ScheduleEventRequest, SqliteAtomRepository, SqliteNoteRepository, SqliteTagRepository,
+    TagService, TagSource,
+};
+use rusqlite::Connection;
+
+fn details(conn: &Connection, atom_id: lazynote_core::AtomId) -> Vec<(String, TagSource)> {
+    TagService::new(SqliteTagRepository::try_new(conn).unwrap())
+        .atom_tag_details(atom_id)
+        .unwrap()
+        .into_iter()
+        .map(|AtomTag { name, source }| (name, source))
+        .collect()
+}
+
+fn inline(name: &str) -> (String, TagSource) {
+    (name.to_string(), TagSource::Inline)
+}
+
+fn manual(name: &str) -> (String, TagSource) {
+    (name.to_string(), TagSource::Manual)
+}
+
+#[test]
+fn parse_hashtags_skips_code_urls_headings_and_numbers() {
+    let content = "---\ntags: [meta]\n---\n\
+# Heading #notatag\n\
+\n\
+Ship it #Urgent, then (#work/Ops) and #café #urgent.\n\
+Issue #42 at https://example.com/page#anchor or see [[Note#section]].\n\
+Inline `#code` and [#link](https://example.com) are ignored.\n\
+\n\
+```\n#fenced\n```\n\
+- [ ] call #mom\n";
+    assert_eq!(
+        parse_hashtags(content),
+        vec![
+            "Urgent".to_string(),
+            "work/Ops".to_string(),
+            "café".to_string(),
+            "urgent".to_string(),
+            "mom".to_string(),
+        ]
+    );
+    assert!(parse_hashtags("a#b ##double #").is_empty());
+    assert_eq!(parse_hashtags("#日本語 #q3"), vec!["日本語", "q3"]);
+}
+
+#[test]
+fn rewrite_hashtags_only_touches_prose_tags() {
+    let rename = |tag: &str| (tag.to_lowercase() == "work").then(|| "archive".to_string());
+    let content = "# Heading #work\n\
+Ship #Work, (#work) and #workshop; not `#work`.\n\
+\n\
+```\n#work\n```\n";
+    assert_eq!(
+        rewrite_hashtags(content, rename).as_deref(),
+        Some(
+            "# Heading #work\n\
+Ship #archive, (#archive) and #workshop; not `#work`.\n\
+\n\
+```\n#work\n```\n"
+        )
+    );
+    assert_eq!(rewrite_hashtags("no tags here", rename), None);
+}
+
+#[test]
+fn entry_created_atoms_pick_up_inline_hashtags() {
+    let conn = open_db_in_memory().unwrap();
+    let service = AtomService::new(SqliteAtomRepository::try_new(&conn).unwrap());
+    let task = service.create_task("buy milk #urgent #Errands").unwrap();
+    let event = service
+        .schedule_event(&ScheduleEventRequest {
+            title: "standup #work".to_string(),
+            start_epoch_ms: 1_000,
+            end_epoch_ms: None,
+        })
+        .unwrap();
+
+    assert_eq!(
+        details(&conn, task),
+        vec![inline("errands"), inline("urgent")]
+    );
+    assert_eq!(details(&conn, event), vec![inline("work")]);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let mut atom = repo.get_atom(task, false).unwrap().unwrap();
+    atom.content = "buy milk #errands".to_string();
+    repo.update_atom(&atom).unwrap();
+    assert_eq!(details(&conn, task), vec![inline("errands")]);
+
+    let tags = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+    assert!(tags.get_tag("urgent").unwrap().is_none());
+}
+
+#[test]
+fn manual_and_inline_tags_stay_separate() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        let mut service = NoteService::new(repo);
+        let note = service.create_note("draft #idea").unwrap().atom_id;
+        service
+            .set_note_tags(note, vec!["project".to_string(), "idea".to_string()])
+            .unwrap();
+        note
+    };
+    // `idea` is both manual and inline: stored as manual.
+    assert_eq!(
+        details(&conn, note),
+        vec![manual("idea"), manual("project")]
+    );
+
+    {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        NoteService::new(repo)
+            .update_note(note, "draft without hashtags but #review")
+            .unwrap();
+    }
+    assert_eq!(
+        details(&conn, note),
+        vec![manual("idea"), manual("project"), inline("review")]
+    );
+
+    let service = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+    assert_eq!(
+        service.set_atom_tags(note, Vec::new()).unwrap(),
+        vec!["review".to_string()]
+    );
+    assert_eq!(
+        service.remove_tag(note, "review").unwrap(),
+        vec!["review".to_string()]
+    );
+    service.add_tag(note, "review").unwrap();
+    assert_eq!(details(&conn, note), vec![manual("review")]);
+}
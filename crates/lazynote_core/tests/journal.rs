@@ -0,0 +1,312 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomId, AtomRepository, AtomType, JournalService, JournalStatus, NoteService, RepoError,
+    SqliteAtomRepository, SqliteJournalRepository, SqliteNoteRepository, SqliteTagRepository,
+    SqliteTreeRepository, TagService, TaskService, TaskStatus, TreeService,
+};
+use rusqlite::Connection;
+
+fn journal(conn: &Connection) -> JournalService<SqliteJournalRepository<'_>> {
+    JournalService::new(SqliteJournalRepository::try_new(conn).unwrap())
+}
+
+fn notes(conn: &mut Connection) -> NoteService<SqliteNoteRepository<'_>> {
+    NoteService::new(
+        SqliteNoteRepository::try_new(conn)
+            .unwrap()
+            .with_revision_window_ms(0),
+    )
+}
+
+fn create_atom(conn: &Connection, atom: Atom) -> AtomId {
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .create_atom(&atom)
+        .unwrap();
+    atom.uuid
+}
+
+fn load_atom(conn: &Connection, id: AtomId) -> Atom {
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .get_atom(id, false)
+        .unwrap()
+        .unwrap()
+}
+
+#[test]
+fn undo_and_redo_note_update_rebuild_links() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = notes(&mut conn)
+        .create_note("# Draft\nsee [[Target]]")
+        .unwrap()
+        .atom_id;
+    notes(&mut conn).update_note(note, "# Final").unwrap();
+    assert!(notes(&mut conn).outgoing_links(note).unwrap().is_empty());
+
+    let entry = journal(&conn).undo().unwrap().unwrap();
+    assert_eq!(entry.command(), "note_update");
+    let service = notes(&mut conn);
+    let restored = service.get_note(note).unwrap().unwrap();
+    assert_eq!(restored.content, "# Draft\nsee [[Target]]");
+    assert_eq!(restored.title.as_deref(), Some("Draft"));
+    let links = service.outgoing_links(note).unwrap();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].target_title, "Target");
+
+    journal(&conn).redo().unwrap().unwrap();
+    let service = notes(&mut conn);
+    assert_eq!(service.get_note(note).unwrap().unwrap().content, "# Final");
+    assert!(service.outgoing_links(note).unwrap().is_empty());
+    assert!(journal(&conn).redo().unwrap().is_none());
+}
+
+#[test]
+fn note_edits_inside_revision_window_undo_as_one_step() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = {
+        let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        let note = service.create_note("one").unwrap().atom_id;
+        service.update_note(note, "one two").unwrap();
+        service.update_note(note, "one two three").unwrap();
+        note
+    };
+    assert_eq!(
+        journal(&conn).status().unwrap(),
+        JournalStatus {
+            undo_count: 1,
+            redo_count: 0,
+        }
+    );
+
+    journal(&conn).undo().unwrap().unwrap();
+    let content = notes(&mut conn).get_note(note).unwrap().unwrap().content;
+    assert_eq!(content, "one");
+}
+
+#[test]
+fn undo_and_redo_note_tags() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = {
+        let mut service = notes(&mut conn);
+        let note = service.create_note("tagged #inline").unwrap().atom_id;
+        service
+            .set_note_tags(note, vec!["work".to_string()])
+            .unwrap();
+        service
+            .set_note_tags(note, vec!["home".to_string(), "urgent".to_string()])
+            .unwrap();
+        note
+    };
+
+    journal(&conn).undo().unwrap().unwrap();
+    let tags = notes(&mut conn).get_note(note).unwrap().unwrap().tags;
+    assert_eq!(tags, vec!["inline".to_string(), "work".to_string()]);
+
+    journal(&conn).undo().unwrap().unwrap();
+    let tags = notes(&mut conn).get_note(note).unwrap().unwrap().tags;
+    assert_eq!(tags, vec!["inline".to_string()]);
+
+    journal(&conn).redo().unwrap().unwrap();
+    journal(&conn).redo().unwrap().unwrap();
+    let tags = notes(&mut conn).get_note(note).unwrap().unwrap().tags;
+    assert_eq!(
+        tags,
+        vec![
+            "home".to_string(),
+            "inline".to_string(),
+            "urgent".to_string()
+        ]
+    );
+}
+
+#[test]
+fn undo_move_restores_parent_and_position() {
+    let conn = open_db_in_memory().unwrap();
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let first = tree.create_folder(None, "First").unwrap();
+    let second = tree.create_folder(None, "Second").unwrap();
+    let third = tree.create_folder(None, "Third").unwrap();
+    let target = tree.create_folder(None, "Target").unwrap();
+
+    tree.move_node(second.node_uuid, Some(target.node_uuid), None)
+        .unwrap();
+    let root: Vec<_> = tree
+        .list_children(None)
+        .unwrap()
+        .into_iter()
+        .map(|node| node.node_uuid)
+        .collect();
+    assert_eq!(
+        root,
+        vec![first.node_uuid, third.node_uuid, target.node_uuid]
+    );
+
+    let entry = journal(&conn).undo().unwrap().unwrap();
+    assert_eq!(entry.command(), "node_move");
+    let root: Vec<_> = tree
+        .list_children(None)
+        .unwrap()
+        .into_iter()
+        .map(|node| node.node_uuid)
+        .collect();
+    assert_eq!(
+        root,
+        vec![
+            first.node_uuid,
+            second.node_uuid,
+            third.node_uuid,
+            target.node_uuid
+        ]
+    );
+
+    journal(&conn).redo().unwrap().unwrap();
+    let moved = tree.list_children(Some(target.node_uuid)).unwrap();
+    assert_eq!(moved.len(), 1);
+    assert_eq!(moved[0].node_uuid, second.node_uuid);
+}
+
+#[test]
+fn undo_status_and_event_time_changes() {
+    let conn = open_db_in_memory().unwrap();
+    let task = create_atom(&conn, Atom::new(AtomType::Task, "errand"));
+    let mut event = Atom::new(AtomType::Event, "standup");
+    event.start_at = Some(1_000);
+    event.end_at = Some(2_000);
+    let event = create_atom(&conn, event);
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let tasks = TaskService::new(&repo, &conn);
+
+    tasks.update_status(task, Some(TaskStatus::Done)).unwrap();
+    tasks.update_event_times(event, 5_000, 6_000).unwrap();
+    tasks.update_event_times(task, 7_000, 8_000).unwrap();
+
+    journal(&conn).undo().unwrap().unwrap();
+    let undone = load_atom(&conn, task);
+    assert_eq!((undone.start_at, undone.end_at), (None, None));
+
+    let entry = journal(&conn).undo().unwrap().unwrap();
+    assert_eq!(entry.command(), "event_times");
+    let rescheduled = load_atom(&conn, event);
+    assert_eq!(
+        (rescheduled.start_at, rescheduled.end_at),
+        (Some(1_000), Some(2_000))
+    );
+
+    journal(&conn).undo().unwrap().unwrap();
+    assert_eq!(load_atom(&conn, task).task_status, None);
+
+    journal(&conn).redo().unwrap().unwrap();
+    assert_eq!(load_atom(&conn, task).task_status, Some(TaskStatus::Done));
+}
+
+#[test]
+fn new_mutation_drops_redo_side() {
+    let conn = open_db_in_memory().unwrap();
+    let task = create_atom(&conn, Atom::new(AtomType::Task, "errand"));
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let tasks = TaskService::new(&repo, &conn);
+
+    tasks.update_status(task, Some(TaskStatus::Todo)).unwrap();
+    tasks
+        .update_status(task, Some(TaskStatus::InProgress))
+        .unwrap();
+    // Same status again is not a mutation worth undoing.
+    tasks
+        .update_status(task, Some(TaskStatus::InProgress))
+        .unwrap();
+    journal(&conn).undo().unwrap().unwrap();
+    assert_eq!(
+        journal(&conn).status().unwrap(),
+        JournalStatus {
+            undo_count: 1,
+            redo_count: 1,
+        }
+    );
+
+    tasks.update_status(task, Some(TaskStatus::Done)).unwrap();
+    assert_eq!(
+        journal(&conn).status().unwrap(),
+        JournalStatus {
+            undo_count: 2,
+            redo_count: 0,
+        }
+    );
+    assert!(journal(&conn).redo().unwrap().is_none());
+    assert_eq!(journal(&conn).history(10).unwrap().len(), 2);
+}
+
+#[test]
+fn undo_of_deleted_target_fails_and_drops_entry() {
+    let conn = open_db_in_memory().unwrap();
+    let task = create_atom(&conn, Atom::new(AtomType::Task, "gone soon"));
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    TaskService::new(&repo, &conn)
+        .update_status(task, Some(TaskStatus::Done))
+        .unwrap();
+    repo.soft_delete_atom(task).unwrap();
+
+    assert!(matches!(
+        journal(&conn).undo(),
+        Err(RepoError::NotFound(id)) if id == task
+    ));
+    assert_eq!(journal(&conn).status().unwrap(), JournalStatus::default());
+    assert!(journal(&conn).undo().unwrap().is_none());
+}
+
+#[test]
+fn undo_and_redo_atom_tags() {
+    let conn = open_db_in_memory().unwrap();
+    let task = create_atom(&conn, Atom::new(AtomType::Task, "ship #release"));
+    let tags = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+    tags.set_atom_tags(task, vec!["Work".to_string()]).unwrap();
+    tags.add_tag(task, "urgent").unwrap();
+    tags.remove_tag(task, "work").unwrap();
+    assert_eq!(
+        tags.atom_tags(task).unwrap(),
+        vec!["release".to_string(), "urgent".to_string()]
+    );
+
+    let entry = journal(&conn).undo().unwrap().unwrap();
+    assert_eq!(entry.command(), "atom_set_tags");
+    assert_eq!(
+        tags.atom_tags(task).unwrap(),
+        vec![
+            "release".to_string(),
+            "urgent".to_string(),
+            "work".to_string()
+        ]
+    );
+    journal(&conn).undo().unwrap().unwrap();
+    journal(&conn).undo().unwrap().unwrap();
+    assert_eq!(tags.atom_tags(task).unwrap(), vec!["release".to_string()]);
+
+    journal(&conn).redo().unwrap().unwrap();
+    assert_eq!(
+        tags.atom_tags(task).unwrap(),
+        vec!["release".to_string(), "work".to_string()]
+    );
+}
+
+#[test]
+fn replay_that_no_longer_applies_drops_entry() {
+    let conn = open_db_in_memory().unwrap();
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let outer = tree.create_folder(None, "Outer").unwrap();
+    let inner = tree.create_folder(None, "Inner").unwrap();
+    tree.move_node(inner.node_uuid, Some(outer.node_uuid), None)
+        .unwrap();
+    journal(&conn).undo().unwrap().unwrap();
+    // Redoing the move would now put `Inner` below its own child.
+    conn.execute(
+        "UPDATE workspace_nodes SET parent_uuid = ?1 WHERE node_uuid = ?2;",
+        [inner.node_uuid.to_string(), outer.node_uuid.to_string()],
+    )
+    .unwrap();
+
+    assert!(matches!(
+        journal(&conn).redo(),
+        Err(RepoError::InvalidData(_))
+    ));
+    assert_eq!(journal(&conn).status().unwrap(), JournalStatus::default());
+}
@@ -0,0 +1,104 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    note_url, render_html, render_plain_text, LinkTable, NoteService, SqliteNoteRepository,
+    SqliteTreeRepository, TreeRepository,
+};
+
+#[test]
+fn html_renders_gfm_blocks() {
+    let html = render_html(
+        "# Plan\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n- [x] done\n- [ ] todo\n\n~~old~~ note[^1]\n\n[^1]: source\n",
+        &LinkTable::new(),
+    );
+    assert!(html.contains("<h1>Plan</h1>"));
+    assert!(html.contains("<table>"));
+    assert!(html.contains("<td>1</td>"));
+    assert!(html.contains("type=\"checkbox\" checked=\"\""));
+    assert!(html.contains("<del>old</del>"));
+    assert!(html.contains("class=\"footnote-reference\""));
+    assert!(html.contains("class=\"footnote-definition\""));
+}
+
+#[test]
+fn html_escapes_raw_html_and_drops_unsafe_urls() {
+    let html = render_html(
+        "<script>alert(1)</script>\n\nhi <b>there</b> [x](javascript:alert(1)) [ok](https://example.com) ![i](data:image/png;base64,AA)",
+        &LinkTable::new(),
+    );
+    assert!(!html.contains("<script>"));
+    assert!(html.contains("&lt;script&gt;"));
+    assert!(html.contains("&lt;b&gt;there&lt;/b&gt;"));
+    assert!(!html.contains("javascript:"));
+    assert!(html.contains("<a href=\"\">x</a>"));
+    assert!(html.contains("<a href=\"https://example.com\">ok</a>"));
+    assert!(html.contains("<img src=\"\" alt=\"i\" />"));
+}
+
+#[test]
+fn html_resolves_wiki_links_and_attachments() {
+    let attachment = uuid::Uuid::new_v4();
+    let target = uuid::Uuid::new_v4();
+    let mut links = LinkTable::new();
+    links.insert_note("Known Note", note_url(target));
+    let mut with_file = links.clone();
+    with_file.insert_attachment(&attachment.to_string(), "file:///blobs/ab/cd");
+
+    let source = format!(
+        "[[known note|Label]] and [[Missing \"One\"]]\n\n![scan](attachment:{attachment})\n\n`[[code]]`"
+    );
+    let html = render_html(source.as_str(), &links);
+    assert!(html.contains(&format!(
+        "<a class=\"wiki-link\" href=\"lazynote://note/{target}\">Label</a>"
+    )));
+    assert!(html.contains(
+        "<span class=\"wiki-link unresolved\" data-target=\"Missing &quot;One&quot;\">Missing \"One\"</span>"
+    ));
+    assert!(html.contains(&format!(
+        "<img src=\"lazynote://attachment/{attachment}\" alt=\"scan\" />"
+    )));
+    assert!(html.contains("<code>[[code]]</code>"));
+
+    let html = render_html(source.as_str(), &with_file);
+    assert!(html.contains("<img src=\"file:///blobs/ab/cd\" alt=\"scan\" />"));
+}
+
+#[test]
+fn plain_text_flattens_structure() {
+    let text = render_plain_text(
+        "---\ntitle: Skip\n---\n# Title\n\nSome **bold** and [link](https://x.y) with [[Target|label]].\n\n- one\n- [x] two\n  - nested\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n<div>raw</div>\n\n```\ncode line\n```\n",
+    );
+    assert_eq!(
+        text,
+        "Title\n\nSome bold and link with label.\n\n- one\n- [x] two\n  - nested\n\na\tb\n1\t2\n\ncode line"
+    );
+}
+
+#[test]
+fn note_service_renders_with_link_index() {
+    let mut conn = open_db_in_memory().unwrap();
+    let target = {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        NoteService::new(repo)
+            .create_note("# Target")
+            .unwrap()
+            .atom_id
+    };
+    SqliteTreeRepository::try_new(&conn)
+        .unwrap()
+        .create_note_ref(None, target, "Target")
+        .unwrap();
+
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let service = NoteService::new(repo);
+    let note = service
+        .create_note("See [[Target]] and [[Nowhere]].")
+        .unwrap();
+
+    let html = service.render_note_html(note.atom_id).unwrap();
+    assert!(html.contains(&format!("href=\"lazynote://note/{target}\"")));
+    assert!(html.contains("data-target=\"Nowhere\""));
+    assert_eq!(
+        service.render_note_plain_text(note.atom_id).unwrap(),
+        "See Target and Nowhere."
+    );
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use common::{create_note, notes, update_note};
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    assign_block_ids, parse_blocks, render_html, rewrite_wiki_link_target, BlockKind, LinkTable,
+    NoteBlock, SqliteTreeRepository, TreeRepository,
+};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+fn note_blocks(conn: &mut Connection, atom_id: Uuid) -> Vec<NoteBlock> {
+    notes(conn).note_blocks(atom_id).unwrap()
+}
+
+#[test]
+fn parse_blocks_segments_paragraphs_headings_and_items() {
+    let source = "---\ntitle: x\n---\n# Plan\n\nFirst para\nwraps. ^intro\n\n- one\n  - nested\n- [ ] two\n\n> quoted\n\n```\ncode\n```\n";
+    let blocks = parse_blocks(source);
+    let summary: Vec<(BlockKind, &str, Option<&str>)> = blocks
+        .iter()
+        .map(|block| (block.kind, block.text.as_str(), block.anchor.as_deref()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (BlockKind::Heading, "# Plan", None),
+            (BlockKind::Paragraph, "First para\nwraps.", Some("intro")),
+            (BlockKind::ListItem, "- one", None),
+            (BlockKind::ListItem, "- nested", None),
+            (BlockKind::ListItem, "- [ ] two", None),
+            (BlockKind::Paragraph, "quoted", None),
+        ]
+    );
+    assert_eq!(&source[blocks[0].start..blocks[0].end], "# Plan");
+    assert_eq!(
+        &source[blocks[1].start..blocks[1].end],
+        "First para\nwraps. ^intro"
+    );
+}
+
+#[test]
+fn assign_block_ids_carries_ids_across_edits() {
+    let previous = parse_blocks("# A\n\nkeep\n\nedit me\n\ndrop");
+    let mut counter = 0;
+    let mut next = || {
+        counter += 1;
+        format!("n{counter}")
+    };
+    let old_ids = assign_block_ids(&[], &previous, &mut next);
+    assert_eq!(old_ids, vec!["n1", "n2", "n3", "n4"]);
+    let stored: Vec<(String, BlockKind, String)> = old_ids
+        .into_iter()
+        .zip(&previous)
+        .map(|(id, block)| (id, block.kind, block.text.clone()))
+        .collect();
+
+    let current = parse_blocks("# A\n\nnew first\n\nkeep\n\nedited\n\nmine ^pinned");
+    let ids = assign_block_ids(&stored, &current, &mut next);
+    assert_eq!(ids, vec!["n1", "n5", "n2", "n3", "pinned"]);
+}
+
+#[test]
+fn block_ids_survive_note_updates() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = create_note(&mut conn, "# Log\n\nAlpha fact.\n\nBeta fact.");
+    let before = note_blocks(&mut conn, note);
+    assert_eq!(before.len(), 3);
+
+    update_note(
+        &mut conn,
+        note,
+        "# Log\n\nIntro.\n\nAlpha fact.\n\nBeta fact, revised.",
+    );
+    let after = note_blocks(&mut conn, note);
+    assert_eq!(after.len(), 4);
+    assert_eq!(after[0].block_id, before[0].block_id);
+    assert_eq!(after[2].content, "Alpha fact.");
+    assert_eq!(after[2].block_id, before[1].block_id);
+    assert_eq!(after[3].block_id, before[2].block_id);
+    assert!(before
+        .iter()
+        .all(|block| block.block_id != after[1].block_id));
+    assert_eq!(after[1].position, 1);
+}
+
+#[test]
+fn embeds_render_referenced_blocks() {
+    let mut conn = open_db_in_memory().unwrap();
+    let source = create_note(
+        &mut conn,
+        "# Source\n\nImportant **claim** with [[Elsewhere]]. ^claim",
+    );
+    SqliteTreeRepository::try_new(&conn)
+        .unwrap()
+        .create_note_ref(None, source, "Source")
+        .unwrap();
+
+    let service = notes(&mut conn);
+    let block = service.note_block(source, "^claim").unwrap().unwrap();
+    assert_eq!(block.content, "Important **claim** with [[Elsewhere]].");
+
+    let reader = service
+        .create_note("See:\n\n![[Source#^claim]]\n\n![[Source#^missing]] and [[Source#Heading]]")
+        .unwrap();
+    let html = service.render_note_html(reader.atom_id).unwrap();
+    assert!(html.contains(
+        "<div class=\"note-embed\" data-target=\"Source#^claim\"><p>Important <strong>claim</strong> with <span class=\"wiki-link unresolved\" data-target=\"Elsewhere\">Elsewhere</span>.</p></div>"
+    ));
+    let url = format!("lazynote://note/{source}");
+    assert!(html.contains(&format!(
+        "<a class=\"wiki-link\" href=\"{url}\">Source#^missing</a>"
+    )));
+    assert!(html.contains(&format!("href=\"{url}\">Source#Heading</a>")));
+    let links = service.outgoing_links(reader.atom_id).unwrap();
+    assert!(links.iter().all(|link| link.target_atom_id == Some(source)));
+}
+
+#[test]
+fn embeds_do_not_recurse_and_renames_keep_fragments() {
+    let mut table = LinkTable::new();
+    table.insert_embed("Loop#^a", "again ![[Loop#^a]]");
+    let html = render_html("![[Loop#^a]]", &table);
+    assert_eq!(html.matches("note-embed").count(), 1);
+    assert!(html.contains("data-target=\"Loop#^a\""));
+
+    assert_eq!(
+        rewrite_wiki_link_target("![[Old#^a]] [[old|x]]", "Old", "New").as_deref(),
+        Some("![[New#^a]] [[New|x]]")
+    );
+}
@@ -0,0 +1,285 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    parse_front_matter, parse_front_matter_date, rewrite_front_matter_tags, AtomRepository,
+    FrontMatterFormat, NoteService, PropertyFilter, SqliteAtomRepository, SqliteNoteRepository,
+    SqliteTreeRepository, TreeRepository, TreeService,
+};
+
+#[test]
+fn parses_yaml_and_toml_blocks() {
+    let yaml = parse_front_matter(
+        "---\ntitle: Reading List\ntags: [Books, to-read]\ndue: 2026-10-20\nStatus: open\nrating: 4\n---\n# Body",
+    )
+    .unwrap();
+    assert_eq!(yaml.format, FrontMatterFormat::Yaml);
+    assert_eq!(yaml.title.as_deref(), Some("Reading List"));
+    assert_eq!(yaml.tags, vec!["Books", "to-read"]);
+    assert_eq!(yaml.end_at, Some(1_792_454_400_000));
+    assert_eq!(
+        yaml.properties.get("status").map(String::as_str),
+        Some("open")
+    );
+    assert_eq!(yaml.properties.get("rating").map(String::as_str), Some("4"));
+
+    let verbatim = parse_front_matter(
+        "---\ncode: 007\nprice: 1.50\nquoted: 'yes'\nempty: ~\nnested: {a: 1}\n---\n",
+    )
+    .unwrap();
+    assert_eq!(
+        verbatim.properties.into_iter().collect::<Vec<_>>(),
+        vec![
+            ("code".to_string(), "007".to_string()),
+            ("price".to_string(), "1.50".to_string()),
+            ("quoted".to_string(), "yes".to_string()),
+        ]
+    );
+
+    let toml = parse_front_matter(
+        "+++\ntags = \"work, triage\"\nstart = 2026-01-02T03:04:05Z\nend = \"someday\"\n+++\nbody",
+    )
+    .unwrap();
+    assert_eq!(toml.format, FrontMatterFormat::Toml);
+    assert_eq!(toml.tags, vec!["work", " triage"]);
+    assert_eq!(
+        toml.start_at,
+        parse_front_matter_date("2026-01-02T03:04:05Z")
+    );
+    assert_eq!(toml.end_at, None);
+    assert_eq!(
+        toml.properties.get("end").map(String::as_str),
+        Some("someday")
+    );
+}
+
+#[test]
+fn missing_or_malformed_blocks_are_ignored() {
+    assert!(parse_front_matter("# title\n---\nkey: value\n---").is_none());
+    assert!(parse_front_matter("---\nkey: value\nno closing fence").is_none());
+    assert!(parse_front_matter("---\n: [unbalanced\n---\n").is_none());
+}
+
+#[test]
+fn rewrite_front_matter_tags_keeps_layout() {
+    let rename = |tag: &str| {
+        let tag = tag.to_lowercase();
+        tag.strip_prefix("work")
+            .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+            .map(|rest| format!("archive{rest}"))
+    };
+    let yaml = "---\ntitle: work\ntags:\n  - Work/Ops # keep\n  - workshop\n  - \"work\"\nwork: 1\n---\n#work body";
+    assert_eq!(
+        rewrite_front_matter_tags(yaml, rename).as_deref(),
+        Some("---\ntitle: work\ntags:\n  - archive/ops # keep\n  - workshop\n  - \"archive\"\nwork: 1\n---\n#work body")
+    );
+    let toml = "+++\ntags = [\"work/a\", 'home']\n+++\n";
+    assert_eq!(
+        rewrite_front_matter_tags(toml, rename).as_deref(),
+        Some("+++\ntags = [\"archive/a\", 'home']\n+++\n")
+    );
+    assert_eq!(
+        rewrite_front_matter_tags("---\ntags: work, home\n---\n", rename).as_deref(),
+        Some("---\ntags: archive, home\n---\n")
+    );
+    assert_eq!(
+        rewrite_front_matter_tags("---\ntags: [home]\n---\n", rename),
+        None
+    );
+    assert_eq!(rewrite_front_matter_tags("#work", rename), None);
+}
+
+#[test]
+fn date_parser_handles_offsets_and_rejects_invalid_dates() {
+    assert_eq!(parse_front_matter_date("1970-01-02"), Some(86_400_000));
+    assert_eq!(
+        parse_front_matter_date("1970-01-01T01:00:00+01:00"),
+        Some(0)
+    );
+    assert_eq!(
+        parse_front_matter_date("1970-01-01 00:00:01.5"),
+        Some(1_500)
+    );
+    assert_eq!(
+        parse_front_matter_date("1712345678000"),
+        Some(1_712_345_678_000)
+    );
+    assert_eq!(parse_front_matter_date("2025-02-29"), None);
+    assert_eq!(parse_front_matter_date("tomorrow"), None);
+}
+
+#[test]
+fn note_writes_apply_tags_times_and_properties() {
+    let mut conn = open_db_in_memory().unwrap();
+    let atom_id = {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        let mut service = NoteService::new(repo);
+        let note = service
+            .create_note("---\ntags: [Books]\nstart: 2026-03-01\ndue: 2026-03-31\nstatus: reading\n---\nDune")
+            .unwrap();
+        assert_eq!(note.preview_text.as_deref(), Some("Dune"));
+        assert_eq!(note.tags, vec!["books"]);
+
+        // Manual tags survive; front-matter tags follow the block.
+        service
+            .set_note_tags(note.atom_id, vec!["favorite".to_string()])
+            .unwrap();
+        let updated = service
+            .update_note(
+                note.atom_id,
+                "---\ntags: [books, sci-fi]\nstatus: reading\n---\nDune",
+            )
+            .unwrap();
+        assert_eq!(updated.tags, vec!["books", "favorite", "sci-fi"]);
+        let updated = service
+            .update_note(
+                note.atom_id,
+                "---\ntags: [sci-fi, favorite]\nstatus: done\n---\nDune #books",
+            )
+            .unwrap();
+        assert_eq!(updated.tags, vec!["books", "favorite", "sci-fi"]);
+        let updated = service
+            .update_note(note.atom_id, "---\nstatus: done\n---\nDune")
+            .unwrap();
+        assert_eq!(updated.tags, vec!["favorite"]);
+        assert_eq!(
+            service
+                .note_properties(note.atom_id)
+                .unwrap()
+                .iter()
+                .map(|property| (property.key.clone(), property.value.to_string()))
+                .collect::<Vec<_>>(),
+            vec![("status".to_string(), "done".to_string())]
+        );
+        note.atom_id
+    };
+
+    let atom = SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .get_atom(atom_id, false)
+        .unwrap()
+        .unwrap();
+    assert_eq!(atom.start_at, parse_front_matter_date("2026-03-01"));
+    assert_eq!(atom.end_at, parse_front_matter_date("2026-03-31"));
+}
+
+#[test]
+fn property_filters_narrow_note_lists() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let service = NoteService::new(repo);
+    let open_bug = service
+        .create_note("---\nkind: bug\nseverity: High\n---\ncrash on start")
+        .unwrap();
+    service
+        .create_note("---\nkind: bug\nseverity: low\n---\ntypo")
+        .unwrap();
+    service.create_note("plain note").unwrap();
+
+    let bugs = service
+        .list_notes_filtered(None, vec![PropertyFilter::has("KIND")], None, 0)
+        .unwrap();
+    assert_eq!(bugs.items.len(), 2);
+
+    let high = service
+        .list_notes_filtered(
+            None,
+            vec![
+                PropertyFilter::equals("kind", "bug"),
+                PropertyFilter::equals("severity", "high"),
+            ],
+            None,
+            0,
+        )
+        .unwrap();
+    assert_eq!(
+        high.items
+            .iter()
+            .map(|note| note.atom_id)
+            .collect::<Vec<_>>(),
+        vec![open_bug.atom_id]
+    );
+
+    // Dropping the block clears front-matter properties.
+    service
+        .update_note(open_bug.atom_id, "crash on start")
+        .unwrap();
+    assert!(service
+        .note_properties(open_bug.atom_id)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn title_renames_note_refs_and_resolves_links() {
+    let mut conn = open_db_in_memory().unwrap();
+    let (target, source) = {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        let service = NoteService::new(repo);
+        let target = service.create_note("draft").unwrap().atom_id;
+        let source = service.create_note("see [[Q3 Plan]]").unwrap().atom_id;
+        (target, source)
+    };
+    let node = {
+        let repo = SqliteTreeRepository::try_new(&conn).unwrap();
+        TreeService::new(repo)
+            .create_note_ref(None, target, Some("Untitled".to_string()))
+            .unwrap()
+            .node_uuid
+    };
+
+    {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        NoteService::new(repo)
+            .update_note(target, "---\ntitle: Q3 Plan\n---\ndraft")
+            .unwrap();
+    }
+
+    let renamed = SqliteTreeRepository::try_new(&conn)
+        .unwrap()
+        .get_node(node, false)
+        .unwrap()
+        .unwrap();
+    assert_eq!(renamed.display_name, "Q3 Plan");
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let service = NoteService::new(repo);
+    assert!(service.unresolved_links(source).unwrap().is_empty());
+    assert_eq!(service.backlinks(target).unwrap().len(), 1);
+}
+
+#[test]
+fn unchanged_title_keeps_explorer_renames() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        NoteService::new(repo)
+            .create_note("---\ntitle: Plan\n---\ndraft")
+            .unwrap()
+            .atom_id
+    };
+    let node = {
+        let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+        let node = tree
+            .create_note_ref(None, note, Some("Plan".to_string()))
+            .unwrap()
+            .node_uuid;
+        tree.rename_node(node, "My plan").unwrap();
+        node
+    };
+    let display_name = |conn: &rusqlite::Connection| {
+        SqliteTreeRepository::try_new(conn)
+            .unwrap()
+            .get_node(node, false)
+            .unwrap()
+            .unwrap()
+            .display_name
+    };
+
+    let update = |conn: &mut rusqlite::Connection, content: &str| {
+        let repo = SqliteNoteRepository::try_new(conn).unwrap();
+        NoteService::new(repo).update_note(note, content).unwrap();
+    };
+    update(&mut conn, "---\ntitle: Plan\n---\ndraft v2");
+    assert_eq!(display_name(&conn), "My plan");
+
+    update(&mut conn, "---\ntitle: Final plan\n---\ndraft v2");
+    assert_eq!(display_name(&conn), "Final plan");
+}
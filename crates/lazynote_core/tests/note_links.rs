@@ -0,0 +1,251 @@
+mod common;
+
+use common::{create_note, note_content, notes, update_note};
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    parse_wiki_links, rewrite_wiki_link_target, rewrite_wiki_link_to_atom, AtomId, JournalService,
+    NoteServiceError, SqliteJournalRepository, SqliteTreeRepository, TreeService, TreeServiceError,
+    WorkspaceNodeId, WorkspaceNodeKind,
+};
+use rusqlite::Connection;
+
+fn add_to_workspace(conn: &Connection, atom_id: AtomId, name: &str) -> WorkspaceNodeId {
+    let repo = SqliteTreeRepository::try_new(conn).unwrap();
+    TreeService::new(repo)
+        .create_note_ref(None, atom_id, Some(name.to_string()))
+        .unwrap()
+        .node_uuid
+}
+
+#[test]
+fn parser_finds_titles_uuids_and_labels_outside_code_fences() {
+    let source = "See [[Project Plan]] and [[Roadmap|the roadmap]].\n\
+                  ```\n[[Not a link]]\n```\n\
+                  Empty [[  ]] is ignored; [[ 1b4e28ba-2fa1-11d2-883f-0016d3cca427 ]] works.";
+    let links = parse_wiki_links(source);
+    assert_eq!(
+        links
+            .iter()
+            .map(|link| (link.target.as_str(), link.label.as_deref()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("Project Plan", None),
+            ("Roadmap", Some("the roadmap")),
+            ("1b4e28ba-2fa1-11d2-883f-0016d3cca427", None),
+        ]
+    );
+    assert_eq!(&source[links[0].start..links[0].end], "[[Project Plan]]");
+    assert_eq!(
+        links[0].context,
+        "See [[Project Plan]] and [[Roadmap|the roadmap]]."
+    );
+}
+
+#[test]
+fn rewrite_keeps_labels_and_other_links() {
+    let source = "[[old]] [[Old|alias]] [[other]]";
+    assert_eq!(
+        rewrite_wiki_link_target(source, "OLD", "New").as_deref(),
+        Some("[[New]] [[New|alias]] [[other]]")
+    );
+    assert_eq!(rewrite_wiki_link_target(source, "missing", "x"), None);
+}
+
+#[test]
+fn links_resolve_by_workspace_title_and_uuid() {
+    let mut conn = open_db_in_memory().unwrap();
+    let target = create_note(&mut conn, "# Plan\nDetails");
+    add_to_workspace(&conn, target, "Project Plan");
+
+    let source = create_note(
+        &mut conn,
+        &format!("Read [[project plan]] first.\nAlso [[{target}]] and [[Nowhere]]."),
+    );
+
+    let service = notes(&mut conn);
+    let outgoing = service.outgoing_links(source).unwrap();
+    assert_eq!(
+        outgoing
+            .iter()
+            .map(|link| link.target_atom_id)
+            .collect::<Vec<_>>(),
+        vec![Some(target), Some(target), None]
+    );
+    assert_eq!(outgoing[0].context, "Read [[project plan]] first.");
+
+    let backlinks = service.backlinks(target).unwrap();
+    assert_eq!(backlinks.len(), 2);
+    assert!(backlinks.iter().all(|link| link.source_atom_id == source));
+    assert!(service.backlinks(source).unwrap().is_empty());
+}
+
+#[test]
+fn update_replaces_link_rows() {
+    let mut conn = open_db_in_memory().unwrap();
+    let first = create_note(&mut conn, "first");
+    let second = create_note(&mut conn, "second");
+    let source = create_note(&mut conn, &format!("[[{first}]]"));
+
+    update_note(&mut conn, source, &format!("now [[{second}]] only"));
+
+    let service = notes(&mut conn);
+    assert!(service.backlinks(first).unwrap().is_empty());
+    assert_eq!(service.backlinks(second).unwrap().len(), 1);
+    assert!(matches!(
+        service.backlinks(uuid::Uuid::new_v4()),
+        Err(NoteServiceError::NoteNotFound(_))
+    ));
+}
+
+#[test]
+fn renaming_note_ref_rewrites_title_links() {
+    let mut conn = open_db_in_memory().unwrap();
+    let target = create_note(&mut conn, "target body");
+    let node = add_to_workspace(&conn, target, "Weekly Sync");
+    let source = create_note(
+        &mut conn,
+        "Agenda in [[weekly sync|sync]], not [[Weekly Syncs]].",
+    );
+
+    {
+        let repo = SqliteTreeRepository::try_new(&conn).unwrap();
+        TreeService::new(repo)
+            .rename_node(node, "Team Sync")
+            .unwrap();
+    }
+
+    assert_eq!(
+        note_content(&mut conn, source),
+        "Agenda in [[Team Sync|sync]], not [[Weekly Syncs]]."
+    );
+    let service = notes(&mut conn);
+    let outgoing = service.outgoing_links(source).unwrap();
+    assert_eq!(outgoing[0].target_title, "Team Sync");
+    assert_eq!(outgoing[0].target_atom_id, Some(target));
+    assert_eq!(outgoing[1].target_atom_id, None);
+    let revisions = service.list_revisions(source).unwrap();
+    assert_eq!(
+        revisions[0].content,
+        "Agenda in [[Team Sync|sync]], not [[Weekly Syncs]]."
+    );
+}
+
+#[test]
+fn rename_rewrites_are_journaled_per_source() {
+    let mut conn = open_db_in_memory().unwrap();
+    let target = create_note(&mut conn, "target body");
+    let node = add_to_workspace(&conn, target, "Weekly Sync");
+    let first = create_note(&mut conn, "See [[Weekly Sync]].");
+    let second = create_note(&mut conn, "Also [[Weekly Sync]].");
+
+    {
+        let repo = SqliteTreeRepository::try_new(&conn).unwrap();
+        TreeService::new(repo)
+            .rename_node(node, "Team Sync")
+            .unwrap();
+    }
+    assert_eq!(note_content(&mut conn, first), "See [[Team Sync]].");
+    assert_eq!(note_content(&mut conn, second), "Also [[Team Sync]].");
+
+    let journal = JournalService::new(SqliteJournalRepository::try_new(&conn).unwrap());
+    journal.undo().unwrap().expect("undo entry");
+    journal.undo().unwrap().expect("undo entry");
+    assert_eq!(note_content(&mut conn, first), "See [[Weekly Sync]].");
+    assert_eq!(note_content(&mut conn, second), "Also [[Weekly Sync]].");
+}
+
+fn unresolved(conn: &mut Connection, atom_id: AtomId) -> Vec<String> {
+    notes(conn).unresolved_links(atom_id).unwrap()
+}
+
+#[test]
+fn rewrite_to_atom_keeps_visible_text_as_label() {
+    let atom_id = uuid::Uuid::new_v4();
+    assert_eq!(
+        rewrite_wiki_link_to_atom("[[Idea]] and [[idea|this]]", "IDEA", atom_id).as_deref(),
+        Some(format!("[[{atom_id}|Idea]] and [[{atom_id}|this]]").as_str())
+    );
+    assert_eq!(
+        rewrite_wiki_link_to_atom("[[Other]]", "Idea", atom_id),
+        None
+    );
+}
+
+#[test]
+fn unresolved_links_are_deduplicated_and_resolve_when_note_appears() {
+    let mut conn = open_db_in_memory().unwrap();
+    let source = create_note(
+        &mut conn,
+        "[[Inbox Zero]] then [[inbox zero|again]] and [[Later]].",
+    );
+    assert_eq!(unresolved(&mut conn, source), vec!["Inbox Zero", "Later"]);
+
+    let target = create_note(&mut conn, "process mail");
+    add_to_workspace(&conn, target, "Inbox zero");
+    assert_eq!(unresolved(&mut conn, source), vec!["Later"]);
+
+    let service = notes(&mut conn);
+    assert_eq!(service.backlinks(target).unwrap().len(), 2);
+    let all = service.all_unresolved_links().unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].source_atom_id, source);
+    assert_eq!(all[0].targets, vec!["Later"]);
+}
+
+#[test]
+fn create_note_from_link_places_node_after_source_and_rewrites_link() {
+    let mut conn = open_db_in_memory().unwrap();
+    let source = create_note(&mut conn, "Plan: [[Launch Checklist|checklist]].");
+    let other = create_note(&mut conn, "Also needs [[launch checklist]].");
+    let source_node = add_to_workspace(&conn, source, "Plan");
+    let trailing_node = add_to_workspace(&conn, other, "Other");
+
+    let created = {
+        let repo = SqliteTreeRepository::try_new(&conn).unwrap();
+        TreeService::new(repo)
+            .create_note_from_link(source, "  Launch Checklist ")
+            .unwrap()
+    };
+    assert_eq!(created.kind, WorkspaceNodeKind::NoteRef);
+    assert_eq!(created.display_name, "Launch Checklist");
+    let new_atom = created.atom_uuid.unwrap();
+
+    let repo = SqliteTreeRepository::try_new(&conn).unwrap();
+    let roots = TreeService::new(repo).list_children(None).unwrap();
+    assert_eq!(
+        roots.iter().map(|node| node.node_uuid).collect::<Vec<_>>(),
+        vec![source_node, created.node_uuid, trailing_node]
+    );
+
+    assert_eq!(
+        note_content(&mut conn, source),
+        format!("Plan: [[{new_atom}|checklist]].")
+    );
+    assert_eq!(note_content(&mut conn, new_atom), "# Launch Checklist\n");
+    assert!(unresolved(&mut conn, source).is_empty());
+    // Other notes linking by title resolve through the new note_ref.
+    assert!(unresolved(&mut conn, other).is_empty());
+}
+
+#[test]
+fn create_note_from_link_rejects_resolved_or_missing_links() {
+    let mut conn = open_db_in_memory().unwrap();
+    let target = create_note(&mut conn, "exists");
+    add_to_workspace(&conn, target, "Known");
+    let source = create_note(&mut conn, "[[Known]] only");
+
+    let repo = SqliteTreeRepository::try_new(&conn).unwrap();
+    let service = TreeService::new(repo);
+    assert!(matches!(
+        service.create_note_from_link(source, "Known"),
+        Err(TreeServiceError::UnresolvedLinkNotFound { .. })
+    ));
+    assert!(matches!(
+        service.create_note_from_link(source, "Absent"),
+        Err(TreeServiceError::UnresolvedLinkNotFound { .. })
+    ));
+    assert!(matches!(
+        service.create_note_from_link(uuid::Uuid::new_v4(), "Known"),
+        Err(TreeServiceError::AtomNotFound(_))
+    ));
+}
@@ -0,0 +1,219 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    diff_text, AtomRepository, DiffGranularity, DiffOp, DiffSegment, JournalService, NoteService,
+    NoteServiceError, RepoError, SqliteAtomRepository, SqliteJournalRepository,
+    SqliteNoteRepository,
+};
+use rusqlite::Connection;
+
+fn service_without_coalescing(conn: &mut Connection) -> NoteService<SqliteNoteRepository<'_>> {
+    let repo = SqliteNoteRepository::try_new(conn)
+        .unwrap()
+        .with_revision_window_ms(0);
+    NoteService::new(repo)
+}
+
+fn contents(service: &NoteService<SqliteNoteRepository<'_>>, note: uuid::Uuid) -> Vec<String> {
+    service
+        .list_revisions(note)
+        .unwrap()
+        .into_iter()
+        .map(|revision| revision.content)
+        .collect()
+}
+
+#[test]
+fn first_update_keeps_original_content_as_baseline() {
+    let mut conn = open_db_in_memory().unwrap();
+    let service = service_without_coalescing(&mut conn);
+    let note = service.create_note("draft").unwrap();
+    assert!(service.list_revisions(note.atom_id).unwrap().is_empty());
+
+    service.update_note(note.atom_id, "draft v2").unwrap();
+    service.update_note(note.atom_id, "draft v3").unwrap();
+    // Unchanged content does not add a revision.
+    service.update_note(note.atom_id, "draft v3").unwrap();
+
+    assert_eq!(
+        contents(&service, note.atom_id),
+        vec!["draft v3", "draft v2", "draft"]
+    );
+}
+
+#[test]
+fn updates_inside_window_coalesce_into_newest_revision() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let service = NoteService::new(repo);
+    let note = service.create_note("a").unwrap();
+
+    service.update_note(note.atom_id, "ab").unwrap();
+    service.update_note(note.atom_id, "abc").unwrap();
+    service.update_note(note.atom_id, "abcd").unwrap();
+
+    let revisions = service.list_revisions(note.atom_id).unwrap();
+    assert_eq!(
+        revisions
+            .iter()
+            .map(|revision| revision.content.as_str())
+            .collect::<Vec<_>>(),
+        vec!["abcd", "a"]
+    );
+    assert!(revisions[0].updated_at >= revisions[0].created_at);
+}
+
+#[test]
+fn restore_appends_revision_and_refreshes_preview() {
+    let mut conn = open_db_in_memory().unwrap();
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let service = NoteService::new(repo);
+    let note = service
+        .create_note("# Plan\n\nFirst paragraph.\n\nSecond paragraph.")
+        .unwrap();
+    service
+        .update_note(note.atom_id, "# Plan\n\nFirst paragraph.")
+        .unwrap();
+
+    let baseline = *service
+        .list_revisions(note.atom_id)
+        .unwrap()
+        .last()
+        .map(|revision| &revision.revision_id)
+        .unwrap();
+    let restored = service.restore_revision(note.atom_id, baseline).unwrap();
+    assert!(restored.content.contains("Second paragraph."));
+    assert_eq!(restored.preview_text.as_deref(), Some("First paragraph."));
+    assert_eq!(restored.word_count, 5);
+
+    // Restore never coalesces, so the replaced content stays restorable.
+    let history = contents(&service, note.atom_id);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[1], "# Plan\n\nFirst paragraph.");
+}
+
+#[test]
+fn atom_update_of_note_content_goes_through_note_write_path() {
+    let mut conn = open_db_in_memory().unwrap();
+    let (note, target) = {
+        let service = service_without_coalescing(&mut conn);
+        let target = service.create_note("target").unwrap().atom_id;
+        (service.create_note("draft").unwrap().atom_id, target)
+    };
+
+    let atoms = SqliteAtomRepository::try_new(&conn).unwrap();
+    let mut atom = atoms.get_atom(note, false).unwrap().unwrap();
+    let linked = format!("draft v2 [[{target}]]");
+    atom.content = linked.clone();
+    atom.is_pinned = true;
+    atoms.update_atom(&atom).unwrap();
+    let loaded = atoms.get_atom(note, false).unwrap().unwrap();
+    assert!(loaded.is_pinned);
+
+    atom.is_deleted = true;
+    atom.content = "gone".to_string();
+    assert!(matches!(
+        atoms.update_atom(&atom),
+        Err(RepoError::InvalidData(_))
+    ));
+
+    {
+        let service = service_without_coalescing(&mut conn);
+        assert_eq!(contents(&service, note), vec![linked.as_str(), "draft"]);
+        let backlinks = service.backlinks(target).unwrap();
+        assert_eq!(backlinks.len(), 1);
+        assert_eq!(backlinks[0].source_atom_id, note);
+    }
+
+    JournalService::new(SqliteJournalRepository::try_new(&conn).unwrap())
+        .undo()
+        .unwrap()
+        .expect("undo entry");
+    let service = service_without_coalescing(&mut conn);
+    assert_eq!(service.get_note(note).unwrap().unwrap().content, "draft");
+}
+
+#[test]
+fn diff_revisions_reports_line_and_word_changes() {
+    let mut conn = open_db_in_memory().unwrap();
+    let service = service_without_coalescing(&mut conn);
+    let note = service.create_note("alpha\nbeta\ngamma\n").unwrap();
+    service
+        .update_note(note.atom_id, "alpha\nbeta two\ngamma\n")
+        .unwrap();
+    let revisions = service.list_revisions(note.atom_id).unwrap();
+    let (newer, older) = (revisions[0].revision_id, revisions[1].revision_id);
+
+    let lines = service
+        .diff_revisions(note.atom_id, older, newer, DiffGranularity::Line)
+        .unwrap();
+    assert_eq!(
+        lines,
+        vec![
+            segment(DiffOp::Equal, "alpha\n"),
+            segment(DiffOp::Delete, "beta\n"),
+            segment(DiffOp::Insert, "beta two\n"),
+            segment(DiffOp::Equal, "gamma\n"),
+        ]
+    );
+
+    let words = service
+        .diff_revisions(note.atom_id, older, newer, DiffGranularity::Word)
+        .unwrap();
+    let inserted = words
+        .iter()
+        .filter(|segment| segment.op == DiffOp::Insert)
+        .map(|segment| segment.text.as_str())
+        .collect::<String>();
+    assert!(inserted.contains("two"));
+    assert!(words
+        .iter()
+        .all(|segment| segment.op != DiffOp::Delete || !segment.text.contains("alpha")));
+}
+
+#[test]
+fn diff_text_segments_reassemble_both_sides() {
+    let old = "one two three\nfour\n";
+    let new = "one 2 three\nfour\nfive\n";
+    for granularity in [DiffGranularity::Line, DiffGranularity::Word] {
+        let segments = diff_text(old, new, granularity);
+        let rebuilt_old = segments
+            .iter()
+            .filter(|segment| segment.op != DiffOp::Insert)
+            .map(|segment| segment.text.as_str())
+            .collect::<String>();
+        let rebuilt_new = segments
+            .iter()
+            .filter(|segment| segment.op != DiffOp::Delete)
+            .map(|segment| segment.text.as_str())
+            .collect::<String>();
+        assert_eq!(rebuilt_old, old);
+        assert_eq!(rebuilt_new, new);
+    }
+}
+
+#[test]
+fn revision_apis_report_missing_note_and_revision() {
+    let mut conn = open_db_in_memory().unwrap();
+    let service = service_without_coalescing(&mut conn);
+    let note = service.create_note("body").unwrap();
+
+    let missing_note = uuid::Uuid::new_v4();
+    assert!(matches!(
+        service.list_revisions(missing_note),
+        Err(NoteServiceError::NoteNotFound(id)) if id == missing_note
+    ));
+    assert!(matches!(
+        service.restore_revision(note.atom_id, 999),
+        Err(NoteServiceError::RevisionNotFound {
+            revision_id: 999,
+            ..
+        })
+    ));
+}
+
+fn segment(op: DiffOp, text: &str) -> DiffSegment {
+    DiffSegment {
+        op,
+        text: text.to_string(),
+    }
+}
@@ -0,0 +1,209 @@
+mod common;
+
+use common::{create_note, note_content, notes, update_note};
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    parse_checklist, set_checklist_item_checked, AtomId, AtomRepository, NoteTask,
+    SqliteAtomRepository, SqliteTrashRepository, TaskService, TaskStatus, TrashQuery, TrashService,
+};
+use rusqlite::Connection;
+
+fn note_tasks(conn: &mut Connection, atom_id: AtomId) -> Vec<NoteTask> {
+    notes(conn).note_tasks(atom_id).unwrap()
+}
+
+#[test]
+fn parse_checklist_skips_code_and_front_matter() {
+    let source = "---\ntitle: x\n---\n- [ ] first\n- plain\n- [X] second **bold**\n  - [ ] nested\n\n```\n- [ ] code\n```\n";
+    let items = parse_checklist(source);
+    let summary: Vec<(&str, bool, u32)> = items
+        .iter()
+        .map(|item| (item.text.as_str(), item.checked, item.line))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("first", false, 4),
+            ("second bold", true, 6),
+            ("nested", false, 7)
+        ]
+    );
+    assert_eq!(&source[items[0].marker..items[0].marker + 3], "[ ]");
+
+    let ticked = set_checklist_item_checked(source, 7, true).unwrap();
+    assert!(ticked.contains("  - [x] nested"));
+    assert_eq!(ticked.len(), source.len());
+    let unticked = set_checklist_item_checked(source, 6, false).unwrap();
+    assert!(unticked.contains("- [ ] second **bold**"));
+    assert!(set_checklist_item_checked(source, 5, true).is_none());
+}
+
+#[test]
+fn checklist_items_become_inbox_tasks() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = create_note(
+        &mut conn,
+        "# Standup\n\n- [ ] Send notes\n- [x] Book room\n- [ ]\n",
+    );
+
+    let tasks = note_tasks(&mut conn, note);
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].text, "Send notes");
+    assert_eq!(tasks[0].status, Some(TaskStatus::Todo));
+    assert_eq!(tasks[0].line, 3);
+    assert_eq!(tasks[1].text, "Book room");
+    assert_eq!(tasks[1].status, Some(TaskStatus::Done));
+    assert!(tasks.iter().all(|task| task.note_atom_id == note));
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let inbox = TaskService::new(&repo, &conn).fetch_inbox(50, 0).unwrap();
+    let inbox_ids: Vec<AtomId> = inbox.iter().map(|item| item.atom.uuid).collect();
+    assert!(inbox_ids.contains(&tasks[0].task_atom_id));
+    assert!(!inbox_ids.contains(&tasks[1].task_atom_id));
+}
+
+#[test]
+fn meeting_note_tasks_inherit_note_times_for_today() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = create_note(
+        &mut conn,
+        "---\nstart: 2026-03-01T09:00:00Z\nend: 2026-03-01T10:00:00Z\n---\n- [ ] Follow up\n",
+    );
+    let task = note_tasks(&mut conn, note).remove(0);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let atom = repo.get_atom(task.task_atom_id, false).unwrap().unwrap();
+    assert!(atom.start_at.is_some());
+    let start = atom.start_at.unwrap();
+    let today = TaskService::new(&repo, &conn)
+        .fetch_today(start - 1_000, start + 86_400_000, 50, 0)
+        .unwrap();
+    assert!(today.iter().any(|item| item.atom.uuid == task.task_atom_id));
+}
+
+#[test]
+fn note_edits_and_status_updates_stay_in_sync() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = create_note(&mut conn, "Plan\n\n- [ ] Draft\n- [ ] Review\n");
+    let before = note_tasks(&mut conn, note);
+
+    // Ticking in the note completes the task; renaming keeps its identity.
+    update_note(&mut conn, note, "Plan\n\n- [x] Draft v2\n- [ ] Review\n");
+    let after = note_tasks(&mut conn, note);
+    assert_eq!(after[0].task_atom_id, before[0].task_atom_id);
+    assert_eq!(after[0].text, "Draft v2");
+    assert_eq!(after[0].status, Some(TaskStatus::Done));
+    assert_eq!(after[1].task_atom_id, before[1].task_atom_id);
+
+    // Completing the task ticks the checkbox in the note.
+    {
+        let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+        TaskService::new(&repo, &conn)
+            .update_status(after[1].task_atom_id, Some(TaskStatus::Done))
+            .unwrap();
+        TaskService::new(&repo, &conn)
+            .update_status(after[0].task_atom_id, Some(TaskStatus::InProgress))
+            .unwrap();
+    }
+    assert_eq!(
+        note_content(&mut conn, note),
+        "Plan\n\n- [ ] Draft v2\n- [x] Review\n"
+    );
+
+    // Unticking a done task reopens it; other statuses are kept.
+    update_note(
+        &mut conn,
+        note,
+        "Intro\n\nPlan\n\n- [ ] Draft v2\n- [ ] Review\n",
+    );
+    let reopened = note_tasks(&mut conn, note);
+    assert_eq!(reopened[0].status, Some(TaskStatus::InProgress));
+    assert_eq!(reopened[1].status, Some(TaskStatus::Todo));
+    assert_eq!(reopened[1].line, 6);
+
+    // Removing an item soft-deletes its task.
+    update_note(&mut conn, note, "Plan\n\n- [ ] Review\n");
+    let remaining = note_tasks(&mut conn, note);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].task_atom_id, before[1].task_atom_id);
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let removed = repo
+        .get_atom(before[0].task_atom_id, true)
+        .unwrap()
+        .unwrap();
+    assert!(removed.is_deleted);
+}
+
+#[test]
+fn status_updates_write_a_note_revision() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = create_note(&mut conn, "# Plan\n\n- [ ] Draft\n");
+    let task = note_tasks(&mut conn, note).remove(0);
+    {
+        let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+        TaskService::new(&repo, &conn)
+            .update_status(task.task_atom_id, Some(TaskStatus::Done))
+            .unwrap();
+    }
+
+    let service = notes(&mut conn);
+    let revisions = service.list_revisions(note).unwrap();
+    assert_eq!(revisions[0].content, "# Plan\n\n- [x] Draft\n");
+    let record = service.get_note(note).unwrap().unwrap();
+    assert_eq!(record.title.as_deref(), Some("Plan"));
+    assert_eq!(
+        service.note_tasks(note).unwrap()[0].status,
+        Some(TaskStatus::Done)
+    );
+}
+
+#[test]
+fn tasks_follow_their_note_into_and_out_of_the_trash() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = create_note(&mut conn, "- [ ] Draft\n");
+    let task = note_tasks(&mut conn, note).remove(0);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    repo.soft_delete_atom(note).unwrap();
+    let trashed = repo.get_atom(task.task_atom_id, true).unwrap().unwrap();
+    assert!(trashed.is_deleted);
+    let trash = TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap());
+    let listed: Vec<AtomId> = trash
+        .list_trash(&TrashQuery::default())
+        .unwrap()
+        .into_iter()
+        .map(|item| item.atom.uuid)
+        .collect();
+    assert_eq!(listed, vec![note]);
+
+    trash.restore(note).unwrap();
+    let restored = repo.get_atom(task.task_atom_id, false).unwrap();
+    assert!(restored.is_some());
+
+    repo.soft_delete_atom(note).unwrap();
+    trash.purge(note).unwrap();
+    assert!(repo.get_atom(task.task_atom_id, true).unwrap().is_none());
+}
+
+#[test]
+fn restoring_a_note_keeps_tasks_deleted_on_their_own() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = create_note(&mut conn, "- [ ] Keep\n- [ ] Drop\n");
+    let tasks = note_tasks(&mut conn, note);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    repo.soft_delete_atom(tasks[1].task_atom_id).unwrap();
+    repo.soft_delete_atom(note).unwrap();
+    TrashService::new(SqliteTrashRepository::try_new(&conn).unwrap())
+        .restore(note)
+        .unwrap();
+
+    assert!(repo
+        .get_atom(tasks[0].task_atom_id, false)
+        .unwrap()
+        .is_some());
+    assert!(repo
+        .get_atom(tasks[1].task_atom_id, false)
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,226 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    render_template, template_prompts, NoteService, NoteServiceError, NoteTemplate,
+    NoteTemplateDraft, SqliteNoteRepository, SqliteTemplateRepository, SqliteTreeRepository,
+    TemplateContext, TemplateService, TreeService,
+};
+use rusqlite::Connection;
+
+/// 2024-03-09T23:30:00Z.
+const NOW_MS: i64 = 1_710_027_000_000;
+
+fn notes(conn: &mut Connection) -> NoteService<SqliteNoteRepository<'_>> {
+    NoteService::new(SqliteNoteRepository::try_new(conn).unwrap())
+}
+
+fn templates(conn: &Connection) -> TemplateService<SqliteTemplateRepository<'_>> {
+    TemplateService::new(SqliteTemplateRepository::try_new(conn).unwrap())
+}
+
+fn create_template(conn: &Connection, draft: NoteTemplateDraft) -> NoteTemplate {
+    templates(conn).create_template(draft).unwrap()
+}
+
+fn context(title: Option<&str>, prompts: &[(&str, &str)]) -> TemplateContext {
+    TemplateContext {
+        title: title.map(str::to_string),
+        now_epoch_ms: NOW_MS,
+        utc_offset_minutes: 0,
+        prompts: prompts
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect(),
+    }
+}
+
+#[test]
+fn render_expands_builtins_and_prompts() {
+    let source = "# {{ title }}\n{{date}} {{TIME}}\nOwner: {{owner}}\n{{cursor}}\n{{missing}}!";
+    let rendered = render_template(source, &context(Some("Standup"), &[("owner", "Ada")]));
+    assert_eq!(
+        rendered.content,
+        "# Standup\n2024-03-09 23:30\nOwner: Ada\n\n!"
+    );
+    assert_eq!(
+        rendered.cursor,
+        Some("# Standup\n2024-03-09 23:30\nOwner: Ada\n".len())
+    );
+}
+
+#[test]
+fn render_uses_caller_utc_offset() {
+    let ctx = TemplateContext {
+        utc_offset_minutes: 90,
+        ..context(None, &[])
+    };
+    let rendered = render_template("{{date}}T{{time}} {{title}}|{{ }}{x}", &ctx);
+    assert_eq!(rendered.content, "2024-03-10T01:00 |{{ }}{x}");
+    assert_eq!(rendered.cursor, None);
+
+    let ctx = TemplateContext {
+        utc_offset_minutes: -24 * 60,
+        ..context(None, &[])
+    };
+    assert_eq!(render_template("{{date}}", &ctx).content, "2024-03-08");
+}
+
+#[test]
+fn prompts_are_listed_once_in_first_use_order() {
+    let source = "{{b}} {{date}} {{ a }} {{b}} {{Cursor}} {{title}}";
+    assert_eq!(
+        template_prompts(source),
+        vec!["b".to_string(), "a".to_string()]
+    );
+}
+
+#[test]
+fn template_crud_normalizes_and_validates() {
+    let conn = open_db_in_memory().unwrap();
+    let service = templates(&conn);
+    let created = service
+        .create_template(NoteTemplateDraft {
+            name: "  Daily  ".to_string(),
+            content: "# {{date}}".to_string(),
+            tags: vec!["Journal".to_string(), "journal".to_string()],
+            folder_uuid: None,
+        })
+        .unwrap();
+    assert_eq!(created.name, "Daily");
+    assert_eq!(created.tags, vec!["journal".to_string()]);
+
+    let duplicate = service.create_template(NoteTemplateDraft {
+        name: "daily".to_string(),
+        ..NoteTemplateDraft::default()
+    });
+    assert!(matches!(
+        duplicate,
+        Err(NoteServiceError::InvalidTemplate(_))
+    ));
+    let blank = service.create_template(NoteTemplateDraft {
+        name: " ".to_string(),
+        ..NoteTemplateDraft::default()
+    });
+    assert!(matches!(blank, Err(NoteServiceError::InvalidTemplate(_))));
+    let bad_tag = service.create_template(NoteTemplateDraft {
+        name: "Weekly".to_string(),
+        tags: vec!["  ".to_string()],
+        ..NoteTemplateDraft::default()
+    });
+    assert!(matches!(bad_tag, Err(NoteServiceError::InvalidTag(_))));
+
+    let renamed = service
+        .update_template(
+            created.uuid,
+            NoteTemplateDraft {
+                name: "DAILY".to_string(),
+                content: "# Today".to_string(),
+                ..NoteTemplateDraft::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(renamed.name, "DAILY");
+    assert!(renamed.tags.is_empty());
+    service
+        .create_template(NoteTemplateDraft {
+            name: "Agenda".to_string(),
+            ..NoteTemplateDraft::default()
+        })
+        .unwrap();
+    let names: Vec<_> = service
+        .list_templates()
+        .unwrap()
+        .into_iter()
+        .map(|template| template.name)
+        .collect();
+    assert_eq!(names, vec!["Agenda".to_string(), "DAILY".to_string()]);
+
+    service.delete_template(created.uuid).unwrap();
+    assert!(service.get_template(created.uuid).unwrap().is_none());
+    assert!(matches!(
+        service.delete_template(created.uuid),
+        Err(NoteServiceError::TemplateNotFound(id)) if id == created.uuid
+    ));
+}
+
+#[test]
+fn create_from_template_applies_tags_and_folder() {
+    let mut conn = open_db_in_memory().unwrap();
+    let folder = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap())
+        .create_folder(None, "Meetings")
+        .unwrap();
+    let template = create_template(
+        &conn,
+        NoteTemplateDraft {
+            name: "Meeting".to_string(),
+            content: "# {{title}}\nWith: {{who}}\n\n- [ ] {{cursor}}\n#inline".to_string(),
+            tags: vec!["meeting".to_string()],
+            folder_uuid: Some(folder.node_uuid),
+        },
+    );
+    let service = notes(&mut conn);
+    let linking = service.create_note("see [[Sync with Ada]]").unwrap();
+
+    let created = service
+        .create_from_template(
+            &template,
+            &context(Some("Sync with Ada"), &[("who", "Ada")]),
+        )
+        .unwrap();
+    assert_eq!(
+        created.note.content,
+        "# Sync with Ada\nWith: Ada\n\n- [ ] \n#inline"
+    );
+    assert_eq!(
+        created.cursor,
+        Some("# Sync with Ada\nWith: Ada\n\n- [ ] ".len())
+    );
+    assert_eq!(
+        created.note.tags,
+        vec!["inline".to_string(), "meeting".to_string()]
+    );
+    assert_eq!(created.note.title.as_deref(), Some("Sync with Ada"));
+    let backlinks = service.backlinks(created.note.atom_id).unwrap();
+    assert_eq!(backlinks.len(), 1);
+    assert_eq!(backlinks[0].source_atom_id, linking.atom_id);
+
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let children = tree.list_children(Some(folder.node_uuid)).unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(Some(children[0].node_uuid), created.node_uuid);
+    assert_eq!(children[0].atom_uuid, Some(created.note.atom_id));
+    assert_eq!(children[0].display_name, "Sync with Ada");
+}
+
+#[test]
+fn create_from_template_falls_back_to_root_for_deleted_folder() {
+    let mut conn = open_db_in_memory().unwrap();
+    let folder = {
+        let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+        let folder = tree.create_folder(None, "Gone").unwrap();
+        tree.delete_folder(folder.node_uuid, lazynote_core::FolderDeleteMode::Dissolve)
+            .unwrap();
+        folder
+    };
+    let template = create_template(
+        &conn,
+        NoteTemplateDraft {
+            name: "Loose".to_string(),
+            content: "plain body".to_string(),
+            folder_uuid: Some(folder.node_uuid),
+            ..NoteTemplateDraft::default()
+        },
+    );
+    let created = notes(&mut conn)
+        .create_from_template(&template, &context(Some("  Loose note "), &[]))
+        .unwrap();
+    assert_eq!(created.cursor, None);
+
+    let root = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap())
+        .list_children(None)
+        .unwrap();
+    let node = root
+        .iter()
+        .find(|node| Some(node.node_uuid) == created.node_uuid)
+        .expect("note filed at root");
+    assert_eq!(node.display_name, "Loose note");
+}
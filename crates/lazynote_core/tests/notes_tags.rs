@@ -17,11 +17,10 @@ fn create_and_update_note_derives_markdown_preview_fields() {
         .create_note("# Title\n\n![cover](images/first.png)\nBody with **markdown**")
         .unwrap();
     assert_eq!(created.preview_image.as_deref(), Some("images/first.png"));
-    assert!(created
-        .preview_text
-        .as_deref()
-        .unwrap_or("")
-        .contains("Title"));
+    assert_eq!(created.title.as_deref(), Some("Title"));
+    assert_eq!(created.preview_text.as_deref(), Some("Body with markdown"));
+    assert_eq!(created.word_count, 4);
+    assert_eq!(created.reading_time_minutes, 1);
 
     let updated = service
         .update_note(
@@ -30,6 +29,7 @@ fn create_and_update_note_derives_markdown_preview_fields() {
         )
         .unwrap();
     assert_eq!(updated.preview_image.as_deref(), Some("second.png"));
+    assert!(updated.title.is_none());
     assert!(updated
         .preview_text
         .as_deref()
@@ -258,11 +258,17 @@ fn create_minimal_atoms_table(conn: &Connection) {
             content TEXT NOT NULL,
             preview_text TEXT NULL,
             preview_image TEXT NULL,
+            preview_title TEXT NULL,
+            word_count INTEGER NOT NULL DEFAULT 0,
+            reading_time_minutes INTEGER NOT NULL DEFAULT 0,
             task_status TEXT NULL,
             start_at INTEGER NULL,
             end_at INTEGER NULL,
             recurrence_rule TEXT NULL,
             is_deleted INTEGER NOT NULL DEFAULT 0,
+            is_archived INTEGER NOT NULL DEFAULT 0,
+            is_pinned INTEGER NOT NULL DEFAULT 0,
+            is_starred INTEGER NOT NULL DEFAULT 0,
             updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now') * 1000)
         );",
     )
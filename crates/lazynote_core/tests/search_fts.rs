@@ -1,11 +1,25 @@
 use lazynote_core::db::migrations::{apply_migrations, latest_version};
 use lazynote_core::db::open_db_in_memory;
 use lazynote_core::{
-    search_all, Atom, AtomRepository, AtomType, SearchError, SearchQuery, SqliteAtomRepository,
+    atom_access_stats, record_atom_access_at, search_all, Atom, AtomRepository, AtomType,
+    SearchError, SearchHit, SearchItem, SearchQuery, SearchRanking, SearchResult,
+    SqliteAtomRepository, SqliteNoteRepository, SqliteTagRepository, SqliteTreeRepository,
+    TagMetadata, TagService, TreeService, WorkspaceNodeKind,
 };
+use lazynote_core::{FolderDeleteMode, NoteService};
 use rusqlite::Connection;
 use std::collections::HashSet;
 
+fn atom_hits(conn: &Connection, query: &SearchQuery) -> SearchResult<Vec<SearchHit>> {
+    Ok(search_all(conn, query)?
+        .into_iter()
+        .filter_map(|item| match item {
+            SearchItem::Atom(hit) => Some(hit),
+            _ => None,
+        })
+        .collect())
+}
+
 #[test]
 fn search_returns_created_atom() {
     let conn = open_db_in_memory().unwrap();
@@ -13,7 +27,7 @@ fn search_returns_created_atom() {
     let atom = Atom::new(AtomType::Note, "hello rust search");
     repo.create_atom(&atom).unwrap();
 
-    let hits = search_all(&conn, &SearchQuery::new("rust")).unwrap();
+    let hits = atom_hits(&conn, &SearchQuery::new("rust")).unwrap();
     assert_eq!(hits.len(), 1);
     assert_eq!(hits[0].atom_id, atom.uuid);
     assert!(hits[0].snippet.contains("rust"));
@@ -29,10 +43,10 @@ fn search_reflects_updated_content() {
     atom.content = "beta text".to_string();
     repo.update_atom(&atom).unwrap();
 
-    let old_hits = search_all(&conn, &SearchQuery::new("alpha")).unwrap();
+    let old_hits = atom_hits(&conn, &SearchQuery::new("alpha")).unwrap();
     assert!(old_hits.is_empty());
 
-    let new_hits = search_all(&conn, &SearchQuery::new("beta")).unwrap();
+    let new_hits = atom_hits(&conn, &SearchQuery::new("beta")).unwrap();
     assert_eq!(new_hits.len(), 1);
     assert_eq!(new_hits[0].atom_id, atom.uuid);
 }
@@ -45,7 +59,7 @@ fn search_excludes_soft_deleted_atoms() {
     repo.create_atom(&atom).unwrap();
     repo.soft_delete_atom(atom.uuid).unwrap();
 
-    let hits = search_all(&conn, &SearchQuery::new("milk")).unwrap();
+    let hits = atom_hits(&conn, &SearchQuery::new("milk")).unwrap();
     assert!(hits.is_empty());
 }
 
@@ -63,7 +77,7 @@ fn search_can_filter_by_type() {
 
     let mut query = SearchQuery::new("plan");
     query.kind = Some(AtomType::Task);
-    let hits = search_all(&conn, &query).unwrap();
+    let hits = atom_hits(&conn, &query).unwrap();
 
     assert_eq!(hits.len(), 1);
     assert_eq!(hits[0].atom_id, task.uuid);
@@ -83,7 +97,7 @@ fn search_limit_is_applied() {
 
     let mut query = SearchQuery::new("token");
     query.limit = 2;
-    let hits = search_all(&conn, &query).unwrap();
+    let hits = atom_hits(&conn, &query).unwrap();
 
     assert_eq!(hits.len(), 2);
     let ids: HashSet<_> = hits.into_iter().map(|hit| hit.atom_id).collect();
@@ -93,7 +107,7 @@ fn search_limit_is_applied() {
 #[test]
 fn blank_query_returns_empty_results() {
     let conn = open_db_in_memory().unwrap();
-    let hits = search_all(&conn, &SearchQuery::new("   ")).unwrap();
+    let hits = atom_hits(&conn, &SearchQuery::new("   ")).unwrap();
     assert!(hits.is_empty());
 }
 
@@ -107,7 +121,7 @@ fn limit_zero_returns_empty_results() {
     let mut query = SearchQuery::new("query");
     query.limit = 0;
 
-    let hits = search_all(&conn, &query).unwrap();
+    let hits = atom_hits(&conn, &query).unwrap();
     assert!(hits.is_empty());
 }
 
@@ -119,7 +133,7 @@ fn escaped_query_text_does_not_fail_on_common_symbols() {
     repo.create_atom(&atom).unwrap();
 
     let query = SearchQuery::new("a:b");
-    let hits = search_all(&conn, &query).unwrap();
+    let hits = atom_hits(&conn, &query).unwrap();
     assert!(hits.is_empty());
 }
 
@@ -159,6 +173,323 @@ fn migration_bootstrap_indexes_existing_v3_atoms() {
         .unwrap();
     assert_eq!(current_version, latest_version());
 
-    let hits = search_all(&conn, &SearchQuery::new("legacy")).unwrap();
+    let hits = atom_hits(&conn, &SearchQuery::new("legacy")).unwrap();
     assert_eq!(hits.len(), 1);
 }
+
+#[test]
+fn search_returns_workspace_folder_hits_before_atom_hits() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let atom = Atom::new(AtomType::Note, "roadmap review notes");
+    repo.create_atom(&atom).unwrap();
+
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let folder = tree.create_folder(None, "Roadmap 2026").unwrap();
+
+    let items = search_all(&conn, &SearchQuery::new("roadmap")).unwrap();
+    assert_eq!(items.len(), 2);
+    match &items[0] {
+        SearchItem::Workspace(hit) => {
+            assert_eq!(hit.node_uuid, folder.node_uuid);
+            assert_eq!(hit.kind, WorkspaceNodeKind::Folder);
+            assert_eq!(hit.display_name, "Roadmap 2026");
+        }
+        other => panic!("expected workspace hit, got {other:?}"),
+    }
+    assert_eq!(items[1].as_atom().map(|hit| hit.atom_id), Some(atom.uuid));
+}
+
+#[test]
+fn search_matches_note_ref_names_and_skips_deleted_folders() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note_id = {
+        let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        service.create_note("body").unwrap().atom_id
+    };
+
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let archive = tree.create_folder(None, "Quarterly archive").unwrap();
+    let note_ref = tree
+        .create_note_ref(None, note_id, Some("Quarterly plan".to_string()))
+        .unwrap();
+    tree.delete_folder(archive.node_uuid, FolderDeleteMode::Dissolve)
+        .unwrap();
+
+    let items = search_all(&conn, &SearchQuery::new("quarterly")).unwrap();
+    assert_eq!(items.len(), 1);
+    match &items[0] {
+        SearchItem::Workspace(hit) => {
+            assert_eq!(hit.node_uuid, note_ref.node_uuid);
+            assert_eq!(hit.kind, WorkspaceNodeKind::NoteRef);
+            assert_eq!(hit.atom_uuid, Some(note_id));
+        }
+        other => panic!("expected workspace hit, got {other:?}"),
+    }
+}
+
+#[test]
+fn search_returns_tag_hits_with_active_atom_counts() {
+    let mut conn = open_db_in_memory().unwrap();
+    {
+        let mut service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        let first = service.create_note("alpha").unwrap();
+        let second = service.create_note("beta").unwrap();
+        service
+            .set_note_tags(first.atom_id, vec!["Release".to_string()])
+            .unwrap();
+        service
+            .set_note_tags(
+                second.atom_id,
+                vec!["release".to_string(), "ops".to_string()],
+            )
+            .unwrap();
+    }
+
+    let items = search_all(&conn, &SearchQuery::new("RELEASE")).unwrap();
+    // Tag hit first, then both tagged notes matched through the `tags` column.
+    assert_eq!(items.len(), 3);
+    assert_eq!(
+        items.iter().filter(|item| item.as_atom().is_some()).count(),
+        2
+    );
+    match &items[0] {
+        SearchItem::Tag(hit) => {
+            assert_eq!(hit.name, "release");
+            assert_eq!(hit.atom_count, 2);
+        }
+        other => panic!("expected tag hit, got {other:?}"),
+    }
+}
+
+#[test]
+fn tag_edits_rebuild_fts_tags_once_links_settle() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let atom = Atom::new(AtomType::Note, "plan #draft");
+    repo.create_atom(&atom).unwrap();
+    let indexed_tags = || -> String {
+        conn.query_row(
+            "SELECT tags FROM atoms_fts WHERE uuid = ?1;",
+            [atom.uuid.to_string()],
+            |row| row.get(0),
+        )
+        .unwrap()
+    };
+    let tag_triggers: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master
+             WHERE type = 'trigger' AND tbl_name = 'atom_tags';",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(tag_triggers, 0);
+    assert_eq!(indexed_tags(), "draft");
+
+    let service = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+    service.add_tag(atom.uuid, "Ops").unwrap();
+    service.add_tag(atom.uuid, "release").unwrap();
+    let tags: HashSet<String> = indexed_tags().split(' ').map(str::to_string).collect();
+    assert_eq!(
+        tags,
+        HashSet::from(["draft", "ops", "release"].map(str::to_string))
+    );
+
+    service.merge_tags(&["ops".to_string()], "release").unwrap();
+    service.delete_tag("draft").unwrap();
+    assert_eq!(indexed_tags(), "release");
+    let hits = atom_hits(&conn, &SearchQuery::new("ops")).unwrap();
+    assert!(hits.is_empty(), "merged tag must leave the index: {hits:?}");
+
+    // Removing a manual tag re-syncs content, which still says `#draft`.
+    service.remove_tag(atom.uuid, "release").unwrap();
+    assert_eq!(indexed_tags(), "draft");
+}
+
+#[test]
+fn search_skips_tags_without_active_atoms() {
+    let mut conn = open_db_in_memory().unwrap();
+    {
+        let mut service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        let note = service.create_note("gone").unwrap();
+        service
+            .set_note_tags(note.atom_id, vec!["archive".to_string()])
+            .unwrap();
+        SqliteAtomRepository::try_new(&conn)
+            .unwrap()
+            .soft_delete_atom(note.atom_id)
+            .unwrap();
+    }
+    TagService::new(SqliteTagRepository::try_new(&conn).unwrap())
+        .set_tag_metadata(
+            "archived",
+            TagMetadata {
+                color: Some("#123456".to_string()),
+                ..TagMetadata::default()
+            },
+        )
+        .unwrap();
+
+    let items = search_all(&conn, &SearchQuery::new("archive")).unwrap();
+    assert!(
+        items.is_empty(),
+        "unused tags must not be returned: {items:?}"
+    );
+}
+
+#[test]
+fn kind_filter_and_opt_out_flags_disable_name_hits() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let task = Atom::new(AtomType::Task, "inbox zero");
+    repo.create_atom(&task).unwrap();
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    tree.create_folder(None, "Inbox").unwrap();
+
+    let mut filtered = SearchQuery::new("inbox");
+    filtered.kind = Some(AtomType::Task);
+    let items = search_all(&conn, &filtered).unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].as_atom().map(|hit| hit.atom_id), Some(task.uuid));
+
+    let mut opted_out = SearchQuery::new("inbox");
+    opted_out.include_workspace_nodes = false;
+    opted_out.include_tags = false;
+    let items = search_all(&conn, &opted_out).unwrap();
+    assert_eq!(items.len(), 1);
+    assert!(items[0].as_atom().is_some());
+}
+
+#[test]
+fn name_matching_treats_like_wildcards_literally() {
+    let conn = open_db_in_memory().unwrap();
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    tree.create_folder(None, "100% done").unwrap();
+    tree.create_folder(None, "1000 things").unwrap();
+
+    let items = search_all(&conn, &SearchQuery::new("100%")).unwrap();
+    assert_eq!(items.len(), 1);
+    match &items[0] {
+        SearchItem::Workspace(hit) => assert_eq!(hit.display_name, "100% done"),
+        other => panic!("expected workspace hit, got {other:?}"),
+    }
+}
+
+const DAY_MS: i64 = 86_400_000;
+const FIXTURE_NOW_MS: i64 = 1_900_000_000_000;
+
+/// Inserts an atom with a pinned `updated_at` so ranking is reproducible.
+fn insert_ranked_atom(conn: &Connection, content: &str, age_days: i64) -> Atom {
+    let repo = SqliteAtomRepository::try_new(conn).unwrap();
+    let atom = Atom::new(AtomType::Note, content);
+    repo.create_atom(&atom).unwrap();
+    conn.execute(
+        "UPDATE atoms SET updated_at = ?1 WHERE uuid = ?2",
+        rusqlite::params![FIXTURE_NOW_MS - age_days * DAY_MS, atom.uuid.to_string()],
+    )
+    .unwrap();
+    atom
+}
+
+fn ranked_ids(conn: &Connection, text: &str, ranking: SearchRanking) -> Vec<uuid::Uuid> {
+    let mut query = SearchQuery::new(text);
+    query.include_tags = false;
+    query.include_workspace_nodes = false;
+    query.ranking = ranking;
+    atom_hits(conn, &query)
+        .unwrap()
+        .into_iter()
+        .map(|hit| hit.atom_id)
+        .collect()
+}
+
+fn fixture_ranking() -> SearchRanking {
+    SearchRanking {
+        now_epoch_ms: Some(FIXTURE_NOW_MS),
+        ..SearchRanking::default()
+    }
+}
+
+#[test]
+fn ranking_fixture_orders_by_weights_recency_and_usage() {
+    let mut conn = open_db_in_memory().unwrap();
+    // Dense keyword match, but stale.
+    let stale = insert_ranked_atom(&conn, "roadmap roadmap review", 400);
+    // Single keyword match, edited yesterday.
+    let fresh = insert_ranked_atom(&conn, "roadmap review", 1);
+    // No keyword in content; matched only through its tag.
+    let tagged = insert_ranked_atom(&conn, "quarterly review", 400);
+    {
+        let mut service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+        service
+            .set_note_tags(tagged.uuid, vec!["roadmap".to_string()])
+            .unwrap();
+    }
+    conn.execute(
+        "UPDATE atoms SET updated_at = ?1 WHERE uuid = ?2",
+        rusqlite::params![FIXTURE_NOW_MS - 400 * DAY_MS, tagged.uuid.to_string()],
+    )
+    .unwrap();
+
+    let bm25_only = SearchRanking {
+        now_epoch_ms: Some(FIXTURE_NOW_MS),
+        ..SearchRanking::bm25_only()
+    };
+    assert_eq!(
+        ranked_ids(&conn, "roadmap", bm25_only.clone()),
+        vec![stale.uuid, fresh.uuid, tagged.uuid]
+    );
+
+    let tags_heavy = SearchRanking {
+        tags_weight: 10.0,
+        ..bm25_only
+    };
+    assert_eq!(ranked_ids(&conn, "roadmap", tags_heavy)[0], tagged.uuid);
+
+    let recency_heavy = SearchRanking {
+        tags_weight: 0.1,
+        recency_weight: 4.0,
+        ..fixture_ranking()
+    };
+    assert_eq!(
+        ranked_ids(&conn, "roadmap", recency_heavy),
+        vec![fresh.uuid, stale.uuid, tagged.uuid]
+    );
+
+    assert_ne!(
+        ranked_ids(&conn, "roadmap", fixture_ranking())[0],
+        tagged.uuid
+    );
+    for _ in 0..20 {
+        assert!(record_atom_access_at(&conn, tagged.uuid, FIXTURE_NOW_MS - DAY_MS).unwrap());
+    }
+    assert_eq!(
+        atom_access_stats(&conn, tagged.uuid).unwrap(),
+        Some((20, FIXTURE_NOW_MS - DAY_MS))
+    );
+    assert_eq!(
+        ranked_ids(&conn, "roadmap", fixture_ranking())[0],
+        tagged.uuid,
+        "frequently opened atom should outrank other hits"
+    );
+}
+
+#[test]
+fn access_log_skips_deleted_atoms_and_keeps_latest_timestamp() {
+    let conn = open_db_in_memory().unwrap();
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let atom = Atom::new(AtomType::Note, "opened note");
+    repo.create_atom(&atom).unwrap();
+
+    assert!(record_atom_access_at(&conn, atom.uuid, 2_000).unwrap());
+    assert!(record_atom_access_at(&conn, atom.uuid, 1_000).unwrap());
+    assert_eq!(
+        atom_access_stats(&conn, atom.uuid).unwrap(),
+        Some((2, 2_000))
+    );
+
+    repo.soft_delete_atom(atom.uuid).unwrap();
+    assert!(!record_atom_access_at(&conn, atom.uuid, 3_000).unwrap());
+    assert!(!record_atom_access_at(&conn, uuid::Uuid::new_v4(), 3_000).unwrap());
+}
@@ -0,0 +1,229 @@
+use lazynote_core::db::{open_db, open_db_in_memory};
+use lazynote_core::{
+    search_all, Atom, AtomFlag, AtomRepository, AtomType, SearchItem, SearchQuery, SearchRanking,
+    SearchSession, SectionFilter, SqliteAtomRepository, SESSION_CANDIDATES_MAX,
+};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+fn insert(conn: &Connection, kind: AtomType, content: &str) -> Uuid {
+    let repo = SqliteAtomRepository::try_new(conn).unwrap();
+    let atom = Atom::new(kind, content);
+    repo.create_atom(&atom).unwrap();
+    atom.uuid
+}
+
+fn ids(session: &mut SearchSession, text: &str) -> (Vec<Uuid>, bool) {
+    let result = session.search(text).unwrap();
+    (
+        result.hits.iter().map(|hit| hit.atom_id).collect(),
+        result.narrowed,
+    )
+}
+
+#[test]
+fn extended_query_narrows_cached_candidates() {
+    let conn = open_db_in_memory().unwrap();
+    let meeting = insert(&conn, AtomType::Note, "meeting with design team");
+    let meet_up = insert(&conn, AtomType::Note, "meet up for coffee");
+    let other = insert(&conn, AtomType::Task, "write release notes");
+    let mut session = SearchSession::new(conn);
+
+    let (first, narrowed) = ids(&mut session, "mee");
+    assert!(!narrowed);
+    assert_eq!(first.len(), 2);
+    assert!(first.contains(&meeting) && first.contains(&meet_up));
+
+    let (second, narrowed) = ids(&mut session, "meeti");
+    assert!(narrowed);
+    assert_eq!(second, vec![meeting]);
+
+    // Trailing whitespace turns the last term into an exact token.
+    let (exact, narrowed) = ids(&mut session, "meeting ");
+    assert!(narrowed);
+    assert_eq!(exact, vec![meeting]);
+
+    // Shrinking the query rescans the index.
+    let (rescanned, narrowed) = ids(&mut session, "no");
+    assert!(!narrowed);
+    assert_eq!(rescanned, vec![other]);
+}
+
+#[test]
+fn narrowing_matches_a_fresh_scan() {
+    let conn = open_db_in_memory().unwrap();
+    insert(&conn, AtomType::Note, "plan the quarterly review");
+    insert(&conn, AtomType::Note, "planning poker session");
+    insert(&conn, AtomType::Task, "plan-b: review vendors");
+    insert(&conn, AtomType::Note, "Plano trip #travel");
+    insert(&conn, AtomType::Note, "unrelated");
+    let mut session = SearchSession::new(conn);
+
+    for text in ["pla", "plan", "plan ", "plan r", "plan rev", "plan review"] {
+        let narrowed_result = ids(&mut session, text);
+        session.invalidate();
+        let fresh = ids(&mut session, text);
+        assert!(!fresh.1);
+        assert_eq!(narrowed_result.0, fresh.0, "query `{text}`");
+    }
+}
+
+#[test]
+fn non_ascii_candidates_are_verified_by_the_index() {
+    let conn = open_db_in_memory().unwrap();
+    let cafe = insert(&conn, AtomType::Note, "Café meeting notes");
+    let plain = insert(&conn, AtomType::Note, "cafe menu");
+    let mut session = SearchSession::new(conn);
+
+    let (first, _) = ids(&mut session, "cafe");
+    assert_eq!(first.len(), 2);
+
+    let (second, narrowed) = ids(&mut session, "cafe me");
+    assert!(narrowed);
+    assert_eq!(second.len(), 2);
+    assert!(second.contains(&cafe) && second.contains(&plain));
+
+    let (third, narrowed) = ids(&mut session, "cafe mee");
+    assert!(narrowed);
+    assert_eq!(third, vec![cafe]);
+}
+
+#[test]
+fn kind_change_and_invalidate_clear_cache() {
+    let conn = open_db_in_memory().unwrap();
+    let note = insert(&conn, AtomType::Note, "budget draft");
+    let task = insert(&conn, AtomType::Task, "budget review");
+    let mut session = SearchSession::new(conn);
+
+    ids(&mut session, "budget");
+    session.set_kind(Some(AtomType::Task));
+    let (tasks, narrowed) = ids(&mut session, "budget");
+    assert!(!narrowed);
+    assert_eq!(tasks, vec![task]);
+
+    // Writes through the session connection force a rescan.
+    let later = insert(session.connection(), AtomType::Task, "budget sign-off");
+    let (fresh, narrowed) = ids(&mut session, "budget ");
+    assert!(!narrowed);
+    assert!(fresh.contains(&later));
+    assert!(!fresh.contains(&note));
+
+    ids(&mut session, "budget");
+    session.invalidate();
+    let (_, narrowed) = ids(&mut session, "budget ");
+    assert!(!narrowed);
+}
+
+#[test]
+fn writes_from_other_connections_drop_the_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.sqlite3");
+    let writer = open_db(&path).unwrap();
+    let kept = insert(&writer, AtomType::Note, "roadmap draft");
+    let deleted = insert(&writer, AtomType::Note, "roadmap review");
+    let mut session = SearchSession::new(open_db(&path).unwrap());
+
+    let (first, _) = ids(&mut session, "road");
+    assert_eq!(first.len(), 2);
+
+    SqliteAtomRepository::try_new(&writer)
+        .unwrap()
+        .soft_delete_atom(deleted)
+        .unwrap();
+    let (second, narrowed) = ids(&mut session, "roadm");
+    assert!(!narrowed);
+    assert_eq!(second, vec![kept]);
+}
+
+#[test]
+fn list_filter_applies_to_scans() {
+    let conn = open_db_in_memory().unwrap();
+    let active = insert(&conn, AtomType::Note, "invoice march");
+    let archived = insert(&conn, AtomType::Note, "invoice april");
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    repo.set_atom_flag(archived, AtomFlag::Archived, true)
+        .unwrap();
+    repo.set_atom_flag(active, AtomFlag::Starred, true).unwrap();
+    let mut session = SearchSession::new(conn);
+
+    let (default, _) = ids(&mut session, "invoice");
+    assert_eq!(default, vec![active]);
+
+    session.set_filter(SectionFilter {
+        include_archived: true,
+        ..SectionFilter::default()
+    });
+    let (all, narrowed) = ids(&mut session, "invoice");
+    assert!(!narrowed);
+    assert_eq!(all.len(), 2);
+
+    session.set_filter(SectionFilter {
+        include_archived: true,
+        starred: Some(false),
+        ..SectionFilter::default()
+    });
+    let (unstarred, _) = ids(&mut session, "invoice");
+    assert_eq!(unstarred, vec![archived]);
+}
+
+#[test]
+fn session_ranking_matches_search_all() {
+    let conn = open_db_in_memory().unwrap();
+    insert(&conn, AtomType::Note, "garden");
+    let pinned = insert(&conn, AtomType::Note, "garden hose and garden gloves");
+    insert(&conn, AtomType::Note, "garden shed garden tools garden");
+    SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .set_atom_flag(pinned, AtomFlag::Pinned, true)
+        .unwrap();
+    let ranking = SearchRanking {
+        pinned_weight: 10.0,
+        ..SearchRanking::default()
+    };
+    let mut query = SearchQuery::new("garden");
+    query.include_tags = false;
+    query.include_workspace_nodes = false;
+    query.ranking = ranking.clone();
+    let expected: Vec<Uuid> = search_all(&conn, &query)
+        .unwrap()
+        .into_iter()
+        .filter_map(|item| match item {
+            SearchItem::Atom(hit) => Some(hit.atom_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(expected[0], pinned);
+
+    let mut session = SearchSession::new(conn);
+    session.set_ranking(ranking);
+    let (hits, _) = ids(&mut session, "garden");
+    assert_eq!(hits, expected);
+}
+
+#[test]
+fn capped_scan_is_never_narrowed() {
+    let conn = open_db_in_memory().unwrap();
+    for index in 0..=SESSION_CANDIDATES_MAX {
+        insert(&conn, AtomType::Note, &format!("standup {index}"));
+    }
+    let mut session = SearchSession::new(conn);
+    session.set_limit(5);
+
+    let first = session.search("stand").unwrap();
+    assert_eq!(first.hits.len(), 5);
+    let second = session.search("standu").unwrap();
+    assert!(!second.narrowed);
+}
+
+#[test]
+fn cancel_handle_only_drops_in_flight_searches() {
+    let conn = open_db_in_memory().unwrap();
+    let atom = insert(&conn, AtomType::Note, "retro notes");
+    let mut session = SearchSession::new(conn);
+    let handle = session.cancel_handle();
+
+    // Cancelling while idle must not poison the next keystroke.
+    std::thread::spawn(move || handle.cancel()).join().unwrap();
+    let (hits, _) = ids(&mut session, "retro");
+    assert_eq!(hits, vec![atom]);
+}
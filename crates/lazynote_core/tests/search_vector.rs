@@ -0,0 +1,131 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    refresh_embeddings, search_all, search_vector, Atom, AtomRepository, AtomType, Embedder,
+    HashingEmbedder, SearchItem, SearchMode, SearchQuery, SqliteAtomRepository,
+};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+fn insert(conn: &Connection, kind: AtomType, content: &str) -> Atom {
+    let repo = SqliteAtomRepository::try_new(conn).unwrap();
+    let atom = Atom::new(kind, content);
+    repo.create_atom(&atom).unwrap();
+    atom
+}
+
+fn hybrid_atom_ids(conn: &Connection, text: &str) -> Vec<Uuid> {
+    refresh_embeddings(conn, &HashingEmbedder::default()).unwrap();
+    let mut query = SearchQuery::new(text);
+    query.mode = SearchMode::Hybrid;
+    query.include_tags = false;
+    query.include_workspace_nodes = false;
+    search_all(conn, &query)
+        .unwrap()
+        .iter()
+        .filter_map(SearchItem::as_atom)
+        .map(|hit| hit.atom_id)
+        .collect()
+}
+
+#[test]
+fn hashing_embedder_is_deterministic_and_normalized() {
+    let embedder = HashingEmbedder::default();
+    let first = embedder.embed("Quarterly Planning");
+    let second = embedder.embed("quarterly planning");
+    assert_eq!(first, second);
+    assert_eq!(first.len(), embedder.dimensions());
+
+    let norm = first.iter().map(|value| value * value).sum::<f32>().sqrt();
+    assert!((norm - 1.0).abs() < 1e-5);
+    assert!(embedder.embed("  ").iter().all(|value| *value == 0.0));
+}
+
+#[test]
+fn embeddings_refresh_lazily_on_content_change_only() {
+    let conn = open_db_in_memory().unwrap();
+    let embedder = HashingEmbedder::default();
+    let atom = insert(&conn, AtomType::Note, "first draft");
+    insert(&conn, AtomType::Note, "second draft");
+
+    assert_eq!(refresh_embeddings(&conn, &embedder).unwrap(), 2);
+    assert_eq!(refresh_embeddings(&conn, &embedder).unwrap(), 0);
+
+    let repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let mut updated = atom.clone();
+    updated.content = "first final".to_string();
+    repo.update_atom(&updated).unwrap();
+    assert_eq!(refresh_embeddings(&conn, &embedder).unwrap(), 1);
+
+    // A different model id invalidates every row.
+    let wider = HashingEmbedder::new(512);
+    assert_eq!(refresh_embeddings(&conn, &wider).unwrap(), 2);
+}
+
+#[test]
+fn vector_search_filters_kind_and_skips_deleted_atoms() {
+    let conn = open_db_in_memory().unwrap();
+    let embedder = HashingEmbedder::default();
+    let note = insert(&conn, AtomType::Note, "garden watering schedule");
+    let task = insert(&conn, AtomType::Task, "water the garden");
+    let deleted = insert(&conn, AtomType::Note, "garden tools");
+    SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .soft_delete_atom(deleted.uuid)
+        .unwrap();
+
+    refresh_embeddings(&conn, &embedder).unwrap();
+    let hits = search_vector(&conn, &embedder, "garden", None, 10).unwrap();
+    let ids: Vec<_> = hits.iter().map(|hit| hit.atom_id).collect();
+    assert!(ids.contains(&note.uuid));
+    assert!(ids.contains(&task.uuid));
+    assert!(!ids.contains(&deleted.uuid));
+
+    let tasks = search_vector(&conn, &embedder, "garden", Some(AtomType::Task), 10).unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].atom_id, task.uuid);
+}
+
+#[test]
+fn hybrid_mode_finds_related_wording_that_keyword_search_misses() {
+    let conn = open_db_in_memory().unwrap();
+    let planning = insert(&conn, AtomType::Note, "quarterly planning session notes");
+    insert(&conn, AtomType::Note, "grocery list with apples");
+
+    let keyword = search_all(&conn, &SearchQuery::new("plans")).unwrap();
+    assert!(keyword.is_empty());
+
+    let hybrid = hybrid_atom_ids(&conn, "plans");
+    assert_eq!(hybrid.first(), Some(&planning.uuid));
+}
+
+#[test]
+fn hybrid_mode_keeps_exact_keyword_hit_first() {
+    let conn = open_db_in_memory().unwrap();
+    let exact = insert(&conn, AtomType::Note, "release checklist");
+    let related = insert(&conn, AtomType::Note, "releasing the checklists tomorrow");
+
+    let ids = hybrid_atom_ids(&conn, "release checklist");
+    assert_eq!(ids.first(), Some(&exact.uuid));
+    assert!(ids.contains(&related.uuid));
+}
+
+#[test]
+fn vector_search_only_reads_stored_embeddings() {
+    let conn = open_db_in_memory().unwrap();
+    let embedder = HashingEmbedder::default();
+    let atom = insert(&conn, AtomType::Note, "garden watering schedule");
+
+    assert!(search_vector(&conn, &embedder, "garden", None, 10)
+        .unwrap()
+        .is_empty());
+    let stored: i64 = conn
+        .query_row("SELECT COUNT(*) FROM atom_embeddings;", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(stored, 0);
+
+    assert_eq!(refresh_embeddings(&conn, &embedder).unwrap(), 1);
+    let hits = search_vector(&conn, &embedder, "garden", None, 10).unwrap();
+    assert_eq!(hits.first().map(|hit| hit.atom_id), Some(atom.uuid));
+}
@@ -0,0 +1,191 @@
+mod common;
+
+use common::create_tagged_note;
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    Atom, AtomId, AtomRepository, AtomType, NoteListQuery, NoteRepository, NoteService, RepoError,
+    SectionFilter, SqliteAtomRepository, SqliteNoteRepository, TagFilter, TagFilterParseError,
+    TaskService,
+};
+use rusqlite::Connection;
+
+fn tag_atom(conn: &Connection, atom_id: AtomId, tag: &str) {
+    conn.execute("INSERT OR IGNORE INTO tags (name) VALUES (?1);", [tag])
+        .unwrap();
+    conn.execute(
+        "INSERT INTO atom_tags (atom_uuid, tag_id)
+         SELECT ?1, id FROM tags WHERE name = ?2;",
+        rusqlite::params![atom_id.to_string(), tag],
+    )
+    .unwrap();
+}
+
+fn insert_task(conn: &Connection, content: &str, start: Option<i64>, tags: &[&str]) -> AtomId {
+    let mut atom = Atom::new(AtomType::Task, content);
+    atom.start_at = start;
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .create_atom(&atom)
+        .unwrap();
+    for tag in tags {
+        tag_atom(conn, atom.uuid, tag);
+    }
+    atom.uuid
+}
+
+#[test]
+fn parse_applies_precedence_and_normalization() {
+    let filter = TagFilter::parse("(Work or HOME) and not Archived").unwrap();
+    assert_eq!(
+        filter,
+        TagFilter::all([
+            TagFilter::any([TagFilter::tag("work"), TagFilter::tag("home")]),
+            TagFilter::not(TagFilter::tag("archived")),
+        ])
+    );
+
+    // NOT binds tighter than AND, AND tighter than OR; juxtaposition is AND.
+    assert_eq!(
+        TagFilter::parse("a OR b c NOT d").unwrap(),
+        TagFilter::any([
+            TagFilter::tag("a"),
+            TagFilter::all([
+                TagFilter::tag("b"),
+                TagFilter::tag("c"),
+                TagFilter::not(TagFilter::tag("d")),
+            ]),
+        ])
+    );
+    assert_eq!(
+        TagFilter::parse("\"deep work\" AND \"or\"").unwrap(),
+        TagFilter::all([TagFilter::tag("deep work"), TagFilter::tag("or")])
+    );
+}
+
+#[test]
+fn display_round_trips_through_parse() {
+    for source in [
+        "(work OR home) AND NOT archived",
+        "NOT (a OR b)",
+        "\"deep work\" OR \"and\"",
+        "x AND (y OR NOT z)",
+    ] {
+        let filter: TagFilter = source.parse().unwrap();
+        assert_eq!(filter.to_string(), source);
+        assert_eq!(TagFilter::parse(&filter.to_string()).unwrap(), filter);
+    }
+}
+
+#[test]
+fn parse_reports_malformed_expressions() {
+    assert_eq!(TagFilter::parse("  "), Err(TagFilterParseError::Empty));
+    assert_eq!(
+        TagFilter::parse("work AND"),
+        Err(TagFilterParseError::UnexpectedEnd)
+    );
+    assert_eq!(
+        TagFilter::parse("(work OR home"),
+        Err(TagFilterParseError::Unclosed { position: 0 })
+    );
+    assert_eq!(
+        TagFilter::parse("work )"),
+        Err(TagFilterParseError::UnexpectedToken {
+            token: ")".to_string(),
+            position: 5
+        })
+    );
+    assert!(matches!(
+        TagFilter::parse("OR work"),
+        Err(TagFilterParseError::UnexpectedToken { position: 0, .. })
+    ));
+}
+
+#[test]
+fn list_notes_applies_boolean_tag_filter() {
+    let mut conn = open_db_in_memory().unwrap();
+    let work = create_tagged_note(&mut conn, "work note", &["work"]);
+    let home = create_tagged_note(&mut conn, "home note", &["home"]);
+    let archived = create_tagged_note(&mut conn, "old work", &["work", "archived"]);
+    let _other = create_tagged_note(&mut conn, "other", &["misc"]);
+
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let service = NoteService::new(repo);
+    let filter = TagFilter::parse("(work OR home) AND NOT archived").unwrap();
+    let result = service
+        .query_notes(NoteListQuery {
+            tag_filter: Some(filter),
+            limit: Some(50),
+            ..NoteListQuery::default()
+        })
+        .unwrap();
+    let mut ids: Vec<AtomId> = result.items.iter().map(|note| note.atom_id).collect();
+    ids.sort();
+    let mut expected = vec![work, home];
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let combined = repo
+        .list_notes(&NoteListQuery {
+            tag: Some("work".to_string()),
+            tag_filter: Some(TagFilter::tag("archived")),
+            limit: Some(50),
+            ..NoteListQuery::default()
+        })
+        .unwrap();
+    assert_eq!(combined.len(), 1);
+    assert_eq!(combined[0].atom_id, archived);
+
+    let invalid = repo.list_notes(&NoteListQuery {
+        tag_filter: Some(TagFilter::any([])),
+        ..NoteListQuery::default()
+    });
+    assert!(matches!(invalid, Err(RepoError::InvalidTagFilter(_))));
+}
+
+#[test]
+fn section_queries_apply_tag_filter() {
+    let conn = open_db_in_memory().unwrap();
+    let urgent = insert_task(&conn, "urgent inbox", None, &["urgent", "work"]);
+    let _someday = insert_task(&conn, "someday inbox", None, &["someday"]);
+    let untagged = insert_task(&conn, "untagged inbox", None, &[]);
+    let today = insert_task(&conn, "started today", Some(500), &["work"]);
+    let _today_home = insert_task(&conn, "home today", Some(500), &["home"]);
+
+    let repo = SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .with_section_filter(SectionFilter {
+            tags: Some(TagFilter::tag("work")),
+            ..SectionFilter::default()
+        });
+    let work = TaskService::new(&repo, &conn);
+    let inbox: Vec<AtomId> = work
+        .fetch_inbox(50, 0)
+        .unwrap()
+        .iter()
+        .map(|item| item.atom.uuid)
+        .collect();
+    assert_eq!(inbox, vec![urgent]);
+    let active: Vec<AtomId> = work
+        .fetch_today(0, 1000, 50, 0)
+        .unwrap()
+        .iter()
+        .map(|item| item.atom.uuid)
+        .collect();
+    assert_eq!(active, vec![today]);
+
+    let repo = SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .with_section_filter(SectionFilter {
+            tags: Some(TagFilter::parse("NOT someday AND NOT urgent").unwrap()),
+            ..SectionFilter::default()
+        });
+    let not_someday = TaskService::new(&repo, &conn);
+    let inbox: Vec<AtomId> = not_someday
+        .fetch_inbox(50, 0)
+        .unwrap()
+        .iter()
+        .map(|item| item.atom.uuid)
+        .collect();
+    assert_eq!(inbox, vec![untagged]);
+}
@@ -0,0 +1,193 @@
+mod common;
+
+use common::create_tagged_note;
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    normalize_tag, AtomId, AtomService, NoteListQuery, NoteService, RepoError, SavedSearchQuery,
+    SqliteAtomRepository, SqliteNoteRepository, SqliteTagRepository, SqliteTreeRepository,
+    TagFilter, TagService, TagTreeNode, TreeRepository,
+};
+use rusqlite::Connection;
+
+fn sorted(mut ids: Vec<AtomId>) -> Vec<AtomId> {
+    ids.sort();
+    ids
+}
+
+fn listed(conn: &mut Connection, tag: &str) -> Vec<AtomId> {
+    let repo = SqliteNoteRepository::try_new(conn).unwrap();
+    let items = NoteService::new(repo)
+        .list_notes(Some(tag.to_string()), Some(50), 0)
+        .unwrap()
+        .items;
+    sorted(items.iter().map(|note| note.atom_id).collect())
+}
+
+fn summary(nodes: &[TagTreeNode]) -> Vec<(String, u32, u32)> {
+    let mut out = Vec::new();
+    for node in nodes {
+        out.push((node.path.clone(), node.count, node.total_count));
+        out.extend(summary(&node.children));
+    }
+    out
+}
+
+#[test]
+fn normalize_tag_cleans_path_segments() {
+    assert_eq!(
+        normalize_tag(" Work / Project-X/ "),
+        Some("work/project-x".to_string())
+    );
+    assert_eq!(normalize_tag("a//b"), Some("a/b".to_string()));
+    assert_eq!(normalize_tag(" / "), None);
+}
+
+#[test]
+fn parent_tag_filters_roll_up_descendants() {
+    let mut conn = open_db_in_memory().unwrap();
+    let project = create_tagged_note(&mut conn, "project", &["work/project-x"]);
+    let ops = create_tagged_note(&mut conn, "ops", &["Work/Ops"]);
+    let root = create_tagged_note(&mut conn, "root", &["work"]);
+    let _workshop = create_tagged_note(&mut conn, "workshop", &["workshop"]);
+
+    assert_eq!(listed(&mut conn, "work"), sorted(vec![project, ops, root]));
+    assert_eq!(listed(&mut conn, "work/ops"), vec![ops]);
+
+    let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+    let filtered = NoteService::new(repo)
+        .query_notes(NoteListQuery {
+            tag_filter: Some(TagFilter::parse("work AND NOT work/project-x").unwrap()),
+            limit: Some(50),
+            ..NoteListQuery::default()
+        })
+        .unwrap();
+    let ids = sorted(filtered.items.iter().map(|note| note.atom_id).collect());
+    assert_eq!(ids, sorted(vec![ops, root]));
+}
+
+#[test]
+fn tag_tree_reports_own_and_rolled_up_counts() {
+    let mut conn = open_db_in_memory().unwrap();
+    create_tagged_note(&mut conn, "a", &["work/project-x", "work"]);
+    create_tagged_note(&mut conn, "b", &["work/ops"]);
+    create_tagged_note(&mut conn, "c", &["home/garden/roses"]);
+
+    let tree = TagService::new(SqliteTagRepository::try_new(&conn).unwrap())
+        .tag_tree()
+        .unwrap();
+    assert_eq!(
+        summary(&tree),
+        vec![
+            ("home".to_string(), 0, 1),
+            ("home/garden".to_string(), 0, 1),
+            ("home/garden/roses".to_string(), 1, 1),
+            ("work".to_string(), 1, 2),
+            ("work/ops".to_string(), 1, 1),
+            ("work/project-x".to_string(), 1, 1),
+        ]
+    );
+    assert_eq!(tree[1].children[1].name, "project-x");
+}
+
+#[test]
+fn rename_tag_moves_subtree_and_merges_collisions() {
+    let mut conn = open_db_in_memory().unwrap();
+    let project = create_tagged_note(&mut conn, "project", &["work/project-x"]);
+    let ops = create_tagged_note(&mut conn, "ops", &["work/ops"]);
+    let archived = create_tagged_note(&mut conn, "archived ops", &["archive/ops"]);
+    let smart_folder = {
+        let tree = SqliteTreeRepository::try_new(&conn).unwrap();
+        tree.create_smart_folder(
+            None,
+            "Ops",
+            &SavedSearchQuery {
+                tag: Some("work/ops".to_string()),
+                ..SavedSearchQuery::default()
+            },
+        )
+        .unwrap()
+    };
+
+    {
+        let tags = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+        assert!(matches!(
+            tags.rename_tag("work", "work/old"),
+            Err(RepoError::InvalidTag(_))
+        ));
+        assert_eq!(tags.rename_tag("Work", "archive").unwrap(), 2);
+    }
+    let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+    assert_eq!(
+        service.list_tags().unwrap(),
+        vec!["archive/ops".to_string(), "archive/project-x".to_string()]
+    );
+    assert_eq!(
+        service.get_note(project).unwrap().unwrap().tags,
+        vec!["archive/project-x".to_string()]
+    );
+
+    assert_eq!(
+        listed(&mut conn, "archive/ops"),
+        sorted(vec![ops, archived])
+    );
+    assert!(listed(&mut conn, "work").is_empty());
+    let tree = SqliteTreeRepository::try_new(&conn).unwrap();
+    let search = tree
+        .get_saved_search(smart_folder.saved_search_uuid.unwrap())
+        .unwrap()
+        .unwrap();
+    assert_eq!(search.query.tag.as_deref(), Some("archive/ops"));
+}
+
+#[test]
+fn rename_tag_rewrites_front_matter_and_inline_tags() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = create_tagged_note(
+        &mut conn,
+        "---\ntags: [Work/Ops, home]\n---\nShip #work/ops and #workshop today.",
+        &[],
+    );
+    let task = AtomService::new(SqliteAtomRepository::try_new(&conn).unwrap())
+        .create_task("call #work")
+        .unwrap();
+
+    let tags = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+    tags.rename_tag("work", "archive").unwrap();
+    assert_eq!(tags.atom_tags(task).unwrap(), vec!["archive".to_string()]);
+
+    let service = NoteService::new(SqliteNoteRepository::try_new(&mut conn).unwrap());
+    let record = service.get_note(note).unwrap().unwrap();
+    assert_eq!(
+        record.content,
+        "---\ntags: [archive/ops, home]\n---\nShip #archive/ops and #workshop today."
+    );
+    assert_eq!(
+        record.tags,
+        vec![
+            "archive/ops".to_string(),
+            "home".to_string(),
+            "workshop".to_string()
+        ]
+    );
+    assert_eq!(service.list_revisions(note).unwrap().len(), 2);
+    assert_eq!(
+        service.list_tags().unwrap(),
+        vec![
+            "archive".to_string(),
+            "archive/ops".to_string(),
+            "home".to_string(),
+            "workshop".to_string()
+        ]
+    );
+}
+
+#[test]
+fn subtree_filters_treat_like_wildcards_literally() {
+    let mut conn = open_db_in_memory().unwrap();
+    let underscored = create_tagged_note(&mut conn, "a", &["my_tag/child"]);
+    let _lookalike = create_tagged_note(&mut conn, "b", &["myxtag/child"]);
+    let _percent = create_tagged_note(&mut conn, "c", &["100%/done"]);
+
+    assert_eq!(listed(&mut conn, "my_tag"), vec![underscored]);
+    assert!(listed(&mut conn, "100").is_empty());
+}
@@ -0,0 +1,176 @@
+mod common;
+
+use common::create_tagged_note;
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    normalize_tag_color, search_all, Atom, AtomId, AtomRepository, AtomType, NoteService,
+    RepoError, SearchItem, SearchQuery, SqliteAtomRepository, SqliteNoteRepository,
+    SqliteTagRepository, TagMetadata, TagService, TagUsage,
+};
+use rusqlite::Connection;
+
+fn create_atom(conn: &Connection, kind: AtomType, content: &str, tags: &[&str]) -> AtomId {
+    let atom = Atom::new(kind, content);
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .create_atom(&atom)
+        .unwrap();
+    for tag in tags {
+        conn.execute("INSERT OR IGNORE INTO tags (name) VALUES (?1);", [tag])
+            .unwrap();
+        conn.execute(
+            "INSERT INTO atom_tags (atom_uuid, tag_id)
+             SELECT ?1, id FROM tags WHERE name = ?2;",
+            rusqlite::params![atom.uuid.to_string(), tag],
+        )
+        .unwrap();
+    }
+    atom.uuid
+}
+
+fn note_tags(conn: &mut Connection, atom_id: AtomId) -> Vec<String> {
+    let repo = SqliteNoteRepository::try_new(conn).unwrap();
+    NoteService::new(repo)
+        .get_note(atom_id)
+        .unwrap()
+        .unwrap()
+        .tags
+}
+
+#[test]
+fn list_tags_breaks_counts_down_by_atom_type() {
+    let mut conn = open_db_in_memory().unwrap();
+    create_tagged_note(&mut conn, "one", &["work"]);
+    create_tagged_note(&mut conn, "two", &["work", "home"]);
+    create_atom(&conn, AtomType::Task, "task", &["work"]);
+    create_atom(&conn, AtomType::Event, "event", &["work/meetings"]);
+    let deleted = create_atom(&conn, AtomType::Task, "gone", &["work"]);
+    SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .soft_delete_atom(deleted)
+        .unwrap();
+
+    let service = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+    let tags = service.list_tags().unwrap();
+    let names: Vec<&str> = tags.iter().map(|tag| tag.name.as_str()).collect();
+    assert_eq!(names, vec!["home", "work", "work/meetings"]);
+    assert_eq!(
+        tags[1].usage,
+        TagUsage {
+            notes: 2,
+            tasks: 1,
+            events: 0
+        }
+    );
+    assert_eq!(tags[1].usage.total(), 3);
+    assert_eq!(tags[2].usage.count(AtomType::Event), 1);
+    assert!(service.get_tag("missing").unwrap().is_none());
+}
+
+#[test]
+fn rename_merge_and_delete_rewrite_every_atom() {
+    let mut conn = open_db_in_memory().unwrap();
+    let a = create_tagged_note(&mut conn, "alpha", &["todo", "later"]);
+    let b = create_tagged_note(&mut conn, "beta", &["someday"]);
+    let c = create_tagged_note(&mut conn, "gamma", &["backlog", "obsolete"]);
+
+    {
+        let service = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+        service
+            .set_tag_metadata(
+                "someday",
+                TagMetadata {
+                    color: Some("#ABC".to_string()),
+                    ..TagMetadata::default()
+                },
+            )
+            .unwrap();
+        // Collision: `later` absorbs `todo`.
+        assert_eq!(service.rename_tag("todo", "later").unwrap(), 1);
+        assert_eq!(
+            service
+                .merge_tags(&["someday".to_string(), "backlog".to_string()], "later")
+                .unwrap(),
+            2
+        );
+        assert_eq!(service.delete_tag("obsolete").unwrap(), 1);
+        let later = service.get_tag("later").unwrap().unwrap();
+        assert_eq!(later.usage.notes, 3);
+        assert_eq!(later.metadata.color.as_deref(), Some("#aabbcc"));
+    }
+
+    assert_eq!(note_tags(&mut conn, a), vec!["later".to_string()]);
+    assert_eq!(note_tags(&mut conn, b), vec!["later".to_string()]);
+    assert_eq!(note_tags(&mut conn, c), vec!["later".to_string()]);
+
+    // FTS tag column follows the rewritten links.
+    let hits: Vec<AtomId> = search_all(&conn, &SearchQuery::new("later"))
+        .unwrap()
+        .into_iter()
+        .filter_map(|item| match item {
+            SearchItem::Atom(hit) => Some(hit.atom_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(hits.len(), 3);
+}
+
+#[test]
+fn metadata_is_validated_and_survives_untagging() {
+    let mut conn = open_db_in_memory().unwrap();
+    let note = create_tagged_note(&mut conn, "styled", &["focus"]);
+
+    {
+        let service = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+        assert!(matches!(
+            service.set_tag_metadata(
+                "focus",
+                TagMetadata {
+                    color: Some("red".to_string()),
+                    ..TagMetadata::default()
+                },
+            ),
+            Err(RepoError::InvalidTag(_))
+        ));
+        let styled = service
+            .set_tag_metadata(
+                "Focus",
+                TagMetadata {
+                    color: Some(" 1E90FF ".to_string()),
+                    icon: Some("🎯".to_string()),
+                    description: Some("  ".to_string()),
+                },
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            styled.metadata,
+            TagMetadata {
+                color: Some("#1e90ff".to_string()),
+                icon: Some("🎯".to_string()),
+                description: None,
+            }
+        );
+    }
+
+    {
+        let repo = SqliteNoteRepository::try_new(&mut conn).unwrap();
+        NoteService::new(repo)
+            .set_note_tags(note, Vec::new())
+            .unwrap();
+    }
+    let service = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+    let kept = service.get_tag("focus").unwrap().unwrap();
+    assert_eq!(kept.usage.total(), 0);
+    assert_eq!(kept.metadata.color.as_deref(), Some("#1e90ff"));
+    assert_eq!(
+        service
+            .set_tag_metadata("focus", TagMetadata::default())
+            .unwrap(),
+        None
+    );
+    assert!(service.list_tags().unwrap().is_empty());
+
+    assert_eq!(normalize_tag_color("").unwrap(), None);
+    assert!(normalize_tag_color("#12345").is_err());
+}
@@ -254,15 +254,11 @@ fn fts_search_works_after_migration_6() {
     let note = make_atom(AtomType::Note, "unique_searchable_term_xyz", None, None);
     insert_atom(&conn, &note);
 
-    let query = lazynote_core::SearchQuery {
-        text: "unique_searchable_term_xyz".to_string(),
-        kind: None,
-        limit: 10,
-        raw_fts_syntax: false,
-    };
+    let mut query = lazynote_core::SearchQuery::new("unique_searchable_term_xyz");
+    query.limit = 10;
     let results = lazynote_core::search_all(&conn, &query).unwrap();
     assert_eq!(results.len(), 1);
-    assert_eq!(results[0].atom_id, note.uuid);
+    assert_eq!(results[0].as_atom().map(|hit| hit.atom_id), Some(note.uuid));
 }
 
 // ---------------------------------------------------------------------------
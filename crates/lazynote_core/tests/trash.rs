@@ -0,0 +1,275 @@
+use lazynote_core::db::open_db_in_memory;
+use lazynote_core::{
+    search_all, Atom, AtomId, AtomRepository, AtomType, FolderDeleteMode, RepoError, SearchQuery,
+    SqliteAtomRepository, SqliteTagRepository, SqliteTrashRepository, SqliteTreeRepository,
+    TagService, TrashQuery, TrashService, TreeService,
+};
+use rusqlite::Connection;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+fn create_atom(conn: &Connection, kind: AtomType, content: &str) -> AtomId {
+    let atom = Atom::new(kind, content);
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .create_atom(&atom)
+        .unwrap();
+    atom.uuid
+}
+
+fn soft_delete(conn: &Connection, id: AtomId) {
+    SqliteAtomRepository::try_new(conn)
+        .unwrap()
+        .soft_delete_atom(id)
+        .unwrap();
+}
+
+fn set_deleted_at(conn: &Connection, id: AtomId, deleted_at: i64) {
+    conn.execute(
+        "UPDATE atoms SET deleted_at = ?2 WHERE uuid = ?1;",
+        rusqlite::params![id.to_string(), deleted_at],
+    )
+    .unwrap();
+}
+
+fn trash(conn: &Connection) -> TrashService<SqliteTrashRepository<'_>> {
+    TrashService::new(SqliteTrashRepository::try_new(conn).unwrap())
+}
+
+fn count(conn: &Connection, sql: &str, id: AtomId) -> i64 {
+    conn.query_row(sql, [id.to_string()], |row| row.get(0))
+        .unwrap()
+}
+
+#[test]
+fn trash_lists_deleted_atoms_by_deletion_time() {
+    let conn = open_db_in_memory().unwrap();
+    let active = create_atom(&conn, AtomType::Note, "active");
+    let older = create_atom(&conn, AtomType::Task, "older");
+    let newer = create_atom(&conn, AtomType::Note, "newer");
+    soft_delete(&conn, older);
+    soft_delete(&conn, newer);
+    set_deleted_at(&conn, older, 1_000);
+    set_deleted_at(&conn, newer, 2_000);
+
+    let items = trash(&conn).list_trash(&TrashQuery::default()).unwrap();
+    let ids: Vec<AtomId> = items.iter().map(|item| item.atom.uuid).collect();
+    assert_eq!(ids, vec![newer, older]);
+    assert_eq!(items[0].deleted_at, 2_000);
+    assert!(items[0].atom.is_deleted);
+    assert!(!ids.contains(&active));
+
+    let tasks = trash(&conn)
+        .list_trash(&TrashQuery {
+            kind: Some(AtomType::Task),
+            ..TrashQuery::default()
+        })
+        .unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].atom.uuid, older);
+}
+
+#[test]
+fn restore_clears_tombstone_and_reindexes() {
+    let conn = open_db_in_memory().unwrap();
+    let id = create_atom(&conn, AtomType::Task, "restorable errand");
+    soft_delete(&conn, id);
+    assert_eq!(
+        count(
+            &conn,
+            "SELECT deleted_at IS NOT NULL FROM atoms WHERE uuid = ?1;",
+            id
+        ),
+        1
+    );
+    assert!(search_all(&conn, &SearchQuery::new("restorable"))
+        .unwrap()
+        .is_empty());
+
+    trash(&conn).restore(id).unwrap();
+    let atom = SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .get_atom(id, false)
+        .unwrap();
+    assert!(atom.is_some());
+    assert_eq!(
+        count(
+            &conn,
+            "SELECT deleted_at IS NULL FROM atoms WHERE uuid = ?1;",
+            id
+        ),
+        1
+    );
+    assert_eq!(
+        search_all(&conn, &SearchQuery::new("restorable"))
+            .unwrap()
+            .len(),
+        1
+    );
+
+    assert!(matches!(
+        trash(&conn).restore(id),
+        Err(RepoError::NotFound(missing)) if missing == id
+    ));
+}
+
+#[test]
+fn restoring_a_note_reattaches_its_note_ref() {
+    let conn = open_db_in_memory().unwrap();
+    let note = create_atom(&conn, AtomType::Note, "nested note");
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let folder = tree.create_folder(None, "Projects").unwrap();
+    let node = tree
+        .create_note_ref(Some(folder.node_uuid), note, Some("Nested".to_string()))
+        .unwrap();
+    tree.delete_folder(folder.node_uuid, FolderDeleteMode::DeleteAll)
+        .unwrap();
+    assert!(tree.list_children(None).unwrap().is_empty());
+
+    trash(&conn).restore(note).unwrap();
+    let root = tree.list_children(None).unwrap();
+    assert_eq!(root.len(), 1);
+    assert_eq!(root[0].node_uuid, node.node_uuid);
+    assert_eq!(root[0].parent_uuid, None);
+}
+
+#[test]
+fn restoring_a_note_keeps_visible_note_ref_in_place() {
+    let conn = open_db_in_memory().unwrap();
+    let note = create_atom(&conn, AtomType::Note, "filed note");
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let folder = tree.create_folder(None, "Inbox").unwrap();
+    let node = tree
+        .create_note_ref(Some(folder.node_uuid), note, None)
+        .unwrap();
+    soft_delete(&conn, note);
+    assert!(tree
+        .list_children(Some(folder.node_uuid))
+        .unwrap()
+        .is_empty());
+
+    trash(&conn).restore(note).unwrap();
+    let children = tree.list_children(Some(folder.node_uuid)).unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].node_uuid, node.node_uuid);
+}
+
+#[test]
+fn purge_removes_dependent_rows() {
+    let conn = open_db_in_memory().unwrap();
+    let note = create_atom(&conn, AtomType::Note, "purge me #doomed");
+    let keeper = create_atom(&conn, AtomType::Task, "keeper #shared");
+    TagService::new(SqliteTagRepository::try_new(&conn).unwrap())
+        .add_tag(note, "shared")
+        .unwrap();
+    conn.execute(
+        "INSERT INTO external_mappings (provider, external_id, atom_uuid)
+         VALUES ('caldav', 'ext-1', ?1);",
+        [note.to_string()],
+    )
+    .unwrap();
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    tree.create_note_ref(None, note, None).unwrap();
+
+    assert!(matches!(
+        trash(&conn).purge(note),
+        Err(RepoError::NotFound(id)) if id == note
+    ));
+    soft_delete(&conn, note);
+    trash(&conn).purge(note).unwrap();
+
+    for sql in [
+        "SELECT COUNT(*) FROM atoms WHERE uuid = ?1;",
+        "SELECT COUNT(*) FROM atom_tags WHERE atom_uuid = ?1;",
+        "SELECT COUNT(*) FROM atoms_fts WHERE uuid = ?1;",
+        "SELECT COUNT(*) FROM external_mappings WHERE atom_uuid = ?1;",
+        "SELECT COUNT(*) FROM workspace_nodes WHERE atom_uuid = ?1;",
+    ] {
+        assert_eq!(count(&conn, sql, note), 0, "{sql}");
+    }
+    let tags = TagService::new(SqliteTagRepository::try_new(&conn).unwrap());
+    assert!(tags.get_tag("doomed").unwrap().is_none());
+    assert_eq!(tags.atom_tags(keeper).unwrap(), vec!["shared".to_string()]);
+    assert!(trash(&conn)
+        .list_trash(&TrashQuery::default())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn purge_older_than_keeps_recent_tombstones() {
+    let conn = open_db_in_memory().unwrap();
+    let now = 100 * DAY_MS;
+    let expired = create_atom(&conn, AtomType::Note, "expired");
+    let recent = create_atom(&conn, AtomType::Event, "recent");
+    let active = create_atom(&conn, AtomType::Task, "active");
+    soft_delete(&conn, expired);
+    soft_delete(&conn, recent);
+    set_deleted_at(&conn, expired, now - 31 * DAY_MS);
+    set_deleted_at(&conn, recent, now - DAY_MS);
+
+    assert_eq!(trash(&conn).purge_older_than_at(30, now).unwrap(), 1);
+    let remaining: Vec<AtomId> = trash(&conn)
+        .list_trash(&TrashQuery::default())
+        .unwrap()
+        .into_iter()
+        .map(|item| item.atom.uuid)
+        .collect();
+    assert_eq!(remaining, vec![recent]);
+
+    assert_eq!(trash(&conn).purge_older_than(0).unwrap(), 1);
+    assert!(SqliteAtomRepository::try_new(&conn)
+        .unwrap()
+        .get_atom(active, false)
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn purge_older_than_drops_expired_folder_delete_records() {
+    let conn = open_db_in_memory().unwrap();
+    let now = 100 * DAY_MS;
+    let tree = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let old = tree.create_folder(None, "Old").unwrap();
+    let recent = tree.create_folder(None, "Recent").unwrap();
+    tree.delete_folder(old.node_uuid, FolderDeleteMode::Dissolve)
+        .unwrap();
+    tree.delete_folder(recent.node_uuid, FolderDeleteMode::DeleteAll)
+        .unwrap();
+    conn.execute(
+        "UPDATE workspace_delete_ops SET created_at = ?2 WHERE folder_uuid = ?1;",
+        rusqlite::params![old.node_uuid.to_string(), now - 31 * DAY_MS],
+    )
+    .unwrap();
+    conn.execute(
+        "UPDATE workspace_delete_ops SET created_at = ?2 WHERE folder_uuid = ?1;",
+        rusqlite::params![recent.node_uuid.to_string(), now - DAY_MS],
+    )
+    .unwrap();
+
+    trash(&conn).purge_older_than_at(30, now).unwrap();
+    let remaining: Vec<_> = tree
+        .list_deleted_folders()
+        .unwrap()
+        .into_iter()
+        .map(|folder| (folder.folder_uuid, folder.mode))
+        .collect();
+    assert_eq!(
+        remaining,
+        vec![(recent.node_uuid, FolderDeleteMode::DeleteAll)]
+    );
+    assert_eq!(
+        count(
+            &conn,
+            "SELECT COUNT(*) FROM workspace_delete_op_nodes WHERE node_uuid = ?1;",
+            old.node_uuid,
+        ),
+        0
+    );
+    assert_eq!("delete_all".parse(), Ok(FolderDeleteMode::DeleteAll));
+    assert_eq!(FolderDeleteMode::Dissolve.as_str(), "dissolve");
+    assert_eq!(
+        "purge".parse::<FolderDeleteMode>(),
+        Err("purge".to_string())
+    );
+}
@@ -1,7 +1,7 @@
 use lazynote_core::db::open_db_in_memory;
 use lazynote_core::{
-    Atom, AtomRepository, AtomType, FolderDeleteMode, SqliteAtomRepository, SqliteTreeRepository,
-    TreeService, TreeServiceError, WorkspaceNodeKind,
+    Atom, AtomRepository, AtomType, FolderDeleteMode, SavedSearchQuery, SqliteAtomRepository,
+    SqliteTreeRepository, TaskStatus, TreeService, TreeServiceError, WorkspaceNodeKind,
 };
 use uuid::Uuid;
 
@@ -401,6 +401,150 @@ fn delete_folder_delete_all_soft_deletes_unique_atoms_only() {
     assert!(!shared_atom.is_deleted);
 }
 
+#[test]
+fn restore_deleted_folder_undoes_dissolve_in_original_order() {
+    let conn = setup();
+    let service = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let note_a = Atom::new(AtomType::Note, "A");
+    let note_b = Atom::new(AtomType::Note, "B");
+    insert_atom(&conn, &note_a);
+    insert_atom(&conn, &note_b);
+
+    let before = service.create_folder(None, "Before").unwrap();
+    let folder = service.create_folder(None, "Group").unwrap();
+    let after = service.create_folder(None, "After").unwrap();
+    let first = service
+        .create_note_ref(Some(folder.node_uuid), note_a.uuid, Some("A".to_string()))
+        .unwrap();
+    let second = service
+        .create_note_ref(Some(folder.node_uuid), note_b.uuid, Some("B".to_string()))
+        .unwrap();
+    service.move_node(before.node_uuid, None, Some(1)).unwrap();
+    service
+        .delete_folder(folder.node_uuid, FolderDeleteMode::Dissolve)
+        .unwrap();
+    let root_before_restore: Vec<_> = service
+        .list_children(None)
+        .unwrap()
+        .iter()
+        .map(|node| node.node_uuid)
+        .collect();
+    assert!(root_before_restore.contains(&first.node_uuid));
+
+    let deleted = service.list_deleted_folders().unwrap();
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0].folder_uuid, folder.node_uuid);
+    assert_eq!(deleted[0].mode, FolderDeleteMode::Dissolve);
+    assert_eq!(
+        deleted[0].node_uuids,
+        vec![folder.node_uuid, first.node_uuid, second.node_uuid]
+    );
+    assert!(deleted[0].atom_uuids.is_empty());
+
+    let restored = service.restore_deleted_folder(folder.node_uuid).unwrap();
+    assert!(!restored.is_deleted);
+    let root: Vec<_> = service
+        .list_children(None)
+        .unwrap()
+        .iter()
+        .map(|node| node.node_uuid)
+        .collect();
+    assert_eq!(
+        root,
+        vec![folder.node_uuid, before.node_uuid, after.node_uuid]
+    );
+    let children: Vec<_> = service
+        .list_children(Some(folder.node_uuid))
+        .unwrap()
+        .iter()
+        .map(|node| node.node_uuid)
+        .collect();
+    assert_eq!(children, vec![first.node_uuid, second.node_uuid]);
+
+    assert!(service.list_deleted_folders().unwrap().is_empty());
+    assert!(matches!(
+        service.restore_deleted_folder(folder.node_uuid),
+        Err(TreeServiceError::NodeNotFound(id)) if id == folder.node_uuid
+    ));
+}
+
+#[test]
+fn restore_deleted_folder_undoes_delete_all_with_notes() {
+    let conn = setup();
+    let atom_repo = SqliteAtomRepository::try_new(&conn).unwrap();
+    let service = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let only_here = Atom::new(AtomType::Note, "only here");
+    let shared = Atom::new(AtomType::Note, "shared");
+    insert_atom(&conn, &only_here);
+    insert_atom(&conn, &shared);
+
+    let folder = service.create_folder(None, "Target").unwrap();
+    let nested = service
+        .create_folder(Some(folder.node_uuid), "Nested")
+        .unwrap();
+    let nested_ref = service
+        .create_note_ref(Some(nested.node_uuid), only_here.uuid, None)
+        .unwrap();
+    let shared_ref = service
+        .create_note_ref(Some(folder.node_uuid), shared.uuid, None)
+        .unwrap();
+    service.create_note_ref(None, shared.uuid, None).unwrap();
+
+    service
+        .delete_folder(folder.node_uuid, FolderDeleteMode::DeleteAll)
+        .unwrap();
+    let deleted = service.list_deleted_folders().unwrap();
+    assert_eq!(deleted[0].mode, FolderDeleteMode::DeleteAll);
+    assert_eq!(deleted[0].node_uuids.len(), 4);
+    assert_eq!(deleted[0].atom_uuids, vec![only_here.uuid]);
+
+    service.restore_deleted_folder(folder.node_uuid).unwrap();
+    assert!(
+        !atom_repo
+            .get_atom(only_here.uuid, true)
+            .unwrap()
+            .unwrap()
+            .is_deleted
+    );
+    let children: Vec<_> = service
+        .list_children(Some(folder.node_uuid))
+        .unwrap()
+        .iter()
+        .map(|node| node.node_uuid)
+        .collect();
+    assert_eq!(children, vec![nested.node_uuid, shared_ref.node_uuid]);
+    let nested_children = service.list_children(Some(nested.node_uuid)).unwrap();
+    assert_eq!(nested_children.len(), 1);
+    assert_eq!(nested_children[0].node_uuid, nested_ref.node_uuid);
+}
+
+#[test]
+fn restore_deleted_folder_falls_back_to_root_without_parent() {
+    let conn = setup();
+    let service = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let parent = service.create_folder(None, "Parent").unwrap();
+    let child = service
+        .create_folder(Some(parent.node_uuid), "Child")
+        .unwrap();
+    service
+        .delete_folder(child.node_uuid, FolderDeleteMode::DeleteAll)
+        .unwrap();
+    service
+        .delete_folder(parent.node_uuid, FolderDeleteMode::DeleteAll)
+        .unwrap();
+
+    let restored = service.restore_deleted_folder(child.node_uuid).unwrap();
+    assert_eq!(restored.parent_uuid, None);
+    let root = service.list_children(None).unwrap();
+    assert_eq!(root.len(), 1);
+    assert_eq!(root[0].node_uuid, child.node_uuid);
+
+    assert!(matches!(
+        service.restore_deleted_folder(Uuid::new_v4()),
+        Err(TreeServiceError::NodeNotFound(_))
+    ));
+}
+
 #[test]
 fn move_node_rolls_back_when_reorder_fails() {
     let conn = setup();
@@ -448,3 +592,203 @@ fn move_node_rolls_back_when_reorder_fails() {
     let target_ids: Vec<_> = target_children.iter().map(|item| item.node_uuid).collect();
     assert!(!target_ids.contains(&moving.node_uuid));
 }
+
+fn tag_atom(conn: &rusqlite::Connection, atom: &Atom, tag: &str) {
+    conn.execute("INSERT OR IGNORE INTO tags (name) VALUES (?1);", [tag])
+        .unwrap();
+    conn.execute(
+        "INSERT INTO atom_tags (atom_uuid, tag_id)
+         SELECT ?1, id FROM tags WHERE name = ?2;",
+        [atom.uuid.to_string(), tag.to_string()],
+    )
+    .unwrap();
+}
+
+fn task_with_status(content: &str, status: Option<TaskStatus>) -> Atom {
+    let mut atom = Atom::new(AtomType::Task, content);
+    atom.task_status = status;
+    atom
+}
+
+#[test]
+fn smart_folder_lists_matching_atoms_live() {
+    let conn = setup();
+    let service = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+
+    let open_task = task_with_status("ship build", Some(TaskStatus::Todo));
+    let done_task = task_with_status("write notes", Some(TaskStatus::Done));
+    let untagged_task = task_with_status("other work", Some(TaskStatus::InProgress));
+    let tagged_note = Atom::new(AtomType::Note, "release plan");
+    for atom in [&open_task, &done_task, &untagged_task, &tagged_note] {
+        insert_atom(&conn, atom);
+    }
+    for atom in [&open_task, &done_task, &tagged_note] {
+        tag_atom(&conn, atom, "release");
+    }
+
+    let group = service.create_folder(None, "Views").unwrap();
+    let smart = service
+        .create_smart_folder(
+            Some(group.node_uuid),
+            "Open release tasks",
+            SavedSearchQuery {
+                kind: Some(AtomType::Task),
+                tag: Some(" #Release ".to_string()),
+                open_only: true,
+                ..SavedSearchQuery::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(smart.kind, WorkspaceNodeKind::SmartFolder);
+    assert!(smart.saved_search_uuid.is_some());
+    assert_eq!(
+        service.get_saved_search(smart.node_uuid).unwrap().query.tag,
+        Some("release".to_string())
+    );
+
+    let group_children = service.list_children(Some(group.node_uuid)).unwrap();
+    assert_eq!(group_children.len(), 1);
+    assert_eq!(group_children[0].node_uuid, smart.node_uuid);
+
+    let children = service.list_children(Some(smart.node_uuid)).unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].kind, WorkspaceNodeKind::AtomRef);
+    assert_eq!(children[0].atom_uuid, Some(open_task.uuid));
+    assert_eq!(children[0].parent_uuid, Some(smart.node_uuid));
+    assert_eq!(children[0].display_name, "ship build");
+    assert_ne!(children[0].node_uuid, open_task.uuid);
+
+    // Live evaluation: a newly tagged open task appears without touching the node.
+    let later_task = task_with_status("cut branch", Some(TaskStatus::Todo));
+    insert_atom(&conn, &later_task);
+    tag_atom(&conn, &later_task, "release");
+    let children = service.list_children(Some(smart.node_uuid)).unwrap();
+    let ids: Vec<_> = children.iter().filter_map(|node| node.atom_uuid).collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&later_task.uuid));
+
+    service
+        .update_saved_search(
+            smart.node_uuid,
+            SavedSearchQuery {
+                text: "notes".to_string(),
+                ..SavedSearchQuery::default()
+            },
+        )
+        .unwrap();
+    let children = service.list_children(Some(smart.node_uuid)).unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].atom_uuid, Some(done_task.uuid));
+}
+
+#[test]
+fn smart_folder_rejects_children_and_deletes_as_single_node() {
+    let conn = setup();
+    let service = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let smart = service
+        .create_smart_folder(None, "Everything", SavedSearchQuery::default())
+        .unwrap();
+
+    let err = service
+        .create_folder(Some(smart.node_uuid), "Nested")
+        .unwrap_err();
+    assert!(matches!(err, TreeServiceError::ParentMustBeFolder(id) if id == smart.node_uuid));
+
+    let err = service
+        .create_smart_folder(
+            None,
+            "Blank tag",
+            SavedSearchQuery {
+                tag: Some(" # ".to_string()),
+                ..SavedSearchQuery::default()
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(err, TreeServiceError::InvalidSavedSearchTag(_)));
+
+    let folder = service.create_folder(None, "Plain").unwrap();
+    let err = service.get_saved_search(folder.node_uuid).unwrap_err();
+    assert!(matches!(err, TreeServiceError::NodeMustBeSmartFolder(id) if id == folder.node_uuid));
+
+    let saved_search_uuid = smart.saved_search_uuid.unwrap();
+    service
+        .delete_folder(smart.node_uuid, FolderDeleteMode::DeleteAll)
+        .unwrap();
+    let roots = service.list_children(None).unwrap();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].node_uuid, folder.node_uuid);
+    let saved_searches: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM saved_searches WHERE uuid = ?1;",
+            [saved_search_uuid.to_string()],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(saved_searches, 0);
+    let err = service
+        .delete_folder(smart.node_uuid, FolderDeleteMode::DeleteAll)
+        .unwrap_err();
+    assert!(matches!(err, TreeServiceError::NodeNotFound(id) if id == smart.node_uuid));
+}
+
+#[test]
+fn smart_folder_virtual_ids_are_stable_and_distinct_per_folder() {
+    let conn = setup();
+    let service = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let note = Atom::new(AtomType::Note, "shared note");
+    insert_atom(&conn, &note);
+    tag_atom(&conn, &note, "shared");
+
+    let query = SavedSearchQuery {
+        tag: Some("shared".to_string()),
+        ..SavedSearchQuery::default()
+    };
+    let first = service
+        .create_smart_folder(None, "First", query.clone())
+        .unwrap();
+    let second = service.create_smart_folder(None, "Second", query).unwrap();
+
+    let first_children = service.list_children(Some(first.node_uuid)).unwrap();
+    let second_children = service.list_children(Some(second.node_uuid)).unwrap();
+    assert_eq!(first_children.len(), 1);
+    assert_eq!(second_children.len(), 1);
+    assert_eq!(first_children[0].atom_uuid, Some(note.uuid));
+    assert_eq!(second_children[0].atom_uuid, Some(note.uuid));
+    assert_ne!(first_children[0].node_uuid, second_children[0].node_uuid);
+    assert_ne!(first_children[0].node_uuid, note.uuid);
+
+    let again = service.list_children(Some(first.node_uuid)).unwrap();
+    assert_eq!(again[0].node_uuid, first_children[0].node_uuid);
+}
+
+#[test]
+fn smart_folder_skips_archived_atoms_and_normalizes_tag_paths() {
+    let conn = setup();
+    let service = TreeService::new(SqliteTreeRepository::try_new(&conn).unwrap());
+    let active = Atom::new(AtomType::Note, "active plan");
+    let mut archived = Atom::new(AtomType::Note, "archived plan");
+    archived.is_archived = true;
+    for atom in [&active, &archived] {
+        insert_atom(&conn, atom);
+        tag_atom(&conn, atom, "work/plans");
+    }
+
+    let smart = service
+        .create_smart_folder(
+            None,
+            "Plans",
+            SavedSearchQuery {
+                tag: Some("#Work / Plans/".to_string()),
+                ..SavedSearchQuery::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        service.get_saved_search(smart.node_uuid).unwrap().query.tag,
+        Some("work/plans".to_string())
+    );
+
+    let children = service.list_children(Some(smart.node_uuid)).unwrap();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].atom_uuid, Some(active.uuid));
+}
@@ -14,20 +14,34 @@
 use lazynote_core::db::open_db;
 use lazynote_core::{
     core_version as core_version_inner, init_logging as init_logging_inner, ping as ping_inner,
-    search_all, AtomId, AtomService, AtomType, FolderDeleteMode, NoteRecord, NoteService,
-    NoteServiceError, ScheduleEventRequest, SearchQuery, SectionAtom, SqliteAtomRepository,
-    SqliteNoteRepository, SqliteTreeRepository, TaskService, TaskServiceError, TreeRepoError,
-    TreeService, TreeServiceError, WorkspaceNode, WorkspaceNodeKind,
+    record_atom_access, refresh_embeddings, search_all, AtomFlag, AtomId, AtomService, AtomType,
+    FolderDeleteMode, HashingEmbedder, JournalEntry, JournalService, NoteListQuery, NoteRecord,
+    NoteService, NoteServiceError, NoteTemplate, NoteTemplateDraft, SavedSearchQuery,
+    ScheduleEventRequest, SearchCancelHandle, SearchError, SearchItem, SearchMode, SearchQuery,
+    SearchSession, SectionAtom, SectionFilter, SqliteAtomRepository, SqliteJournalRepository,
+    SqliteNoteRepository, SqliteTagRepository, SqliteTemplateRepository, SqliteTrashRepository,
+    SqliteTreeRepository, TagService, TagSource, TaskService, TaskServiceError, TemplateContext,
+    TemplateService, TrashQuery, TrashService, TreeRepoError, TreeService, TreeServiceError,
+    WorkspaceNode, WorkspaceNodeKind,
 };
 use log::error;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 const ENTRY_DEFAULT_LIMIT: u32 = 10;
 const ENTRY_SEARCH_MAX_LIMIT: u32 = 50;
 const ENTRY_DB_FILE_NAME: &str = "lazynote_entry.sqlite3";
 static ENTRY_DB_PATH_OVERRIDE: Mutex<Option<PathBuf>> = Mutex::new(None);
+static SEARCH_SESSIONS: Mutex<BTreeMap<String, SearchSessionSlot>> = Mutex::new(BTreeMap::new());
+
+/// Open search-as-you-type session kept between keystroke calls.
+struct SearchSessionSlot {
+    session: Arc<Mutex<SearchSession>>,
+    cancel: SearchCancelHandle,
+    limit: u32,
+}
 
 /// Minimal health-check API for FRB smoke integration.
 ///
@@ -110,6 +124,71 @@ pub struct EntrySearchResponse {
     pub applied_limit: u32,
 }
 
+/// Response envelope for search session lifecycle calls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchSessionResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Optional stable error code for machine branching.
+    pub error_code: Option<String>,
+    /// Session ID for subsequent `search_session_*` calls.
+    pub session_id: Option<String>,
+    /// Human-readable response message for diagnostics.
+    pub message: String,
+}
+
+impl SearchSessionResponse {
+    fn success(message: impl Into<String>, session_id: String) -> Self {
+        Self {
+            ok: true,
+            error_code: None,
+            session_id: Some(session_id),
+            message: message.into(),
+        }
+    }
+
+    fn failure(error_code: &str, message: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            error_code: Some(error_code.to_string()),
+            session_id: None,
+            message: message.into(),
+        }
+    }
+}
+
+/// Typed search item returned by [`entry_search_all`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntrySearchAllItem {
+    /// Hit kind (`note|task|event|folder|note_ref|tag`).
+    pub kind: String,
+    /// Stable atom ID for atom hits and note_ref hits.
+    pub atom_id: Option<String>,
+    /// Workspace node ID for folder/note_ref hits.
+    pub node_id: Option<String>,
+    /// Parent workspace node ID for non-root folder/note_ref hits.
+    pub parent_node_id: Option<String>,
+    /// Snippet for atom hits, display name for nodes, tag name for tags.
+    pub label: String,
+    /// Active atom count for tag hits.
+    pub atom_count: Option<u32>,
+}
+
+/// Search response envelope for typed single-entry search flow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntrySearchAllResponse {
+    /// Whether search execution succeeded.
+    pub ok: bool,
+    /// Optional stable error code for machine branching.
+    pub error_code: Option<String>,
+    /// Typed results: workspace nodes, tags, then atoms.
+    pub items: Vec<EntrySearchAllItem>,
+    /// Human-readable response message for diagnostics.
+    pub message: String,
+    /// Effective applied search limit.
+    pub applied_limit: u32,
+}
+
 /// Generic action response envelope for single-entry command flow.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EntryActionResponse {
@@ -154,6 +233,12 @@ pub struct NoteItem {
     pub updated_at: i64,
     /// Normalized tags attached to the note.
     pub tags: Vec<String>,
+    /// Hidden from default lists; not deleted.
+    pub is_archived: bool,
+    /// Sorted first in lists.
+    pub is_pinned: bool,
+    /// User favorite.
+    pub is_starred: bool,
 }
 
 /// Note create/update/get response envelope.
@@ -197,6 +282,25 @@ pub struct TagsListResponse {
     pub tags: Vec<String>,
 }
 
+/// Atom tag mutation response envelope (any atom type).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomTagsResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Target atom ID on success.
+    pub atom_id: Option<String>,
+    /// Normalized tags on the atom after the mutation.
+    pub tags: Vec<String>,
+    /// Subset of `tags` derived from the content (inline `#hashtags` and
+    /// front-matter `tags`); these go away by editing the content, not via
+    /// `atom_remove_tag`.
+    pub inline_tags: Vec<String>,
+}
+
 /// Workspace action response envelope.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WorkspaceActionResponse {
@@ -213,11 +317,11 @@ pub struct WorkspaceActionResponse {
 pub struct WorkspaceNodeItem {
     /// Stable workspace node id.
     pub node_id: String,
-    /// Node kind label (`folder|note_ref`).
+    /// Node kind label (`folder|note_ref|smart_folder|atom_ref`).
     pub kind: String,
     /// Parent node id for non-root nodes.
     pub parent_node_id: Option<String>,
-    /// Target note atom id for note_ref nodes.
+    /// Target atom id for note_ref and virtual atom_ref nodes.
     pub atom_id: Option<String>,
     /// User-facing display name.
     pub display_name: String,
@@ -251,6 +355,36 @@ pub struct WorkspaceListChildrenResponse {
     pub items: Vec<WorkspaceNodeItem>,
 }
 
+/// Undoable folder deletion DTO exposed over FFI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeletedFolderItem {
+    /// Deleted folder node id.
+    pub node_id: String,
+    /// Folder display name.
+    pub display_name: String,
+    /// Delete mode label (`dissolve|delete_all`).
+    pub mode: String,
+    /// Deletion time in epoch milliseconds.
+    pub deleted_at: i64,
+    /// Number of nodes the delete moved or removed, folder included.
+    pub node_count: u32,
+    /// Number of notes the delete moved to the trash.
+    pub note_count: u32,
+}
+
+/// Deleted-folder list response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeletedFoldersResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Undoable folder deletions, newest first.
+    pub items: Vec<DeletedFolderItem>,
+}
+
 #[derive(Debug)]
 enum WorkspaceFfiError {
     InvalidNodeId(String),
@@ -261,10 +395,14 @@ enum WorkspaceFfiError {
     NodeNotFound(String),
     ParentNotFound(String),
     NodeNotFolder(String),
+    NodeNotSmartFolder(String),
     ParentNotFolder(String),
     AtomNotFound(String),
     AtomNotNote(String),
+    SavedSearchNotFound(String),
+    InvalidSavedSearch(String),
     CycleDetected(String),
+    UnresolvedLinkNotFound(String),
     DbBusy(String),
     DbError(String),
     Internal(String),
@@ -281,10 +419,14 @@ impl WorkspaceFfiError {
             Self::NodeNotFound(_) => "node_not_found",
             Self::ParentNotFound(_) => "parent_not_found",
             Self::NodeNotFolder(_) => "node_not_folder",
+            Self::NodeNotSmartFolder(_) => "node_not_smart_folder",
             Self::ParentNotFolder(_) => "parent_not_folder",
             Self::AtomNotFound(_) => "atom_not_found",
             Self::AtomNotNote(_) => "atom_not_note",
+            Self::SavedSearchNotFound(_) => "saved_search_not_found",
+            Self::InvalidSavedSearch(_) => "invalid_saved_search",
             Self::CycleDetected(_) => "cycle_detected",
+            Self::UnresolvedLinkNotFound(_) => "unresolved_link_not_found",
             Self::DbBusy(_) => "db_busy",
             Self::DbError(_) => "db_error",
             Self::Internal(_) => "internal_error",
@@ -303,10 +445,16 @@ impl WorkspaceFfiError {
             Self::NodeNotFound(value) => format!("workspace node not found: {value}"),
             Self::ParentNotFound(value) => format!("workspace parent not found: {value}"),
             Self::NodeNotFolder(value) => format!("workspace node is not a folder: {value}"),
+            Self::NodeNotSmartFolder(value) => {
+                format!("workspace node is not a smart folder: {value}")
+            }
             Self::ParentNotFolder(value) => format!("workspace parent is not a folder: {value}"),
             Self::AtomNotFound(value) => format!("workspace atom not found: {value}"),
             Self::AtomNotNote(value) => format!("workspace atom is not a note: {value}"),
+            Self::SavedSearchNotFound(value) => format!("saved search not found: {value}"),
+            Self::InvalidSavedSearch(value) => format!("invalid saved search: {value}"),
             Self::CycleDetected(value) => format!("workspace cycle detected: {value}"),
+            Self::UnresolvedLinkNotFound(value) => format!("unresolved link not found: {value}"),
             Self::DbBusy(value) => format!("workspace database busy: {value}"),
             Self::DbError(value) => format!("workspace database error: {value}"),
             Self::Internal(value) => format!("workspace internal error: {value}"),
@@ -319,6 +467,10 @@ enum NotesFfiError {
     InvalidNoteId(String),
     InvalidTag(String),
     NoteNotFound(String),
+    RevisionNotFound(String),
+    InvalidTemplateId(String),
+    TemplateNotFound(String),
+    InvalidTemplate(String),
     DbBusy(String),
     DbError(String),
     InvalidArgument(String),
@@ -331,6 +483,10 @@ impl NotesFfiError {
             Self::InvalidNoteId(_) => "invalid_note_id",
             Self::InvalidTag(_) => "invalid_tag",
             Self::NoteNotFound(_) => "note_not_found",
+            Self::RevisionNotFound(_) => "revision_not_found",
+            Self::InvalidTemplateId(_) => "invalid_template_id",
+            Self::TemplateNotFound(_) => "template_not_found",
+            Self::InvalidTemplate(_) => "invalid_template",
             Self::DbBusy(_) => "db_busy",
             Self::DbError(_) => "db_error",
             Self::InvalidArgument(_) => "invalid_argument",
@@ -343,6 +499,10 @@ impl NotesFfiError {
             Self::InvalidNoteId(value) => format!("invalid note id: {value}"),
             Self::InvalidTag(value) => format!("invalid tag: {value}"),
             Self::NoteNotFound(value) => format!("note not found: {value}"),
+            Self::RevisionNotFound(value) => format!("revision not found: {value}"),
+            Self::InvalidTemplateId(value) => format!("invalid template id: {value}"),
+            Self::TemplateNotFound(value) => format!("note template not found: {value}"),
+            Self::InvalidTemplate(value) => format!("invalid note template: {value}"),
             Self::DbBusy(value) => format!("notes database busy: {value}"),
             Self::DbError(value) => format!("notes database error: {value}"),
             Self::InvalidArgument(value) => format!("invalid argument: {value}"),
@@ -373,46 +533,106 @@ fn entry_search_impl(
     kind: Option<String>,
     limit: Option<u32>,
 ) -> EntrySearchResponse {
-    let normalized_limit = normalize_entry_limit(limit);
-    let query_text = text.trim().to_string();
-    let parsed_kind = match parse_entry_search_kind(kind) {
-        Ok(parsed) => parsed,
-        Err(err) => {
-            return EntrySearchResponse {
-                ok: false,
-                error_code: Some("invalid_kind".to_string()),
-                items: Vec::new(),
-                message: err,
-                applied_limit: normalized_limit,
-            };
-        }
-    };
-    let db_path = resolve_entry_db_path();
-    let conn = match open_db(&db_path) {
-        Ok(conn) => conn,
-        Err(err) => {
-            return EntrySearchResponse {
-                ok: false,
-                error_code: Some("db_error".to_string()),
-                items: Vec::new(),
-                message: format!("entry_search failed: {err}"),
-                applied_limit: normalized_limit,
-            };
-        }
-    };
+    run_entry_atom_search("entry_search", text, kind, limit, SearchMode::Keyword)
+}
 
-    let query = SearchQuery {
-        text: query_text,
-        kind: parsed_kind,
-        limit: normalized_limit,
-        raw_fts_syntax: false,
-    };
+/// Searches atoms by keywords and meaning for single-entry flow.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - Same `kind`/`limit` semantics and envelope as [`entry_search`].
+/// - Fuses keyword rank with local embedding similarity, so atoms that share
+///   no literal term with `text` can match.
+/// - Read-only: embeddings come from [`search_refresh_embeddings`].
+#[flutter_rust_bridge::frb]
+pub async fn entry_search_semantic(
+    text: String,
+    kind: Option<String>,
+    limit: Option<u32>,
+) -> EntrySearchResponse {
+    entry_search_semantic_impl(text, kind, limit)
+}
+
+fn entry_search_semantic_impl(
+    text: String,
+    kind: Option<String>,
+    limit: Option<u32>,
+) -> EntrySearchResponse {
+    run_entry_atom_search(
+        "entry_search_semantic",
+        text,
+        kind,
+        limit,
+        SearchMode::Hybrid,
+    )
+}
+
+/// Embeddings refresh response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddingsRefreshResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Number of atoms whose embedding was (re)computed.
+    pub refreshed: u32,
+}
+
+/// Embeds atoms whose stored vector is missing or out of date.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - [`entry_search_semantic`] only reads stored embeddings; call this on
+///   app start and after bulk edits so new wording is found by meaning.
+#[flutter_rust_bridge::frb]
+pub async fn search_refresh_embeddings() -> EmbeddingsRefreshResponse {
+    search_refresh_embeddings_impl()
+}
+
+fn search_refresh_embeddings_impl() -> EmbeddingsRefreshResponse {
+    let db_path = resolve_entry_db_path();
+    let refreshed = open_db(&db_path)
+        .map_err(|err| ("db_error", err.to_string()))
+        .and_then(|conn| {
+            refresh_embeddings(&conn, &HashingEmbedder::default())
+                .map_err(|err| ("internal_error", err.to_string()))
+        });
+    match refreshed {
+        Ok(refreshed) => EmbeddingsRefreshResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Refreshed {refreshed} embedding(s)."),
+            refreshed: u32::try_from(refreshed).unwrap_or(u32::MAX),
+        },
+        Err((error_code, message)) => EmbeddingsRefreshResponse {
+            ok: false,
+            error_code: Some(error_code.to_string()),
+            message: format!("search_refresh_embeddings failed: {message}"),
+            refreshed: 0,
+        },
+    }
+}
 
-    match search_all(&conn, &query) {
+fn run_entry_atom_search(
+    operation: &str,
+    text: String,
+    kind: Option<String>,
+    limit: Option<u32>,
+    mode: SearchMode,
+) -> EntrySearchResponse {
+    let normalized_limit = normalize_entry_limit(limit);
+    match run_entry_search(text, kind, normalized_limit, false, mode) {
         Ok(hits) => {
             let items = hits
                 .into_iter()
-                .map(to_entry_search_item)
+                .filter_map(|item| match item {
+                    SearchItem::Atom(hit) => Some(to_entry_search_item(hit)),
+                    _ => None,
+                })
                 .collect::<Vec<_>>();
             let message = if items.is_empty() {
                 "No results.".to_string()
@@ -427,112 +647,387 @@ fn entry_search_impl(
                 applied_limit: normalized_limit,
             }
         }
-        Err(err) => EntrySearchResponse {
+        Err((error_code, message)) => EntrySearchResponse {
             ok: false,
-            error_code: Some("internal_error".to_string()),
+            error_code: Some(error_code.to_string()),
             items: Vec::new(),
-            message: format!("entry_search failed: {err}"),
+            message: format!("{operation} failed: {message}"),
             applied_limit: normalized_limit,
         },
     }
 }
 
-fn parse_entry_search_kind(raw: Option<String>) -> Result<Option<AtomType>, String> {
-    let Some(value) = raw else {
-        return Ok(None);
-    };
-    let normalized = value.trim().to_ascii_lowercase();
-    if normalized.is_empty() {
-        return Err("invalid kind: blank value is not allowed".to_string());
-    }
-    if normalized == "all" {
-        return Ok(None);
-    }
-    match normalized.as_str() {
-        "note" => Ok(Some(AtomType::Note)),
-        "task" => Ok(Some(AtomType::Task)),
-        "event" => Ok(Some(AtomType::Event)),
-        _ => Err(format!(
-            "invalid kind `{value}`; expected one of all|note|task|event"
-        )),
-    }
-}
-
-/// Creates a note from single-entry command flow.
+/// Searches atoms, tags and workspace node names for single-entry flow.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
 /// - Never panics.
-/// - Returns operation result and created atom ID on success.
+/// - Same `kind`/`limit` semantics as [`entry_search`]; a non-`all` kind
+///   disables tag and workspace name matching.
+/// - Items are ordered workspace nodes, tags, then atoms.
 #[flutter_rust_bridge::frb]
-pub async fn entry_create_note(content: String) -> EntryActionResponse {
-    entry_create_note_impl(content)
+pub async fn entry_search_all(
+    text: String,
+    kind: Option<String>,
+    limit: Option<u32>,
+) -> EntrySearchAllResponse {
+    entry_search_all_impl(text, kind, limit)
 }
 
-fn entry_create_note_impl(content: String) -> EntryActionResponse {
-    match with_atom_service(|service| service.create_note(content.trim().to_string())) {
-        Ok(atom_id) => EntryActionResponse::success("Note created.", atom_id.to_string()),
-        Err(err) => EntryActionResponse::failure(format!("entry_create_note failed: {err}")),
+fn entry_search_all_impl(
+    text: String,
+    kind: Option<String>,
+    limit: Option<u32>,
+) -> EntrySearchAllResponse {
+    let normalized_limit = normalize_entry_limit(limit);
+    match run_entry_search(text, kind, normalized_limit, true, SearchMode::Keyword) {
+        Ok(hits) => {
+            let items = hits
+                .into_iter()
+                .map(to_entry_search_all_item)
+                .collect::<Vec<_>>();
+            let message = if items.is_empty() {
+                "No results.".to_string()
+            } else {
+                format!("Found {} result(s).", items.len())
+            };
+            EntrySearchAllResponse {
+                ok: true,
+                error_code: None,
+                items,
+                message,
+                applied_limit: normalized_limit,
+            }
+        }
+        Err((error_code, message)) => EntrySearchAllResponse {
+            ok: false,
+            error_code: Some(error_code.to_string()),
+            items: Vec::new(),
+            message: format!("entry_search_all failed: {message}"),
+            applied_limit: normalized_limit,
+        },
     }
 }
 
-/// Creates a task from single-entry command flow.
+/// Opens a search-as-you-type session bound to the entry database.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
 /// - Never panics.
-/// - Returns operation result and created atom ID on success.
+/// - `kind` and `limit` follow [`entry_search`] semantics and stay fixed for
+///   the session lifetime.
+/// - Callers must release the session with [`search_session_close`].
 #[flutter_rust_bridge::frb]
-pub async fn entry_create_task(content: String) -> EntryActionResponse {
-    entry_create_task_impl(content)
+pub async fn search_session_open(
+    kind: Option<String>,
+    limit: Option<u32>,
+) -> SearchSessionResponse {
+    search_session_open_impl(kind, limit)
 }
 
-fn entry_create_task_impl(content: String) -> EntryActionResponse {
-    match with_atom_service(|service| service.create_task(content.trim().to_string())) {
-        Ok(atom_id) => EntryActionResponse::success("Task created.", atom_id.to_string()),
-        Err(err) => EntryActionResponse::failure(format!("entry_create_task failed: {err}")),
+fn search_session_open_impl(kind: Option<String>, limit: Option<u32>) -> SearchSessionResponse {
+    let parsed_kind = match parse_entry_search_kind(kind) {
+        Ok(value) => value,
+        Err(err) => return SearchSessionResponse::failure("invalid_kind", err),
+    };
+    let normalized_limit = normalize_entry_limit(limit);
+    let db_path = resolve_entry_db_path();
+    let conn = match open_db(&db_path) {
+        Ok(conn) => conn,
+        Err(err) => {
+            return SearchSessionResponse::failure(
+                "db_error",
+                format!("search_session_open failed: {err}"),
+            )
+        }
+    };
+
+    let mut session = SearchSession::new(conn);
+    session.set_kind(parsed_kind);
+    session.set_limit(normalized_limit);
+    let cancel = session.cancel_handle();
+    let session_id = Uuid::new_v4().to_string();
+    let slot = SearchSessionSlot {
+        session: Arc::new(Mutex::new(session)),
+        cancel,
+        limit: normalized_limit,
+    };
+    match SEARCH_SESSIONS.lock() {
+        Ok(mut sessions) => {
+            sessions.insert(session_id.clone(), slot);
+            SearchSessionResponse::success("Search session opened.", session_id)
+        }
+        Err(_) => SearchSessionResponse::failure(
+            "internal_error",
+            "search_session_open failed: session registry lock poisoned",
+        ),
     }
 }
 
-/// Schedules an event from single-entry command flow.
+/// Runs one keystroke query inside an open search session.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Accepts point (`end_epoch_ms=None`) and range (`Some(end)`) shapes.
 /// - Never panics.
-/// - Returns operation result and created atom ID on success.
+/// - Cancels any in-flight query of the same session first; the superseded
+///   call returns `error_code = "cancelled"`.
+/// - Extending the previous text narrows cached results without a rescan.
 #[flutter_rust_bridge::frb]
-pub async fn entry_schedule(
-    title: String,
-    start_epoch_ms: i64,
-    end_epoch_ms: Option<i64>,
-) -> EntryActionResponse {
-    entry_schedule_impl(title, start_epoch_ms, end_epoch_ms)
+pub async fn search_session_query(session_id: String, text: String) -> EntrySearchResponse {
+    search_session_query_impl(session_id, text)
 }
 
-fn entry_schedule_impl(
-    title: String,
-    start_epoch_ms: i64,
-    end_epoch_ms: Option<i64>,
-) -> EntryActionResponse {
-    let request = ScheduleEventRequest {
-        title: title.trim().to_string(),
-        start_epoch_ms,
-        end_epoch_ms,
+fn search_session_query_impl(session_id: String, text: String) -> EntrySearchResponse {
+    let failure = |error_code: &str, message: String, applied_limit: u32| EntrySearchResponse {
+        ok: false,
+        error_code: Some(error_code.to_string()),
+        items: Vec::new(),
+        message: format!("search_session_query failed: {message}"),
+        applied_limit,
+    };
+    let (session, limit) = match lookup_search_session(session_id.as_str()) {
+        Ok(Some(slot)) => {
+            slot.1.cancel();
+            (slot.0, slot.2)
+        }
+        Ok(None) => {
+            return failure(
+                "session_not_found",
+                format!("search session not found: {session_id}"),
+                ENTRY_DEFAULT_LIMIT,
+            )
+        }
+        Err(message) => return failure("internal_error", message, ENTRY_DEFAULT_LIMIT),
     };
-    match with_atom_service(|service| service.schedule_event(&request)) {
-        Ok(atom_id) => EntryActionResponse::success("Event scheduled.", atom_id.to_string()),
-        Err(err) => EntryActionResponse::failure(format!("entry_schedule failed: {err}")),
-    }
-}
 
-/// Creates one note from markdown content.
-///
-/// # FFI contract
-/// - Async call, DB-backed execution.
-/// - Applies markdown preview hooks (`preview_text`, `preview_image`).
-/// - Returns typed envelope with stable error codes.
-#[flutter_rust_bridge::frb]
+    let mut guard = match session.lock() {
+        Ok(guard) => guard,
+        Err(_) => {
+            return failure(
+                "internal_error",
+                "search session lock poisoned".to_string(),
+                limit,
+            )
+        }
+    };
+    match guard.search(text.as_str()) {
+        Ok(result) => {
+            let items = result
+                .hits
+                .into_iter()
+                .map(to_entry_search_item)
+                .collect::<Vec<_>>();
+            let message = if items.is_empty() {
+                "No results.".to_string()
+            } else {
+                format!("Found {} result(s).", items.len())
+            };
+            EntrySearchResponse {
+                ok: true,
+                error_code: None,
+                items,
+                message,
+                applied_limit: limit,
+            }
+        }
+        Err(SearchError::Cancelled) => failure(
+            "cancelled",
+            "superseded by a newer query".to_string(),
+            limit,
+        ),
+        Err(err) => failure("internal_error", err.to_string(), limit),
+    }
+}
+
+/// Cancels the in-flight query of a search session, if any.
+///
+/// # FFI contract
+/// - Sync call, non-blocking; safe to call from the UI thread.
+/// - Never panics.
+/// - The session stays open.
+#[flutter_rust_bridge::frb(sync)]
+pub fn search_session_cancel(session_id: String) -> SearchSessionResponse {
+    search_session_cancel_impl(session_id)
+}
+
+fn search_session_cancel_impl(session_id: String) -> SearchSessionResponse {
+    match lookup_search_session(session_id.as_str()) {
+        Ok(Some((_, cancel, _))) => {
+            cancel.cancel();
+            SearchSessionResponse::success("Search cancelled.", session_id)
+        }
+        Ok(None) => SearchSessionResponse::failure(
+            "session_not_found",
+            format!("search_session_cancel failed: search session not found: {session_id}"),
+        ),
+        Err(message) => SearchSessionResponse::failure(
+            "internal_error",
+            format!("search_session_cancel failed: {message}"),
+        ),
+    }
+}
+
+/// Closes a search session and releases its connection.
+///
+/// # FFI contract
+/// - Sync call, non-blocking.
+/// - Never panics.
+/// - Cancels any in-flight query of the session.
+#[flutter_rust_bridge::frb(sync)]
+pub fn search_session_close(session_id: String) -> SearchSessionResponse {
+    search_session_close_impl(session_id)
+}
+
+fn search_session_close_impl(session_id: String) -> SearchSessionResponse {
+    let removed = match SEARCH_SESSIONS.lock() {
+        Ok(mut sessions) => sessions.remove(session_id.as_str()),
+        Err(_) => {
+            return SearchSessionResponse::failure(
+                "internal_error",
+                "search_session_close failed: session registry lock poisoned",
+            )
+        }
+    };
+    match removed {
+        Some(slot) => {
+            slot.cancel.cancel();
+            SearchSessionResponse::success("Search session closed.", session_id)
+        }
+        None => SearchSessionResponse::failure(
+            "session_not_found",
+            format!("search_session_close failed: search session not found: {session_id}"),
+        ),
+    }
+}
+
+type SearchSessionHandles = (Arc<Mutex<SearchSession>>, SearchCancelHandle, u32);
+
+fn lookup_search_session(session_id: &str) -> Result<Option<SearchSessionHandles>, String> {
+    let sessions = SEARCH_SESSIONS
+        .lock()
+        .map_err(|_| "session registry lock poisoned".to_string())?;
+    Ok(sessions
+        .get(session_id)
+        .map(|slot| (Arc::clone(&slot.session), slot.cancel.clone(), slot.limit)))
+}
+
+fn run_entry_search(
+    text: String,
+    kind: Option<String>,
+    limit: u32,
+    include_names: bool,
+    mode: SearchMode,
+) -> Result<Vec<SearchItem>, (&'static str, String)> {
+    let parsed_kind = parse_entry_search_kind(kind).map_err(|err| ("invalid_kind", err))?;
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|err| ("db_error", err.to_string()))?;
+
+    let mut query = SearchQuery::new(text.trim());
+    query.kind = parsed_kind;
+    query.limit = limit;
+    query.include_tags = include_names;
+    query.include_workspace_nodes = include_names;
+    query.mode = mode;
+
+    search_all(&conn, &query).map_err(|err| ("internal_error", err.to_string()))
+}
+
+fn parse_entry_search_kind(raw: Option<String>) -> Result<Option<AtomType>, String> {
+    let Some(value) = raw else {
+        return Ok(None);
+    };
+    let normalized = value.trim().to_ascii_lowercase();
+    if normalized.is_empty() {
+        return Err("invalid kind: blank value is not allowed".to_string());
+    }
+    if normalized == "all" {
+        return Ok(None);
+    }
+    match normalized.as_str() {
+        "note" => Ok(Some(AtomType::Note)),
+        "task" => Ok(Some(AtomType::Task)),
+        "event" => Ok(Some(AtomType::Event)),
+        _ => Err(format!(
+            "invalid kind `{value}`; expected one of all|note|task|event"
+        )),
+    }
+}
+
+/// Creates a note from single-entry command flow.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - Returns operation result and created atom ID on success.
+#[flutter_rust_bridge::frb]
+pub async fn entry_create_note(content: String) -> EntryActionResponse {
+    entry_create_note_impl(content)
+}
+
+fn entry_create_note_impl(content: String) -> EntryActionResponse {
+    match with_atom_service(|service| service.create_note(content.trim().to_string())) {
+        Ok(atom_id) => EntryActionResponse::success("Note created.", atom_id.to_string()),
+        Err(err) => EntryActionResponse::failure(format!("entry_create_note failed: {err}")),
+    }
+}
+
+/// Creates a task from single-entry command flow.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never panics.
+/// - Returns operation result and created atom ID on success.
+#[flutter_rust_bridge::frb]
+pub async fn entry_create_task(content: String) -> EntryActionResponse {
+    entry_create_task_impl(content)
+}
+
+fn entry_create_task_impl(content: String) -> EntryActionResponse {
+    match with_atom_service(|service| service.create_task(content.trim().to_string())) {
+        Ok(atom_id) => EntryActionResponse::success("Task created.", atom_id.to_string()),
+        Err(err) => EntryActionResponse::failure(format!("entry_create_task failed: {err}")),
+    }
+}
+
+/// Schedules an event from single-entry command flow.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Accepts point (`end_epoch_ms=None`) and range (`Some(end)`) shapes.
+/// - Never panics.
+/// - Returns operation result and created atom ID on success.
+#[flutter_rust_bridge::frb]
+pub async fn entry_schedule(
+    title: String,
+    start_epoch_ms: i64,
+    end_epoch_ms: Option<i64>,
+) -> EntryActionResponse {
+    entry_schedule_impl(title, start_epoch_ms, end_epoch_ms)
+}
+
+fn entry_schedule_impl(
+    title: String,
+    start_epoch_ms: i64,
+    end_epoch_ms: Option<i64>,
+) -> EntryActionResponse {
+    let request = ScheduleEventRequest {
+        title: title.trim().to_string(),
+        start_epoch_ms,
+        end_epoch_ms,
+    };
+    match with_atom_service(|service| service.schedule_event(&request)) {
+        Ok(atom_id) => EntryActionResponse::success("Event scheduled.", atom_id.to_string()),
+        Err(err) => EntryActionResponse::failure(format!("entry_schedule failed: {err}")),
+    }
+}
+
+/// Creates one note from markdown content.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Applies markdown preview hooks (`preview_text`, `preview_image`).
+/// - Returns typed envelope with stable error codes.
+#[flutter_rust_bridge::frb]
 pub async fn note_create(content: String) -> NoteResponse {
     note_create_impl(content)
 }
@@ -614,29 +1109,46 @@ fn note_get_impl(atom_id: String) -> NoteResponse {
     }
 }
 
-/// Lists notes with optional single-tag filter and pagination.
+/// Lists notes with optional single-tag filter, flag filters and pagination.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Returns only `AtomType::Note` rows.
+/// - Returns only `AtomType::Note` rows, pinned notes first.
+/// - Archived notes are skipped unless `include_archived` is true.
+/// - `pinned`/`starred`: `Some(value)` keeps only notes whose flag equals
+///   `value`.
 /// - Limit normalization: default 10, max 50.
 #[flutter_rust_bridge::frb]
 pub async fn notes_list(
     tag: Option<String>,
     limit: Option<u32>,
     offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
 ) -> NotesListResponse {
-    notes_list_impl(tag, limit, offset)
+    notes_list_impl(tag, limit, offset, include_archived, pinned, starred)
 }
 
 fn notes_list_impl(
     tag: Option<String>,
     limit: Option<u32>,
     offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
 ) -> NotesListResponse {
-    let resolved_offset = offset.unwrap_or(0);
+    let query = NoteListQuery {
+        tag,
+        limit,
+        offset: offset.unwrap_or(0),
+        include_archived,
+        pinned,
+        starred,
+        ..NoteListQuery::default()
+    };
 
-    match with_note_service(|service| service.list_notes(tag, limit, resolved_offset)) {
+    match with_note_service(|service| service.query_notes(query)) {
         Ok(result) => NotesListResponse {
             ok: true,
             error_code: None,
@@ -771,6 +1283,62 @@ fn workspace_create_folder_impl(
     }
 }
 
+/// Creates one workspace smart folder backed by a saved search.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `kind`: `all|note|task|event` or null for all types.
+/// - `tag`: optional tag filter; leading `#` is ignored.
+/// - `open_only`: keep only atoms with `todo|in_progress` status.
+/// - Children are evaluated live by [`workspace_list_children`].
+#[flutter_rust_bridge::frb]
+pub async fn workspace_create_smart_folder(
+    parent_node_id: Option<String>,
+    name: String,
+    query_text: String,
+    kind: Option<String>,
+    tag: Option<String>,
+    open_only: bool,
+) -> WorkspaceNodeResponse {
+    workspace_create_smart_folder_impl(parent_node_id, name, query_text, kind, tag, open_only)
+}
+
+fn workspace_create_smart_folder_impl(
+    parent_node_id: Option<String>,
+    name: String,
+    query_text: String,
+    kind: Option<String>,
+    tag: Option<String>,
+    open_only: bool,
+) -> WorkspaceNodeResponse {
+    let parsed_parent = match parse_optional_parent_node_id(parent_node_id) {
+        Ok(value) => value,
+        Err(err) => return workspace_node_failure(err),
+    };
+    let parsed_kind = match parse_entry_search_kind(kind) {
+        Ok(value) => value,
+        Err(message) => {
+            return workspace_node_failure(WorkspaceFfiError::InvalidSavedSearch(message))
+        }
+    };
+    let query = SavedSearchQuery {
+        text: query_text,
+        kind: parsed_kind,
+        tag,
+        open_only,
+    };
+
+    match with_tree_service(|service| service.create_smart_folder(parsed_parent, name, query)) {
+        Ok(node) => WorkspaceNodeResponse {
+            ok: true,
+            error_code: None,
+            message: "Workspace smart folder created.".to_string(),
+            node: Some(to_workspace_node_item(node)),
+        },
+        Err(err) => workspace_node_failure(err),
+    }
+}
+
 /// Creates one workspace note_ref under optional parent.
 ///
 /// # FFI contract
@@ -812,6 +1380,40 @@ fn workspace_create_note_ref_impl(
     }
 }
 
+/// Creates the missing note behind an unresolved `[[target_title]]` link.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `source_atom_id` must be UUID string of a note containing the link.
+/// - Returns the new note_ref node placed next to the source note's node.
+#[flutter_rust_bridge::frb]
+pub async fn workspace_create_note_from_link(
+    source_atom_id: String,
+    target_title: String,
+) -> WorkspaceNodeResponse {
+    workspace_create_note_from_link_impl(source_atom_id, target_title)
+}
+
+fn workspace_create_note_from_link_impl(
+    source_atom_id: String,
+    target_title: String,
+) -> WorkspaceNodeResponse {
+    let parsed_atom_id = match parse_workspace_atom_id(source_atom_id.as_str()) {
+        Ok(value) => value,
+        Err(err) => return workspace_node_failure(err),
+    };
+
+    match with_tree_service(|service| service.create_note_from_link(parsed_atom_id, target_title)) {
+        Ok(node) => WorkspaceNodeResponse {
+            ok: true,
+            error_code: None,
+            message: "Note created from link.".to_string(),
+            node: Some(to_workspace_node_item(node)),
+        },
+        Err(err) => workspace_node_failure(err),
+    }
+}
+
 /// Renames one workspace node.
 ///
 /// # FFI contract
@@ -907,6 +1509,72 @@ fn workspace_delete_folder_impl(node_id: String, mode: String) -> WorkspaceActio
     }
 }
 
+/// Lists workspace folder deletions that can still be undone.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Newest deletion first.
+#[flutter_rust_bridge::frb]
+pub async fn workspace_list_deleted_folders() -> DeletedFoldersResponse {
+    workspace_list_deleted_folders_impl()
+}
+
+fn workspace_list_deleted_folders_impl() -> DeletedFoldersResponse {
+    match with_tree_service(|service| service.list_deleted_folders()) {
+        Ok(folders) => DeletedFoldersResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} deleted folder(s).", folders.len()),
+            items: folders
+                .into_iter()
+                .map(|folder| DeletedFolderItem {
+                    node_id: folder.folder_uuid.to_string(),
+                    display_name: folder.display_name,
+                    mode: folder.mode.as_str().to_string(),
+                    deleted_at: folder.deleted_at,
+                    node_count: folder.node_uuids.len() as u32,
+                    note_count: folder.atom_uuids.len() as u32,
+                })
+                .collect(),
+        },
+        Err(err) => DeletedFoldersResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+        },
+    }
+}
+
+/// Undoes the latest deletion of one workspace folder.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Restores the folder at its original parent and position (root when
+///   the parent is gone), its subtree and the notes the delete trashed.
+/// - Returns `node_not_found` when the folder has no undoable deletion.
+#[flutter_rust_bridge::frb]
+pub async fn workspace_restore_folder(node_id: String) -> WorkspaceNodeResponse {
+    workspace_restore_folder_impl(node_id)
+}
+
+fn workspace_restore_folder_impl(node_id: String) -> WorkspaceNodeResponse {
+    let parsed_id = match parse_workspace_node_id(node_id.as_str()) {
+        Ok(value) => value,
+        Err(err) => return workspace_node_failure(err),
+    };
+
+    match with_tree_service(|service| service.restore_deleted_folder(parsed_id)) {
+        Ok(node) => WorkspaceNodeResponse {
+            ok: true,
+            error_code: None,
+            message: "Workspace folder restored.".to_string(),
+            node: Some(to_workspace_node_item(node)),
+        },
+        Err(err) => workspace_node_failure(err),
+    }
+}
+
 fn normalize_entry_limit(limit: Option<u32>) -> u32 {
     match limit {
         Some(0) => ENTRY_DEFAULT_LIMIT,
@@ -986,7 +1654,17 @@ fn with_note_service<T>(
     f(&mut service).map_err(map_note_service_error)
 }
 
-fn with_tree_service<T>(
+fn with_template_service<T>(
+    f: impl FnOnce(&TemplateService<SqliteTemplateRepository<'_>>) -> Result<T, NoteServiceError>,
+) -> Result<T, NotesFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(map_db_error)?;
+    let repo = SqliteTemplateRepository::try_new(&conn).map_err(map_repo_error)?;
+    let service = TemplateService::new(repo);
+    f(&service).map_err(map_note_service_error)
+}
+
+fn with_tree_service<T>(
     f: impl FnOnce(&TreeService<SqliteTreeRepository<'_>>) -> Result<T, TreeServiceError>,
 ) -> Result<T, WorkspaceFfiError> {
     let db_path = resolve_entry_db_path();
@@ -997,11 +1675,9 @@ fn with_tree_service<T>(
 }
 
 fn parse_folder_delete_mode(raw: &str) -> Result<FolderDeleteMode, WorkspaceFfiError> {
-    match raw.trim() {
-        "dissolve" => Ok(FolderDeleteMode::Dissolve),
-        "delete_all" => Ok(FolderDeleteMode::DeleteAll),
-        other => Err(WorkspaceFfiError::InvalidDeleteMode(other.to_string())),
-    }
+    raw.trim()
+        .parse()
+        .map_err(WorkspaceFfiError::InvalidDeleteMode)
 }
 
 fn parse_workspace_node_id(raw: &str) -> Result<Uuid, WorkspaceFfiError> {
@@ -1039,6 +1715,9 @@ fn to_note_item(value: NoteRecord) -> NoteItem {
         preview_image: value.preview_image,
         updated_at: value.updated_at,
         tags: value.tags,
+        is_archived: value.is_archived,
+        is_pinned: value.is_pinned,
+        is_starred: value.is_starred,
     }
 }
 
@@ -1046,6 +1725,8 @@ fn workspace_node_kind_label(kind: WorkspaceNodeKind) -> &'static str {
     match kind {
         WorkspaceNodeKind::Folder => "folder",
         WorkspaceNodeKind::NoteRef => "note_ref",
+        WorkspaceNodeKind::SmartFolder => "smart_folder",
+        WorkspaceNodeKind::AtomRef => "atom_ref",
     }
 }
 
@@ -1099,6 +1780,14 @@ fn map_note_service_error(err: NoteServiceError) -> NotesFfiError {
     match err {
         NoteServiceError::InvalidTag(value) => NotesFfiError::InvalidTag(value),
         NoteServiceError::NoteNotFound(atom_id) => NotesFfiError::NoteNotFound(atom_id.to_string()),
+        NoteServiceError::RevisionNotFound {
+            atom_id,
+            revision_id,
+        } => NotesFfiError::RevisionNotFound(format!("{atom_id}#{revision_id}")),
+        NoteServiceError::TemplateNotFound(uuid) => {
+            NotesFfiError::TemplateNotFound(uuid.to_string())
+        }
+        NoteServiceError::InvalidTemplate(details) => NotesFfiError::InvalidTemplate(details),
         NoteServiceError::Repo(repo_err) => map_repo_error(repo_err),
         NoteServiceError::InconsistentState(details) => {
             NotesFfiError::Internal(details.to_string())
@@ -1130,6 +1819,16 @@ fn map_repo_error(err: lazynote_core::RepoError) -> NotesFfiError {
             ))
         }
         lazynote_core::RepoError::InvalidData(details) => NotesFfiError::Internal(details),
+        lazynote_core::RepoError::InvalidProperty(details) => {
+            NotesFfiError::InvalidArgument(details)
+        }
+        lazynote_core::RepoError::InvalidTagFilter(details) => {
+            NotesFfiError::InvalidArgument(details)
+        }
+        lazynote_core::RepoError::InvalidTag(details) => NotesFfiError::InvalidArgument(details),
+        lazynote_core::RepoError::InvalidTemplate(details) => {
+            NotesFfiError::InvalidTemplate(details)
+        }
     }
 }
 
@@ -1158,6 +1857,9 @@ fn map_tree_repo_error(err: TreeRepoError) -> WorkspaceFfiError {
         TreeRepoError::NodeNotFolder(node_id) => {
             WorkspaceFfiError::NodeNotFolder(node_id.to_string())
         }
+        TreeRepoError::SavedSearchNotFound(search_id) => {
+            WorkspaceFfiError::SavedSearchNotFound(search_id.to_string())
+        }
         TreeRepoError::UninitializedConnection {
             expected_version,
             actual_version,
@@ -1170,6 +1872,12 @@ fn map_tree_repo_error(err: TreeRepoError) -> WorkspaceFfiError {
         TreeRepoError::MissingRequiredColumn { table, column } => WorkspaceFfiError::DbError(
             format!("missing required column `{column}` in table `{table}`"),
         ),
+        TreeRepoError::UnresolvedLinkNotFound {
+            source_atom_id,
+            target_title,
+        } => WorkspaceFfiError::UnresolvedLinkNotFound(format!(
+            "source={source_atom_id} target={target_title}"
+        )),
         TreeRepoError::InvalidData(details) => WorkspaceFfiError::Internal(details),
     }
 }
@@ -1191,6 +1899,12 @@ fn map_tree_service_error(err: TreeServiceError) -> WorkspaceFfiError {
         TreeServiceError::NodeMustBeFolder(node_id) => {
             WorkspaceFfiError::NodeNotFolder(node_id.to_string())
         }
+        TreeServiceError::NodeMustBeSmartFolder(node_id) => {
+            WorkspaceFfiError::NodeNotSmartFolder(node_id.to_string())
+        }
+        TreeServiceError::InvalidSavedSearchTag(value) => {
+            WorkspaceFfiError::InvalidSavedSearch(format!("blank tag `{value}`"))
+        }
         TreeServiceError::AtomNotFound(atom_id) => {
             WorkspaceFfiError::AtomNotFound(atom_id.to_string())
         }
@@ -1201,6 +1915,12 @@ fn map_tree_service_error(err: TreeServiceError) -> WorkspaceFfiError {
             node_uuid,
             parent_uuid,
         } => WorkspaceFfiError::CycleDetected(format!("node={node_uuid} parent={parent_uuid}")),
+        TreeServiceError::UnresolvedLinkNotFound {
+            source_atom_id,
+            target_title,
+        } => WorkspaceFfiError::UnresolvedLinkNotFound(format!(
+            "source={source_atom_id} target={target_title}"
+        )),
         TreeServiceError::Repo(repo_err) => map_tree_repo_error(repo_err),
     }
 }
@@ -1222,6 +1942,35 @@ fn to_entry_search_item(hit: lazynote_core::SearchHit) -> EntrySearchItem {
     }
 }
 
+fn to_entry_search_all_item(item: SearchItem) -> EntrySearchAllItem {
+    match item {
+        SearchItem::Atom(hit) => EntrySearchAllItem {
+            kind: atom_type_label(hit.kind).to_string(),
+            atom_id: Some(hit.atom_id.to_string()),
+            node_id: None,
+            parent_node_id: None,
+            label: hit.snippet,
+            atom_count: None,
+        },
+        SearchItem::Workspace(hit) => EntrySearchAllItem {
+            kind: workspace_node_kind_label(hit.kind).to_string(),
+            atom_id: hit.atom_uuid.map(|value| value.to_string()),
+            node_id: Some(hit.node_uuid.to_string()),
+            parent_node_id: hit.parent_uuid.map(|value| value.to_string()),
+            label: hit.display_name,
+            atom_count: None,
+        },
+        SearchItem::Tag(hit) => EntrySearchAllItem {
+            kind: "tag".to_string(),
+            atom_id: None,
+            node_id: None,
+            parent_node_id: None,
+            label: hit.name,
+            atom_count: Some(hit.atom_count),
+        },
+    }
+}
+
 fn atom_type_label(kind: AtomType) -> &'static str {
     match kind {
         AtomType::Note => "note",
@@ -1257,6 +2006,12 @@ pub struct AtomListItem {
     pub task_status: Option<String>,
     /// Update timestamp in epoch milliseconds.
     pub updated_at: i64,
+    /// Hidden from default lists; not deleted.
+    pub is_archived: bool,
+    /// Sorted first in lists.
+    pub is_pinned: bool,
+    /// User favorite.
+    pub is_starred: bool,
 }
 
 /// Section list response envelope.
@@ -1284,6 +2039,9 @@ enum AtomFfiError {
     AtomNotFound(String),
     InvalidStatus(String),
     InvalidTimeRange(String),
+    InvalidTag(String),
+    InvalidFlag(String),
+    StepNotReplayable(String),
     DbError(String),
     Internal(String),
 }
@@ -1295,6 +2053,9 @@ impl AtomFfiError {
             Self::AtomNotFound(_) => "atom_not_found",
             Self::InvalidStatus(_) => "invalid_status",
             Self::InvalidTimeRange(_) => "invalid_time_range",
+            Self::InvalidTag(_) => "invalid_tag",
+            Self::InvalidFlag(_) => "invalid_flag",
+            Self::StepNotReplayable(_) => "step_not_replayable",
             Self::DbError(_) => "db_error",
             Self::Internal(_) => "internal_error",
         }
@@ -1306,6 +2067,9 @@ impl AtomFfiError {
             Self::AtomNotFound(v) => format!("atom not found: {v}"),
             Self::InvalidStatus(v) => format!("invalid status: {v}"),
             Self::InvalidTimeRange(v) => format!("invalid time range: {v}"),
+            Self::InvalidTag(v) => format!("invalid tag: {v}"),
+            Self::InvalidFlag(v) => format!("invalid flag: {v}"),
+            Self::StepNotReplayable(v) => format!("step no longer applies: {v}"),
             Self::DbError(v) => format!("database error: {v}"),
             Self::Internal(v) => format!("internal error: {v}"),
         }
@@ -1326,15 +2090,51 @@ fn map_task_service_error(err: TaskServiceError) -> AtomFfiError {
 
 fn with_task_service<T>(
     f: impl FnOnce(&TaskService<'_, SqliteAtomRepository<'_>>) -> Result<T, TaskServiceError>,
+) -> Result<T, AtomFfiError> {
+    with_filtered_task_service(SectionFilter::default(), f)
+}
+
+fn with_filtered_task_service<T>(
+    filter: SectionFilter,
+    f: impl FnOnce(&TaskService<'_, SqliteAtomRepository<'_>>) -> Result<T, TaskServiceError>,
 ) -> Result<T, AtomFfiError> {
     let db_path = resolve_entry_db_path();
     let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
-    let repo =
-        SqliteAtomRepository::try_new(&conn).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let repo = SqliteAtomRepository::try_new(&conn)
+        .map_err(|e| AtomFfiError::DbError(e.to_string()))?
+        .with_section_filter(filter);
     let service = TaskService::new(&repo, &conn);
     f(&service).map_err(map_task_service_error)
 }
 
+fn section_flag_filter(
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
+) -> SectionFilter {
+    SectionFilter {
+        tags: None,
+        include_archived,
+        pinned,
+        starred,
+    }
+}
+
+fn with_tag_service<T>(
+    f: impl FnOnce(&TagService<SqliteTagRepository<'_>>) -> Result<T, lazynote_core::RepoError>,
+) -> Result<T, AtomFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let repo =
+        SqliteTagRepository::try_new(&conn).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let service = TagService::new(repo);
+    f(&service).map_err(|err| match err {
+        lazynote_core::RepoError::NotFound(id) => AtomFfiError::AtomNotFound(id.to_string()),
+        lazynote_core::RepoError::InvalidTag(details) => AtomFfiError::InvalidTag(details),
+        other => AtomFfiError::DbError(other.to_string()),
+    })
+}
+
 fn normalize_section_limit(limit: Option<u32>) -> u32 {
     match limit {
         Some(0) => SECTION_DEFAULT_LIMIT,
@@ -1364,6 +2164,9 @@ fn to_atom_list_item(sa: SectionAtom) -> AtomListItem {
             .to_string()
         }),
         updated_at: sa.updated_at,
+        is_archived: sa.atom.is_archived,
+        is_pinned: sa.atom.is_pinned,
+        is_starred: sa.atom.is_starred,
     }
 }
 
@@ -1382,15 +2185,30 @@ fn atom_list_failure(err: AtomFfiError, limit: u32) -> AtomListResponse {
 /// # FFI contract
 /// - Async call, DB-backed execution.
 /// - Excludes done/cancelled atoms.
+/// - Archived atoms are skipped unless `include_archived` is true;
+///   `pinned`/`starred` keep only atoms whose flag equals the given value.
 #[flutter_rust_bridge::frb]
-pub async fn tasks_list_inbox(limit: Option<u32>, offset: Option<u32>) -> AtomListResponse {
-    tasks_list_inbox_impl(limit, offset)
+pub async fn tasks_list_inbox(
+    limit: Option<u32>,
+    offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
+) -> AtomListResponse {
+    tasks_list_inbox_impl(limit, offset, include_archived, pinned, starred)
 }
 
-fn tasks_list_inbox_impl(limit: Option<u32>, offset: Option<u32>) -> AtomListResponse {
+fn tasks_list_inbox_impl(
+    limit: Option<u32>,
+    offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
+) -> AtomListResponse {
     let norm_limit = normalize_section_limit(limit);
     let norm_offset = offset.unwrap_or(0);
-    match with_task_service(|svc| svc.fetch_inbox(norm_limit, norm_offset)) {
+    let filter = section_flag_filter(include_archived, pinned, starred);
+    match with_filtered_task_service(filter, |svc| svc.fetch_inbox(norm_limit, norm_offset)) {
         Ok(items) => AtomListResponse {
             ok: true,
             error_code: None,
@@ -1408,14 +2226,27 @@ fn tasks_list_inbox_impl(limit: Option<u32>, offset: Option<u32>) -> AtomListRes
 /// - `bod_ms`/`eod_ms`: device-local day boundaries in epoch ms.
 /// - Async call, DB-backed execution.
 /// - Excludes done/cancelled atoms.
+/// - Archived atoms are skipped unless `include_archived` is true;
+///   `pinned`/`starred` keep only atoms whose flag equals the given value.
 #[flutter_rust_bridge::frb]
 pub async fn tasks_list_today(
     bod_ms: i64,
     eod_ms: i64,
     limit: Option<u32>,
     offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
 ) -> AtomListResponse {
-    tasks_list_today_impl(bod_ms, eod_ms, limit, offset)
+    tasks_list_today_impl(
+        bod_ms,
+        eod_ms,
+        limit,
+        offset,
+        include_archived,
+        pinned,
+        starred,
+    )
 }
 
 fn tasks_list_today_impl(
@@ -1423,10 +2254,16 @@ fn tasks_list_today_impl(
     eod_ms: i64,
     limit: Option<u32>,
     offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
 ) -> AtomListResponse {
     let norm_limit = normalize_section_limit(limit);
     let norm_offset = offset.unwrap_or(0);
-    match with_task_service(|svc| svc.fetch_today(bod_ms, eod_ms, norm_limit, norm_offset)) {
+    let filter = section_flag_filter(include_archived, pinned, starred);
+    match with_filtered_task_service(filter, |svc| {
+        svc.fetch_today(bod_ms, eod_ms, norm_limit, norm_offset)
+    }) {
         Ok(items) => AtomListResponse {
             ok: true,
             error_code: None,
@@ -1444,187 +2281,995 @@ fn tasks_list_today_impl(
 /// - `eod_ms`: end of today in epoch ms.
 /// - Async call, DB-backed execution.
 /// - Excludes done/cancelled atoms.
+/// - Archived atoms are skipped unless `include_archived` is true;
+///   `pinned`/`starred` keep only atoms whose flag equals the given value.
 #[flutter_rust_bridge::frb]
 pub async fn tasks_list_upcoming(
     eod_ms: i64,
     limit: Option<u32>,
     offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
+) -> AtomListResponse {
+    tasks_list_upcoming_impl(eod_ms, limit, offset, include_archived, pinned, starred)
+}
+
+fn tasks_list_upcoming_impl(
+    eod_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
+) -> AtomListResponse {
+    let norm_limit = normalize_section_limit(limit);
+    let norm_offset = offset.unwrap_or(0);
+    let filter = section_flag_filter(include_archived, pinned, starred);
+    match with_filtered_task_service(filter, |svc| {
+        svc.fetch_upcoming(eod_ms, norm_limit, norm_offset)
+    }) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} upcoming item(s).", items.len()),
+            items: items.into_iter().map(to_atom_list_item).collect(),
+            applied_limit: norm_limit,
+        },
+        Err(err) => atom_list_failure(err, norm_limit),
+    }
+}
+
+/// Updates `task_status` for any atom type (universal completion).
+///
+/// # FFI contract
+/// - `status`: one of `todo|in_progress|done|cancelled`, or null to clear (demote).
+/// - Async call, DB-backed execution.
+/// - Idempotent: setting the same status twice succeeds.
+#[flutter_rust_bridge::frb]
+pub async fn atom_update_status(atom_id: String, status: Option<String>) -> EntryActionResponse {
+    atom_update_status_impl(atom_id, status)
+}
+
+fn atom_update_status_impl(atom_id: String, status: Option<String>) -> EntryActionResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => {
+            let err = AtomFfiError::InvalidAtomId(atom_id);
+            return EntryActionResponse {
+                ok: false,
+                atom_id: None,
+                message: err.message(),
+            };
+        }
+    };
+
+    let parsed_status = match status.as_deref() {
+        None => None,
+        Some("todo") => Some(lazynote_core::TaskStatus::Todo),
+        Some("in_progress") => Some(lazynote_core::TaskStatus::InProgress),
+        Some("done") => Some(lazynote_core::TaskStatus::Done),
+        Some("cancelled") => Some(lazynote_core::TaskStatus::Cancelled),
+        Some(other) => {
+            let err = AtomFfiError::InvalidStatus(other.to_string());
+            return EntryActionResponse {
+                ok: false,
+                atom_id: None,
+                message: err.message(),
+            };
+        }
+    };
+
+    match with_task_service(|svc| svc.update_status(parsed_id, parsed_status)) {
+        Ok(()) => EntryActionResponse {
+            ok: true,
+            atom_id: Some(parsed_id.to_string()),
+            message: "Status updated.".to_string(),
+        },
+        Err(err) => EntryActionResponse {
+            ok: false,
+            atom_id: None,
+            message: err.message(),
+        },
+    }
+}
+
+/// Sets the archived, pinned or starred flag on any atom type.
+///
+/// # FFI contract
+/// - `flag`: one of `archived|pinned|starred`.
+/// - Async call, DB-backed execution.
+/// - Idempotent: setting the same value twice succeeds.
+/// - Archiving hides the atom from lists, sections and search; it is not a
+///   delete.
+#[flutter_rust_bridge::frb]
+pub async fn atom_set_flag(atom_id: String, flag: String, value: bool) -> EntryActionResponse {
+    atom_set_flag_impl(atom_id, flag, value)
+}
+
+fn atom_set_flag_impl(atom_id: String, flag: String, value: bool) -> EntryActionResponse {
+    let parsed = Uuid::parse_str(atom_id.trim())
+        .map_err(|_| AtomFfiError::InvalidAtomId(atom_id.clone()))
+        .and_then(|id| {
+            let parsed_flag = match flag.trim() {
+                "archived" => AtomFlag::Archived,
+                "pinned" => AtomFlag::Pinned,
+                "starred" => AtomFlag::Starred,
+                other => return Err(AtomFfiError::InvalidFlag(other.to_string())),
+            };
+            Ok((id, parsed_flag))
+        });
+    let result = parsed.and_then(|(id, parsed_flag)| {
+        with_task_service(|svc| svc.set_flag(id, parsed_flag, value)).map(|()| id)
+    });
+
+    match result {
+        Ok(id) => EntryActionResponse {
+            ok: true,
+            atom_id: Some(id.to_string()),
+            message: "Flag updated.".to_string(),
+        },
+        Err(err) => EntryActionResponse {
+            ok: false,
+            atom_id: None,
+            message: err.message(),
+        },
+    }
+}
+
+/// Records that the user opened an atom; feeds the search usage boost.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Fails with `atom_not_found` for missing or deleted atoms.
+/// - Stores only an aggregated count and last access time per atom.
+#[flutter_rust_bridge::frb]
+pub async fn atom_record_access(atom_id: String) -> EntryActionResponse {
+    atom_record_access_impl(atom_id)
+}
+
+fn atom_record_access_impl(atom_id: String) -> EntryActionResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => {
+            return EntryActionResponse::failure(AtomFfiError::InvalidAtomId(atom_id).message())
+        }
+    };
+
+    let db_path = resolve_entry_db_path();
+    let result = open_db(&db_path)
+        .map_err(|err| AtomFfiError::DbError(err.to_string()))
+        .and_then(|conn| {
+            record_atom_access(&conn, parsed_id)
+                .map_err(|err| AtomFfiError::Internal(err.to_string()))
+        });
+    match result {
+        Ok(true) => EntryActionResponse::success("Access recorded.", parsed_id.to_string()),
+        Ok(false) => EntryActionResponse::failure(
+            AtomFfiError::AtomNotFound(parsed_id.to_string()).message(),
+        ),
+        Err(err) => EntryActionResponse::failure(err.message()),
+    }
+}
+
+/// Replaces all tags of one atom of any type (note, task, event).
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Tags are normalized (trimmed, lowercased, deduplicated).
+/// - Replaces manual tags only; inline `#hashtags` from content are kept.
+/// - Fails with `invalid_tag` when any tag is blank and `atom_not_found`
+///   for missing or deleted atoms.
+#[flutter_rust_bridge::frb]
+pub async fn atom_set_tags(atom_id: String, tags: Vec<String>) -> AtomTagsResponse {
+    atom_set_tags_impl(atom_id, tags)
+}
+
+fn atom_set_tags_impl(atom_id: String, tags: Vec<String>) -> AtomTagsResponse {
+    atom_tags_mutation(atom_id, "Tags updated.", |svc, id| {
+        svc.set_atom_tags(id, tags)
+    })
+}
+
+/// Adds one tag to an atom of any type.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Idempotent: adding an existing tag succeeds unchanged.
+/// - Returns the atom's full tag list after the mutation.
+#[flutter_rust_bridge::frb]
+pub async fn atom_add_tag(atom_id: String, tag: String) -> AtomTagsResponse {
+    atom_add_tag_impl(atom_id, tag)
+}
+
+fn atom_add_tag_impl(atom_id: String, tag: String) -> AtomTagsResponse {
+    atom_tags_mutation(atom_id, "Tag added.", |svc, id| svc.add_tag(id, &tag))
+}
+
+/// Removes one tag from an atom of any type.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Idempotent: removing an absent tag succeeds unchanged.
+/// - Descendant tags (`tag/...`) and inline `#hashtags` stay on the atom.
+#[flutter_rust_bridge::frb]
+pub async fn atom_remove_tag(atom_id: String, tag: String) -> AtomTagsResponse {
+    atom_remove_tag_impl(atom_id, tag)
+}
+
+fn atom_remove_tag_impl(atom_id: String, tag: String) -> AtomTagsResponse {
+    atom_tags_mutation(atom_id, "Tag removed.", |svc, id| svc.remove_tag(id, &tag))
+}
+
+fn atom_tags_mutation(
+    atom_id: String,
+    success_message: &str,
+    f: impl FnOnce(
+        &TagService<SqliteTagRepository<'_>>,
+        AtomId,
+    ) -> Result<Vec<String>, lazynote_core::RepoError>,
+) -> AtomTagsResponse {
+    let result = Uuid::parse_str(atom_id.trim())
+        .map_err(|_| AtomFfiError::InvalidAtomId(atom_id))
+        .and_then(|id| {
+            with_tag_service(|svc| {
+                let tags = f(svc, id)?;
+                let inline_tags = svc
+                    .atom_tag_details(id)?
+                    .into_iter()
+                    .filter(|tag| tag.source != TagSource::Manual)
+                    .map(|tag| tag.name)
+                    .collect();
+                Ok((id, tags, inline_tags))
+            })
+        });
+    match result {
+        Ok((id, tags, inline_tags)) => AtomTagsResponse {
+            ok: true,
+            error_code: None,
+            message: success_message.to_string(),
+            atom_id: Some(id.to_string()),
+            tags,
+            inline_tags,
+        },
+        Err(err) => AtomTagsResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            atom_id: None,
+            tags: Vec::new(),
+            inline_tags: Vec::new(),
+        },
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Calendar APIs (PR-0012A)
+// ---------------------------------------------------------------------------
+
+/// Lists atoms with both `start_at` and `end_at` that overlap the given time range.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Includes all statuses (done/cancelled shown on calendar).
+/// - Range overlap: `start_at < range_end AND end_at > range_start`.
+/// - Archived atoms are skipped unless `include_archived` is true;
+///   `pinned`/`starred` keep only atoms whose flag equals the given value.
+#[flutter_rust_bridge::frb]
+pub async fn calendar_list_by_range(
+    start_ms: i64,
+    end_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
 ) -> AtomListResponse {
-    tasks_list_upcoming_impl(eod_ms, limit, offset)
+    calendar_list_by_range_impl(
+        start_ms,
+        end_ms,
+        limit,
+        offset,
+        include_archived,
+        pinned,
+        starred,
+    )
 }
 
-fn tasks_list_upcoming_impl(
-    eod_ms: i64,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> AtomListResponse {
-    let norm_limit = normalize_section_limit(limit);
-    let norm_offset = offset.unwrap_or(0);
-    match with_task_service(|svc| svc.fetch_upcoming(eod_ms, norm_limit, norm_offset)) {
-        Ok(items) => AtomListResponse {
+fn calendar_list_by_range_impl(
+    start_ms: i64,
+    end_ms: i64,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    include_archived: bool,
+    pinned: Option<bool>,
+    starred: Option<bool>,
+) -> AtomListResponse {
+    let norm_limit = normalize_section_limit(limit);
+    let norm_offset = offset.unwrap_or(0);
+    let filter = section_flag_filter(include_archived, pinned, starred);
+    match with_filtered_task_service(filter, |svc| {
+        svc.fetch_by_time_range(start_ms, end_ms, norm_limit, norm_offset)
+    }) {
+        Ok(items) => AtomListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} calendar event(s).", items.len()),
+            items: items.into_iter().map(to_atom_list_item).collect(),
+            applied_limit: norm_limit,
+        },
+        Err(err) => atom_list_failure(err, norm_limit),
+    }
+}
+
+/// Updates only `start_at` and `end_at` for a calendar event.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Validates `end_ms >= start_ms`; returns `invalid_time_range` on failure.
+/// - Returns `atom_not_found` when target atom does not exist.
+#[flutter_rust_bridge::frb]
+pub async fn calendar_update_event(
+    atom_id: String,
+    start_ms: i64,
+    end_ms: i64,
+) -> EntryActionResponse {
+    calendar_update_event_impl(atom_id, start_ms, end_ms)
+}
+
+fn calendar_update_event_impl(atom_id: String, start_ms: i64, end_ms: i64) -> EntryActionResponse {
+    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
+        Ok(id) => id,
+        Err(_) => {
+            let err = AtomFfiError::InvalidAtomId(atom_id);
+            return EntryActionResponse {
+                ok: false,
+                atom_id: None,
+                message: err.message(),
+            };
+        }
+    };
+
+    match with_task_service(|svc| svc.update_event_times(parsed_id, start_ms, end_ms)) {
+        Ok(()) => EntryActionResponse {
+            ok: true,
+            atom_id: Some(parsed_id.to_string()),
+            message: "Event times updated.".to_string(),
+        },
+        Err(err) => EntryActionResponse {
+            ok: false,
+            atom_id: None,
+            message: err.message(),
+        },
+    }
+}
+
+/// One soft-deleted atom shown in the trash view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashItem {
+    /// Stable atom ID in string form.
+    pub atom_id: String,
+    /// Atom projection kind (`note|task|event`).
+    pub kind: String,
+    /// Raw markdown content.
+    pub content: String,
+    /// Derived plain-text preview.
+    pub preview_text: Option<String>,
+    /// Deletion time in epoch milliseconds.
+    pub deleted_at: i64,
+}
+
+/// Trash list response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashListResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Trashed atoms, most recently deleted first.
+    pub items: Vec<TrashItem>,
+    /// Effective limit after normalization.
+    pub applied_limit: u32,
+}
+
+/// Trash purge response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashPurgeResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Number of atoms permanently deleted.
+    pub purged: u32,
+}
+
+fn with_trash_service<T>(
+    f: impl FnOnce(&TrashService<SqliteTrashRepository<'_>>) -> Result<T, lazynote_core::RepoError>,
+) -> Result<T, AtomFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let repo =
+        SqliteTrashRepository::try_new(&conn).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let service = TrashService::new(repo);
+    f(&service).map_err(|err| match err {
+        lazynote_core::RepoError::NotFound(id) => AtomFfiError::AtomNotFound(id.to_string()),
+        lazynote_core::RepoError::Db(err) => AtomFfiError::DbError(err.to_string()),
+        other => AtomFfiError::StepNotReplayable(other.to_string()),
+    })
+}
+
+/// Lists soft-deleted atoms, most recently deleted first.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `limit` defaults to 50 and clamps to 50.
+#[flutter_rust_bridge::frb]
+pub async fn trash_list(limit: Option<u32>, offset: Option<u32>) -> TrashListResponse {
+    trash_list_impl(limit, offset)
+}
+
+fn trash_list_impl(limit: Option<u32>, offset: Option<u32>) -> TrashListResponse {
+    let applied_limit = normalize_section_limit(limit);
+    let query = TrashQuery {
+        kind: None,
+        limit: Some(applied_limit),
+        offset: offset.unwrap_or(0),
+    };
+    match with_trash_service(|svc| svc.list_trash(&query)) {
+        Ok(items) => TrashListResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Loaded {} trashed atom(s).", items.len()),
+            items: items
+                .into_iter()
+                .map(|item| TrashItem {
+                    atom_id: item.atom.uuid.to_string(),
+                    kind: atom_type_label(item.atom.kind).to_string(),
+                    content: item.atom.content,
+                    preview_text: item.atom.preview_text,
+                    deleted_at: item.deleted_at,
+                })
+                .collect(),
+            applied_limit,
+        },
+        Err(err) => TrashListResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            items: Vec::new(),
+            applied_limit,
+        },
+    }
+}
+
+/// Restores one soft-deleted atom from the trash.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `atom_not_found` when the atom is not in the trash.
+/// - A restored note reappears in the workspace tree; its note_ref moves to
+///   the root when the original folder was deleted.
+#[flutter_rust_bridge::frb]
+pub async fn trash_restore(atom_id: String) -> EntryActionResponse {
+    trash_restore_impl(atom_id)
+}
+
+fn trash_restore_impl(atom_id: String) -> EntryActionResponse {
+    trash_action(atom_id, "Atom restored.", |svc, id| svc.restore(id))
+}
+
+/// Permanently deletes one soft-deleted atom.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Returns `atom_not_found` when the atom is not in the trash; active
+///   atoms are never purged.
+#[flutter_rust_bridge::frb]
+pub async fn trash_purge(atom_id: String) -> EntryActionResponse {
+    trash_purge_impl(atom_id)
+}
+
+fn trash_purge_impl(atom_id: String) -> EntryActionResponse {
+    trash_action(atom_id, "Atom purged.", |svc, id| svc.purge(id))
+}
+
+/// Permanently deletes atoms trashed more than `days` days ago.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `days = 0` empties the trash.
+/// - Intended to run on app start with the user's retention setting.
+#[flutter_rust_bridge::frb]
+pub async fn trash_purge_older_than(days: u32) -> TrashPurgeResponse {
+    trash_purge_older_than_impl(days)
+}
+
+fn trash_purge_older_than_impl(days: u32) -> TrashPurgeResponse {
+    match with_trash_service(|svc| svc.purge_older_than(days)) {
+        Ok(purged) => TrashPurgeResponse {
+            ok: true,
+            error_code: None,
+            message: format!("Purged {purged} atom(s)."),
+            purged,
+        },
+        Err(err) => TrashPurgeResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            purged: 0,
+        },
+    }
+}
+
+fn trash_action(
+    atom_id: String,
+    success_message: &str,
+    f: impl FnOnce(
+        &TrashService<SqliteTrashRepository<'_>>,
+        AtomId,
+    ) -> Result<(), lazynote_core::RepoError>,
+) -> EntryActionResponse {
+    let result = Uuid::parse_str(atom_id.trim())
+        .map_err(|_| AtomFfiError::InvalidAtomId(atom_id))
+        .and_then(|id| with_trash_service(|svc| f(svc, id)).map(|()| id));
+    match result {
+        Ok(id) => EntryActionResponse {
+            ok: true,
+            atom_id: Some(id.to_string()),
+            message: success_message.to_string(),
+        },
+        Err(err) => EntryActionResponse {
+            ok: false,
+            atom_id: None,
+            message: err.message(),
+        },
+    }
+}
+
+/// Undo/redo response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Replayed command
+    /// (`note_update|note_set_tags|atom_set_tags|node_move|atom_status|event_times`);
+    /// `None` when there was nothing to replay.
+    pub command: Option<String>,
+    /// Atom or workspace node id the replay wrote to.
+    pub target_id: Option<String>,
+    /// Steps that can be undone after this call.
+    pub undo_count: u32,
+    /// Steps that can be redone after this call.
+    pub redo_count: u32,
+}
+
+fn with_journal_service<T>(
+    f: impl FnOnce(&JournalService<SqliteJournalRepository<'_>>) -> Result<T, lazynote_core::RepoError>,
+) -> Result<T, AtomFfiError> {
+    let db_path = resolve_entry_db_path();
+    let conn = open_db(&db_path).map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let repo = SqliteJournalRepository::try_new(&conn)
+        .map_err(|e| AtomFfiError::DbError(e.to_string()))?;
+    let service = JournalService::new(repo);
+    f(&service).map_err(|err| match err {
+        lazynote_core::RepoError::NotFound(id) => AtomFfiError::AtomNotFound(id.to_string()),
+        lazynote_core::RepoError::Db(err) => AtomFfiError::DbError(err.to_string()),
+        other => AtomFfiError::StepNotReplayable(other.to_string()),
+    })
+}
+
+/// Undoes the newest recorded mutation.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Covers note content/tag edits, workspace moves, status changes and
+///   event time changes.
+/// - `ok=true` with `command=None` when there is nothing to undo.
+/// - Returns `atom_not_found` when the mutated atom or node is gone; that
+///   step is dropped so the next call undoes the one before it.
+#[flutter_rust_bridge::frb]
+pub async fn journal_undo() -> JournalResponse {
+    journal_undo_impl()
+}
+
+fn journal_undo_impl() -> JournalResponse {
+    journal_replay("Undone", "Nothing to undo.", |svc| svc.undo())
+}
+
+/// Redoes the last undone mutation.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - `ok=true` with `command=None` when there is nothing to redo; any new
+///   mutation after an undo clears the redo side.
+#[flutter_rust_bridge::frb]
+pub async fn journal_redo() -> JournalResponse {
+    journal_redo_impl()
+}
+
+fn journal_redo_impl() -> JournalResponse {
+    journal_replay("Redone", "Nothing to redo.", |svc| svc.redo())
+}
+
+/// Returns how many steps can be undone and redone.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Never replays anything; `command` and `target_id` are always `None`.
+#[flutter_rust_bridge::frb]
+pub async fn journal_status() -> JournalResponse {
+    journal_status_impl()
+}
+
+fn journal_status_impl() -> JournalResponse {
+    journal_replay("", "Journal status loaded.", |_| Ok(None))
+}
+
+fn journal_replay(
+    success_prefix: &str,
+    empty_message: &str,
+    f: impl FnOnce(
+        &JournalService<SqliteJournalRepository<'_>>,
+    ) -> Result<Option<JournalEntry>, lazynote_core::RepoError>,
+) -> JournalResponse {
+    let result = with_journal_service(|svc| {
+        let entry = f(svc)?;
+        Ok((entry, svc.status()?))
+    });
+    match result {
+        Ok((entry, status)) => JournalResponse {
+            ok: true,
+            error_code: None,
+            message: match &entry {
+                Some(entry) => format!("{success_prefix} {}.", entry.command()),
+                None => empty_message.to_string(),
+            },
+            command: entry.as_ref().map(|entry| entry.command().to_string()),
+            target_id: entry.map(|entry| entry.redo.target().to_string()),
+            undo_count: status.undo_count,
+            redo_count: status.redo_count,
+        },
+        Err(err) => JournalResponse {
+            ok: false,
+            error_code: Some(err.code().to_string()),
+            message: err.message(),
+            command: None,
+            target_id: None,
+            undo_count: 0,
+            redo_count: 0,
+        },
+    }
+}
+
+/// Note template DTO.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTemplateItem {
+    /// Stable template id.
+    pub template_id: String,
+    /// Display name, unique case-insensitively.
+    pub name: String,
+    /// Markdown source with `{{placeholder}}` markers.
+    pub content: String,
+    /// Normalized tags applied to created notes.
+    pub tags: Vec<String>,
+    /// Workspace folder receiving created notes.
+    pub folder_node_id: Option<String>,
+    /// Custom prompt names to ask for, in first-use order.
+    pub prompts: Vec<String>,
+    /// Update timestamp in epoch milliseconds.
+    pub updated_at: i64,
+}
+
+/// Note template save/delete response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTemplateResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Saved template; `None` on failure and after delete.
+    pub template: Option<NoteTemplateItem>,
+}
+
+/// Note template list response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTemplatesListResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Templates sorted by name.
+    pub items: Vec<NoteTemplateItem>,
+}
+
+/// Answer to one custom template prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplatePromptAnswer {
+    /// Prompt name as listed in [`NoteTemplateItem::prompts`].
+    pub name: String,
+    /// Text substituted for the prompt.
+    pub value: String,
+}
+
+/// Create-from-template response envelope.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateNoteResponse {
+    /// Whether operation succeeded.
+    pub ok: bool,
+    /// Stable machine-readable error code for failure paths.
+    pub error_code: Option<String>,
+    /// Human-readable message for diagnostics/UI.
+    pub message: String,
+    /// Created note.
+    pub note: Option<NoteItem>,
+    /// `note_ref` node filing the note, when the template has a folder.
+    pub node_id: Option<String>,
+    /// UTF-16 offset of `{{cursor}}` in note content, for the editor.
+    pub cursor_offset: Option<u32>,
+}
+
+/// Creates or updates one note template.
+///
+/// # FFI contract
+/// - Async call, DB-backed execution.
+/// - Creates a template when `template_id` is `None`, otherwise replaces
+///   every field of that template.
+/// - Returns `invalid_template` for a blank or already used name and
+///   `invalid_tag` for blank tags.
+/// - `folder_node_id` is not validated: notes land at the workspace root
+///   when it does not point at an active folder.
+#[flutter_rust_bridge::frb]
+pub async fn note_template_save(
+    template_id: Option<String>,
+    name: String,
+    content: String,
+    tags: Vec<String>,
+    folder_node_id: Option<String>,
+) -> NoteTemplateResponse {
+    note_template_save_impl(template_id, name, content, tags, folder_node_id)
+}
+
+fn note_template_save_impl(
+    template_id: Option<String>,
+    name: String,
+    content: String,
+    tags: Vec<String>,
+    folder_node_id: Option<String>,
+) -> NoteTemplateResponse {
+    let parsed_id = match template_id.as_deref().map(parse_template_id).transpose() {
+        Ok(value) => value,
+        Err(err) => return note_template_failure(err),
+    };
+    let folder_uuid = match folder_node_id {
+        None => None,
+        Some(raw) => match Uuid::parse_str(raw.trim()) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                return note_template_failure(NotesFfiError::InvalidArgument(format!(
+                    "invalid folder node id: {raw}"
+                )))
+            }
+        },
+    };
+    let draft = NoteTemplateDraft {
+        name,
+        content,
+        tags,
+        folder_uuid,
+    };
+
+    match with_template_service(|service| match parsed_id {
+        Some(uuid) => service.update_template(uuid, draft),
+        None => service.create_template(draft),
+    }) {
+        Ok(template) => NoteTemplateResponse {
             ok: true,
             error_code: None,
-            message: format!("Loaded {} upcoming item(s).", items.len()),
-            items: items.into_iter().map(to_atom_list_item).collect(),
-            applied_limit: norm_limit,
+            message: "Note template saved.".to_string(),
+            template: Some(to_note_template_item(template)),
         },
-        Err(err) => atom_list_failure(err, norm_limit),
+        Err(err) => note_template_failure(err),
     }
 }
 
-/// Updates `task_status` for any atom type (universal completion).
+/// Lists note templates sorted by name.
 ///
 /// # FFI contract
-/// - `status`: one of `todo|in_progress|done|cancelled`, or null to clear (demote).
 /// - Async call, DB-backed execution.
-/// - Idempotent: setting the same status twice succeeds.
+/// - Returns typed envelope with stable error codes.
 #[flutter_rust_bridge::frb]
-pub async fn atom_update_status(atom_id: String, status: Option<String>) -> EntryActionResponse {
-    atom_update_status_impl(atom_id, status)
+pub async fn note_templates_list() -> NoteTemplatesListResponse {
+    note_templates_list_impl()
 }
 
-fn atom_update_status_impl(atom_id: String, status: Option<String>) -> EntryActionResponse {
-    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
-        Ok(id) => id,
-        Err(_) => {
-            let err = AtomFfiError::InvalidAtomId(atom_id);
-            return EntryActionResponse {
-                ok: false,
-                atom_id: None,
-                message: err.message(),
-            };
-        }
-    };
-
-    let parsed_status = match status.as_deref() {
-        None => None,
-        Some("todo") => Some(lazynote_core::TaskStatus::Todo),
-        Some("in_progress") => Some(lazynote_core::TaskStatus::InProgress),
-        Some("done") => Some(lazynote_core::TaskStatus::Done),
-        Some("cancelled") => Some(lazynote_core::TaskStatus::Cancelled),
-        Some(other) => {
-            let err = AtomFfiError::InvalidStatus(other.to_string());
-            return EntryActionResponse {
-                ok: false,
-                atom_id: None,
-                message: err.message(),
-            };
-        }
-    };
-
-    match with_task_service(|svc| svc.update_status(parsed_id, parsed_status)) {
-        Ok(()) => EntryActionResponse {
+fn note_templates_list_impl() -> NoteTemplatesListResponse {
+    match with_template_service(|service| service.list_templates()) {
+        Ok(templates) => NoteTemplatesListResponse {
             ok: true,
-            atom_id: Some(parsed_id.to_string()),
-            message: "Status updated.".to_string(),
+            error_code: None,
+            message: format!("Loaded {} template(s).", templates.len()),
+            items: templates.into_iter().map(to_note_template_item).collect(),
         },
-        Err(err) => EntryActionResponse {
+        Err(err) => NoteTemplatesListResponse {
             ok: false,
-            atom_id: None,
+            error_code: Some(err.code().to_string()),
             message: err.message(),
+            items: Vec::new(),
         },
     }
 }
 
-// ---------------------------------------------------------------------------
-// Calendar APIs (PR-0012A)
-// ---------------------------------------------------------------------------
-
-/// Lists atoms with both `start_at` and `end_at` that overlap the given time range.
+/// Deletes one note template.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Includes all statuses (done/cancelled shown on calendar).
-/// - Range overlap: `start_at < range_end AND end_at > range_start`.
+/// - Notes already created from the template are kept.
+/// - Returns `template_not_found` when the template does not exist.
 #[flutter_rust_bridge::frb]
-pub async fn calendar_list_by_range(
-    start_ms: i64,
-    end_ms: i64,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> AtomListResponse {
-    calendar_list_by_range_impl(start_ms, end_ms, limit, offset)
+pub async fn note_template_delete(template_id: String) -> NoteTemplateResponse {
+    note_template_delete_impl(template_id)
 }
 
-fn calendar_list_by_range_impl(
-    start_ms: i64,
-    end_ms: i64,
-    limit: Option<u32>,
-    offset: Option<u32>,
-) -> AtomListResponse {
-    let norm_limit = normalize_section_limit(limit);
-    let norm_offset = offset.unwrap_or(0);
-    match with_task_service(|svc| {
-        svc.fetch_by_time_range(start_ms, end_ms, norm_limit, norm_offset)
-    }) {
-        Ok(items) => AtomListResponse {
+fn note_template_delete_impl(template_id: String) -> NoteTemplateResponse {
+    let parsed_id = match parse_template_id(template_id.as_str()) {
+        Ok(value) => value,
+        Err(err) => return note_template_failure(err),
+    };
+
+    match with_template_service(|service| service.delete_template(parsed_id)) {
+        Ok(()) => NoteTemplateResponse {
             ok: true,
             error_code: None,
-            message: format!("Loaded {} calendar event(s).", items.len()),
-            items: items.into_iter().map(to_atom_list_item).collect(),
-            applied_limit: norm_limit,
+            message: "Note template deleted.".to_string(),
+            template: None,
         },
-        Err(err) => atom_list_failure(err, norm_limit),
+        Err(err) => note_template_failure(err),
     }
 }
 
-/// Updates only `start_at` and `end_at` for a calendar event.
+/// Creates one note from a template.
 ///
 /// # FFI contract
 /// - Async call, DB-backed execution.
-/// - Validates `end_ms >= start_ms`; returns `invalid_time_range` on failure.
-/// - Returns `atom_not_found` when target atom does not exist.
+/// - Expands `{{date}}`/`{{time}}` in `utc_offset_minutes` (UTC when
+///   `None`), `{{title}}` from `title` and custom prompts from `answers`;
+///   unanswered prompts expand to empty text.
+/// - Applies the template tags and files the note in the template folder.
+/// - Returns `template_not_found` when the template does not exist.
 #[flutter_rust_bridge::frb]
-pub async fn calendar_update_event(
-    atom_id: String,
-    start_ms: i64,
-    end_ms: i64,
-) -> EntryActionResponse {
-    calendar_update_event_impl(atom_id, start_ms, end_ms)
+pub async fn note_create_from_template(
+    template_id: String,
+    title: Option<String>,
+    answers: Vec<TemplatePromptAnswer>,
+    utc_offset_minutes: Option<i32>,
+) -> TemplateNoteResponse {
+    note_create_from_template_impl(template_id, title, answers, utc_offset_minutes)
 }
 
-fn calendar_update_event_impl(atom_id: String, start_ms: i64, end_ms: i64) -> EntryActionResponse {
-    let parsed_id = match Uuid::parse_str(atom_id.trim()) {
-        Ok(id) => id,
-        Err(_) => {
-            let err = AtomFfiError::InvalidAtomId(atom_id);
-            return EntryActionResponse {
+fn note_create_from_template_impl(
+    template_id: String,
+    title: Option<String>,
+    answers: Vec<TemplatePromptAnswer>,
+    utc_offset_minutes: Option<i32>,
+) -> TemplateNoteResponse {
+    let parsed_id = match parse_template_id(template_id.as_str()) {
+        Ok(value) => value,
+        Err(err) => {
+            return TemplateNoteResponse {
                 ok: false,
-                atom_id: None,
+                error_code: Some(err.code().to_string()),
                 message: err.message(),
-            };
+                note: None,
+                node_id: None,
+                cursor_offset: None,
+            }
         }
     };
+    let context = TemplateContext {
+        title,
+        now_epoch_ms: lazynote_core::now_epoch_ms(),
+        utc_offset_minutes: utc_offset_minutes.unwrap_or(0),
+        prompts: answers
+            .into_iter()
+            .map(|answer| (answer.name.trim().to_string(), answer.value))
+            .collect(),
+    };
 
-    match with_task_service(|svc| svc.update_event_times(parsed_id, start_ms, end_ms)) {
-        Ok(()) => EntryActionResponse {
-            ok: true,
-            atom_id: Some(parsed_id.to_string()),
-            message: "Event times updated.".to_string(),
-        },
-        Err(err) => EntryActionResponse {
+    let created = with_template_service(|service| {
+        service
+            .get_template(parsed_id)?
+            .ok_or(NoteServiceError::TemplateNotFound(parsed_id))
+    })
+    .and_then(|template| {
+        with_note_service(|service| service.create_from_template(&template, &context))
+    });
+    match created {
+        Ok(created) => {
+            let cursor_offset = created
+                .cursor
+                .map(|cursor| created.note.content[..cursor].encode_utf16().count() as u32);
+            TemplateNoteResponse {
+                ok: true,
+                error_code: None,
+                message: "Note created from template.".to_string(),
+                note: Some(to_note_item(created.note)),
+                node_id: created.node_uuid.map(|uuid| uuid.to_string()),
+                cursor_offset,
+            }
+        }
+        Err(err) => TemplateNoteResponse {
             ok: false,
-            atom_id: None,
+            error_code: Some(err.code().to_string()),
             message: err.message(),
+            note: None,
+            node_id: None,
+            cursor_offset: None,
         },
     }
 }
 
+fn parse_template_id(raw: &str) -> Result<Uuid, NotesFfiError> {
+    Uuid::parse_str(raw.trim()).map_err(|_| NotesFfiError::InvalidTemplateId(raw.to_string()))
+}
+
+fn to_note_template_item(value: NoteTemplate) -> NoteTemplateItem {
+    NoteTemplateItem {
+        template_id: value.uuid.to_string(),
+        prompts: lazynote_core::template_prompts(value.content.as_str()),
+        name: value.name,
+        content: value.content,
+        tags: value.tags,
+        folder_node_id: value.folder_uuid.map(|uuid| uuid.to_string()),
+        updated_at: value.updated_at,
+    }
+}
+
+fn note_template_failure(error: NotesFfiError) -> NoteTemplateResponse {
+    NoteTemplateResponse {
+        ok: false,
+        error_code: Some(error.code().to_string()),
+        message: error.message(),
+        template: None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        calendar_list_by_range_impl, calendar_update_event_impl, configure_entry_db_path,
-        core_version, entry_create_note_impl, entry_create_task_impl, entry_schedule_impl,
-        entry_search_impl, init_logging, map_db_error, map_repo_error, map_workspace_db_error,
-        note_create_impl, note_get_impl, note_set_tags_impl, note_update_impl, notes_list_impl,
-        ping, tags_list_impl, workspace_create_folder_impl, workspace_create_note_ref_impl,
-        workspace_delete_folder_impl, workspace_list_children_impl, workspace_move_node_impl,
-        workspace_rename_node_impl, NotesFfiError, WorkspaceFfiError,
+        atom_add_tag_impl, atom_record_access_impl, atom_remove_tag_impl, atom_set_flag_impl,
+        atom_set_tags_impl, calendar_list_by_range_impl, calendar_update_event_impl,
+        configure_entry_db_path, core_version, entry_create_note_impl, entry_create_task_impl,
+        entry_schedule_impl, entry_search_all_impl, entry_search_impl, entry_search_semantic_impl,
+        init_logging, journal_redo_impl, journal_status_impl, journal_undo_impl, map_db_error,
+        map_repo_error, map_workspace_db_error, note_create_from_template_impl, note_create_impl,
+        note_get_impl, note_set_tags_impl, note_template_delete_impl, note_template_save_impl,
+        note_templates_list_impl, note_update_impl, notes_list_impl, ping,
+        search_refresh_embeddings_impl, search_session_cancel_impl, search_session_close_impl,
+        search_session_open_impl, search_session_query_impl, tags_list_impl, tasks_list_inbox_impl,
+        trash_list_impl, trash_purge_impl, trash_restore_impl, workspace_create_folder_impl,
+        workspace_create_note_from_link_impl, workspace_create_note_ref_impl,
+        workspace_create_smart_folder_impl, workspace_delete_folder_impl,
+        workspace_list_children_impl, workspace_list_deleted_folders_impl,
+        workspace_move_node_impl, workspace_rename_node_impl, workspace_restore_folder_impl,
+        NotesFfiError, TemplatePromptAnswer, WorkspaceFfiError,
     };
     use lazynote_core::db::open_db;
-    use lazynote_core::{SqliteTreeRepository, TreeService};
+    use lazynote_core::{AtomRepository, SqliteAtomRepository, SqliteTreeRepository, TreeService};
     use std::sync::{Mutex, MutexGuard};
     use std::time::{SystemTime, UNIX_EPOCH};
+    use uuid::Uuid;
 
     static TEST_DB_LOCK: Mutex<()> = Mutex::new(());
 
@@ -1706,6 +3351,312 @@ mod tests {
         assert_eq!(blank_response.error_code.as_deref(), Some("invalid_kind"));
     }
 
+    #[test]
+    fn entry_search_all_returns_folder_hits_and_entry_search_stays_atom_only() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("entry-search-folder");
+        let folder_id = create_workspace_folder(&format!("Folder {token}"));
+        let note = entry_create_note_impl(format!("note {token}"));
+        assert!(note.ok, "{}", note.message);
+
+        let typed = entry_search_all_impl(token.clone(), None, Some(10));
+        assert!(typed.ok, "{}", typed.message);
+        assert_eq!(typed.items[0].kind, "folder");
+        assert_eq!(typed.items[0].node_id.as_deref(), Some(folder_id.as_str()));
+        assert!(typed
+            .items
+            .iter()
+            .any(|item| item.kind == "note" && item.atom_id == note.atom_id));
+
+        let atoms_only = entry_search_impl(token, None, Some(10));
+        assert!(atoms_only.ok, "{}", atoms_only.message);
+        assert!(atoms_only.items.iter().all(|item| item.kind == "note"));
+    }
+
+    #[test]
+    fn atom_record_access_accepts_existing_atoms_only() {
+        let _guard = acquire_test_db_lock();
+        let note = entry_create_note_impl(format!("note {}", unique_token("record-access")));
+        let atom_id = note.atom_id.expect("created note id");
+
+        let recorded = atom_record_access_impl(atom_id.clone());
+        assert!(recorded.ok, "{}", recorded.message);
+        assert_eq!(recorded.atom_id.as_deref(), Some(atom_id.as_str()));
+
+        let missing = atom_record_access_impl(Uuid::new_v4().to_string());
+        assert!(!missing.ok);
+        assert!(missing.message.starts_with("atom not found"));
+
+        let invalid = atom_record_access_impl("not-a-uuid".to_string());
+        assert!(!invalid.ok);
+        assert!(invalid.message.starts_with("invalid atom id"));
+    }
+
+    #[test]
+    fn atom_set_flag_archives_and_validates_flag_names() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("archive");
+        let note = entry_create_note_impl(format!("note {token}"));
+        let atom_id = note.atom_id.expect("created note id");
+
+        let archived = atom_set_flag_impl(atom_id.clone(), "archived".to_string(), true);
+        assert!(archived.ok, "{}", archived.message);
+        assert_eq!(archived.atom_id.as_deref(), Some(atom_id.as_str()));
+        let hidden = entry_search_impl(token.clone(), None, Some(10));
+        assert!(hidden.ok, "{}", hidden.message);
+        assert!(hidden.items.iter().all(|item| item.atom_id != atom_id));
+
+        let restored = atom_set_flag_impl(atom_id.clone(), "archived".to_string(), false);
+        assert!(restored.ok, "{}", restored.message);
+        let visible = entry_search_impl(token, None, Some(10));
+        assert!(visible.items.iter().any(|item| item.atom_id == atom_id));
+
+        let invalid = atom_set_flag_impl(atom_id, "hidden".to_string(), true);
+        assert!(!invalid.ok);
+        assert!(invalid.message.starts_with("invalid flag"));
+
+        let missing = atom_set_flag_impl(Uuid::new_v4().to_string(), "pinned".to_string(), true);
+        assert!(!missing.ok);
+        assert!(missing.message.starts_with("atom not found"));
+    }
+
+    #[test]
+    fn list_apis_filter_and_report_atom_flags() {
+        let _guard = acquire_test_db_lock();
+        let tag = unique_token("flags");
+        let note = entry_create_note_impl(format!("note {tag}"));
+        let note_id = note.atom_id.expect("created note id");
+        assert!(note_set_tags_impl(note_id.clone(), vec![tag.clone()]).ok);
+
+        let listed = notes_list_impl(Some(tag.clone()), None, None, false, None, None);
+        assert!(listed.ok, "{}", listed.message);
+        assert_eq!(listed.items.len(), 1);
+        assert!(!listed.items[0].is_pinned && !listed.items[0].is_archived);
+
+        assert!(atom_set_flag_impl(note_id.clone(), "pinned".to_string(), true).ok);
+        let pinned = notes_list_impl(Some(tag.clone()), None, None, false, Some(true), None);
+        assert_eq!(pinned.items.len(), 1);
+        assert!(pinned.items[0].is_pinned);
+        let starred = notes_list_impl(Some(tag.clone()), None, None, false, None, Some(true));
+        assert!(starred.items.is_empty());
+
+        assert!(atom_set_flag_impl(note_id.clone(), "archived".to_string(), true).ok);
+        let hidden = notes_list_impl(Some(tag.clone()), None, None, false, None, None);
+        assert!(hidden.items.is_empty());
+        let archived = notes_list_impl(Some(tag), None, None, true, None, None);
+        assert_eq!(archived.items.len(), 1);
+        assert!(archived.items[0].is_archived);
+
+        let task = entry_create_task_impl(format!("task {}", unique_token("flags")));
+        let task_id = task.atom_id.expect("created task id");
+        assert!(atom_set_flag_impl(task_id.clone(), "pinned".to_string(), true).ok);
+        assert!(atom_set_flag_impl(task_id.clone(), "starred".to_string(), true).ok);
+        let inbox = tasks_list_inbox_impl(None, None, false, Some(true), Some(true));
+        assert!(inbox.ok, "{}", inbox.message);
+        let item = inbox
+            .items
+            .iter()
+            .find(|item| item.atom_id == task_id)
+            .expect("pinned starred task listed");
+        assert!(item.is_pinned && item.is_starred && !item.is_archived);
+
+        assert!(atom_set_flag_impl(task_id.clone(), "archived".to_string(), true).ok);
+        let inbox = tasks_list_inbox_impl(None, None, false, Some(true), Some(true));
+        assert!(inbox.items.iter().all(|item| item.atom_id != task_id));
+        let inbox = tasks_list_inbox_impl(None, None, true, Some(true), Some(true));
+        assert!(inbox
+            .items
+            .iter()
+            .any(|item| item.atom_id == task_id && item.is_archived));
+    }
+
+    #[test]
+    fn trash_apis_restore_and_purge_deleted_atoms() {
+        let _guard = acquire_test_db_lock();
+        let kept = entry_create_note_impl(format!("note {}", unique_token("trash-keep")));
+        let gone = entry_create_note_impl(format!("note {}", unique_token("trash-gone")));
+        let kept_id = kept.atom_id.expect("created note id");
+        let gone_id = gone.atom_id.expect("created note id");
+        {
+            let conn = open_db(super::resolve_entry_db_path()).expect("open db");
+            let repo = SqliteAtomRepository::try_new(&conn).expect("atom repo");
+            for id in [&kept_id, &gone_id] {
+                repo.soft_delete_atom(Uuid::parse_str(id).unwrap())
+                    .expect("soft delete");
+            }
+        }
+
+        let listed = trash_list_impl(None, None);
+        assert!(listed.ok, "{}", listed.message);
+        assert_eq!(listed.applied_limit, 50);
+        assert!(listed.items.iter().any(|item| item.atom_id == kept_id));
+
+        let restored = trash_restore_impl(kept_id.clone());
+        assert!(restored.ok, "{}", restored.message);
+        let purged = trash_purge_impl(gone_id.clone());
+        assert!(purged.ok, "{}", purged.message);
+        let listed = trash_list_impl(Some(50), None);
+        assert!(listed
+            .items
+            .iter()
+            .all(|item| item.atom_id != kept_id && item.atom_id != gone_id));
+
+        let missing = trash_purge_impl(kept_id);
+        assert!(!missing.ok);
+        assert!(missing.message.starts_with("atom not found"));
+        let invalid = trash_restore_impl("not-a-uuid".to_string());
+        assert!(!invalid.ok);
+        assert!(invalid.message.starts_with("invalid atom id"));
+    }
+
+    #[test]
+    fn journal_apis_undo_and_redo_workspace_move() {
+        let _guard = acquire_test_db_lock();
+        let parent = workspace_create_folder_impl(None, unique_token("journal-parent"));
+        let child = workspace_create_folder_impl(None, unique_token("journal-child"));
+        let parent_id = parent.node.expect("parent").node_id;
+        let child_id = child.node.expect("child").node_id;
+
+        let moved = workspace_move_node_impl(child_id.clone(), Some(parent_id.clone()), Some(0));
+        assert!(moved.ok, "{}", moved.message);
+        let status = journal_status_impl();
+        assert!(status.ok, "{}", status.message);
+        assert!(status.undo_count >= 1);
+        assert_eq!(status.redo_count, 0);
+
+        let undone = journal_undo_impl();
+        assert!(undone.ok, "{}", undone.message);
+        assert_eq!(undone.command.as_deref(), Some("node_move"));
+        assert_eq!(undone.target_id.as_deref(), Some(child_id.as_str()));
+        assert_eq!(undone.redo_count, 1);
+        let children = workspace_list_children_impl(Some(parent_id.clone()));
+        assert!(children.items.is_empty());
+
+        let redone = journal_redo_impl();
+        assert!(redone.ok, "{}", redone.message);
+        assert_eq!(redone.redo_count, 0);
+        let children = workspace_list_children_impl(Some(parent_id));
+        assert_eq!(children.items.len(), 1);
+        assert_eq!(children.items[0].node_id, child_id);
+
+        let nothing = journal_redo_impl();
+        assert!(nothing.ok);
+        assert_eq!(nothing.command, None);
+    }
+
+    #[test]
+    fn note_template_apis_create_filed_note() {
+        let _guard = acquire_test_db_lock();
+        let folder = workspace_create_folder_impl(None, unique_token("template-folder"));
+        let folder_id = folder.node.expect("folder").node_id;
+        let name = unique_token("template");
+
+        let saved = note_template_save_impl(
+            None,
+            format!("  {name}  "),
+            "# {{title}}\nProject: {{ project }}\n{{cursor}}".to_string(),
+            vec!["Meeting".to_string()],
+            Some(folder_id.clone()),
+        );
+        assert!(saved.ok, "{}", saved.message);
+        let template = saved.template.expect("template");
+        assert_eq!(template.name, name);
+        assert_eq!(template.prompts, vec!["project".to_string()]);
+        let duplicate =
+            note_template_save_impl(None, name.to_uppercase(), String::new(), Vec::new(), None);
+        assert_eq!(duplicate.error_code.as_deref(), Some("invalid_template"));
+        let listed = note_templates_list_impl();
+        assert!(listed
+            .items
+            .iter()
+            .any(|item| item.template_id == template.template_id));
+
+        let created = note_create_from_template_impl(
+            template.template_id.clone(),
+            Some("Kickoff ünïcode".to_string()),
+            vec![TemplatePromptAnswer {
+                name: "project".to_string(),
+                value: "Apollo".to_string(),
+            }],
+            None,
+        );
+        assert!(created.ok, "{}", created.message);
+        let note = created.note.expect("note");
+        assert_eq!(note.content, "# Kickoff ünïcode\nProject: Apollo\n");
+        assert_eq!(note.tags, vec!["meeting".to_string()]);
+        assert_eq!(created.cursor_offset, Some(34));
+        let children = workspace_list_children_impl(Some(folder_id));
+        assert_eq!(children.items.len(), 1);
+        assert_eq!(Some(children.items[0].node_id.clone()), created.node_id);
+        assert_eq!(children.items[0].display_name, "Kickoff ünïcode");
+
+        let deleted = note_template_delete_impl(template.template_id.clone());
+        assert!(deleted.ok, "{}", deleted.message);
+        let missing = note_create_from_template_impl(template.template_id, None, Vec::new(), None);
+        assert_eq!(missing.error_code.as_deref(), Some("template_not_found"));
+        let invalid = note_template_delete_impl("nope".to_string());
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_template_id"));
+    }
+
+    #[test]
+    fn entry_search_semantic_matches_related_word_forms() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("semantic");
+        let note = entry_create_note_impl(format!("{token} planning session"));
+        assert!(note.ok, "{}", note.message);
+
+        let keyword = entry_search_impl(format!("{token} plans"), None, Some(10));
+        assert!(keyword.ok, "{}", keyword.message);
+        assert!(keyword.items.is_empty());
+
+        let refreshed = search_refresh_embeddings_impl();
+        assert!(refreshed.ok, "{}", refreshed.message);
+        assert!(refreshed.refreshed >= 1);
+        let semantic = entry_search_semantic_impl(format!("{token} plans"), None, Some(10));
+        assert!(semantic.ok, "{}", semantic.message);
+        assert_eq!(
+            semantic
+                .items
+                .first()
+                .map(|item| Some(item.atom_id.clone())),
+            Some(note.atom_id)
+        );
+    }
+
+    #[test]
+    fn search_session_narrows_and_closes() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("session");
+        let task = entry_create_task_impl(format!("{token} standup"));
+        assert!(task.ok, "{}", task.message);
+        let note = entry_create_note_impl(format!("{token} stock report"));
+        assert!(note.ok, "{}", note.message);
+
+        let opened = search_session_open_impl(Some("all".to_string()), Some(10));
+        assert!(opened.ok, "{}", opened.message);
+        let session_id = opened.session_id.expect("session id");
+
+        let broad = search_session_query_impl(session_id.clone(), format!("{token} st"));
+        assert!(broad.ok, "{}", broad.message);
+        assert_eq!(broad.items.len(), 2);
+        let narrow = search_session_query_impl(session_id.clone(), format!("{token} sta"));
+        assert!(narrow.ok, "{}", narrow.message);
+        assert_eq!(
+            narrow
+                .items
+                .iter()
+                .map(|item| Some(item.atom_id.clone()))
+                .collect::<Vec<_>>(),
+            vec![task.atom_id]
+        );
+
+        assert!(search_session_cancel_impl(session_id.clone()).ok);
+        assert!(search_session_close_impl(session_id.clone()).ok);
+        let closed = search_session_query_impl(session_id, format!("{token} sta"));
+        assert!(!closed.ok);
+        assert_eq!(closed.error_code.as_deref(), Some("session_not_found"));
+    }
+
     #[test]
     fn entry_search_filters_results_by_kind() {
         let _guard = acquire_test_db_lock();
@@ -1769,6 +3720,62 @@ mod tests {
         assert_eq!(status.as_deref(), Some("todo"));
     }
 
+    #[test]
+    fn atom_tag_apis_tag_tasks_and_events() {
+        let _guard = acquire_test_db_lock();
+        let task = entry_create_task_impl("tag me".to_string());
+        let task_id = task.atom_id.expect("task create should return atom_id");
+        let event = entry_schedule_impl("standup".to_string(), 1_000, Some(2_000));
+        let event_id = event.atom_id.expect("schedule should return atom_id");
+
+        let set = atom_set_tags_impl(
+            task_id.clone(),
+            vec!["Work/Ops".to_string(), "urgent".to_string()],
+        );
+        assert!(set.ok, "{}", set.message);
+        assert_eq!(set.tags, vec!["urgent".to_string(), "work/ops".to_string()]);
+
+        let added = atom_add_tag_impl(event_id.clone(), " Meetings ".to_string());
+        assert!(added.ok, "{}", added.message);
+        assert_eq!(added.tags, vec!["meetings".to_string()]);
+
+        let removed = atom_remove_tag_impl(task_id.clone(), "urgent".to_string());
+        assert!(removed.ok, "{}", removed.message);
+        assert_eq!(removed.atom_id.as_deref(), Some(task_id.as_str()));
+        assert_eq!(removed.tags, vec!["work/ops".to_string()]);
+    }
+
+    #[test]
+    fn entry_hashtags_are_reported_as_inline_tags() {
+        let _guard = acquire_test_db_lock();
+        let task = entry_create_task_impl("call plumber #urgent".to_string());
+        let task_id = task.atom_id.expect("task create should return atom_id");
+
+        let resp = atom_add_tag_impl(task_id.clone(), "home".to_string());
+        assert!(resp.ok, "{}", resp.message);
+        assert_eq!(resp.tags, vec!["home".to_string(), "urgent".to_string()]);
+        assert_eq!(resp.inline_tags, vec!["urgent".to_string()]);
+
+        let cleared = atom_set_tags_impl(task_id, Vec::new());
+        assert_eq!(cleared.tags, vec!["urgent".to_string()]);
+    }
+
+    #[test]
+    fn atom_tag_apis_report_stable_error_codes() {
+        let _guard = acquire_test_db_lock();
+        let invalid = atom_add_tag_impl("not-a-uuid".to_string(), "x".to_string());
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_atom_id"));
+
+        let missing = atom_add_tag_impl(Uuid::new_v4().to_string(), "x".to_string());
+        assert_eq!(missing.error_code.as_deref(), Some("atom_not_found"));
+
+        let task = entry_create_task_impl("blank tag".to_string());
+        let task_id = task.atom_id.expect("task create should return atom_id");
+        let blank = atom_set_tags_impl(task_id, vec![" / ".to_string()]);
+        assert!(!blank.ok);
+        assert_eq!(blank.error_code.as_deref(), Some("invalid_tag"));
+    }
+
     #[test]
     fn entry_schedule_supports_point_shape() {
         let _guard = acquire_test_db_lock();
@@ -1866,7 +3873,14 @@ mod tests {
         );
         assert!(tag_set.ok, "{}", tag_set.message);
 
-        let filtered = notes_list_impl(Some("work".to_string()), Some(200), Some(0));
+        let filtered = notes_list_impl(
+            Some("work".to_string()),
+            Some(200),
+            Some(0),
+            false,
+            None,
+            None,
+        );
         assert!(filtered.ok, "{}", filtered.message);
         assert_eq!(filtered.applied_limit, 50);
         assert!(filtered.items.iter().any(|item| item.atom_id == first_id));
@@ -1879,7 +3893,14 @@ mod tests {
         let created = note_create_impl("blank tag filter source".to_string());
         assert!(created.ok, "{}", created.message);
 
-        let response = notes_list_impl(Some("   ".to_string()), Some(20), Some(0));
+        let response = notes_list_impl(
+            Some("   ".to_string()),
+            Some(20),
+            Some(0),
+            false,
+            None,
+            None,
+        );
         assert!(!response.ok);
         assert_eq!(response.error_code.as_deref(), Some("invalid_tag"));
     }
@@ -2104,6 +4125,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn workspace_smart_folder_lists_matching_atoms_as_atom_refs() {
+        let _guard = acquire_test_db_lock();
+        let token = unique_token("smart-folder");
+        let task = entry_create_task_impl(format!("task {token}"));
+        assert!(task.ok, "{}", task.message);
+
+        let created = workspace_create_smart_folder_impl(
+            None,
+            format!("Smart {token}"),
+            token,
+            Some("task".to_string()),
+            None,
+            false,
+        );
+        assert!(created.ok, "{}", created.message);
+        let node = created.node.expect("smart folder payload");
+        assert_eq!(node.kind, "smart_folder");
+
+        let children = workspace_list_children_impl(Some(node.node_id));
+        assert!(children.ok, "{}", children.message);
+        assert_eq!(children.items.len(), 1);
+        assert_eq!(children.items[0].kind, "atom_ref");
+        assert_eq!(children.items[0].atom_id, task.atom_id);
+
+        let invalid = workspace_create_smart_folder_impl(
+            None,
+            "Bad kind".to_string(),
+            String::new(),
+            Some("photo".to_string()),
+            None,
+            false,
+        );
+        assert!(!invalid.ok);
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_saved_search"));
+    }
+
     #[test]
     fn workspace_create_note_ref_rejects_invalid_atom_id() {
         let _guard = acquire_test_db_lock();
@@ -2132,6 +4190,27 @@ mod tests {
         assert_eq!(response.error_code.as_deref(), Some("atom_not_note"));
     }
 
+    #[test]
+    fn workspace_create_note_from_link_creates_note_once() {
+        let _guard = acquire_test_db_lock();
+        let title = unique_token("Missing page");
+        let source = note_create_impl(format!("see [[{title}]]"));
+        assert!(source.ok, "{}", source.message);
+        let source_id = source.note.expect("source note").atom_id;
+
+        let created = workspace_create_note_from_link_impl(source_id.clone(), title.clone());
+        assert!(created.ok, "{}", created.message);
+        let node = created.node.expect("created node");
+        assert_eq!(node.display_name, title);
+
+        let again = workspace_create_note_from_link_impl(source_id, title);
+        assert!(!again.ok);
+        assert_eq!(
+            again.error_code.as_deref(),
+            Some("unresolved_link_not_found")
+        );
+    }
+
     #[test]
     fn workspace_rename_node_rejects_blank_name() {
         let _guard = acquire_test_db_lock();
@@ -2168,6 +4247,37 @@ mod tests {
         assert_eq!(response.error_code.as_deref(), Some("invalid_node_id"));
     }
 
+    #[test]
+    fn workspace_restore_folder_undoes_delete_all() {
+        let _guard = acquire_test_db_lock();
+        let folder_id = create_workspace_folder_via_ffi(&unique_token("restore-folder"));
+        let deleted = workspace_delete_folder_impl(folder_id.clone(), "delete_all".to_string());
+        assert!(deleted.ok, "{}", deleted.message);
+
+        let listed = workspace_list_deleted_folders_impl();
+        assert!(listed.ok, "{}", listed.message);
+        let item = listed
+            .items
+            .iter()
+            .find(|item| item.node_id == folder_id)
+            .expect("deleted folder listed");
+        assert_eq!(item.mode, "delete_all");
+        assert_eq!(item.node_count, 1);
+
+        let restored = workspace_restore_folder_impl(folder_id.clone());
+        assert!(restored.ok, "{}", restored.message);
+        assert_eq!(
+            restored.node.expect("restored node").node_id,
+            folder_id.clone()
+        );
+
+        let again = workspace_restore_folder_impl(folder_id);
+        assert!(!again.ok);
+        assert_eq!(again.error_code.as_deref(), Some("node_not_found"));
+        let invalid = workspace_restore_folder_impl("not-a-uuid".to_string());
+        assert_eq!(invalid.error_code.as_deref(), Some("invalid_node_id"));
+    }
+
     #[test]
     fn workspace_delete_folder_rejects_invalid_mode() {
         let _guard = acquire_test_db_lock();
@@ -2232,7 +4342,7 @@ mod tests {
         let _outside_id = create_test_event("outside", 20_000, 22_000);
 
         // Query range: 9:00–13:00 (9_000–13_000)
-        let resp = calendar_list_by_range_impl(9_000, 13_000, None, None);
+        let resp = calendar_list_by_range_impl(9_000, 13_000, None, None, false, None, None);
         assert!(resp.ok, "{}", resp.message);
         assert!(
             resp.items.iter().any(|i| i.atom_id == inside_id),
@@ -2255,7 +4365,7 @@ mod tests {
         assert!(status_resp.ok, "{}", status_resp.message);
 
         // Query should still include it
-        let resp = calendar_list_by_range_impl(29_000, 33_000, None, None);
+        let resp = calendar_list_by_range_impl(29_000, 33_000, None, None, false, None, None);
         assert!(resp.ok, "{}", resp.message);
         assert!(
             resp.items.iter().any(|i| i.atom_id == event_id),
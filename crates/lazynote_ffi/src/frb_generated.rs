@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1852740980;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 705686268;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__atom_update_status_impl(
+fn wire__crate__api__atom_add_tag_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__atom_update_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "atom_update_status",
+            debug_name: "atom_add_tag",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -68,13 +68,13 @@ fn wire__crate__api__atom_update_status_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_status = <Option<String>>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::atom_update_status(api_atom_id, api_status).await,
+                            crate::api::atom_add_tag(api_atom_id, api_tag).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -84,7 +84,7 @@ fn wire__crate__api__atom_update_status_impl(
         },
     )
 }
-fn wire__crate__api__calendar_list_by_range_impl(
+fn wire__crate__api__atom_record_access_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -92,7 +92,7 @@ fn wire__crate__api__calendar_list_by_range_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "calendar_list_by_range",
+            debug_name: "atom_record_access",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -106,23 +106,13 @@ fn wire__crate__api__calendar_list_by_range_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_start_ms = <i64>::sse_decode(&mut deserializer);
-            let api_end_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::calendar_list_by_range(
-                                api_start_ms,
-                                api_end_ms,
-                                api_limit,
-                                api_offset,
-                            )
-                            .await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::atom_record_access(api_atom_id).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -131,7 +121,7 @@ fn wire__crate__api__calendar_list_by_range_impl(
         },
     )
 }
-fn wire__crate__api__calendar_update_event_impl(
+fn wire__crate__api__atom_remove_tag_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -139,7 +129,7 @@ fn wire__crate__api__calendar_update_event_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "calendar_update_event",
+            debug_name: "atom_remove_tag",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -154,19 +144,13 @@ fn wire__crate__api__calendar_update_event_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_start_ms = <i64>::sse_decode(&mut deserializer);
-            let api_end_ms = <i64>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::calendar_update_event(
-                                api_atom_id,
-                                api_start_ms,
-                                api_end_ms,
-                            )
-                            .await,
+                            crate::api::atom_remove_tag(api_atom_id, api_tag).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -176,16 +160,17 @@ fn wire__crate__api__calendar_update_event_impl(
         },
     )
 }
-fn wire__crate__api__configure_entry_db_path_impl(
+fn wire__crate__api__atom_set_flag_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "configure_entry_db_path",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "atom_set_flag",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -197,26 +182,35 @@ fn wire__crate__api__configure_entry_db_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_db_path = <String>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_flag = <String>::sse_decode(&mut deserializer);
+            let api_value = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::configure_entry_db_path(api_db_path))?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::atom_set_flag(api_atom_id, api_flag, api_value).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__core_version_impl(
+fn wire__crate__api__atom_set_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "core_version",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "atom_set_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -228,15 +222,24 @@ fn wire__crate__api__core_version_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::core_version())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::atom_set_tags(api_atom_id, api_tags).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__entry_create_note_impl(
+fn wire__crate__api__atom_update_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -244,7 +247,7 @@ fn wire__crate__api__entry_create_note_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "entry_create_note",
+            debug_name: "atom_update_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -258,13 +261,15 @@ fn wire__crate__api__entry_create_note_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_status = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::entry_create_note(api_content).await)?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::atom_update_status(api_atom_id, api_status).await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -273,7 +278,7 @@ fn wire__crate__api__entry_create_note_impl(
         },
     )
 }
-fn wire__crate__api__entry_create_task_impl(
+fn wire__crate__api__calendar_list_by_range_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -281,7 +286,7 @@ fn wire__crate__api__entry_create_task_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "entry_create_task",
+            debug_name: "calendar_list_by_range",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -295,13 +300,29 @@ fn wire__crate__api__entry_create_task_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_include_archived = <bool>::sse_decode(&mut deserializer);
+            let api_pinned = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_starred = <Option<bool>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            Result::<_, ()>::Ok(crate::api::entry_create_task(api_content).await)?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::calendar_list_by_range(
+                                api_start_ms,
+                                api_end_ms,
+                                api_limit,
+                                api_offset,
+                                api_include_archived,
+                                api_pinned,
+                                api_starred,
+                            )
+                            .await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -310,7 +331,7 @@ fn wire__crate__api__entry_create_task_impl(
         },
     )
 }
-fn wire__crate__api__entry_schedule_impl(
+fn wire__crate__api__calendar_update_event_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -318,7 +339,7 @@ fn wire__crate__api__entry_schedule_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "entry_schedule",
+            debug_name: "calendar_update_event",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -332,18 +353,18 @@ fn wire__crate__api__entry_schedule_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_start_epoch_ms = <i64>::sse_decode(&mut deserializer);
-            let api_end_epoch_ms = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_start_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_ms = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::entry_schedule(
-                                api_title,
-                                api_start_epoch_ms,
-                                api_end_epoch_ms,
+                            crate::api::calendar_update_event(
+                                api_atom_id,
+                                api_start_ms,
+                                api_end_ms,
                             )
                             .await,
                         )?;
@@ -355,17 +376,16 @@ fn wire__crate__api__entry_schedule_impl(
         },
     )
 }
-fn wire__crate__api__entry_search_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__configure_entry_db_path_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "entry_search",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "configure_entry_db_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -377,32 +397,24 @@ fn wire__crate__api__entry_search_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
-            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_db_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::entry_search(api_text, api_kind, api_limit).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::configure_entry_db_path(api_db_path))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__init_logging_impl(
+fn wire__crate__api__core_version_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_logging",
+            debug_name: "core_version",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -416,18 +428,15 @@ fn wire__crate__api__init_logging_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_level = <String>::sse_decode(&mut deserializer);
-            let api_log_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::init_logging(api_level, api_log_dir))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::core_version())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__note_create_impl(
+fn wire__crate__api__entry_create_note_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -435,7 +444,7 @@ fn wire__crate__api__note_create_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_create",
+            debug_name: "entry_create_note",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -455,7 +464,7 @@ fn wire__crate__api__note_create_impl(
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok =
-                            Result::<_, ()>::Ok(crate::api::note_create(api_content).await)?;
+                            Result::<_, ()>::Ok(crate::api::entry_create_note(api_content).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -464,7 +473,7 @@ fn wire__crate__api__note_create_impl(
         },
     )
 }
-fn wire__crate__api__note_get_impl(
+fn wire__crate__api__entry_create_task_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -472,7 +481,7 @@ fn wire__crate__api__note_get_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_get",
+            debug_name: "entry_create_task",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -486,13 +495,13 @@ fn wire__crate__api__note_get_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok =
-                            Result::<_, ()>::Ok(crate::api::note_get(api_atom_id).await)?;
+                            Result::<_, ()>::Ok(crate::api::entry_create_task(api_content).await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -501,7 +510,7 @@ fn wire__crate__api__note_get_impl(
         },
     )
 }
-fn wire__crate__api__note_set_tags_impl(
+fn wire__crate__api__entry_schedule_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -509,7 +518,7 @@ fn wire__crate__api__note_set_tags_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_set_tags",
+            debug_name: "entry_schedule",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -523,14 +532,20 @@ fn wire__crate__api__note_set_tags_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_start_epoch_ms = <i64>::sse_decode(&mut deserializer);
+            let api_end_epoch_ms = <Option<i64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::note_set_tags(api_atom_id, api_tags).await,
+                            crate::api::entry_schedule(
+                                api_title,
+                                api_start_epoch_ms,
+                                api_end_epoch_ms,
+                            )
+                            .await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -540,7 +555,7 @@ fn wire__crate__api__note_set_tags_impl(
         },
     )
 }
-fn wire__crate__api__note_update_impl(
+fn wire__crate__api__entry_search_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -548,7 +563,7 @@ fn wire__crate__api__note_update_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_update",
+            debug_name: "entry_search",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -562,14 +577,15 @@ fn wire__crate__api__note_update_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::note_update(api_atom_id, api_content).await,
+                            crate::api::entry_search(api_text, api_kind, api_limit).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -579,7 +595,7 @@ fn wire__crate__api__note_update_impl(
         },
     )
 }
-fn wire__crate__api__notes_list_impl(
+fn wire__crate__api__entry_search_all_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -587,7 +603,7 @@ fn wire__crate__api__notes_list_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "notes_list",
+            debug_name: "entry_search_all",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -601,15 +617,15 @@ fn wire__crate__api__notes_list_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_tag = <Option<String>>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
             let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::notes_list(api_tag, api_limit, api_offset).await,
+                            crate::api::entry_search_all(api_text, api_kind, api_limit).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -619,16 +635,17 @@ fn wire__crate__api__notes_list_impl(
         },
     )
 }
-fn wire__crate__api__ping_impl(
+fn wire__crate__api__entry_search_semantic_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "ping",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "entry_search_semantic",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -640,23 +657,65 @@ fn wire__crate__api__ping_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::ping())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::entry_search_semantic(api_text, api_kind, api_limit).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__tags_list_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__init_logging_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_logging",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_level = <String>::sse_decode(&mut deserializer);
+            let api_log_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::init_logging(api_level, api_log_dir))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__journal_redo_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tags_list",
+            debug_name: "journal_redo",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -674,7 +733,7 @@ fn wire__crate__api__tags_list_impl(
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(crate::api::tags_list().await)?;
+                        let output_ok = Result::<_, ()>::Ok(crate::api::journal_redo().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -683,7 +742,7 @@ fn wire__crate__api__tags_list_impl(
         },
     )
 }
-fn wire__crate__api__tasks_list_inbox_impl(
+fn wire__crate__api__journal_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -691,7 +750,7 @@ fn wire__crate__api__tasks_list_inbox_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tasks_list_inbox",
+            debug_name: "journal_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -705,15 +764,11 @@ fn wire__crate__api__tasks_list_inbox_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::tasks_list_inbox(api_limit, api_offset).await,
-                        )?;
+                        let output_ok = Result::<_, ()>::Ok(crate::api::journal_status().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -722,7 +777,7 @@ fn wire__crate__api__tasks_list_inbox_impl(
         },
     )
 }
-fn wire__crate__api__tasks_list_today_impl(
+fn wire__crate__api__journal_undo_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -730,7 +785,7 @@ fn wire__crate__api__tasks_list_today_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tasks_list_today",
+            debug_name: "journal_undo",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -744,17 +799,93 @@ fn wire__crate__api__tasks_list_today_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_bod_ms = <i64>::sse_decode(&mut deserializer);
-            let api_eod_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(crate::api::journal_undo().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_create_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_create",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_create(api_content).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_create_from_template_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_create_from_template",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_template_id = <String>::sse_decode(&mut deserializer);
+            let api_title = <Option<String>>::sse_decode(&mut deserializer);
+            let api_answers =
+                <Vec<crate::api::TemplatePromptAnswer>>::sse_decode(&mut deserializer);
+            let api_utc_offset_minutes = <Option<i32>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::tasks_list_today(
-                                api_bod_ms, api_eod_ms, api_limit, api_offset,
+                            crate::api::note_create_from_template(
+                                api_template_id,
+                                api_title,
+                                api_answers,
+                                api_utc_offset_minutes,
                             )
                             .await,
                         )?;
@@ -766,7 +897,7 @@ fn wire__crate__api__tasks_list_today_impl(
         },
     )
 }
-fn wire__crate__api__tasks_list_upcoming_impl(
+fn wire__crate__api__note_get_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -774,7 +905,7 @@ fn wire__crate__api__tasks_list_upcoming_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tasks_list_upcoming",
+            debug_name: "note_get",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -788,16 +919,51 @@ fn wire__crate__api__tasks_list_upcoming_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_eod_ms = <i64>::sse_decode(&mut deserializer);
-            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
-            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_get(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__note_set_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_set_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::tasks_list_upcoming(api_eod_ms, api_limit, api_offset)
-                                .await,
+                            crate::api::note_set_tags(api_atom_id, api_tags).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -807,7 +973,7 @@ fn wire__crate__api__tasks_list_upcoming_impl(
         },
     )
 }
-fn wire__crate__api__workspace_create_folder_impl(
+fn wire__crate__api__note_template_delete_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -815,7 +981,7 @@ fn wire__crate__api__workspace_create_folder_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_create_folder",
+            debug_name: "note_template_delete",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -829,14 +995,13 @@ fn wire__crate__api__workspace_create_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_template_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_create_folder(api_parent_node_id, api_name).await,
+                            crate::api::note_template_delete(api_template_id).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -846,7 +1011,7 @@ fn wire__crate__api__workspace_create_folder_impl(
         },
     )
 }
-fn wire__crate__api__workspace_create_note_ref_impl(
+fn wire__crate__api__note_template_save_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -854,7 +1019,7 @@ fn wire__crate__api__workspace_create_note_ref_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_create_note_ref",
+            debug_name: "note_template_save",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -868,18 +1033,22 @@ fn wire__crate__api__workspace_create_note_ref_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_atom_id = <String>::sse_decode(&mut deserializer);
-            let api_display_name = <Option<String>>::sse_decode(&mut deserializer);
+            let api_template_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_folder_node_id = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_create_note_ref(
-                                api_parent_node_id,
-                                api_atom_id,
-                                api_display_name,
+                            crate::api::note_template_save(
+                                api_template_id,
+                                api_name,
+                                api_content,
+                                api_tags,
+                                api_folder_node_id,
                             )
                             .await,
                         )?;
@@ -891,7 +1060,7 @@ fn wire__crate__api__workspace_create_note_ref_impl(
         },
     )
 }
-fn wire__crate__api__workspace_delete_folder_impl(
+fn wire__crate__api__note_templates_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -899,7 +1068,7 @@ fn wire__crate__api__workspace_delete_folder_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_delete_folder",
+            debug_name: "note_templates_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -913,15 +1082,12 @@ fn wire__crate__api__workspace_delete_folder_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_node_id = <String>::sse_decode(&mut deserializer);
-            let api_mode = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_delete_folder(api_node_id, api_mode).await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::note_templates_list().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -930,7 +1096,7 @@ fn wire__crate__api__workspace_delete_folder_impl(
         },
     )
 }
-fn wire__crate__api__workspace_list_children_impl(
+fn wire__crate__api__note_update_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -938,7 +1104,7 @@ fn wire__crate__api__workspace_list_children_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_list_children",
+            debug_name: "note_update",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -952,13 +1118,14 @@ fn wire__crate__api__workspace_list_children_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_list_children(api_parent_node_id).await,
+                            crate::api::note_update(api_atom_id, api_content).await,
                         )?;
                         Ok(output_ok)
                     })()
@@ -968,7 +1135,7 @@ fn wire__crate__api__workspace_list_children_impl(
         },
     )
 }
-fn wire__crate__api__workspace_move_node_impl(
+fn wire__crate__api__notes_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -976,7 +1143,7 @@ fn wire__crate__api__workspace_move_node_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_move_node",
+            debug_name: "notes_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -990,18 +1157,24 @@ fn wire__crate__api__workspace_move_node_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_node_id = <String>::sse_decode(&mut deserializer);
-            let api_new_parent_id = <Option<String>>::sse_decode(&mut deserializer);
-            let api_target_order = <Option<i64>>::sse_decode(&mut deserializer);
+            let api_tag = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_include_archived = <bool>::sse_decode(&mut deserializer);
+            let api_pinned = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_starred = <Option<bool>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
                         let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_move_node(
-                                api_node_id,
-                                api_new_parent_id,
-                                api_target_order,
+                            crate::api::notes_list(
+                                api_tag,
+                                api_limit,
+                                api_offset,
+                                api_include_archived,
+                                api_pinned,
+                                api_starred,
                             )
                             .await,
                         )?;
@@ -1013,7 +1186,36 @@ fn wire__crate__api__workspace_move_node_impl(
         },
     )
 }
-fn wire__crate__api__workspace_rename_node_impl(
+fn wire__crate__api__ping_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "ping",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::ping())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search_refresh_embeddings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1021,7 +1223,7 @@ fn wire__crate__api__workspace_rename_node_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "workspace_rename_node",
+            debug_name: "search_refresh_embeddings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1035,15 +1237,12 @@ fn wire__crate__api__workspace_rename_node_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_node_id = <String>::sse_decode(&mut deserializer);
-            let api_new_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::workspace_rename_node(api_node_id, api_new_name).await,
-                        )?;
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::search_refresh_embeddings().await)?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1052,54 +1251,1398 @@ fn wire__crate__api__workspace_rename_node_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+fn wire__crate__api__search_session_cancel_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_session_cancel",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::search_session_cancel(api_session_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search_session_close_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_session_close",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::search_session_close(api_session_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search_session_open_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_session_open",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::search_session_open(api_kind, api_limit).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__search_session_query_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_session_query",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::search_session_query(api_session_id, api_text).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tags_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tags_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(crate::api::tags_list().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_inbox_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_inbox",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_include_archived = <bool>::sse_decode(&mut deserializer);
+            let api_pinned = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_starred = <Option<bool>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_inbox(
+                                api_limit,
+                                api_offset,
+                                api_include_archived,
+                                api_pinned,
+                                api_starred,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_today_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_today",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bod_ms = <i64>::sse_decode(&mut deserializer);
+            let api_eod_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_include_archived = <bool>::sse_decode(&mut deserializer);
+            let api_pinned = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_starred = <Option<bool>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_today(
+                                api_bod_ms,
+                                api_eod_ms,
+                                api_limit,
+                                api_offset,
+                                api_include_archived,
+                                api_pinned,
+                                api_starred,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tasks_list_upcoming_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tasks_list_upcoming",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_eod_ms = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_include_archived = <bool>::sse_decode(&mut deserializer);
+            let api_pinned = <Option<bool>>::sse_decode(&mut deserializer);
+            let api_starred = <Option<bool>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::tasks_list_upcoming(
+                                api_eod_ms,
+                                api_limit,
+                                api_offset,
+                                api_include_archived,
+                                api_pinned,
+                                api_starred,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trash_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trash_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <Option<u32>>::sse_decode(&mut deserializer);
+            let api_offset = <Option<u32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::trash_list(api_limit, api_offset).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trash_purge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trash_purge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::trash_purge(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trash_purge_older_than_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trash_purge_older_than",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_days = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::trash_purge_older_than(api_days).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__trash_restore_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trash_restore",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::trash_restore(api_atom_id).await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_create_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_create_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_create_folder(api_parent_node_id, api_name).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_create_note_from_link_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_create_note_from_link",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_target_title = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_create_note_from_link(
+                                api_source_atom_id,
+                                api_target_title,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_create_note_ref_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_create_note_ref",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_atom_id = <String>::sse_decode(&mut deserializer);
+            let api_display_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_create_note_ref(
+                                api_parent_node_id,
+                                api_atom_id,
+                                api_display_name,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_create_smart_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_create_smart_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_query_text = <String>::sse_decode(&mut deserializer);
+            let api_kind = <Option<String>>::sse_decode(&mut deserializer);
+            let api_tag = <Option<String>>::sse_decode(&mut deserializer);
+            let api_open_only = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_create_smart_folder(
+                                api_parent_node_id,
+                                api_name,
+                                api_query_text,
+                                api_kind,
+                                api_tag,
+                                api_open_only,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_delete_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_delete_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            let api_mode = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_delete_folder(api_node_id, api_mode).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_list_children_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_list_children",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_parent_node_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_list_children(api_parent_node_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_list_deleted_folders_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_list_deleted_folders",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_list_deleted_folders().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_move_node_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_move_node",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            let api_new_parent_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_target_order = <Option<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_move_node(
+                                api_node_id,
+                                api_new_parent_id,
+                                api_target_order,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_rename_node_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_rename_node",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            let api_new_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_rename_node(api_node_id, api_new_name).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__workspace_restore_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "workspace_restore_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_node_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::workspace_restore_folder(api_node_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::AtomListItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_previewText = <Option<String>>::sse_decode(deserializer);
+        let mut var_previewImage = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_startAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_endAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_taskStatus = <Option<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        let mut var_isArchived = <bool>::sse_decode(deserializer);
+        let mut var_isPinned = <bool>::sse_decode(deserializer);
+        let mut var_isStarred = <bool>::sse_decode(deserializer);
+        return crate::api::AtomListItem {
+            atom_id: var_atomId,
+            kind: var_kind,
+            content: var_content,
+            preview_text: var_previewText,
+            preview_image: var_previewImage,
+            tags: var_tags,
+            start_at: var_startAt,
+            end_at: var_endAt,
+            task_status: var_taskStatus,
+            updated_at: var_updatedAt,
+            is_archived: var_isArchived,
+            is_pinned: var_isPinned,
+            is_starred: var_isStarred,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AtomListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::AtomListItem>>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::AtomListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            applied_limit: var_appliedLimit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AtomTagsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_inlineTags = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::AtomTagsResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            atom_id: var_atomId,
+            tags: var_tags,
+            inline_tags: var_inlineTags,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::DeletedFolderItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodeId = <String>::sse_decode(deserializer);
+        let mut var_displayName = <String>::sse_decode(deserializer);
+        let mut var_mode = <String>::sse_decode(deserializer);
+        let mut var_deletedAt = <i64>::sse_decode(deserializer);
+        let mut var_nodeCount = <u32>::sse_decode(deserializer);
+        let mut var_noteCount = <u32>::sse_decode(deserializer);
+        return crate::api::DeletedFolderItem {
+            node_id: var_nodeId,
+            display_name: var_displayName,
+            mode: var_mode,
+            deleted_at: var_deletedAt,
+            node_count: var_nodeCount,
+            note_count: var_noteCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::DeletedFoldersResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::DeletedFolderItem>>::sse_decode(deserializer);
+        return crate::api::DeletedFoldersResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EmbeddingsRefreshResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_refreshed = <u32>::sse_decode(deserializer);
+        return crate::api::EmbeddingsRefreshResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            refreshed: var_refreshed,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntryActionResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::EntryActionResponse {
+            ok: var_ok,
+            atom_id: var_atomId,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntrySearchAllItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_nodeId = <Option<String>>::sse_decode(deserializer);
+        let mut var_parentNodeId = <Option<String>>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_atomCount = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::EntrySearchAllItem {
+            kind: var_kind,
+            atom_id: var_atomId,
+            node_id: var_nodeId,
+            parent_node_id: var_parentNodeId,
+            label: var_label,
+            atom_count: var_atomCount,
+        };
     }
 }
 
-impl SseDecode for crate::api::AtomListItem {
+impl SseDecode for crate::api::EntrySearchAllResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::EntrySearchAllItem>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::EntrySearchAllResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            items: var_items,
+            message: var_message,
+            applied_limit: var_appliedLimit,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntrySearchItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_atomId = <String>::sse_decode(deserializer);
         let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_snippet = <String>::sse_decode(deserializer);
+        return crate::api::EntrySearchItem {
+            atom_id: var_atomId,
+            kind: var_kind,
+            snippet: var_snippet,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EntrySearchResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::EntrySearchItem>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::EntrySearchResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            items: var_items,
+            message: var_message,
+            applied_limit: var_appliedLimit,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::JournalResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_command = <Option<String>>::sse_decode(deserializer);
+        let mut var_targetId = <Option<String>>::sse_decode(deserializer);
+        let mut var_undoCount = <u32>::sse_decode(deserializer);
+        let mut var_redoCount = <u32>::sse_decode(deserializer);
+        return crate::api::JournalResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            command: var_command,
+            target_id: var_targetId,
+            undo_count: var_undoCount,
+            redo_count: var_redoCount,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::AtomListItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::AtomListItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::DeletedFolderItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::DeletedFolderItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EntrySearchAllItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EntrySearchAllItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EntrySearchItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EntrySearchItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::NoteItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::NoteItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::NoteTemplateItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::NoteTemplateItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TemplatePromptAnswer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TemplatePromptAnswer>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TrashItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TrashItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::WorkspaceNodeItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::WorkspaceNodeItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::NoteItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_atomId = <String>::sse_decode(deserializer);
         let mut var_content = <String>::sse_decode(deserializer);
         let mut var_previewText = <Option<String>>::sse_decode(deserializer);
         let mut var_previewImage = <Option<String>>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        let mut var_startAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_endAt = <Option<i64>>::sse_decode(deserializer);
-        let mut var_taskStatus = <Option<String>>::sse_decode(deserializer);
         let mut var_updatedAt = <i64>::sse_decode(deserializer);
-        return crate::api::AtomListItem {
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_isArchived = <bool>::sse_decode(deserializer);
+        let mut var_isPinned = <bool>::sse_decode(deserializer);
+        let mut var_isStarred = <bool>::sse_decode(deserializer);
+        return crate::api::NoteItem {
             atom_id: var_atomId,
-            kind: var_kind,
             content: var_content,
             preview_text: var_previewText,
             preview_image: var_previewImage,
+            updated_at: var_updatedAt,
             tags: var_tags,
-            start_at: var_startAt,
-            end_at: var_endAt,
-            task_status: var_taskStatus,
+            is_archived: var_isArchived,
+            is_pinned: var_isPinned,
+            is_starred: var_isStarred,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_note = <Option<crate::api::NoteItem>>::sse_decode(deserializer);
+        return crate::api::NoteResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            note: var_note,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteTemplateItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_templateId = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_folderNodeId = <Option<String>>::sse_decode(deserializer);
+        let mut var_prompts = <Vec<String>>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        return crate::api::NoteTemplateItem {
+            template_id: var_templateId,
+            name: var_name,
+            content: var_content,
+            tags: var_tags,
+            folder_node_id: var_folderNodeId,
+            prompts: var_prompts,
             updated_at: var_updatedAt,
         };
     }
 }
 
-impl SseDecode for crate::api::AtomListResponse {
+impl SseDecode for crate::api::NoteTemplateResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_template = <Option<crate::api::NoteTemplateItem>>::sse_decode(deserializer);
+        return crate::api::NoteTemplateResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            template: var_template,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NoteTemplatesListResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_ok = <bool>::sse_decode(deserializer);
         let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
         let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_items = <Vec<crate::api::AtomListItem>>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::NoteTemplateItem>>::sse_decode(deserializer);
+        return crate::api::NoteTemplatesListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::NotesListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::NoteItem>>::sse_decode(deserializer);
         let mut var_appliedLimit = <u32>::sse_decode(deserializer);
-        return crate::api::AtomListResponse {
+        return crate::api::NotesListResponse {
             ok: var_ok,
             error_code: var_errorCode,
             message: var_message,
@@ -1109,434 +2652,771 @@ impl SseDecode for crate::api::AtomListResponse {
     }
 }
 
-impl SseDecode for bool {
+impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for crate::api::EntryActionResponse {
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::NoteItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::NoteItem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::NoteTemplateItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::NoteTemplateItem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::WorkspaceNodeItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::WorkspaceNodeItem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::SearchSessionResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_sessionId = <Option<String>>::sse_decode(deserializer);
         let mut var_message = <String>::sse_decode(deserializer);
-        return crate::api::EntryActionResponse {
+        return crate::api::SearchSessionResponse {
             ok: var_ok,
-            atom_id: var_atomId,
+            error_code: var_errorCode,
+            session_id: var_sessionId,
             message: var_message,
         };
     }
 }
 
-impl SseDecode for crate::api::EntrySearchItem {
+impl SseDecode for crate::api::TagsListResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_atomId = <String>::sse_decode(deserializer);
-        let mut var_kind = <String>::sse_decode(deserializer);
-        let mut var_snippet = <String>::sse_decode(deserializer);
-        return crate::api::EntrySearchItem {
-            atom_id: var_atomId,
-            kind: var_kind,
-            snippet: var_snippet,
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::TagsListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            tags: var_tags,
         };
     }
 }
 
-impl SseDecode for crate::api::EntrySearchResponse {
+impl SseDecode for crate::api::TemplateNoteResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_ok = <bool>::sse_decode(deserializer);
         let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_items = <Vec<crate::api::EntrySearchItem>>::sse_decode(deserializer);
         let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
-        return crate::api::EntrySearchResponse {
+        let mut var_note = <Option<crate::api::NoteItem>>::sse_decode(deserializer);
+        let mut var_nodeId = <Option<String>>::sse_decode(deserializer);
+        let mut var_cursorOffset = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::TemplateNoteResponse {
             ok: var_ok,
             error_code: var_errorCode,
-            items: var_items,
             message: var_message,
-            applied_limit: var_appliedLimit,
+            note: var_note,
+            node_id: var_nodeId,
+            cursor_offset: var_cursorOffset,
         };
     }
 }
 
-impl SseDecode for i64 {
+impl SseDecode for crate::api::TemplatePromptAnswer {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::TemplatePromptAnswer {
+            name: var_name,
+            value: var_value,
+        };
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseDecode for crate::api::TrashItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_atomId = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_previewText = <Option<String>>::sse_decode(deserializer);
+        let mut var_deletedAt = <i64>::sse_decode(deserializer);
+        return crate::api::TrashItem {
+            atom_id: var_atomId,
+            kind: var_kind,
+            content: var_content,
+            preview_text: var_previewText,
+            deleted_at: var_deletedAt,
+        };
     }
 }
 
-impl SseDecode for Vec<crate::api::AtomListItem> {
+impl SseDecode for crate::api::TrashListResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::AtomListItem>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_items = <Vec<crate::api::TrashItem>>::sse_decode(deserializer);
+        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
+        return crate::api::TrashListResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            items: var_items,
+            applied_limit: var_appliedLimit,
+        };
     }
 }
 
-impl SseDecode for Vec<crate::api::EntrySearchItem> {
+impl SseDecode for crate::api::TrashPurgeResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::EntrySearchItem>::sse_decode(deserializer));
-        }
-        return ans_;
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_purged = <u32>::sse_decode(deserializer);
+        return crate::api::TrashPurgeResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
+            purged: var_purged,
+        };
     }
 }
 
-impl SseDecode for Vec<crate::api::NoteItem> {
+impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::NoteItem>::sse_decode(deserializer));
-        }
-        return ans_;
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+        deserializer.cursor.read_u8().unwrap()
     }
 }
 
-impl SseDecode for Vec<crate::api::WorkspaceNodeItem> {
+impl SseDecode for () {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::WorkspaceNodeItem>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
-impl SseDecode for crate::api::NoteItem {
+impl SseDecode for crate::api::WorkspaceActionResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_atomId = <String>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_previewText = <Option<String>>::sse_decode(deserializer);
-        let mut var_previewImage = <Option<String>>::sse_decode(deserializer);
-        let mut var_updatedAt = <i64>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::NoteItem {
-            atom_id: var_atomId,
-            content: var_content,
-            preview_text: var_previewText,
-            preview_image: var_previewImage,
-            updated_at: var_updatedAt,
-            tags: var_tags,
+        let mut var_ok = <bool>::sse_decode(deserializer);
+        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::WorkspaceActionResponse {
+            ok: var_ok,
+            error_code: var_errorCode,
+            message: var_message,
         };
     }
 }
 
-impl SseDecode for crate::api::NoteResponse {
+impl SseDecode for crate::api::WorkspaceListChildrenResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_ok = <bool>::sse_decode(deserializer);
         let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
         let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_note = <Option<crate::api::NoteItem>>::sse_decode(deserializer);
-        return crate::api::NoteResponse {
+        let mut var_items = <Vec<crate::api::WorkspaceNodeItem>>::sse_decode(deserializer);
+        return crate::api::WorkspaceListChildrenResponse {
             ok: var_ok,
             error_code: var_errorCode,
             message: var_message,
-            note: var_note,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::api::WorkspaceNodeItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodeId = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_parentNodeId = <Option<String>>::sse_decode(deserializer);
+        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
+        let mut var_displayName = <String>::sse_decode(deserializer);
+        let mut var_sortOrder = <i64>::sse_decode(deserializer);
+        return crate::api::WorkspaceNodeItem {
+            node_id: var_nodeId,
+            kind: var_kind,
+            parent_node_id: var_parentNodeId,
+            atom_id: var_atomId,
+            display_name: var_displayName,
+            sort_order: var_sortOrder,
         };
     }
 }
 
-impl SseDecode for crate::api::NotesListResponse {
+impl SseDecode for crate::api::WorkspaceNodeResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_ok = <bool>::sse_decode(deserializer);
         let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
         let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_items = <Vec<crate::api::NoteItem>>::sse_decode(deserializer);
-        let mut var_appliedLimit = <u32>::sse_decode(deserializer);
-        return crate::api::NotesListResponse {
+        let mut var_node = <Option<crate::api::WorkspaceNodeItem>>::sse_decode(deserializer);
+        return crate::api::WorkspaceNodeResponse {
             ok: var_ok,
             error_code: var_errorCode,
             message: var_message,
-            items: var_items,
-            applied_limit: var_appliedLimit,
+            node: var_node,
         };
     }
 }
 
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__atom_add_tag_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__atom_record_access_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__atom_remove_tag_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__atom_set_flag_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__atom_set_tags_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__atom_update_status_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__calendar_list_by_range_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__calendar_update_event_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__entry_create_note_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__entry_create_task_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__entry_schedule_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__entry_search_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__entry_search_all_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__entry_search_semantic_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__journal_redo_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__journal_status_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__journal_undo_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__note_create_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__note_create_from_template_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__note_get_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__note_set_tags_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__note_template_delete_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__note_template_save_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__note_templates_list_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__note_update_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__notes_list_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__search_refresh_embeddings_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__search_session_open_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__search_session_query_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__tags_list_impl(port, ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__tasks_list_inbox_impl(port, ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__tasks_list_today_impl(port, ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__tasks_list_upcoming_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__trash_list_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__trash_purge_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__trash_purge_older_than_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__trash_restore_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__workspace_create_folder_impl(port, ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__workspace_create_note_from_link_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => wire__crate__api__workspace_create_note_ref_impl(port, ptr, rust_vec_len, data_len),
+        47 => {
+            wire__crate__api__workspace_create_smart_folder_impl(port, ptr, rust_vec_len, data_len)
         }
+        48 => wire__crate__api__workspace_delete_folder_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__workspace_list_children_impl(port, ptr, rust_vec_len, data_len),
+        50 => {
+            wire__crate__api__workspace_list_deleted_folders_impl(port, ptr, rust_vec_len, data_len)
+        }
+        51 => wire__crate__api__workspace_move_node_impl(port, ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__workspace_rename_node_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__workspace_restore_folder_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
     }
 }
 
-impl SseDecode for Option<i64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        9 => wire__crate__api__configure_entry_db_path_impl(ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__core_version_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__init_logging_impl(ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__ping_impl(ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__search_session_cancel_impl(ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__search_session_close_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
     }
 }
 
-impl SseDecode for Option<crate::api::NoteItem> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::NoteItem>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AtomListItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.atom_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.preview_text.into_into_dart().into_dart(),
+            self.preview_image.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.start_at.into_into_dart().into_dart(),
+            self.end_at.into_into_dart().into_dart(),
+            self.task_status.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.is_archived.into_into_dart().into_dart(),
+            self.is_pinned.into_into_dart().into_dart(),
+            self.is_starred.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<u32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AtomListItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomListItem> for crate::api::AtomListItem {
+    fn into_into_dart(self) -> crate::api::AtomListItem {
+        self
     }
 }
-
-impl SseDecode for Option<crate::api::WorkspaceNodeItem> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::WorkspaceNodeItem>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AtomListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.applied_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::TagsListResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::TagsListResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-            tags: var_tags,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AtomListResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomListResponse>
+    for crate::api::AtomListResponse
+{
+    fn into_into_dart(self) -> crate::api::AtomListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AtomTagsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.inline_tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AtomTagsResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomTagsResponse>
+    for crate::api::AtomTagsResponse
+{
+    fn into_into_dart(self) -> crate::api::AtomTagsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DeletedFolderItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.node_id.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.mode.into_into_dart().into_dart(),
+            self.deleted_at.into_into_dart().into_dart(),
+            self.node_count.into_into_dart().into_dart(),
+            self.note_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DeletedFolderItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DeletedFolderItem>
+    for crate::api::DeletedFolderItem
+{
+    fn into_into_dart(self) -> crate::api::DeletedFolderItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DeletedFoldersResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::DeletedFoldersResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DeletedFoldersResponse>
+    for crate::api::DeletedFoldersResponse
+{
+    fn into_into_dart(self) -> crate::api::DeletedFoldersResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EmbeddingsRefreshResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.refreshed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EmbeddingsRefreshResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EmbeddingsRefreshResponse>
+    for crate::api::EmbeddingsRefreshResponse
+{
+    fn into_into_dart(self) -> crate::api::EmbeddingsRefreshResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntryActionResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntryActionResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntryActionResponse>
+    for crate::api::EntryActionResponse
+{
+    fn into_into_dart(self) -> crate::api::EntryActionResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchAllItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.node_id.into_into_dart().into_dart(),
+            self.parent_node_id.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.atom_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntrySearchAllItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchAllItem>
+    for crate::api::EntrySearchAllItem
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchAllItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchAllResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.applied_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntrySearchAllResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchAllResponse>
+    for crate::api::EntrySearchAllResponse
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchAllResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.atom_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.snippet.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EntrySearchItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchItem>
+    for crate::api::EntrySearchItem
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchItem {
+        self
     }
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.applied_limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::EntrySearchResponse
+{
 }
-
-impl SseDecode for crate::api::WorkspaceActionResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        return crate::api::WorkspaceActionResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchResponse>
+    for crate::api::EntrySearchResponse
+{
+    fn into_into_dart(self) -> crate::api::EntrySearchResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WorkspaceListChildrenResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_items = <Vec<crate::api::WorkspaceNodeItem>>::sse_decode(deserializer);
-        return crate::api::WorkspaceListChildrenResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-            items: var_items,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::JournalResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.command.into_into_dart().into_dart(),
+            self.target_id.into_into_dart().into_dart(),
+            self.undo_count.into_into_dart().into_dart(),
+            self.redo_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::WorkspaceNodeItem {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_nodeId = <String>::sse_decode(deserializer);
-        let mut var_kind = <String>::sse_decode(deserializer);
-        let mut var_parentNodeId = <Option<String>>::sse_decode(deserializer);
-        let mut var_atomId = <Option<String>>::sse_decode(deserializer);
-        let mut var_displayName = <String>::sse_decode(deserializer);
-        let mut var_sortOrder = <i64>::sse_decode(deserializer);
-        return crate::api::WorkspaceNodeItem {
-            node_id: var_nodeId,
-            kind: var_kind,
-            parent_node_id: var_parentNodeId,
-            atom_id: var_atomId,
-            display_name: var_displayName,
-            sort_order: var_sortOrder,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::JournalResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::JournalResponse>
+    for crate::api::JournalResponse
+{
+    fn into_into_dart(self) -> crate::api::JournalResponse {
+        self
     }
 }
-
-impl SseDecode for crate::api::WorkspaceNodeResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ok = <bool>::sse_decode(deserializer);
-        let mut var_errorCode = <Option<String>>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        let mut var_node = <Option<crate::api::WorkspaceNodeItem>>::sse_decode(deserializer);
-        return crate::api::WorkspaceNodeResponse {
-            ok: var_ok,
-            error_code: var_errorCode,
-            message: var_message,
-            node: var_node,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.atom_id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.preview_text.into_into_dart().into_dart(),
+            self.preview_image.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.is_archived.into_into_dart().into_dart(),
+            self.is_pinned.into_into_dart().into_dart(),
+            self.is_starred.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteItem> for crate::api::NoteItem {
+    fn into_into_dart(self) -> crate::api::NoteItem {
+        self
     }
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__atom_update_status_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__calendar_list_by_range_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__calendar_update_event_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__entry_create_note_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__entry_create_task_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__entry_schedule_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__entry_search_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__note_create_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__note_get_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__note_set_tags_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__note_update_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__notes_list_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__tags_list_impl(port, ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__tasks_list_inbox_impl(port, ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__tasks_list_today_impl(port, ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__tasks_list_upcoming_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__workspace_create_folder_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__workspace_create_note_ref_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__workspace_delete_folder_impl(port, ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__workspace_list_children_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__workspace_move_node_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__workspace_rename_node_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        4 => wire__crate__api__configure_entry_db_path_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__core_version_impl(ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__init_logging_impl(ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__ping_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteResponse> for crate::api::NoteResponse {
+    fn into_into_dart(self) -> crate::api::NoteResponse {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::AtomListItem {
+impl flutter_rust_bridge::IntoDart for crate::api::NoteTemplateItem {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.atom_id.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
+            self.template_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
             self.content.into_into_dart().into_dart(),
-            self.preview_text.into_into_dart().into_dart(),
-            self.preview_image.into_into_dart().into_dart(),
             self.tags.into_into_dart().into_dart(),
-            self.start_at.into_into_dart().into_dart(),
-            self.end_at.into_into_dart().into_dart(),
-            self.task_status.into_into_dart().into_dart(),
+            self.folder_node_id.into_into_dart().into_dart(),
+            self.prompts.into_into_dart().into_dart(),
             self.updated_at.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AtomListItem {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomListItem> for crate::api::AtomListItem {
-    fn into_into_dart(self) -> crate::api::AtomListItem {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteTemplateItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteTemplateItem>
+    for crate::api::NoteTemplateItem
+{
+    fn into_into_dart(self) -> crate::api::NoteTemplateItem {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::AtomListResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::NoteTemplateResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.template.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::NoteTemplateResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteTemplateResponse>
+    for crate::api::NoteTemplateResponse
+{
+    fn into_into_dart(self) -> crate::api::NoteTemplateResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NoteTemplatesListResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::NoteTemplatesListResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteTemplatesListResponse>
+    for crate::api::NoteTemplatesListResponse
+{
+    fn into_into_dart(self) -> crate::api::NoteTemplatesListResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::NotesListResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.ok.into_into_dart().into_dart(),
@@ -1548,119 +3428,124 @@ impl flutter_rust_bridge::IntoDart for crate::api::AtomListResponse {
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AtomListResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::AtomListResponse>
-    for crate::api::AtomListResponse
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NotesListResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::NotesListResponse>
+    for crate::api::NotesListResponse
 {
-    fn into_into_dart(self) -> crate::api::AtomListResponse {
+    fn into_into_dart(self) -> crate::api::NotesListResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::EntryActionResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::SearchSessionResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.ok.into_into_dart().into_dart(),
-            self.atom_id.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.session_id.into_into_dart().into_dart(),
             self.message.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::EntryActionResponse
+    for crate::api::SearchSessionResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::EntryActionResponse>
-    for crate::api::EntryActionResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SearchSessionResponse>
+    for crate::api::SearchSessionResponse
 {
-    fn into_into_dart(self) -> crate::api::EntryActionResponse {
+    fn into_into_dart(self) -> crate::api::SearchSessionResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchItem {
+impl flutter_rust_bridge::IntoDart for crate::api::TagsListResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.atom_id.into_into_dart().into_dart(),
-            self.kind.into_into_dart().into_dart(),
-            self.snippet.into_into_dart().into_dart(),
+            self.ok.into_into_dart().into_dart(),
+            self.error_code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EntrySearchItem {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchItem>
-    for crate::api::EntrySearchItem
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TagsListResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TagsListResponse>
+    for crate::api::TagsListResponse
 {
-    fn into_into_dart(self) -> crate::api::EntrySearchItem {
+    fn into_into_dart(self) -> crate::api::TagsListResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::EntrySearchResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::TemplateNoteResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.ok.into_into_dart().into_dart(),
             self.error_code.into_into_dart().into_dart(),
-            self.items.into_into_dart().into_dart(),
             self.message.into_into_dart().into_dart(),
-            self.applied_limit.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+            self.node_id.into_into_dart().into_dart(),
+            self.cursor_offset.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::EntrySearchResponse
+    for crate::api::TemplateNoteResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::EntrySearchResponse>
-    for crate::api::EntrySearchResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TemplateNoteResponse>
+    for crate::api::TemplateNoteResponse
 {
-    fn into_into_dart(self) -> crate::api::EntrySearchResponse {
+    fn into_into_dart(self) -> crate::api::TemplateNoteResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::NoteItem {
+impl flutter_rust_bridge::IntoDart for crate::api::TemplatePromptAnswer {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.atom_id.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.preview_text.into_into_dart().into_dart(),
-            self.preview_image.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteItem {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteItem> for crate::api::NoteItem {
-    fn into_into_dart(self) -> crate::api::NoteItem {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TemplatePromptAnswer
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TemplatePromptAnswer>
+    for crate::api::TemplatePromptAnswer
+{
+    fn into_into_dart(self) -> crate::api::TemplatePromptAnswer {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::NoteResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::TrashItem {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.ok.into_into_dart().into_dart(),
-            self.error_code.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-            self.note.into_into_dart().into_dart(),
+            self.atom_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.preview_text.into_into_dart().into_dart(),
+            self.deleted_at.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NoteResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::NoteResponse> for crate::api::NoteResponse {
-    fn into_into_dart(self) -> crate::api::NoteResponse {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TrashItem {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrashItem> for crate::api::TrashItem {
+    fn into_into_dart(self) -> crate::api::TrashItem {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::NotesListResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::TrashListResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.ok.into_into_dart().into_dart(),
@@ -1672,31 +3557,34 @@ impl flutter_rust_bridge::IntoDart for crate::api::NotesListResponse {
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::NotesListResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::NotesListResponse>
-    for crate::api::NotesListResponse
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TrashListResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrashListResponse>
+    for crate::api::TrashListResponse
 {
-    fn into_into_dart(self) -> crate::api::NotesListResponse {
+    fn into_into_dart(self) -> crate::api::TrashListResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::TagsListResponse {
+impl flutter_rust_bridge::IntoDart for crate::api::TrashPurgeResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.ok.into_into_dart().into_dart(),
             self.error_code.into_into_dart().into_dart(),
             self.message.into_into_dart().into_dart(),
-            self.tags.into_into_dart().into_dart(),
+            self.purged.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TagsListResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::TagsListResponse>
-    for crate::api::TagsListResponse
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TrashPurgeResponse
 {
-    fn into_into_dart(self) -> crate::api::TagsListResponse {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TrashPurgeResponse>
+    for crate::api::TrashPurgeResponse
+{
+    fn into_into_dart(self) -> crate::api::TrashPurgeResponse {
         self
     }
 }
@@ -1811,6 +3699,9 @@ impl SseEncode for crate::api::AtomListItem {
         <Option<i64>>::sse_encode(self.end_at, serializer);
         <Option<String>>::sse_encode(self.task_status, serializer);
         <i64>::sse_encode(self.updated_at, serializer);
+        <bool>::sse_encode(self.is_archived, serializer);
+        <bool>::sse_encode(self.is_pinned, serializer);
+        <bool>::sse_encode(self.is_starred, serializer);
     }
 }
 
@@ -1825,6 +3716,18 @@ impl SseEncode for crate::api::AtomListResponse {
     }
 }
 
+impl SseEncode for crate::api::AtomTagsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Vec<String>>::sse_encode(self.inline_tags, serializer);
+    }
+}
+
 impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1832,6 +3735,38 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::api::DeletedFolderItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.node_id, serializer);
+        <String>::sse_encode(self.display_name, serializer);
+        <String>::sse_encode(self.mode, serializer);
+        <i64>::sse_encode(self.deleted_at, serializer);
+        <u32>::sse_encode(self.node_count, serializer);
+        <u32>::sse_encode(self.note_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::DeletedFoldersResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::DeletedFolderItem>>::sse_encode(self.items, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EmbeddingsRefreshResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.refreshed, serializer);
+    }
+}
+
 impl SseEncode for crate::api::EntryActionResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1841,6 +3776,29 @@ impl SseEncode for crate::api::EntryActionResponse {
     }
 }
 
+impl SseEncode for crate::api::EntrySearchAllItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.atom_id, serializer);
+        <Option<String>>::sse_encode(self.node_id, serializer);
+        <Option<String>>::sse_encode(self.parent_node_id, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <Option<u32>>::sse_encode(self.atom_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EntrySearchAllResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <Vec<crate::api::EntrySearchAllItem>>::sse_encode(self.items, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.applied_limit, serializer);
+    }
+}
+
 impl SseEncode for crate::api::EntrySearchItem {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1861,6 +3819,13 @@ impl SseEncode for crate::api::EntrySearchResponse {
     }
 }
 
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for i64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1868,6 +3833,19 @@ impl SseEncode for i64 {
     }
 }
 
+impl SseEncode for crate::api::JournalResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.command, serializer);
+        <Option<String>>::sse_encode(self.target_id, serializer);
+        <u32>::sse_encode(self.undo_count, serializer);
+        <u32>::sse_encode(self.redo_count, serializer);
+    }
+}
+
 impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1888,6 +3866,26 @@ impl SseEncode for Vec<crate::api::AtomListItem> {
     }
 }
 
+impl SseEncode for Vec<crate::api::DeletedFolderItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::DeletedFolderItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::EntrySearchAllItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EntrySearchAllItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::EntrySearchItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1908,6 +3906,16 @@ impl SseEncode for Vec<crate::api::NoteItem> {
     }
 }
 
+impl SseEncode for Vec<crate::api::NoteTemplateItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::NoteTemplateItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1918,6 +3926,26 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<crate::api::TemplatePromptAnswer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TemplatePromptAnswer>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::TrashItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TrashItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::WorkspaceNodeItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1937,6 +3965,9 @@ impl SseEncode for crate::api::NoteItem {
         <Option<String>>::sse_encode(self.preview_image, serializer);
         <i64>::sse_encode(self.updated_at, serializer);
         <Vec<String>>::sse_encode(self.tags, serializer);
+        <bool>::sse_encode(self.is_archived, serializer);
+        <bool>::sse_encode(self.is_pinned, serializer);
+        <bool>::sse_encode(self.is_starred, serializer);
     }
 }
 
@@ -1950,6 +3981,39 @@ impl SseEncode for crate::api::NoteResponse {
     }
 }
 
+impl SseEncode for crate::api::NoteTemplateItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.template_id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Option<String>>::sse_encode(self.folder_node_id, serializer);
+        <Vec<String>>::sse_encode(self.prompts, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::NoteTemplateResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<crate::api::NoteTemplateItem>>::sse_encode(self.template, serializer);
+    }
+}
+
+impl SseEncode for crate::api::NoteTemplatesListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::NoteTemplateItem>>::sse_encode(self.items, serializer);
+    }
+}
+
 impl SseEncode for crate::api::NotesListResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1971,6 +4035,26 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1991,6 +4075,16 @@ impl SseEncode for Option<crate::api::NoteItem> {
     }
 }
 
+impl SseEncode for Option<crate::api::NoteTemplateItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::NoteTemplateItem>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2011,6 +4105,16 @@ impl SseEncode for Option<crate::api::WorkspaceNodeItem> {
     }
 }
 
+impl SseEncode for crate::api::SearchSessionResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <Option<String>>::sse_encode(self.session_id, serializer);
+        <String>::sse_encode(self.message, serializer);
+    }
+}
+
 impl SseEncode for crate::api::TagsListResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2021,6 +4125,58 @@ impl SseEncode for crate::api::TagsListResponse {
     }
 }
 
+impl SseEncode for crate::api::TemplateNoteResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<crate::api::NoteItem>>::sse_encode(self.note, serializer);
+        <Option<String>>::sse_encode(self.node_id, serializer);
+        <Option<u32>>::sse_encode(self.cursor_offset, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TemplatePromptAnswer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TrashItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.atom_id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.preview_text, serializer);
+        <i64>::sse_encode(self.deleted_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TrashListResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Vec<crate::api::TrashItem>>::sse_encode(self.items, serializer);
+        <u32>::sse_encode(self.applied_limit, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TrashPurgeResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.ok, serializer);
+        <Option<String>>::sse_encode(self.error_code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <u32>::sse_encode(self.purged, serializer);
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2081,13 +4237,6 @@ impl SseEncode for crate::api::WorkspaceNodeResponse {
     }
 }
 
-impl SseEncode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
-    }
-}
-
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.